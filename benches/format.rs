@@ -0,0 +1,27 @@
+use astrolabe::{Date, DateTime, Time};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Formats the numeric symbols which are zero padded without `format!`
+fn format(c: &mut Criterion) {
+    let mut group = c.benchmark_group("format");
+
+    let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap();
+    group.bench_function("date_time", |b| {
+        b.iter(|| black_box(date_time).format(black_box("yyyy-MM-dd HH:mm:ss.nnn")))
+    });
+
+    let date = Date::from_ymd(2022, 5, 2).unwrap();
+    group.bench_function("date", |b| {
+        b.iter(|| black_box(date).format(black_box("yyyy-MM-dd")))
+    });
+
+    let time = Time::from_hms(12, 32, 1).unwrap();
+    group.bench_function("time", |b| {
+        b.iter(|| black_box(time).format(black_box("HH:mm:ss.nnn")))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, format);
+criterion_main!(benches);
@@ -0,0 +1,43 @@
+use astrolabe::{Date, DateTime, Time};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Compares formats handled by the fixed-width fast path with equivalent formats which need the generic parser. `y` and `H` read the same digits as `yyyy` and `HH` here, but are not supported by the fast path.
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+
+    group.bench_function("date_time_fixed_width", |b| {
+        b.iter(|| {
+            DateTime::parse(
+                black_box("2022-05-02 12:32:01"),
+                black_box("yyyy-MM-dd HH:mm:ss"),
+            )
+        })
+    });
+    group.bench_function("date_time_generic", |b| {
+        b.iter(|| {
+            DateTime::parse(
+                black_box("2022-05-02 12:32:01"),
+                black_box("y-MM-dd HH:mm:ss"),
+            )
+        })
+    });
+
+    group.bench_function("date_fixed_width", |b| {
+        b.iter(|| Date::parse(black_box("2022-05-02"), black_box("yyyy-MM-dd")))
+    });
+    group.bench_function("date_generic", |b| {
+        b.iter(|| Date::parse(black_box("2022-05-02"), black_box("y-MM-dd")))
+    });
+
+    group.bench_function("time_fixed_width", |b| {
+        b.iter(|| Time::parse(black_box("12:32:01.123"), black_box("HH:mm:ss.nnn")))
+    });
+    group.bench_function("time_generic", |b| {
+        b.iter(|| Time::parse(black_box("12:32:01.123"), black_box("H:mm:ss.nnn")))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
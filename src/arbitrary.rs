@@ -0,0 +1,55 @@
+use crate::{
+    util::constants::{NANOS_PER_DAY, SECS_PER_DAY},
+    Date, DateTime, Offset, Time,
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Generates dates uniformly across the whole supported range, including negative years.
+impl<'a> Arbitrary<'a> for Date {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self {
+            days: i32::arbitrary(u)?,
+        })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        i32::size_hint(depth)
+    }
+}
+
+/// Generates times of day with nanosecond precision and an arbitrary fixed offset.
+impl<'a> Arbitrary<'a> for Time {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self {
+            nanoseconds: u.int_in_range(0..=NANOS_PER_DAY - 1)?,
+            offset: Offset::arbitrary(u)?,
+        })
+    }
+}
+
+/// Generates instants uniformly across the whole supported range with nanosecond precision and an arbitrary fixed offset.
+///
+/// Instants within a day of the range bounds get a zero offset if their local date in the generated offset would be out of range.
+impl<'a> Arbitrary<'a> for DateTime {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let date_time = Self {
+            days: i32::arbitrary(u)?,
+            nanoseconds: u.int_in_range(0..=NANOS_PER_DAY - 1)?,
+            offset: Offset::default(),
+        };
+        let offset = Offset::arbitrary(u)?;
+        Ok(date_time.try_set_offset(offset).unwrap_or(date_time))
+    }
+}
+
+/// Generates fixed offsets between `UTC-23:59:59` and `UTC+23:59:59`. [`Offset::Local`] is never generated, as it depends on the system.
+impl<'a> Arbitrary<'a> for Offset {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let max = SECS_PER_DAY as i32 - 1;
+        Ok(Self::Fixed(u.int_in_range(-max..=max)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        i32::size_hint(depth)
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    errors::{out_of_range::create_custom_oor, AstrolabeError},
+    util::{
+        constants::NANOS_PER_SEC,
+        offset::{add_offset_to_dn, add_offset_to_nanos},
+    },
+    Date, DateTime, Offset, Time,
+};
+use chrono::{Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+/// Converts days since `0001-01-01` to a [`NaiveDate`], which only supports years up to about ±262,000
+fn to_naive_date(days: i32) -> Result<NaiveDate, AstrolabeError> {
+    // chrono counts days from `0000-12-31` (Which is `-1-12-31` in astrolabe)
+    days.checked_add(1)
+        .and_then(NaiveDate::from_num_days_from_ce_opt)
+        .ok_or_else(|| {
+            create_custom_oor(format!(
+                "Date {} is outside of the range supported by chrono ({} to {}).",
+                Date::from_days(days),
+                Date::from(NaiveDate::MIN),
+                Date::from(NaiveDate::MAX)
+            ))
+        })
+}
+
+fn from_naive_date(date: NaiveDate) -> i32 {
+    date.num_days_from_ce() - 1
+}
+
+fn to_naive_time(nanoseconds: u64) -> NaiveTime {
+    NaiveTime::from_num_seconds_from_midnight_opt(
+        (nanoseconds / NANOS_PER_SEC) as u32,
+        (nanoseconds % NANOS_PER_SEC) as u32,
+    )
+    .expect("Nanoseconds are always less than a day")
+}
+
+/// Converts a [`NaiveTime`] to nanoseconds since midnight. Returns an error for leap seconds, as they can't be represented.
+fn from_naive_time(time: NaiveTime) -> Result<u64, AstrolabeError> {
+    if time.nanosecond() >= NANOS_PER_SEC as u32 {
+        return Err(create_custom_oor(format!(
+            "Leap second {} cannot be represented.",
+            time
+        )));
+    }
+    Ok(time.num_seconds_from_midnight() as u64 * NANOS_PER_SEC + time.nanosecond() as u64)
+}
+
+fn to_naive_date_time(days: i32, nanoseconds: u64) -> Result<NaiveDateTime, AstrolabeError> {
+    Ok(to_naive_date(days)?.and_time(to_naive_time(nanoseconds)))
+}
+
+fn from_naive_date_time(date_time: NaiveDateTime) -> Result<(i32, u64), AstrolabeError> {
+    Ok((
+        from_naive_date(date_time.date()),
+        from_naive_time(date_time.time())?,
+    ))
+}
+
+/// Dates outside of chrono's range (About ±262,000 years) return an [`OutOfRange`](AstrolabeError::OutOfRange) error.
+impl TryFrom<Date> for NaiveDate {
+    type Error = AstrolabeError;
+
+    fn try_from(value: Date) -> Result<Self, Self::Error> {
+        to_naive_date(value.days)
+    }
+}
+
+impl From<NaiveDate> for Date {
+    fn from(value: NaiveDate) -> Self {
+        Self {
+            days: from_naive_date(value),
+        }
+    }
+}
+
+/// Converts the local time of the offset.
+impl From<Time> for NaiveTime {
+    fn from(value: Time) -> Self {
+        to_naive_time(add_offset_to_nanos(
+            value.nanoseconds,
+            value.offset.resolve(),
+        ))
+    }
+}
+
+/// The time is interpreted as UTC. Leap seconds return an [`OutOfRange`](AstrolabeError::OutOfRange) error.
+impl TryFrom<NaiveTime> for Time {
+    type Error = AstrolabeError;
+
+    fn try_from(value: NaiveTime) -> Result<Self, Self::Error> {
+        Ok(Self {
+            nanoseconds: from_naive_time(value)?,
+            offset: Offset::default(),
+        })
+    }
+}
+
+/// Converts the local date and time of the offset. Dates outside of chrono's range (About ±262,000 years) return an [`OutOfRange`](AstrolabeError::OutOfRange) error.
+impl TryFrom<DateTime> for NaiveDateTime {
+    type Error = AstrolabeError;
+
+    fn try_from(value: DateTime) -> Result<Self, Self::Error> {
+        let (days, nanoseconds) =
+            add_offset_to_dn(value.days, value.nanoseconds, value.offset.resolve());
+        to_naive_date_time(days, nanoseconds)
+    }
+}
+
+/// The date and time are interpreted as UTC. Leap seconds return an [`OutOfRange`](AstrolabeError::OutOfRange) error.
+impl TryFrom<NaiveDateTime> for DateTime {
+    type Error = AstrolabeError;
+
+    fn try_from(value: NaiveDateTime) -> Result<Self, Self::Error> {
+        let (days, nanoseconds) = from_naive_date_time(value)?;
+        Ok(Self {
+            days,
+            nanoseconds,
+            offset: Offset::default(),
+        })
+    }
+}
+
+/// Keeps the instant and maps the offset to a [`FixedOffset`]. [`Offset::Local`] is resolved to the current local offset.
+///
+/// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the date in UTC or in the offset is outside of chrono's range (About ±262,000 years).
+impl TryFrom<DateTime> for chrono::DateTime<FixedOffset> {
+    type Error = AstrolabeError;
+
+    fn try_from(value: DateTime) -> Result<Self, Self::Error> {
+        let offset_seconds = value.offset.resolve();
+        let utc = to_naive_date_time(value.days, value.nanoseconds)?;
+        // Also make sure the local date is in range, as chrono expects it to be
+        let (local_days, local_nanoseconds) =
+            add_offset_to_dn(value.days, value.nanoseconds, offset_seconds);
+        to_naive_date_time(local_days, local_nanoseconds)?;
+        let offset =
+            FixedOffset::east_opt(offset_seconds).expect("Offsets are always less than a day");
+        Ok(Self::from_naive_utc_and_offset(utc, offset))
+    }
+}
+
+/// Keeps the instant and maps the [`FixedOffset`] to [`Offset::Fixed`]. Leap seconds return an [`OutOfRange`](AstrolabeError::OutOfRange) error.
+impl TryFrom<chrono::DateTime<FixedOffset>> for DateTime {
+    type Error = AstrolabeError;
+
+    fn try_from(value: chrono::DateTime<FixedOffset>) -> Result<Self, Self::Error> {
+        let (days, nanoseconds) = from_naive_date_time(value.naive_utc())?;
+        Ok(Self {
+            days,
+            nanoseconds,
+            offset: Offset::Fixed(value.offset().local_minus_utc()),
+        })
+    }
+}
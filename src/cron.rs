@@ -1,8 +1,15 @@
 use crate::{
-    errors::{invalid_format::create_invalid_format, AstrolabeError},
+    errors::{
+        invalid_format::{create_field_invalid_format, create_invalid_format},
+        out_of_range::{create_conditional_oor, create_simple_oor},
+        AstrolabeError,
+    },
+    util::constants::{
+        MAX_DATE, NANOS_PER_DAY, NANOS_PER_HOUR, NANOS_PER_MINUTE, NANOS_PER_SEC, SECS_PER_DAY,
+    },
     DateTime, DateUtilities, TimeUtilities,
 };
-use std::{collections::HashSet, str::FromStr};
+use std::{collections::HashSet, str::FromStr, time::Duration};
 
 pub(crate) enum Month {
     Jan,
@@ -83,16 +90,50 @@ enum CronPartType {
     Numeric,
     Month,
     DayOfWeek,
+    /// Day of week as used by Quartz, where `1` is Sunday and `7` is Saturday
+    QuartzDayOfWeek,
 }
 
-/// A cron expression parser. Implements [`std::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html) to generate corresponding [`DateTime`] structs.
+/// Years matched by a part of the year field
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct YearRange {
+    start: u32,
+    end: u32,
+    step: u32,
+}
+
+impl YearRange {
+    /// Returns the first matching year which is greater than or equal to `year`
+    fn next_match(&self, year: u32) -> Option<u32> {
+        if year <= self.start {
+            return Some(self.start);
+        }
+        let steps = (year - self.start + self.step - 1) / self.step;
+        self.start
+            .checked_add(steps.checked_mul(self.step)?)
+            .filter(|year| *year <= self.end)
+    }
+}
+
+/// A cron expression parser. Generates the upcoming occurrences as [`DateTime`] structs with [`CronSchedule::upcoming`], [`CronSchedule::next_occurrence`] and [`CronSchedule::upcoming_n`].
+///
+/// [`CronSchedule`] also implements [`std::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html) itself for compatibility. Iterating over it that way consumes the schedule, so prefer the borrowing methods above.
 #[derive(Debug, Clone)]
 pub struct CronSchedule {
+    seconds: HashSet<u8>,
     minutes: HashSet<u8>,
     hours: HashSet<u8>,
     days_of_month: HashSet<u8>,
     months: HashSet<u8>,
     days_of_week: HashSet<u8>,
+    years: Option<Vec<YearRange>>,
+    /// If the day of month field starts with `*`
+    days_of_month_wildcard: bool,
+    /// If the day of week field starts with `*`
+    days_of_week_wildcard: bool,
+    dom_dow_and: bool,
+    max_jitter: Duration,
+    seed: u64,
     last_schedule: Option<DateTime>,
     #[cfg(test)]
     now: Option<DateTime>,
@@ -102,7 +143,7 @@ impl CronSchedule {
     /// Creates a new [`CronSchedule`] based on the provided cron expression.
     /// Aims to be compatible with [crontab](https://man7.org/linux/man-pages/man5/crontab.5.html) from Linux.
     ///
-    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the given expression could not be parsed.
+    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the given expression could not be parsed. The error contains the name of the field, the invalid token and its position in the expression. Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if a value is not allowed in its field, or if none of the days in the day of month field exist in the months of the month field and the day of week field starts with `*`, e.g. `0 0 30 2 *`.
     ///
     /// Format: `"minute hour day-of-month month day-of-week"`
     ///
@@ -117,12 +158,25 @@ impl CronSchedule {
     /// - Use `*` (asterisk) to indicate that all values of the field are valid.
     /// - Every field also allows `,` (comma) and `-` (hyphen) to specify multiple values and ranges. You can also combine them, for example `1,3-5,10-15`.
     /// - Step values are also supported, for example `*/5` in the minute field means every 5 minutes.
+    /// - `?` (question mark) is an alias of `*` in the day of month and day of week fields.
+    ///
+    /// [Quartz](https://www.quartz-scheduler.org/documentation/quartz-2.3.0/tutorials/crontrigger.html) expressions with 6 or 7 fields are supported as well: `"second minute hour day-of-month month day-of-week [year]"`
+    ///
+    /// | Field        | Allowed values                 |
+    /// | ------------ | ------------------------------ |
+    /// | second       | 0-59                           |
+    /// | day of week  | 1-7 (1 is Sunday), Sun-Sat     |
+    /// | year         | 1-5879611 (optional)           |
+    ///
+    /// The year field additionally allows steps on ranges and start values, for example `2024-2030/2` or `2024/2`. Once no matching year remains, the iterator returns [`None`]. The special characters `L`, `W` and `#` are not supported.
+    ///
+    /// Like in crontab, if both the day of month and day of week fields are restricted (don't start with `*`), a day matches if *either* field matches. For example, `0 0 13 * 5` runs on the 13th of every month and on every Friday. If one of the fields starts with `*`, a day has to match both fields. Use [`CronSchedule::with_dom_dow_and`] to always require both fields to match.
     ///
     /// ```rust
     /// # use astrolabe::CronSchedule;
     /// // Every 5 minutes
     /// let schedule = CronSchedule::parse("*/5 * * * *").unwrap();
-    /// for date in schedule.take(3) {
+    /// for date in schedule.upcoming().take(3) {
     ///    println!("{}", date);
     /// }
     /// // Prints for example:
@@ -132,24 +186,38 @@ impl CronSchedule {
     ///
     /// // Every weekday at 10:00
     /// let schedule = CronSchedule::parse("0 10 * * Mon-Fri").unwrap();
-    /// for date in schedule.take(3) {
+    /// for date in schedule.upcoming().take(3) {
     ///    println!("{}", date.format("yyyy-MM-dd HH:mm:ss eeee"));
     /// }
     /// // Prints for example:
     /// // 2022-05-03 10:00:00 Tuesday
     /// // 2022-05-04 10:00:00 Wednesday
     /// // 2022-05-05 10:00:00 Thursday
+    ///
+    /// // Every day at 12:00 from 2025 to 2030
+    /// let schedule = CronSchedule::parse("0 0 12 * * ? 2025-2030").unwrap();
+    ///
+    /// // Schedules in the past have no occurrences
+    /// let schedule = CronSchedule::parse("0 0 12 1 1 ? 2020").unwrap();
+    /// assert!(schedule.next_occurrence().is_none());
     /// ```
     #[cfg(not(test))]
     pub fn parse(expression: &str) -> Result<Self, AstrolabeError> {
         let fields = parse_expression(expression)?;
 
         Ok(CronSchedule {
-            minutes: fields.0,
-            hours: fields.1,
-            days_of_month: fields.2,
-            months: fields.3,
-            days_of_week: fields.4,
+            seconds: fields.0,
+            minutes: fields.1,
+            hours: fields.2,
+            days_of_month: fields.3,
+            months: fields.4,
+            days_of_week: fields.5,
+            years: fields.6,
+            days_of_month_wildcard: fields.7,
+            days_of_week_wildcard: fields.8,
+            dom_dow_and: false,
+            max_jitter: Duration::ZERO,
+            seed: 0,
             last_schedule: None,
         })
     }
@@ -161,115 +229,550 @@ impl CronSchedule {
         let fields = parse_expression(expression)?;
 
         Ok(CronSchedule {
-            minutes: fields.0,
-            hours: fields.1,
-            days_of_month: fields.2,
-            months: fields.3,
-            days_of_week: fields.4,
+            seconds: fields.0,
+            minutes: fields.1,
+            hours: fields.2,
+            days_of_month: fields.3,
+            months: fields.4,
+            days_of_week: fields.5,
+            years: fields.6,
+            days_of_month_wildcard: fields.7,
+            days_of_week_wildcard: fields.8,
+            dom_dow_and: false,
+            max_jitter: Duration::ZERO,
+            seed: 0,
             last_schedule: None,
             now,
         })
     }
-}
 
-type CronParts = (
-    HashSet<u8>,
-    HashSet<u8>,
-    HashSet<u8>,
-    HashSet<u8>,
-    HashSet<u8>,
-);
-
-fn parse_expression(expression: &str) -> Result<CronParts, AstrolabeError> {
-    let fields: Vec<&str> = expression.split_whitespace().collect();
+    /// Returns an iterator over the upcoming occurrences of the schedule, starting at the current time.
+    ///
+    /// Unlike iterating over the [`CronSchedule`] itself, the schedule is only borrowed and not modified, so it can be reused and shared. Iterating over `&CronSchedule` does the same.
+    ///
+    /// ```rust
+    /// # use astrolabe::{CronSchedule, TimeUtilities};
+    /// let schedule = CronSchedule::parse("0 10 * * Mon-Fri").unwrap();
+    /// for date in schedule.upcoming().take(3) {
+    ///     assert_eq!(10, date.hour());
+    /// }
+    /// for date in (&schedule).into_iter().take(3) {
+    ///     assert_eq!(10, date.hour());
+    /// }
+    /// ```
+    pub fn upcoming(&self) -> Upcoming<'_> {
+        Upcoming {
+            schedule: self,
+            last_schedule: None,
+        }
+    }
 
-    if fields.len() != 5 {
-        return Err(create_invalid_format(
-            "Invalid number of cron fields, has to consists of 5 fields".to_string(),
-        ));
+    /// Returns the next occurrence of the schedule after the current time, or [`None`] if there is no upcoming occurrence.
+    ///
+    /// ```rust
+    /// # use astrolabe::{CronSchedule, DateTime};
+    /// let schedule = CronSchedule::parse("*/5 * * * *").unwrap();
+    /// assert!(schedule.next_occurrence().unwrap() > DateTime::now());
+    ///
+    /// let schedule = CronSchedule::parse("0 0 12 1 1 ? 2020").unwrap();
+    /// assert!(schedule.next_occurrence().is_none());
+    /// ```
+    pub fn next_occurrence(&self) -> Option<DateTime> {
+        self.upcoming().next()
     }
 
-    let minutes = parse_cron_part(fields[0], 0, 59, &CronPartType::Numeric)
-        .map_err(|err| create_invalid_format(format!("Failed parsing minute field: {}", err)))?;
+    /// Returns the next `n` occurrences of the schedule after the current time. Returns less than `n` occurrences if the schedule ends earlier.
+    ///
+    /// ```rust
+    /// # use astrolabe::{CronSchedule, TimeUtilities};
+    /// let schedule = CronSchedule::parse("0 * * * *").unwrap();
+    /// let occurrences = schedule.upcoming_n(3);
+    /// assert_eq!(3, occurrences.len());
+    /// assert_eq!(3600, occurrences[1].seconds_since(&occurrences[0]));
+    /// ```
+    pub fn upcoming_n(&self, n: usize) -> Vec<DateTime> {
+        self.upcoming().take(n).collect()
+    }
 
-    let hours = parse_cron_part(fields[1], 0, 23, &CronPartType::Numeric)
-        .map_err(|err| create_invalid_format(format!("Failed parsing hour field: {}", err)))?;
+    /// Requires both the day of month and day of week fields to match, instead of either of them if both are restricted.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if none of the days in the day of month field exist in the months of the month field, e.g. `0 0 30 2 1`, as no day could match both fields.
+    ///
+    /// ```rust
+    /// # use astrolabe::{CronSchedule, DateTime};
+    /// // Every Friday the 13th
+    /// let schedule = CronSchedule::parse("0 0 13 * 5")
+    ///     .unwrap()
+    ///     .with_dom_dow_and()
+    ///     .unwrap();
+    /// assert!(schedule.matches(&DateTime::from_ymd(2022, 5, 13).unwrap()));
+    /// assert!(!schedule.matches(&DateTime::from_ymd(2022, 1, 13).unwrap()));
+    /// assert!(!schedule.matches(&DateTime::from_ymd(2022, 1, 14).unwrap()));
+    ///
+    /// // Every 13th and every Friday
+    /// let schedule = CronSchedule::parse("0 0 13 * 5").unwrap();
+    /// assert!(schedule.matches(&DateTime::from_ymd(2022, 1, 13).unwrap()));
+    /// assert!(schedule.matches(&DateTime::from_ymd(2022, 1, 14).unwrap()));
+    ///
+    /// // February 30th never exists
+    /// let schedule = CronSchedule::parse("0 0 30 2 1").unwrap();
+    /// assert!(schedule.with_dom_dow_and().is_err());
+    /// ```
+    pub fn with_dom_dow_and(self) -> Result<Self, AstrolabeError> {
+        validate_days_of_month(&self.days_of_month, &self.months)?;
 
-    let days_of_month =
-        parse_cron_part(fields[2], 1, 31, &CronPartType::Numeric).map_err(|err| {
-            create_invalid_format(format!("Failed parsing day of month field: {}", err))
-        })?;
+        Ok(Self {
+            dom_dow_and: true,
+            ..self
+        })
+    }
 
-    let months = parse_cron_part(fields[3], 1, 12, &CronPartType::Month)
-        .map_err(|err| create_invalid_format(format!("Failed parsing month field: {}", err)))?;
+    /// Shifts every occurrence forward by a pseudo-random amount in the range `[0, max_jitter)`.
+    ///
+    /// The shift is derived from `seed` and the scheduled instant, so the same schedule and seed always produce the same occurrences. This allows to display upcoming occurrences which agree with the ones that are executed. A `max_jitter` of zero disables jitter.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if `max_jitter` is longer than one day. Occurrences up to `max_jitter` before an instant are walked to find its jittered occurrences, so the maximum keeps this bounded.
+    ///
+    /// Occurrences are returned in the order of their scheduled instants. If `max_jitter` is larger than the interval between two occurrences, the jittered instants might not be in ascending order.
+    ///
+    /// ```rust
+    /// # use astrolabe::CronSchedule;
+    /// # use std::time::Duration;
+    /// // Every hour, up to 5 minutes later
+    /// let schedule = CronSchedule::parse("0 * * * *")
+    ///     .unwrap()
+    ///     .with_jitter(Duration::from_secs(5 * 60), 42)
+    ///     .unwrap();
+    /// for date in schedule.upcoming().take(3) {
+    ///    println!("{}", date);
+    /// }
+    /// // Prints for example:
+    /// // 2022/05/02 17:03:12
+    /// // 2022/05/02 18:00:47
+    /// // 2022/05/02 19:04:31
+    /// ```
+    pub fn with_jitter(self, max_jitter: Duration, seed: u64) -> Result<Self, AstrolabeError> {
+        if max_jitter.as_nanos() > NANOS_PER_DAY as u128 {
+            return Err(create_conditional_oor(
+                "max_jitter",
+                0,
+                NANOS_PER_DAY as i128,
+                max_jitter.as_nanos() as i128,
+                "in nanoseconds (one day)".to_string(),
+            ));
+        }
 
-    let days_of_week =
-        parse_cron_part(fields[4], 0, 6, &CronPartType::DayOfWeek).map_err(|err| {
-            create_invalid_format(format!("Failed parsing day of week field: {}", err))
-        })?;
+        Ok(Self {
+            max_jitter,
+            seed,
+            ..self
+        })
+    }
 
-    Ok((minutes, hours, days_of_month, months, days_of_week))
-}
+    /// Returns `true` if the given instant is an occurrence of the schedule. The instant is truncated to seconds and the cron fields are compared in the offset of `date_time`.
+    ///
+    /// For schedules with [jitter](CronSchedule::with_jitter), only the second of the jittered instant matches, not the scheduled instant it was derived from.
+    ///
+    /// ```rust
+    /// # use astrolabe::{CronSchedule, DateTime, TimeUtilities};
+    /// let schedule = CronSchedule::parse("*/15 * * * *").unwrap();
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 10, 30, 0).unwrap();
+    /// assert!(schedule.matches(&date_time));
+    /// assert!(schedule.matches(&date_time.set_milli(500).unwrap()));
+    /// assert!(!schedule.matches(&DateTime::from_ymdhms(2022, 5, 2, 10, 31, 0).unwrap()));
+    /// assert!(!schedule.matches(&DateTime::from_ymdhms(2022, 5, 2, 10, 30, 1).unwrap()));
+    /// ```
+    pub fn matches(&self, date_time: &DateTime) -> bool {
+        let second = date_time.clear_until_milli();
+        let window = self.jitter_window();
+        let before = second.saturating_sub_seconds(window);
+        // Saturated at the minimum date, which can be scheduled itself
+        let mut scheduled =
+            if second.seconds_since(&before) < window as i64 && self.matches_fields(&before) {
+                Some(before)
+            } else {
+                self.next_scheduled(before)
+            };
 
-impl Iterator for CronSchedule {
-    type Item = DateTime;
+        while let Some(instant) = scheduled.filter(|instant| *instant <= second) {
+            if self
+                .apply_jitter(instant)
+                .map_or(false, |jittered| jittered.clear_until_milli() == second)
+            {
+                return true;
+            }
+            scheduled = self.next_scheduled(instant);
+        }
+        false
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
+    /// Returns the scheduled and the jittered instant of the next occurrence after the current time. Continues after `last_schedule` if it is still relevant.
+    fn next_occurrence_after(
+        &self,
+        last_schedule: Option<DateTime>,
+    ) -> Option<(DateTime, DateTime)> {
         #[cfg(not(test))]
-        let now = DateTime::now().clear_until_second();
+        let now = DateTime::now().clear_until_milli();
         #[cfg(test)]
-        let now = self.now.unwrap_or(DateTime::now()).clear_until_second();
+        let now = self.now.unwrap_or(DateTime::now()).clear_until_milli();
 
-        let last = match self.last_schedule {
-            Some(last) if last >= now => last,
-            _ => now,
+        // Scheduled instants up to `max_jitter` in the past can still have an upcoming jittered occurrence
+        let earliest = now.saturating_sub_seconds(self.jitter_window());
+        let mut scheduled = match last_schedule {
+            Some(last) if last >= earliest => last,
+            _ => earliest,
         };
 
-        let mut next = last.add_minutes(1);
+        loop {
+            scheduled = self.next_scheduled(scheduled)?;
+            let jittered = self.apply_jitter(scheduled)?;
+            if jittered > now {
+                return Some((scheduled, jittered));
+            }
+        }
+    }
 
-        let dom_restricted = self.days_of_month.len() != 31;
-        let dow_restricted = self.days_of_week.len() != 7;
+    /// Returns the next scheduled instant after `last`, without jitter. Returns [`None`] if no matching year remains, no occurrence was found in [`MAX_SEARCHED_YEARS`] matching years or the next occurrence would be out of range.
+    fn next_scheduled(&self, last: DateTime) -> Option<DateTime> {
+        let mut next = last.apply_relative(0, NANOS_PER_SEC as i128).ok()?;
+        let mut searched_year = next.year();
+        let mut searched_years = 0;
 
         loop {
+            let year = next.year();
+            if year != searched_year {
+                searched_year = year;
+                searched_years += 1;
+                if searched_years > MAX_SEARCHED_YEARS {
+                    return None;
+                }
+            }
+
+            let next_year = self.next_year(year)?;
+            if next_year != year {
+                next = next.clear_until_month().set_year(next_year).ok()?;
+                continue;
+            }
+
             if !self.months.contains(&(next.month() as u8)) {
-                next = next.add_months(1).clear_until_day();
+                next = next.clear_until_day().apply_relative(1, 0).ok()?;
                 continue;
             }
 
-            let day_of_month = next.day() as u8;
-            let day_of_week = next.weekday();
-
-            // If both are restricted, the datetime will be valid if either field
-            // matches the current time.
-            // If only one is restricted, the datetime will be valid if the
-            // restricted field matches the current time.
-            if (dom_restricted
-                && dow_restricted
-                && !self.days_of_month.contains(&day_of_month)
-                && !self.days_of_week.contains(&day_of_week))
-                || (dom_restricted
-                    && !dow_restricted
-                    && !self.days_of_month.contains(&day_of_month))
-                || (dow_restricted && !dom_restricted && !self.days_of_week.contains(&day_of_week))
-            {
-                next = next.add_days(1).clear_until_hour();
+            if !self.matches_day(&next) {
+                next = next
+                    .clear_until_hour()
+                    .apply_relative(0, NANOS_PER_DAY as i128)
+                    .ok()?;
                 continue;
             }
 
             if !self.hours.contains(&(next.hour() as u8)) {
-                next = next.add_hours(1).clear_until_minute();
+                next = next
+                    .clear_until_minute()
+                    .apply_relative(0, NANOS_PER_HOUR as i128)
+                    .ok()?;
                 continue;
             }
 
             if !self.minutes.contains(&(next.minute() as u8)) {
-                next = next.add_minutes(1).clear_until_second();
+                next = next
+                    .clear_until_second()
+                    .apply_relative(0, NANOS_PER_MINUTE as i128)
+                    .ok()?;
+                continue;
+            }
+
+            if !self.seconds.contains(&(next.second() as u8)) {
+                next = next
+                    .clear_until_milli()
+                    .apply_relative(0, NANOS_PER_SEC as i128)
+                    .ok()?;
                 continue;
             }
 
             break;
         }
 
-        self.last_schedule = Some(next);
+        Some(next)
+    }
+
+    /// Returns the first year which is greater than or equal to `year` and matches the year field
+    fn next_year(&self, year: i32) -> Option<i32> {
+        let years = match &self.years {
+            Some(years) => years,
+            None => return Some(year),
+        };
+        let year = year.max(1) as u32;
+
+        years
+            .iter()
+            .filter_map(|range| range.next_match(year))
+            .min()
+            .map(|year| year as i32)
+    }
+
+    /// Checks if all cron fields match the given instant and it is at the start of a second
+    fn matches_fields(&self, date_time: &DateTime) -> bool {
+        date_time.clear_until_milli() == *date_time
+            && self.next_year(date_time.year()) == Some(date_time.year())
+            && self.months.contains(&(date_time.month() as u8))
+            && self.matches_day(date_time)
+            && self.hours.contains(&(date_time.hour() as u8))
+            && self.minutes.contains(&(date_time.minute() as u8))
+            && self.seconds.contains(&(date_time.second() as u8))
+    }
+
+    /// Checks if the day of month and day of week fields match the given instant
+    fn matches_day(&self, date_time: &DateTime) -> bool {
+        let day_of_month = self.days_of_month.contains(&(date_time.day() as u8));
+        let day_of_week = self.days_of_week.contains(&date_time.weekday_sunday0());
+
+        // Same as crontab: If both fields are restricted, the datetime will be
+        // valid if either field matches the current time.
+        if self.dom_dow_and || self.days_of_month_wildcard || self.days_of_week_wildcard {
+            day_of_month && day_of_week
+        } else {
+            day_of_month || day_of_week
+        }
+    }
+
+    /// Shifts a scheduled instant by its jitter. Returns [`None`] if the jittered instant would be out of range.
+    fn apply_jitter(&self, scheduled: DateTime) -> Option<DateTime> {
+        let max_nanos = self.max_jitter.as_nanos();
+        if max_nanos == 0 {
+            return Some(scheduled);
+        }
+
+        let hash = splitmix64(self.seed ^ splitmix64(scheduled.as_seconds() as u64));
+        let jitter = hash as u128 % max_nanos;
+        scheduled.apply_relative(0, jitter as i128).ok()
+    }
+
+    /// Returns `max_jitter` rounded up to whole seconds, but at least one second. Only instants scheduled less than this before a second can be jittered into it.
+    fn jitter_window(&self) -> u32 {
+        // `max_jitter` is at most one day
+        let seconds = (self.max_jitter.as_nanos() as u64 + NANOS_PER_SEC - 1) / NANOS_PER_SEC;
+        (seconds as u32).clamp(1, SECS_PER_DAY)
+    }
+}
+
+/// The Gregorian calendar repeats every 400 years, so a schedule without an occurrence in 400 matching years never matches
+const MAX_SEARCHED_YEARS: u32 = 400;
+
+/// SplitMix64 pseudo-random number generator step, see https://prng.di.unimi.it/splitmix64.c
+fn splitmix64(state: u64) -> u64 {
+    let mut z = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+type CronParts = (
+    HashSet<u8>,
+    HashSet<u8>,
+    HashSet<u8>,
+    HashSet<u8>,
+    HashSet<u8>,
+    HashSet<u8>,
+    Option<Vec<YearRange>>,
+    bool,
+    bool,
+);
+
+fn parse_expression(expression: &str) -> Result<CronParts, AstrolabeError> {
+    // Fields with their byte position in the expression
+    let mut fields: Vec<(usize, &str)> = expression
+        .split_whitespace()
+        .map(|field| {
+            (
+                field.as_ptr() as usize - expression.as_ptr() as usize,
+                field,
+            )
+        })
+        .collect();
+
+    let quartz = match fields.len() {
+        5 => false,
+        6 | 7 => true,
+        _ => {
+            return Err(create_invalid_format(
+                "Invalid number of cron fields, has to consists of 5, 6 or 7 fields".to_string(),
+            ))
+        }
+    };
+
+    let seconds = if quartz {
+        let (position, field) = fields.remove(0);
+        parse_cron_part(field, position, "second", 0, 59, &CronPartType::Numeric)?
+    } else {
+        HashSet::from([0])
+    };
+
+    // `?` means no specific value in Quartz expressions
+    for index in [2, 4] {
+        if fields[index].1 == "?" {
+            fields[index].1 = "*";
+        }
+    }
+
+    let (position, field) = fields[0];
+    let minutes = parse_cron_part(field, position, "minute", 0, 59, &CronPartType::Numeric)?;
+
+    let (position, field) = fields[1];
+    let hours = parse_cron_part(field, position, "hour", 0, 23, &CronPartType::Numeric)?;
+
+    let (position, field) = fields[2];
+    let days_of_month = parse_cron_part(
+        field,
+        position,
+        "day of month",
+        1,
+        31,
+        &CronPartType::Numeric,
+    )?;
+
+    let (position, field) = fields[3];
+    let months = parse_cron_part(field, position, "month", 1, 12, &CronPartType::Month)?;
+
+    let (position, field) = fields[4];
+    let days_of_week: HashSet<u8> = if quartz {
+        parse_cron_part(
+            field,
+            position,
+            "day of week",
+            1,
+            7,
+            &CronPartType::QuartzDayOfWeek,
+        )?
+        .into_iter()
+        .map(|day| day - 1)
+        .collect()
+    } else {
+        // 0 and 7 are both Sunday
+        parse_cron_part(
+            field,
+            position,
+            "day of week",
+            0,
+            7,
+            &CronPartType::DayOfWeek,
+        )?
+        .into_iter()
+        .map(|day| day % 7)
+        .collect()
+    };
+
+    let years = match fields.get(5) {
+        Some((position, field)) => parse_year_part(field, *position)?,
+        None => None,
+    };
+
+    let days_of_month_wildcard = fields[2].1.starts_with('*');
+    let days_of_week_wildcard = fields[4].1.starts_with('*');
+
+    // Without a restricted day of week, a day has to exist in one of the months
+    if days_of_week_wildcard {
+        validate_days_of_month(&days_of_month, &months)?;
+    }
+
+    Ok((
+        seconds,
+        minutes,
+        hours,
+        days_of_month,
+        months,
+        days_of_week,
+        years,
+        days_of_month_wildcard,
+        days_of_week_wildcard,
+    ))
+}
+
+/// Checks if at least one day of month exists in one of the months, e.g. `30 2` (February 30th) doesn't
+fn validate_days_of_month(
+    days_of_month: &HashSet<u8>,
+    months: &HashSet<u8>,
+) -> Result<(), AstrolabeError> {
+    let first_day = days_of_month.iter().min().copied().unwrap_or(1);
+    let max_days = months
+        .iter()
+        .map(|month| match month {
+            2 => 29,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        })
+        .max()
+        .unwrap_or(31);
+
+    if first_day > max_days {
+        return Err(create_conditional_oor(
+            "day of month",
+            1,
+            max_days as i128,
+            first_day as i128,
+            format!(
+                "because the months in the month field have at most {} days",
+                max_days
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Schedules are equal if they have the same fields, settings and jitter. The position of an iteration over the schedule is not compared.
+impl PartialEq for CronSchedule {
+    fn eq(&self, other: &Self) -> bool {
+        self.seconds == other.seconds
+            && self.minutes == other.minutes
+            && self.hours == other.hours
+            && self.days_of_month == other.days_of_month
+            && self.months == other.months
+            && self.days_of_week == other.days_of_week
+            && self.years == other.years
+            && self.days_of_month_wildcard == other.days_of_month_wildcard
+            && self.days_of_week_wildcard == other.days_of_week_wildcard
+            && self.dom_dow_and == other.dom_dow_and
+            && self.max_jitter == other.max_jitter
+            && self.seed == other.seed
+    }
+}
+
+impl Eq for CronSchedule {}
+
+impl Iterator for CronSchedule {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (scheduled, next) = self.next_occurrence_after(self.last_schedule)?;
+        self.last_schedule = Some(scheduled);
+        Some(next)
+    }
+}
+
+impl<'a> IntoIterator for &'a CronSchedule {
+    type Item = DateTime;
+    type IntoIter = Upcoming<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.upcoming()
+    }
+}
+
+/// Iterator over the upcoming occurrences of a [`CronSchedule`], created by [`CronSchedule::upcoming`]. Borrows the schedule without modifying it.
+#[derive(Debug, Clone)]
+pub struct Upcoming<'a> {
+    schedule: &'a CronSchedule,
+    last_schedule: Option<DateTime>,
+}
+
+impl Iterator for Upcoming<'_> {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (scheduled, next) = self.schedule.next_occurrence_after(self.last_schedule)?;
+        self.last_schedule = Some(scheduled);
         Some(next)
     }
 }
@@ -287,89 +790,219 @@ impl FromStr for CronSchedule {
 
 fn parse_cron_part(
     field: &str,
+    position: usize,
+    name: &'static str,
     min: u8,
     max: u8,
     cron_type: &CronPartType,
-) -> Result<HashSet<u8>, String> {
-    if cron_type == &CronPartType::Numeric && !is_numeric_part(field) {
-        return Err(format!(
-            "Invalid character in numeric cron field: {}",
-            field.chars().find(|c| !is_numeric_char(c)).unwrap_or(' ')
-        ));
-    }
-
+) -> Result<HashSet<u8>, AstrolabeError> {
     let mut values = HashSet::new();
+    let mut part_position = position;
 
     for part in field.split(',') {
+        let error = |message: String| create_cron_error(name, message, part_position, part);
+
+        if cron_type == &CronPartType::Numeric && !is_numeric_part(part) {
+            return Err(error(format!(
+                "Invalid character in numeric cron field: {}",
+                part.chars().find(|c| !is_numeric_char(c)).unwrap_or(' ')
+            )));
+        }
+
         if part == "*" {
             values.extend(min..=max);
         } else if let Some(step) = part.strip_prefix("*/") {
             let step: u8 = step
                 .parse()
-                .map_err(|_| format!("Can't parse step value to u8: {}", step))?;
+                .map_err(|_| error(format!("Can't parse step value to u8: {}", step)))?;
             if step == 0 {
-                return Err("Step value can't be 0".to_string());
+                return Err(error("Step value can't be 0".to_string()));
             }
             values.extend((min..=max).step_by(step as usize));
-        } else if part.contains('-') {
-            let mut range_parts = part.split('-');
-            let start = range_parts.next().unwrap_or_default();
-            if start.is_empty() {
-                return Err("Can't find start number of range".to_string());
-            }
-            let start = parse_value(start, cron_type)?;
-            let end = range_parts.next().unwrap_or_default();
-            if end.is_empty() {
-                return Err("Can't find end number of range".to_string());
-            }
-            let end = parse_value(end, cron_type)?;
+        } else if let Some((start, end)) = part.split_once('-') {
+            let end_position = part_position + start.len() + 1;
+            let start = parse_value(start, part_position, name, min, max, cron_type)?;
+            let end = parse_value(end, end_position, name, min, max, cron_type)?;
 
             if start > end {
-                return Err(
-                  format!("The start number of a range must be greater than or equal than the end value: {}>={}", start, end)
-              );
-            }
-
-            if start < min || end > max {
-                return Err(format!(
-                    "Only numbers between {} and {} are allowed",
-                    min, max
-                ));
+                return Err(error(format!(
+                    "The start number of a range must be greater than or equal than the end value: {}>={}",
+                    start, end
+                )));
             }
 
             values.extend(start..=end);
         } else {
-            let value = parse_value(part, cron_type)?;
+            values.insert(parse_value(part, part_position, name, min, max, cron_type)?);
+        }
 
-            if value < min || value > max {
-                return Err(format!(
-                    "Only numbers between {} and {} are allowed",
-                    min, max
-                ));
-            }
+        part_position += part.len() + 1;
+    }
 
-            values.insert(value);
+    Ok(values)
+}
+
+fn parse_year_part(field: &str, position: usize) -> Result<Option<Vec<YearRange>>, AstrolabeError> {
+    if field == "*" {
+        return Ok(None);
+    }
+
+    let max = MAX_DATE.0 as u32;
+    let mut years = Vec::new();
+    let mut part_position = position;
+
+    for part in field.split(',') {
+        let error = |message: String| create_cron_error("year", message, part_position, part);
+
+        if !is_numeric_part(part) {
+            return Err(error(format!(
+                "Invalid character in numeric cron field: {}",
+                part.chars().find(|c| !is_numeric_char(c)).unwrap_or(' ')
+            )));
         }
+
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .map_err(|_| error(format!("Can't parse step value to u32: {}", step)))?;
+                if step == 0 {
+                    return Err(error("Step value can't be 0".to_string()));
+                }
+                (range, Some(step))
+            }
+            None => (part, None),
+        };
+
+        let (start, end) = if range == "*" {
+            (1, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            let end_position = part_position + start.len() + 1;
+            let start = parse_year(start, part_position)?;
+            let end = parse_year(end, end_position)?;
+            if start > end {
+                return Err(error(format!(
+                    "The start number of a range must be greater than or equal than the end value: {}>={}",
+                    start, end
+                )));
+            }
+            (start, end)
+        } else {
+            let start = parse_year(range, part_position)?;
+            // A start value with a step value matches every nth year from the start value
+            (start, if step.is_some() { max } else { start })
+        };
+
+        years.push(YearRange {
+            start,
+            end,
+            step: step.unwrap_or(1),
+        });
+
+        part_position += part.len() + 1;
     }
 
-    Ok(values)
+    Ok(Some(years))
 }
 
-fn parse_value(value: &str, cron_type: &CronPartType) -> Result<u8, String> {
-    Ok(match cron_type {
+fn parse_year(value: &str, position: usize) -> Result<u32, AstrolabeError> {
+    if value.is_empty() {
+        return Err(create_cron_error(
+            "year",
+            "Missing value".to_string(),
+            position,
+            value,
+        ));
+    }
+
+    let year = value.parse::<u32>().map_err(|_| {
+        create_cron_error(
+            "year",
+            format!("Can't parse value to u32: {}", value),
+            position,
+            value,
+        )
+    })?;
+
+    if year < 1 || year > MAX_DATE.0 as u32 {
+        return Err(create_simple_oor(
+            "year",
+            1,
+            MAX_DATE.0 as i128,
+            year as i128,
+        ));
+    }
+
+    Ok(year)
+}
+
+fn parse_value(
+    value: &str,
+    position: usize,
+    name: &'static str,
+    min: u8,
+    max: u8,
+    cron_type: &CronPartType,
+) -> Result<u8, AstrolabeError> {
+    let error = |message: String| create_cron_error(name, message, position, value);
+
+    if value.is_empty() {
+        return Err(error("Missing value".to_string()));
+    }
+
+    let number = match cron_type {
         CronPartType::Month if !is_numeric_part(value) => {
-            let value = value.to_lowercase();
-            Month::from_str(&value)? as u8 + 1
+            Month::from_str(value).map_err(|_| {
+                error(format!(
+                    "Invalid month name: {}, allowed are Jan, Feb, Mar, Apr, May, Jun, Jul, Aug, Sep, Oct, Nov and Dec",
+                    value
+                ))
+            })? as u32
+                + 1
         }
-        CronPartType::DayOfWeek if !is_numeric_part(value) => {
-            let value = value.to_lowercase();
-            DayOfWeek::from_str(&value)? as u8
+        CronPartType::DayOfWeek | CronPartType::QuartzDayOfWeek if !is_numeric_part(value) => {
+            let day = DayOfWeek::from_str(value).map_err(|_| {
+                error(format!(
+                    "Invalid day of week name: {}, allowed are Sun, Mon, Tue, Wed, Thu, Fri and Sat",
+                    value
+                ))
+            })? as u32;
+            if cron_type == &CronPartType::QuartzDayOfWeek {
+                day + 1
+            } else {
+                day
+            }
         }
-        CronPartType::DayOfWeek if value == "7" => 0,
         _ => value
-            .parse::<u8>()
-            .map_err(|_| format!("Can't parse value to u8: {}", value))?,
-    })
+            .parse::<u32>()
+            .map_err(|_| error(format!("Can't parse value to u32: {}", value)))?,
+    };
+
+    if number < min as u32 || number > max as u32 {
+        return Err(create_simple_oor(
+            name,
+            min as i128,
+            max as i128,
+            number as i128,
+        ));
+    }
+
+    Ok(number as u8)
+}
+
+/// Creates an [`InvalidFormat`](AstrolabeError::InvalidFormat) error for the given cron field and token
+fn create_cron_error(
+    name: &'static str,
+    message: String,
+    position: usize,
+    token: &str,
+) -> AstrolabeError {
+    create_field_invalid_format(
+        format!("Failed parsing {} field: {}", name, message),
+        name,
+        position,
+        token,
+    )
 }
 
 fn is_numeric_part(part: &str) -> bool {
@@ -382,7 +1015,8 @@ fn is_numeric_char(char: &char) -> bool {
 
 #[cfg(test)]
 mod cron_tests {
-    use crate::{CronSchedule, DateTime};
+    use crate::{CronSchedule, DateTime, TimeUtilities};
+    use std::time::Duration;
 
     #[test]
     fn iterator() {
@@ -482,11 +1116,215 @@ mod cron_tests {
     }
 
     #[test]
-    #[should_panic]
+    fn dom_dow() {
+        let now = DateTime::from_ymdhms(2022, 1, 1, 0, 0, 0).unwrap();
+
+        // The 13th of January 2022 is a Thursday
+        let expected = vec![
+            "2022/01/07 00:00:00",
+            "2022/01/13 00:00:00",
+            "2022/01/14 00:00:00",
+            "2022/01/21 00:00:00",
+            "2022/01/28 00:00:00",
+            "2022/02/04 00:00:00",
+        ];
+        cron_next("0 0 13 * 5", expected, now);
+
+        let expected = vec![
+            "2022/05/13 00:00:00",
+            "2023/01/13 00:00:00",
+            "2023/10/13 00:00:00",
+        ];
+        let mut schedule = CronSchedule::parse("0 0 13 * 5", Some(now))
+            .unwrap()
+            .with_dom_dow_and()
+            .unwrap();
+        for expected in expected {
+            let next = schedule.next().unwrap();
+            assert_eq!(expected, next.format("yyyy/MM/dd HH:mm:ss"));
+        }
+
+        // Fields starting with `*` are not restricted, even with a step value
+        let expected = vec![
+            "2022/01/07 00:00:00",
+            "2022/01/21 00:00:00",
+            "2022/02/11 00:00:00",
+        ];
+        cron_next("0 0 */2 * 5", expected, now);
+        let expected = vec!["2022/01/01 00:01:00", "2022/01/01 00:02:00"];
+        cron_next("* * 1-31 * 5", expected, now);
+        let expected = vec!["2022/01/07 00:00:00", "2022/01/14 00:00:00"];
+        cron_next("0 0 ? * 5", expected, now);
+    }
+
+    #[test]
     // Test if iterator returns none at overflow
     fn overflow() {
         let now = DateTime::from_ymdhms(5_879_611, 7, 12, 23, 59, 0).unwrap();
-        CronSchedule::parse("* * * * *", Some(now)).unwrap().next();
+        assert!(CronSchedule::parse("* * * * *", Some(now))
+            .unwrap()
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn jitter() {
+        let now = DateTime::from_ymdhms(2021, 12, 31, 23, 58, 0).unwrap();
+        let max_jitter = Duration::from_secs(90);
+        let schedule = |seed| {
+            CronSchedule::parse("*/5 * * * *", Some(now))
+                .unwrap()
+                .with_jitter(max_jitter, seed)
+                .unwrap()
+        };
+
+        let plain: Vec<DateTime> = CronSchedule::parse("*/5 * * * *", Some(now))
+            .unwrap()
+            .take(50)
+            .collect();
+        let jittered: Vec<DateTime> = schedule(42).take(50).collect();
+
+        // Deterministic
+        assert_eq!(jittered, schedule(42).take(50).collect::<Vec<_>>());
+        assert_eq!(
+            "2022/01/01 00:00:08",
+            jittered[0].format("yyyy/MM/dd HH:mm:ss")
+        );
+        assert_eq!(141_490_439, jittered[0].nano());
+
+        // Within bounds
+        for (scheduled, jittered) in plain.iter().zip(jittered.iter()) {
+            assert!(jittered >= scheduled);
+            assert!(jittered.duration_between(scheduled) < max_jitter);
+        }
+        assert!(plain.iter().zip(jittered.iter()).any(|(a, b)| a != b));
+
+        // Different seeds
+        assert_ne!(jittered, schedule(43).take(50).collect::<Vec<_>>());
+
+        // Zero jitter
+        let zero: Vec<DateTime> = CronSchedule::parse("*/5 * * * *", Some(now))
+            .unwrap()
+            .with_jitter(Duration::ZERO, 42)
+            .unwrap()
+            .take(50)
+            .collect();
+        assert_eq!(plain, zero);
+
+        // Matches only the second of the jittered instants
+        let schedule = schedule(42);
+        for (scheduled, jittered) in plain.iter().zip(jittered.iter()) {
+            assert!(schedule.matches(jittered));
+            assert!(schedule.matches(&jittered.clear_until_milli()));
+            assert_eq!(
+                *scheduled == jittered.clear_until_milli(),
+                schedule.matches(scheduled)
+            );
+            assert!(!schedule.matches(&jittered.add_seconds(1)));
+            assert!(!schedule.matches(&jittered.sub_seconds(1)));
+        }
+
+        // Jittered occurrences of already scheduled instants are still upcoming
+        let next = jittered[0].sub_nanos(1);
+        let mut schedule = CronSchedule::parse("*/5 * * * *", Some(next))
+            .unwrap()
+            .with_jitter(max_jitter, 42)
+            .unwrap();
+        assert_eq!(jittered[0], schedule.next().unwrap());
+        assert_eq!(jittered[1], schedule.next().unwrap());
+    }
+
+    #[test]
+    fn quartz() {
+        let now = DateTime::from_ymdhms(2022, 1, 1, 0, 0, 0).unwrap();
+
+        // Seconds
+        let expected = vec![
+            "2022/01/01 00:00:15",
+            "2022/01/01 00:00:30",
+            "2022/01/01 00:00:45",
+            "2022/01/01 00:01:00",
+        ];
+        cron_next("*/15 * * * * ?", expected, now);
+        let expected = vec!["2022/01/01 12:00:00", "2022/01/02 12:00:00"];
+        cron_next("0 0 12 * * ?", expected, now);
+
+        // Day of week starts with Sunday as 1
+        let expected = vec![
+            "2022/01/02 00:00:00",
+            "2022/01/08 00:00:00",
+            "2022/01/09 00:00:00",
+        ];
+        cron_next("0 0 0 ? * 1,7", expected.clone(), now);
+        cron_next("0 0 0 ? * sun,sat", expected, now);
+
+        // `?` is an alias of `*`
+        let expected = vec!["2022/01/03 00:00:00", "2022/01/10 00:00:00"];
+        cron_next("0 0 ? * mon", expected, now);
+
+        // Matches seconds
+        let schedule = CronSchedule::parse("30 * * * * ?", None).unwrap();
+        assert!(schedule.matches(&DateTime::from_ymdhms(2022, 1, 1, 0, 0, 30).unwrap()));
+        assert!(!schedule.matches(&DateTime::from_ymdhms(2022, 1, 1, 0, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn years() {
+        let now = DateTime::from_ymdhms(2022, 1, 1, 0, 0, 0).unwrap();
+
+        let expected = vec![
+            "2025/01/01 12:00:00",
+            "2026/01/01 12:00:00",
+            "2027/01/01 12:00:00",
+            "2028/01/01 12:00:00",
+            "2029/01/01 12:00:00",
+            "2030/01/01 12:00:00",
+        ];
+        let mut schedule = CronSchedule::parse("0 0 12 1 1 ? 2025-2030", Some(now)).unwrap();
+        for expected in expected {
+            let next = schedule.next().unwrap();
+            assert_eq!(expected, next.format("yyyy/MM/dd HH:mm:ss"));
+        }
+        assert!(schedule.next().is_none());
+        assert!(schedule.next().is_none());
+
+        // Lists and steps
+        let expected = vec![
+            "2023/01/01 00:00:00",
+            "2024/01/01 00:00:00",
+            "2027/01/01 00:00:00",
+            "2030/01/01 00:00:00",
+        ];
+        cron_next("0 0 0 1 1 ? 2021,2023,2024-2030/3", expected, now);
+        let expected = vec![
+            "2024/02/29 00:00:00",
+            "2028/02/29 00:00:00",
+            "2032/02/29 00:00:00",
+        ];
+        cron_next("0 0 0 29 2 ? 2024/4", expected, now);
+        let expected = vec!["2023/01/01 00:00:00", "2025/01/01 00:00:00"];
+        cron_next("0 0 0 1 1 ? */2", expected, now);
+
+        // Entirely in the past
+        let schedule = CronSchedule::parse("0 0 12 1 1 ? 2020", Some(now)).unwrap();
+        assert_eq!(0, schedule.count());
+        let schedule = CronSchedule::parse("0 0 12 1 1 ? 2000-2021", Some(now)).unwrap();
+        assert_eq!(0, schedule.count());
+
+        // Ends at the maximum date
+        let now = DateTime::from_ymdhms(5_879_611, 7, 12, 23, 59, 58).unwrap();
+        let schedule = CronSchedule::parse("* * * * * ?", Some(now)).unwrap();
+        assert_eq!(1, schedule.count());
+
+        // No matching day in the remaining years
+        let schedule = CronSchedule::parse("0 0 0 29 2 ? 2025-2027", Some(now)).unwrap();
+        assert_eq!(0, schedule.count());
+
+        // Matches years
+        let schedule = CronSchedule::parse("0 0 0 1 1 ? 2023", None).unwrap();
+        assert!(schedule.matches(&DateTime::from_ymd(2023, 1, 1).unwrap()));
+        assert!(!schedule.matches(&DateTime::from_ymd(2022, 1, 1).unwrap()));
+        assert!(!schedule.matches(&DateTime::from_ymd(-2023, 1, 1).unwrap()));
     }
 
     fn cron_next(cron: &str, expected: Vec<&str>, now: DateTime) {
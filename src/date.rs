@@ -1,27 +1,45 @@
 use crate::{
-    errors::{out_of_range::create_simple_oor, AstrolabeError},
+    errors::{
+        invalid_format::create_invalid_format,
+        out_of_range::{create_custom_oor, create_simple_oor},
+        AstrolabeError,
+    },
     util::{
-        constants::{DAYS_TO_1970, DAYS_TO_1970_I64, SECS_PER_DAY_U64},
+        constants::{AUTO_DATE_FORMATS, DAYS_TO_1970_I64, JULIAN_DAY_OFFSET, SECS_PER_DAY_U64},
         date::{
+            business::{add_business_days, business_days_between, is_weekend, sub_business_days},
             convert::{
-                date_to_days, days_to_date, days_to_doy, days_to_wday, months_between,
-                year_doy_to_days, years_between,
+                date_to_days, date_to_days_unchecked, days_to_date, days_to_doy, days_to_iso_wday,
+                days_to_wday, days_to_week_of_month, lenient_date_to_days, months_between,
+                weekdays_in_month, year_doy_to_days, years_between,
             },
             manipulate::{
-                add_days, add_months, add_years, set_day, set_day_of_year, set_month, set_year,
-                sub_days, sub_months, sub_years,
+                add_days, add_months, add_months_with, add_quarters, add_years, add_years_with,
+                days_since_weekday, days_until_weekday, first_day_of_quarter, last_day_of_quarter,
+                set_day, set_day_of_year, set_month, set_year, sub_days, sub_months,
+                sub_months_with, sub_quarters, sub_years, sub_years_with,
             },
+            validate::is_valid_date,
+        },
+        format::{
+            check_format_symbols, format_date_part, format_year_of_era, has_era_symbol,
+            DATE_SYMBOLS,
         },
-        format::format_date_part,
-        parse::{parse_date_part, parse_format_string, ParseUnit, ParsedDate},
+        offset::{add_offset_to_dn, add_offset_to_nanos},
+        parse::{
+            parse_date_part, parse_digits, parse_fixed_width, parse_format_string, FormatPart,
+            ParseUnit, ParsedDate,
+        },
+        time::convert::now_days_nanos,
     },
-    DateTime, DateUtilities,
+    Bounds, DateRange, DateTime, DateUnit, DateUtilities, Era, HolidayCalendar, Locale,
+    MonthOverflow, Offset, OffsetUtilities, ParseOptions, Time, Unit, WeekNumbering, Weekday,
 };
 use std::{
     fmt::Display,
     ops::{Add, AddAssign, Sub, SubAssign},
     str::FromStr,
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    time::Duration,
 };
 
 /// Date in the proleptic Gregorian calendar.
@@ -29,197 +47,1135 @@ use std::{
 /// See the [`DateUtilities`](#impl-DateUtilities-for-Date) implementation for get, set and manipulation methods.
 ///
 /// Range: `30. June -5879611`..=`12. July 5879611`. Please note that year 0 does not exist. After year -1 follows year 1.
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Date {
     pub(crate) days: i32,
 }
 
-impl Date {
-    /// Creates a new [`Date`] instance with [`SystemTime::now()`].
+impl Date {
+    /// Creates a new [`Date`] instance with [`SystemTime::now()`](std::time::SystemTime::now).
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, DateUtilities};
+    /// let date = Date::now();
+    /// assert!(2021 < date.year());
+    /// ```
+    pub fn now() -> Self {
+        let days = now_days_nanos().0;
+        Self { days }
+    }
+
+    /// Creates a new [`Date`] instance with the current date in UTC. Same as [`Date::now`].
+    ///
+    /// Use [`Date::today_local`] for the current date in the local timezone, which differs from the UTC date around midnight.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, DateUtilities};
+    /// let date = Date::today();
+    /// assert!(2021 < date.year());
+    /// ```
+    pub fn today() -> Self {
+        Self::now()
+    }
+
+    /// Creates a new [`Date`] instance with the current date in the local timezone ([`Offset::Local`](crate::Offset::Local)).
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, DateTime, DateUtilities};
+    /// let date = Date::today_local();
+    /// assert!(2021 < date.year());
+    /// ```
+    pub fn today_local() -> Self {
+        Self::local_date(&DateTime::now(), Offset::Local.resolve())
+    }
+
+    /// Returns `true` if the date is the current date in UTC ([`Date::today`]).
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, DateUtilities};
+    /// assert!(Date::today().is_today());
+    /// assert!(!Date::today().add_days(1).is_today());
+    /// ```
+    pub fn is_today(&self) -> bool {
+        self.is_today_at(&DateTime::now())
+    }
+
+    /// Returns `true` if the date is the current date in the local timezone ([`Date::today_local`]).
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, DateUtilities};
+    /// assert!(Date::today_local().is_today_local());
+    /// assert!(!Date::today_local().sub_days(1).is_today_local());
+    /// ```
+    pub fn is_today_local(&self) -> bool {
+        self.is_today_at(&DateTime::now_local())
+    }
+
+    /// Returns `true` if the date is the local date of `now` in its offset. Use this with a single snapshot of the current time to get consistent results across several checks.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, DateTime, Offset, OffsetUtilities};
+    /// let now = DateTime::from_ymdhms(2022, 5, 2, 23, 30, 0).unwrap();
+    /// assert!(Date::from_ymd(2022, 5, 2).unwrap().is_today_at(&now));
+    ///
+    /// let now = now.set_offset(Offset::from_hms(2, 0, 0).unwrap());
+    /// assert!(Date::from_ymd(2022, 5, 3).unwrap().is_today_at(&now));
+    /// ```
+    pub fn is_today_at(&self, now: &DateTime) -> bool {
+        *self == now.date()
+    }
+
+    /// Returns the local date of the given instant in the given offset (in seconds)
+    fn local_date(date_time: &DateTime, offset: i32) -> Self {
+        Self {
+            days: add_offset_to_dn(date_time.days, date_time.nanoseconds, offset).0,
+        }
+    }
+
+    /// Creates a new [`Date`] instance from year, month and day (day of month).
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided values are invalid.
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::from_ymd(2022, 05, 02).unwrap();
+    /// assert_eq!("2022/05/02", date.format("yyyy/MM/dd"));
+    /// ```
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Result<Self, AstrolabeError> {
+        let days = date_to_days(year, month, day)?;
+
+        Ok(Self { days })
+    }
+
+    /// Creates a new [`Date`] instance from year and day of year (`1-365` or `1-366` in leap years). The ordinal date can be formatted and parsed as an ISO 8601 string (`yyyy-DDD`) with [`Date::format_ordinal`] and [`Date::parse_ordinal`].
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided values are invalid, e.g. day `366` in a year which is not a leap year.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, DateUtilities};
+    /// let date = Date::from_ordinal(2022, 122).unwrap();
+    /// assert_eq!("2022/05/02", date.format("yyyy/MM/dd"));
+    /// assert_eq!(122, date.ordinal());
+    /// assert!(Date::from_ordinal(2022, 366).is_err());
+    /// ```
+    pub fn from_ordinal(year: i32, day_of_year: u32) -> Result<Self, AstrolabeError> {
+        let days = year_doy_to_days(year, day_of_year, false)?;
+
+        Ok(Self { days })
+    }
+
+    /// Creates a new [`Date`] instance from year, month and day (day of month) without validating them. Intended for literals in const contexts, like lookup tables of known dates.
+    ///
+    /// The values are only checked with debug assertions, which panic if the date is invalid. Without debug assertions, invalid values don't cause undefined behavior but produce a logically invalid [`Date`].
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// const NEW_YEAR: Date = Date::from_ymd_unchecked(2023, 1, 1);
+    /// assert_eq!(Date::from_ymd(2023, 1, 1).unwrap(), NEW_YEAR);
+    /// ```
+    pub const fn from_ymd_unchecked(year: i32, month: u32, day: u32) -> Self {
+        debug_assert!(is_valid_date(year, month, day), "Invalid date");
+
+        Self {
+            days: date_to_days_unchecked(year, month, day),
+        }
+    }
+
+    /// Creates a new [`Date`] instance from year, month and day (day of month), normalizing out of range values like JavaScript's `Date`. Months outside of `1-12` are carried into the year and days outside of the month into the month, e.g. month `13` is January of the next year and `2022-02-30` is `2022-03-02`. Month `0` and day `0` borrow downward, so they are December of the previous year and the last day of the previous month.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the year is `0` or the normalized date is out of range. Use [`Date::from_ymd`] to reject out of range values instead.
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// assert_eq!("2023/01/01", Date::from_ymd_lenient(2022, 13, 1).unwrap().format("yyyy/MM/dd"));
+    /// assert_eq!("2022/03/02", Date::from_ymd_lenient(2022, 2, 30).unwrap().format("yyyy/MM/dd"));
+    /// assert_eq!("2021/12/31", Date::from_ymd_lenient(2022, 1, 0).unwrap().format("yyyy/MM/dd"));
+    /// ```
+    pub fn from_ymd_lenient(year: i32, month: i64, day: i64) -> Result<Self, AstrolabeError> {
+        let days = lenient_date_to_days(year, month, day)?;
+        let days = i32::try_from(days).map_err(|_| {
+            create_custom_oor(format!(
+                "Normalized date of {}-{}-{} is out of range.",
+                year, month, day
+            ))
+        })?;
+
+        Ok(Self { days })
+    }
+
+    /// Creates a new [`Date`] instance from the `n`th occurrence of a weekday in the given month, e.g. the second Tuesday.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided values are invalid or if the month has no `n`th occurrence of the weekday.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, Weekday};
+    /// let date = Date::nth_weekday_of_month(2022, 5, Weekday::Tuesday, 2).unwrap();
+    /// assert_eq!("2022/05/10", date.format("yyyy/MM/dd"));
+    ///
+    /// // May 2022 only has four Wednesdays
+    /// assert!(Date::nth_weekday_of_month(2022, 5, Weekday::Wednesday, 5).is_err());
+    /// ```
+    pub fn nth_weekday_of_month(
+        year: i32,
+        month: u32,
+        weekday: Weekday,
+        n: u8,
+    ) -> Result<Self, AstrolabeError> {
+        date_to_days(year, month, 1)?;
+        let weekdays = weekdays_in_month(year, month, weekday.into());
+
+        match weekdays.get(usize::from(n).wrapping_sub(1)) {
+            Some(day) => Self::from_ymd(year, month, *day),
+            None => Err(create_simple_oor("n", 1, weekdays.len() as i128, n as i128)),
+        }
+    }
+
+    /// Creates a new [`Date`] instance from the last occurrence of a weekday in the given month.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided values are invalid.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, Weekday};
+    /// let date = Date::last_weekday_of_month(2022, 5, Weekday::Monday).unwrap();
+    /// assert_eq!("2022/05/30", date.format("yyyy/MM/dd"));
+    /// ```
+    pub fn last_weekday_of_month(
+        year: i32,
+        month: u32,
+        weekday: Weekday,
+    ) -> Result<Self, AstrolabeError> {
+        date_to_days(year, month, 1)?;
+        let weekdays = weekdays_in_month(year, month, weekday.into());
+
+        // Using unwrap because every month contains each weekday at least four times
+        Self::from_ymd(year, month, *weekdays.last().unwrap())
+    }
+
+    /// Creates a new [`Date`] instance from a Julian day number. Julian day `2451545` is 1. January 2000.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided Julian day number would result in an out of range date.
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::from_julian_day(2451545).unwrap();
+    /// assert_eq!("2000/01/01", date.format("yyyy/MM/dd"));
+    /// ```
+    pub fn from_julian_day(julian_day: i64) -> Result<Self, AstrolabeError> {
+        let days = (julian_day as i128 - JULIAN_DAY_OFFSET as i128)
+            .try_into()
+            .map_err(|_| {
+                create_simple_oor(
+                    "julian_day",
+                    i32::MIN as i128 + JULIAN_DAY_OFFSET as i128,
+                    i32::MAX as i128 + JULIAN_DAY_OFFSET as i128,
+                    julian_day as i128,
+                )
+            })?;
+
+        Ok(Self { days })
+    }
+
+    /// Returns the Julian day number of the date. Julian day `2451545` is 1. January 2000.
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::from_ymd(2000, 1, 1).unwrap();
+    /// assert_eq!(2451545, date.julian_day());
+    /// ```
+    pub fn julian_day(&self) -> i64 {
+        self.days as i64 + JULIAN_DAY_OFFSET
+    }
+
+    /// Returns the date.
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::from_ymd(2022, 05, 02).unwrap();
+    /// let (year, month, day) = date.as_ymd();
+    /// assert_eq!(2022, year);
+    /// assert_eq!(5, month);
+    /// assert_eq!(2, day);
+    /// ```
+    pub fn as_ymd(&self) -> (i32, u32, u32) {
+        days_to_date(self.days)
+    }
+
+    /// Creates a new [`Date`] instance from its raw representation, the number of days since `0001-01-01`.
+    ///
+    /// Day `0` is `0001-01-01`. Negative days count backwards from there, skipping year `0`, so day `-1` is `-0001-12-31`. Every [`i32`] is a valid date. `i32::MIN` is `-5879611-06-23` and `i32::MAX` is `5879611-07-12`.
+    ///
+    /// The day number increases with the date, so together with [`Date::days`], this allows using dates as integer keys, e.g. for databases or sorting, and implementing custom serialization formats.
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::from_days(738_276);
+    /// assert_eq!("2022/05/02", date.format("yyyy/MM/dd"));
+    /// assert_eq!(0, Date::from_ymd(1, 1, 1).unwrap().days());
+    /// assert_eq!("-0001/12/31", Date::from_days(-1).format("yyyy/MM/dd"));
+    /// ```
+    pub fn from_days(days: i32) -> Self {
+        Self { days }
+    }
+
+    /// Returns the raw representation of the date, the number of days since `0001-01-01`. Negative for dates before `0001-01-01`. See [`Date::from_days`].
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::from_ymd(2022, 5, 2).unwrap();
+    /// assert_eq!(738_276, date.days());
+    /// assert_eq!(-366, Date::from_ymd(-1, 1, 1).unwrap().days());
+    /// ```
+    pub fn days(&self) -> i32 {
+        self.days
+    }
+
+    /// Returns the raw representation of the date, the number of days since `0001-01-01`. Same as [`Date::days`].
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::from_ymd(2022, 5, 2).unwrap();
+    /// assert_eq!(738_276, date.as_days());
+    /// ```
+    pub fn as_days(&self) -> i32 {
+        self.days
+    }
+
+    /// Returns the following day, or `None` if this is the last representable date (`5879611-07-12`).
+    ///
+    /// Unlike [`add_days`](DateUtilities::add_days), this doesn't panic and only increments the day number, which makes it cheap to step through ranges of dates.
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::from_ymd(2022, 12, 31).unwrap();
+    /// assert_eq!("2023/01/01", date.next_day().unwrap().to_string());
+    /// assert_eq!(None, Date::from_days(i32::MAX).next_day());
+    /// ```
+    pub fn next_day(&self) -> Option<Self> {
+        self.days.checked_add(1).map(|days| Self { days })
+    }
+
+    /// Returns the previous day, or `None` if this is the first representable date (`-5879611-06-23`).
+    ///
+    /// Unlike [`sub_days`](DateUtilities::sub_days), this doesn't panic and only decrements the day number, which makes it cheap to step through ranges of dates.
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::from_ymd(1, 1, 1).unwrap();
+    /// assert_eq!("-0001/12/31", date.previous_day().unwrap().to_string());
+    /// assert_eq!(None, Date::from_days(i32::MIN).previous_day());
+    /// ```
+    pub fn previous_day(&self) -> Option<Self> {
+        self.days.checked_sub(1).map(|days| Self { days })
+    }
+
+    /// Combines the date with the time of day to a [`DateTime`] with the offset of the [`Time`]. Same as `date + time`.
+    ///
+    /// The date is the local date in the offset of the time, so [`DateTime::date`] and [`DateTime::time`] return the date and time again.
+    ///
+    /// Panics if the resulting instant would be out of range. This can only happen for dates within a day of `-5879611-06-23` or `5879611-07-12`.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, Offset, OffsetUtilities, Time};
+    /// let date = Date::from_ymd(2022, 5, 2).unwrap();
+    /// let time = Time::from_hms(12, 32, 1)
+    ///     .unwrap()
+    ///     .as_offset(Offset::from_hms(2, 0, 0).unwrap());
+    /// let date_time = date.at(time);
+    /// assert_eq!("2022-05-02 12:32:01 +02:00", date_time.format("yyyy-MM-dd HH:mm:ss xxx"));
+    /// assert_eq!(date, date_time.date());
+    /// assert_eq!(time, date_time.time());
+    /// ```
+    pub fn at(&self, time: Time) -> DateTime {
+        *self + time
+    }
+
+    /// Parses a string with a given format and creates a new [`Date`] instance from it. See [`Date::format`] for a list of available symbols.
+    ///
+    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the given string could not be parsed with the given format, or an [`OutOfRange`](AstrolabeError::OutOfRange) error if a parsed value is out of range (E.g. month `13`).
+    ///
+    /// A parsed BC era (`G`) turns a positive year negative, e.g. `BC 2022` is parsed as the year `-2022`. `parse_checked` rejects a signed year together with an era.
+    ///
+    /// If a day of year (`D`) is parsed, it takes precedence over a parsed month and day of month, no matter in which order they appear in the format. [`Date::parse_checked`](Date::parse_checked) returns an error instead if they don't match. If the parsed fields don't form a valid date, e.g. day `31` in February, the returned [`OutOfRange`](AstrolabeError::OutOfRange) error contains the [`span`](crate::errors::OutOfRange::span) of the invalid value in the parsed string.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, DateUtilities};
+    /// let date = Date::parse("2022-05-02", "yyyy-MM-dd").unwrap();
+    /// assert_eq!("2022/05/02", date.format("yyyy/MM/dd"));
+    /// let date = Date::parse("BC 2022-05-02", "G yyyy-MM-dd").unwrap();
+    /// assert_eq!(-2022, date.year());
+    /// ```
+    #[doc(alias = "strptime")]
+    pub fn parse(string: &str, format: &str) -> Result<Self, AstrolabeError> {
+        Self::parse_with(string, format, &ParseOptions::default())
+    }
+
+    /// Parses a string with a given format like [`Date::parse`], using the names of the given [`Locale`].
+    ///
+    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the given string could not be parsed with the given format and locale.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, Locale};
+    /// let date = Date::parse_localized("2. März 2022", "d. MMMM yyyy", &Locale::GERMAN).unwrap();
+    /// assert_eq!("2022/03/02", date.format("yyyy/MM/dd"));
+    /// ```
+    pub fn parse_localized(
+        string: &str,
+        format: &str,
+        locale: &Locale,
+    ) -> Result<Self, AstrolabeError> {
+        Self::parse_with(
+            string,
+            format,
+            &ParseOptions {
+                locale: *locale,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Parses a string with a given format like [`Date::parse`], using custom [`ParseOptions`].
+    ///
+    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the given string could not be parsed with the given format and options.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, EraTokens, ParseOptions};
+    /// let options = ParseOptions {
+    ///     era_tokens: Some(EraTokens::new(&["AD"], &["BC"])),
+    ///     ..Default::default()
+    /// };
+    /// let date = Date::parse_with("2022AD-05-02", "yyyyG-MM-dd", &options).unwrap();
+    /// assert_eq!("2022/05/02", date.format("yyyy/MM/dd"));
+    /// ```
+    pub fn parse_with(
+        string: &str,
+        format: &str,
+        options: &ParseOptions,
+    ) -> Result<Self, AstrolabeError> {
+        Ok(Self::parse_with_remainder(string, format, options)?.0)
+    }
+
+    /// Parses a string with a given format like [`Date::parse`], but returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the weekday or week of year doesn't match the date or the day of year doesn't match the month and day of month. See [`ParseOptions::reject_conflicts`].
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// assert!(Date::parse_checked("Tuesday, 2022-05-02", "eeee, yyyy-MM-dd").is_err());
+    /// let date = Date::parse_checked("Monday, 2022-05-02", "eeee, yyyy-MM-dd").unwrap();
+    /// assert_eq!("2022/05/02", date.format("yyyy/MM/dd"));
+    /// ```
+    pub fn parse_checked(string: &str, format: &str) -> Result<Self, AstrolabeError> {
+        Self::parse_with(
+            string,
+            format,
+            &ParseOptions {
+                reject_conflicts: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Parses a string in one of the commonly used date formats like [`Date::parse`], without specifying a format.
+    ///
+    /// The formats are tried in the following order and the first one which parses the whole string is used:
+    /// - `yyyy-MM-dd` (`2022-05-02`)
+    /// - `dd.MM.yyyy` (`02.05.2022`)
+    /// - `d MMM yy` (`02 May 22`)
+    /// - `d MMM yyyy` (`2 May 2022`)
+    /// - `d MMMM yy` (`02 September 22`)
+    /// - `d MMMM yyyy` (`2 September 2022`)
+    ///
+    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the given string could not be parsed with any of these formats.
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::parse_auto("2 May 2022").unwrap();
+    /// assert_eq!("2022/05/02", date.format("yyyy/MM/dd"));
+    /// ```
+    pub fn parse_auto(string: &str) -> Result<Self, AstrolabeError> {
+        Self::parse_auto_with(string, &ParseOptions::default())
+    }
+
+    /// Parses a string in one of the commonly used date formats like [`Date::parse_auto`], using custom [`ParseOptions`].
+    ///
+    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the given string could not be parsed with any of the formats and the given options.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, ParseOptions, TwoDigitYear};
+    /// let options = ParseOptions {
+    ///     two_digit_year: TwoDigitYear::StartingAt(1950),
+    ///     ..Default::default()
+    /// };
+    /// let date = Date::parse_auto_with("02 May 72", &options).unwrap();
+    /// assert_eq!("1972/05/02", date.format("yyyy/MM/dd"));
+    /// ```
+    pub fn parse_auto_with(string: &str, options: &ParseOptions) -> Result<Self, AstrolabeError> {
+        AUTO_DATE_FORMATS
+            .iter()
+            .find_map(
+                |format| match Self::parse_with_remainder(string, format, options) {
+                    Ok((date, 0)) => Some(date),
+                    _ => None,
+                },
+            )
+            .ok_or_else(|| {
+                create_invalid_format(format!(
+                    "Failed parsing the date '{}' with any of the supported formats.",
+                    string
+                ))
+            })
+    }
+
+    /// Parses a string like [`Date::parse_with`] and additionally returns the length of the unparsed rest of the string.
+    fn parse_with_remainder(
+        string: &str,
+        format: &str,
+        options: &ParseOptions,
+    ) -> Result<(Self, usize), AstrolabeError> {
+        // Subsecond symbols are skipped by their length instead of their digits when parsing a date
+        let fixed_width = if format.contains('n') {
+            None
+        } else {
+            parse_fixed_width(string, format)
+        };
+        match fixed_width {
+            Some(parsed) => Ok((Self { days: parsed.days }, parsed.rest.len())),
+            None => Self::parse_generic(string, format, options),
+        }
+    }
+
+    /// Parses a string like [`Date::parse_with_remainder`] with any format string, used if [`parse_fixed_width`] doesn't apply
+    pub(crate) fn parse_generic(
+        mut string: &str,
+        format: &str,
+        options: &ParseOptions,
+    ) -> Result<(Self, usize), AstrolabeError> {
+        let parts = parse_format_string(format);
+
+        let mut date = ParsedDate::default();
+        let input = string;
+        let length = string.len();
+
+        for part in parts {
+            let part = match part {
+                FormatPart::Symbol(part) => part,
+                // Escaped apostrophes
+                FormatPart::EscapedQuote => {
+                    string = &string[1..];
+                    continue;
+                }
+                // Escaped parts
+                FormatPart::Literal(literal) => {
+                    string = &string[literal.len()..];
+                    continue;
+                }
+            };
+
+            let position = length - string.len();
+            let parsed_part = parse_date_part(part, &mut string, options)
+                .map_err(|e| e.at_position(position, part))?;
+            if let Some(parsed_part) = parsed_part {
+                date.set_span(&parsed_part.unit, position..length - string.len());
+                match parsed_part.unit {
+                    ParseUnit::Era => date.era = Some(Era::from_index(parsed_part.value)),
+                    ParseUnit::Year => date.set_year(parsed_part.value as i32, &input[position..]),
+                    ParseUnit::Quarter => date.quarter = Some(parsed_part.value as u32),
+                    ParseUnit::Month => date.month = Some(parsed_part.value as u32),
+                    ParseUnit::DayOfMonth => date.day_of_month = Some(parsed_part.value as u32),
+                    ParseUnit::Weekday => date.weekday = Some(parsed_part.value as u32),
+                    ParseUnit::WeekOfYear => date.week_of_year = Some(parsed_part.value as u32),
+                    ParseUnit::OrdinalSuffix => date
+                        .check_ordinal_suffix(parsed_part.value)
+                        .map_err(|e| e.at_position(position, part))?,
+                    // Can't be any other variant than `ParseUnit::DayOfYear`
+                    _ => date.day_of_year = Some(parsed_part.value as u32),
+                };
+            };
+        }
+
+        let result = Self { days: date.days()? };
+        if options.reject_conflicts {
+            date.check_conflicts(result.days, options.locale.first_weekday)?;
+        }
+        Ok((result, string.len()))
+    }
+
+    /// Formatting with format strings based on [Unicode Date Field Symbols](https://www.unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table).
+    ///
+    /// Please note that not all symbols are implemented. If you need something that is not implemented, please open an issue on [GitHub](https://github.com/GiyoMoon/astrolabe/issues) describing your need.
+    ///
+    /// # Available Symbols:
+    ///
+    /// | Field Type          | Pattern  | Examples                      | Hint                                     |
+    /// | ------------------- | -------- | ----------------------------- | ---------------------------------------- |
+    /// | era                 | G..GGG   | AD                            |                                          |
+    /// |                     | GGGG     | Anno Domini                   | *                                        |
+    /// |                     | GGGGG    | A                             |                                          |
+    /// | year                | y        | 2, 20, 201, 2017, 20173       |                                          |
+    /// |                     | yy       | 02, 20, 01, 17, 73            |                                          |
+    /// |                     | yyy      | 002, 020, 201, 2017, 20173    |                                          |
+    /// |                     | yyyy     | 0002, 0020, 0201, 2017, 20173 |                                          |
+    /// |                     | yyyyy+   | ...                           | Unlimited length,<br/>padded with zeros. |
+    /// |                     | +y+      | +2017, -34, +002017           | Always signed,<br/>padded with zeros.    |
+    /// | extended year       | u        | -1, 0, 2017                   | Astronomical year                        |
+    /// |                     | uu+      | -01, 00, 2017                 | Padded with zeros                        |
+    /// | quarter             | q        | 2                             | *                                        |
+    /// |                     | qq       | 02                            |                                          |
+    /// |                     | qqq      | Q2                            |                                          |
+    /// |                     | qqqq     | 2nd quarter                   |                                          |
+    /// |                     | qqqqq    | 2                             |                                          |
+    /// | month               | M        | 9, 12                         |                                          |
+    /// |                     | MM       | 09, 12                        |                                          |
+    /// |                     | MMM      | Sep                           |                                          |
+    /// |                     | MMMM     | September                     | *                                        |
+    /// |                     | MMMMM    | S                             |                                          |
+    /// | week                | w        | 8, 27                         | Week of year                             |
+    /// |                     | ww       | 08, 27                        | *                                        |
+    /// |                     | W        | 1, 6                          | Week of month, starting on Sunday        |
+    /// | days                | d        | 1                             | Day of month                             |
+    /// |                     | dd       | 01                            | *                                        |
+    /// |                     | D        | 1, 24, 135                    | Day of year, *                           |
+    /// |                     | DD       | 01, 24, 135                   |                                          |
+    /// |                     | DDD      | 001, 024, 135                 |                                          |
+    /// | ordinal suffix      | o        | st, nd, rd, th                | Of the day of month,<br/>`do` -> 2nd     |
+    /// | modified julian day | g+       | 59701                         | Unlimited length,<br/>padded with zeros. |
+    /// | week day            | e        | 3                             | 1-7, 1 is Sunday, *                      |
+    /// |                     | ee       | 03                            | 1-7, 1 is Sunday                         |
+    /// |                     | eee      | Tue                           |                                          |
+    /// |                     | eeee     | Tuesday                       |                                          |
+    /// |                     | eeeee    | T                             |                                          |
+    /// |                     | eeeeee   | Tu                            |                                          |
+    /// |                     | eeeeeee  | 2                             | 1-7, 1 is Monday                         |
+    /// |                     | eeeeeeee | 02                            | 1-7, 1 is Monday                         |
+    ///
+    /// `*` = Default
+    ///
+    /// The extended year `u` uses astronomical year numbering, which has a year `0`. Astrolabe skips year `0`, so `u` maps `0` to the year `-1` (1 BC), `-1` to `-2` (2 BC) and so on. Positive years are the same for `y` and `u`.
+    ///
+    /// If the sequence is longer than listed in the table, the output will be the same as the default pattern for this unit (marked with `*`).
+    ///
+    /// The numeric weekdays `e` and `ee` and the week of year `w` depend on the [`FirstWeekday`](crate::FirstWeekday) of the locale. By default, `e` and `ee` count from Sunday and `w` is the ISO 8601 week of year, see [`week_of_year`](DateUtilities::week_of_year) with [`WeekNumbering::Iso`]. The first days of January can therefore be formatted as week `52` or `53`.
+    ///
+    /// The ordinal suffix `o` is only valid directly after the day of month (`d` or `dd`), which [`validate_format`](crate::validate_format) and `try_format` check. The suffixes are English regardless of the locale. When parsing, the suffix is optional, but has to match the parsed day if present (`2nd`, not `2st`).
+    ///
+    /// Numeric values are never truncated, the length of the sequence only sets the minimum width. E.g. `DD` formats the day of year `135` as `135`. The only exception is `yy`, which outputs the last two digits of the year and keeps the sign of negative years (`-34`).
+    ///
+    /// If the format string contains the era (`G`), `y` is the year of the era and has no sign, e.g. `BC2022` for the year `-2022` with `Gyyyy`.
+    ///
+    /// A `+` directly before a year sequence outputs the sign of the year even if it is positive, e.g. `+yyyyyy` formats the ISO 8601 expanded year `+002022`. When parsing, a `+` or `-` sign is required and all following digits are part of the year. Except `yy`, all year sequences are parsed with all their digits, so years with more digits than the sequence are parsed completely.
+    ///
+    /// Surround any character with apostrophes (`'`) to escape them.
+    /// If you want escape `'`, write `''`.
+    ///
+    /// Letters which are not a format symbol are output as they are. Use [`Date::try_format`] to get an error instead.
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::from_ymd(2022, 5, 2).unwrap();
+    /// assert_eq!("2022/05/02", date.format("yyyy/MM/dd"));
+    /// // Escape characters
+    /// assert_eq!("2022/MM/dd", date.format("yyyy/'MM/dd'"));
+    /// assert_eq!("2022/'05/02'", date.format("yyyy/''MM/dd''"));
+    /// ```
+    ///
+    #[doc(alias = "strftime")]
+    pub fn format(&self, format: &str) -> String {
+        self.format_localized(format, &Locale::ENGLISH)
+    }
+
+    /// Formats the date like [`Date::format`], but returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the format string contains an unescaped letter which is not a date format symbol, a symbol sequence which is too long or an escaped part which is not closed, like [`validate_format`](crate::validate_format). The error names the problematic part and its byte position.
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::from_ymd(2022, 5, 2).unwrap();
+    /// assert_eq!("2022/05/02", date.try_format("yyyy/MM/dd").unwrap());
+    /// assert!(date.try_format("yyyy/MM/dd HH:mm").is_err());
+    /// ```
+    pub fn try_format(&self, format: &str) -> Result<String, AstrolabeError> {
+        check_format_symbols(format, &[DATE_SYMBOLS])?;
+        Ok(self.format(format))
+    }
+
+    /// Formatting with format strings based on [Unicode Date Field Symbols](https://www.unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table), using the names of the given [`Locale`]. See [`Date::format`] for the available symbols.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, Locale};
+    /// let date = Date::from_ymd(2022, 10, 2).unwrap();
+    /// assert_eq!("So., 2. Okt. 2022", date.format_localized("eee, d. MMM yyyy", &Locale::GERMAN));
+    /// ```
+    pub fn format_localized(&self, format: &str, locale: &Locale) -> String {
+        let year_of_era = has_era_symbol(format);
+        let mut formatted = String::with_capacity(format.len());
+        for part in parse_format_string(format) {
+            match part {
+                FormatPart::Symbol(part) if year_of_era && part.starts_with('y') => {
+                    format_year_of_era(&mut formatted, part, self.days)
+                }
+                FormatPart::Symbol(part) => {
+                    format_date_part(&mut formatted, part, self.days, locale)
+                }
+                // Escaped parts
+                FormatPart::Literal(literal) => formatted.push_str(literal),
+                // Escaped apostrophes
+                FormatPart::EscapedQuote => formatted.push('\''),
+            }
+        }
+        formatted
+    }
+
+    /// Formats the date as an ISO 8601 ordinal date (`yyyy-DDD`).
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::from_ymd(2022, 1, 5).unwrap();
+    /// assert_eq!("2022-005", date.format_ordinal());
+    /// ```
+    pub fn format_ordinal(&self) -> String {
+        self.format("yyyy-DDD")
+    }
+
+    /// Parses an ISO 8601 ordinal date (`yyyy-DDD`) and creates a new [`Date`] instance from it.
+    ///
+    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the given string could not be parsed or an [`OutOfRange`](AstrolabeError::OutOfRange) error if the day of year is invalid.
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::parse_ordinal("2022-122").unwrap();
+    /// assert_eq!("2022/05/02", date.format("yyyy/MM/dd"));
+    /// ```
+    pub fn parse_ordinal(string: &str) -> Result<Self, AstrolabeError> {
+        let date = Self::parse(string, "yyyy-DDD")?;
+        // Rejects trailing characters and day of year values which are not zero padded to three digits
+        if date.format_ordinal() != string {
+            return Err(create_invalid_format(format!(
+                "Failed parsing the ordinal date '{}'. Expected format 'yyyy-DDD'.",
+                string
+            )));
+        }
+        Ok(date)
+    }
+
+    /// Returns the duration between the provided date.
+    pub fn duration_between(&self, compare: &Self) -> Duration {
+        Duration::from_secs(self.days_since(compare).unsigned_abs() * SECS_PER_DAY_U64)
+    }
+
+    /// Returns an iterator over all dates from `start` (inclusive) to `end` (exclusive).
+    ///
+    /// If `end` is before `start`, the iterator counts backwards. The iterator can also be reversed with [`rev`](Iterator::rev).
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let start = Date::from_ymd(2022, 5, 2).unwrap();
+    /// let end = Date::from_ymd(2022, 5, 5).unwrap();
+    ///
+    /// let dates: Vec<String> = Date::range(start, end).map(|date| date.to_string()).collect();
+    /// assert_eq!(vec!["2022/05/02", "2022/05/03", "2022/05/04"], dates);
+    ///
+    /// let dates: Vec<String> = Date::range(end, start).map(|date| date.to_string()).collect();
+    /// assert_eq!(vec!["2022/05/05", "2022/05/04", "2022/05/03"], dates);
+    /// ```
+    pub fn range(start: Self, end: Self) -> DateRange {
+        DateRange::new(start, end)
+    }
+
+    /// Returns `true` if both dates are in the same month of the same year.
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::from_ymd(2022, 5, 2).unwrap();
+    /// assert!(date.same_month_as(&Date::from_ymd(2022, 5, 31).unwrap()));
+    /// assert!(!date.same_month_as(&Date::from_ymd(2021, 5, 2).unwrap()));
+    /// ```
+    pub fn same_month_as(&self, other: &Self) -> bool {
+        let (year, month, _) = days_to_date(self.days);
+        let (other_year, other_month, _) = days_to_date(other.days);
+        year == other_year && month == other_month
+    }
+
+    /// Returns `true` if both dates are in the same year.
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::from_ymd(2022, 5, 2).unwrap();
+    /// assert!(date.same_year_as(&Date::from_ymd(2022, 12, 31).unwrap()));
+    /// assert!(!date.same_year_as(&Date::from_ymd(2023, 1, 1).unwrap()));
+    /// ```
+    pub fn same_year_as(&self, other: &Self) -> bool {
+        days_to_date(self.days).0 == days_to_date(other.days).0
+    }
+
+    /// Returns `true` if the date is between `start` and `end`. Use [`Bounds`] to specify whether `start` and `end` are included.
+    ///
+    /// If `start` is after `end`, no date is between them.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Bounds, Date};
+    /// let start = Date::from_ymd(2022, 5, 2).unwrap();
+    /// let end = Date::from_ymd(2022, 5, 9).unwrap();
+    ///
+    /// assert!(end.is_between(&start, &end, Bounds::Inclusive));
+    /// assert!(!end.is_between(&start, &end, Bounds::Exclusive));
+    /// ```
+    pub fn is_between(&self, start: &Self, end: &Self, bounds: Bounds) -> bool {
+        bounds.after_start(self, start) && bounds.before_end(self, end)
+    }
+
+    /// Returns the date if it is between `start` and `end`, otherwise the closest date which is. Use [`Bounds`] to specify whether `start` and `end` are included. An excluded bound is clamped to the day after `start` or before `end`.
+    ///
+    /// Panics if no date is between `start` and `end`.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Bounds, Date};
+    /// let start = Date::from_ymd(2022, 5, 2).unwrap();
+    /// let end = Date::from_ymd(2022, 5, 9).unwrap();
+    ///
+    /// let date = Date::from_ymd(2022, 4, 1).unwrap();
+    /// assert_eq!("2022/05/02", date.clamp_between(&start, &end, Bounds::Inclusive).to_string());
+    /// assert_eq!("2022/05/03", date.clamp_between(&start, &end, Bounds::Exclusive).to_string());
+    /// ```
+    pub fn clamp_between(&self, start: &Self, end: &Self, bounds: Bounds) -> Self {
+        let lower = start.days as i64 + if bounds.includes_start() { 0 } else { 1 };
+        let upper = end.days as i64 - if bounds.includes_end() { 0 } else { 1 };
+        assert!(lower <= upper, "No date is between start and end");
+
+        Self {
+            days: (self.days as i64).clamp(lower, upper) as i32,
+        }
+    }
+
+    /// Returns the date if it is between `min` and `max`, otherwise `min` or `max`. Same as [`Ord::clamp`].
+    ///
+    /// Panics if `min` is after `max`.
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let min = Date::from_ymd(2022, 5, 2).unwrap();
+    /// let max = Date::from_ymd(2022, 5, 9).unwrap();
+    ///
+    /// assert_eq!(min, Date::from_ymd(2022, 4, 1).unwrap().clamp(min, max));
+    /// assert_eq!(max, Date::from_ymd(2022, 6, 1).unwrap().clamp(min, max));
+    /// ```
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min <= max, "min is after max");
+        self.clamp_between(&min, &max, Bounds::Inclusive)
+    }
+
+    /// Returns the earlier of both dates. Same as [`Ord::min`].
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::from_ymd(2022, 5, 2).unwrap();
+    /// let other = Date::from_ymd(2022, 5, 9).unwrap();
+    /// assert_eq!(date, date.min(other));
+    /// ```
+    pub fn min(self, other: Self) -> Self {
+        Self {
+            days: self.days.min(other.days),
+        }
+    }
+
+    /// Returns the later of both dates. Same as [`Ord::max`].
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// let date = Date::from_ymd(2022, 5, 2).unwrap();
+    /// let other = Date::from_ymd(2022, 5, 9).unwrap();
+    /// assert_eq!(other, date.max(other));
+    /// ```
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            days: self.days.max(other.days),
+        }
+    }
+
+    /// Returns the next date which falls on the given weekday. The next occurrence is always strictly after `self`.
+    ///
+    /// Panics if the resulting date would be out of range.
     ///
     /// ```rust
-    /// # use astrolabe::{Date, DateUtilities};
-    /// let date = Date::now();
-    /// assert!(2021 < date.year());
+    /// # use astrolabe::{Date, Weekday};
+    /// // Monday, 2. May 2022
+    /// let date = Date::from_ymd(2022, 5, 2).unwrap();
+    /// assert_eq!("2022/05/06", date.next_occurrence_of(Weekday::Friday).format("yyyy/MM/dd"));
+    /// assert_eq!("2022/05/09", date.next_occurrence_of(Weekday::Monday).format("yyyy/MM/dd"));
     /// ```
-    pub fn now() -> Self {
-        let days = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs()
-            / SECS_PER_DAY_U64
-            + DAYS_TO_1970;
-        Self { days: days as i32 }
+    pub fn next_occurrence_of(&self, weekday: Weekday) -> Self {
+        self.add_days(days_until_weekday(self.weekday(), weekday, false))
     }
 
-    /// Creates a new [`Date`] instance from year, month and day (day of month).
+    /// Returns the next date which falls on the given weekday, or `self` if it already falls on it.
     ///
-    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided values are invalid.
+    /// Panics if the resulting date would be out of range.
     ///
     /// ```rust
-    /// # use astrolabe::Date;
-    /// let date = Date::from_ymd(2022, 05, 02).unwrap();
-    /// assert_eq!("2022/05/02", date.format("yyyy/MM/dd"));
+    /// # use astrolabe::{Date, Weekday};
+    /// // Monday, 2. May 2022
+    /// let date = Date::from_ymd(2022, 5, 2).unwrap();
+    /// assert_eq!("2022/05/06", date.next_occurrence_of_or_same(Weekday::Friday).format("yyyy/MM/dd"));
+    /// assert_eq!("2022/05/02", date.next_occurrence_of_or_same(Weekday::Monday).format("yyyy/MM/dd"));
     /// ```
-    pub fn from_ymd(year: i32, month: u32, day: u32) -> Result<Self, AstrolabeError> {
-        let days = date_to_days(year, month, day)?;
+    pub fn next_occurrence_of_or_same(&self, weekday: Weekday) -> Self {
+        self.add_days(days_until_weekday(self.weekday(), weekday, true))
+    }
 
-        Ok(Self { days })
+    /// Returns the previous date which falls on the given weekday. The previous occurrence is always strictly before `self`.
+    ///
+    /// Panics if the resulting date would be out of range.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, Weekday};
+    /// // Monday, 2. May 2022
+    /// let date = Date::from_ymd(2022, 5, 2).unwrap();
+    /// assert_eq!("2022/04/29", date.previous_occurrence_of(Weekday::Friday).format("yyyy/MM/dd"));
+    /// assert_eq!("2022/04/25", date.previous_occurrence_of(Weekday::Monday).format("yyyy/MM/dd"));
+    /// ```
+    pub fn previous_occurrence_of(&self, weekday: Weekday) -> Self {
+        self.sub_days(days_since_weekday(self.weekday(), weekday, false))
     }
 
-    /// Returns the date.
+    /// Returns the previous date which falls on the given weekday, or `self` if it already falls on it.
+    ///
+    /// Panics if the resulting date would be out of range.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, Weekday};
+    /// // Monday, 2. May 2022
+    /// let date = Date::from_ymd(2022, 5, 2).unwrap();
+    /// assert_eq!("2022/04/29", date.previous_occurrence_of_or_same(Weekday::Friday).format("yyyy/MM/dd"));
+    /// assert_eq!("2022/05/02", date.previous_occurrence_of_or_same(Weekday::Monday).format("yyyy/MM/dd"));
+    /// ```
+    pub fn previous_occurrence_of_or_same(&self, weekday: Weekday) -> Self {
+        self.sub_days(days_since_weekday(self.weekday(), weekday, true))
+    }
+
+    /// Returns `true` if the date is a Saturday or Sunday.
     ///
     /// ```rust
     /// # use astrolabe::Date;
-    /// let date = Date::from_ymd(2022, 05, 02).unwrap();
-    /// let (year, month, day) = date.as_ymd();
-    /// assert_eq!(2022, year);
-    /// assert_eq!(5, month);
-    /// assert_eq!(2, day);
+    /// assert!(Date::from_ymd(2022, 5, 7).unwrap().is_weekend());
+    /// assert!(!Date::from_ymd(2022, 5, 9).unwrap().is_weekend());
     /// ```
-    pub fn as_ymd(&self) -> (i32, u32, u32) {
-        days_to_date(self.days)
+    pub fn is_weekend(&self) -> bool {
+        is_weekend(self.days as i64)
     }
 
-    /// Parses a string with a given format and creates a new [`Date`] instance from it. See [`Date::format`] for a list of available symbols.
+    /// Adds business days, skipping Saturdays and Sundays.
     ///
-    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the given string could not be parsed with the given format.
+    /// If the date is on a weekend, it is first rolled forward to the next Monday before counting. Adding `0` business days therefore returns the next business day.
+    ///
+    /// Panics if the result would be out of range.
     ///
     /// ```rust
     /// # use astrolabe::Date;
-    /// let date = Date::parse("2022-05-02", "yyyy-MM-dd").unwrap();
-    /// assert_eq!("2022/05/02", date.format("yyyy/MM/dd"));
+    /// // Friday
+    /// let date = Date::from_ymd(2022, 5, 6).unwrap();
+    /// assert_eq!("2022/05/09", date.add_business_days(1).to_string());
+    /// // Saturday
+    /// let date = Date::from_ymd(2022, 5, 7).unwrap();
+    /// assert_eq!("2022/05/09", date.add_business_days(0).to_string());
+    /// assert_eq!("2022/05/10", date.add_business_days(1).to_string());
     /// ```
-    pub fn parse(string: &str, format: &str) -> Result<Self, AstrolabeError> {
-        let parts = parse_format_string(format);
-
-        let mut date = ParsedDate::default();
-        let mut string = string.to_string();
-
-        for part in parts {
-            // Escaped apostrophes
-            if part.starts_with('\u{0000}') {
-                string.replace_range(0..part.len(), "");
-                continue;
-            }
-
-            // Escaped parts
-            if part.starts_with('\'') {
-                string.replace_range(0..part.len() - if part.ends_with('\'') { 2 } else { 1 }, "");
-                continue;
-            }
+    pub fn add_business_days(&self, business_days: u32) -> Self {
+        self.add_business_days_with(business_days, &HolidayCalendar::default())
+    }
 
-            let parsed_part = parse_date_part(&part, &mut string)?;
-            if let Some(parsed_part) = parsed_part {
-                match parsed_part.unit {
-                    ParseUnit::Year => date.year = Some(parsed_part.value as i32),
-                    ParseUnit::Month => date.month = Some(parsed_part.value as u32),
-                    ParseUnit::DayOfMonth => date.day_of_month = Some(parsed_part.value as u32),
-                    // Can't be any other variant than `ParseUnit::DayOfYear`
-                    _ => date.day_of_year = Some(parsed_part.value as u32),
-                };
-            };
+    /// Adds business days, skipping Saturdays, Sundays and the holidays of the given [`HolidayCalendar`].
+    ///
+    /// If the date is not a business day, it is first rolled forward to the next business day before counting.
+    ///
+    /// Panics if the result would be out of range.
+    pub fn add_business_days_with(&self, business_days: u32, calendar: &HolidayCalendar) -> Self {
+        match add_business_days(self.days, business_days, calendar) {
+            Ok(days) => Self { days },
+            Err(e) => panic!("{}", e),
         }
+    }
 
-        // Use day of year if present, otherwise use month + day of month
-        Ok(if date.day_of_year.is_some() {
-            let days = year_doy_to_days(date.year.unwrap_or(1), date.day_of_year.unwrap(), false)?;
-            Self { days }
-        } else {
-            Self::from_ymd(
-                date.year.unwrap_or(1),
-                date.month.unwrap_or(1),
-                date.day_of_month.unwrap_or(1),
-            )?
-        })
+    /// Subtracts business days, skipping Saturdays and Sundays.
+    ///
+    /// If the date is on a weekend, it is first rolled back to the previous Friday before counting. Subtracting `0` business days therefore returns the previous business day.
+    ///
+    /// Panics if the result would be out of range.
+    ///
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// // Monday
+    /// let date = Date::from_ymd(2022, 5, 9).unwrap();
+    /// assert_eq!("2022/05/06", date.sub_business_days(1).to_string());
+    /// // Sunday
+    /// let date = Date::from_ymd(2022, 5, 8).unwrap();
+    /// assert_eq!("2022/05/06", date.sub_business_days(0).to_string());
+    /// assert_eq!("2022/05/05", date.sub_business_days(1).to_string());
+    /// ```
+    pub fn sub_business_days(&self, business_days: u32) -> Self {
+        self.sub_business_days_with(business_days, &HolidayCalendar::default())
     }
 
-    /// Formatting with format strings based on [Unicode Date Field Symbols](https://www.unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table).
+    /// Subtracts business days, skipping Saturdays, Sundays and the holidays of the given [`HolidayCalendar`].
     ///
-    /// Please note that not all symbols are implemented. If you need something that is not implemented, please open an issue on [GitHub](https://github.com/GiyoMoon/astrolabe/issues) describing your need.
+    /// If the date is not a business day, it is first rolled back to the previous business day before counting.
     ///
-    /// # Available Symbols:
+    /// Panics if the result would be out of range.
+    pub fn sub_business_days_with(&self, business_days: u32, calendar: &HolidayCalendar) -> Self {
+        match sub_business_days(self.days, business_days, calendar) {
+            Ok(days) => Self { days },
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns the number of business days from `compare` (inclusive) to `self` (exclusive). Negative if `self` is before `compare`.
     ///
-    /// | Field Type | Pattern  | Examples                      | Hint                                     |
-    /// | ---------- | -------- | ----------------------------- | ---------------------------------------- |
-    /// | era        | G..GGG   | AD                            |                                          |
-    /// |            | GGGG     | Anno Domini                   | *                                        |
-    /// |            | GGGGG    | A                             |                                          |
-    /// | year       | y        | 2, 20, 201, 2017, 20173       |                                          |
-    /// |            | yy       | 02, 20, 01, 17, 73            |                                          |
-    /// |            | yyy      | 002, 020, 201, 2017, 20173    |                                          |
-    /// |            | yyyy     | 0002, 0020, 0201, 2017, 20173 |                                          |
-    /// |            | yyyyy+   | ...                           | Unlimited length,<br/>padded with zeros. |
-    /// | quarter    | q        | 2                             | *                                        |
-    /// |            | qq       | 02                            |                                          |
-    /// |            | qqq      | Q2                            |                                          |
-    /// |            | qqqq     | 2nd quarter                   |                                          |
-    /// |            | qqqqq    | 2                             |                                          |
-    /// | month      | M        | 9, 12                         |                                          |
-    /// |            | MM       | 09, 12                        |                                          |
-    /// |            | MMM      | Sep                           |                                          |
-    /// |            | MMMM     | September                     | *                                        |
-    /// |            | MMMMM    | S                             |                                          |
-    /// | week       | w        | 8, 27                         | Week of year                             |
-    /// |            | ww       | 08, 27                        | *                                        |
-    /// | days       | d        | 1                             | Day of month                             |
-    /// |            | dd       | 01                            | *                                        |
-    /// |            | D        | 1, 24, 135                    | Day of year, *                           |
-    /// |            | DD       | 01, 24, 135                   |                                          |
-    /// |            | DDD      | 001, 024, 135                 |                                          |
-    /// | week day   | e        | 3                             | 1-7, 1 is Sunday, *                      |
-    /// |            | ee       | 03                            | 1-7, 1 is Sunday                         |
-    /// |            | eee      | Tue                           |                                          |
-    /// |            | eeee     | Tuesday                       |                                          |
-    /// |            | eeeee    | T                             |                                          |
-    /// |            | eeeeee   | Tu                            |                                          |
-    /// |            | eeeeeee  | 2                             | 1-7, 1 is Monday                         |
-    /// |            | eeeeeeee | 02                            | 1-7, 1 is Monday                         |
+    /// ```rust
+    /// # use astrolabe::Date;
+    /// // Friday and the following Monday
+    /// let friday = Date::from_ymd(2022, 5, 6).unwrap();
+    /// let monday = Date::from_ymd(2022, 5, 9).unwrap();
+    /// assert_eq!(1, monday.business_days_since(&friday));
+    /// assert_eq!(-1, friday.business_days_since(&monday));
+    /// ```
+    pub fn business_days_since(&self, compare: &Self) -> i64 {
+        self.business_days_since_with(compare, &HolidayCalendar::default())
+    }
+
+    /// Returns the number of business days from `compare` (inclusive) to `self` (exclusive), skipping the holidays of the given [`HolidayCalendar`]. Negative if `self` is before `compare`.
+    pub fn business_days_since_with(&self, compare: &Self, calendar: &HolidayCalendar) -> i64 {
+        business_days_between(compare.days, self.days, calendar)
+    }
+
+    /// Returns the value of the given unit. Useful if the unit is only known at runtime.
     ///
-    /// `*` = Default
+    /// ```rust
+    /// # use astrolabe::{Date, DateUnit};
+    /// let date = Date::from_ymd(2022, 5, 2).unwrap();
+    /// assert_eq!(2022, date.get_unit(DateUnit::Year));
+    /// assert_eq!(5, date.get_unit(DateUnit::Month));
+    /// ```
+    pub fn get_unit(&self, unit: DateUnit) -> i64 {
+        match unit {
+            DateUnit::Year => self.year() as i64,
+            DateUnit::Month => self.month() as i64,
+            DateUnit::Day => self.day() as i64,
+        }
+    }
+
+    /// Sets the given unit to the provided value, like [`set_year`](DateUtilities::set_year), [`set_month`](DateUtilities::set_month) and [`set_day`](DateUtilities::set_day).
     ///
-    /// If the sequence is longer than listed in the table, the output will be the same as the default pattern for this unit (marked with `*`).
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided value is out of range.
     ///
-    /// Surround any character with apostrophes (`'`) to escape them.
-    /// If you want escape `'`, write `''`.
+    /// ```rust
+    /// # use astrolabe::{Date, DateUnit};
+    /// let date = Date::from_ymd(2022, 5, 2).unwrap();
+    /// assert_eq!("2022/12/02", date.set_unit(DateUnit::Month, 12).unwrap().to_string());
+    /// assert!(date.set_unit(DateUnit::Month, 13).is_err());
+    /// ```
+    pub fn set_unit(&self, unit: DateUnit, value: i64) -> Result<Self, AstrolabeError> {
+        let value_unit = Unit::from(unit);
+        match unit {
+            DateUnit::Year => self.set_year(value_unit.value(value)?),
+            DateUnit::Month => self.set_month(value_unit.value(value)?),
+            DateUnit::Day => self.set_day(value_unit.value(value)?),
+        }
+    }
+
+    /// Adds the provided amount of the given unit, or subtracts it if the amount is negative. Same as the `add_` and `sub_` functions, e.g. [`add_years`](DateUtilities::add_years), but returns an error instead of panicking.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the result would be an out of range date.
     ///
     /// ```rust
-    /// # use astrolabe::Date;
+    /// # use astrolabe::{Date, DateUnit};
     /// let date = Date::from_ymd(2022, 5, 2).unwrap();
-    /// assert_eq!("2022/05/02", date.format("yyyy/MM/dd"));
-    /// // Escape characters
-    /// assert_eq!("2022/MM/dd", date.format("yyyy/'MM/dd'"));
-    /// assert_eq!("2022/'05/02'", date.format("yyyy/''MM/dd''"));
+    /// assert_eq!("2022/02/02", date.apply_unit(DateUnit::Month, -3).unwrap().to_string());
+    /// assert!(date.apply_unit(DateUnit::Year, 6_000_000).is_err());
     /// ```
+    pub fn apply_unit(&self, unit: DateUnit, amount: i64) -> Result<Self, AstrolabeError> {
+        let (negative, amount) = Unit::from(unit).amount(amount)?;
+        let days = match (unit, negative) {
+            (DateUnit::Year, false) => add_years(self.days, amount),
+            (DateUnit::Year, true) => sub_years(self.days, amount),
+            (DateUnit::Month, false) => add_months(self.days, amount),
+            (DateUnit::Month, true) => sub_months(self.days, amount),
+            (DateUnit::Day, false) => add_days(self.days, amount),
+            (DateUnit::Day, true) => sub_days(self.days, amount),
+        }?;
+        Ok(Self { days })
+    }
+
+    /// Adds years like [`add_years`](DateUtilities::add_years), but resolves February 29th in a target year which is not a leap year with the given [`MonthOverflow`].
     ///
-    pub fn format(&self, format: &str) -> String {
-        let parts = parse_format_string(format);
-        parts
-            .iter()
-            .flat_map(|part| -> Vec<char> {
-                // Escaped apostrophes
-                if part.starts_with('\u{0000}') {
-                    return part.replace('\u{0000}', "'").chars().collect::<Vec<char>>();
-                }
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the result would be out of range, or if the day doesn't exist in the target year and [`MonthOverflow::Error`] is used.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, MonthOverflow};
+    /// let date = Date::from_ymd(2024, 2, 29).unwrap();
+    /// assert_eq!("2025/02/28", date.add_years_with(1, MonthOverflow::ClampToLastDay).unwrap().to_string());
+    /// assert_eq!("2025/03/01", date.add_years_with(1, MonthOverflow::RollToNextMonth).unwrap().to_string());
+    /// assert!(date.add_years_with(1, MonthOverflow::Error).is_err());
+    /// assert_eq!("2028/02/29", date.add_years_with(4, MonthOverflow::Error).unwrap().to_string());
+    /// ```
+    pub fn add_years_with(
+        &self,
+        years: u32,
+        overflow: MonthOverflow,
+    ) -> Result<Self, AstrolabeError> {
+        let days = add_years_with(self.days, years, overflow)?;
+        Ok(Self { days })
+    }
 
-                // Escape parts starting with apostrophe
-                if part.starts_with('\'') {
-                    let part = part.replace('\u{0000}', "'");
-                    return part[1..part.len() - usize::from(part.ends_with('\''))]
-                        .chars()
-                        .collect::<Vec<char>>();
-                }
+    /// Subtracts years like [`sub_years`](DateUtilities::sub_years), but resolves February 29th in a target year which is not a leap year with the given [`MonthOverflow`].
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the result would be out of range, or if the day doesn't exist in the target year and [`MonthOverflow::Error`] is used.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, MonthOverflow};
+    /// let date = Date::from_ymd(2024, 2, 29).unwrap();
+    /// assert_eq!("2023/03/01", date.sub_years_with(1, MonthOverflow::RollToNextMonth).unwrap().to_string());
+    /// assert!(date.sub_years_with(1, MonthOverflow::Error).is_err());
+    /// ```
+    pub fn sub_years_with(
+        &self,
+        years: u32,
+        overflow: MonthOverflow,
+    ) -> Result<Self, AstrolabeError> {
+        let days = sub_years_with(self.days, years, overflow)?;
+        Ok(Self { days })
+    }
 
-                format_date_part(part, self.days)
-                    .chars()
-                    .collect::<Vec<char>>()
-            })
-            .collect::<String>()
+    /// Adds months like [`add_months`](DateUtilities::add_months), but resolves a day of month which doesn't exist in the target month with the given [`MonthOverflow`].
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the result would be out of range, or if the day doesn't exist in the target month and [`MonthOverflow::Error`] is used.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, MonthOverflow};
+    /// let date = Date::from_ymd(2022, 1, 31).unwrap();
+    /// assert_eq!("2022/02/28", date.add_months_with(1, MonthOverflow::ClampToLastDay).unwrap().to_string());
+    /// assert_eq!("2022/03/01", date.add_months_with(1, MonthOverflow::RollToNextMonth).unwrap().to_string());
+    /// assert!(date.add_months_with(1, MonthOverflow::Error).is_err());
+    /// ```
+    pub fn add_months_with(
+        &self,
+        months: u32,
+        overflow: MonthOverflow,
+    ) -> Result<Self, AstrolabeError> {
+        let days = add_months_with(self.days, months, overflow)?;
+        Ok(Self { days })
     }
 
-    /// Returns the duration between the provided date.
-    pub fn duration_between(&self, compare: &Self) -> Duration {
-        Duration::from_secs(self.days_since(compare).unsigned_abs() * SECS_PER_DAY_U64)
+    /// Subtracts months like [`sub_months`](DateUtilities::sub_months), but resolves a day of month which doesn't exist in the target month with the given [`MonthOverflow`].
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the result would be out of range, or if the day doesn't exist in the target month and [`MonthOverflow::Error`] is used.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, MonthOverflow};
+    /// let date = Date::from_ymd(2022, 3, 31).unwrap();
+    /// assert_eq!("2022/03/01", date.sub_months_with(1, MonthOverflow::RollToNextMonth).unwrap().to_string());
+    /// assert!(date.sub_months_with(1, MonthOverflow::Error).is_err());
+    /// ```
+    pub fn sub_months_with(
+        &self,
+        months: u32,
+        overflow: MonthOverflow,
+    ) -> Result<Self, AstrolabeError> {
+        let days = sub_months_with(self.days, months, overflow)?;
+        Ok(Self { days })
     }
 }
 
@@ -238,6 +1194,10 @@ impl DateUtilities for Date {
         days_to_date(self.days).1
     }
 
+    fn quarter(&self) -> u32 {
+        (days_to_date(self.days).1 - 1) / 3 + 1
+    }
+
     fn day(&self) -> u32 {
         days_to_date(self.days).2
     }
@@ -247,7 +1207,27 @@ impl DateUtilities for Date {
     }
 
     fn weekday(&self) -> u8 {
-        days_to_wday(self.days, false) as u8
+        days_to_wday(self.days) as u8
+    }
+
+    fn weekday_iso(&self) -> u8 {
+        days_to_iso_wday(self.days) as u8
+    }
+
+    fn weekday_sunday0(&self) -> u8 {
+        self.weekday()
+    }
+
+    fn week_of_month(&self) -> u32 {
+        self.week_of_month_with(Weekday::Sunday)
+    }
+
+    fn week_of_month_with(&self, week_start: Weekday) -> u32 {
+        days_to_week_of_month(self.days, week_start as u32)
+    }
+
+    fn week_of_year(&self, system: WeekNumbering) -> u32 {
+        system.week_of_year(self.days)
     }
 
     fn from_timestamp(timestamp: i64) -> Self {
@@ -321,6 +1301,17 @@ impl DateUtilities for Date {
         Self { days: new_days }
     }
 
+    fn add_quarters(&self, quarters: u32) -> Self {
+        let new_days = add_quarters(self.days, quarters);
+
+        let new_days = match new_days {
+            Ok(new_days) => new_days,
+            Err(e) => panic!("{}", e),
+        };
+
+        Self { days: new_days }
+    }
+
     fn add_days(&self, days: u32) -> Self {
         let new_days = add_days(self.days, days);
 
@@ -354,6 +1345,17 @@ impl DateUtilities for Date {
         Self { days: new_days }
     }
 
+    fn sub_quarters(&self, quarters: u32) -> Self {
+        let new_days = sub_quarters(self.days, quarters);
+
+        let new_days = match new_days {
+            Ok(new_days) => new_days,
+            Err(e) => panic!("{}", e),
+        };
+
+        Self { days: new_days }
+    }
+
     fn sub_days(&self, days: u32) -> Self {
         let new_days = sub_days(self.days, days);
 
@@ -365,6 +1367,72 @@ impl DateUtilities for Date {
         Self { days: new_days }
     }
 
+    fn saturating_add_years(&self, years: u32) -> Self {
+        Self {
+            days: add_years(self.days, years).unwrap_or(i32::MAX),
+        }
+    }
+
+    fn saturating_add_months(&self, months: u32) -> Self {
+        Self {
+            days: add_months(self.days, months).unwrap_or(i32::MAX),
+        }
+    }
+
+    fn saturating_add_quarters(&self, quarters: u32) -> Self {
+        Self {
+            days: add_quarters(self.days, quarters).unwrap_or(i32::MAX),
+        }
+    }
+
+    fn saturating_add_days(&self, days: u32) -> Self {
+        Self {
+            days: add_days(self.days, days).unwrap_or(i32::MAX),
+        }
+    }
+
+    fn saturating_sub_years(&self, years: u32) -> Self {
+        Self {
+            days: sub_years(self.days, years).unwrap_or(i32::MIN),
+        }
+    }
+
+    fn saturating_sub_months(&self, months: u32) -> Self {
+        Self {
+            days: sub_months(self.days, months).unwrap_or(i32::MIN),
+        }
+    }
+
+    fn saturating_sub_quarters(&self, quarters: u32) -> Self {
+        Self {
+            days: sub_quarters(self.days, quarters).unwrap_or(i32::MIN),
+        }
+    }
+
+    fn saturating_sub_days(&self, days: u32) -> Self {
+        Self {
+            days: sub_days(self.days, days).unwrap_or(i32::MIN),
+        }
+    }
+
+    fn first_day_of_quarter(&self) -> Self {
+        let new_days = match first_day_of_quarter(self.days) {
+            Ok(new_days) => new_days,
+            Err(e) => panic!("{}", e),
+        };
+
+        Self { days: new_days }
+    }
+
+    fn last_day_of_quarter(&self) -> Self {
+        let new_days = match last_day_of_quarter(self.days) {
+            Ok(new_days) => new_days,
+            Err(e) => panic!("{}", e),
+        };
+
+        Self { days: new_days }
+    }
+
     fn clear_until_year(&self) -> Self {
         Self { days: 0 }
     }
@@ -423,8 +1491,13 @@ impl Add<Duration> for Date {
     /// Performs the `+` operation.
     ///
     /// Only adds full days (`86 400` seconds) to [`Date`]. Any additional duration will be ignored.
+    ///
+    /// Panics if the result would be out of range.
     fn add(self, rhs: Duration) -> Self::Output {
-        let days = self.days + (rhs.as_secs() / SECS_PER_DAY_U64) as i32;
+        let days = match duration_days(rhs).and_then(|days| add_days(self.days, days)) {
+            Ok(days) => days,
+            Err(e) => panic!("{}", e),
+        };
         Self { days }
     }
 }
@@ -432,6 +1505,8 @@ impl AddAssign<Duration> for Date {
     /// Performs the `+=` operation.
     ///
     /// Only adds full days (`86 400` seconds) to [`Date`]. Any additional duration will be ignored.
+    ///
+    /// Panics if the result would be out of range.
     fn add_assign(&mut self, rhs: Duration) {
         *self = *self + rhs;
     }
@@ -443,8 +1518,13 @@ impl Sub<Duration> for Date {
     /// Performs the `-` operation.
     ///
     /// Only removes full days (`86 400` seconds) to [`Date`]. Any additional duration will be ignored.
+    ///
+    /// Panics if the result would be out of range.
     fn sub(self, rhs: Duration) -> Self::Output {
-        let days = self.days - (rhs.as_secs() / SECS_PER_DAY_U64) as i32;
+        let days = match duration_days(rhs).and_then(|days| sub_days(self.days, days)) {
+            Ok(days) => days,
+            Err(e) => panic!("{}", e),
+        };
         Self { days }
     }
 }
@@ -452,21 +1532,151 @@ impl SubAssign<Duration> for Date {
     /// Performs the `-=` operation.
     ///
     /// Only removes full days (`86 400` seconds) to [`Date`]. Any additional duration will be ignored.
+    ///
+    /// Panics if the result would be out of range.
     fn sub_assign(&mut self, rhs: Duration) {
         *self = *self - rhs;
     }
 }
 
+/// Returns the full days of the duration
+fn duration_days(duration: Duration) -> Result<u32, AstrolabeError> {
+    let days = duration.as_secs() / SECS_PER_DAY_U64;
+    days.try_into().map_err(|_| {
+        create_custom_oor(format!(
+            "Instance would result into an overflow if {} days were added.",
+            days
+        ))
+    })
+}
+
+impl Add<Time> for Date {
+    type Output = DateTime;
+
+    /// Performs the `+` operation.
+    ///
+    /// Combines the date with the time of day to a [`DateTime`] with the offset of the [`Time`]. The date is the local date in this offset.
+    ///
+    /// Panics if the resulting instant would be out of range. This can only happen for dates within a day of `-5879611-06-23` or `5879611-07-12`.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, Offset, OffsetUtilities, Time};
+    /// let date = Date::from_ymd(2022, 5, 2).unwrap();
+    /// let time = Time::from_hms(12, 32, 1)
+    ///     .unwrap()
+    ///     .as_offset(Offset::from_hms(2, 0, 0).unwrap());
+    /// let date_time = date + time;
+    /// assert_eq!("2022-05-02 12:32:01 +02:00", date_time.format("yyyy-MM-dd HH:mm:ss xxx"));
+    /// ```
+    fn add(self, rhs: Time) -> Self::Output {
+        let offset = rhs.get_offset();
+        let local_time = add_offset_to_nanos(rhs.as_nanos(), offset.resolve());
+        // Using unwrap because the local time is always within a day
+        DateTime::from(self)
+            .set_time(Time::from_nanos(local_time).unwrap())
+            .as_offset(offset)
+    }
+}
+
+/// Formats the date as `yyyy/MM/dd`.
+///
+/// The alternate flag formats the date as ISO 8601 `yyyy-MM-dd`, with a `-` sign for years before `0001` and more than four digits for years after `9999`. This is the format accepted by the [`FromStr`] implementation.
+///
+/// ```rust
+/// # use astrolabe::Date;
+/// let date = Date::from_ymd(2022, 5, 2).unwrap();
+/// assert_eq!("2022/05/02", format!("{}", date));
+/// assert_eq!("2022-05-02", format!("{:#}", date));
+/// assert_eq!("-0001-12-31", format!("{:#}", Date::from_ymd(-1, 12, 31).unwrap()));
+/// ```
 impl Display for Date {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.format("yyyy/MM/dd"))
+        if f.alternate() {
+            write!(f, "{}", self.format("yyyy-MM-dd"))
+        } else {
+            write!(f, "{}", self.format("yyyy/MM/dd"))
+        }
     }
 }
 
+/// Parses a `yyyy-MM-dd` formatted string. The year has at least four digits and can have a leading `-` or `+` (E.g. `-0044-03-15` or `+12345-01-01`).
+///
+/// ```rust
+/// # use astrolabe::Date;
+/// let date: Date = "2022-05-02".parse().unwrap();
+/// assert_eq!("2022/05/02", date.format("yyyy/MM/dd"));
+/// ```
 impl FromStr for Date {
     type Err = AstrolabeError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::parse(s, "yyyy-MM-dd")
+        let (negative, rest) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        let mut parts = rest.splitn(3, '-');
+        let (year, month, day) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(year), Some(month), Some(day)) => (year, month, day),
+            _ => {
+                return Err(create_invalid_format(format!(
+                    "Date has to be in the format 'yyyy-MM-dd'. Value is '{}'.",
+                    s
+                )))
+            }
+        };
+
+        if year.len() < 4 || !year.chars().all(|char| char.is_ascii_digit()) {
+            return Err(create_invalid_format(format!(
+                "Failed parsing year from given string. Expected at least 4 digits, value is '{}'.",
+                year
+            )));
+        }
+        let year = year.parse::<i32>().map_err(|_| {
+            create_invalid_format(format!(
+                "Failed parsing year from given string. Value '{}' is too large.",
+                year
+            ))
+        })?;
+
+        Self::from_ymd(
+            if negative { -year } else { year },
+            parse_digits(month, 2, "month")?,
+            parse_digits(day, 2, "day of month")?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod date_tests {
+    use super::Date;
+    use crate::{local::timezone::TimeZone, DateTime, DateUtilities};
+
+    #[test]
+    fn local_date() {
+        // Fixed offset of +13:00 without transitions
+        let bytes = b"TZif\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04\0\0\xb6\xd0\0\0+13\0";
+        let time_zone = TimeZone::from_tzif(bytes).unwrap();
+
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 10, 59, 59).unwrap();
+        let offset = time_zone.to_local_time_type(date_time.timestamp()).utoff;
+        assert_eq!(46_800, offset);
+        assert_eq!(
+            Date::from_ymd(2022, 5, 2).unwrap(),
+            Date::local_date(&date_time, offset)
+        );
+
+        // Already the next day in +13:00, but not in UTC
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 11, 0, 0).unwrap();
+        assert_eq!(
+            Date::from_ymd(2022, 5, 3).unwrap(),
+            Date::local_date(&date_time, offset)
+        );
+        assert_eq!(2, Date::from(date_time).day());
+        assert_eq!(
+            Date::from_ymd(2022, 5, 1).unwrap(),
+            Date::local_date(&date_time, -46_800)
+        );
     }
 }
@@ -1,27 +1,38 @@
 use crate::offset::Offset;
-use crate::util::constants::DAYS_TO_1970;
 use crate::{
-    errors::{invalid_format::create_invalid_format, AstrolabeError},
+    errors::{
+        invalid_format::{create_invalid_format, create_positioned_invalid_format},
+        out_of_range::{create_custom_oor, create_simple_oor},
+        AstrolabeError, BufferTooSmall,
+    },
     util::{
         constants::{
-            DAYS_TO_1970_I64, NANOS_PER_DAY, NANOS_PER_SEC, SECS_PER_DAY_U64, SECS_PER_HOUR_U64,
+            DAYS_TO_1970_I64, MJD_JULIAN_DATE, NANOS_PER_DAY, NANOS_PER_HOUR, NANOS_PER_MINUTE,
+            NANOS_PER_SEC, RFC3339_MAX_LEN, SECS_PER_DAY_U64, SECS_PER_HOUR_U64,
             SECS_PER_MINUTE_U64,
         },
         date::{
             convert::{
-                date_to_days, days_to_date, days_to_doy, days_to_wday, months_between,
-                year_doy_to_days, years_between,
+                date_to_days, date_to_days_unchecked, days_to_date, days_to_doy, days_to_iso_wday,
+                days_to_mjd, days_to_wday, days_to_week_of_month, lenient_date_to_days,
+                months_between, year_doy_to_days, years_between,
             },
             manipulate::{
-                add_days, add_months, add_years, set_day, set_day_of_year, set_month, set_year,
-                sub_days, sub_months, sub_years,
+                add_days, add_months, add_months_with, add_quarters, add_years, add_years_with,
+                days_since_weekday, days_until_weekday, first_day_of_quarter, last_day_of_quarter,
+                set_day, set_day_of_year, set_month, set_year, shift_months, sub_days, sub_months,
+                sub_months_with, sub_quarters, sub_years, sub_years_with,
             },
+            validate::is_valid_date,
+        },
+        format::{
+            check_format_symbols, format_part, format_year_of_era, has_era_symbol, write_rfc3339,
+            DATE_SYMBOLS, TIME_SYMBOLS,
         },
-        format::format_part,
         offset::{add_offset_to_dn, remove_offset_from_dn},
         parse::{
-            parse_format_string, parse_offset, parse_part, ParseUnit, ParsedDate, ParsedTime,
-            Period,
+            parse_fixed_width, parse_format_string, parse_offset, parse_part, parse_subseconds,
+            FormatPart, OffsetFormat, ParseUnit, ParsedDate, ParsedTime, Period,
         },
         time::{
             convert::{
@@ -29,24 +40,28 @@ use crate::{
                 days_nanos_to_minutes, days_nanos_to_nanos, days_nanos_to_seconds,
                 days_nanos_to_secs, nanos_to_days_nanos, nanos_to_subhour_nanos,
                 nanos_to_submicro_nanos, nanos_to_submilli_nanos, nanos_to_subminute_nanos,
-                nanos_to_subsecond, nanos_to_subsecond_nanos, nanos_to_time, secs_to_days_nanos,
-                since_i128, since_i64, time_to_day_seconds,
+                nanos_to_subsecond, nanos_to_subsecond_nanos, nanos_to_time, now_days_nanos,
+                secs_to_days_nanos, since_i128, since_i64, time_to_day_nanos, time_to_day_seconds,
+                time_to_day_seconds_unchecked,
             },
             manipulate::{
-                add_hours, add_micros, add_millis, add_minutes, add_seconds,
+                add_hours, add_micros, add_millis, add_minutes, add_seconds, ceil_nanos,
                 clear_nanos_until_micro, clear_nanos_until_milli, clear_nanos_until_minute,
-                clear_nanos_until_nanos, clear_nanos_until_second, set_hour, set_micro, set_milli,
-                set_minute, set_nano, set_second, sub_hours, sub_micros, sub_millis, sub_minutes,
-                sub_seconds,
+                clear_nanos_until_nanos, clear_nanos_until_second, day_divisor_nanos, floor_nanos,
+                round_nanos, set_hour, set_micro, set_milli, set_minute, set_nano, set_second,
+                sub_hours, sub_micros, sub_millis, sub_minutes, sub_seconds,
             },
+            validate::is_valid_time,
         },
     },
-    Date, DateUtilities, OffsetUtilities, Precision, Time, TimeUtilities,
+    Bounds, Date, DateTimeDelta, DateTimeRange, DateUnit, DateUtilities, Era, Locale,
+    MonthOverflow, OffsetUtilities, ParseOptions, Precision, RelativeThresholds, Time, TimeUnit,
+    TimeUtilities, Unit, WeekNumbering, Weekday,
 };
-use std::time::{SystemTime, UNIX_EPOCH};
 use std::{
     cmp,
     fmt::Display,
+    hash::{Hash, Hasher},
     ops::{Add, AddAssign, Sub, SubAssign},
     str::FromStr,
     time::Duration,
@@ -68,7 +83,11 @@ pub struct DateTime {
 }
 
 impl DateTime {
-    /// Creates a new [`DateTime`] instance with [`SystemTime::now()`].
+    /// Creates a new [`DateTime`] instance with [`SystemTime::now()`](std::time::SystemTime::now).
+    ///
+    /// The instance has nanosecond precision, but the actual resolution depends on the system clock. As the system clock can be adjusted, consecutive calls are not guaranteed to be increasing.
+    ///
+    /// With the `test-util` feature, the current time can be mocked in tests with [`testing::set_mock_now`](crate::testing::set_mock_now).
     ///
     /// ```rust
     /// # use astrolabe::{DateTime, DateUtilities};
@@ -76,22 +95,47 @@ impl DateTime {
     /// assert!(2021 < date_time.year());
     /// ```
     pub fn now() -> Self {
-        let duration = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards");
-
-        let days = duration.as_secs() / SECS_PER_DAY_U64 + DAYS_TO_1970;
-        let nanoseconds =
-            duration.as_secs() % SECS_PER_DAY_U64 * NANOS_PER_SEC + duration.subsec_nanos() as u64;
+        let (days, nanoseconds) = now_days_nanos();
 
         Self {
-            days: days as i32,
+            days,
             nanoseconds,
             offset: Offset::default(),
         }
     }
 
-    /// Creates a new [`DateTime`] instance with [`SystemTime::now()`] with the local timezone as the offset.
+    /// Creates a new [`DateTime`] instance with [`SystemTime::now()`](std::time::SystemTime::now), truncated to whole seconds.
+    ///
+    /// Useful if subsecond values are not needed, e.g. for timestamps which are stored with second precision. Like [`DateTime::now`], the current time can be mocked with the `test-util` feature.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, TimeUtilities};
+    /// let date_time = DateTime::now_seconds();
+    /// assert_eq!(0, date_time.nano());
+    /// ```
+    pub fn now_seconds() -> Self {
+        let (days, nanoseconds) = now_days_nanos();
+
+        Self {
+            days,
+            nanoseconds: nanoseconds - nanoseconds % NANOS_PER_SEC,
+            offset: Offset::default(),
+        }
+    }
+
+    /// Creates a new [`DateTime`] instance at midnight of the current date in UTC.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, DateTime, TimeUtilities};
+    /// let date_time = DateTime::today();
+    /// assert_eq!(0, date_time.hour());
+    /// assert_eq!(0, date_time.nano());
+    /// ```
+    pub fn today() -> Self {
+        Self::from(Date::today())
+    }
+
+    /// Creates a new [`DateTime`] instance with [`SystemTime::now()`](std::time::SystemTime::now) with the local timezone as the offset.
     ///
     /// ```rust
     /// # use astrolabe::{DateTime, DateUtilities, Offset, OffsetUtilities};
@@ -129,6 +173,114 @@ impl DateTime {
         })
     }
 
+    /// Creates a new [`DateTime`] instance from year, month, day (day of month), hour, minute, seconds and nanoseconds.
+    ///
+    /// All values are validated before the instance is created. Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error naming the first invalid value.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let date_time = DateTime::from_ymdhms_nano(2022, 5, 2, 12, 32, 1, 123_456_789).unwrap();
+    /// assert_eq!("2022-05-02T12:32:01.123456789Z", date_time.format_rfc3339(astrolabe::Precision::Nanos));
+    /// assert!(DateTime::from_ymdhms_nano(2022, 5, 2, 12, 32, 1, 1_000_000_000).is_err());
+    /// ```
+    pub fn from_ymdhms_nano(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        nanos: u32,
+    ) -> Result<Self, AstrolabeError> {
+        let days = date_to_days(year, month, day)?;
+        let nanoseconds = time_to_day_nanos(hour, minute, second, nanos)?;
+        Ok(Self {
+            days,
+            nanoseconds,
+            offset: Offset::default(),
+        })
+    }
+
+    /// Creates a new [`DateTime`] instance from year, month, day (day of month), hour, minute and seconds without validating them. Intended for literals in const contexts, like lookup tables of known dates and times.
+    ///
+    /// The values are only checked with debug assertions, which panic if the date or time is invalid. Without debug assertions, invalid values don't cause undefined behavior but produce a logically invalid [`DateTime`].
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// const LAUNCH: DateTime = DateTime::from_ymdhms_unchecked(2022, 5, 2, 12, 32, 1);
+    /// assert_eq!(DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap(), LAUNCH);
+    /// ```
+    pub const fn from_ymdhms_unchecked(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+    ) -> Self {
+        debug_assert!(is_valid_date(year, month, day), "Invalid date");
+        debug_assert!(is_valid_time(hour, minute, second), "Invalid time");
+
+        Self {
+            days: date_to_days_unchecked(year, month, day),
+            nanoseconds: time_to_day_seconds_unchecked(hour, minute, second) as u64 * NANOS_PER_SEC,
+            offset: Offset::Fixed(0),
+        }
+    }
+
+    /// Creates a new [`DateTime`] instance from year, month, day (day of month), hour, minute and seconds, normalizing out of range values by carrying them into the next larger unit, e.g. hour `24` is midnight of the next day and second `-1` is the last second of the previous minute. See [`Date::from_ymd_lenient`] for how months and days are normalized.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the year is `0` or the normalized date time is out of range. Use [`DateTime::from_ymdhms`] to reject out of range values instead.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let date_time = DateTime::from_ymdhms_lenient(2022, 12, 31, 24, 0, 0).unwrap();
+    /// assert_eq!("2023/01/01 00:00:00", date_time.format("yyyy/MM/dd HH:mm:ss"));
+    /// let date_time = DateTime::from_ymdhms_lenient(2022, 1, 1, 0, 0, -1).unwrap();
+    /// assert_eq!("2021/12/31 23:59:59", date_time.format("yyyy/MM/dd HH:mm:ss"));
+    /// ```
+    pub fn from_ymdhms_lenient(
+        year: i32,
+        month: i64,
+        day: i64,
+        hour: i64,
+        minute: i64,
+        second: i64,
+    ) -> Result<Self, AstrolabeError> {
+        let days = lenient_date_to_days(year, month, day)?;
+        let seconds = hour as i128 * SECS_PER_HOUR_U64 as i128
+            + minute as i128 * SECS_PER_MINUTE_U64 as i128
+            + second as i128;
+        let (days, nanoseconds) =
+            nanos_to_days_nanos(days * NANOS_PER_DAY as i128 + seconds * NANOS_PER_SEC as i128)?;
+
+        Ok(Self {
+            days,
+            nanoseconds,
+            offset: Offset::default(),
+        })
+    }
+
+    /// Creates a new [`DateTime`] instance from year and day of year (`1-365` or `1-366` in leap years) at midnight.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided values are invalid, e.g. day `366` in a year which is not a leap year.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, DateUtilities};
+    /// let date_time = DateTime::from_ordinal(2022, 122).unwrap();
+    /// assert_eq!("2022/05/02 00:00:00", date_time.format("yyyy/MM/dd HH:mm:ss"));
+    /// assert_eq!(122, date_time.ordinal());
+    /// ```
+    pub fn from_ordinal(year: i32, day_of_year: u32) -> Result<Self, AstrolabeError> {
+        let days = year_doy_to_days(year, day_of_year, false)?;
+
+        Ok(Self {
+            days,
+            nanoseconds: 0,
+            offset: Offset::default(),
+        })
+    }
+
     /// Returns the DateTime as year, month, day (day of month), hour, minute and seconds.
     ///
     /// ```rust
@@ -148,6 +300,20 @@ impl DateTime {
         (year, month, day, hour, minute, second)
     }
 
+    /// Returns the DateTime as year, month, day (day of month), hour, minute, seconds and nanoseconds. Counterpart of [`DateTime::from_ymdhms_nano`].
+    ///
+    /// Like [`DateTime::as_ymdhms`], the values are in UTC.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let date_time = DateTime::from_ymdhms_nano(2022, 5, 2, 12, 32, 1, 5).unwrap();
+    /// assert_eq!((2022, 5, 2, 12, 32, 1, 5), date_time.as_ymdhms_nano());
+    /// ```
+    pub fn as_ymdhms_nano(&self) -> (i32, u32, u32, u32, u32, u32, u32) {
+        let (year, month, day, hour, minute, second) = self.as_ymdhms();
+        (year, month, day, hour, minute, second, self.nano())
+    }
+
     /// Creates a new [`DateTime`] instance from year, month and day (day of month).
     ///
     /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided values are invalid.
@@ -220,6 +386,109 @@ impl DateTime {
         (hour as u32, minute as u32, second as u32)
     }
 
+    /// Creates a new [`DateTime`] instance in UTC from its raw representation, the number of days since `0001-01-01` and the nanoseconds since midnight of that day, both in UTC. The days are counted like in [`Date::from_days`].
+    ///
+    /// Every [`i32`] is a valid day. Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the nanoseconds are `86_400_000_000_000` or more. Together with [`DateTime::as_raw`], this allows implementing custom serialization formats. The offset is not part of the raw representation and has to be stored separately if needed.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let date_time = DateTime::from_raw(738_276, 55_820_000_000_000).unwrap();
+    /// assert_eq!("2022-05-02T15:30:20Z", date_time.format_rfc3339(astrolabe::Precision::Seconds));
+    /// assert!(DateTime::from_raw(0, 86_400_000_000_000).is_err());
+    /// ```
+    pub fn from_raw(days: i32, nanoseconds: u64) -> Result<Self, AstrolabeError> {
+        if nanoseconds >= NANOS_PER_DAY {
+            return Err(create_simple_oor(
+                "nanoseconds",
+                0,
+                NANOS_PER_DAY as i128 - 1,
+                nanoseconds as i128,
+            ));
+        }
+        Ok(Self {
+            days,
+            nanoseconds,
+            offset: Offset::default(),
+        })
+    }
+
+    /// Creates a new [`DateTime`] instance in UTC from the number of days since `0001-01-01` and the nanoseconds since midnight of that day. Same as [`DateTime::from_raw`].
+    ///
+    /// Day `0` is `0001-01-01`. Negative days count backwards from there, skipping year `0`. Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the nanoseconds are `86_400_000_000_000` or more.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, DateTime};
+    /// let date = Date::from_ymd(2022, 5, 2).unwrap();
+    /// let date_time = DateTime::from_days_nanos(date.days(), 55_820_000_000_000).unwrap();
+    /// assert_eq!("2022/05/02 15:30:20", date_time.to_string());
+    /// assert_eq!("-0001/12/31 00:00:00", DateTime::from_days_nanos(-1, 0).unwrap().to_string());
+    /// ```
+    pub fn from_days_nanos(days: i32, nanoseconds: u64) -> Result<Self, AstrolabeError> {
+        Self::from_raw(days, nanoseconds)
+    }
+
+    /// Returns the raw representation of the instant as days since `0001-01-01` and nanoseconds since midnight of that day, both in UTC regardless of the offset. See [`DateTime::from_raw`].
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Offset, OffsetUtilities};
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 15, 30, 20).unwrap();
+    /// assert_eq!((738_276, 55_820_000_000_000), date_time.as_raw());
+    /// assert_eq!(date_time.as_raw(), date_time.set_offset(Offset::Fixed(3600)).as_raw());
+    /// ```
+    pub fn as_raw(&self) -> (i32, u64) {
+        (self.days, self.nanoseconds)
+    }
+
+    /// Returns the instant one second later, or `None` if it would be out of range.
+    ///
+    /// Unlike [`add_seconds`](TimeUtilities::add_seconds), this doesn't panic and only shifts the raw representation, which makes it cheap to step through ranges of instants.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let date_time = DateTime::from_ymdhms(2022, 12, 31, 23, 59, 59).unwrap();
+    /// assert_eq!("2023/01/01 00:00:00", date_time.next_second().unwrap().to_string());
+    /// assert_eq!(None, DateTime::from_ymdhms(5_879_611, 7, 12, 23, 59, 59).unwrap().next_second());
+    /// ```
+    pub fn next_second(&self) -> Option<Self> {
+        self.checked_step(NANOS_PER_SEC as i64)
+    }
+
+    /// Returns the instant one second earlier, or `None` if it would be out of range.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let date_time = DateTime::from_ymd(2023, 1, 1).unwrap();
+    /// assert_eq!("2022/12/31 23:59:59", date_time.previous_second().unwrap().to_string());
+    /// assert_eq!(None, DateTime::from_ymd(-5_879_611, 6, 23).unwrap().previous_second());
+    /// ```
+    pub fn previous_second(&self) -> Option<Self> {
+        self.checked_step(-(NANOS_PER_SEC as i64))
+    }
+
+    /// Returns the instant one nanosecond later, or `None` if this is the last representable instant.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, TimeUtilities};
+    /// let date_time = DateTime::from_ymd(2022, 5, 2).unwrap();
+    /// assert_eq!(1, date_time.next_nano().unwrap().nano());
+    /// assert_eq!(None, DateTime::from_raw(i32::MAX, 86_399_999_999_999).unwrap().next_nano());
+    /// ```
+    pub fn next_nano(&self) -> Option<Self> {
+        self.checked_step(1)
+    }
+
+    /// Returns the instant one nanosecond earlier, or `None` if this is the first representable instant.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, TimeUtilities};
+    /// let date_time = DateTime::from_ymd(2022, 5, 2).unwrap();
+    /// assert_eq!(999_999_999, date_time.previous_nano().unwrap().nano());
+    /// assert_eq!(None, DateTime::from_raw(i32::MIN, 0).unwrap().previous_nano());
+    /// ```
+    pub fn previous_nano(&self) -> Option<Self> {
+        self.checked_step(-1)
+    }
+
     /// Creates a new [`DateTime`] with the specified time.
     ///
     /// ```rust
@@ -236,135 +505,663 @@ impl DateTime {
         }
     }
 
-    /// Creates a new [`DateTime`] instance from an RFC 3339 timestamp string.
+    /// Creates a new [`DateTime`] with the specified date. The local time of day and the offset are kept.
+    ///
+    /// Panics if the resulting instant would be out of range. This can only happen for dates within a day of `-5879611-06-23` or `5879611-07-12`.
     ///
     /// ```rust
-    /// # use astrolabe::DateTime;
-    /// let date_time = DateTime::parse_rfc3339("2022-05-02T15:30:20Z").unwrap();
-    /// assert_eq!("2022/05/02 15:30:20", date_time.format("yyyy/MM/dd HH:mm:ss"));
+    /// # use astrolabe::{Date, DateTime, Offset, OffsetUtilities};
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 23, 30, 0)
+    ///     .unwrap()
+    ///     .as_offset(Offset::from_hms(-2, 0, 0).unwrap());
+    /// let date = Date::from_ymd(2022, 12, 24).unwrap();
+    /// assert_eq!(
+    ///     "2022-12-24 23:30:00 -02:00",
+    ///     date_time.replace_date(date).format("yyyy-MM-dd HH:mm:ss xxx")
+    /// );
     /// ```
-    pub fn parse_rfc3339(string: &str) -> Result<Self, AstrolabeError> {
-        if string.len() < 20 {
-            return Err(create_invalid_format(
-                "RFC 3339 string cannot be shorter than 20 chars".to_string(),
-            ));
-        }
+    pub fn replace_date(&self, date: Date) -> Self {
+        self.try_replace_date(date).unwrap_or_else(|_| {
+            panic!(
+                "Replacing the date with {} would result into an out of range datetime",
+                date
+            )
+        })
+    }
 
-        let year = string[0..4].parse::<i32>().map_err(|_| {
-            create_invalid_format("Failed parsing year from RFC 3339 string".to_string())
-        })?;
-        let month = string[5..7].parse::<u32>().map_err(|_| {
-            create_invalid_format("Failed parsing month from RFC 3339 string".to_string())
-        })?;
-        let day = string[8..10].parse::<u32>().map_err(|_| {
-            create_invalid_format("Failed parsing day from RFC 3339 string".to_string())
-        })?;
-        let hour = string[11..13].parse::<u32>().map_err(|_| {
-            create_invalid_format("Failed parsing hour from RFC 3339 string".to_string())
-        })?;
-        let minute = string[14..16].parse::<u32>().map_err(|_| {
-            create_invalid_format("Failed parsing minute from RFC 3339 string".to_string())
-        })?;
-        let second = string[17..19].parse::<u32>().map_err(|_| {
-            create_invalid_format("Failed parsing second from RFC 3339 string".to_string())
-        })?;
+    /// Replaces the local date like [`DateTime::replace_date`], but returns an [`OutOfRange`](AstrolabeError::OutOfRange) error instead of panicking
+    fn try_replace_date(&self, date: Date) -> Result<Self, AstrolabeError> {
+        let offset_seconds = self.offset.resolve();
+        let (_, nanoseconds) = add_offset_to_dn(self.days, self.nanoseconds, offset_seconds);
+        let nanos = days_nanos_to_nanos(date.days, nanoseconds)
+            - offset_seconds as i128 * NANOS_PER_SEC as i128;
+        let (days, nanoseconds) = nanos_to_days_nanos(nanos)?;
 
-        let (nanos, offset) = if string.chars().nth(19).unwrap() == '.' {
-            let nanos_string = string[20..]
-                .chars()
-                .take_while(|&char| char != 'Z' && char != '+' && char != '-')
-                .collect::<String>();
-            let nanos = nanos_string.parse::<u64>().map_err(|_| {
-                create_invalid_format("Failed parsing subseconds from RFC 3339 string".to_string())
-            })? * (1000000000 / 10_u64.pow(nanos_string.len() as u32));
+        Ok(Self {
+            days,
+            nanoseconds,
+            offset: self.offset,
+        })
+    }
 
-            let offset_substring = string[20..]
-                .chars()
-                .position(|char| char == 'Z' || char == '+' || char == '-')
-                .ok_or_else(|| {
-                    create_invalid_format("Failed parsing offset from RFC 3339 string".to_string())
-                })?;
-            let offset = parse_offset(&string[20 + offset_substring..])?;
+    /// Sets the year like [`set_year`](DateUtilities::set_year), but consumes the instance so calls can be chained with `?`.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, errors::AstrolabeError};
+    /// # fn main() -> Result<(), AstrolabeError> {
+    /// let date_time = DateTime::from_ymd(2022, 5, 2)?
+    ///     .with_year(2025)?
+    ///     .with_month(1)?
+    ///     .with_hms(9, 0, 0)?;
+    /// assert_eq!("2025-01-02 09:00:00", date_time.format("yyyy-MM-dd HH:mm:ss"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_year(self, year: i32) -> Result<Self, AstrolabeError> {
+        self.set_year(year)
+    }
 
-            (nanos, offset)
-        } else {
-            let offset = parse_offset(&string[19..])?;
-            (0, offset)
-        };
+    /// Sets the month like [`set_month`](DateUtilities::set_month), but consumes the instance so calls can be chained with `?`.
+    pub fn with_month(self, month: u32) -> Result<Self, AstrolabeError> {
+        self.set_month(month)
+    }
 
-        let days = date_to_days(year, month, day)?;
+    /// Sets the day of the month like [`set_day`](DateUtilities::set_day), but consumes the instance so calls can be chained with `?`.
+    pub fn with_day(self, day: u32) -> Result<Self, AstrolabeError> {
+        self.set_day(day)
+    }
+
+    /// Sets the nanosecond like [`set_nano`](TimeUtilities::set_nano), but consumes the instance so calls can be chained with `?`.
+    pub fn with_nano(self, nano: u32) -> Result<Self, AstrolabeError> {
+        self.set_nano(nano)
+    }
+
+    /// Sets the local date to the given year, month and day at once. The local time of day and the offset are kept.
+    ///
+    /// Unlike setting the year, month and day one after another, only the resulting date has to be valid. Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the date is invalid or the resulting instant would be out of range.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, DateUtilities};
+    /// let date_time = DateTime::from_ymdhms(2020, 2, 29, 12, 0, 0).unwrap();
+    /// // 2021-02-29 doesn't exist
+    /// assert!(date_time.set_year(2021).is_err());
+    /// assert_eq!(
+    ///     "2021-03-01 12:00:00",
+    ///     date_time.with_date(2021, 3, 1).unwrap().format("yyyy-MM-dd HH:mm:ss")
+    /// );
+    /// assert!(date_time.with_date(2021, 2, 29).is_err());
+    /// ```
+    pub fn with_date(self, year: i32, month: u32, day: u32) -> Result<Self, AstrolabeError> {
+        self.try_replace_date(Date::from_ymd(year, month, day)?)
+    }
+
+    /// Sets the local hour, minute and second at once. Subsecond values, the date and the offset are kept.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the time is invalid or the resulting instant would be out of range.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap();
+    /// assert_eq!("09:00:00", date_time.with_hms(9, 0, 0).unwrap().format("HH:mm:ss"));
+    /// assert!(date_time.with_hms(24, 0, 0).is_err());
+    /// ```
+    pub fn with_hms(self, hour: u32, minute: u32, second: u32) -> Result<Self, AstrolabeError> {
         let seconds = time_to_day_seconds(hour, minute, second)? as u64;
+        let offset_seconds = self.offset.resolve();
+        let (days, nanoseconds) = add_offset_to_dn(self.days, self.nanoseconds, offset_seconds);
+        let nanos =
+            days_nanos_to_nanos(days, seconds * NANOS_PER_SEC + nanoseconds % NANOS_PER_SEC)
+                - offset_seconds as i128 * NANOS_PER_SEC as i128;
+        let (days, nanoseconds) = nanos_to_days_nanos(nanos)?;
 
         Ok(Self {
             days,
-            nanoseconds: seconds * NANOS_PER_SEC + nanos,
-            offset: Offset::default(),
+            nanoseconds,
+            offset: self.offset,
+        })
+    }
+
+    /// Returns the local date in the offset of the instance.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, DateTime, Offset, OffsetUtilities};
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 23, 30, 0).unwrap();
+    /// assert_eq!(Date::from_ymd(2022, 5, 2).unwrap(), date_time.date());
+    /// let date_time = date_time.set_offset(Offset::from_hms(2, 0, 0).unwrap());
+    /// assert_eq!(Date::from_ymd(2022, 5, 3).unwrap(), date_time.date());
+    /// ```
+    pub fn date(&self) -> Date {
+        let days = self.resolve_local().0;
+        Date { days }
+    }
+
+    /// Returns the time of day with the offset of the instance.
+    ///
+    /// Together with [`DateTime::date`], this round-trips through [`Date::at`].
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Offset, OffsetUtilities};
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 23, 30, 0)
+    ///     .unwrap()
+    ///     .set_offset(Offset::from_hms(2, 0, 0).unwrap());
+    /// assert_eq!("01:30:00", date_time.time().to_string());
+    /// assert_eq!(date_time, date_time.date().at(date_time.time()));
+    /// ```
+    pub fn time(&self) -> Time {
+        Time {
+            nanoseconds: self.nanoseconds,
+            offset: self.offset,
         }
-        .as_offset(Offset::Fixed(offset)))
     }
 
-    /// Format as an RFC 3339 timestamp (`2022-05-02T15:30:20Z`).
+    /// Returns the value of the given unit. Useful if the unit is only known at runtime.
     ///
-    /// Use the [`Precision`] enum to specify decimal places after seconds:
-    /// * [`Precision::Seconds`] -> `2022-05-02T15:30:20Z`
-    /// * [`Precision::Centis`] -> `2022-05-02T15:30:20.00Z`
-    /// * [`Precision::Millis`] -> `2022-05-02T15:30:20.000Z`
-    /// * [`Precision::Micros`] -> `2022-05-02T15:30:20.000000Z`
-    /// * [`Precision::Nanos`] -> `2022-05-02T15:30:20.000000000Z`
+    /// ```rust
+    /// # use astrolabe::{DateTime, DateUnit, Unit};
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap();
+    /// assert_eq!(2022, date_time.get_unit(Unit::Year));
+    /// assert_eq!(32, date_time.get_unit(Unit::Minute));
+    /// assert_eq!(5, date_time.get_unit(DateUnit::Month.into()));
+    /// ```
+    pub fn get_unit(&self, unit: Unit) -> i64 {
+        match unit {
+            Unit::Year => self.year() as i64,
+            Unit::Month => self.month() as i64,
+            Unit::Day => self.day() as i64,
+            Unit::Hour => self.hour() as i64,
+            Unit::Minute => self.minute() as i64,
+            Unit::Second => self.second() as i64,
+            Unit::Milli => self.milli() as i64,
+            Unit::Micro => self.micro() as i64,
+            Unit::Nano => self.nano() as i64,
+        }
+    }
+
+    /// Sets the given unit to the provided value, like [`set_year`](DateUtilities::set_year), [`set_hour`](TimeUtilities::set_hour) and the other `set_` functions.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided value is out of range.
     ///
     /// ```rust
-    /// # use astrolabe::{DateTime, Precision};
-    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 15, 30, 20).unwrap();
-    /// assert_eq!("2022-05-02T15:30:20Z", date_time.format_rfc3339(Precision::Seconds));
-    /// // Equivalent to:
-    /// assert_eq!("2022-05-02T15:30:20Z", date_time.format("yyyy-MM-ddTHH:mm:ssXXX"));
+    /// # use astrolabe::{DateTime, Unit};
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap();
+    /// assert_eq!(
+    ///     "2022/05/24 12:32:01",
+    ///     date_time.set_unit(Unit::Day, 24).unwrap().to_string()
+    /// );
+    /// assert!(date_time.set_unit(Unit::Hour, 24).is_err());
     /// ```
-    pub fn format_rfc3339(&self, precision: Precision) -> String {
-        match precision {
-            Precision::Seconds => self.format("yyyy-MM-ddTHH:mm:ssXXX"),
-            Precision::Centis => self.format("yyyy-MM-ddTHH:mm:ss.nnXXX"),
-            Precision::Millis => self.format("yyyy-MM-ddTHH:mm:ss.nnnXXX"),
-            Precision::Micros => self.format("yyyy-MM-ddTHH:mm:ss.nnnnXXX"),
-            Precision::Nanos => self.format("yyyy-MM-ddTHH:mm:ss.nnnnnXXX"),
+    pub fn set_unit(&self, unit: Unit, value: i64) -> Result<Self, AstrolabeError> {
+        match unit {
+            Unit::Year => self.set_year(unit.value(value)?),
+            Unit::Month => self.set_month(unit.value(value)?),
+            Unit::Day => self.set_day(unit.value(value)?),
+            Unit::Hour => self.set_hour(unit.value(value)?),
+            Unit::Minute => self.set_minute(unit.value(value)?),
+            Unit::Second => self.set_second(unit.value(value)?),
+            Unit::Milli => self.set_milli(unit.value(value)?),
+            Unit::Micro => self.set_micro(unit.value(value)?),
+            Unit::Nano => self.set_nano(unit.value(value)?),
         }
     }
 
-    /// Parses a string with a given format and creates a new [`DateTime`] instance from it. See [`DateTime::format`] for a list of available symbols.
+    /// Adds the provided amount of the given unit, or subtracts it if the amount is negative. Same as the `add_` and `sub_` functions, e.g. [`add_years`](DateUtilities::add_years) or [`add_hours`](TimeUtilities::add_hours), but returns an error instead of panicking.
     ///
-    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the given string could not be parsed with the given format.
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the result would be an out of range date.
     ///
     /// ```rust
-    /// # use astrolabe::DateTime;
-    /// let date_time = DateTime::parse("2022-05-02 12:32:01", "yyyy-MM-dd HH:mm:ss").unwrap();
-    /// assert_eq!("2022/05/02 12:32:01", date_time.format("yyyy/MM/dd HH:mm:ss"));
+    /// # use astrolabe::{DateTime, Unit};
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap();
+    /// assert_eq!(
+    ///     "2022/05/01 23:32:01",
+    ///     date_time.apply_unit(Unit::Hour, -13).unwrap().to_string()
+    /// );
+    /// assert!(date_time.apply_unit(Unit::Year, 6_000_000).is_err());
     /// ```
-    pub fn parse(string: &str, format: &str) -> Result<Self, AstrolabeError> {
-        let parts = parse_format_string(format);
+    pub fn apply_unit(&self, unit: Unit, amount: i64) -> Result<Self, AstrolabeError> {
+        let time_unit = match unit {
+            Unit::Year => return self.apply_date_unit(DateUnit::Year, amount),
+            Unit::Month => return self.apply_date_unit(DateUnit::Month, amount),
+            Unit::Day => return self.apply_date_unit(DateUnit::Day, amount),
+            Unit::Hour => TimeUnit::Hour,
+            Unit::Minute => TimeUnit::Minute,
+            Unit::Second => TimeUnit::Second,
+            Unit::Milli => TimeUnit::Milli,
+            Unit::Micro => TimeUnit::Micro,
+            Unit::Nano => TimeUnit::Nano,
+        };
 
-        let mut date = ParsedDate::default();
-        let mut time = ParsedTime::default();
-        let mut string = string.to_string();
+        let total_nanos = days_nanos_to_nanos(self.days, self.nanoseconds)
+            + amount as i128 * time_unit.nanos() as i128;
+        let (days, nanoseconds) = nanos_to_days_nanos(total_nanos)?;
 
-        for part in parts {
-            // Escaped apostrophes
-            if part.starts_with('\u{0000}') {
-                string.replace_range(0..part.len(), "");
-                continue;
+        Ok(Self {
+            days,
+            nanoseconds,
+            offset: self.offset,
+        })
+    }
+
+    /// Applies a date unit to the days like the `add_` and `sub_` functions of [`DateUtilities`], keeping the time
+    fn apply_date_unit(&self, unit: DateUnit, amount: i64) -> Result<Self, AstrolabeError> {
+        let date = Date { days: self.days }.apply_unit(unit, amount)?;
+        Ok(Self {
+            days: date.days,
+            nanoseconds: self.nanoseconds,
+            offset: self.offset,
+        })
+    }
+
+    /// Sets the offset. Fallible version of [`set_offset`](OffsetUtilities::set_offset).
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the local date in the new offset would be out of range.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Offset};
+    /// let max = DateTime::from_ymdhms(5_879_611, 7, 12, 23, 59, 59).unwrap();
+    /// assert!(max.try_set_offset(Offset::from_hms(-1, 0, 0).unwrap()).is_ok());
+    /// assert!(max.try_set_offset(Offset::from_hms(1, 0, 0).unwrap()).is_err());
+    /// ```
+    pub fn try_set_offset(&self, offset: Offset) -> Result<Self, AstrolabeError> {
+        let offset_nanos = offset.resolve() as i128 * NANOS_PER_SEC as i128;
+        nanos_to_days_nanos(self.as_nanos() + offset_nanos).map_err(|_| {
+            create_custom_oor("Offset would result in an out of range date".to_string())
+        })?;
+
+        Ok(Self {
+            days: self.days,
+            nanoseconds: self.nanoseconds,
+            offset,
+        })
+    }
+
+    /// Sets the offset, assuming the current instance has the provided offset applied. Fallible version of [`as_offset`](OffsetUtilities::as_offset).
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the date in `UTC` would be out of range.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Offset};
+    /// let max = DateTime::from_ymdhms(5_879_611, 7, 12, 23, 59, 59).unwrap();
+    /// assert!(max.try_as_offset(Offset::from_hms(1, 0, 0).unwrap()).is_ok());
+    /// assert!(max.try_as_offset(Offset::from_hms(-1, 0, 0).unwrap()).is_err());
+    /// ```
+    pub fn try_as_offset(&self, offset: Offset) -> Result<Self, AstrolabeError> {
+        let offset_nanos = offset.resolve() as i128 * NANOS_PER_SEC as i128;
+        let (days, nanoseconds) =
+            nanos_to_days_nanos(self.as_nanos() - offset_nanos).map_err(|_| {
+                create_custom_oor("Offset would result in an out of range date".to_string())
+            })?;
+
+        Ok(Self {
+            days,
+            nanoseconds,
+            offset,
+        })
+    }
+
+    /// Creates a new [`DateTime`] instance from an RFC 3339 timestamp string.
+    ///
+    /// Parsing is strict: the date and time have to be separated by `T`, subseconds by `.` and the offset has to be `Z` or `+hh:mm`/`-hh:mm`. Use [`DateTime::parse_rfc3339_lenient`] to accept common ISO 8601 variants.
+    ///
+    /// Subseconds need at least one digit. Digits after the ninth are truncated, as they are beyond nanosecond precision.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let date_time = DateTime::parse_rfc3339("2022-05-02T15:30:20Z").unwrap();
+    /// assert_eq!("2022/05/02 15:30:20", date_time.format("yyyy/MM/dd HH:mm:ss"));
+    ///
+    /// assert!(DateTime::parse_rfc3339("2022-05-02 15:30:20Z").is_err());
+    /// ```
+    pub fn parse_rfc3339(string: &str) -> Result<Self, AstrolabeError> {
+        Self::parse_rfc3339_with(string, false)
+    }
+
+    /// Creates a new [`DateTime`] instance from an RFC 3339 timestamp string, also accepting common ISO 8601 variants:
+    /// * `T`, `t` or a space between the date and time (`2022-05-02 15:30:20Z`)
+    /// * `.` or `,` before subseconds (`2022-05-02T15:30:20,123Z`)
+    /// * Offsets without a colon (`2022-05-02T15:30:20+0200`)
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let date_time = DateTime::parse_rfc3339_lenient("2022-05-02 15:30:20,123+0200").unwrap();
+    /// assert_eq!(
+    ///     "2022-05-02T15:30:20.123+02:00",
+    ///     date_time.format("yyyy-MM-ddTHH:mm:ss.nnnxxx")
+    /// );
+    /// ```
+    pub fn parse_rfc3339_lenient(string: &str) -> Result<Self, AstrolabeError> {
+        Self::parse_rfc3339_with(string, true)
+    }
+
+    /// Parses an RFC 3339 timestamp string, accepting the variants of [`DateTime::parse_rfc3339_lenient`] if `lenient` is set
+    fn parse_rfc3339_with(string: &str, lenient: bool) -> Result<Self, AstrolabeError> {
+        if string.len() < 20 {
+            return Err(create_invalid_format(
+                "RFC 3339 string cannot be shorter than 20 chars".to_string(),
+            ));
+        }
+        if let Some(position) = string.find(|char: char| !char.is_ascii()) {
+            let length = string[position..].chars().next().map_or(1, char::len_utf8);
+            return Err(create_positioned_invalid_format(
+                format!(
+                    "RFC 3339 string can only contain ASCII characters. Found a non-ASCII character at position {}.",
+                    position
+                ),
+                position,
+                &string[position..position + length],
+            ));
+        }
+
+        let date_time_separators = if lenient { "Tt " } else { "T" };
+        for (position, separators) in [
+            (4, "-"),
+            (7, "-"),
+            (10, date_time_separators),
+            (13, ":"),
+            (16, ":"),
+        ] {
+            if !separators.as_bytes().contains(&string.as_bytes()[position]) {
+                return Err(create_positioned_invalid_format(
+                    format!(
+                        "Failed parsing separator at position {} from RFC 3339 string. Separator has to be {}.",
+                        position,
+                        separators
+                            .chars()
+                            .map(|separator| format!("'{}'", separator))
+                            .collect::<Vec<_>>()
+                            .join(" or ")
+                    ),
+                    position,
+                    &separators[..1],
+                ));
+            }
+        }
+
+        let year = string[0..4].parse::<i32>().map_err(|_| {
+            create_positioned_invalid_format(
+                "Failed parsing year from RFC 3339 string".to_string(),
+                0,
+                "yyyy",
+            )
+        })?;
+        let month = string[5..7].parse::<u32>().map_err(|_| {
+            create_positioned_invalid_format(
+                "Failed parsing month from RFC 3339 string".to_string(),
+                5,
+                "MM",
+            )
+        })?;
+        let day = string[8..10].parse::<u32>().map_err(|_| {
+            create_positioned_invalid_format(
+                "Failed parsing day from RFC 3339 string".to_string(),
+                8,
+                "dd",
+            )
+        })?;
+        let hour = string[11..13].parse::<u32>().map_err(|_| {
+            create_positioned_invalid_format(
+                "Failed parsing hour from RFC 3339 string".to_string(),
+                11,
+                "HH",
+            )
+        })?;
+        let minute = string[14..16].parse::<u32>().map_err(|_| {
+            create_positioned_invalid_format(
+                "Failed parsing minute from RFC 3339 string".to_string(),
+                14,
+                "mm",
+            )
+        })?;
+        let second = string[17..19].parse::<u32>().map_err(|_| {
+            create_positioned_invalid_format(
+                "Failed parsing second from RFC 3339 string".to_string(),
+                17,
+                "ss",
+            )
+        })?;
+
+        let mut rest = &string[19..];
+
+        let nanos = if rest.starts_with('.') || (lenient && rest.starts_with(',')) {
+            let digits = rest[1..]
+                .chars()
+                .take_while(|char| char.is_ascii_digit())
+                .collect::<String>();
+            if digits.is_empty() {
+                return Err(create_positioned_invalid_format(
+                    "Failed parsing subseconds from RFC 3339 string. Expected at least one digit after the decimal separator.".to_string(),
+                    20,
+                    "n",
+                ));
             }
+            rest = &rest[digits.len() + 1..];
+            parse_subseconds(&digits).map_err(|e| e.at_position(20, "n"))?
+        } else {
+            0
+        };
 
-            // Escaped parts
-            if part.starts_with('\'') {
-                string.replace_range(0..part.len() - if part.ends_with('\'') { 2 } else { 1 }, "");
-                continue;
+        let offset_position = string.len() - rest.len();
+        let offset_format = if lenient && rest.get(3..4) != Some(":") {
+            OffsetFormat {
+                with_colon: false,
+                ..OffsetFormat::RFC3339
             }
+        } else {
+            OffsetFormat::RFC3339
+        };
+        let offset = parse_offset(&mut rest, offset_format)
+            .map_err(|e| e.at_position(offset_position, "XXX"))?;
+        if !rest.is_empty() {
+            return Err(create_invalid_format(format!(
+                "Unexpected characters after the offset of the RFC 3339 string: '{}'",
+                rest
+            )));
+        }
+
+        let days = date_to_days(year, month, day)?;
+        let seconds = time_to_day_seconds(hour, minute, second)? as u64;
+
+        Self {
+            days,
+            nanoseconds: seconds * NANOS_PER_SEC + nanos,
+            offset: Offset::default(),
+        }
+        .try_as_offset(Offset::Fixed(offset))
+    }
+
+    /// Format as an RFC 3339 timestamp (`2022-05-02T15:30:20Z`).
+    ///
+    /// Use the [`Precision`] enum to specify decimal places after seconds:
+    /// * [`Precision::Seconds`] -> `2022-05-02T15:30:20Z`
+    /// * [`Precision::Decis`] -> `2022-05-02T15:30:20.0Z`
+    /// * [`Precision::Centis`] -> `2022-05-02T15:30:20.00Z`
+    /// * [`Precision::Millis`] -> `2022-05-02T15:30:20.000Z`
+    /// * [`Precision::Micros`] -> `2022-05-02T15:30:20.000000Z`
+    /// * [`Precision::Nanos`] -> `2022-05-02T15:30:20.000000000Z`
+    /// * [`Precision::Digits`] -> Any number of decimal places from `1` to `9`
+    /// * [`Precision::Auto`] -> As few decimal places as needed, `2022-05-02T15:30:20Z` or `2022-05-02T15:30:20.5Z`
+    ///
+    /// Additional digits are truncated.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Precision, TimeUtilities};
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 15, 30, 20).unwrap();
+    /// assert_eq!("2022-05-02T15:30:20Z", date_time.format_rfc3339(Precision::Seconds));
+    /// // Equivalent to:
+    /// assert_eq!("2022-05-02T15:30:20Z", date_time.format("yyyy-MM-ddTHH:mm:ssXXX"));
+    ///
+    /// let date_time = date_time.set_nano(123_456_789).unwrap();
+    /// assert_eq!("2022-05-02T15:30:20.1234Z", date_time.format_rfc3339(Precision::Digits(4)));
+    ///
+    /// let date_time = date_time.set_nano(120_000_000).unwrap();
+    /// assert_eq!("2022-05-02T15:30:20.12Z", date_time.format_rfc3339(Precision::Auto));
+    /// ```
+    pub fn format_rfc3339(&self, precision: Precision) -> String {
+        let (output, length) = self.rfc3339_bytes(precision);
+        // Using unwrap because only ASCII bytes are written
+        std::str::from_utf8(&output[..length]).unwrap().to_string()
+    }
+
+    /// Format as an RFC 3339 timestamp like [`DateTime::format_rfc3339`], but write the ASCII bytes into the provided buffer instead of allocating a [`String`]. Returns the number of written bytes.
+    ///
+    /// A buffer with a length of [`DateTime::rfc3339_max_len`] fits every timestamp with the given precision. Returns a [`BufferTooSmall`] error if the buffer is too short for this timestamp, in which case the buffer is left untouched.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Precision};
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 15, 30, 20).unwrap();
+    /// let mut buf = [0; DateTime::rfc3339_max_len(Precision::Millis)];
+    /// let length = date_time.format_rfc3339_buf(Precision::Millis, &mut buf).unwrap();
+    /// assert_eq!(b"2022-05-02T15:30:20.000Z", &buf[..length]);
+    /// ```
+    pub fn format_rfc3339_buf(
+        &self,
+        precision: Precision,
+        buf: &mut [u8],
+    ) -> Result<usize, BufferTooSmall> {
+        let (output, length) = self.rfc3339_bytes(precision);
+
+        if buf.len() < length {
+            return Err(BufferTooSmall {
+                required: length,
+                available: buf.len(),
+            });
+        }
+        buf[..length].copy_from_slice(&output[..length]);
+        Ok(length)
+    }
+
+    /// Returns the maximum length of an RFC 3339 timestamp with the given precision, see [`DateTime::format_rfc3339_buf`].
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Precision};
+    /// assert_eq!(29, DateTime::rfc3339_max_len(Precision::Seconds));
+    /// assert_eq!(39, DateTime::rfc3339_max_len(Precision::Nanos));
+    /// ```
+    pub const fn rfc3339_max_len(precision: Precision) -> usize {
+        // Year with sign (8), date and time separators and digits (15), offset (6)
+        let decimals = precision.digits() as usize;
+        29 + if decimals > 0 { decimals + 1 } else { 0 }
+    }
+
+    /// Parses a string with a given format and creates a new [`DateTime`] instance from it. See [`DateTime::format`] for a list of available symbols.
+    ///
+    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the given string could not be parsed with the given format, or an [`OutOfRange`](AstrolabeError::OutOfRange) error if a parsed value is out of range (E.g. minute `60` or hour `13` for `h`).
+    ///
+    /// A parsed BC era (`G`) turns a positive year negative, e.g. `BC 2022` is parsed as the year `-2022`. `parse_checked` rejects a signed year together with an era.
+    ///
+    /// If a day of year (`D`) is parsed, it takes precedence over a parsed month and day of month, no matter in which order they appear in the format. [`DateTime::parse_checked`](DateTime::parse_checked) returns an error instead if they don't match. If the parsed fields don't form a valid date, e.g. day `31` in February, the returned [`OutOfRange`](AstrolabeError::OutOfRange) error contains the [`span`](crate::errors::OutOfRange::span) of the invalid value in the parsed string.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let date_time = DateTime::parse("2022-05-02 12:32:01", "yyyy-MM-dd HH:mm:ss").unwrap();
+    /// assert_eq!("2022/05/02 12:32:01", date_time.format("yyyy/MM/dd HH:mm:ss"));
+    /// ```
+    #[doc(alias = "strptime")]
+    pub fn parse(string: &str, format: &str) -> Result<Self, AstrolabeError> {
+        Self::parse_with(string, format, &ParseOptions::default())
+    }
+
+    /// Parses a string with a given format like [`DateTime::parse`], using the names of the given [`Locale`].
+    ///
+    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the given string could not be parsed with the given format and locale.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Locale};
+    /// let date_time = DateTime::parse_localized(
+    ///     "Mittwoch, 2. März 2022 12:00",
+    ///     "eeee, d. MMMM yyyy HH:mm",
+    ///     &Locale::GERMAN,
+    /// )
+    /// .unwrap();
+    /// assert_eq!("2022/03/02 12:00:00", date_time.to_string());
+    /// ```
+    pub fn parse_localized(
+        string: &str,
+        format: &str,
+        locale: &Locale,
+    ) -> Result<Self, AstrolabeError> {
+        Self::parse_with(
+            string,
+            format,
+            &ParseOptions {
+                locale: *locale,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Parses a string with a given format like [`DateTime::parse`], using custom [`ParseOptions`].
+    ///
+    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the given string could not be parsed with the given format and options.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, ParseOptions, PeriodTokens};
+    /// let options = ParseOptions {
+    ///     period_tokens: Some(PeriodTokens::new(&["A"], &["P"])),
+    ///     ..Default::default()
+    /// };
+    /// let date_time = DateTime::parse_with("2022-05-02 09:30P", "yyyy-MM-dd hh:mma", &options).unwrap();
+    /// assert_eq!("2022/05/02 21:30:00", date_time.format("yyyy/MM/dd HH:mm:ss"));
+    /// ```
+    pub fn parse_with(
+        string: &str,
+        format: &str,
+        options: &ParseOptions,
+    ) -> Result<Self, AstrolabeError> {
+        match parse_fixed_width(string, format) {
+            Some(parsed) => Ok(Self {
+                days: parsed.days,
+                nanoseconds: parsed.nanoseconds,
+                ..Default::default()
+            }),
+            None => Self::parse_generic(string, format, options),
+        }
+    }
+
+    /// Parses a string with any format string, used if [`parse_fixed_width`] doesn't apply
+    pub(crate) fn parse_generic(
+        mut string: &str,
+        format: &str,
+        options: &ParseOptions,
+    ) -> Result<Self, AstrolabeError> {
+        let parts = parse_format_string(format);
+
+        let mut date = ParsedDate::default();
+        let mut time = ParsedTime::default();
+        let input = string;
+        let length = string.len();
+
+        for part in parts {
+            let part = match part {
+                FormatPart::Symbol(part) => part,
+                // Escaped apostrophes
+                FormatPart::EscapedQuote => {
+                    string = &string[1..];
+                    continue;
+                }
+                // Escaped parts
+                FormatPart::Literal(literal) => {
+                    string = &string[literal.len()..];
+                    continue;
+                }
+            };
 
-            let parsed_part = parse_part(&part, &mut string)?;
+            let position = length - string.len();
+            let parsed_part = parse_part(part, &mut string, options)
+                .map_err(|e| e.at_position(position, part))?;
             if let Some(parsed_part) = parsed_part {
+                date.set_span(&parsed_part.unit, position..length - string.len());
                 match parsed_part.unit {
-                    ParseUnit::Year => date.year = Some(parsed_part.value as i32),
+                    ParseUnit::Era => date.era = Some(Era::from_index(parsed_part.value)),
+                    ParseUnit::Year => date.set_year(parsed_part.value as i32, &input[position..]),
+                    ParseUnit::Quarter => date.quarter = Some(parsed_part.value as u32),
                     ParseUnit::Month => date.month = Some(parsed_part.value as u32),
                     ParseUnit::DayOfMonth => date.day_of_month = Some(parsed_part.value as u32),
                     ParseUnit::DayOfYear => date.day_of_year = Some(parsed_part.value as u32),
+                    ParseUnit::Weekday => date.weekday = Some(parsed_part.value as u32),
+                    ParseUnit::WeekOfYear => date.week_of_year = Some(parsed_part.value as u32),
+                    ParseUnit::OrdinalSuffix => date
+                        .check_ordinal_suffix(parsed_part.value)
+                        .map_err(|e| e.at_position(position, part))?,
                     ParseUnit::Hour => time.hour = Some(parsed_part.value as u64),
                     ParseUnit::PeriodHour => time.period_hour = Some(parsed_part.value as u64),
                     ParseUnit::Period => {
@@ -376,58 +1173,82 @@ impl DateTime {
                     }
                     ParseUnit::Minute => time.minute = Some(parsed_part.value as u64),
                     ParseUnit::Second => time.second = Some(parsed_part.value as u64),
-                    ParseUnit::Decis => time.decis = Some(parsed_part.value as u64),
-                    ParseUnit::Centis => time.centis = Some(parsed_part.value as u64),
-                    ParseUnit::Millis => time.millis = Some(parsed_part.value as u64),
-                    ParseUnit::Micros => time.micros = Some(parsed_part.value as u64),
                     ParseUnit::Nanos => time.nanos = Some(parsed_part.value as u64),
                     ParseUnit::Offset => time.offset = Some(parsed_part.value as i32),
                 };
             };
         }
 
-        // Use day of year if present, otherwise use month + day of month
-        let mut date_time = if date.day_of_year.is_some() {
-            let days = year_doy_to_days(date.year.unwrap_or(1), date.day_of_year.unwrap(), false)?;
-            Self {
-                days,
-                ..Default::default()
-            }
-        } else {
-            Self::from_ymd(
-                date.year.unwrap_or(1),
-                date.month.unwrap_or(1),
-                date.day_of_month.unwrap_or(1),
-            )?
+        let mut date_time = Self {
+            days: date.days()?,
+            ..Default::default()
         };
 
-        let mut nanoseconds = 0;
+        if options.reject_conflicts {
+            date.check_conflicts(date_time.days, options.locale.first_weekday)?;
+            time.check_conflicts()?;
+        }
 
-        if time.hour.is_some() {
-            nanoseconds += time.hour.unwrap_or(0) * SECS_PER_HOUR_U64 * NANOS_PER_SEC;
-        } else {
-            nanoseconds += (time.period_hour.unwrap_or(0)
-                + time.period.unwrap_or(Period::AM) as u64)
-                * SECS_PER_HOUR_U64
-                * NANOS_PER_SEC;
-        }
-        nanoseconds += time.minute.unwrap_or(0) * SECS_PER_MINUTE_U64 * NANOS_PER_SEC;
-        nanoseconds += time.second.unwrap_or(0) * NANOS_PER_SEC;
-        nanoseconds += time.decis.unwrap_or(0) * 100_000_000;
-        nanoseconds += time.centis.unwrap_or(0) * 10_000_000;
-        nanoseconds += time.millis.unwrap_or(0) * 1_000_000;
-        nanoseconds += time.micros.unwrap_or(0) * 1_000;
-        nanoseconds += time.nanos.unwrap_or(0);
+        let nanoseconds = time.nanoseconds()?;
 
         date_time = date_time.set_time(Time::from_nanos(nanoseconds)?);
 
         if let Some(offset) = time.offset {
-            date_time = date_time.as_offset(Offset::from_seconds(offset)?);
+            date_time = date_time.try_as_offset(Offset::from_seconds(offset)?)?;
         }
 
         Ok(date_time)
     }
 
+    /// Parses a string with a given format like [`DateTime::parse`], but returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if parsed fields contradict each other, e.g. if the weekday name doesn't match the date or a 24-hour hour doesn't match a 12-hour hour with its day period. See [`ParseOptions::reject_conflicts`].
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// assert!(DateTime::parse_checked("Tuesday, 2022-05-02 01 PM 13", "eeee, yyyy-MM-dd hh a HH").is_err());
+    /// let date_time = DateTime::parse_checked("Monday, 2022-05-02 01 PM 13", "eeee, yyyy-MM-dd hh a HH").unwrap();
+    /// assert_eq!("2022/05/02 13:00:00", date_time.format("yyyy/MM/dd HH:mm:ss"));
+    /// ```
+    pub fn parse_checked(string: &str, format: &str) -> Result<Self, AstrolabeError> {
+        Self::parse_with(
+            string,
+            format,
+            &ParseOptions {
+                reject_conflicts: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Parses a string with a given format like [`DateTime::parse`], falling back to `default` if the string could not be parsed. Empty strings always fall back.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let default = DateTime::from_ymdhms(2022, 1, 1, 0, 0, 0).unwrap();
+    /// let date_time = DateTime::parse_or("2022-05-02 12:32", "yyyy-MM-dd HH:mm", default);
+    /// assert_eq!("2022/05/02 12:32:00", date_time.to_string());
+    /// assert_eq!(default, DateTime::parse_or("", "yyyy-MM-dd HH:mm", default));
+    /// ```
+    pub fn parse_or(string: &str, format: &str, default: Self) -> Self {
+        if string.is_empty() {
+            return default;
+        }
+        Self::parse(string, format).unwrap_or(default)
+    }
+
+    /// Parses a string with a given format like [`DateTime::parse`], falling back to [`DateTime::now`] if the string could not be parsed. Empty strings always fall back.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let last_seen = DateTime::parse_or_now("", "yyyy-MM-dd HH:mm");
+    /// assert!(last_seen <= DateTime::now());
+    /// ```
+    pub fn parse_or_now(string: &str, format: &str) -> Self {
+        if string.is_empty() {
+            return Self::now();
+        }
+        Self::parse(string, format).unwrap_or_else(|_| Self::now())
+    }
+
     /// Formatting with format strings based on [Unicode Date Field Symbols](https://www.unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table).
     ///
     /// Please note that not all symbols are implemented. If you need something that is not implemented, please open an issue on [GitHub](https://github.com/GiyoMoon/astrolabe/issues) describing your need.
@@ -444,6 +1265,9 @@ impl DateTime {
     /// |                            | yyy      | 002, 020, 201, 2017, 20173     |                                          |
     /// |                            | yyyy     | 0002, 0020, 0201, 2017, 20173  |                                          |
     /// |                            | yyyyy+   | ...                            | Unlimited length,<br/>padded with zeros. |
+    /// |                            | +y+      | +2017, -34, +002017            | Always signed,<br/>padded with zeros.    |
+    /// | extended year              | u        | -1, 0, 2017                    | Astronomical year                        |
+    /// |                            | uu+      | -01, 00, 2017                  | Padded with zeros                        |
     /// | quarter                    | q        | 2                              | *                                        |
     /// |                            | qq       | 02                             |                                          |
     /// |                            | qqq      | Q2                             |                                          |
@@ -456,11 +1280,14 @@ impl DateTime {
     /// |                            | MMMMM    | S                              |                                          |
     /// | week                       | w        | 8, 27                          | Week of year                             |
     /// |                            | ww       | 08, 27                         | *                                        |
+    /// |                            | W        | 1, 6                           | Week of month, starting on Sunday        |
     /// | days                       | d        | 1                              | Day of month                             |
     /// |                            | dd       | 01                             | *                                        |
     /// |                            | D        | 1, 24, 135                     | Day of year, *                           |
     /// |                            | DD       | 01, 24, 135                    |                                          |
     /// |                            | DDD      | 001, 024, 135                  |                                          |
+    /// | ordinal suffix             | o        | st, nd, rd, th                 | Of the day of month,<br/>`do` -> 2nd     |
+    /// | modified julian day        | g+       | 59701                          | Unlimited length,<br/>padded with zeros. |
     /// | week day                   | e        | 3                              | 1-7, 1 is Sunday, *                      |
     /// |                            | ee       | 03                             | 1-7, 1 is Sunday                         |
     /// |                            | eee      | Tue                            |                                          |
@@ -477,6 +1304,9 @@ impl DateTime {
     /// |                            | bbb      | am, pm,<br/>noon, midnight     | *                                        |
     /// |                            | bbbb     | a.m., p.m.,<br/>noon, midnight |                                          |
     /// |                            | bbbbb    | a, p, n, mi                    |                                          |
+    /// | flexible day period        | B..BBB   | in the morning, at night       | *                                        |
+    /// |                            | BBBB     | in the morning, at night       |                                          |
+    /// |                            | BBBBB    | in the morning, at night       |                                          |
     /// | hour                       | h        | 1, 12                          | [1-12]                                   |
     /// |                            | hh       | 01, 12                         | *                                        |
     /// |                            | H        | 0, 23                          | [0-23]                                   |
@@ -504,66 +1334,849 @@ impl DateTime {
     /// |                            | xxx      | -08:00, +00:00                 | *                                        |
     /// |                            | xxxx     | -0800, -075258, +0000          |                                          |
     /// |                            | xxxxx    | -08:00, -07:52:58, +00:00      |                                          |
+    /// |                            | z..zzz   | CEST, GMT-08:00                | Designation if known, *                  |
+    /// |                            | zzzz     | GMT-08:00, GMT                 |                                          |
+    ///
+    /// `*` = Default
+    ///
+    /// The extended year `u` uses astronomical year numbering, which has a year `0`. Astrolabe skips year `0`, so `u` maps `0` to the year `-1` (1 BC), `-1` to `-2` (2 BC) and so on. Positive years are the same for `y` and `u`.
+    ///
+    /// If the sequence is longer than listed in the table, the output will be the same as the default pattern for this unit (marked with `*`).
+    ///
+    /// The numeric weekdays `e` and `ee` and the week of year `w` depend on the [`FirstWeekday`](crate::FirstWeekday) of the locale. By default, `e` and `ee` count from Sunday and `w` is the ISO 8601 week of year, see [`week_of_year`](DateUtilities::week_of_year) with [`WeekNumbering::Iso`]. The first days of January can therefore be formatted as week `52` or `53`.
+    ///
+    /// Flexible day periods (`B`) are morning from 06:00 to 11:59, afternoon from 12:00 to 17:59, evening from 18:00 to 20:59 and night from 21:00 to 05:59. Unlike `b`, they have no separate noon and midnight.
+    ///
+    /// Time zone designations (`z..zzz`) are only known for [`Offset::Local`](crate::Offset::Local), where they are read from the time zone data of the system. Fixed offsets and designations which are numeric (E.g. `+13`) are formatted like `zzzz`. When parsing, `GMT` offsets set the offset, while designations are skipped.
+    ///
+    /// The ordinal suffix `o` is only valid directly after the day of month (`d` or `dd`), which [`validate_format`](crate::validate_format) and `try_format` check. The suffixes are English regardless of the locale. When parsing, the suffix is optional, but has to match the parsed day if present (`2nd`, not `2st`).
+    ///
+    /// Numeric values are never truncated, the length of the sequence only sets the minimum width. E.g. `DD` formats the day of year `135` as `135`. The only exceptions are `yy`, which outputs the last two digits of the year and keeps the sign of negative years (`-34`), and subseconds (`n..nnnnn`), which are cut to the requested precision.
+    ///
+    /// If the format string contains the era (`G`), `y` is the year of the era and has no sign, e.g. `BC2022` for the year `-2022` with `Gyyyy`.
+    ///
+    /// A `+` directly before a year sequence outputs the sign of the year even if it is positive, e.g. `+yyyyyy` formats the ISO 8601 expanded year `+002022`. When parsing, a `+` or `-` sign is required and all following digits are part of the year. Except `yy`, all year sequences are parsed with all their digits, so years with more digits than the sequence are parsed completely.
+    ///
+    /// Surround any character with apostrophes (`'`) to escape them.
+    /// If you want escape `'`, write `''`.
+    ///
+    /// Letters which are not a format symbol are output as they are. Use [`DateTime::try_format`] to get an error instead.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap();
+    /// assert_eq!("2022/05/02 12:32:01", date_time.format("yyyy/MM/dd HH:mm:ss"));
+    /// // Escape characters
+    /// assert_eq!("2022/MM/dd 12:32:01", date_time.format("yyyy/'MM/dd' HH:mm:ss"));
+    /// assert_eq!("2022/'05/02' 12:32:01", date_time.format("yyyy/''MM/dd'' HH:mm:ss"));
+    /// ```
+    ///
+    #[doc(alias = "strftime")]
+    pub fn format(&self, format: &str) -> String {
+        self.format_localized(format, &Locale::ENGLISH)
+    }
+
+    /// Formats the date time like [`DateTime::format`], but returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the format string contains an unescaped letter which is not a format symbol, a symbol sequence which is too long or an escaped part which is not closed, like [`validate_format`](crate::validate_format). The error names the problematic part and its byte position.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap();
+    /// assert_eq!("2022/05/02 12:32:01", date_time.try_format("yyyy/MM/dd HH:mm:ss").unwrap());
+    /// assert!(date_time.try_format("yyyy/MM/dd HH:mm:ss ffff").is_err());
+    /// ```
+    pub fn try_format(&self, format: &str) -> Result<String, AstrolabeError> {
+        check_format_symbols(format, &[DATE_SYMBOLS, TIME_SYMBOLS])?;
+        Ok(self.format(format))
+    }
+
+    /// Formatting with format strings based on [Unicode Date Field Symbols](https://www.unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table), using the names of the given [`Locale`]. See [`DateTime::format`] for the available symbols.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Locale};
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap();
+    /// assert_eq!(
+    ///     "Montag, 2. Mai 2022, 12:32",
+    ///     date_time.format_localized("eeee, d. MMMM yyyy, HH:mm", &Locale::GERMAN)
+    /// );
+    /// ```
+    pub fn format_localized(&self, format: &str, locale: &Locale) -> String {
+        let (offset_seconds, designation) = self.offset.resolve_zone();
+        let (days, nanoseconds) = add_offset_to_dn(self.days, self.nanoseconds, offset_seconds);
+        Self::format_resolved(
+            format,
+            locale,
+            days,
+            nanoseconds,
+            offset_seconds,
+            designation.as_deref(),
+        )
+    }
+
+    /// Formats local days and nanoseconds with an already resolved offset, so that operations which format multiple parts resolve [`Offset::Local`] only once
+    fn format_resolved(
+        format: &str,
+        locale: &Locale,
+        days: i32,
+        nanoseconds: u64,
+        offset_seconds: i32,
+        designation: Option<&str>,
+    ) -> String {
+        let year_of_era = has_era_symbol(format);
+        let mut formatted = String::with_capacity(format.len());
+        for part in parse_format_string(format) {
+            match part {
+                FormatPart::Symbol(part) if year_of_era && part.starts_with('y') => {
+                    format_year_of_era(&mut formatted, part, days)
+                }
+                FormatPart::Symbol(part) => format_part(
+                    &mut formatted,
+                    part,
+                    days,
+                    nanoseconds,
+                    offset_seconds,
+                    designation,
+                    locale,
+                ),
+                // Escaped parts
+                FormatPart::Literal(literal) => formatted.push_str(literal),
+                // Escaped apostrophes
+                FormatPart::EscapedQuote => formatted.push('\''),
+            }
+        }
+        formatted
+    }
+
+    /// Returns the Julian date, which are the fractional days since 1. January 4713 BC (Julian calendar) 12:00 UTC. The offset is not relevant.
+    ///
+    /// The result is an `f64`, sub-millisecond precision is lost for current dates.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let date_time = DateTime::from_ymdhms(2000, 1, 1, 18, 0, 0).unwrap();
+    /// assert_eq!(2451545.25, date_time.julian_date());
+    /// ```
+    pub fn julian_date(&self) -> f64 {
+        self.modified_julian_date() + MJD_JULIAN_DATE
+    }
+
+    /// Returns the modified Julian date, which are the fractional days since 17. November 1858 00:00 UTC. The offset is not relevant.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let date_time = DateTime::from_ymdhms(2000, 1, 1, 18, 0, 0).unwrap();
+    /// assert_eq!(51544.75, date_time.modified_julian_date());
+    /// ```
+    pub fn modified_julian_date(&self) -> f64 {
+        days_to_mjd(self.days) as f64 + self.nanoseconds as f64 / NANOS_PER_DAY as f64
+    }
+
+    /// Returns the days between the calendar dates of both instances, each with its own offset applied. The time of day is ignored.
+    ///
+    /// Unlike [`days_since`](DateUtilities::days_since), which counts completed 24-hour periods, this function counts the midnights in between.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, DateUtilities};
+    /// let start = DateTime::from_ymdhms(2022, 5, 2, 23, 59, 0).unwrap();
+    /// let end = DateTime::from_ymdhms(2022, 5, 3, 0, 1, 0).unwrap();
+    ///
+    /// assert_eq!(0, end.days_since(&start));
+    /// assert_eq!(1, end.calendar_days_since(&start));
+    /// ```
+    pub fn calendar_days_since(&self, compare: &Self) -> i64 {
+        let days = self.resolve_local().0;
+        let compare_days =
+            add_offset_to_dn(compare.days, compare.nanoseconds, compare.offset.resolve()).0;
+
+        days as i64 - compare_days as i64
+    }
+
+    /// Returns `true` if both instances are on the same calendar day, each in its own offset. The time of day is ignored.
+    ///
+    /// Instances at the same instant can be on different days if their offsets differ. Use [`DateTime::same_day_as_in`] to compare them in a common offset.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Offset, OffsetUtilities};
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 23, 0, 0).unwrap();
+    /// assert!(date_time.same_day_as(&DateTime::from_ymdhms(2022, 5, 2, 0, 0, 0).unwrap()));
+    ///
+    /// // Same instant, but already 2022-05-03 at +02:00
+    /// let plus_two = date_time.set_offset(Offset::from_hms(2, 0, 0).unwrap());
+    /// assert!(!date_time.same_day_as(&plus_two));
+    /// ```
+    pub fn same_day_as(&self, other: &Self) -> bool {
+        self.date() == other.date()
+    }
+
+    /// Returns `true` if both instances are on the same calendar day in the given offset. The offsets of the instances are ignored.
+    ///
+    /// Returns `false` if the local date of either instance would be out of range in the given offset.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Offset, OffsetUtilities};
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 23, 0, 0).unwrap();
+    /// let plus_two = date_time.set_offset(Offset::from_hms(2, 0, 0).unwrap());
+    /// assert!(date_time.same_day_as_in(&plus_two, Offset::Fixed(0)));
+    /// ```
+    pub fn same_day_as_in(&self, other: &Self, offset: Offset) -> bool {
+        self.pair_in(other, offset)
+            .map_or(false, |(date_time, other)| date_time.same_day_as(&other))
+    }
+
+    /// Returns `true` if both instances are in the same month of the same year, each in its own offset.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Offset, OffsetUtilities};
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 31, 23, 0, 0).unwrap();
+    /// assert!(date_time.same_month_as(&DateTime::from_ymd(2022, 5, 1).unwrap()));
+    /// assert!(!date_time.same_month_as(&DateTime::from_ymd(2021, 5, 1).unwrap()));
+    ///
+    /// let plus_two = date_time.set_offset(Offset::from_hms(2, 0, 0).unwrap());
+    /// assert!(!date_time.same_month_as(&plus_two));
+    /// ```
+    pub fn same_month_as(&self, other: &Self) -> bool {
+        self.date().same_month_as(&other.date())
+    }
+
+    /// Returns `true` if both instances are in the same month of the same year in the given offset. The offsets of the instances are ignored.
+    ///
+    /// Returns `false` if the local date of either instance would be out of range in the given offset.
+    pub fn same_month_as_in(&self, other: &Self, offset: Offset) -> bool {
+        self.pair_in(other, offset)
+            .map_or(false, |(date_time, other)| date_time.same_month_as(&other))
+    }
+
+    /// Returns `true` if both instances are in the same year, each in its own offset.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Offset, OffsetUtilities};
+    /// let date_time = DateTime::from_ymdhms(2022, 12, 31, 23, 0, 0).unwrap();
+    /// assert!(date_time.same_year_as(&DateTime::from_ymd(2022, 1, 1).unwrap()));
+    ///
+    /// let plus_two = date_time.set_offset(Offset::from_hms(2, 0, 0).unwrap());
+    /// assert!(!date_time.same_year_as(&plus_two));
+    /// assert!(date_time.same_year_as_in(&plus_two, Offset::Fixed(0)));
+    /// ```
+    pub fn same_year_as(&self, other: &Self) -> bool {
+        self.date().same_year_as(&other.date())
+    }
+
+    /// Returns `true` if both instances are in the same year in the given offset. The offsets of the instances are ignored.
+    ///
+    /// Returns `false` if the local date of either instance would be out of range in the given offset.
+    pub fn same_year_as_in(&self, other: &Self, offset: Offset) -> bool {
+        self.pair_in(other, offset)
+            .map_or(false, |(date_time, other)| date_time.same_year_as(&other))
+    }
+
+    /// Returns the exact duration between the provided DateTime, regardless of which one is earlier.
+    ///
+    /// Both instances are compared as instants, so their offsets are not relevant. Instances with the same wall clock time but different offsets are apart by the difference of their offsets.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Offset, OffsetUtilities};
+    /// let utc = DateTime::from_ymdhms(2022, 5, 2, 12, 0, 0).unwrap();
+    /// let plus_two = Offset::from_hms(2, 0, 0).unwrap();
+    ///
+    /// // Same instant, 14:00 at +02:00
+    /// assert_eq!(0, utc.duration_between(&utc.set_offset(plus_two)).as_secs());
+    /// // Same wall clock time, 12:00 at +02:00
+    /// assert_eq!(7200, utc.duration_between(&utc.as_offset(plus_two)).as_secs());
+    /// ```
+    pub fn duration_between(&self, compare: &Self) -> Duration {
+        let lower = cmp::min(self, compare);
+        let upper = cmp::max(self, compare);
+
+        let mut days = upper.days as i64 - lower.days as i64;
+        let mut nanos = upper.nanoseconds as i64 - lower.nanoseconds as i64;
+        if lower.nanoseconds > upper.nanoseconds {
+            days -= 1;
+            nanos += NANOS_PER_DAY as i64;
+        };
+
+        let days_duration = Duration::from_secs(days.unsigned_abs() * SECS_PER_DAY_U64);
+        let nanos_duration = Duration::from_nanos(nanos.unsigned_abs());
+        days_duration + nanos_duration
+    }
+
+    /// Returns an iterator from `start` (inclusive) to `end` (exclusive) in steps of `step`.
+    ///
+    /// If `end` is before `start`, the iterator steps backwards. All returned instances have the offset of `start`.
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// # use std::time::Duration;
+    /// let start = DateTime::from_ymdhms(2022, 5, 2, 10, 0, 0).unwrap();
+    /// let end = DateTime::from_ymdhms(2022, 5, 2, 11, 0, 0).unwrap();
+    ///
+    /// let range = DateTime::range_step(start, end, Duration::from_secs(15 * 60));
+    /// let times: Vec<String> = range.map(|date_time| date_time.format("HH:mm")).collect();
+    /// assert_eq!(vec!["10:00", "10:15", "10:30", "10:45"], times);
+    /// ```
+    pub fn range_step(start: Self, end: Self, step: Duration) -> DateTimeRange {
+        DateTimeRange::new(start, end, step.as_nanos())
+    }
+
+    /// Returns `true` if the instance is between `start` and `end`. Use [`Bounds`] to specify whether `start` and `end` are included.
+    ///
+    /// The comparison is based on the absolute instants, the offsets of the instances are not relevant. If `start` is after `end`, no instance is between them.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Bounds, DateTime};
+    /// let start = DateTime::from_ymd(2022, 5, 2).unwrap();
+    /// let end = DateTime::from_ymd(2022, 5, 3).unwrap();
+    ///
+    /// assert!(start.is_between(&start, &end, Bounds::StartInclusive));
+    /// assert!(!end.is_between(&start, &end, Bounds::StartInclusive));
+    /// ```
+    pub fn is_between(&self, start: &Self, end: &Self, bounds: Bounds) -> bool {
+        bounds.after_start(self, start) && bounds.before_end(self, end)
+    }
+
+    /// Returns `true` if the instance is after the current time ([`DateTime::now`]). Offsets are not relevant, as the absolute instants are compared.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, DateUtilities};
+    /// assert!(DateTime::now().add_days(1).is_future());
+    /// assert!(!DateTime::now().sub_days(1).is_future());
+    /// ```
+    pub fn is_future(&self) -> bool {
+        self.is_future_at(&DateTime::now())
+    }
+
+    /// Returns `true` if the instance is after `now`.
+    ///
+    /// Use this with a single snapshot of the current time to get consistent results across several checks.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let now = DateTime::from_ymdhms(2022, 5, 2, 12, 0, 0).unwrap();
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 0, 1).unwrap();
+    /// assert!(date_time.is_future_at(&now));
+    /// assert!(!date_time.is_past_at(&now));
+    /// assert!(!now.is_future_at(&now));
+    /// ```
+    pub fn is_future_at(&self, now: &Self) -> bool {
+        self > now
+    }
+
+    /// Returns `true` if the instance is before the current time ([`DateTime::now`]). Offsets are not relevant, as the absolute instants are compared.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, DateUtilities};
+    /// assert!(DateTime::now().sub_days(1).is_past());
+    /// assert!(!DateTime::now().add_days(1).is_past());
+    /// ```
+    pub fn is_past(&self) -> bool {
+        self.is_past_at(&DateTime::now())
+    }
+
+    /// Returns `true` if the instance is before `now`. See [`DateTime::is_future_at`].
+    pub fn is_past_at(&self, now: &Self) -> bool {
+        self < now
+    }
+
+    /// Returns the duration that has passed since the instance until the current time ([`DateTime::now`]). Returns [`Duration::ZERO`] if the instance is in the future.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, TimeUtilities};
+    /// let date_time = DateTime::now().sub_minutes(5);
+    /// assert!(300 <= date_time.elapsed().as_secs());
+    /// assert!(DateTime::now().add_minutes(5).elapsed().is_zero());
+    /// ```
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed_at(&DateTime::now())
+    }
+
+    /// Returns the duration that has passed since the instance until `now`. Returns [`Duration::ZERO`] if the instance is after `now`.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let now = DateTime::from_ymdhms(2022, 5, 2, 12, 0, 0).unwrap();
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 11, 59, 0).unwrap();
+    /// assert_eq!(60, date_time.elapsed_at(&now).as_secs());
+    /// assert!(now.elapsed_at(&date_time).is_zero());
+    /// ```
+    pub fn elapsed_at(&self, now: &Self) -> Duration {
+        if self.is_past_at(now) {
+            self.duration_between(now)
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    /// Formats the instance relative to `now` in English, like `3 hours ago`, `in 2 days` or `just now`. Uses the [`RelativeThresholds::DEFAULT`] thresholds, see [`DateTime::format_relative_with`].
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, DateUtilities, TimeUtilities};
+    /// let now = DateTime::from_ymdhms(2022, 5, 2, 12, 0, 0).unwrap();
+    /// assert_eq!("just now", now.sub_seconds(10).format_relative(&now));
+    /// assert_eq!("in a few seconds", now.add_seconds(10).format_relative(&now));
+    /// assert_eq!("3 hours ago", now.sub_hours(3).format_relative(&now));
+    /// assert_eq!("in 2 days", now.add_days(2).format_relative(&now));
+    /// assert_eq!("1 month ago", now.sub_months(1).format_relative(&now));
+    /// assert_eq!("in 5 years", now.add_years(5).format_relative(&now));
+    /// ```
+    pub fn format_relative(&self, now: &Self) -> String {
+        self.format_relative_with(now, &RelativeThresholds::DEFAULT)
+    }
+
+    /// Formats the instance relative to `now` in English, using the given [`RelativeThresholds`] to choose the unit.
+    ///
+    /// Minutes, hours and days are rounded to the nearest whole unit, with at least `1` of the chosen unit. Months and years are full calendar units counted with [`months_since`](DateUtilities::months_since) and [`years_since`](DateUtilities::years_since), instead of fixed lengths of 30 or 365 days. If the days reach their threshold before a full calendar month has passed, `1 month` is shown.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, RelativeThresholds, TimeUtilities};
+    /// let now = DateTime::from_ymdhms(2022, 5, 2, 12, 0, 0).unwrap();
+    /// let thresholds = RelativeThresholds {
+    ///     seconds: 5,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!("1 minute ago", now.sub_seconds(10).format_relative_with(&now, &thresholds));
+    /// ```
+    pub fn format_relative_with(&self, now: &Self, thresholds: &RelativeThresholds) -> String {
+        let future = self.is_future_at(now);
+        let seconds = self.duration_between(now).as_secs();
+        // Rounds half up to the nearest whole unit, but at least to 1
+        let round = |divisor: u64| cmp::max((seconds + divisor / 2) / divisor, 1);
+
+        let (amount, unit) = if seconds < thresholds.seconds {
+            return if future {
+                "in a few seconds".to_string()
+            } else {
+                "just now".to_string()
+            };
+        } else if round(SECS_PER_MINUTE_U64) < thresholds.minutes {
+            (round(SECS_PER_MINUTE_U64), "minute")
+        } else if round(SECS_PER_HOUR_U64) < thresholds.hours {
+            (round(SECS_PER_HOUR_U64), "hour")
+        } else if round(SECS_PER_DAY_U64) < thresholds.days {
+            (round(SECS_PER_DAY_U64), "day")
+        } else {
+            let months = self.months_since(now).unsigned_abs();
+            if months < thresholds.months {
+                (cmp::max(months, 1) as u64, "month")
+            } else {
+                (
+                    cmp::max(self.years_since(now).unsigned_abs(), 1) as u64,
+                    "year",
+                )
+            }
+        };
+
+        let plural = if amount == 1 { "" } else { "s" };
+        if future {
+            format!("in {} {}{}", amount, unit, plural)
+        } else {
+            format!("{} {}{} ago", amount, unit, plural)
+        }
+    }
+
+    /// Returns the instance if it is between `start` and `end`, otherwise the closest instance which is. Use [`Bounds`] to specify whether `start` and `end` are included. An excluded bound is clamped to one nanosecond after `start` or before `end`.
+    ///
+    /// The comparison is based on the absolute instants. The returned instance has the offset of `self`.
+    ///
+    /// Panics if no instance is between `start` and `end`.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Bounds, DateTime, TimeUtilities};
+    /// let start = DateTime::from_ymd(2022, 5, 2).unwrap();
+    /// let end = DateTime::from_ymd(2022, 5, 3).unwrap();
+    ///
+    /// let date_time = DateTime::from_ymd(2022, 5, 4).unwrap();
+    /// assert_eq!(end, date_time.clamp_between(&start, &end, Bounds::Inclusive));
+    /// assert_eq!(end.sub_nanos(1), date_time.clamp_between(&start, &end, Bounds::StartInclusive));
+    /// ```
+    pub fn clamp_between(&self, start: &Self, end: &Self, bounds: Bounds) -> Self {
+        let lower = start.as_nanos() + if bounds.includes_start() { 0 } else { 1 };
+        let upper = end.as_nanos() - if bounds.includes_end() { 0 } else { 1 };
+        assert!(lower <= upper, "No instance is between start and end");
+
+        // Using unwrap because the clamped nanoseconds lie between both instances
+        let (days, nanoseconds) = nanos_to_days_nanos(self.as_nanos().clamp(lower, upper)).unwrap();
+        Self {
+            days,
+            nanoseconds,
+            offset: self.offset,
+        }
+    }
+
+    /// Returns the instance if it is between `min` and `max`, otherwise `min` or `max` with the offset of `self`.
+    ///
+    /// Like [`Ord::clamp`], the comparison is based on the absolute instants, the offsets of the instances are not relevant. `10:00+02:00` is therefore before `09:30Z`. Unlike [`Ord::clamp`], the returned instance always keeps the offset of `self`.
+    ///
+    /// Panics if `min` is after `max`.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Offset, OffsetUtilities};
+    /// let min = DateTime::from_ymdhms(2022, 5, 2, 10, 0, 0).unwrap();
+    /// let max = DateTime::from_ymdhms(2022, 5, 2, 18, 0, 0).unwrap();
+    ///
+    /// // 09:30 UTC
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 11, 30, 0).unwrap().as_offset(Offset::from_hms(2, 0, 0).unwrap());
+    /// let clamped = date_time.clamp(min, max);
+    /// assert_eq!(min, clamped);
+    /// assert_eq!("2022-05-02T12:00:00+02:00", clamped.format_rfc3339(astrolabe::Precision::Seconds));
+    /// ```
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min <= max, "min is after max");
+        self.clamp_between(&min, &max, Bounds::Inclusive)
+    }
+
+    /// Returns the earlier of both instances, comparing the absolute instants like [`Ord::min`]. If both are the same instant, `self` is returned, even if the offsets differ.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Offset, OffsetUtilities};
+    /// // 10:00+02:00 is 08:00 UTC
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 8, 0, 0).unwrap().set_offset(Offset::from_hms(2, 0, 0).unwrap());
+    /// let other = DateTime::from_ymdhms(2022, 5, 2, 9, 30, 0).unwrap();
+    /// assert_eq!(date_time, date_time.min(other));
+    /// assert_eq!(other, date_time.max(other));
+    /// ```
+    pub fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the later of both instances, comparing the absolute instants like [`Ord::max`]. If both are the same instant, `self` is returned, even if the offsets differ.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// let date_time = DateTime::from_ymd(2022, 5, 2).unwrap();
+    /// let other = DateTime::from_ymd(2022, 5, 3).unwrap();
+    /// assert_eq!(other, date_time.max(other));
+    /// ```
+    pub fn max(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Rounds the instance down to the previous multiple of `duration` in local time. The offset is kept.
+    ///
+    /// Multiples are counted from local midnight, so `duration` has to evenly divide a day. Otherwise, or if the duration is zero, an [`OutOfRange`](AstrolabeError::OutOfRange) error is returned.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Offset, OffsetUtilities};
+    /// # use std::time::Duration;
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 10, 44, 0).unwrap();
+    /// let floored = date_time.floor_to(Duration::from_secs(15 * 60)).unwrap();
+    /// assert_eq!("2022-05-02 10:30", floored.format("yyyy-MM-dd HH:mm"));
+    ///
+    /// // Hours are aligned to the local clock
+    /// let offset = Offset::from_hms(5, 30, 0).unwrap();
+    /// let floored = date_time.set_offset(offset).floor_to(Duration::from_secs(3600)).unwrap();
+    /// assert_eq!("16:00 +05:30", floored.format("HH:mm xxx"));
+    ///
+    /// assert!(date_time.floor_to(Duration::from_secs(7 * 60)).is_err());
+    /// ```
+    pub fn floor_to(&self, duration: Duration) -> Result<Self, AstrolabeError> {
+        self.align_to(duration, floor_nanos)
+    }
+
+    /// Rounds the instance up to the next multiple of `duration` in local time. Instances which already are a multiple are returned unchanged. The offset is kept.
+    ///
+    /// Multiples are counted from local midnight, so `duration` has to evenly divide a day. Otherwise, or if the duration is zero or the result would overflow, an [`OutOfRange`](AstrolabeError::OutOfRange) error is returned.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// # use std::time::Duration;
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 23, 31, 0).unwrap();
+    /// let ceiled = date_time.ceil_to(Duration::from_secs(3600)).unwrap();
+    /// assert_eq!("2022-05-03 00:00", ceiled.format("yyyy-MM-dd HH:mm"));
+    /// ```
+    pub fn ceil_to(&self, duration: Duration) -> Result<Self, AstrolabeError> {
+        self.align_to(duration, ceil_nanos)
+    }
+
+    /// Rounds the instance to the nearest multiple of `duration` in local time. Ties are rounded up to the later instant. The offset is kept.
+    ///
+    /// Multiples are counted from local midnight, so `duration` has to evenly divide a day. Otherwise, or if the duration is zero or the result would overflow, an [`OutOfRange`](AstrolabeError::OutOfRange) error is returned.
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTime;
+    /// # use std::time::Duration;
+    /// let quarter = Duration::from_secs(15 * 60);
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 10, 7, 29).unwrap();
+    /// assert_eq!("10:00:00", date_time.round_to(quarter).unwrap().format("HH:mm:ss"));
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 10, 7, 30).unwrap();
+    /// assert_eq!("10:15:00", date_time.round_to(quarter).unwrap().format("HH:mm:ss"));
+    /// ```
+    pub fn round_to(&self, duration: Duration) -> Result<Self, AstrolabeError> {
+        self.align_to(duration, round_nanos)
+    }
+
+    /// Returns the next date which falls on the given weekday. The next occurrence is always strictly after `self`.
+    ///
+    /// The weekday is determined in the offset of `self`, the time of day is kept.
+    ///
+    /// Panics if the resulting date would be out of range.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Weekday};
+    /// // Monday, 2. May 2022
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 10, 0, 0).unwrap();
+    /// assert_eq!("2022/05/06", date_time.next_occurrence_of(Weekday::Friday).format("yyyy/MM/dd"));
+    /// assert_eq!("2022/05/09", date_time.next_occurrence_of(Weekday::Monday).format("yyyy/MM/dd"));
+    /// ```
+    pub fn next_occurrence_of(&self, weekday: Weekday) -> Self {
+        self.add_days(days_until_weekday(self.weekday(), weekday, false))
+    }
+
+    /// Returns the next date which falls on the given weekday, or `self` if it already falls on it.
+    ///
+    /// The weekday is determined in the offset of `self`, the time of day is kept.
+    ///
+    /// Panics if the resulting date would be out of range.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Weekday};
+    /// // Monday, 2. May 2022
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 10, 0, 0).unwrap();
+    /// assert_eq!("2022/05/06", date_time.next_occurrence_of_or_same(Weekday::Friday).format("yyyy/MM/dd"));
+    /// assert_eq!("2022/05/02", date_time.next_occurrence_of_or_same(Weekday::Monday).format("yyyy/MM/dd"));
+    /// ```
+    pub fn next_occurrence_of_or_same(&self, weekday: Weekday) -> Self {
+        self.add_days(days_until_weekday(self.weekday(), weekday, true))
+    }
+
+    /// Returns the previous date which falls on the given weekday. The previous occurrence is always strictly before `self`.
+    ///
+    /// The weekday is determined in the offset of `self`, the time of day is kept.
+    ///
+    /// Panics if the resulting date would be out of range.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Weekday};
+    /// // Monday, 2. May 2022
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 10, 0, 0).unwrap();
+    /// assert_eq!("2022/04/29", date_time.previous_occurrence_of(Weekday::Friday).format("yyyy/MM/dd"));
+    /// assert_eq!("2022/04/25", date_time.previous_occurrence_of(Weekday::Monday).format("yyyy/MM/dd"));
+    /// ```
+    pub fn previous_occurrence_of(&self, weekday: Weekday) -> Self {
+        self.sub_days(days_since_weekday(self.weekday(), weekday, false))
+    }
+
+    /// Returns the previous date which falls on the given weekday, or `self` if it already falls on it.
+    ///
+    /// The weekday is determined in the offset of `self`, the time of day is kept.
+    ///
+    /// Panics if the resulting date would be out of range.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Weekday};
+    /// // Monday, 2. May 2022
+    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 10, 0, 0).unwrap();
+    /// assert_eq!("2022/04/29", date_time.previous_occurrence_of_or_same(Weekday::Friday).format("yyyy/MM/dd"));
+    /// assert_eq!("2022/05/02", date_time.previous_occurrence_of_or_same(Weekday::Monday).format("yyyy/MM/dd"));
+    /// ```
+    pub fn previous_occurrence_of_or_same(&self, weekday: Weekday) -> Self {
+        self.sub_days(days_since_weekday(self.weekday(), weekday, true))
+    }
+
+    /// Returns the difference to the provided [`DateTime`] broken down into years, months, days, hours, minutes, seconds and nanoseconds. Negative if `self` is before `other`.
+    ///
+    /// The calendar units are counted in the offset of `other`. Full months are counted first, the remainder is split into the smaller units. If the day of month of `other` does not exist in the month reached after adding the full months, it is clamped to the last day of that month, the same way as [`add_months`](DateUtilities::add_months) does. For example, `31. March` since `31. January` is 2 months, while `2. March` since `31. January` is 1 month and 2 days (`31. January` + 1 month = `28. February`).
+    ///
+    /// Adding the returned [`DateTimeDelta`] to `other` results in `self`.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, DateUtilities};
+    /// let start = DateTime::from_ymd(2022, 1, 31).unwrap();
+    /// let end = DateTime::from_ymd(2022, 3, 31).unwrap();
+    /// assert_eq!("2 months", end.since(&start).to_string());
+    /// assert_eq!("-2 months", start.since(&end).to_string());
+    ///
+    /// let end = DateTime::from_ymd(2022, 3, 2).unwrap();
+    /// assert_eq!("1 month, 2 days", end.since(&start).to_string());
+    /// assert_eq!(end, start + end.since(&start));
+    /// ```
+    pub fn since(&self, other: &Self) -> DateTimeDelta {
+        let offset = other.offset.resolve();
+        let (days, nanoseconds) = add_offset_to_dn(self.days, self.nanoseconds, offset);
+        let (other_days, other_nanoseconds) =
+            add_offset_to_dn(other.days, other.nanoseconds, offset);
+
+        let months = months_between(days, nanoseconds, other_days, other_nanoseconds);
+        // Using unwrap because the shifted date always lies between both instances
+        let shifted_days = shift_months(other_days, months as i64).unwrap();
+
+        let nanos = days_nanos_to_nanos(days, nanoseconds)
+            - days_nanos_to_nanos(shifted_days, other_nanoseconds);
+
+        DateTimeDelta::from_months_nanos(months, nanos)
+    }
+
+    /// Returns the calendar aware difference between both instances as a non-negative [`DateTimeDelta`], counted from the earlier instance.
+    ///
+    /// The components are always counted from the earlier instance in its offset, so adding the returned delta to the earlier instance results in the later one, no matter in which order both are passed. Full months are counted first. If the day of month of the earlier instance does not exist in a month, it is clamped to the last day of that month, and a month is full once this clamped day is reached. Unlike with [`since`](DateTime::since), `28. February` after `31. January` therefore is 1 month and 0 days, and `1. March` after `31. January` is 1 month and 1 day.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, DateUtilities};
+    /// let start = DateTime::from_ymd(2022, 1, 31).unwrap();
+    /// let end = DateTime::from_ymdhms(2022, 3, 2, 12, 0, 0).unwrap();
+    /// assert_eq!("1 month, 2 days, 12 hours", end.calendar_duration_between(&start).to_string());
+    /// assert_eq!(end.calendar_duration_between(&start), start.calendar_duration_between(&end));
+    /// assert_eq!(end, start + start.calendar_duration_between(&end));
+    ///
+    /// let end = DateTime::from_ymd(2022, 2, 28).unwrap();
+    /// assert_eq!("1 month", end.calendar_duration_between(&start).to_string());
+    /// ```
+    pub fn calendar_duration_between(&self, compare: &Self) -> DateTimeDelta {
+        let lower = cmp::min(self, compare);
+        let upper = cmp::max(self, compare);
+
+        let offset = lower.offset.resolve();
+        let (upper_days, upper_nanoseconds) =
+            add_offset_to_dn(upper.days, upper.nanoseconds, offset);
+        let (lower_days, lower_nanoseconds) =
+            add_offset_to_dn(lower.days, lower.nanoseconds, offset);
+        let upper_nanos = days_nanos_to_nanos(upper_days, upper_nanoseconds);
+
+        let mut months =
+            months_between(upper_days, upper_nanoseconds, lower_days, lower_nanoseconds);
+        // A clamped day of month completes the month, e.g. 31. January to 28. February
+        if let Ok(next_days) = shift_months(lower_days, months as i64 + 1) {
+            if days_nanos_to_nanos(next_days, lower_nanoseconds) <= upper_nanos {
+                months += 1;
+            }
+        }
+        // Using unwrap because the shifted date always lies between both instances
+        let shifted_days = shift_months(lower_days, months as i64).unwrap();
+        let nanos = upper_nanos - days_nanos_to_nanos(shifted_days, lower_nanoseconds);
+
+        DateTimeDelta::from_months_nanos(months, nanos)
+    }
+
+    /// Adds a [`DateTimeDelta`], e.g. one parsed with [`DateTimeDelta::parse_iso8601`].
+    ///
+    /// The years and months are added first in the calendar of this instance's offset. If the day of month doesn't exist in the target month, it is clamped to the last day of that month, the same way as [`add_months`](DateUtilities::add_months) does. The remaining units are added afterwards as exact durations.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the result would be out of range.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, DateTimeDelta};
+    /// let date_time = DateTime::from_ymdhms(2022, 1, 31, 12, 0, 0).unwrap();
+    /// let delta = DateTimeDelta::parse_iso8601("P1MT12H").unwrap();
+    /// assert_eq!("2022/03/01 00:00:00", date_time.add_relative(&delta).unwrap().to_string());
+    /// ```
+    pub fn add_relative(&self, delta: &DateTimeDelta) -> Result<Self, AstrolabeError> {
+        self.apply_relative(delta.total_months(), delta.submonth_nanos())
+    }
+
+    /// Subtracts a [`DateTimeDelta`], e.g. one parsed with [`DateTimeDelta::parse_iso8601`].
+    ///
+    /// Like [`add_relative`](DateTime::add_relative), the years and months are subtracted first with the day of month clamped, then the remaining units as exact durations.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the result would be out of range.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, DateTimeDelta};
+    /// let date_time = DateTime::from_ymd(2022, 3, 31).unwrap();
+    /// let delta = DateTimeDelta::parse_iso8601("P1MT1H").unwrap();
+    /// assert_eq!("2022/02/27 23:00:00", date_time.sub_relative(&delta).unwrap().to_string());
+    /// ```
+    pub fn sub_relative(&self, delta: &DateTimeDelta) -> Result<Self, AstrolabeError> {
+        self.apply_relative(-delta.total_months(), -delta.submonth_nanos())
+    }
+
+    /// Shifts by the given months in the local calendar, then by the given nanoseconds
+    pub(crate) fn apply_relative(&self, months: i64, nanos: i128) -> Result<Self, AstrolabeError> {
+        let offset = self.offset.resolve();
+        let (days, nanoseconds) = add_offset_to_dn(self.days, self.nanoseconds, offset);
+
+        let days = shift_months(days, months)?;
+        let nanos = days_nanos_to_nanos(days, nanoseconds) + nanos;
+        let (days, nanoseconds) = nanos_to_days_nanos(nanos)?;
+        let (days, nanoseconds) = remove_offset_from_dn(days, nanoseconds, offset);
+
+        Ok(Self {
+            days,
+            nanoseconds,
+            offset: self.offset,
+        })
+    }
+
+    /// Adds years like [`add_years`](DateUtilities::add_years), but resolves February 29th in a target year which is not a leap year with the given [`MonthOverflow`]. The time is not changed.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the result would be out of range, or if the day doesn't exist in the target year and [`MonthOverflow::Error`] is used.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, MonthOverflow};
+    /// let date_time = DateTime::from_ymdhms(2024, 2, 29, 12, 32, 1).unwrap();
+    /// assert_eq!("2025/03/01 12:32:01", date_time.add_years_with(1, MonthOverflow::RollToNextMonth).unwrap().to_string());
+    /// assert!(date_time.add_years_with(1, MonthOverflow::Error).is_err());
+    /// ```
+    pub fn add_years_with(
+        &self,
+        years: u32,
+        overflow: MonthOverflow,
+    ) -> Result<Self, AstrolabeError> {
+        let days = add_years_with(self.days, years, overflow)?;
+        Ok(Self { days, ..*self })
+    }
+
+    /// Subtracts years like [`sub_years`](DateUtilities::sub_years), but resolves February 29th in a target year which is not a leap year with the given [`MonthOverflow`]. The time is not changed.
     ///
-    /// `*` = Default
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the result would be out of range, or if the day doesn't exist in the target year and [`MonthOverflow::Error`] is used.
     ///
-    /// If the sequence is longer than listed in the table, the output will be the same as the default pattern for this unit (marked with `*`).
+    /// ```rust
+    /// # use astrolabe::{DateTime, MonthOverflow};
+    /// let date_time = DateTime::from_ymdhms(2024, 2, 29, 12, 32, 1).unwrap();
+    /// assert_eq!("2023/02/28 12:32:01", date_time.sub_years_with(1, MonthOverflow::ClampToLastDay).unwrap().to_string());
+    /// assert!(date_time.sub_years_with(1, MonthOverflow::Error).is_err());
+    /// ```
+    pub fn sub_years_with(
+        &self,
+        years: u32,
+        overflow: MonthOverflow,
+    ) -> Result<Self, AstrolabeError> {
+        let days = sub_years_with(self.days, years, overflow)?;
+        Ok(Self { days, ..*self })
+    }
+
+    /// Adds months like [`add_months`](DateUtilities::add_months), but resolves a day of month which doesn't exist in the target month with the given [`MonthOverflow`]. The time is not changed.
     ///
-    /// Surround any character with apostrophes (`'`) to escape them.
-    /// If you want escape `'`, write `''`.
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the result would be out of range, or if the day doesn't exist in the target month and [`MonthOverflow::Error`] is used.
     ///
     /// ```rust
-    /// # use astrolabe::DateTime;
-    /// let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap();
-    /// assert_eq!("2022/05/02 12:32:01", date_time.format("yyyy/MM/dd HH:mm:ss"));
-    /// // Escape characters
-    /// assert_eq!("2022/MM/dd 12:32:01", date_time.format("yyyy/'MM/dd' HH:mm:ss"));
-    /// assert_eq!("2022/'05/02' 12:32:01", date_time.format("yyyy/''MM/dd'' HH:mm:ss"));
+    /// # use astrolabe::{DateTime, MonthOverflow};
+    /// let date_time = DateTime::from_ymdhms(2022, 1, 31, 12, 32, 1).unwrap();
+    /// assert_eq!("2022/03/01 12:32:01", date_time.add_months_with(1, MonthOverflow::RollToNextMonth).unwrap().to_string());
+    /// assert!(date_time.add_months_with(1, MonthOverflow::Error).is_err());
     /// ```
-    ///
-    pub fn format(&self, format: &str) -> String {
-        let offset_seconds = self.offset.resolve();
-        let parts = parse_format_string(format);
-        let (days, nanoseconds) = add_offset_to_dn(self.days, self.nanoseconds, offset_seconds);
-
-        parts
-            .iter()
-            .flat_map(|part| -> Vec<char> {
-                // Escaped apostrophes
-                if part.starts_with('\u{0000}') {
-                    return part.replace('\u{0000}', "'").chars().collect::<Vec<char>>();
-                }
-
-                // Escape parts starting with apostrophe
-                if part.starts_with('\'') {
-                    let part = part.replace('\u{0000}', "'");
-                    return part[1..part.len() - usize::from(part.ends_with('\''))]
-                        .chars()
-                        .collect::<Vec<char>>();
-                }
-
-                format_part(part, days, nanoseconds, offset_seconds)
-                    .chars()
-                    .collect::<Vec<char>>()
-            })
-            .collect::<String>()
+    pub fn add_months_with(
+        &self,
+        months: u32,
+        overflow: MonthOverflow,
+    ) -> Result<Self, AstrolabeError> {
+        let days = add_months_with(self.days, months, overflow)?;
+        Ok(Self { days, ..*self })
     }
 
-    /// Returns the duration between the provided DateTime.
-    pub fn duration_between(&self, compare: &Self) -> Duration {
-        let lower = cmp::min(self, compare);
-        let upper = cmp::max(self, compare);
-
-        let mut days = upper.days as i64 - lower.days as i64;
-        let mut nanos = upper.nanoseconds as i64 - lower.nanoseconds as i64;
-        if lower.nanoseconds > upper.nanoseconds {
-            days -= 1;
-            nanos += NANOS_PER_DAY as i64;
-        };
-
-        let days_duration = Duration::from_secs(days.unsigned_abs() * SECS_PER_DAY_U64);
-        let nanos_duration = Duration::from_nanos(nanos.unsigned_abs());
-        days_duration + nanos_duration
+    /// Subtracts months like [`sub_months`](DateUtilities::sub_months), but resolves a day of month which doesn't exist in the target month with the given [`MonthOverflow`]. The time is not changed.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the result would be out of range, or if the day doesn't exist in the target month and [`MonthOverflow::Error`] is used.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, MonthOverflow};
+    /// let date_time = DateTime::from_ymdhms(2022, 3, 31, 12, 32, 1).unwrap();
+    /// assert_eq!("2022/02/28 12:32:01", date_time.sub_months_with(1, MonthOverflow::ClampToLastDay).unwrap().to_string());
+    /// assert!(date_time.sub_months_with(1, MonthOverflow::Error).is_err());
+    /// ```
+    pub fn sub_months_with(
+        &self,
+        months: u32,
+        overflow: MonthOverflow,
+    ) -> Result<Self, AstrolabeError> {
+        let days = sub_months_with(self.days, months, overflow)?;
+        Ok(Self { days, ..*self })
     }
 }
 
@@ -575,33 +2188,63 @@ impl DateTime {
 
 impl DateUtilities for DateTime {
     fn year(&self) -> i32 {
-        let days = add_offset_to_dn(self.days, self.nanoseconds, self.offset.resolve()).0;
+        let days = self.resolve_local().0;
 
         days_to_date(days).0
     }
 
     fn month(&self) -> u32 {
-        let days = add_offset_to_dn(self.days, self.nanoseconds, self.offset.resolve()).0;
+        let days = self.resolve_local().0;
 
         days_to_date(days).1
     }
 
+    fn quarter(&self) -> u32 {
+        (self.month() - 1) / 3 + 1
+    }
+
     fn day(&self) -> u32 {
-        let days = add_offset_to_dn(self.days, self.nanoseconds, self.offset.resolve()).0;
+        let days = self.resolve_local().0;
 
         days_to_date(days).2
     }
 
     fn day_of_year(&self) -> u32 {
-        let days = add_offset_to_dn(self.days, self.nanoseconds, self.offset.resolve()).0;
+        let days = self.resolve_local().0;
 
         days_to_doy(days)
     }
 
     fn weekday(&self) -> u8 {
-        let days = add_offset_to_dn(self.days, self.nanoseconds, self.offset.resolve()).0;
+        let days = self.resolve_local().0;
 
-        days_to_wday(days, false) as u8
+        days_to_wday(days) as u8
+    }
+
+    fn weekday_iso(&self) -> u8 {
+        let days = self.resolve_local().0;
+
+        days_to_iso_wday(days) as u8
+    }
+
+    fn weekday_sunday0(&self) -> u8 {
+        self.weekday()
+    }
+
+    fn week_of_month(&self) -> u32 {
+        self.week_of_month_with(Weekday::Sunday)
+    }
+
+    fn week_of_month_with(&self, week_start: Weekday) -> u32 {
+        let days = self.resolve_local().0;
+
+        days_to_week_of_month(days, week_start as u32)
+    }
+
+    fn week_of_year(&self, system: WeekNumbering) -> u32 {
+        let days = self.resolve_local().0;
+
+        system.week_of_year(days)
     }
 
     fn from_timestamp(timestamp: i64) -> Self {
@@ -698,6 +2341,21 @@ impl DateUtilities for DateTime {
         }
     }
 
+    fn add_quarters(&self, quarters: u32) -> Self {
+        let new_days = add_quarters(self.days, quarters);
+
+        let new_days = match new_days {
+            Ok(new_days) => new_days,
+            Err(e) => panic!("{}", e),
+        };
+
+        Self {
+            days: new_days,
+            nanoseconds: self.nanoseconds,
+            offset: self.offset,
+        }
+    }
+
     fn add_days(&self, days: u32) -> Self {
         let new_days = add_days(self.days, days);
 
@@ -743,6 +2401,21 @@ impl DateUtilities for DateTime {
         }
     }
 
+    fn sub_quarters(&self, quarters: u32) -> Self {
+        let new_days = sub_quarters(self.days, quarters);
+
+        let new_days = match new_days {
+            Ok(new_days) => new_days,
+            Err(e) => panic!("{}", e),
+        };
+
+        Self {
+            days: new_days,
+            nanoseconds: self.nanoseconds,
+            offset: self.offset,
+        }
+    }
+
     fn sub_days(&self, days: u32) -> Self {
         let new_days = sub_days(self.days, days);
 
@@ -758,6 +2431,94 @@ impl DateUtilities for DateTime {
         }
     }
 
+    fn saturating_add_years(&self, years: u32) -> Self {
+        match add_years(self.days, years) {
+            Ok(days) => self.saturating_from_nanos(days_nanos_to_nanos(days, self.nanoseconds)),
+            Err(_) => self.saturating_from_nanos(i128::MAX),
+        }
+    }
+
+    fn saturating_add_months(&self, months: u32) -> Self {
+        match add_months(self.days, months) {
+            Ok(days) => self.saturating_from_nanos(days_nanos_to_nanos(days, self.nanoseconds)),
+            Err(_) => self.saturating_from_nanos(i128::MAX),
+        }
+    }
+
+    fn saturating_add_quarters(&self, quarters: u32) -> Self {
+        match add_quarters(self.days, quarters) {
+            Ok(days) => self.saturating_from_nanos(days_nanos_to_nanos(days, self.nanoseconds)),
+            Err(_) => self.saturating_from_nanos(i128::MAX),
+        }
+    }
+
+    fn saturating_add_days(&self, days: u32) -> Self {
+        match add_days(self.days, days) {
+            Ok(days) => self.saturating_from_nanos(days_nanos_to_nanos(days, self.nanoseconds)),
+            Err(_) => self.saturating_from_nanos(i128::MAX),
+        }
+    }
+
+    fn saturating_sub_years(&self, years: u32) -> Self {
+        match sub_years(self.days, years) {
+            Ok(days) => self.saturating_from_nanos(days_nanos_to_nanos(days, self.nanoseconds)),
+            Err(_) => self.saturating_from_nanos(i128::MIN),
+        }
+    }
+
+    fn saturating_sub_months(&self, months: u32) -> Self {
+        match sub_months(self.days, months) {
+            Ok(days) => self.saturating_from_nanos(days_nanos_to_nanos(days, self.nanoseconds)),
+            Err(_) => self.saturating_from_nanos(i128::MIN),
+        }
+    }
+
+    fn saturating_sub_quarters(&self, quarters: u32) -> Self {
+        match sub_quarters(self.days, quarters) {
+            Ok(days) => self.saturating_from_nanos(days_nanos_to_nanos(days, self.nanoseconds)),
+            Err(_) => self.saturating_from_nanos(i128::MIN),
+        }
+    }
+
+    fn saturating_sub_days(&self, days: u32) -> Self {
+        match sub_days(self.days, days) {
+            Ok(days) => self.saturating_from_nanos(days_nanos_to_nanos(days, self.nanoseconds)),
+            Err(_) => self.saturating_from_nanos(i128::MIN),
+        }
+    }
+
+    fn first_day_of_quarter(&self) -> Self {
+        let offset_seconds = self.offset.resolve();
+        let (days, nanoseconds) = add_offset_to_dn(self.days, self.nanoseconds, offset_seconds);
+
+        let new_days = match first_day_of_quarter(days) {
+            Ok(new_days) => new_days,
+            Err(e) => panic!("{}", e),
+        };
+
+        Self {
+            days: remove_offset_from_dn(new_days, nanoseconds, offset_seconds).0,
+            nanoseconds: self.nanoseconds,
+            offset: self.offset,
+        }
+    }
+
+    fn last_day_of_quarter(&self) -> Self {
+        let offset_seconds = self.offset.resolve();
+        let (days, nanoseconds) = add_offset_to_dn(self.days, self.nanoseconds, offset_seconds);
+
+        let new_days = match last_day_of_quarter(days) {
+            Ok(new_days) => new_days,
+            Err(e) => panic!("{}", e),
+        };
+
+        Self {
+            days: remove_offset_from_dn(new_days, nanoseconds, offset_seconds).0,
+            nanoseconds: self.nanoseconds,
+            offset: self.offset,
+        }
+    }
+
     fn clear_until_year(&self) -> Self {
         Self {
             offset: self.offset,
@@ -785,34 +2546,31 @@ impl DateUtilities for DateTime {
         }
     }
 
+    /// Compares the calendar fields of both instances, each with its own offset applied.
     fn years_since(&self, compare: &Self) -> i32 {
-        years_between(
-            self.days,
-            self.nanoseconds,
-            compare.days,
-            compare.nanoseconds,
-        )
+        let (days, nanoseconds) =
+            add_offset_to_dn(self.days, self.nanoseconds, self.offset.resolve());
+        let (compare_days, compare_nanoseconds) =
+            add_offset_to_dn(compare.days, compare.nanoseconds, compare.offset.resolve());
+
+        years_between(days, nanoseconds, compare_days, compare_nanoseconds)
     }
 
+    /// Compares the calendar fields of both instances, each with its own offset applied.
     fn months_since(&self, compare: &Self) -> i32 {
-        months_between(
-            self.days,
-            self.nanoseconds,
-            compare.days,
-            compare.nanoseconds,
-        )
+        let (days, nanoseconds) =
+            add_offset_to_dn(self.days, self.nanoseconds, self.offset.resolve());
+        let (compare_days, compare_nanoseconds) =
+            add_offset_to_dn(compare.days, compare.nanoseconds, compare.offset.resolve());
+
+        months_between(days, nanoseconds, compare_days, compare_nanoseconds)
     }
 
+    /// Returns the completed 24-hour periods between both instants, truncated towards zero. The offsets of both instances are not relevant.
+    ///
+    /// See [`DateTime::calendar_days_since`] to compare the calendar dates instead.
     fn days_since(&self, compare: &Self) -> i64 {
-        let extra_day = if self.days > compare.days && self.nanoseconds < compare.nanoseconds {
-            -1
-        } else if self.days < compare.days && self.nanoseconds > compare.nanoseconds {
-            1
-        } else {
-            0
-        };
-
-        self.days as i64 - compare.days as i64 + extra_day
+        (self.nanos_since(compare) / NANOS_PER_DAY as i128) as i64
     }
 }
 
@@ -824,37 +2582,37 @@ impl DateUtilities for DateTime {
 
 impl TimeUtilities for DateTime {
     fn hour(&self) -> u32 {
-        let nanoseconds = add_offset_to_dn(self.days, self.nanoseconds, self.offset.resolve()).1;
+        let nanoseconds = self.resolve_local().1;
 
         nanos_to_time(nanoseconds).0
     }
 
     fn minute(&self) -> u32 {
-        let nanoseconds = add_offset_to_dn(self.days, self.nanoseconds, self.offset.resolve()).1;
+        let nanoseconds = self.resolve_local().1;
 
         nanos_to_time(nanoseconds).1
     }
 
     fn second(&self) -> u32 {
-        let nanoseconds = add_offset_to_dn(self.days, self.nanoseconds, self.offset.resolve()).1;
+        let nanoseconds = self.resolve_local().1;
 
         nanos_to_time(nanoseconds).2
     }
 
     fn milli(&self) -> u32 {
-        let nanoseconds = add_offset_to_dn(self.days, self.nanoseconds, self.offset.resolve()).1;
+        let nanoseconds = self.resolve_local().1;
 
         nanos_to_subsecond(nanoseconds).0
     }
 
     fn micro(&self) -> u32 {
-        let nanoseconds = add_offset_to_dn(self.days, self.nanoseconds, self.offset.resolve()).1;
+        let nanoseconds = self.resolve_local().1;
 
         nanos_to_subsecond(nanoseconds).1
     }
 
     fn nano(&self) -> u32 {
-        let nanoseconds = add_offset_to_dn(self.days, self.nanoseconds, self.offset.resolve()).1;
+        let nanoseconds = self.resolve_local().1;
 
         nanos_to_subsecond(nanoseconds).2
     }
@@ -1174,6 +2932,54 @@ impl TimeUtilities for DateTime {
         }
     }
 
+    fn saturating_add_hours(&self, hours: u32) -> Self {
+        self.saturating_shift(hours as i128 * NANOS_PER_HOUR as i128)
+    }
+
+    fn saturating_add_minutes(&self, minutes: u32) -> Self {
+        self.saturating_shift(minutes as i128 * NANOS_PER_MINUTE as i128)
+    }
+
+    fn saturating_add_seconds(&self, seconds: u32) -> Self {
+        self.saturating_shift(seconds as i128 * NANOS_PER_SEC as i128)
+    }
+
+    fn saturating_add_millis(&self, millis: u32) -> Self {
+        self.saturating_shift(millis as i128 * 1_000_000)
+    }
+
+    fn saturating_add_micros(&self, micros: u32) -> Self {
+        self.saturating_shift(micros as i128 * 1_000)
+    }
+
+    fn saturating_add_nanos(&self, nanos: u32) -> Self {
+        self.saturating_shift(nanos as i128)
+    }
+
+    fn saturating_sub_hours(&self, hours: u32) -> Self {
+        self.saturating_shift(-(hours as i128) * NANOS_PER_HOUR as i128)
+    }
+
+    fn saturating_sub_minutes(&self, minutes: u32) -> Self {
+        self.saturating_shift(-(minutes as i128) * NANOS_PER_MINUTE as i128)
+    }
+
+    fn saturating_sub_seconds(&self, seconds: u32) -> Self {
+        self.saturating_shift(-(seconds as i128) * NANOS_PER_SEC as i128)
+    }
+
+    fn saturating_sub_millis(&self, millis: u32) -> Self {
+        self.saturating_shift(-(millis as i128) * 1_000_000)
+    }
+
+    fn saturating_sub_micros(&self, micros: u32) -> Self {
+        self.saturating_shift(-(micros as i128) * 1_000)
+    }
+
+    fn saturating_sub_nanos(&self, nanos: u32) -> Self {
+        self.saturating_shift(-(nanos as i128))
+    }
+
     fn clear_until_hour(&self) -> Self {
         let offset_seconds = self.offset.resolve();
 
@@ -1347,27 +3153,17 @@ impl TimeUtilities for DateTime {
 
 impl OffsetUtilities for DateTime {
     fn set_offset(&self, offset: Offset) -> Self {
-        let offset_seconds = offset.resolve();
-
-        let offset_days = (self.as_seconds() + offset_seconds as i64) / SECS_PER_DAY_U64 as i64;
-        let offset_nanos = (self.nanoseconds / NANOS_PER_SEC) as i64 + offset_seconds as i64;
-        if offset_days < i32::MIN as i64
-            || offset_days > i32::MAX as i64
-            || (offset_days == i32::MIN as i64 && offset_nanos.is_negative())
-        {
-            panic!("Offset would result in an out of range date");
-        }
-
-        Self {
-            days: self.days,
-            nanoseconds: self.nanoseconds,
-            offset,
+        match self.try_set_offset(offset) {
+            Ok(date_time) => date_time,
+            Err(e) => panic!("{}", e),
         }
     }
 
     fn as_offset(&self, offset: Offset) -> Self {
-        let new_nanos = self.as_nanos() - offset.resolve() as i128 * NANOS_PER_SEC as i128;
-        Self::from_nanos(new_nanos).set_offset(offset)
+        match self.try_as_offset(offset) {
+            Ok(date_time) => date_time,
+            Err(e) => panic!("{}", e),
+        }
     }
 
     fn get_offset(&self) -> Offset {
@@ -1398,20 +3194,111 @@ impl DateTime {
         days_nanos_to_secs(self.days, self.nanoseconds)
     }
 
-    /// Creates a new [`DateTime`] instance from nanoseconds.
-    pub(crate) fn from_nanos(nanos: i128) -> Self {
-        let (days, nanoseconds) = nanos_to_days_nanos(nanos).unwrap();
+    /// Returns the number of nanoseconds since January 1, 0001 00:00:00 UTC. (Negative if date is before)
+    pub(crate) fn as_nanos(&self) -> i128 {
+        days_nanos_to_nanos(self.days, self.nanoseconds)
+    }
+
+    /// Shifts the instant by less than a day, returning `None` if the days would overflow
+    fn checked_step(&self, nanos: i64) -> Option<Self> {
+        let nanoseconds = self.nanoseconds as i64 + nanos;
+        let (days, nanoseconds) = if nanoseconds < 0 {
+            (
+                self.days.checked_sub(1)?,
+                nanoseconds + NANOS_PER_DAY as i64,
+            )
+        } else if nanoseconds >= NANOS_PER_DAY as i64 {
+            (
+                self.days.checked_add(1)?,
+                nanoseconds - NANOS_PER_DAY as i64,
+            )
+        } else {
+            (self.days, nanoseconds)
+        };
+
+        Some(Self {
+            days,
+            nanoseconds: nanoseconds as u64,
+            offset: self.offset,
+        })
+    }
+
+    /// Sets the offset of both instances, returning `None` if either local date would be out of range
+    fn pair_in(&self, other: &Self, offset: Offset) -> Option<(Self, Self)> {
+        Some((
+            self.try_set_offset(offset).ok()?,
+            other.try_set_offset(offset).ok()?,
+        ))
+    }
+
+    /// Writes the RFC 3339 timestamp into a buffer which fits every precision and returns it with the number of written bytes
+    fn rfc3339_bytes(&self, precision: Precision) -> ([u8; RFC3339_MAX_LEN], usize) {
+        let (days, nanoseconds, offset_seconds) = self.resolve_local();
+
+        let mut output = [0; RFC3339_MAX_LEN];
+        let length = write_rfc3339(
+            &mut output,
+            days,
+            nanoseconds,
+            offset_seconds,
+            precision.digits_for(nanos_to_subsecond(nanoseconds).2),
+        );
+        (output, length)
+    }
+
+    /// Resolves the offset once and returns the local days and nanoseconds together with the resolved offset in seconds
+    fn resolve_local(&self) -> (i32, u64, i32) {
+        let offset_seconds = self.offset.resolve();
+        let (days, nanoseconds) = add_offset_to_dn(self.days, self.nanoseconds, offset_seconds);
+        (days, nanoseconds, offset_seconds)
+    }
+
+    /// Aligns the local nanoseconds of the day to a multiple of `duration` using the given rounding function
+    fn align_to(
+        &self,
+        duration: Duration,
+        align: fn(u64, u64) -> u64,
+    ) -> Result<Self, AstrolabeError> {
+        let step = day_divisor_nanos(duration)?;
+        let offset_seconds = self.offset.resolve();
+
+        let (days, nanoseconds) = add_offset_to_dn(self.days, self.nanoseconds, offset_seconds);
+        let nanos = days_nanos_to_nanos(days, 0) + align(nanoseconds, step) as i128
+            - offset_seconds as i128 * NANOS_PER_SEC as i128;
+        let (days, nanoseconds) = nanos_to_days_nanos(nanos).map_err(|_| {
+            create_custom_oor(format!(
+                "Instance would result into an overflow if aligned to {} nanoseconds.",
+                step
+            ))
+        })?;
+
+        Ok(Self {
+            days,
+            nanoseconds,
+            offset: self.offset,
+        })
+    }
+
+    /// Creates a new instance with the offset of `self` from nanoseconds since January 1, 0001 00:00:00 UTC. Clamps the nanoseconds so that both the UTC and the local date and time are in range.
+    fn saturating_from_nanos(&self, nanoseconds: i128) -> Self {
+        let offset_nanos = self.offset.resolve() as i128 * NANOS_PER_SEC as i128;
+        let min = days_nanos_to_nanos(i32::MIN, 0);
+        let max = days_nanos_to_nanos(i32::MAX, NANOS_PER_DAY - 1);
+        let nanoseconds =
+            nanoseconds.clamp(min.max(min - offset_nanos), max.min(max - offset_nanos));
+        let (days, nanoseconds) =
+            nanos_to_days_nanos(nanoseconds).expect("Clamped nanoseconds are always in range");
 
         Self {
             days,
             nanoseconds,
-            offset: Offset::default(),
+            offset: self.offset,
         }
     }
 
-    /// Returns the number of nanoseconds since January 1, 0001 00:00:00 UTC. (Negative if date is before)
-    pub(crate) fn as_nanos(&self) -> i128 {
-        days_nanos_to_nanos(self.days, self.nanoseconds)
+    /// Adds the provided nanoseconds and clamps the result, see [`DateTime::saturating_from_nanos`]
+    fn saturating_shift(&self, nanoseconds: i128) -> Self {
+        self.saturating_from_nanos(self.as_nanos() + nanoseconds)
     }
 }
 
@@ -1469,9 +3356,59 @@ impl From<&Time> for DateTime {
     }
 }
 
+/// Formats the date and time as `yyyy/MM/dd HH:mm:ss`.
+///
+/// The precision flag appends up to 9 fractional digits of the second, the alternate flag formats an RFC 3339 timestamp including the offset, like [`DateTime::format_rfc3339`].
+///
+/// ```rust
+/// # use astrolabe::{DateTime, Offset, OffsetUtilities, TimeUtilities};
+/// let date_time = DateTime::from_ymdhms(2022, 5, 2, 15, 30, 20)
+///     .unwrap()
+///     .add_millis(123)
+///     .as_offset(Offset::from_hms(2, 0, 0).unwrap());
+/// assert_eq!("2022/05/02 15:30:20", format!("{}", date_time));
+/// assert_eq!("2022/05/02 15:30:20.123", format!("{:.3}", date_time));
+/// assert_eq!("2022-05-02T15:30:20+02:00", format!("{:#}", date_time));
+/// assert_eq!("2022-05-02T15:30:20.123000+02:00", format!("{:#.6}", date_time));
+/// ```
 impl Display for DateTime {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.format("yyyy/MM/dd HH:mm:ss"))
+        let (days, nanoseconds, offset_seconds) = self.resolve_local();
+        let digits = f.precision().map_or(0, |precision| precision.min(9) as u32);
+
+        if f.alternate() {
+            let date_time = Self {
+                offset: Offset::Fixed(offset_seconds),
+                ..*self
+            };
+            return write!(
+                f,
+                "{}",
+                date_time.format_rfc3339(Precision::Digits(digits as u8))
+            );
+        }
+
+        write!(
+            f,
+            "{}",
+            Self::format_resolved(
+                "yyyy/MM/dd HH:mm:ss",
+                &Locale::ENGLISH,
+                days,
+                nanoseconds,
+                offset_seconds,
+                None
+            )
+        )?;
+        if digits > 0 {
+            write!(
+                f,
+                ".{:0width$}",
+                nanos_to_subsecond(nanoseconds).2 / 10_u32.pow(9 - digits),
+                width = digits as usize
+            )?;
+        }
+        Ok(())
     }
 }
 
@@ -1492,6 +3429,12 @@ impl Ord for DateTime {
     }
 }
 
+impl Hash for DateTime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_nanos().hash(state);
+    }
+}
+
 impl FromStr for DateTime {
     type Err = AstrolabeError;
 
@@ -1536,6 +3479,25 @@ impl SubAssign<Time> for DateTime {
     }
 }
 
+impl Add<DateTimeDelta> for DateTime {
+    type Output = Self;
+
+    /// Adds the years and months of the delta in the calendar of this instance's offset, then the remaining units as exact durations. See [`DateTimeDelta`].
+    ///
+    /// Panics if the result would be out of range.
+    fn add(self, rhs: DateTimeDelta) -> Self::Output {
+        match self.add_relative(&rhs) {
+            Ok(date_time) => date_time,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+impl AddAssign<DateTimeDelta> for DateTime {
+    fn add_assign(&mut self, rhs: DateTimeDelta) {
+        *self = *self + rhs;
+    }
+}
+
 impl Add<Duration> for DateTime {
     type Output = Self;
 
@@ -0,0 +1,319 @@
+use crate::{
+    errors::{
+        invalid_format::create_positioned_invalid_format, out_of_range::create_custom_oor,
+        AstrolabeError,
+    },
+    util::constants::{NANOS_PER_DAY, NANOS_PER_HOUR, NANOS_PER_MINUTE, NANOS_PER_SEC},
+};
+use std::fmt::Display;
+
+/// Difference between two [`DateTime`](crate::DateTime) instances, broken down into calendar units.
+///
+/// Returned by [`DateTime::since`](crate::DateTime::since) and [`DateTime::calendar_duration_between`](crate::DateTime::calendar_duration_between), or parsed from an ISO 8601 duration with [`DateTimeDelta::parse_iso8601`]. All fields share the sign of the difference. Adding a delta to a [`DateTime`](crate::DateTime) first adds the years and months, clamping the day of month to the last day of the target month, and then adds the remaining units as exact durations.
+///
+/// ```rust
+/// # use astrolabe::{DateTime, DateTimeDelta};
+/// let start = DateTime::from_ymdhms(2022, 1, 31, 8, 0, 0).unwrap();
+/// let end = DateTime::from_ymdhms(2023, 3, 2, 12, 30, 0).unwrap();
+///
+/// let delta = end.since(&start);
+/// assert_eq!(1, delta.years);
+/// assert_eq!(1, delta.months);
+/// assert_eq!(2, delta.days);
+/// assert_eq!("1 year, 1 month, 2 days, 4 hours, 30 minutes", delta.to_string());
+/// assert_eq!(end, start + delta);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateTimeDelta {
+    /// Full years
+    pub years: i32,
+    /// Full months, in the range `-11..=11`
+    pub months: i32,
+    /// Full days
+    pub days: i32,
+    /// Full hours, in the range `-23..=23`
+    pub hours: i32,
+    /// Full minutes, in the range `-59..=59`
+    pub minutes: i32,
+    /// Full seconds, in the range `-59..=59`
+    pub seconds: i32,
+    /// Nanoseconds, in the range `-999_999_999..=999_999_999`
+    pub nanos: i32,
+}
+
+impl DateTimeDelta {
+    /// Creates a delta from a signed amount of months and the nanoseconds which remain after adding these months
+    pub(crate) fn from_months_nanos(months: i32, nanos: i128) -> Self {
+        let day_nanos = (nanos % NANOS_PER_DAY as i128) as i64;
+
+        Self {
+            years: months / 12,
+            months: months % 12,
+            days: (nanos / NANOS_PER_DAY as i128) as i32,
+            hours: (day_nanos / NANOS_PER_HOUR as i64) as i32,
+            minutes: (day_nanos % NANOS_PER_HOUR as i64 / NANOS_PER_MINUTE as i64) as i32,
+            seconds: (day_nanos % NANOS_PER_MINUTE as i64 / NANOS_PER_SEC as i64) as i32,
+            nanos: (day_nanos % NANOS_PER_SEC as i64) as i32,
+        }
+    }
+
+    /// Returns the total amount of months (years and months)
+    pub(crate) fn total_months(&self) -> i64 {
+        self.years as i64 * 12 + self.months as i64
+    }
+
+    /// Returns the total amount of nanoseconds of all units smaller than a month
+    pub(crate) fn submonth_nanos(&self) -> i128 {
+        self.days as i128 * NANOS_PER_DAY as i128
+            + self.hours as i128 * NANOS_PER_HOUR as i128
+            + self.minutes as i128 * NANOS_PER_MINUTE as i128
+            + self.seconds as i128 * NANOS_PER_SEC as i128
+            + self.nanos as i128
+    }
+
+    /// Returns `true` if the delta is negative
+    pub fn is_negative(&self) -> bool {
+        self.total_months() < 0 || self.submonth_nanos() < 0
+    }
+
+    /// Parses an ISO 8601 duration like `P1Y2M10DT2H30M` or `-PT1.5S`.
+    ///
+    /// Supports years (`Y`), months (`M`), weeks (`W`), days (`D`), hours (`H`), minutes (`M` after `T`) and seconds (`S`). Seconds can have up to 9 fractional digits, separated by `.` or `,`. A leading `-` negates the whole duration. The components have to be in this order and at least one of them is required.
+    ///
+    /// Larger values are carried into the next unit, e.g. `P14M` is 1 year and 2 months and `PT36H` is 1 day and 12 hours. Weeks are converted to 7 days.
+    ///
+    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error with the position of the problematic character if the string is not a valid duration, or an [`OutOfRange`](AstrolabeError::OutOfRange) error if the total months or days don't fit into an [`i32`].
+    ///
+    /// ```rust
+    /// # use astrolabe::DateTimeDelta;
+    /// let delta = DateTimeDelta::parse_iso8601("P1Y2M10DT2H30M").unwrap();
+    /// assert_eq!("1 year, 2 months, 10 days, 2 hours, 30 minutes", delta.to_string());
+    ///
+    /// let delta = DateTimeDelta::parse_iso8601("-P2WT1.5S").unwrap();
+    /// assert_eq!("-14 days, 1.5 seconds", delta.to_string());
+    /// ```
+    pub fn parse_iso8601(string: &str) -> Result<Self, AstrolabeError> {
+        let bytes = string.as_bytes();
+        let error = |message: &str, position: usize| {
+            let token = string[position..].chars().next().map_or("", |char| {
+                let end = position + char.len_utf8();
+                &string[position..end]
+            });
+            create_positioned_invalid_format(
+                format!("Failed parsing ISO 8601 duration: {}", message),
+                position,
+                token,
+            )
+        };
+
+        let mut position = 0;
+        let negative = match bytes.first() {
+            Some(b'-') => {
+                position += 1;
+                true
+            }
+            Some(b'+') => {
+                position += 1;
+                false
+            }
+            _ => false,
+        };
+        if bytes.get(position) != Some(&b'P') {
+            return Err(error("Expected 'P'.", position));
+        }
+        position += 1;
+
+        let mut months: i128 = 0;
+        let mut nanos: i128 = 0;
+        let mut in_time = false;
+        let mut has_component = false;
+        let mut has_time_component = false;
+        // Index of the last parsed designator, to enforce their order
+        let mut last_designator = None;
+
+        while position < bytes.len() {
+            if bytes[position] == b'T' {
+                if in_time {
+                    return Err(error("Unexpected second 'T'.", position));
+                }
+                in_time = true;
+                position += 1;
+                continue;
+            }
+
+            let start = position;
+            while bytes.get(position).map_or(false, u8::is_ascii_digit) {
+                position += 1;
+            }
+            if start == position {
+                return Err(error("Expected a number.", position));
+            }
+            let value = string[start..position]
+                .parse::<u64>()
+                .map_err(|_| error("Number is too large.", start))? as i128;
+
+            let mut fraction = 0;
+            if in_time && matches!(bytes.get(position), Some(b'.') | Some(b',')) {
+                position += 1;
+                let fraction_start = position;
+                while bytes.get(position).map_or(false, u8::is_ascii_digit) {
+                    position += 1;
+                }
+                let digits = position - fraction_start;
+                if digits == 0 || digits > 9 {
+                    return Err(error("Expected 1 to 9 fractional digits.", fraction_start));
+                }
+                // Using unwrap because the fraction only consists of up to 9 digits
+                fraction = string[fraction_start..position].parse::<i128>().unwrap()
+                    * 10_i128.pow(9 - digits as u32);
+                if bytes.get(position) != Some(&b'S') {
+                    return Err(error("Only seconds can have fractional digits.", position));
+                }
+            }
+
+            let (index, unit_nanos) = match (in_time, bytes.get(position)) {
+                (false, Some(b'Y')) => (0, 0),
+                (false, Some(b'M')) => (1, 0),
+                (false, Some(b'W')) => (2, 7 * NANOS_PER_DAY),
+                (false, Some(b'D')) => (3, NANOS_PER_DAY),
+                (true, Some(b'H')) => (4, NANOS_PER_HOUR),
+                (true, Some(b'M')) => (5, NANOS_PER_MINUTE),
+                (true, Some(b'S')) => (6, NANOS_PER_SEC),
+                (_, Some(_)) => return Err(error("Unexpected designator.", position)),
+                (_, None) => return Err(error("Expected a designator.", position)),
+            };
+            if last_designator.map_or(false, |last| last >= index) {
+                return Err(error("Designator is out of order.", position));
+            }
+            last_designator = Some(index);
+            position += 1;
+
+            match index {
+                0 => months += value * 12,
+                1 => months += value,
+                _ => nanos += value * unit_nanos as i128 + fraction,
+            }
+            has_component = true;
+            has_time_component |= in_time;
+        }
+
+        if in_time && !has_time_component {
+            return Err(error("Expected a time component after 'T'.", position));
+        }
+        if !has_component {
+            return Err(error("Expected at least one component.", position));
+        }
+
+        if months > i32::MAX as i128 {
+            return Err(create_custom_oor(format!(
+                "{} months are out of range",
+                months
+            )));
+        }
+        if nanos / NANOS_PER_DAY as i128 > i32::MAX as i128 {
+            return Err(create_custom_oor(format!(
+                "{} days are out of range",
+                nanos / NANOS_PER_DAY as i128
+            )));
+        }
+
+        let sign = if negative { -1 } else { 1 };
+        Ok(Self::from_months_nanos(
+            months as i32 * sign,
+            nanos * sign as i128,
+        ))
+    }
+
+    /// Formats the delta as an ISO 8601 duration like `P1Y2M10DT2H30M`, which can be parsed with [`DateTimeDelta::parse_iso8601`].
+    ///
+    /// Zero components are skipped and an empty delta is formatted as `PT0S`. Negative deltas are prefixed with `-`. Like [`Display`], the absolute values of the fields are used, so their signs should match.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, DateTimeDelta};
+    /// let start = DateTime::from_ymdhms(2022, 1, 31, 8, 0, 0).unwrap();
+    /// let end = DateTime::from_ymdhms(2023, 3, 2, 12, 30, 0).unwrap();
+    /// assert_eq!("P1Y1M2DT4H30M", end.since(&start).format_iso8601());
+    /// assert_eq!("-P1Y1M2DT4H30M", start.since(&end).format_iso8601());
+    /// assert_eq!("PT0S", DateTimeDelta::default().format_iso8601());
+    /// ```
+    pub fn format_iso8601(&self) -> String {
+        let mut formatted = String::from(if self.is_negative() { "-P" } else { "P" });
+
+        for (value, designator) in [(self.years, 'Y'), (self.months, 'M'), (self.days, 'D')] {
+            if value != 0 {
+                formatted.push_str(&format!("{}{}", value.unsigned_abs(), designator));
+            }
+        }
+
+        let seconds = self.seconds.unsigned_abs();
+        let nanos = self.nanos.unsigned_abs();
+        if self.hours != 0 || self.minutes != 0 || seconds != 0 || nanos != 0 {
+            formatted.push('T');
+            for (value, designator) in [(self.hours, 'H'), (self.minutes, 'M')] {
+                if value != 0 {
+                    formatted.push_str(&format!("{}{}", value.unsigned_abs(), designator));
+                }
+            }
+            if nanos != 0 {
+                let fraction = format!("{:09}", nanos);
+                formatted.push_str(&format!("{}.{}S", seconds, fraction.trim_end_matches('0')));
+            } else if seconds != 0 {
+                formatted.push_str(&format!("{}S", seconds));
+            }
+        } else if formatted.len() <= 2 {
+            formatted = String::from("PT0S");
+        }
+
+        formatted
+    }
+}
+
+/// Formats the delta as a comma separated list of its non-zero units, e.g. `1 year, 2 months, 3.5 seconds`.
+///
+/// Negative deltas are prefixed with `-`. An empty delta is formatted as `0 seconds`.
+impl Display for DateTimeDelta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+        for (value, unit) in [
+            (self.years, "year"),
+            (self.months, "month"),
+            (self.days, "day"),
+            (self.hours, "hour"),
+            (self.minutes, "minute"),
+        ] {
+            if value != 0 {
+                let value = value.unsigned_abs();
+                parts.push(format!(
+                    "{} {}{}",
+                    value,
+                    unit,
+                    if value == 1 { "" } else { "s" }
+                ));
+            }
+        }
+
+        let seconds = self.seconds.unsigned_abs();
+        let nanos = self.nanos.unsigned_abs();
+        if nanos != 0 {
+            let fraction = format!("{:09}", nanos);
+            parts.push(format!(
+                "{}.{} seconds",
+                seconds,
+                fraction.trim_end_matches('0')
+            ));
+        } else if seconds != 0 || parts.is_empty() {
+            parts.push(format!(
+                "{} second{}",
+                seconds,
+                if seconds == 1 { "" } else { "s" }
+            ));
+        }
+
+        write!(
+            f,
+            "{}{}",
+            if self.is_negative() { "-" } else { "" },
+            parts.join(", ")
+        )
+    }
+}
@@ -0,0 +1,32 @@
+use std::fmt;
+
+/// An error indicating that a provided buffer is too small for the output, e.g. in [`DateTime::format_rfc3339_buf`](crate::DateTime::format_rfc3339_buf).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BufferTooSmall {
+    pub(crate) required: usize,
+    pub(crate) available: usize,
+}
+
+impl BufferTooSmall {
+    /// Returns the length the buffer needs to have.
+    pub fn required(&self) -> usize {
+        self.required
+    }
+
+    /// Returns the length of the provided buffer.
+    pub fn available(&self) -> usize {
+        self.available
+    }
+}
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Buffer is too small, {} bytes are required but only {} are available",
+            self.required, self.available
+        )
+    }
+}
+
+impl std::error::Error for BufferTooSmall {}
@@ -3,14 +3,75 @@ use std::fmt;
 
 /// An error indicating that the string to be parsed is invalid.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct InvalidFormat(String);
+pub struct InvalidFormat {
+    pub(crate) message: String,
+    pub(crate) position: Option<usize>,
+    pub(crate) token: Option<String>,
+    pub(crate) field: Option<&'static str>,
+}
+
+impl InvalidFormat {
+    /// Returns the error message. Same as the [`Display`](fmt::Display) output.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the byte position where the error occurred, if known. For parse errors, this is the position in the parsed string where the failing format symbol starts. For errors about the format string itself (E.g. from [`validate_format`](crate::validate_format)), it's the position in the format string. For cron expressions, it's the position of the invalid token in the expression.
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// Returns the part of the format string which caused the error, if known, e.g. `MMM` if a month name couldn't be parsed. For cron expressions, this is the invalid part of the field, e.g. `Mondy`.
+    pub fn token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+
+    /// Returns the name of the field which couldn't be parsed, if known. Set for cron expressions, e.g. `day of week`.
+    pub fn field_name(&self) -> Option<&str> {
+        self.field
+    }
+}
 
 impl fmt::Display for InvalidFormat {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.message)
     }
 }
 
+impl std::error::Error for InvalidFormat {}
+
 pub(crate) fn create_invalid_format(message: String) -> AstrolabeError {
-    AstrolabeError::InvalidFormat(InvalidFormat(message))
+    AstrolabeError::InvalidFormat(InvalidFormat {
+        message,
+        position: None,
+        token: None,
+        field: None,
+    })
+}
+
+pub(crate) fn create_positioned_invalid_format(
+    message: String,
+    position: usize,
+    token: &str,
+) -> AstrolabeError {
+    AstrolabeError::InvalidFormat(InvalidFormat {
+        message,
+        position: Some(position),
+        token: Some(token.to_string()),
+        field: None,
+    })
+}
+
+pub(crate) fn create_field_invalid_format(
+    message: String,
+    field: &'static str,
+    position: usize,
+    token: &str,
+) -> AstrolabeError {
+    AstrolabeError::InvalidFormat(InvalidFormat {
+        message,
+        position: Some(position),
+        token: Some(token.to_string()),
+        field: Some(field),
+    })
 }
@@ -0,0 +1,47 @@
+use super::AstrolabeError;
+use std::fmt;
+
+/// An error indicating that the local time zone could not be determined, see [`Offset::try_local`](crate::Offset::try_local).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LocalTimeZone {
+    pub(crate) kind: LocalTimeZoneKind,
+    pub(crate) message: String,
+}
+
+/// Reason why the local time zone could not be determined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LocalTimeZoneKind {
+    /// The TZif file of the local time zone (`/etc/localtime`) doesn't exist
+    NotFound,
+    /// The TZif file of the local time zone exists, but couldn't be read (E.g. missing permissions)
+    Unreadable,
+    /// The TZif file of the local time zone couldn't be parsed
+    InvalidFile,
+    /// Reading the local time zone is not supported on this platform. Only UNIX systems are supported.
+    UnsupportedPlatform,
+}
+
+impl LocalTimeZone {
+    /// Returns the reason why the local time zone could not be determined.
+    pub fn kind(&self) -> LocalTimeZoneKind {
+        self.kind
+    }
+
+    /// Returns the error message, including the underlying error. Same as the [`Display`](fmt::Display) output.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for LocalTimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for LocalTimeZone {}
+
+pub(crate) fn create_local_time_zone(kind: LocalTimeZoneKind, message: String) -> AstrolabeError {
+    AstrolabeError::LocalTimeZone(LocalTimeZone { kind, message })
+}
@@ -1,17 +1,29 @@
 //! Various error types returned by functions in the astrolabe crate.
 
+pub(crate) mod buffer_too_small;
 pub(crate) mod invalid_format;
+pub(crate) mod local_time_zone;
 pub(crate) mod out_of_range;
-pub use self::{invalid_format::InvalidFormat, out_of_range::OutOfRange};
-use std::fmt;
+pub use self::{
+    buffer_too_small::BufferTooSmall,
+    invalid_format::InvalidFormat,
+    local_time_zone::{LocalTimeZone, LocalTimeZoneKind},
+    out_of_range::OutOfRange,
+};
+use std::{fmt, ops::Range};
 
 /// Custom error enum for the astrolabe crate.
+///
+/// The wrapped error is also returned by [`source`](std::error::Error::source). New variants may be added in the future.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AstrolabeError {
     /// An error indicating that some given parameter is out of range or resulted in an out of range date/time value.
     OutOfRange(OutOfRange),
     /// An error indicating that the string to be parsed is invalid.
     InvalidFormat(InvalidFormat),
+    /// An error indicating that the local time zone could not be determined.
+    LocalTimeZone(LocalTimeZone),
 }
 
 impl fmt::Display for AstrolabeError {
@@ -19,10 +31,44 @@ impl fmt::Display for AstrolabeError {
         match self {
             Self::OutOfRange(e) => e.fmt(f),
             Self::InvalidFormat(e) => e.fmt(f),
+            Self::LocalTimeZone(e) => e.fmt(f),
         }
     }
 }
 
+impl std::error::Error for AstrolabeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::OutOfRange(e) => Some(e),
+            Self::InvalidFormat(e) => Some(e),
+            Self::LocalTimeZone(e) => Some(e),
+        }
+    }
+}
+
+impl AstrolabeError {
+    /// Sets the position and token of an [`InvalidFormat`] error if they aren't set yet
+    pub(crate) fn at_position(mut self, position: usize, token: &str) -> Self {
+        if let Self::InvalidFormat(e) = &mut self {
+            if e.position.is_none() {
+                e.position = Some(position);
+                e.token = Some(token.to_string());
+            }
+        }
+        self
+    }
+
+    /// Sets the span of an [`OutOfRange`] error if it isn't set yet
+    pub(crate) fn with_span(mut self, span: Option<Range<usize>>) -> Self {
+        if let Self::OutOfRange(e) = &mut self {
+            if e.span.is_none() {
+                e.span = span.map(Box::new);
+            }
+        }
+        self
+    }
+}
+
 impl From<AstrolabeError> for String {
     fn from(e: AstrolabeError) -> Self {
         e.to_string()
@@ -1,5 +1,5 @@
 use super::AstrolabeError;
-use std::fmt;
+use std::{fmt, ops::Range};
 
 /// An error indicating that some given parameter is out of range or resulted in an out of range date/time value.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -8,13 +8,50 @@ pub struct OutOfRange {
     pub(crate) min: i128,
     pub(crate) max: i128,
     pub(crate) value: i128,
-    pub(crate) custom: Option<String>,
-    pub(crate) conditional: Option<String>,
+    pub(crate) message: Option<Message>,
+    // Boxed to keep the error small, as it's rarely set
+    pub(crate) span: Option<Box<Range<usize>>>,
+}
+
+/// Additional message of an [`OutOfRange`] error
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum Message {
+    /// Replaces the default message
+    Custom(String),
+    /// Appended to the default message
+    Conditional(String),
+}
+
+impl OutOfRange {
+    /// Returns the name of the field which is out of range, e.g. `minute`. Empty for errors with a custom message.
+    pub fn field_name(&self) -> &str {
+        self.name
+    }
+
+    /// Returns the smallest valid value of the field. `0` for errors with a custom message.
+    pub fn min(&self) -> i128 {
+        self.min
+    }
+
+    /// Returns the largest valid value of the field. `0` for errors with a custom message.
+    pub fn max(&self) -> i128 {
+        self.max
+    }
+
+    /// Returns the value which is out of range. `0` for errors with a custom message.
+    pub fn value(&self) -> i128 {
+        self.value
+    }
+
+    /// Returns the byte range of the value in the parsed string, if the error occurred while building the date from the parsed fields. E.g. `0..2` if the day in `31-02-2022` parsed with `dd-MM-yyyy` doesn't exist in the parsed month.
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.as_deref().cloned()
+    }
 }
 
 impl fmt::Display for OutOfRange {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(custom) = &self.custom {
+        if let Some(Message::Custom(custom)) = &self.message {
             write!(f, "{}", custom).unwrap();
             return Ok(());
         }
@@ -26,7 +63,7 @@ impl fmt::Display for OutOfRange {
         )
         .unwrap();
 
-        if let Some(conditional) = &self.conditional {
+        if let Some(Message::Conditional(conditional)) = &self.message {
             write!(f, ", {}", conditional).unwrap();
         }
 
@@ -34,6 +71,8 @@ impl fmt::Display for OutOfRange {
     }
 }
 
+impl std::error::Error for OutOfRange {}
+
 pub(crate) fn create_simple_oor(
     name: &'static str,
     min: i128,
@@ -45,8 +84,8 @@ pub(crate) fn create_simple_oor(
         min,
         max,
         value,
-        custom: None,
-        conditional: None,
+        message: None,
+        span: None,
     })
 }
 
@@ -62,8 +101,8 @@ pub(crate) fn create_conditional_oor(
         min,
         max,
         value,
-        custom: None,
-        conditional: Some(conditional),
+        message: Some(Message::Conditional(conditional)),
+        span: None,
     })
 }
 
@@ -73,7 +112,7 @@ pub(crate) fn create_custom_oor(custom: String) -> AstrolabeError {
         min: 0,
         max: 0,
         value: 0,
-        custom: Some(custom),
-        conditional: None,
+        message: Some(Message::Custom(custom)),
+        span: None,
     })
 }
@@ -0,0 +1,91 @@
+use crate::Date;
+use std::collections::{btree_set, BTreeSet};
+
+/// A set of holidays which are skipped by the business day methods of [`Date`], in addition to Saturdays and Sundays.
+///
+/// The calendar is filled by the caller, astrolabe does not ship any holiday data.
+///
+/// ```rust
+/// # use astrolabe::{Date, HolidayCalendar};
+/// let mut calendar = HolidayCalendar::new();
+/// calendar.insert(Date::from_ymd(2022, 12, 26).unwrap());
+///
+/// // Friday, 23. December 2022
+/// let date = Date::from_ymd(2022, 12, 23).unwrap();
+/// assert_eq!("2022/12/27", date.add_business_days_with(1, &calendar).to_string());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct HolidayCalendar {
+    holidays: BTreeSet<Date>,
+}
+
+impl HolidayCalendar {
+    /// Creates an empty [`HolidayCalendar`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a holiday. Returns `false` if the date was already present.
+    pub fn insert(&mut self, date: Date) -> bool {
+        self.holidays.insert(date)
+    }
+
+    /// Removes a holiday. Returns `false` if the date was not present.
+    pub fn remove(&mut self, date: &Date) -> bool {
+        self.holidays.remove(date)
+    }
+
+    /// Returns `true` if the given date is a holiday.
+    pub fn contains(&self, date: &Date) -> bool {
+        self.holidays.contains(date)
+    }
+
+    /// Returns the number of holidays.
+    pub fn len(&self) -> usize {
+        self.holidays.len()
+    }
+
+    /// Returns `true` if the calendar contains no holidays.
+    pub fn is_empty(&self) -> bool {
+        self.holidays.is_empty()
+    }
+
+    /// Returns an iterator over the holidays in ascending order.
+    pub fn iter(&self) -> btree_set::Iter<'_, Date> {
+        self.holidays.iter()
+    }
+
+    /// Returns the number of holidays from `start` to `end` (both inclusive) which are not on a weekend
+    pub(crate) fn weekday_holidays_between(&self, start: i32, end: i32) -> u64 {
+        if start > end {
+            return 0;
+        }
+        self.holidays
+            .range(Date { days: start }..=Date { days: end })
+            .filter(|date| !date.is_weekend())
+            .count() as u64
+    }
+}
+
+impl FromIterator<Date> for HolidayCalendar {
+    fn from_iter<I: IntoIterator<Item = Date>>(iter: I) -> Self {
+        Self {
+            holidays: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<Date> for HolidayCalendar {
+    fn extend<I: IntoIterator<Item = Date>>(&mut self, iter: I) {
+        self.holidays.extend(iter);
+    }
+}
+
+impl<'a> IntoIterator for &'a HolidayCalendar {
+    type Item = &'a Date;
+    type IntoIter = btree_set::Iter<'a, Date>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.holidays.iter()
+    }
+}
@@ -9,14 +9,19 @@
 //! - **Local** timezone on UNIX platforms
 //! - **Zero** dependencies
 //! - **Serde** serializing and deserializing (With feature flag `serde`)
-//! - **sqlx** postgres encoding and decoding (With feature flag `sqlx-postgres`)
+//! - **sqlx** postgres, MySQL and SQLite encoding and decoding (With feature flags `sqlx-postgres`, `sqlx-mysql` and `sqlx-sqlite`)
+//! - **chrono** conversions from and to chrono types (With feature flag `chrono`)
+//! - **time** conversions from and to time types (With feature flag `time`)
+//! - **Arbitrary** values for fuzzing and property-based testing (With feature flag `arbitrary`)
+//! - **rand** random values across the whole range or within a range (With feature flag `rand`)
+//! - **Mocked current time** for deterministic tests (With feature flag `test-util`)
 //!
 //! ## Examples
 //! ### Basic
 //! A basic example which demonstrates creating, formatting and manipulating a `DateTime` instance.
 //!
 //! ```rust
-//! use astrolabe::{DateTime, TimeUtilities, Precision};
+//! use astrolabe::prelude::*;
 //!
 //! // Create a DateTime instance from year, month, and days (day of month)
 //! let date_time = DateTime::from_ymd(2022, 5, 2).unwrap();
@@ -33,6 +38,8 @@
 //! ```
 //! To see all implementations for the `DateTime` struct, check out it's [documentation](https://docs.rs/astrolabe/latest/astrolabe/struct.DateTime.html).
 //!
+//! The [`prelude`] re-exports all types and traits needed for the examples above, so `use astrolabe::prelude::*;` is usually all you need.
+//!
 //! ### Local timezone (UNIX systems only)
 //! Astrolabe can parse the timezone from `/etc/localtime` to get the local UTC offset. This only works on UNIX systems.
 //!
@@ -55,7 +62,7 @@
 //!
 //! // Every 5 minutes
 //! let schedule = CronSchedule::parse("*/5 * * * *").unwrap();
-//! for date in schedule.take(3) {
+//! for date in schedule.upcoming().take(3) {
 //!    println!("{}", date);
 //! }
 //! // Prints for example:
@@ -65,7 +72,7 @@
 //!
 //! // Every weekday at 10:00
 //! let schedule = CronSchedule::parse("0 10 * * Mon-Fri").unwrap();
-//! for date in schedule.take(3) {
+//! for date in schedule.upcoming().take(3) {
 //!    println!("{}", date.format("yyyy-MM-dd HH:mm:ss eeee"));
 //! }
 //! // Prints for example:
@@ -86,25 +93,59 @@
 #![allow(clippy::many_single_char_names)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+mod arbitrary;
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+mod chrono;
 mod cron;
 mod date;
 mod datetime;
+mod delta;
 pub mod errors;
+mod holiday;
 mod local;
+mod locale;
+mod macros;
 mod offset;
+mod options;
+pub mod prelude;
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+mod rand;
+mod range;
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-mod serde;
+pub mod serde;
 mod shared;
 #[cfg(feature = "sqlx")]
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
 mod sqlx;
+#[cfg(feature = "test-util")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-util")))]
+pub mod testing;
 mod time;
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+mod time_interop;
 mod util;
 
-pub use self::cron::CronSchedule;
+pub use self::cron::{CronSchedule, Upcoming};
 pub use self::date::Date;
 pub use self::datetime::DateTime;
+pub use self::delta::DateTimeDelta;
+pub use self::holiday::HolidayCalendar;
+pub use self::locale::{FirstWeekday, Locale};
 pub use self::offset::Offset;
-pub use self::shared::{DateUtilities, OffsetUtilities, Precision, TimeUtilities};
+pub use self::options::{EraTokens, ParseOptions, PeriodTokens, RelativeThresholds, TwoDigitYear};
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub use self::rand::{UniformDate, UniformDateTime, UniformTime};
+pub use self::range::{DateRange, DateTimeRange};
+pub use self::shared::{
+    Bounds, DateUnit, DateUtilities, Era, MonthOverflow, OffsetUtilities, Precision, TimeUnit,
+    TimeUtilities, Unit, WeekNumbering, Weekday,
+};
 pub use self::time::Time;
+pub use self::util::format::{is_valid_format, validate_format};
@@ -15,7 +15,8 @@ pub(super) struct DataBlock<'a> {
     pub(super) transition_types: &'a [u8],
     /// Local time types specifying UTC offsets and DST
     pub(super) local_time_types: &'a [u8],
-    _time_zone_designations: &'a [u8],
+    /// NUL-terminated time zone designations, referenced by the local time types
+    pub(super) time_zone_designations: &'a [u8],
     _leap_seconds: &'a [u8],
     _standard_wall: &'a [u8],
     _ut_local: &'a [u8],
@@ -38,7 +39,7 @@ impl<'a> DataBlock<'a> {
             transition_times: cursor.read_exact(header.transition_count * time_size)?,
             transition_types: cursor.read_exact(header.transition_count)?,
             local_time_types: cursor.read_exact(header.type_count * 6)?,
-            _time_zone_designations: cursor.read_exact(header.char_count)?,
+            time_zone_designations: cursor.read_exact(header.char_count)?,
             _leap_seconds: cursor.read_exact(header.leap_count * (time_size + 4))?,
             _standard_wall: cursor.read_exact(header.isstd_count)?,
             _ut_local: cursor.read_exact(header.isut_count)?,
@@ -63,7 +63,9 @@ impl TimeZone {
         for local_time_type in data_block.local_time_types.chunks_exact(6) {
             let utoff = i32::from_be_bytes(local_time_type[0..4].try_into().expect(BUG_MSG));
             let dst = local_time_type[4] != 0;
-            local_time_types.push(LocalTimeType::new(utoff, dst));
+            let designation =
+                read_designation(data_block.time_zone_designations, local_time_type[5]);
+            local_time_types.push(LocalTimeType::new(utoff, dst).with_designation(designation));
         }
 
         let extra_rule = if let Some(footer) = footer {
@@ -151,6 +153,15 @@ impl Transition {
     }
 }
 
+/// Reads the NUL-terminated designation starting at `index`. Invalid designations are treated as unknown, as they are only used for formatting
+fn read_designation(designations: &[u8], index: u8) -> &str {
+    designations
+        .get(index as usize..)
+        .and_then(|designation| designation.split(|&byte| byte == 0).next())
+        .and_then(|designation| std::str::from_utf8(designation).ok())
+        .unwrap_or_default()
+}
+
 /// Local time type of a TZif file
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct LocalTimeType {
@@ -158,20 +169,37 @@ pub(crate) struct LocalTimeType {
     pub(crate) utoff: i32,
     /// If the local time type is considered DST
     _dst: bool,
+    /// Time zone designation, e.g. `CEST`. Empty if unknown
+    pub(crate) designation: String,
 }
 
 impl LocalTimeType {
     pub(super) fn new(utoff: i32, dst: bool) -> Self {
-        Self { utoff, _dst: dst }
+        Self {
+            utoff,
+            _dst: dst,
+            designation: String::new(),
+        }
+    }
+
+    pub(super) fn with_designation(self, designation: &str) -> Self {
+        Self {
+            designation: designation.to_string(),
+            ..self
+        }
     }
 }
 
 #[cfg(test)]
 mod local_tests {
-    use crate::local::{
-        header::Version,
-        timezone::{LocalTimeType, TimeZone, Transition},
-        transition_rule::{AlternateLocalTimeType, RuleDay, TransitionRule},
+    use crate::{
+        local::{
+            header::Version,
+            timezone::{LocalTimeType, TimeZone, Transition},
+            transition_rule::{AlternateLocalTimeType, RuleDay, TransitionRule},
+        },
+        util::format::format_time_part,
+        Locale,
     };
 
     #[test]
@@ -182,7 +210,7 @@ mod local_tests {
 
         let time_zone_result = TimeZone {
             transitions: vec![],
-            local_time_types: vec![LocalTimeType::new(0, false)],
+            local_time_types: vec![LocalTimeType::new(0, false).with_designation("UTC")],
             extra_rule: None,
         };
 
@@ -206,25 +234,27 @@ mod local_tests {
                 Transition::new(-712150200, 5),
             ],
             local_time_types: vec![
-                LocalTimeType::new(-37886, false),
-                LocalTimeType::new(-37800, false),
-                LocalTimeType::new(-34200, true),
-                LocalTimeType::new(-34200, true),
-                LocalTimeType::new(-34200, true),
-                LocalTimeType::new(-36000, false),
+                LocalTimeType::new(-37886, false).with_designation("LMT"),
+                LocalTimeType::new(-37800, false).with_designation("HST"),
+                LocalTimeType::new(-34200, true).with_designation("HDT"),
+                LocalTimeType::new(-34200, true).with_designation("HWT"),
+                LocalTimeType::new(-34200, true).with_designation("HPT"),
+                LocalTimeType::new(-36000, false).with_designation("HST"),
             ],
-            extra_rule: Some(TransitionRule::Fixed(LocalTimeType::new(-36000, false))),
+            extra_rule: Some(TransitionRule::Fixed(
+                LocalTimeType::new(-36000, false).with_designation("HST"),
+            )),
         };
 
         assert_eq!(time_zone, time_zone_result);
 
         assert_eq!(
             time_zone.to_local_time_type(-1156939200),
-            LocalTimeType::new(-34200, true)
+            LocalTimeType::new(-34200, true).with_designation("HDT")
         );
         assert_eq!(
             time_zone.to_local_time_type(1546300800),
-            LocalTimeType::new(-36000, false)
+            LocalTimeType::new(-36000, false).with_designation("HST")
         );
     }
 
@@ -236,12 +266,12 @@ mod local_tests {
 
         let time_zone_result = TimeZone {
             transitions: vec![Transition::new(2145916800, 0)],
-            local_time_types: vec![LocalTimeType::new(7200, false)],
+            local_time_types: vec![LocalTimeType::new(7200, false).with_designation("IST")],
             extra_rule: Some(TransitionRule::Alternate(AlternateLocalTimeType::new(
-                LocalTimeType::new(7200, false),
+                LocalTimeType::new(7200, false).with_designation("IST"),
                 RuleDay::MonthWeekDay(3, 4, 4),
                 93600,
-                LocalTimeType::new(10800, true),
+                LocalTimeType::new(10800, true).with_designation("IDT"),
                 RuleDay::MonthWeekDay(10, 5, 0),
                 7200,
             ))),
@@ -258,62 +288,62 @@ mod local_tests {
         let time_zone = TimeZone::from_tzif(bytes).unwrap();
         assert_eq!(
             Some(TransitionRule::Alternate(AlternateLocalTimeType::new(
-                LocalTimeType::new(3600, false),
+                LocalTimeType::new(3600, false).with_designation("CET"),
                 RuleDay::JulianDayWithoutLeap(100),
                 7200,
-                LocalTimeType::new(7200, true),
+                LocalTimeType::new(7200, true).with_designation("CEST"),
                 RuleDay::JulianDayWithoutLeap(200),
                 7200,
             ))),
             time_zone.extra_rule
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1672531200)
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1681088400 - 1)
         );
         assert_eq!(
-            LocalTimeType::new(7200, true),
+            LocalTimeType::new(7200, true).with_designation("CEST"),
             time_zone.to_local_time_type(1681088400)
         );
         assert_eq!(
-            LocalTimeType::new(7200, true),
+            LocalTimeType::new(7200, true).with_designation("CEST"),
             time_zone.to_local_time_type(1689724800 - 1)
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1689724800)
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1704067199)
         );
 
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1704067200)
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1712710800 - 1)
         );
         assert_eq!(
-            LocalTimeType::new(7200, true),
+            LocalTimeType::new(7200, true).with_designation("CEST"),
             time_zone.to_local_time_type(1712710800)
         );
         assert_eq!(
-            LocalTimeType::new(7200, true),
+            LocalTimeType::new(7200, true).with_designation("CEST"),
             time_zone.to_local_time_type(1721347200 - 1)
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1721347200)
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1735689599)
         );
 
@@ -322,62 +352,62 @@ mod local_tests {
         let time_zone = TimeZone::from_tzif(bytes).unwrap();
         assert_eq!(
             Some(TransitionRule::Alternate(AlternateLocalTimeType::new(
-                LocalTimeType::new(3600, false),
+                LocalTimeType::new(3600, false).with_designation("CET"),
                 RuleDay::JulianDayWithLeap(99),
                 7200,
-                LocalTimeType::new(7200, true),
+                LocalTimeType::new(7200, true).with_designation("CEST"),
                 RuleDay::JulianDayWithLeap(199),
                 7200,
             ))),
             time_zone.extra_rule
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1672531200)
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1681088400 - 1)
         );
         assert_eq!(
-            LocalTimeType::new(7200, true),
+            LocalTimeType::new(7200, true).with_designation("CEST"),
             time_zone.to_local_time_type(1681088400)
         );
         assert_eq!(
-            LocalTimeType::new(7200, true),
+            LocalTimeType::new(7200, true).with_designation("CEST"),
             time_zone.to_local_time_type(1689724800 - 1)
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1689724800)
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1704067199)
         );
 
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1704067200)
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1712624400 - 1)
         );
         assert_eq!(
-            LocalTimeType::new(7200, true),
+            LocalTimeType::new(7200, true).with_designation("CEST"),
             time_zone.to_local_time_type(1712624400)
         );
         assert_eq!(
-            LocalTimeType::new(7200, true),
+            LocalTimeType::new(7200, true).with_designation("CEST"),
             time_zone.to_local_time_type(1721260800 - 1)
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1721260800)
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1735689599)
         );
     }
@@ -400,14 +430,14 @@ mod local_tests {
             b"TZif3\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0TZif3\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x0a<CET>-1CEST+2,M3.3.0,M10.3.0\x0a";
         let time_zone = TimeZone::from_tzif(bytes).unwrap();
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1672531200)
         );
         let bytes =
             b"TZif3\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0TZif3\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x0aCET-1:0:0CEST,M3.3.0,M10.3.0\x0a";
         let time_zone = TimeZone::from_tzif(bytes).unwrap();
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1672531200)
         );
         let bytes =
@@ -505,37 +535,37 @@ mod local_tests {
         let time_zone = TimeZone::from_tzif(bytes).unwrap();
         assert_eq!(
             Some(TransitionRule::Alternate(AlternateLocalTimeType::new(
-                LocalTimeType::new(3600, false),
+                LocalTimeType::new(3600, false).with_designation("CET"),
                 RuleDay::MonthWeekDay(3, 5, 0),
                 7200,
-                LocalTimeType::new(7200, true),
+                LocalTimeType::new(7200, true).with_designation("CEST"),
                 RuleDay::MonthWeekDay(10, 5, 0),
                 7200,
             ))),
             time_zone.extra_rule
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1672531200)
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1679792400 - 1)
         );
         assert_eq!(
-            LocalTimeType::new(7200, true),
+            LocalTimeType::new(7200, true).with_designation("CEST"),
             time_zone.to_local_time_type(1679792400)
         );
         assert_eq!(
-            LocalTimeType::new(7200, true),
+            LocalTimeType::new(7200, true).with_designation("CEST"),
             time_zone.to_local_time_type(1698537600 - 1)
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1698537600)
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1704067199)
         );
 
@@ -543,51 +573,92 @@ mod local_tests {
         let time_zone = TimeZone::from_tzif(bytes).unwrap();
         assert_eq!(
             Some(TransitionRule::Alternate(AlternateLocalTimeType::new(
-                LocalTimeType::new(3600, false),
+                LocalTimeType::new(3600, false).with_designation("CET"),
                 RuleDay::MonthWeekDay(10, 5, 0),
                 7200,
-                LocalTimeType::new(7200, true),
+                LocalTimeType::new(7200, true).with_designation("CEST"),
                 RuleDay::MonthWeekDay(3, 5, 0),
                 7200,
             ))),
             time_zone.extra_rule
         );
         assert_eq!(
-            LocalTimeType::new(7200, true),
+            LocalTimeType::new(7200, true).with_designation("CEST"),
             time_zone.to_local_time_type(1672531200)
         );
         assert_eq!(
-            LocalTimeType::new(7200, true),
+            LocalTimeType::new(7200, true).with_designation("CEST"),
             time_zone.to_local_time_type(1679788800 - 1)
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1679788800)
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1698541200 - 1)
         );
         assert_eq!(
-            LocalTimeType::new(7200, true),
+            LocalTimeType::new(7200, true).with_designation("CEST"),
             time_zone.to_local_time_type(1698541200)
         );
         assert_eq!(
-            LocalTimeType::new(7200, true),
+            LocalTimeType::new(7200, true).with_designation("CEST"),
             time_zone.to_local_time_type(1704067199)
         );
         let bytes = b"TZif3\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0TZif3\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x0aCET-1\x0a";
         let time_zone = TimeZone::from_tzif(bytes).unwrap();
         assert_eq!(
-            Some(TransitionRule::Fixed(LocalTimeType::new(3600, false))),
+            Some(TransitionRule::Fixed(
+                LocalTimeType::new(3600, false).with_designation("CET")
+            )),
             time_zone.extra_rule
         );
         assert_eq!(
-            LocalTimeType::new(3600, false),
+            LocalTimeType::new(3600, false).with_designation("CET"),
             time_zone.to_local_time_type(1704067199)
         );
     }
 
+    #[test]
+    fn designations() {
+        let bytes = b"TZif3\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0TZif3\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x0aCET-1CEST,M3.5.0,M10.5.0\x0a";
+        let time_zone = TimeZone::from_tzif(bytes).unwrap();
+        let format = |timestamp: i64, chars: &str| {
+            let local_time_type = time_zone.to_local_time_type(timestamp);
+            let mut formatted = String::new();
+            format_time_part(
+                &mut formatted,
+                chars,
+                0,
+                local_time_type.utoff,
+                Some(&local_time_type.designation),
+                &Locale::ENGLISH,
+            );
+            formatted
+        };
+        assert_eq!("CET", format(1672531200, "z"));
+        assert_eq!("CEST", format(1689724800, "zzz"));
+        assert_eq!("GMT+01:00", format(1672531200, "zzzz"));
+        assert_eq!("GMT+02:00", format(1689724800, "zzzz"));
+
+        // Numeric designations are formatted as GMT offsets
+        let bytes = b"TZif\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04\0\0\xb6\xd0\0\0+13\0";
+        let time_zone = TimeZone::from_tzif(bytes).unwrap();
+        let local_time_type = time_zone.to_local_time_type(0);
+        assert_eq!("+13", local_time_type.designation);
+        let mut formatted = String::new();
+        format_time_part(
+            &mut formatted,
+            "z",
+            0,
+            local_time_type.utoff,
+            Some(&local_time_type.designation),
+            &Locale::ENGLISH,
+        );
+        assert_eq!("GMT+13:00", formatted);
+    }
+
     #[test]
     fn invalid_tzif_file() {
         let result = TimeZone::from_tzif(b"");
@@ -680,7 +751,7 @@ mod local_tests {
         let time_zone = TimeZone::from_tzif(bytes.as_slice()).unwrap();
 
         assert_eq!(
-            "TimeZone { transitions: [], local_time_types: [], extra_rule: Some(Alternate(AlternateLocalTimeType { std: LocalTimeType { utoff: 3600, _dst: false }, std_end: MonthWeekDay(3, 5, 0), std_end_time: 7200, dst: LocalTimeType { utoff: 7200, _dst: true }, dst_end: MonthWeekDay(10, 5, 0), dst_end_time: 7200 })) }",
+            "TimeZone { transitions: [], local_time_types: [], extra_rule: Some(Alternate(AlternateLocalTimeType { std: LocalTimeType { utoff: 3600, _dst: false, designation: \"CET\" }, std_end: MonthWeekDay(3, 5, 0), std_end_time: 7200, dst: LocalTimeType { utoff: 7200, _dst: true, designation: \"CEST\" }, dst_end: MonthWeekDay(10, 5, 0), dst_end_time: 7200 })) }",
             format!("{:?}", time_zone)
         );
 
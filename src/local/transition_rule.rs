@@ -38,18 +38,17 @@ impl TransitionRule {
 
         let mut cursor = Cursor::new(tz_string.as_bytes());
 
-        remove_designation(&mut cursor)?;
+        let std_designation = parse_designation(&mut cursor)?;
 
         let std_offset = parse_tz_string_offset(&mut cursor)?;
 
         if cursor.empty() {
-            return Ok(Some(TransitionRule::Fixed(LocalTimeType::new(
-                -std_offset,
-                false,
-            ))));
+            return Ok(Some(TransitionRule::Fixed(
+                LocalTimeType::new(-std_offset, false).with_designation(std_designation),
+            )));
         }
 
-        remove_designation(&mut cursor)?;
+        let dst_designation = parse_designation(&mut cursor)?;
 
         let dst_offset = match cursor.remaining().first() {
             Some(&b',') => std_offset - 3600,
@@ -67,10 +66,10 @@ impl TransitionRule {
 
         Ok(Some(TransitionRule::Alternate(
             AlternateLocalTimeType::new(
-                LocalTimeType::new(-std_offset, false),
+                LocalTimeType::new(-std_offset, false).with_designation(std_designation),
                 std_end,
                 std_end_time,
-                LocalTimeType::new(-dst_offset, true),
+                LocalTimeType::new(-dst_offset, true).with_designation(dst_designation),
                 dst_end,
                 dst_end_time,
             ),
@@ -147,14 +146,16 @@ fn rule_to_local_timestamp(start: &RuleDay, time: i32, timestamp: i64) -> i64 {
         .timestamp()
 }
 
-fn remove_designation(cursor: &mut Cursor) -> Result<(), TimeZoneError> {
-    if cursor.get_next()? == b'<' {
-        cursor.read_until('>');
+fn parse_designation<'a>(cursor: &mut Cursor<'a>) -> Result<&'a str, TimeZoneError> {
+    let designation = if cursor.get_next()? == b'<' {
         cursor.read_exact(1)?;
+        let designation = cursor.read_until('>');
+        cursor.read_exact(1)?;
+        designation
     } else {
-        cursor.read_while(|c: &u8| c.is_ascii_alphabetic());
+        cursor.read_while(|c: &u8| c.is_ascii_alphabetic())
     };
-    Ok(())
+    Ok(std::str::from_utf8(designation)?)
 }
 
 fn parse_hms(cursor: &mut Cursor) -> Result<(i32, i32, i32, i32), TimeZoneError> {
@@ -0,0 +1,233 @@
+use crate::{util::date::convert::days_to_week_of_year, WeekNumbering, Weekday};
+
+/// Names of months, weekdays, day periods, eras and quarters used when formatting and parsing.
+///
+/// [`Locale::default()`] is [`Locale::ENGLISH`]. Use the `_localized` functions like [`DateTime::format_localized`](crate::DateTime::format_localized) or [`DateTime::parse_localized`](crate::DateTime::parse_localized) to use another locale. Custom locales can be created by setting all fields.
+///
+/// ```rust
+/// # use astrolabe::{Date, Locale};
+/// let date = Date::from_ymd(2022, 3, 2).unwrap();
+/// assert_eq!("Wednesday, 2. March 2022", date.format("eeee, d. MMMM yyyy"));
+/// assert_eq!(
+///     "Mittwoch, 2. März 2022",
+///     date.format_localized("eeee, d. MMMM yyyy", &Locale::GERMAN)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    /// Wide month names (`MMMM`), starting with January
+    pub months_wide: [&'static str; 12],
+    /// Abbreviated month names (`MMM`), starting with January
+    pub months_abbreviated: [&'static str; 12],
+    /// Narrow month names (`MMMMM`), starting with January. As narrow names are ambiguous, they are skipped when parsing.
+    pub months_narrow: [&'static str; 12],
+    /// Wide weekday names (`eeee`), starting with Sunday
+    pub weekdays_wide: [&'static str; 7],
+    /// Abbreviated weekday names (`eee`), starting with Sunday
+    pub weekdays_abbreviated: [&'static str; 7],
+    /// Narrow weekday names (`eeeee`), starting with Sunday. As narrow names are ambiguous, they are skipped when parsing.
+    pub weekdays_narrow: [&'static str; 7],
+    /// Short weekday names (`eeeeee`), starting with Sunday
+    pub weekdays_short: [&'static str; 7],
+    /// Day periods for the symbol lengths one to five (`a` to `aaaaa`), each as `[AM, PM, noon, midnight]`. Noon and midnight are only used by `b`.
+    pub periods: [[&'static str; 4]; 5],
+    /// Flexible day periods for `B..BBB`, `BBBB` and `BBBBB`, each as `[morning, afternoon, evening, night]`
+    pub flexible_periods: [[&'static str; 4]; 3],
+    /// Wide era names (`GGGG`) as `[BC, AD]`
+    pub eras_wide: [&'static str; 2],
+    /// Abbreviated era names (`G`) as `[BC, AD]`
+    pub eras_abbreviated: [&'static str; 2],
+    /// Narrow era names (`GGGGG`) as `[BC, AD]`
+    pub eras_narrow: [&'static str; 2],
+    /// Wide quarter names (`qqqq`), starting with the first quarter
+    pub quarters_wide: [&'static str; 4],
+    /// Abbreviated quarter names (`qqq`), starting with the first quarter
+    pub quarters_abbreviated: [&'static str; 4],
+    /// First day of the week for the numeric weekdays (`e` and `ee`) and the week of year (`w`). See [`FirstWeekday`].
+    pub first_weekday: FirstWeekday,
+}
+
+impl Locale {
+    /// English names. Used by the `format` and `parse` functions.
+    pub const ENGLISH: Self = Self {
+        months_wide: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        months_abbreviated: [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+        months_narrow: ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+        weekdays_wide: [
+            "Sunday",
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+        ],
+        weekdays_abbreviated: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+        weekdays_narrow: ["S", "M", "T", "W", "T", "F", "S"],
+        weekdays_short: ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"],
+        periods: [
+            ["AM", "PM", "noon", "midnight"],
+            ["AM", "PM", "noon", "midnight"],
+            ["am", "pm", "noon", "midnight"],
+            ["a.m.", "p.m.", "noon", "midnight"],
+            ["a", "p", "n", "mi"],
+        ],
+        flexible_periods: [
+            [
+                "in the morning",
+                "in the afternoon",
+                "in the evening",
+                "at night",
+            ],
+            [
+                "in the morning",
+                "in the afternoon",
+                "in the evening",
+                "at night",
+            ],
+            [
+                "in the morning",
+                "in the afternoon",
+                "in the evening",
+                "at night",
+            ],
+        ],
+        eras_wide: ["Before Christ", "Anno Domini"],
+        eras_abbreviated: ["BC", "AD"],
+        eras_narrow: ["B", "A"],
+        quarters_wide: ["1st quarter", "2nd quarter", "3rd quarter", "4th quarter"],
+        quarters_abbreviated: ["Q1", "Q2", "Q3", "Q4"],
+        first_weekday: FirstWeekday::Standard,
+    };
+
+    /// German names.
+    pub const GERMAN: Self = Self {
+        months_wide: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        months_abbreviated: [
+            "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+            "Dez.",
+        ],
+        months_narrow: ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+        weekdays_wide: [
+            "Sonntag",
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+        ],
+        weekdays_abbreviated: ["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."],
+        weekdays_narrow: ["S", "M", "D", "M", "D", "F", "S"],
+        weekdays_short: ["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"],
+        periods: [
+            ["AM", "PM", "Mittag", "Mitternacht"],
+            ["AM", "PM", "Mittag", "Mitternacht"],
+            ["am", "pm", "Mittag", "Mitternacht"],
+            ["vorm.", "nachm.", "Mittag", "Mitternacht"],
+            ["a", "p", "m", "mi"],
+        ],
+        flexible_periods: [
+            ["morgens", "nachmittags", "abends", "nachts"],
+            ["morgens", "nachmittags", "abends", "nachts"],
+            ["morgens", "nachmittags", "abends", "nachts"],
+        ],
+        eras_wide: ["vor Christus", "nach Christus"],
+        eras_abbreviated: ["v. Chr.", "n. Chr."],
+        eras_narrow: ["v. Chr.", "n. Chr."],
+        quarters_wide: ["1. Quartal", "2. Quartal", "3. Quartal", "4. Quartal"],
+        quarters_abbreviated: ["Q1", "Q2", "Q3", "Q4"],
+        first_weekday: FirstWeekday::Standard,
+    };
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::ENGLISH
+    }
+}
+
+/// First day of the week used by a [`Locale`] for the numeric weekdays (`e` and `ee`) and the week of year (`w`), when formatting and parsing.
+///
+/// The ISO 8601 weekdays (`eeeeeee` and `eeeeeeee`, `1` is Monday) and the week of month (`W`) are not affected.
+///
+/// ```rust
+/// # use astrolabe::{Date, FirstWeekday, Locale, Weekday};
+/// // Saturday
+/// let date = Date::from_ymd(2022, 1, 1).unwrap();
+/// assert_eq!("7 52", date.format("e w"));
+///
+/// let locale = Locale {
+///     first_weekday: FirstWeekday::On(Weekday::Monday),
+///     ..Locale::ENGLISH
+/// };
+/// assert_eq!("6 1", date.format_localized("e w", &locale));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FirstWeekday {
+    /// `e` and `ee` count from Sunday (`1`) and `w` is the ISO 8601 week of year, which starts on Monday. The first week is the one containing the first Thursday of the year, so the first days of January can belong to week `52` or `53` ([`WeekNumbering::Iso`]). This is the default.
+    Standard,
+    /// `e` and `ee` count from the given weekday (`1`) and weeks of `w` start on the given weekday. The first week starts on the 1st of January, so `w` is in the range `1-54`. With Sunday, this is [`WeekNumbering::SundayStart`].
+    On(Weekday),
+}
+
+impl FirstWeekday {
+    /// Returns the numeric weekday (`1-7`) of a weekday with `0` as Sunday
+    pub(crate) fn number(&self, wday: u32) -> u32 {
+        (wday + 7 - self.start()) % 7 + 1
+    }
+
+    /// Returns the weekday with `0` as Sunday of a numeric weekday (`1-7`)
+    pub(crate) fn wday(&self, number: u32) -> u32 {
+        (number - 1 + self.start()) % 7
+    }
+
+    /// Returns the week of year of the given days
+    pub(crate) fn week_of_year(&self, days: i32) -> u32 {
+        match self {
+            Self::Standard => WeekNumbering::Iso.week_of_year(days),
+            Self::On(weekday) => days_to_week_of_year(days, *weekday as u32),
+        }
+    }
+
+    fn start(&self) -> u32 {
+        match self {
+            Self::Standard => 0,
+            Self::On(weekday) => *weekday as u32,
+        }
+    }
+}
+
+impl Default for FirstWeekday {
+    fn default() -> Self {
+        Self::Standard
+    }
+}
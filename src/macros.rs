@@ -0,0 +1,29 @@
+/// Asserts at compile time that a format string is valid and returns it unchanged as a `&'static str`.
+///
+/// The build fails if the format string contains an unknown symbol, a too long symbol sequence or an unclosed escaped part. See [`is_valid_format`](crate::is_valid_format) for the exact rules. The macro is only a const assertion: the returned value is a plain string, so the formatting and parsing functions don't know that it was checked and accept any other string as well.
+///
+/// ```rust
+/// # use astrolabe::{format_spec, DateTime};
+/// let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap();
+/// assert_eq!("2022-05-02T12:32:01", date_time.format(format_spec!("yyyy-MM-dd'T'HH:mm:ss")));
+/// ```
+///
+/// Invalid format strings are rejected by the compiler:
+///
+/// ```rust,compile_fail
+/// # use astrolabe::format_spec;
+/// let format = format_spec!("yyyy-MM-dd QQ");
+/// ```
+///
+/// ```rust,compile_fail
+/// # use astrolabe::format_spec;
+/// let format = format_spec!("yyyy-MM-dd 'at HH:mm");
+/// ```
+#[macro_export]
+macro_rules! format_spec {
+    ($format:expr) => {{
+        // Fails with a mismatched array size if the format string is invalid
+        const _: [(); 0] = [(); !$crate::is_valid_format($format) as usize];
+        $format
+    }};
+}
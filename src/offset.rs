@@ -1,21 +1,39 @@
-use std::fs;
+use std::{
+    env::{self, VarError},
+    fmt::Display,
+    fs,
+    str::FromStr,
+};
 
 use crate::{
-    errors::{out_of_range::create_simple_oor, AstrolabeError},
-    local::timezone::TimeZone,
+    errors::{
+        invalid_format::create_invalid_format, local_time_zone::create_local_time_zone,
+        out_of_range::create_simple_oor, AstrolabeError, LocalTimeZoneKind,
+    },
+    local::timezone::{LocalTimeType, TimeZone},
     util::{
         constants::{SECS_PER_DAY, SECS_PER_HOUR, SECS_PER_MINUTE},
+        parse::{parse_offset, OffsetFormat},
         time::convert::time_to_day_seconds,
     },
     DateTime, DateUtilities,
 };
 
+#[cfg(test)]
+thread_local! {
+    /// Number of times [`Offset::Local`] was resolved on the current thread
+    static LOCAL_RESOLUTIONS: std::cell::Cell<usize> = std::cell::Cell::new(0);
+    /// Path of the TZif file of the local timezone on the current thread
+    static LOCAL_TZIF_PATH: std::cell::RefCell<std::path::PathBuf> =
+        std::cell::RefCell::new("/etc/localtime".into());
+}
+
 /// Represents an offset from UTC
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Offset {
     /// Fixed offset in seconds
     Fixed(i32),
-    /// Local timezone. Only works on UNIX systems. On other systems, this is equivalent to `Fixed(0)`.
+    /// Local timezone. Only works on UNIX systems. On other systems, or if the local timezone can't be read, this is equivalent to `Fixed(0)`. Use [`Offset::try_local`] to find out why the local timezone can't be read.
     Local,
 }
 
@@ -50,26 +68,78 @@ impl Offset {
         Ok(Self::Fixed(seconds))
     }
 
-    /// Resolves the offset to seconds from UTC
+    /// Creates a fixed offset from the value of an environment variable. See the [`FromStr`] implementation for accepted notations.
+    ///
+    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the environment variable is not set, is not valid unicode or could not be parsed. The error message states which of these cases applies.
+    ///
+    /// ```rust
+    /// # use astrolabe::{DateTime, Offset, OffsetUtilities};
+    /// std::env::set_var("APP_TZ_OFFSET", "+05:30");
+    ///
+    /// let offset = Offset::from_env("APP_TZ_OFFSET").unwrap();
+    /// assert_eq!(Offset::Fixed(19800), offset);
+    ///
+    /// let now = DateTime::now().set_offset(offset);
+    /// ```
+    pub fn from_env(var: &str) -> Result<Self, AstrolabeError> {
+        let value = env::var(var).map_err(|error| match error {
+            VarError::NotPresent => {
+                create_invalid_format(format!("Environment variable '{}' is not set.", var))
+            }
+            VarError::NotUnicode(_) => create_invalid_format(format!(
+                "Environment variable '{}' is not valid unicode.",
+                var
+            )),
+        })?;
+
+        value.parse().map_err(|error| {
+            create_invalid_format(format!(
+                "Failed parsing environment variable '{}': {}",
+                var, error
+            ))
+        })
+    }
+
+    /// Returns the current offset of the local timezone as a fixed offset.
+    ///
+    /// [`Offset::Local`] silently resolves to UTC if the local timezone can't be determined. This function returns a [`LocalTimeZone`](AstrolabeError::LocalTimeZone) error instead, whose [`kind`](crate::errors::LocalTimeZone::kind) tells if the TZif file of the local timezone is missing, unreadable or invalid, or if the platform is not supported. Applications can use it to warn or fail at startup and keep using [`Offset::Local`] afterwards, which follows daylight saving time changes.
+    ///
+    /// ```rust
+    /// # use astrolabe::Offset;
+    /// match Offset::try_local() {
+    ///     Ok(offset) => assert_eq!(offset.resolve(), Offset::Local.resolve()),
+    ///     Err(error) => eprintln!("Falling back to UTC: {}", error),
+    /// }
+    /// ```
+    pub fn try_local() -> Result<Self, AstrolabeError> {
+        Ok(Self::Fixed(local_time_type()?.utoff))
+    }
+
+    /// Resolves the offset to seconds from UTC. [`Offset::Local`] is resolved using the current offset of the local timezone.
+    ///
+    /// ```rust
+    /// # use astrolabe::Offset;
+    /// assert_eq!(-28_800, Offset::from_hms(-8, 0, 0).unwrap().resolve());
+    /// ```
     pub fn resolve(self) -> i32 {
+        self.resolve_zone().0
+    }
+
+    /// Resolves the offset to seconds from UTC and the time zone designation (E.g. `CEST`) if known. Only [`Offset::Local`] has a designation, which is read from the TZif data of the local timezone.
+    pub(crate) fn resolve_zone(self) -> (i32, Option<String>) {
         match self {
-            Self::Fixed(offset) => offset,
+            Self::Fixed(offset) => (offset, None),
             Self::Local => {
-                #[cfg(not(unix))]
-                return 0;
-                #[cfg(unix)]
-                return {
-                    let result = fs::read("/etc/localtime");
-                    match result {
-                        Ok(bytes) => {
-                            TimeZone::from_tzif(&bytes)
-                                .unwrap()
-                                .to_local_time_type(DateTime::now().timestamp())
-                                .utoff
-                        }
-                        Err(_) => 0,
+                #[cfg(test)]
+                LOCAL_RESOLUTIONS.with(|count| count.set(count.get() + 1));
+                match local_time_type() {
+                    Ok(local_time_type) => {
+                        let designation = Some(local_time_type.designation)
+                            .filter(|designation| !designation.is_empty());
+                        (local_time_type.utoff, designation)
                     }
-                };
+                    Err(_) => (0, None),
+                }
             }
         }
     }
@@ -86,8 +156,255 @@ impl Offset {
     }
 }
 
+/// Returns the local time type of the local timezone at the current instant
+fn local_time_type() -> Result<LocalTimeType, AstrolabeError> {
+    #[cfg(not(unix))]
+    return Err(create_local_time_zone(
+        LocalTimeZoneKind::UnsupportedPlatform,
+        "The local timezone can only be read on UNIX systems.".to_string(),
+    ));
+    #[cfg(unix)]
+    return {
+        #[cfg(not(test))]
+        let path = std::path::PathBuf::from("/etc/localtime");
+        #[cfg(test)]
+        let path = LOCAL_TZIF_PATH.with(|path| path.borrow().clone());
+
+        let bytes = fs::read(&path).map_err(|error| {
+            let kind = match error.kind() {
+                std::io::ErrorKind::NotFound => LocalTimeZoneKind::NotFound,
+                _ => LocalTimeZoneKind::Unreadable,
+            };
+            create_local_time_zone(
+                kind,
+                format!("Failed reading '{}': {}", path.display(), error),
+            )
+        })?;
+        let time_zone = TimeZone::from_tzif(&bytes).map_err(|error| {
+            create_local_time_zone(
+                LocalTimeZoneKind::InvalidFile,
+                format!("Failed parsing '{}': {}", path.display(), error),
+            )
+        })?;
+        Ok(time_zone.to_local_time_type(DateTime::now().timestamp()))
+    };
+}
+
 impl Default for Offset {
     fn default() -> Self {
         Self::Fixed(0)
     }
 }
+
+/// Parses a fixed offset. Accepts `Z` and `+`/`-` followed by hours and optionally minutes and seconds, either separated by `:` or not (E.g. `+05`, `+05:30`, `-0800` or `+01:00:00`).
+impl FromStr for Offset {
+    type Err = AstrolabeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut string = s;
+        let seconds = parse_offset(
+            &mut string,
+            OffsetFormat {
+                with_z: true,
+                with_colon: s.contains(':'),
+                optional_minutes: true,
+                optional_seconds: true,
+            },
+        )?;
+
+        if !string.is_empty() {
+            return Err(create_invalid_format(format!(
+                "Unexpected characters after the offset: '{}'",
+                string
+            )));
+        }
+
+        Ok(Self::Fixed(seconds))
+    }
+}
+
+/// Formats the resolved offset as `Z`, `+hh:mm` or `+hh:mm:ss` if the offset has seconds (E.g. `+02:00` or `-07:52:58`).
+///
+/// ```rust
+/// # use astrolabe::Offset;
+/// assert_eq!("Z", Offset::Fixed(0).to_string());
+/// assert_eq!("+02:00", Offset::from_hms(2, 0, 0).unwrap().to_string());
+/// assert_eq!("-07:52:58", Offset::from_hms(-7, 52, 58).unwrap().to_string());
+/// ```
+impl Display for Offset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let seconds = self.resolve();
+        if seconds == 0 {
+            return write!(f, "Z");
+        }
+
+        let sign = if seconds.is_negative() { '-' } else { '+' };
+        let seconds = seconds.unsigned_abs();
+        let hour = seconds / SECS_PER_HOUR;
+        let minute = seconds % SECS_PER_HOUR / SECS_PER_MINUTE;
+        let second = seconds % SECS_PER_MINUTE;
+
+        write!(f, "{}{:02}:{:02}", sign, hour, minute)?;
+        if second != 0 {
+            write!(f, ":{:02}", second)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod offset_tests {
+    use super::{Offset, LOCAL_RESOLUTIONS, LOCAL_TZIF_PATH};
+    use crate::{
+        errors::{AstrolabeError, LocalTimeZoneKind},
+        DateTime, DateUtilities, OffsetUtilities, Precision, Time, TimeUtilities,
+    };
+    use std::{fs, path::PathBuf};
+
+    /// Returns how often [`Offset::Local`] was resolved while running `operation`
+    fn local_resolutions(operation: impl FnOnce()) -> usize {
+        let before = LOCAL_RESOLUTIONS.with(|count| count.get());
+        operation();
+        LOCAL_RESOLUTIONS.with(|count| count.get()) - before
+    }
+
+    #[test]
+    fn resolve_local_once() {
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 15, 30, 20)
+            .unwrap()
+            .add_nanos(123_456_789)
+            .set_offset(Offset::Local);
+
+        for precision in [
+            Precision::Seconds,
+            Precision::Decis,
+            Precision::Millis,
+            Precision::Micros,
+            Precision::Nanos,
+            Precision::Digits(4),
+        ] {
+            assert_eq!(
+                1,
+                local_resolutions(|| {
+                    date_time.format_rfc3339(precision.clone());
+                })
+            );
+            assert_eq!(
+                1,
+                local_resolutions(|| {
+                    let mut buf = [0; DateTime::rfc3339_max_len(Precision::Nanos)];
+                    date_time.format_rfc3339_buf(precision, &mut buf).unwrap();
+                })
+            );
+        }
+        assert_eq!(
+            1,
+            local_resolutions(|| {
+                date_time.format("yyyy-MM-dd HH:mm:ss.nnn xxx eeee w");
+            })
+        );
+        assert_eq!(
+            1,
+            local_resolutions(|| {
+                date_time.to_string();
+            })
+        );
+        assert_eq!(
+            1,
+            local_resolutions(|| {
+                date_time.year();
+            })
+        );
+        assert_eq!(
+            1,
+            local_resolutions(|| {
+                date_time.quarter();
+            })
+        );
+        assert_eq!(
+            1,
+            local_resolutions(|| {
+                date_time.nano();
+            })
+        );
+        assert_eq!(
+            1,
+            local_resolutions(|| {
+                date_time.date();
+            })
+        );
+
+        let time = Time::from_hms(15, 30, 20)
+            .unwrap()
+            .set_offset(Offset::Local);
+        assert_eq!(
+            1,
+            local_resolutions(|| {
+                time.format("HH:mm:ss.nnn xxx");
+            })
+        );
+
+        // Fixed offsets are not counted
+        let date_time = date_time.set_offset(Offset::Fixed(3600));
+        assert_eq!(
+            0,
+            local_resolutions(|| {
+                date_time.format_rfc3339(Precision::Nanos);
+            })
+        );
+    }
+
+    /// Reads the local timezone from `path` while running `operation`
+    fn with_local_tzif<T>(path: PathBuf, operation: impl FnOnce() -> T) -> T {
+        let previous = LOCAL_TZIF_PATH.with(|local| local.replace(path));
+        let result = operation();
+        LOCAL_TZIF_PATH.with(|local| local.replace(previous));
+        result
+    }
+
+    fn local_error_kind() -> LocalTimeZoneKind {
+        match Offset::try_local().unwrap_err() {
+            AstrolabeError::LocalTimeZone(error) => error.kind(),
+            error => panic!("Unexpected error: {}", error),
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn try_local() {
+        let directory = std::env::temp_dir().join(format!("astrolabe-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+
+        // Missing file
+        with_local_tzif(directory.join("missing"), || {
+            assert_eq!(LocalTimeZoneKind::NotFound, local_error_kind());
+            assert_eq!(0, Offset::Local.resolve());
+        });
+
+        // Directories can't be read
+        with_local_tzif(directory.clone(), || {
+            assert_eq!(LocalTimeZoneKind::Unreadable, local_error_kind());
+            assert_eq!(0, Offset::Local.resolve());
+        });
+
+        // Invalid file
+        let invalid = directory.join("invalid");
+        fs::write(&invalid, b"InvalidMagic").unwrap();
+        with_local_tzif(invalid, || {
+            assert_eq!(LocalTimeZoneKind::InvalidFile, local_error_kind());
+            let message = Offset::try_local().unwrap_err().to_string();
+            assert!(message.starts_with("Failed parsing '"), "{}", message);
+            assert_eq!(0, Offset::Local.resolve());
+        });
+
+        // Valid file with a fixed offset of +13:00
+        let valid = directory.join("valid");
+        fs::write(&valid, b"TZif\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\x04\0\0\xb6\xd0\0\0+13\0").unwrap();
+        with_local_tzif(valid, || {
+            assert_eq!(Offset::Fixed(46_800), Offset::try_local().unwrap());
+            assert_eq!(46_800, Offset::Local.resolve());
+        });
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
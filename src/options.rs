@@ -0,0 +1,151 @@
+use crate::{DateUtilities, Locale};
+
+/// Options to customize parsing. Used by [`Date::parse_with`](crate::Date::parse_with), [`Time::parse_with`](crate::Time::parse_with) and [`DateTime::parse_with`](crate::DateTime::parse_with).
+///
+/// Use `..Default::default()` when constructing, as more options might be added in the future.
+///
+/// ```rust
+/// # use astrolabe::{ParseOptions, PeriodTokens, Time};
+/// let options = ParseOptions {
+///     period_tokens: Some(PeriodTokens::new(&["A"], &["P", "NOON"])),
+///     ..Default::default()
+/// };
+/// let time = Time::parse_with("0930P", "hhmma", &options).unwrap();
+/// assert_eq!("21:30:00", time.to_string());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Accepted strings for the period symbols `a` and `b`. Replaces the built-in strings of all widths if set.
+    pub period_tokens: Option<PeriodTokens>,
+    /// Accepted strings for the era symbol `G`. Replaces the built-in strings of all widths if set.
+    pub era_tokens: Option<EraTokens>,
+    /// Names of months, weekdays, day periods, eras and quarters. Defaults to [`Locale::ENGLISH`].
+    pub locale: Locale,
+    /// How two digit years (`yy`) are expanded to full years. Defaults to [`TwoDigitYear::CurrentCentury`].
+    pub two_digit_year: TwoDigitYear,
+    /// Return an error if parsed fields contradict each other instead of silently using one of them. Checks 24-hour against 12-hour hours with their day period, weekdays, the week of year and AD against the parsed date, the day of year against month and day of month and rejects signed years together with an era. Defaults to `false`.
+    pub reject_conflicts: bool,
+}
+
+/// Policy for expanding two digit years (`yy`) when parsing, see [`ParseOptions`].
+///
+/// ```rust
+/// # use astrolabe::{Date, DateUtilities, ParseOptions, TwoDigitYear};
+/// let options = ParseOptions {
+///     two_digit_year: TwoDigitYear::StartingAt(1950),
+///     ..Default::default()
+/// };
+/// assert_eq!(1975, Date::parse_with("75", "yy", &options).unwrap().year());
+/// assert_eq!(2022, Date::parse_with("22", "yy", &options).unwrap().year());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwoDigitYear {
+    /// Years are placed in the current century, e.g. `22` is parsed as `2022` if the current year is `2023`.
+    CurrentCentury,
+    /// Years are placed in the 100 years starting at the given year, e.g. `StartingAt(1950)` parses `50` to `99` as `1950` to `1999` and `00` to `49` as `2000` to `2049`.
+    StartingAt(i32),
+}
+
+impl TwoDigitYear {
+    pub(crate) fn expand(&self, year: i32) -> i32 {
+        let start = match self {
+            Self::CurrentCentury => crate::Date::now().year() / 100 * 100,
+            Self::StartingAt(start) => *start,
+        };
+        start + (year - start).rem_euclid(100)
+    }
+}
+
+impl Default for TwoDigitYear {
+    fn default() -> Self {
+        Self::CurrentCentury
+    }
+}
+
+/// Custom strings for the day period (AM/PM), see [`ParseOptions`].
+///
+/// Tokens are matched case-insensitively (ASCII only). If multiple tokens match, the longest one is used.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PeriodTokens {
+    /// Strings which are parsed as AM
+    pub am: Vec<String>,
+    /// Strings which are parsed as PM
+    pub pm: Vec<String>,
+}
+
+impl PeriodTokens {
+    /// Creates new [`PeriodTokens`] from the strings for AM and PM.
+    pub fn new(am: &[&str], pm: &[&str]) -> Self {
+        Self {
+            am: am.iter().map(|token| token.to_string()).collect(),
+            pm: pm.iter().map(|token| token.to_string()).collect(),
+        }
+    }
+}
+
+/// Custom strings for the era (AD/BC), see [`ParseOptions`].
+///
+/// Tokens are matched case-insensitively (ASCII only). If multiple tokens match, the longest one is used. Like the built-in era strings, a BC token turns a positive parsed year negative, e.g. `BC 2022` is parsed as the year `-2022`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EraTokens {
+    /// Strings which are parsed as AD
+    pub ad: Vec<String>,
+    /// Strings which are parsed as BC
+    pub bc: Vec<String>,
+}
+
+impl EraTokens {
+    /// Creates new [`EraTokens`] from the strings for AD and BC.
+    pub fn new(ad: &[&str], bc: &[&str]) -> Self {
+        Self {
+            ad: ad.iter().map(|token| token.to_string()).collect(),
+            bc: bc.iter().map(|token| token.to_string()).collect(),
+        }
+    }
+}
+
+/// Thresholds used by [`DateTime::format_relative_with`](crate::DateTime::format_relative_with) to choose the unit of a relative time. Each threshold is the first amount which is shown in the next larger unit instead.
+///
+/// Minutes, hours and days are rounded to the nearest whole unit before they are compared. Months and years are full calendar units, see [`months_since`](crate::DateUtilities::months_since) and [`years_since`](crate::DateUtilities::years_since).
+///
+/// ```rust
+/// # use astrolabe::{DateTime, RelativeThresholds, TimeUtilities};
+/// let now = DateTime::from_ymdhms(2022, 5, 2, 12, 0, 0).unwrap();
+/// let thresholds = RelativeThresholds {
+///     minutes: 60,
+///     ..RelativeThresholds::DEFAULT
+/// };
+/// let date_time = now.sub_minutes(50);
+/// assert_eq!("1 hour ago", date_time.format_relative(&now));
+/// assert_eq!("50 minutes ago", date_time.format_relative_with(&now, &thresholds));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RelativeThresholds {
+    /// Differences below this amount of seconds are shown as `just now` or `in a few seconds`
+    pub seconds: u64,
+    /// Differences below this amount of minutes are shown in minutes
+    pub minutes: u64,
+    /// Differences below this amount of hours are shown in hours
+    pub hours: u64,
+    /// Differences below this amount of days are shown in days
+    pub days: u64,
+    /// Differences below this amount of months are shown in months, larger differences in years
+    pub months: u32,
+}
+
+impl RelativeThresholds {
+    /// Default thresholds: `45` seconds, `45` minutes, `22` hours, `26` days and `11` months.
+    pub const DEFAULT: Self = Self {
+        seconds: 45,
+        minutes: 45,
+        hours: 22,
+        days: 26,
+        months: 11,
+    };
+}
+
+impl Default for RelativeThresholds {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -0,0 +1,33 @@
+//! Re-exports the most commonly used types and traits.
+//!
+//! Most functionality of astrolabe is implemented in the [`DateUtilities`], [`TimeUtilities`] and [`OffsetUtilities`] traits. Forgetting to import one of them leads to "method not found" errors, so importing the prelude is the easiest way to get started.
+//!
+//! ```rust
+//! use astrolabe::prelude::*;
+//!
+//! let date_time = DateTime::from_ymd(2022, 5, 2).unwrap();
+//! assert_eq!("2022/05/02", date_time.format("yyyy/MM/dd"));
+//!
+//! let modified_dt = date_time.add_hours(11).add_minutes(23);
+//! assert_eq!("2022/05/02 11:23:00", modified_dt.format("yyyy/MM/dd HH:mm:ss"));
+//! assert_eq!("2022-05-02T11:23:00Z", modified_dt.format_rfc3339(Precision::Seconds));
+//!
+//! let now = DateTime::now_local();
+//! assert_eq!(Offset::Local, now.get_offset());
+//! assert!(2021 < now.year());
+//!
+//! let date = Date::from_ymd(2022, 5, 2).unwrap();
+//! let time = Time::from_hms(11, 23, 0).unwrap();
+//! assert_eq!(modified_dt, DateTime::from(date).set_time(time));
+//!
+//! let schedule = CronSchedule::parse("0 10 * * Mon-Fri").unwrap();
+//! for date in schedule.upcoming().take(3) {
+//!     assert_eq!(10, date.hour());
+//! }
+//! ```
+
+pub use crate::{
+    Bounds, CronSchedule, Date, DateTime, DateTimeDelta, DateUnit, DateUtilities, Era,
+    MonthOverflow, Offset, OffsetUtilities, Precision, Time, TimeUnit, TimeUtilities, Unit,
+    WeekNumbering, Weekday,
+};
@@ -0,0 +1,191 @@
+use crate::{util::constants::NANOS_PER_DAY, Date, DateTime, Offset, Time};
+use rand::{
+    distributions::{
+        uniform::{SampleBorrow, SampleUniform, UniformInt, UniformSampler},
+        Distribution, Standard,
+    },
+    Rng,
+};
+
+/// Generates dates uniformly across the whole supported range, including negative years.
+impl Distribution<Date> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Date {
+        Date { days: rng.gen() }
+    }
+}
+
+/// Generates times of day uniformly with nanosecond precision and a UTC offset.
+impl Distribution<Time> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Time {
+        Time {
+            nanoseconds: rng.gen_range(0..NANOS_PER_DAY),
+            offset: Offset::default(),
+        }
+    }
+}
+
+/// Generates instants uniformly across the whole supported range with nanosecond precision and a UTC offset.
+impl Distribution<DateTime> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DateTime {
+        DateTime {
+            days: rng.gen(),
+            nanoseconds: rng.gen_range(0..NANOS_PER_DAY),
+            offset: Offset::default(),
+        }
+    }
+}
+
+/// Uniform sampler for [`Date`], used by [`Rng::gen_range`].
+#[derive(Clone, Copy, Debug)]
+pub struct UniformDate(UniformInt<i32>);
+
+impl UniformSampler for UniformDate {
+    type X = Date;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(UniformInt::new(low.borrow().days, high.borrow().days))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(UniformInt::new_inclusive(
+            low.borrow().days,
+            high.borrow().days,
+        ))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Date {
+            days: self.0.sample(rng),
+        }
+    }
+}
+
+/// Samples dates uniformly between two dates, e.g. with `rng.gen_range(start..end)`. The range can span the BC/AD boundary.
+///
+/// Panics if the range is empty.
+impl SampleUniform for Date {
+    type Sampler = UniformDate;
+}
+
+/// Uniform sampler for [`Time`], used by [`Rng::gen_range`].
+#[derive(Clone, Copy, Debug)]
+pub struct UniformTime {
+    nanoseconds: UniformInt<u64>,
+    offset: Offset,
+}
+
+impl UniformSampler for UniformTime {
+    type X = Time;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self {
+            nanoseconds: UniformInt::new(low.borrow().nanoseconds, high.borrow().nanoseconds),
+            offset: low.borrow().offset,
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self {
+            nanoseconds: UniformInt::new_inclusive(
+                low.borrow().nanoseconds,
+                high.borrow().nanoseconds,
+            ),
+            offset: low.borrow().offset,
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Time {
+            nanoseconds: self.nanoseconds.sample(rng),
+            offset: self.offset,
+        }
+    }
+}
+
+/// Samples times uniformly between two times, e.g. with `rng.gen_range(start..end)`. The sampled times have the offset of the lower bound.
+///
+/// Like the comparison operators, the range doesn't wrap around midnight. Panics if the range is empty.
+impl SampleUniform for Time {
+    type Sampler = UniformTime;
+}
+
+/// Uniform sampler for [`DateTime`], used by [`Rng::gen_range`].
+#[derive(Clone, Copy, Debug)]
+pub struct UniformDateTime {
+    nanos: UniformInt<i128>,
+    low_offset: Offset,
+    high_offset: Offset,
+}
+
+impl UniformDateTime {
+    fn from_bounds(low: &DateTime, high: &DateTime, nanos: UniformInt<i128>) -> Self {
+        Self {
+            nanos,
+            low_offset: low.offset,
+            high_offset: high.offset,
+        }
+    }
+}
+
+impl UniformSampler for UniformDateTime {
+    type X = DateTime;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        Self::from_bounds(low, high, UniformInt::new(low.as_nanos(), high.as_nanos()))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        Self::from_bounds(
+            low,
+            high,
+            UniformInt::new_inclusive(low.as_nanos(), high.as_nanos()),
+        )
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        let nanos = self.nanos.sample(rng);
+        let date_time = DateTime {
+            days: nanos.div_euclid(NANOS_PER_DAY as i128) as i32,
+            nanoseconds: nanos.rem_euclid(NANOS_PER_DAY as i128) as u64,
+            offset: Offset::default(),
+        };
+        // The local date in the offset of one of the bounds is always in range
+        date_time
+            .try_set_offset(self.low_offset)
+            .or_else(|_| date_time.try_set_offset(self.high_offset))
+            .unwrap_or(date_time)
+    }
+}
+
+/// Samples instants uniformly between two instants with nanosecond precision, e.g. with `rng.gen_range(start..end)`. The sampled instants have the offset of the lower bound, or the offset of the upper bound if their local date would be out of range.
+///
+/// Panics if the range is empty.
+impl SampleUniform for DateTime {
+    type Sampler = UniformDateTime;
+}
@@ -0,0 +1,146 @@
+use crate::{util::time::convert::nanos_to_days_nanos, Date, DateTime};
+use std::iter::FusedIterator;
+
+/// Iterator over the days between two [`Date`] instances. Created with [`Date::range`].
+///
+/// The start date is included, the end date is excluded. If the end date is before the start date, the iterator counts backwards.
+#[derive(Debug, Clone)]
+pub struct DateRange {
+    start: i64,
+    direction: i64,
+    front: u64,
+    back: u64,
+}
+
+impl DateRange {
+    pub(crate) fn new(start: Date, end: Date) -> Self {
+        let start = start.days as i64;
+        let end = end.days as i64;
+
+        Self {
+            start,
+            direction: if end < start { -1 } else { 1 },
+            front: 0,
+            back: (end - start).unsigned_abs(),
+        }
+    }
+
+    fn date_at(&self, index: u64) -> Date {
+        Date {
+            days: (self.start + self.direction * index as i64) as i32,
+        }
+    }
+}
+
+impl Iterator for DateRange {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let date = self.date_at(self.front);
+        self.front += 1;
+        Some(date)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(self.back as u128 - self.front as u128)
+    }
+}
+
+impl DoubleEndedIterator for DateRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.date_at(self.back))
+    }
+}
+
+/// [`len`](ExactSizeIterator::len) panics if the amount of remaining dates does not fit into a `usize`, which can only happen on platforms with a pointer width below 64 bits.
+impl ExactSizeIterator for DateRange {}
+
+impl FusedIterator for DateRange {}
+
+/// Iterator over [`DateTime`] instances between two [`DateTime`] instances with a fixed step. Created with [`DateTime::range_step`].
+///
+/// The start is included, the end is excluded. If the end is before the start, the iterator steps backwards. All yielded instances have the offset of the start.
+///
+/// Unlike [`DateRange`], this iterator doesn't implement [`ExactSizeIterator`], as the amount of steps doesn't always fit into a `usize`, e.g. when stepping by single nanoseconds over multiple centuries. [`size_hint`](Iterator::size_hint) is exact whenever it does.
+#[derive(Debug, Clone)]
+pub struct DateTimeRange {
+    start: DateTime,
+    step: i128,
+    front: u128,
+    back: u128,
+}
+
+impl DateTimeRange {
+    pub(crate) fn new(start: DateTime, end: DateTime, step: u128) -> Self {
+        assert!(step != 0, "Step of a DateTimeRange cannot be zero");
+
+        let distance = (end.as_nanos() - start.as_nanos()).unsigned_abs();
+
+        Self {
+            start,
+            step: if end < start {
+                -(step as i128)
+            } else {
+                step as i128
+            },
+            front: 0,
+            back: (distance + step - 1) / step,
+        }
+    }
+
+    fn date_time_at(&self, index: u128) -> DateTime {
+        let nanos = self.start.as_nanos() + self.step * index as i128;
+        // Using unwrap because all steps lie between the start and the end
+        let (days, nanoseconds) = nanos_to_days_nanos(nanos).unwrap();
+
+        DateTime {
+            days,
+            nanoseconds,
+            offset: self.start.offset,
+        }
+    }
+}
+
+impl Iterator for DateTimeRange {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let date_time = self.date_time_at(self.front);
+        self.front += 1;
+        Some(date_time)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(self.back - self.front)
+    }
+}
+
+impl DoubleEndedIterator for DateTimeRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.date_time_at(self.back))
+    }
+}
+
+impl FusedIterator for DateTimeRange {}
+
+/// Returns an exact size hint if the remaining amount fits into a `usize`
+fn size_hint(remaining: u128) -> (usize, Option<usize>) {
+    match usize::try_from(remaining) {
+        Ok(remaining) => (remaining, Some(remaining)),
+        Err(_) => (usize::MAX, None),
+    }
+}
@@ -0,0 +1,30 @@
+//! Serde helpers for [`DateTime`] fields which keep subsecond values.
+//!
+//! Use with `#[serde(with = "astrolabe::serde::auto_precision")]`. The default implementation serializes RFC 3339 strings without subseconds. This module uses [`Precision::Auto`] instead, which only adds as many decimal places as needed, e.g. `2022-05-02T12:32:01Z` or `2022-05-02T12:32:01.5Z`. Deserialization accepts any number of decimal places, like the default implementation.
+//!
+//! ```rust
+//! # use astrolabe::{DateTime, TimeUtilities};
+//! # use serde::de::IntoDeserializer;
+//! # use serde::de::value::{Error, StrDeserializer};
+//! let deserializer: StrDeserializer<Error> = "2022-05-02T12:32:01.5Z".into_deserializer();
+//! let date_time = astrolabe::serde::auto_precision::deserialize(deserializer).unwrap();
+//! assert_eq!(500, date_time.milli());
+//! ```
+use crate::{DateTime, Precision};
+use serde::{de, ser};
+
+/// Serializes a [`DateTime`] as an RFC 3339 string with [`Precision::Auto`].
+pub fn serialize<S>(date_time: &DateTime, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+{
+    serializer.serialize_str(&date_time.format_rfc3339(Precision::Auto))
+}
+
+/// Deserializes an RFC 3339 string with any number of decimal places into a [`DateTime`].
+pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    de::Deserialize::deserialize(deserializer)
+}
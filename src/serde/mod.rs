@@ -1,3 +1,8 @@
+//! Serde helpers for use with `#[serde(with = "...")]`.
+//!
+//! [`Date`](crate::Date), [`Time`](crate::Time) and [`DateTime`](crate::DateTime) implement `Serialize` and `Deserialize` directly. The modules in here provide alternative representations.
+pub mod auto_precision;
 mod date;
 mod datetime;
+pub mod rfc3339_or_now;
 mod time;
@@ -0,0 +1,76 @@
+//! Serde helpers for [`DateTime`] fields where a missing value means "now".
+//!
+//! Use with `#[serde(with = "astrolabe::serde::rfc3339_or_now")]`. Values are serialized as RFC 3339 strings, like the default implementation. When deserializing, `null` and empty strings result in [`DateTime::now`], which makes deserialization non-deterministic. Only use it for fields like `last_seen` where this is intended. Combine with `#[serde(default = "astrolabe::DateTime::now")]` to also handle absent fields.
+//!
+//! ```rust
+//! # use astrolabe::DateTime;
+//! # use serde::de::IntoDeserializer;
+//! # use serde::de::value::{Error, StrDeserializer};
+//! let deserializer: StrDeserializer<Error> = "2022-05-02T12:32:01Z".into_deserializer();
+//! let date_time = astrolabe::serde::rfc3339_or_now::deserialize(deserializer).unwrap();
+//! assert_eq!("2022/05/02 12:32:01", date_time.to_string());
+//!
+//! let deserializer: StrDeserializer<Error> = "".into_deserializer();
+//! let date_time = astrolabe::serde::rfc3339_or_now::deserialize(deserializer).unwrap();
+//! assert!(date_time <= DateTime::now());
+//! ```
+use crate::DateTime;
+use serde::{de, ser};
+use std::fmt;
+
+/// Serializes a [`DateTime`] as an RFC 3339 string.
+pub fn serialize<S>(date_time: &DateTime, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+{
+    ser::Serialize::serialize(date_time, serializer)
+}
+
+/// Deserializes an RFC 3339 string into a [`DateTime`]. `null` and empty strings result in [`DateTime::now`].
+pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    deserializer.deserialize_option(OrNowVisitor)
+}
+
+struct OrNowVisitor;
+
+impl<'de> de::Visitor<'de> for OrNowVisitor {
+    type Value = DateTime;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an RFC 3339 formatted date string, an empty string or null")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if value.is_empty() {
+            return Ok(DateTime::now());
+        }
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(DateTime::now())
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(DateTime::now())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
@@ -1,18 +1,418 @@
-use crate::{errors::AstrolabeError, offset::Offset};
+use crate::{
+    errors::{
+        out_of_range::{create_custom_oor, create_simple_oor},
+        AstrolabeError,
+    },
+    offset::Offset,
+    util::{
+        constants::{MAX_DATE, MIN_DATE, NANOS_PER_HOUR, NANOS_PER_MINUTE, NANOS_PER_SEC},
+        date::convert::{days_to_doy, days_to_week_of_year, days_to_wyear},
+    },
+};
 
-/// Used for specifing the precision for RFC 3339 timestamps.
+/// Used for specifing the precision for RFC 3339 timestamps. Additional digits are truncated.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Precision {
     /// Only seconds -> `2022-05-02T15:30:20Z`
-    Seconds = 0,
+    Seconds,
+    /// 1 decimal place -> `2022-05-02T15:30:20.0Z`
+    Decis,
     /// 2 decimal places -> `2022-05-02T15:30:20.00Z`
-    Centis = 2,
+    Centis,
     /// 3 decimal places -> `2022-05-02T15:30:20.000Z`
-    Millis = 3,
+    Millis,
     /// 6 decimal places -> `2022-05-02T15:30:20.000000Z`
-    Micros = 6,
+    Micros,
     /// 9 decimal places -> `2022-05-02T15:30:20.000000000Z`
-    Nanos = 9,
+    Nanos,
+    /// The given number of decimal places from `1` to `9`, e.g. `Digits(4)` -> `2022-05-02T15:30:20.0000Z`. The value isn't checked: `0` is the same as [`Precision::Seconds`] and values above `9` are clamped to `9`. Use [`Precision::from_digits`] to reject values outside of this range.
+    Digits(u8),
+    /// As few decimal places as possible without losing precision. No decimal places if the subsecond value is zero, otherwise without trailing zeros, e.g. `2022-05-02T15:30:20.5Z` or `2022-05-02T15:30:20.000000001Z`
+    Auto,
+}
+
+impl Precision {
+    /// Creates a [`Precision::Digits`] with the given number of decimal places. Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the number is not between `1` and `9`.
+    ///
+    /// ```rust
+    /// # use astrolabe::Precision;
+    /// assert_eq!(Precision::Digits(4), Precision::from_digits(4).unwrap());
+    /// assert!(Precision::from_digits(0).is_err());
+    /// assert!(Precision::from_digits(10).is_err());
+    /// ```
+    pub fn from_digits(digits: u8) -> Result<Self, AstrolabeError> {
+        if !(1..=9).contains(&digits) {
+            return Err(create_simple_oor("digits", 1, 9, digits.into()));
+        }
+        Ok(Self::Digits(digits))
+    }
+
+    /// Returns the number of decimal places. For [`Precision::Auto`], this is the maximum of `9`.
+    ///
+    /// ```rust
+    /// # use astrolabe::Precision;
+    /// assert_eq!(3, Precision::Millis.digits());
+    /// assert_eq!(4, Precision::Digits(4).digits());
+    /// assert_eq!(9, Precision::Digits(12).digits());
+    /// assert_eq!(9, Precision::Auto.digits());
+    /// ```
+    pub const fn digits(&self) -> u8 {
+        match self {
+            Self::Seconds => 0,
+            Self::Decis => 1,
+            Self::Centis => 2,
+            Self::Millis => 3,
+            Self::Micros => 6,
+            Self::Nanos => 9,
+            Self::Digits(digits) if *digits > 9 => 9,
+            Self::Digits(digits) => *digits,
+            Self::Auto => 9,
+        }
+    }
+
+    /// Returns the number of decimal places used for the given subsecond nanoseconds. Only differs from [`Precision::digits`] for [`Precision::Auto`], which drops trailing zeros.
+    pub(crate) fn digits_for(&self, subsec_nanos: u32) -> u32 {
+        match self {
+            Self::Auto if subsec_nanos == 0 => 0,
+            Self::Auto => {
+                let mut digits = 9;
+                let mut subsec_nanos = subsec_nanos;
+                while subsec_nanos % 10 == 0 {
+                    subsec_nanos /= 10;
+                    digits -= 1;
+                }
+                digits
+            }
+            _ => self.digits() as u32,
+        }
+    }
+}
+
+/// Used for specifying what happens if the day of month doesn't exist in the target month when adding years or months, e.g. in [`Date::add_months_with`](crate::Date::add_months_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MonthOverflow {
+    /// Use the last day of the target month -> `2022-01-31 + 1 month = 2022-02-28`. Default, used by [`add_months`](DateUtilities::add_months) and [`add_years`](DateUtilities::add_years).
+    ClampToLastDay,
+    /// Use the first day of the month after the target month -> `2022-01-31 + 1 month = 2022-03-01`
+    RollToNextMonth,
+    /// Return an [`OutOfRange`](crate::errors::AstrolabeError::OutOfRange) error
+    Error,
+}
+
+impl Default for MonthOverflow {
+    fn default() -> Self {
+        Self::ClampToLastDay
+    }
+}
+
+/// Used for specifying whether the start and end of a range are included, e.g. in [`DateTime::is_between`](crate::DateTime::is_between).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bounds {
+    /// Start and end are included -> `start <= value <= end`
+    Inclusive,
+    /// Start and end are excluded -> `start < value < end`
+    Exclusive,
+    /// Only the start is included -> `start <= value < end`
+    StartInclusive,
+    /// Only the end is included -> `start < value <= end`
+    EndInclusive,
+}
+
+impl Bounds {
+    /// Returns `true` if the start is included
+    pub fn includes_start(&self) -> bool {
+        matches!(self, Self::Inclusive | Self::StartInclusive)
+    }
+
+    /// Returns `true` if the end is included
+    pub fn includes_end(&self) -> bool {
+        matches!(self, Self::Inclusive | Self::EndInclusive)
+    }
+
+    /// Returns `true` if the value is after the start, respecting the bound
+    pub(crate) fn after_start<T: Ord>(&self, value: &T, start: &T) -> bool {
+        if self.includes_start() {
+            value >= start
+        } else {
+            value > start
+        }
+    }
+
+    /// Returns `true` if the value is before the end, respecting the bound
+    pub(crate) fn before_end<T: Ord>(&self, value: &T, end: &T) -> bool {
+        if self.includes_end() {
+            value <= end
+        } else {
+            value < end
+        }
+    }
+}
+
+/// Day of the week. The discriminants match [`DateUtilities::weekday`] (`0` is Sunday).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    /// Sunday -> `0`
+    Sunday = 0,
+    /// Monday -> `1`
+    Monday = 1,
+    /// Tuesday -> `2`
+    Tuesday = 2,
+    /// Wednesday -> `3`
+    Wednesday = 3,
+    /// Thursday -> `4`
+    Thursday = 4,
+    /// Friday -> `5`
+    Friday = 5,
+    /// Saturday -> `6`
+    Saturday = 6,
+}
+
+impl From<Weekday> for u8 {
+    fn from(weekday: Weekday) -> Self {
+        weekday as u8
+    }
+}
+
+impl TryFrom<u8> for Weekday {
+    type Error = AstrolabeError;
+
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the value is not in the range `0-6`.
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Sunday,
+            1 => Self::Monday,
+            2 => Self::Tuesday,
+            3 => Self::Wednesday,
+            4 => Self::Thursday,
+            5 => Self::Friday,
+            6 => Self::Saturday,
+            _ => return Err(create_simple_oor("weekday", 0, 6, value as i128)),
+        })
+    }
+}
+
+/// Era of a year. Years before `0001` are negative and belong to [`Era::BC`], there is no year `0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Era {
+    /// Before Christ, years `-1` and lower
+    BC = 0,
+    /// Anno Domini, years `1` and higher
+    AD = 1,
+}
+
+impl Era {
+    /// Returns the era of the given days since 01. January 0001
+    pub(crate) fn from_days(days: i32) -> Self {
+        if days.is_negative() {
+            Self::BC
+        } else {
+            Self::AD
+        }
+    }
+
+    /// Returns the era of the given index in the era names of a [`Locale`](crate::Locale), `0` is BC
+    pub(crate) fn from_index(index: i64) -> Self {
+        if index == 0 {
+            Self::BC
+        } else {
+            Self::AD
+        }
+    }
+
+    /// Returns the index of the era in the era names of a [`Locale`](crate::Locale), `0` is BC
+    pub(crate) fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// Week numbering system, used by [`DateUtilities::week_of_year`].
+///
+/// The format symbol `w` uses [`WeekNumbering::Iso`] by default and [`WeekNumbering::SundayStart`] with [`FirstWeekday::On(Weekday::Sunday)`](crate::FirstWeekday::On), both when formatting and parsing. [`WeekNumbering::SimpleJan1`] has no format symbol.
+///
+/// ```rust
+/// # use astrolabe::{Date, DateUtilities, WeekNumbering};
+/// // Friday
+/// let date = Date::from_ymd(2021, 1, 1).unwrap();
+/// assert_eq!(53, date.week_of_year(WeekNumbering::Iso));
+/// assert_eq!(1, date.week_of_year(WeekNumbering::SundayStart));
+/// assert_eq!(1, date.week_of_year(WeekNumbering::SimpleJan1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WeekNumbering {
+    /// ISO 8601 weeks (`1-53`), starting on Monday. The first week is the one containing the first Thursday of the year, so the first days of January can belong to week `52` or `53` of the previous year and the last days of December to week `1` of the next year.
+    Iso,
+    /// Weeks (`1-54`) starting on Sunday, as used in the US. The first week starts on the 1st of January, so the first and last week can be shorter than seven days.
+    SundayStart,
+    /// Weeks (`1-53`) of seven days counted from the 1st of January, regardless of the weekday. The last week has one or two days.
+    SimpleJan1,
+}
+
+impl WeekNumbering {
+    /// Returns the week of year of the given days since 01. January 0001
+    pub(crate) fn week_of_year(&self, days: i32) -> u32 {
+        match self {
+            Self::Iso => days_to_wyear(days),
+            Self::SundayStart => days_to_week_of_year(days, Weekday::Sunday as u32),
+            Self::SimpleJan1 => (days_to_doy(days) - 1) / 7 + 1,
+        }
+    }
+}
+
+/// Date units, used by [`Date::get_unit`](crate::Date::get_unit), [`Date::set_unit`](crate::Date::set_unit) and [`Date::apply_unit`](crate::Date::apply_unit).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateUnit {
+    /// Year, see [`DateUtilities::year`]
+    Year,
+    /// Month of the year, see [`DateUtilities::month`]
+    Month,
+    /// Day of the month, see [`DateUtilities::day`]
+    Day,
+}
+
+impl DateUnit {
+    /// All date units, from year to day
+    pub const ALL: [DateUnit; 3] = [Self::Year, Self::Month, Self::Day];
+}
+
+/// Time units, used by [`Time::get_unit`](crate::Time::get_unit), [`Time::set_unit`](crate::Time::set_unit) and [`Time::apply_unit`](crate::Time::apply_unit).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeUnit {
+    /// Hour, see [`TimeUtilities::hour`]
+    Hour,
+    /// Minute of the hour, see [`TimeUtilities::minute`]
+    Minute,
+    /// Second of the minute, see [`TimeUtilities::second`]
+    Second,
+    /// Millisecond of the second, see [`TimeUtilities::milli`]
+    Milli,
+    /// Microsecond of the second, see [`TimeUtilities::micro`]
+    Micro,
+    /// Nanosecond of the second, see [`TimeUtilities::nano`]
+    Nano,
+}
+
+impl TimeUnit {
+    /// All time units, from hour to nanosecond
+    pub const ALL: [TimeUnit; 6] = [
+        Self::Hour,
+        Self::Minute,
+        Self::Second,
+        Self::Milli,
+        Self::Micro,
+        Self::Nano,
+    ];
+
+    /// Returns the nanoseconds of one unit
+    pub(crate) fn nanos(&self) -> u64 {
+        match self {
+            Self::Hour => NANOS_PER_HOUR,
+            Self::Minute => NANOS_PER_MINUTE,
+            Self::Second => NANOS_PER_SEC,
+            Self::Milli => 1_000_000,
+            Self::Micro => 1_000,
+            Self::Nano => 1,
+        }
+    }
+}
+
+/// Date and time units, used by [`DateTime::get_unit`](crate::DateTime::get_unit), [`DateTime::set_unit`](crate::DateTime::set_unit) and [`DateTime::apply_unit`](crate::DateTime::apply_unit). Can be created from a [`DateUnit`] or [`TimeUnit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// Year, see [`DateUtilities::year`]
+    Year,
+    /// Month of the year, see [`DateUtilities::month`]
+    Month,
+    /// Day of the month, see [`DateUtilities::day`]
+    Day,
+    /// Hour, see [`TimeUtilities::hour`]
+    Hour,
+    /// Minute of the hour, see [`TimeUtilities::minute`]
+    Minute,
+    /// Second of the minute, see [`TimeUtilities::second`]
+    Second,
+    /// Millisecond of the second, see [`TimeUtilities::milli`]
+    Milli,
+    /// Microsecond of the second, see [`TimeUtilities::micro`]
+    Micro,
+    /// Nanosecond of the second, see [`TimeUtilities::nano`]
+    Nano,
+}
+
+impl Unit {
+    /// All units, from year to nanosecond
+    pub const ALL: [Unit; 9] = [
+        Self::Year,
+        Self::Month,
+        Self::Day,
+        Self::Hour,
+        Self::Minute,
+        Self::Second,
+        Self::Milli,
+        Self::Micro,
+        Self::Nano,
+    ];
+
+    /// Returns the name and the valid range of the unit, used for errors
+    fn range(&self) -> (&'static str, i128, i128) {
+        match self {
+            Self::Year => ("year", MIN_DATE.0 as i128, MAX_DATE.0 as i128),
+            Self::Month => ("month", 1, 12),
+            Self::Day => ("day", 1, 31),
+            Self::Hour => ("hour", 0, 23),
+            Self::Minute => ("minute", 0, 59),
+            Self::Second => ("second", 0, 59),
+            Self::Milli => ("milli", 0, 999),
+            Self::Micro => ("micro", 0, 999_999),
+            Self::Nano => ("nano", 0, 999_999_999),
+        }
+    }
+
+    /// Converts a value for a `set_` function to the type of the function.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error with the range of the unit if the value doesn't fit into the type.
+    pub(crate) fn value<T: TryFrom<i64>>(&self, value: i64) -> Result<T, AstrolabeError> {
+        T::try_from(value).map_err(|_| {
+            let (name, min, max) = self.range();
+            create_simple_oor(name, min, max, value as i128)
+        })
+    }
+
+    /// Splits an amount for an `add_` or `sub_` function into its sign (`true` if negative) and its absolute value.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the absolute value is larger than `u32::MAX`.
+    pub(crate) fn amount(&self, amount: i64) -> Result<(bool, u32), AstrolabeError> {
+        let abs = u32::try_from(amount.unsigned_abs()).map_err(|_| {
+            create_custom_oor(format!(
+                "Instance would result into an overflow if {} {}s were added.",
+                amount,
+                self.range().0,
+            ))
+        })?;
+        Ok((amount.is_negative(), abs))
+    }
+}
+
+impl From<DateUnit> for Unit {
+    fn from(unit: DateUnit) -> Self {
+        match unit {
+            DateUnit::Year => Self::Year,
+            DateUnit::Month => Self::Month,
+            DateUnit::Day => Self::Day,
+        }
+    }
+}
+
+impl From<TimeUnit> for Unit {
+    fn from(unit: TimeUnit) -> Self {
+        match unit {
+            TimeUnit::Hour => Self::Hour,
+            TimeUnit::Minute => Self::Minute,
+            TimeUnit::Second => Self::Second,
+            TimeUnit::Milli => Self::Milli,
+            TimeUnit::Micro => Self::Micro,
+            TimeUnit::Nano => Self::Nano,
+        }
+    }
 }
 
 /// Defines functions to get and manipulate date units.
@@ -23,32 +423,107 @@ pub trait DateUtilities: Sized {
     fn year(&self) -> i32;
     /// Returns the month of the year (`1-12`).
     fn month(&self) -> u32;
+    /// Returns the quarter of the year (`1-4`).
+    fn quarter(&self) -> u32;
     /// Returns the day of the month (`1-31`).
     fn day(&self) -> u32;
     /// Returns the day of the year (`1-365` or `1-366`).
+    #[doc(alias = "yday")]
     fn day_of_year(&self) -> u32;
-    /// Returns the day of the week (`0-6`, `0` is Sunday).
+    /// Returns the ordinal day of the year (`1-365` or `1-366`) as used in ISO 8601 ordinal dates. Same as [`day_of_year`](DateUtilities::day_of_year).
+    fn ordinal(&self) -> u32 {
+        self.day_of_year()
+    }
+    /// Returns the day of the week (`0-6`, `0` is Sunday). Same as [`weekday_sunday0`](DateUtilities::weekday_sunday0).
+    ///
+    /// Note that the format symbols `e` and `ee` use `1-7` with `1` as Sunday and `eeeeeee` uses `1-7` with `1` as Monday like [`weekday_iso`](DateUtilities::weekday_iso).
+    #[doc(alias = "day_of_week")]
+    #[doc(alias = "wday")]
     fn weekday(&self) -> u8;
+    /// Returns the ISO 8601 day of the week (`1-7`, `1` is Monday).
+    fn weekday_iso(&self) -> u8;
+    /// Returns the day of the week (`0-6`, `0` is Sunday), like `tm_wday` of `struct tm` and the day of week field of cron expressions.
+    fn weekday_sunday0(&self) -> u8;
+    /// Returns the era of the year, [`Era::BC`] for negative years and [`Era::AD`] otherwise.
+    fn era(&self) -> Era {
+        if self.year().is_negative() {
+            Era::BC
+        } else {
+            Era::AD
+        }
+    }
+    /// Returns the century of the year, counted from `1` in both directions with the sign of the year. Years `1` to `100` are century `1`, `2001` to `2100` century `21` and years `-1` to `-100` century `-1`.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, DateUtilities};
+    /// assert_eq!(21, Date::from_ymd(2022, 5, 2).unwrap().century());
+    /// assert_eq!(20, Date::from_ymd(2000, 5, 2).unwrap().century());
+    /// assert_eq!(-1, Date::from_ymd(-5, 5, 2).unwrap().century());
+    /// ```
+    fn century(&self) -> i32 {
+        let year = self.year();
+        year.signum() * ((year.abs() - 1) / 100 + 1)
+    }
+    /// Returns the decade of the year, which is the year divided by `10` and rounded towards negative infinity. Years `2020` to `2029` are decade `202` and years `-1` to `-10` decade `-1`.
+    ///
+    /// As there is no year `0`, decade `0` only contains the years `1` to `9`.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, DateUtilities};
+    /// assert_eq!(202, Date::from_ymd(2022, 5, 2).unwrap().decade());
+    /// assert_eq!(-1, Date::from_ymd(-5, 5, 2).unwrap().decade());
+    /// ```
+    fn decade(&self) -> i32 {
+        self.year().div_euclid(10)
+    }
+    /// Returns the week of the month (`1-6`), with weeks starting on Sunday. The first week starts on the 1st of the month, so the first and last week can be shorter than seven days. Use [`week_of_month_with`](DateUtilities::week_of_month_with) to start weeks on another day.
+    fn week_of_month(&self) -> u32;
+    /// Returns the week of the month (`1-6`), with weeks starting on the provided weekday. The first week starts on the 1st of the month, so the first and last week can be shorter than seven days.
+    fn week_of_month_with(&self, week_start: Weekday) -> u32;
+    /// Returns the week of the year in the given [`WeekNumbering`] system.
+    ///
+    /// Matches the format symbol `w` with [`WeekNumbering::Iso`] by default and with [`WeekNumbering::SundayStart`] if the [`FirstWeekday`](crate::FirstWeekday) of the locale is Sunday.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Date, DateUtilities, WeekNumbering};
+    /// // Friday, ISO week 53 of 2015
+    /// let date = Date::from_ymd(2016, 1, 1).unwrap();
+    /// assert_eq!(53, date.week_of_year(WeekNumbering::Iso));
+    /// assert_eq!("53", date.format("w"));
+    /// assert_eq!(1, date.week_of_year(WeekNumbering::SundayStart));
+    /// ```
+    fn week_of_year(&self, system: WeekNumbering) -> u32;
 
     /// Creates a date from a unix timestamp (non-leap seconds since January 1, 1970 00:00:00 UTC).
     ///
     /// Panics if the provided timestamp would result in an out of range date.
+    #[doc(alias = "from_unix")]
     fn from_timestamp(timestamp: i64) -> Self;
     /// Returns the number of non-leap seconds since January 1, 1970 00:00:00 UTC. (Negative if date is before)
+    #[doc(alias = "unix_timestamp")]
+    #[doc(alias = "epoch")]
     fn timestamp(&self) -> i64;
 
     /// Sets the year to the provided value. Has to be in range `-5879611..=5879611`.
+    ///
+    /// For [`DateTime`](crate::DateTime), the date is set in the offset of the instance (local wall time), see [`OffsetUtilities`].
     fn set_year(&self, year: i32) -> Result<Self, AstrolabeError>;
     /// Sets the month of the year to the provided value. Has to be in range `1..=12`.
     ///
+    /// For [`DateTime`](crate::DateTime), the date is set in the offset of the instance (local wall time), see [`OffsetUtilities`].
+    ///
     /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided value is out of range.
     fn set_month(&self, month: u32) -> Result<Self, AstrolabeError>;
     /// Sets the day of the month to the provided value. Has to be in range `1..=31` and cannot be greater than the number of days in the current month.
     ///
+    /// For [`DateTime`](crate::DateTime), the date is set in the offset of the instance (local wall time), see [`OffsetUtilities`].
+    ///
     /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided value is out of range.
     fn set_day(&self, day: u32) -> Result<Self, AstrolabeError>;
     /// Sets the day of the year to the provided value. Has to be in range `1..=365` or `1..=366` in case of a leap year.
     ///
+    /// For [`DateTime`](crate::DateTime), the date is set in the offset of the instance (local wall time), see [`OffsetUtilities`].
+    ///
     /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided value is out of range.
     fn set_day_of_year(&self, day_of_year: u32) -> Result<Self, AstrolabeError>;
 
@@ -60,6 +535,10 @@ pub trait DateUtilities: Sized {
     ///
     /// Panics if the provided value would result in an out of range date.
     fn add_months(&self, months: u32) -> Self;
+    /// Adds the provided quarters (three months each) to the current date. The day of month is clamped like in [`add_months`](DateUtilities::add_months).
+    ///
+    /// Panics if the provided value would result in an out of range date.
+    fn add_quarters(&self, quarters: u32) -> Self;
     /// Adds the provided days to the current date.
     ///
     /// Panics if the provided value would result in an out of range date.
@@ -73,11 +552,46 @@ pub trait DateUtilities: Sized {
     ///
     /// Panics if the provided value would result in an out of range date.
     fn sub_months(&self, months: u32) -> Self;
+    /// Subtracts the provided quarters (three months each) from the current date. The day of month is clamped like in [`sub_months`](DateUtilities::sub_months).
+    ///
+    /// Panics if the provided value would result in an out of range date.
+    fn sub_quarters(&self, quarters: u32) -> Self;
     /// Subtracts the provided days from the current date.
     ///
     /// Panics if the provided value would result in an out of range date.
     fn sub_days(&self, days: u32) -> Self;
 
+    /// Adds the provided years to the current date. Clamps at the maximum date instead of panicking, which is `5879611-07-12` for [`Date`](crate::Date) and `5879611-07-12 23:59:59.999999999` for [`DateTime`](crate::DateTime).
+    fn saturating_add_years(&self, years: u32) -> Self;
+    /// Adds the provided months to the current date. Clamps at the maximum date instead of panicking, see [`saturating_add_years`](DateUtilities::saturating_add_years).
+    fn saturating_add_months(&self, months: u32) -> Self;
+    /// Adds the provided quarters to the current date. Clamps at the maximum date instead of panicking, see [`saturating_add_years`](DateUtilities::saturating_add_years).
+    fn saturating_add_quarters(&self, quarters: u32) -> Self;
+    /// Adds the provided days to the current date. Clamps at the maximum date instead of panicking, see [`saturating_add_years`](DateUtilities::saturating_add_years).
+    fn saturating_add_days(&self, days: u32) -> Self;
+
+    /// Subtracts the provided years from the current date. Clamps at the minimum date instead of panicking, which is `-5879611-06-23` for [`Date`](crate::Date) and `-5879611-06-23 00:00:00` for [`DateTime`](crate::DateTime).
+    fn saturating_sub_years(&self, years: u32) -> Self;
+    /// Subtracts the provided months from the current date. Clamps at the minimum date instead of panicking, see [`saturating_sub_years`](DateUtilities::saturating_sub_years).
+    fn saturating_sub_months(&self, months: u32) -> Self;
+    /// Subtracts the provided quarters from the current date. Clamps at the minimum date instead of panicking, see [`saturating_sub_years`](DateUtilities::saturating_sub_years).
+    fn saturating_sub_quarters(&self, quarters: u32) -> Self;
+    /// Subtracts the provided days from the current date. Clamps at the minimum date instead of panicking, see [`saturating_sub_years`](DateUtilities::saturating_sub_years).
+    fn saturating_sub_days(&self, days: u32) -> Self;
+
+    /// Moves the date to the first day of its quarter.
+    ///
+    /// For [`DateTime`](crate::DateTime), the date is set in the offset of the instance (local wall time) and the time is kept.
+    ///
+    /// Panics if the result would be an out of range date, which can only happen in the first supported quarter.
+    fn first_day_of_quarter(&self) -> Self;
+    /// Moves the date to the last day of its quarter.
+    ///
+    /// For [`DateTime`](crate::DateTime), the date is set in the offset of the instance (local wall time) and the time is kept.
+    ///
+    /// Panics if the result would be an out of range date, which can only happen in the last supported quarter.
+    fn last_day_of_quarter(&self) -> Self;
+
     /// Clears date/time units until the year (inclusive).
     fn clear_until_year(&self) -> Self;
     /// Clears date/time units until the month (inclusive).
@@ -89,7 +603,7 @@ pub trait DateUtilities: Sized {
     fn years_since(&self, compare: &Self) -> i32;
     /// Returns full months since the provided date.
     fn months_since(&self, compare: &Self) -> i32;
-    /// Returns full days since the provided date.
+    /// Returns full days since the provided date. For [`DateTime`](crate::DateTime), this is the number of completed 24-hour periods between both instants.
     fn days_since(&self, compare: &Self) -> i64;
 }
 
@@ -112,26 +626,38 @@ pub trait TimeUtilities: Sized {
 
     /// Sets the hour to the provided value. Has to be in range `0..=23`.
     ///
+    /// The value is set in the offset of the instance (local wall time), see [`OffsetUtilities`].
+    ///
     /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided value is out of range.
     fn set_hour(&self, hour: u32) -> Result<Self, AstrolabeError>;
     /// Sets the minute to the provided value. Has to be in range `0..=59`.
     ///
+    /// The value is set in the offset of the instance (local wall time), see [`OffsetUtilities`].
+    ///
     /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided value is out of range.
     fn set_minute(&self, minute: u32) -> Result<Self, AstrolabeError>;
     /// Sets the second to the provided value. Has to be in range `0..=59`.
     ///
+    /// The value is set in the offset of the instance (local wall time), see [`OffsetUtilities`].
+    ///
     /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided value is out of range.
     fn set_second(&self, second: u32) -> Result<Self, AstrolabeError>;
     /// Sets the millisecond to the provided value. Has to be in range `0..=100`.
     ///
+    /// The value is set in the offset of the instance (local wall time), see [`OffsetUtilities`].
+    ///
     /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided value is out of range.
     fn set_milli(&self, milli: u32) -> Result<Self, AstrolabeError>;
     /// Sets the microsecond to the provided value. Has to be in range `0..=100_000`.
     ///
+    /// The value is set in the offset of the instance (local wall time), see [`OffsetUtilities`].
+    ///
     /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided value is out of range.
     fn set_micro(&self, micro: u32) -> Result<Self, AstrolabeError>;
     /// Sets the nanosecond to the provided value. Has to be in range `0..=100_000_000`.
     ///
+    /// The value is set in the offset of the instance (local wall time), see [`OffsetUtilities`].
+    ///
     /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided value is out of range.
     fn set_nano(&self, nano: u32) -> Result<Self, AstrolabeError>;
 
@@ -161,6 +687,32 @@ pub trait TimeUtilities: Sized {
     /// Subtracts the provided nanoseconds.
     fn sub_nanos(&self, nanos: u32) -> Self;
 
+    /// Adds the provided hours. Clamps at `23:59:59.999999999` local wall time for [`Time`](crate::Time) instead of wrapping around and at `5879611-07-12 23:59:59.999999999` for [`DateTime`](crate::DateTime) instead of panicking.
+    fn saturating_add_hours(&self, hours: u32) -> Self;
+    /// Adds the provided minutes. Clamps at the maximum, see [`saturating_add_hours`](TimeUtilities::saturating_add_hours).
+    fn saturating_add_minutes(&self, minutes: u32) -> Self;
+    /// Adds the provided seconds. Clamps at the maximum, see [`saturating_add_hours`](TimeUtilities::saturating_add_hours).
+    fn saturating_add_seconds(&self, seconds: u32) -> Self;
+    /// Adds the provided milliseconds. Clamps at the maximum, see [`saturating_add_hours`](TimeUtilities::saturating_add_hours).
+    fn saturating_add_millis(&self, millis: u32) -> Self;
+    /// Adds the provided microseconds. Clamps at the maximum, see [`saturating_add_hours`](TimeUtilities::saturating_add_hours).
+    fn saturating_add_micros(&self, micros: u32) -> Self;
+    /// Adds the provided nanoseconds. Clamps at the maximum, see [`saturating_add_hours`](TimeUtilities::saturating_add_hours).
+    fn saturating_add_nanos(&self, nanos: u32) -> Self;
+
+    /// Subtracts the provided hours. Clamps at `00:00:00` local wall time for [`Time`](crate::Time) instead of wrapping around and at `-5879611-06-23 00:00:00` for [`DateTime`](crate::DateTime) instead of panicking.
+    fn saturating_sub_hours(&self, hours: u32) -> Self;
+    /// Subtracts the provided minutes. Clamps at the minimum, see [`saturating_sub_hours`](TimeUtilities::saturating_sub_hours).
+    fn saturating_sub_minutes(&self, minutes: u32) -> Self;
+    /// Subtracts the provided seconds. Clamps at the minimum, see [`saturating_sub_hours`](TimeUtilities::saturating_sub_hours).
+    fn saturating_sub_seconds(&self, seconds: u32) -> Self;
+    /// Subtracts the provided milliseconds. Clamps at the minimum, see [`saturating_sub_hours`](TimeUtilities::saturating_sub_hours).
+    fn saturating_sub_millis(&self, millis: u32) -> Self;
+    /// Subtracts the provided microseconds. Clamps at the minimum, see [`saturating_sub_hours`](TimeUtilities::saturating_sub_hours).
+    fn saturating_sub_micros(&self, micros: u32) -> Self;
+    /// Subtracts the provided nanoseconds. Clamps at the minimum, see [`saturating_sub_hours`](TimeUtilities::saturating_sub_hours).
+    fn saturating_sub_nanos(&self, nanos: u32) -> Self;
+
     /// Clears date/time units until the hour (inclusive).
     fn clear_until_hour(&self) -> Self;
     /// Clears date/time units until the minute (inclusive).
@@ -198,6 +750,28 @@ pub trait TimeUtilities: Sized {
 /// Offset can range anywhere from `UTC-23:59:59` to `UTC+23:59:59`.
 /// The offset affects all `format`, `get` and `set` functions.
 /// Used by [`DateTime`](crate::DateTime) and [`Time`](crate::Time).
+///
+/// Instances always store a point in time in `UTC`. Getters and setters work with the local wall time, which is the stored time with the offset applied. This means `set_` functions interpret the provided value in the current offset of the instance, so the order of `set_offset` and `set_` calls matters:
+///
+/// ```rust
+/// # use astrolabe::{DateTime, Offset, OffsetUtilities, TimeUtilities};
+/// // 2022-05-02 22:00 UTC
+/// let date_time = DateTime::from_ymdhms(2022, 5, 2, 22, 0, 0).unwrap();
+/// let offset = Offset::from_hms(2, 0, 0).unwrap();
+///
+/// // Sets 09:00 local time. The local date is already 2022-05-03.
+/// let local = date_time.set_offset(offset).set_hour(9).unwrap();
+/// assert_eq!("2022-05-03 09:00 +02:00", local.format("yyyy-MM-dd HH:mm xxx"));
+/// assert_eq!("2022-05-03 07:00 +00:00", local.set_offset(Offset::Fixed(0)).format("yyyy-MM-dd HH:mm xxx"));
+///
+/// // Sets 09:00 UTC and converts that instant to the offset afterwards
+/// let utc = date_time.set_hour(9).unwrap().set_offset(offset);
+/// assert_eq!("2022-05-02 11:00 +02:00", utc.format("yyyy-MM-dd HH:mm xxx"));
+///
+/// // Interprets 09:00 as local time of the offset, without converting it
+/// let wall_time = date_time.set_hour(9).unwrap().as_offset(offset);
+/// assert_eq!("2022-05-02 09:00 +02:00", wall_time.format("yyyy-MM-dd HH:mm xxx"));
+/// ```
 pub trait OffsetUtilities: Sized {
     /// Sets the offset
     ///
@@ -205,6 +779,10 @@ pub trait OffsetUtilities: Sized {
     /// - `UTC+1` is `set_offset(Offset::Fixed(3600))`
     /// - `UTC-1` is `set_offset(Offset::Fixed(-3600))`
     /// - To set the offset to the local timezone, use `set_offset(Offset::Local)`
+    ///
+    /// For [`DateTime`](crate::DateTime), panics if the local date in the new offset would be before `-5879611-06-23` or after `5879611-07-12`. This can only happen for instances within a day of these bounds. Use [`DateTime::try_set_offset`](crate::DateTime::try_set_offset) to handle this case.
+    ///
+    /// For [`Time`](crate::Time), the local time wraps around midnight (E.g. `00:30` in `UTC` is `22:30` in `UTC-2`).
     fn set_offset(&self, offset: Offset) -> Self;
     /// Sets the offset, assuming the current instance has the provided offset applied. The new instance will have the specified offset and the datetime itself will be converted to `UTC`.
     ///
@@ -212,7 +790,12 @@ pub trait OffsetUtilities: Sized {
     /// - `UTC+1` is `as_offset(Offset::Fixed(3600))`
     /// - `UTC-1` is `as_offset(Offset::Fixed(-3600))`.
     /// - To set the offset to the local timezone, use `as_offset(Offset::Local)`
+    ///
+    /// For [`DateTime`](crate::DateTime), panics if the date converted to `UTC` would be before `-5879611-06-23` or after `5879611-07-12`. Use [`DateTime::try_as_offset`](crate::DateTime::try_as_offset) to handle this case.
+    ///
+    /// For [`Time`](crate::Time), the time converted to `UTC` wraps around midnight and never panics.
     fn as_offset(&self, offset: Offset) -> Self;
     /// Returns the offset
+    #[doc(alias = "timezone")]
     fn get_offset(&self) -> Offset;
 }
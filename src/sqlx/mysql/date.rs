@@ -0,0 +1,58 @@
+use super::{decode_datetime, encode_date};
+use crate::Date;
+use sqlx::{
+    encode::IsNull,
+    error::BoxDynError,
+    mysql::{MySql, MySqlTypeInfo, MySqlValueRef},
+    Decode, Encode, Type, TypeInfo,
+};
+
+impl Type<MySql> for Date {
+    fn type_info() -> MySqlTypeInfo {
+        // `MySqlTypeInfo` can't be created outside of sqlx, so the info of the equivalent type is used
+        <sqlx::types::time::Date as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        ty.name() == "DATE"
+    }
+}
+
+/// Returns an error if the year is outside of `1` to `9999`.
+impl Encode<'_, MySql> for Date {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        let (year, month, day) = self.as_ymd();
+        buf.push(4);
+        encode_date(year, month, day, buf)?;
+
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        5
+    }
+}
+
+/// Returns an error for zero dates (`0000-00-00`) and other invalid dates.
+impl Decode<'_, MySql> for Date {
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes: &[u8] = Decode::<MySql>::decode(value)?;
+        decode_datetime(bytes).map(Date::from)
+    }
+}
+
+#[cfg(test)]
+mod mysql_date_tests {
+    use crate::Date;
+    use sqlx::{mysql::MySql, Encode};
+
+    #[test]
+    fn encode_binary() {
+        let mut buf = Vec::new();
+        assert!(Encode::<MySql>::encode(Date::from_ymd(2022, 5, 2).unwrap(), &mut buf).is_ok());
+        assert_eq!(vec![4, 0xe6, 0x07, 5, 2], buf);
+
+        let mut buf = Vec::new();
+        assert!(Encode::<MySql>::encode(Date::from_ymd(-5, 5, 2).unwrap(), &mut buf).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use super::{decode_datetime, encode_date};
+use crate::DateTime;
+use sqlx::{
+    encode::IsNull,
+    error::BoxDynError,
+    mysql::{MySql, MySqlTypeInfo, MySqlValueRef},
+    Decode, Encode, Type, TypeInfo,
+};
+
+impl Type<MySql> for DateTime {
+    fn type_info() -> MySqlTypeInfo {
+        // `MySqlTypeInfo` can't be created outside of sqlx, so the info of the equivalent type is used
+        <sqlx::types::time::PrimitiveDateTime as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        matches!(ty.name(), "DATETIME" | "TIMESTAMP")
+    }
+}
+
+/// Encodes the instance in UTC, the offset is not stored. Subsecond values are truncated to microseconds, which is the precision of `DATETIME(6)`.
+///
+/// Returns an error if the year is outside of `1` to `9999`.
+impl Encode<'_, MySql> for DateTime {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        let (year, month, day, hour, minute, second, nanos) = self.as_ymdhms_nano();
+        let micros = nanos / 1_000;
+        let length = match (hour, minute, second, micros) {
+            (0, 0, 0, 0) => 4,
+            (_, _, _, 0) => 7,
+            _ => 11,
+        };
+
+        buf.push(length);
+        encode_date(year, month, day, buf)?;
+        if length > 4 {
+            buf.extend_from_slice(&[hour as u8, minute as u8, second as u8]);
+        }
+        if length > 7 {
+            buf.extend_from_slice(&micros.to_le_bytes());
+        }
+
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        12
+    }
+}
+
+/// Decodes the value as UTC. Returns an error for zero dates (`0000-00-00`) and other invalid dates.
+impl Decode<'_, MySql> for DateTime {
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes: &[u8] = Decode::<MySql>::decode(value)?;
+        decode_datetime(bytes)
+    }
+}
+
+#[cfg(test)]
+mod mysql_datetime_tests {
+    use crate::{DateTime, Offset, OffsetUtilities};
+    use sqlx::{mysql::MySql, Encode};
+
+    fn encode(date_time: DateTime) -> Result<Vec<u8>, ()> {
+        let mut buf = Vec::new();
+        Encode::<MySql>::encode(date_time, &mut buf)
+            .map(|_| buf)
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn encode_binary() {
+        let date_time = DateTime::from_ymdhms_nano(2022, 5, 2, 12, 32, 1, 123_456_789).unwrap();
+        assert_eq!(
+            Ok(vec![
+                11, 0xe6, 0x07, 5, 2, 12, 32, 1, 0x40, 0xe2, 0x01, 0x00
+            ]),
+            encode(date_time)
+        );
+        // The offset is ignored
+        assert_eq!(
+            encode(date_time),
+            encode(date_time.set_offset(Offset::from_hms(-5, 0, 0).unwrap()))
+        );
+        assert_eq!(
+            Ok(vec![7, 0xe6, 0x07, 5, 2, 12, 32, 1]),
+            encode(DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap())
+        );
+        // Nanoseconds below one microsecond are truncated
+        assert_eq!(
+            Ok(vec![7, 0xe6, 0x07, 5, 2, 12, 32, 1]),
+            encode(DateTime::from_ymdhms_nano(2022, 5, 2, 12, 32, 1, 999).unwrap())
+        );
+        assert_eq!(
+            Ok(vec![4, 0xe6, 0x07, 5, 2]),
+            encode(DateTime::from_ymd(2022, 5, 2).unwrap())
+        );
+
+        assert!(encode(DateTime::from_ymd(10_000, 1, 1).unwrap()).is_err());
+        assert!(encode(DateTime::from_ymd(-1, 12, 31).unwrap()).is_err());
+    }
+}
@@ -0,0 +1,163 @@
+mod date;
+mod datetime;
+mod time;
+
+use crate::{Date, DateTime};
+use sqlx::error::BoxDynError;
+
+/// Pushes the date part of a `DATE` or `DATETIME` value to `buf`
+fn encode_date(year: i32, month: u32, day: u32, buf: &mut Vec<u8>) -> Result<(), BoxDynError> {
+    if !(1..=9999).contains(&year) {
+        return Err(format!(
+            "Year {} is out of range for MySQL, only years 1 to 9999 can be encoded",
+            year
+        )
+        .into());
+    }
+
+    buf.extend_from_slice(&(year as u16).to_le_bytes());
+    buf.push(month as u8);
+    buf.push(day as u8);
+    Ok(())
+}
+
+/// Decodes a `DATE`, `DATETIME` or `TIMESTAMP` value. Binary values start with their length, which is at most `11`, while text values start with a digit.
+fn decode_datetime(bytes: &[u8]) -> Result<DateTime, BoxDynError> {
+    match bytes.split_first() {
+        None => Err("Empty buffer".into()),
+        Some((&length, rest)) if length <= 11 => decode_binary(length, rest),
+        Some(_) => decode_text(std::str::from_utf8(bytes)?),
+    }
+}
+
+fn decode_binary(length: u8, buf: &[u8]) -> Result<DateTime, BoxDynError> {
+    if !matches!(length, 0 | 4 | 7 | 11) || buf.len() < length as usize {
+        return Err(format!(
+            "Expected 0, 4, 7 or 11 bytes for a MySQL date, got {} ({} available)",
+            length,
+            buf.len()
+        )
+        .into());
+    }
+    if length == 0 {
+        return Err("MySQL zero dates (0000-00-00) are not supported".into());
+    }
+
+    let year = u16::from_le_bytes([buf[0], buf[1]]) as i32;
+    let (hour, minute, second) = if length >= 7 {
+        (buf[4] as u32, buf[5] as u32, buf[6] as u32)
+    } else {
+        (0, 0, 0)
+    };
+    let micros = if length == 11 {
+        u32::from_le_bytes([buf[7], buf[8], buf[9], buf[10]])
+    } else {
+        0
+    };
+    if micros > 999_999 {
+        return Err(format!("MySQL returned microseconds out of range: {}", micros).into());
+    }
+
+    Ok(DateTime::from_ymdhms_nano(
+        year,
+        buf[2] as u32,
+        buf[3] as u32,
+        hour,
+        minute,
+        second,
+        micros * 1_000,
+    )?)
+}
+
+fn decode_text(string: &str) -> Result<DateTime, BoxDynError> {
+    if string.len() == 10 {
+        return Ok(Date::parse(string, "yyyy-MM-dd")?.into());
+    }
+    // Text values don't have an offset, they are in UTC like the encoded values
+    Ok(DateTime::parse_rfc3339_lenient(&format!("{}Z", string))?)
+}
+
+#[cfg(test)]
+mod mysql_tests {
+    use super::{decode_datetime, encode_date};
+    use crate::{DateTime, TimeUtilities};
+
+    #[test]
+    fn binary() {
+        let date_time =
+            decode_datetime(&[11, 0xe6, 0x07, 5, 2, 12, 32, 1, 0x40, 0xe2, 0x01, 0x00]).unwrap();
+        assert_eq!(
+            DateTime::from_ymdhms_nano(2022, 5, 2, 12, 32, 1, 123_456_000).unwrap(),
+            date_time
+        );
+        assert_eq!(
+            DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap(),
+            decode_datetime(&[7, 0xe6, 0x07, 5, 2, 12, 32, 1]).unwrap()
+        );
+        assert_eq!(
+            DateTime::from_ymd(2022, 5, 2).unwrap(),
+            decode_datetime(&[4, 0xe6, 0x07, 5, 2]).unwrap()
+        );
+        assert_eq!(
+            DateTime::from_ymdhms_nano(9999, 12, 31, 23, 59, 59, 999_999_000).unwrap(),
+            decode_datetime(&[11, 0x0f, 0x27, 12, 31, 23, 59, 59, 0x3f, 0x42, 0x0f, 0x00]).unwrap()
+        );
+
+        // Invalid values return an error
+        assert!(decode_datetime(&[]).is_err());
+        assert!(decode_datetime(&[0]).is_err());
+        assert!(decode_datetime(&[4, 0xe6, 0x07, 5]).is_err());
+        assert!(decode_datetime(&[5, 0xe6, 0x07, 5, 2, 0]).is_err());
+        assert!(decode_datetime(&[4, 0, 0, 0, 0]).is_err());
+        assert!(decode_datetime(&[4, 0xe6, 0x07, 13, 2]).is_err());
+        assert!(decode_datetime(&[4, 0xe6, 0x07, 2, 30]).is_err());
+        assert!(decode_datetime(&[7, 0xe6, 0x07, 5, 2, 24, 0, 0]).is_err());
+        assert!(
+            decode_datetime(&[11, 0xe6, 0x07, 5, 2, 12, 32, 1, 0x40, 0x42, 0x0f, 0x00]).is_err()
+        );
+        assert!(
+            decode_datetime(&[11, 0xe6, 0x07, 5, 2, 12, 32, 1, 0xff, 0xff, 0xff, 0xff]).is_err()
+        );
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(
+            DateTime::from_ymdhms_nano(2022, 5, 2, 12, 32, 1, 123_456_000).unwrap(),
+            decode_datetime(b"2022-05-02 12:32:01.123456").unwrap()
+        );
+        assert_eq!(
+            DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap(),
+            decode_datetime(b"2022-05-02 12:32:01").unwrap()
+        );
+        assert_eq!(
+            DateTime::from_ymd(2022, 5, 2).unwrap(),
+            decode_datetime(b"2022-05-02").unwrap()
+        );
+
+        assert!(decode_datetime(b"0000-00-00").is_err());
+        assert!(decode_datetime(b"0000-00-00 00:00:00").is_err());
+        assert!(decode_datetime(b"2022-02-30 12:32:01").is_err());
+        assert!(decode_datetime(b"2022-05-02 12:32").is_err());
+        assert!(decode_datetime(&[b'2', 0xff]).is_err());
+        assert!(decode_datetime("2022-05-é".as_bytes()).is_err());
+        assert!(decode_datetime("2022-05-02 12:32:0é".as_bytes()).is_err());
+        assert!(decode_datetime("2022-05-02 12:32:01.é".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn year_range() {
+        let mut buf = Vec::new();
+        encode_date(1, 1, 1, &mut buf).unwrap();
+        encode_date(9999, 12, 31, &mut buf).unwrap();
+        assert_eq!(vec![1, 0, 1, 1, 0x0f, 0x27, 12, 31], buf);
+
+        assert!(encode_date(10_000, 1, 1, &mut buf).is_err());
+        assert!(encode_date(-1, 1, 1, &mut buf).is_err());
+        assert_eq!(8, buf.len());
+
+        let date_time = DateTime::from_ymd(1, 1, 1).unwrap();
+        assert_eq!(date_time, decode_datetime(&[4, 1, 0, 1, 1]).unwrap());
+        assert_eq!(0, date_time.nano());
+    }
+}
@@ -0,0 +1,115 @@
+use crate::Time;
+use sqlx::{
+    encode::IsNull,
+    error::BoxDynError,
+    mysql::{
+        types::{MySqlTime, MySqlTimeSign},
+        MySql, MySqlTypeInfo, MySqlValueRef,
+    },
+    Decode, Encode, Type,
+};
+
+impl Type<MySql> for Time {
+    fn type_info() -> MySqlTypeInfo {
+        MySqlTime::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        MySqlTime::compatible(ty)
+    }
+}
+
+/// Subsecond values are truncated to microseconds, which is the precision of `TIME(6)`.
+impl Encode<'_, MySql> for Time {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        to_mysql_time(self)?.encode_by_ref(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        13
+    }
+}
+
+/// Returns an error if the `TIME` value is negative or not a time of day (`24:00:00` or more).
+impl Decode<'_, MySql> for Time {
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        from_mysql_time(MySqlTime::decode(value)?)
+    }
+}
+
+fn to_mysql_time(time: &Time) -> Result<MySqlTime, BoxDynError> {
+    let (hour, minute, second, nanos) = time.as_hms_nano();
+    Ok(MySqlTime::new(
+        MySqlTimeSign::Positive,
+        hour,
+        minute as u8,
+        second as u8,
+        nanos / 1_000,
+    )?)
+}
+
+fn from_mysql_time(time: MySqlTime) -> Result<Time, BoxDynError> {
+    // Also rejects negative values
+    if !time.is_valid_time_of_day() {
+        return Err(format!("MySQL time {} is not a time of day", time).into());
+    }
+
+    Ok(Time::from_hms_nano(
+        time.hours(),
+        time.minutes() as u32,
+        time.seconds() as u32,
+        time.microseconds() * 1_000,
+    )?)
+}
+
+#[cfg(test)]
+mod mysql_time_tests {
+    use super::{from_mysql_time, to_mysql_time};
+    use crate::Time;
+    use sqlx::{
+        mysql::{
+            types::{MySqlTime, MySqlTimeSign},
+            MySql,
+        },
+        Encode,
+    };
+
+    #[test]
+    fn encode_binary() {
+        let encode = |time: Time| {
+            let mut buf = Vec::new();
+            assert!(Encode::<MySql>::encode(time, &mut buf).is_ok());
+            buf
+        };
+
+        assert_eq!(
+            vec![12, 0, 0, 0, 0, 0, 12, 32, 1, 0x40, 0xe2, 0x01, 0x00],
+            encode(Time::from_hms_nano(12, 32, 1, 123_456_789).unwrap())
+        );
+        assert_eq!(
+            vec![8, 0, 0, 0, 0, 0, 23, 59, 59],
+            encode(Time::from_hms(23, 59, 59).unwrap())
+        );
+        assert_eq!(vec![0], encode(Time::from_hms(0, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn conversion() {
+        for time in [
+            Time::from_hms(0, 0, 0).unwrap(),
+            Time::from_hms_nano(12, 32, 1, 123_456_000).unwrap(),
+            Time::from_hms_nano(23, 59, 59, 999_999_000).unwrap(),
+        ] {
+            assert_eq!(
+                time,
+                from_mysql_time(to_mysql_time(&time).unwrap()).unwrap()
+            );
+        }
+
+        let negative = MySqlTime::new(MySqlTimeSign::Negative, 1, 0, 0, 0).unwrap();
+        assert!(from_mysql_time(negative).is_err());
+        let whole_day = MySqlTime::new(MySqlTimeSign::Positive, 24, 0, 0, 0).unwrap();
+        assert!(from_mysql_time(whole_day).is_err());
+        assert!(from_mysql_time(MySqlTime::MAX).is_err());
+    }
+}
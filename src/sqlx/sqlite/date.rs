@@ -0,0 +1,60 @@
+use super::{check_year, from_julian_date};
+use crate::{Date, DateUtilities};
+use sqlx::{
+    encode::IsNull,
+    error::BoxDynError,
+    sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef},
+    Decode, Encode, Type, TypeInfo, ValueRef,
+};
+
+impl Type<Sqlite> for Date {
+    fn type_info() -> SqliteTypeInfo {
+        <str as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        matches!(ty.name(), "TEXT" | "REAL" | "DATE")
+    }
+}
+
+/// Encodes the date as text, e.g. `2022-05-02`.
+///
+/// Returns an error if the year is outside of `1` to `9999`.
+impl<'q> Encode<'q, Sqlite> for Date {
+    fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'q>>) -> Result<IsNull, BoxDynError> {
+        check_year(self.year())?;
+        Encode::<Sqlite>::encode(self.format("yyyy-MM-dd"), buf)
+    }
+}
+
+/// Decodes text like `2022-05-02` and Julian dates (`REAL`). The time of a Julian date is ignored, the date is in UTC.
+impl Decode<'_, Sqlite> for Date {
+    fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+        if value.type_info().name() == "REAL" {
+            return from_julian_date(Decode::<Sqlite>::decode(value)?).map(Date::from);
+        }
+
+        let string: &str = Decode::<Sqlite>::decode(value)?;
+        Ok(Date::parse(string, "yyyy-MM-dd")?)
+    }
+}
+
+#[cfg(test)]
+mod sqlite_date_tests {
+    use crate::Date;
+    use sqlx::{sqlite::Sqlite, sqlite::SqliteArgumentValue, Encode};
+
+    #[test]
+    fn encode_text() {
+        let mut buf = Vec::new();
+        assert!(Encode::<Sqlite>::encode(Date::from_ymd(2022, 5, 2).unwrap(), &mut buf).is_ok());
+        assert!(matches!(
+            buf.as_slice(),
+            [SqliteArgumentValue::Text(text)] if text == "2022-05-02"
+        ));
+
+        let mut buf = Vec::new();
+        assert!(Encode::<Sqlite>::encode(Date::from_ymd(-5, 5, 2).unwrap(), &mut buf).is_err());
+        assert!(buf.is_empty());
+    }
+}
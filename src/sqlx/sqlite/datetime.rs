@@ -0,0 +1,119 @@
+use super::{check_year, from_julian_date};
+use crate::{Date, DateTime, DateUtilities, Offset, OffsetUtilities, Precision};
+use sqlx::{
+    encode::IsNull,
+    error::BoxDynError,
+    sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef},
+    Decode, Encode, Type, TypeInfo, ValueRef,
+};
+
+impl Type<Sqlite> for DateTime {
+    fn type_info() -> SqliteTypeInfo {
+        <str as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        matches!(ty.name(), "TEXT" | "REAL" | "DATETIME")
+    }
+}
+
+/// Encodes the instance as an RFC 3339 string in UTC with nanoseconds, e.g. `2022-05-02T15:30:20.123456789Z`. All values have the same length, so they can be sorted as text.
+///
+/// Returns an error if the year is outside of `1` to `9999`.
+impl<'q> Encode<'q, Sqlite> for DateTime {
+    fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'q>>) -> Result<IsNull, BoxDynError> {
+        let utc = self.set_offset(Offset::Fixed(0));
+        check_year(utc.year())?;
+        Encode::<Sqlite>::encode(utc.format_rfc3339(Precision::Nanos), buf)
+    }
+}
+
+/// Decodes text and Julian dates (`REAL`), which are rounded to milliseconds.
+///
+/// Text is parsed with [`DateTime::parse_rfc3339_lenient`]. Timestamps without an offset, like `2022-05-02 15:30:20` returned by SQLite's `datetime` function, and dates without a time are read as UTC.
+impl Decode<'_, Sqlite> for DateTime {
+    fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+        if value.type_info().name() == "REAL" {
+            return from_julian_date(Decode::<Sqlite>::decode(value)?);
+        }
+
+        let string: &str = Decode::<Sqlite>::decode(value)?;
+        parse_datetime(string)
+    }
+}
+
+fn parse_datetime(string: &str) -> Result<DateTime, BoxDynError> {
+    if string.len() == 10 {
+        return Ok(Date::parse(string, "yyyy-MM-dd")?.into());
+    }
+    DateTime::parse_rfc3339_lenient(string)
+        .or_else(|_| DateTime::parse_rfc3339_lenient(&format!("{}Z", string)))
+        .map_err(Into::into)
+}
+
+#[cfg(test)]
+mod sqlite_datetime_tests {
+    use super::parse_datetime;
+    use crate::{DateTime, Offset, OffsetUtilities};
+    use sqlx::{sqlite::Sqlite, sqlite::SqliteArgumentValue, Encode};
+
+    fn encode(date_time: DateTime) -> Option<String> {
+        let mut buf = Vec::new();
+        match Encode::<Sqlite>::encode(date_time, &mut buf).map(|_| buf.pop()) {
+            Ok(Some(SqliteArgumentValue::Text(text))) => Some(text.into_owned()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn encode_text() {
+        let date_time = DateTime::from_ymdhms_nano(2022, 5, 2, 12, 32, 1, 123_456_789).unwrap();
+        assert_eq!(
+            Some("2022-05-02T12:32:01.123456789Z".to_string()),
+            encode(date_time)
+        );
+        assert_eq!(
+            Some("2022-05-02T12:32:01.123456789Z".to_string()),
+            encode(date_time.set_offset(Offset::from_hms(-5, 0, 0).unwrap()))
+        );
+        assert_eq!(
+            Some("0001-01-01T00:00:00.000000000Z".to_string()),
+            encode(DateTime::from_ymd(1, 1, 1).unwrap())
+        );
+
+        assert_eq!(None, encode(DateTime::from_ymd(10_000, 1, 1).unwrap()));
+        assert_eq!(None, encode(DateTime::from_ymd(-1, 12, 31).unwrap()));
+        // The year is checked in UTC
+        assert_eq!(
+            None,
+            encode(
+                DateTime::from_ymd(1, 1, 1)
+                    .unwrap()
+                    .as_offset(Offset::from_hms(1, 0, 0).unwrap())
+            )
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            DateTime::from_ymdhms_nano(2022, 5, 2, 12, 32, 1, 123_000_000).unwrap(),
+            parse_datetime("2022-05-02 12:32:01.123").unwrap()
+        );
+        assert_eq!(
+            DateTime::from_ymdhms(2022, 5, 2, 10, 32, 1).unwrap(),
+            parse_datetime("2022-05-02T12:32:01+02:00").unwrap()
+        );
+        assert_eq!(
+            DateTime::from_ymd(2022, 5, 2).unwrap(),
+            parse_datetime("2022-05-02").unwrap()
+        );
+
+        // Malformed text returns an error instead of panicking
+        assert!(parse_datetime("2022-05-é").is_err());
+        assert!(parse_datetime("2022-05-02 12:32:0é").is_err());
+        assert!(parse_datetime("2022-05-02T15:30:2éZxxxx").is_err());
+        assert!(parse_datetime("2022-05-02 12:32:01.é").is_err());
+        assert!(parse_datetime("").is_err());
+    }
+}
@@ -0,0 +1,81 @@
+mod date;
+mod datetime;
+mod time;
+
+use crate::{util::constants::JULIAN_DAY_OFFSET, DateTime};
+use sqlx::error::BoxDynError;
+
+/// Checks that the year can be written as four digits, as expected by SQLite's date and time functions
+fn check_year(year: i32) -> Result<(), BoxDynError> {
+    if (1..=9999).contains(&year) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Year {} is out of range for SQLite, only years 1 to 9999 can be encoded",
+            year
+        )
+        .into())
+    }
+}
+
+/// Converts a Julian date, as returned by SQLite's `julianday` function, to an instance. Rounded to milliseconds, as `f64` can't be more precise for current dates.
+fn from_julian_date(julian_date: f64) -> Result<DateTime, BoxDynError> {
+    // Julian days start at noon
+    let millis = ((julian_date - JULIAN_DAY_OFFSET as f64 + 0.5) * 86_400_000.0).round();
+    if !millis.is_finite() || millis.abs() > 1e18 {
+        return Err(format!("Julian date {} is out of range", julian_date).into());
+    }
+
+    let millis = millis as i64;
+    let days = i32::try_from(millis.div_euclid(86_400_000))
+        .map_err(|_| format!("Julian date {} is out of range", julian_date))?;
+    Ok(DateTime::from_raw(
+        days,
+        millis.rem_euclid(86_400_000) as u64 * 1_000_000,
+    )?)
+}
+
+#[cfg(test)]
+mod sqlite_tests {
+    use super::{check_year, from_julian_date};
+    use crate::{DateTime, TimeUtilities};
+
+    #[test]
+    fn julian_date() {
+        assert_eq!(
+            DateTime::from_ymd(2022, 5, 2).unwrap(),
+            from_julian_date(2_459_701.5).unwrap()
+        );
+        assert_eq!(
+            DateTime::from_ymdhms(2000, 1, 1, 18, 0, 0).unwrap(),
+            from_julian_date(2_451_545.25).unwrap()
+        );
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1)
+            .unwrap()
+            .set_milli(123)
+            .unwrap();
+        assert_eq!(
+            date_time,
+            from_julian_date(date_time.julian_date()).unwrap()
+        );
+        assert_eq!(
+            DateTime::from_ymdhms(-4714, 11, 24, 12, 0, 0).unwrap(),
+            from_julian_date(0.0).unwrap()
+        );
+
+        assert!(from_julian_date(f64::NAN).is_err());
+        assert!(from_julian_date(f64::INFINITY).is_err());
+        assert!(from_julian_date(f64::MAX).is_err());
+        assert!(from_julian_date(1e12).is_err());
+        assert!(from_julian_date(-1e12).is_err());
+    }
+
+    #[test]
+    fn year_range() {
+        assert!(check_year(1).is_ok());
+        assert!(check_year(9999).is_ok());
+        assert!(check_year(0).is_err());
+        assert!(check_year(-1).is_err());
+        assert!(check_year(10_000).is_err());
+    }
+}
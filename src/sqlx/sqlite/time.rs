@@ -0,0 +1,101 @@
+use crate::{util::parse::parse_subseconds, Time};
+use sqlx::{
+    encode::IsNull,
+    error::BoxDynError,
+    sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef},
+    Decode, Encode, Type, TypeInfo,
+};
+
+impl Type<Sqlite> for Time {
+    fn type_info() -> SqliteTypeInfo {
+        <str as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        matches!(ty.name(), "TEXT" | "TIME")
+    }
+}
+
+/// Encodes the time as text with nanoseconds, e.g. `15:30:20.123456789`.
+impl<'q> Encode<'q, Sqlite> for Time {
+    fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'q>>) -> Result<IsNull, BoxDynError> {
+        Encode::<Sqlite>::encode(self.format("HH:mm:ss.nnnnn"), buf)
+    }
+}
+
+/// Decodes text like `15:30`, `15:30:20` or `15:30:20.123` with up to nine decimal places.
+impl Decode<'_, Sqlite> for Time {
+    fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+        let string: &str = Decode::<Sqlite>::decode(value)?;
+        parse_time(string)
+    }
+}
+
+fn parse_time(string: &str) -> Result<Time, BoxDynError> {
+    let (time, fraction) = match string.split_once('.') {
+        Some((time, fraction)) => (time, Some(fraction)),
+        None => (string, None),
+    };
+    let time = match time.len() {
+        5 => Time::parse(time, "HH:mm")?,
+        8 => Time::parse(time, "HH:mm:ss")?,
+        _ => return Err(format!("Invalid time '{}'", string).into()),
+    };
+
+    let nanos = match fraction {
+        None => 0,
+        // Nanoseconds fit into an u32
+        Some(fraction) if fraction.len() <= 9 => parse_subseconds(fraction)? as u32,
+        Some(fraction) => return Err(format!("Invalid subseconds '{}'", fraction).into()),
+    };
+    let (hour, minute, second) = time.as_hms();
+    Ok(Time::from_hms_nano(hour, minute, second, nanos)?)
+}
+
+#[cfg(test)]
+mod sqlite_time_tests {
+    use super::parse_time;
+    use crate::Time;
+    use sqlx::{sqlite::Sqlite, sqlite::SqliteArgumentValue, Encode};
+
+    #[test]
+    fn encode_text() {
+        let mut buf = Vec::new();
+        let time = Time::from_hms_nano(15, 30, 20, 123_000_000).unwrap();
+        assert!(Encode::<Sqlite>::encode(time, &mut buf).is_ok());
+        assert!(matches!(
+            buf.as_slice(),
+            [SqliteArgumentValue::Text(text)] if text == "15:30:20.123000000"
+        ));
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Time::from_hms(15, 30, 0).unwrap(),
+            parse_time("15:30").unwrap()
+        );
+        assert_eq!(
+            Time::from_hms(15, 30, 20).unwrap(),
+            parse_time("15:30:20").unwrap()
+        );
+        assert_eq!(
+            Time::from_hms_nano(15, 30, 20, 123_000_000).unwrap(),
+            parse_time("15:30:20.123").unwrap()
+        );
+        assert_eq!(
+            Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap(),
+            parse_time("23:59:59.999999999").unwrap()
+        );
+
+        assert!(parse_time("24:00:00").is_err());
+        assert!(parse_time("15:30:20.").is_err());
+        assert!(parse_time("15:30:20.1234567890").is_err());
+        assert!(parse_time("15:30:20.+12").is_err());
+        assert!(parse_time("15:30:20Z").is_err());
+        assert!(parse_time("").is_err());
+        assert!(parse_time("15:3é").is_err());
+        assert!(parse_time("15:30:2é").is_err());
+        assert!(parse_time("15:30:20.1é").is_err());
+    }
+}
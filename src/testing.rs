@@ -0,0 +1,54 @@
+//! Utilities to control the current time in tests. Requires the `test-util` feature.
+//!
+//! After [`set_mock_now`] is called, [`DateTime::now`], [`Date::now`](crate::Date::now), [`Time::now`](crate::Time::now) and all functions based on them (E.g. [`DateTime::is_future`] or the iterators of [`CronSchedule`](crate::CronSchedule)) use the given instant instead of the system clock. The mocked time doesn't advance on its own.
+//!
+//! The mocked time is stored per thread, so tests running in parallel don't affect each other. Code running on other threads still uses the system clock.
+//!
+//! ```rust
+//! # use astrolabe::{testing, CronSchedule, DateTime, Date, DateUtilities};
+//! testing::set_mock_now(DateTime::from_ymdhms(2022, 5, 2, 10, 17, 0).unwrap());
+//! assert_eq!(Date::from_ymd(2022, 5, 2).unwrap(), Date::now());
+//!
+//! let schedule = CronSchedule::parse("*/15 * * * *").unwrap();
+//! let upcoming: Vec<String> = schedule
+//!     .upcoming()
+//!     .take(3)
+//!     .map(|date_time| date_time.format("HH:mm"))
+//!     .collect();
+//! assert_eq!(vec!["10:30", "10:45", "11:00"], upcoming);
+//!
+//! testing::clear_mock_now();
+//! assert!(2022 < Date::now().year());
+//! ```
+
+use std::cell::Cell;
+
+use crate::DateTime;
+
+thread_local! {
+    static MOCK_NOW: Cell<Option<DateTime>> = Cell::new(None);
+}
+
+/// Sets the current time of this thread to the given instant, until [`clear_mock_now`] is called. The offset of `date_time` is ignored, [`DateTime::now`] always returns an instance with a UTC offset.
+///
+/// ```rust
+/// # use astrolabe::{testing, DateTime, Time};
+/// let date_time = DateTime::from_ymdhms(2022, 5, 2, 10, 17, 0).unwrap();
+/// testing::set_mock_now(date_time);
+/// assert_eq!(date_time, DateTime::now());
+/// assert_eq!(Time::from_hms(10, 17, 0).unwrap(), Time::now());
+/// # testing::clear_mock_now();
+/// ```
+pub fn set_mock_now(date_time: DateTime) {
+    MOCK_NOW.with(|now| now.set(Some(date_time)));
+}
+
+/// Resets the current time of this thread to the system clock.
+pub fn clear_mock_now() {
+    MOCK_NOW.with(|now| now.set(None));
+}
+
+/// Returns the mocked current time of this thread, if set
+pub(crate) fn mock_now() -> Option<DateTime> {
+    MOCK_NOW.with(Cell::get)
+}
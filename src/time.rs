@@ -1,38 +1,46 @@
 use crate::{
-    errors::{out_of_range::create_simple_oor, AstrolabeError},
+    errors::{
+        invalid_format::create_invalid_format, out_of_range::create_simple_oor, AstrolabeError,
+    },
     util::{
         constants::{
-            NANOS_PER_DAY, NANOS_PER_SEC, SECS_PER_DAY, SECS_PER_DAY_U64, SECS_PER_HOUR_U64,
-            SECS_PER_MINUTE_U64,
+            NANOS_PER_DAY, NANOS_PER_HOUR, NANOS_PER_MINUTE, NANOS_PER_SEC, SECS_PER_DAY,
+            SECS_PER_DAY_U64,
         },
-        format::format_time_part,
+        format::{check_format_symbols, format_time_part, TIME_SYMBOLS},
         offset::{add_offset_to_nanos, remove_offset_from_nanos},
-        parse::{parse_format_string, parse_time_part, ParseUnit, ParsedTime, Period},
+        parse::{
+            parse_digits, parse_fixed_width, parse_format_string, parse_subseconds,
+            parse_time_part, FormatPart, ParseUnit, ParsedTime, Period,
+        },
         time::{
             convert::{
                 days_nanos_to_hours, days_nanos_to_micros, days_nanos_to_millis,
                 days_nanos_to_minutes, days_nanos_to_nanos, days_nanos_to_seconds,
                 nanos_to_subhour_nanos, nanos_to_submicro_nanos, nanos_to_submilli_nanos,
                 nanos_to_subminute_nanos, nanos_to_subsecond, nanos_to_subsecond_nanos,
-                nanos_to_time, since_i32, since_i64, time_to_day_seconds,
+                nanos_to_time, now_days_nanos, since_i32, since_i64, time_to_day_nanos,
+                time_to_day_seconds, time_to_day_seconds_unchecked,
             },
             manipulate::{
-                add_hours, add_micros, add_millis, add_minutes, add_seconds,
+                add_hours, add_micros, add_millis, add_minutes, add_seconds, ceil_nanos,
                 clear_nanos_until_micro, clear_nanos_until_milli, clear_nanos_until_minute,
-                clear_nanos_until_nanos, clear_nanos_until_second, set_hour, set_micro, set_milli,
-                set_minute, set_nano, set_second, sub_hours, sub_micros, sub_millis, sub_minutes,
-                sub_seconds,
+                clear_nanos_until_nanos, clear_nanos_until_second, day_divisor_nanos, floor_nanos,
+                round_nanos, set_hour, set_micro, set_milli, set_minute, set_nano, set_second,
+                sub_hours, sub_micros, sub_millis, sub_minutes, sub_seconds,
             },
+            validate::is_valid_time,
         },
     },
-    DateTime, Offset, OffsetUtilities, TimeUtilities,
+    Bounds, DateTime, Locale, Offset, OffsetUtilities, ParseOptions, TimeUnit, TimeUtilities, Unit,
 };
 use std::{
     cmp,
     fmt::Display,
+    hash::{Hash, Hasher},
     ops::{Add, AddAssign, Sub, SubAssign},
     str::FromStr,
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    time::Duration,
 };
 
 /// Clock time with nanosecond precision.
@@ -47,7 +55,9 @@ pub struct Time {
 }
 
 impl Time {
-    /// Creates a new [`Time`] instance with [`SystemTime::now()`].
+    /// Creates a new [`Time`] instance with [`SystemTime::now()`](std::time::SystemTime::now).
+    ///
+    /// The instance has nanosecond precision, but the actual resolution depends on the system clock.
     ///
     /// ```rust
     /// # use astrolabe::Time;
@@ -55,18 +65,14 @@ impl Time {
     /// println!("{}", time);
     /// ```
     pub fn now() -> Self {
-        let duration = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards");
-        let nanoseconds =
-            duration.as_secs() % SECS_PER_DAY_U64 * NANOS_PER_SEC + duration.subsec_nanos() as u64;
+        let nanoseconds = now_days_nanos().1;
         Self {
             nanoseconds,
             offset: Offset::default(),
         }
     }
 
-    /// Creates a new [`Time`] instance with [`SystemTime::now()`] with the local timezone as the offset.
+    /// Creates a new [`Time`] instance with [`SystemTime::now()`](std::time::SystemTime::now) with the local timezone as the offset.
     ///
     /// ```rust
     /// # use astrolabe::{Time, Offset, OffsetUtilities};
@@ -96,6 +102,46 @@ impl Time {
         })
     }
 
+    /// Creates a new [`Time`] instance from hour, minute, seconds and nanoseconds.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error naming the first invalid value.
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// let time = Time::from_hms_nano(12, 32, 1, 123_456_789).unwrap();
+    /// assert_eq!("12:32:01.123456789", time.format("HH:mm:ss.nnnnn"));
+    /// assert!(Time::from_hms_nano(12, 32, 1, 1_000_000_000).is_err());
+    /// ```
+    pub fn from_hms_nano(
+        hour: u32,
+        minute: u32,
+        second: u32,
+        nanos: u32,
+    ) -> Result<Self, AstrolabeError> {
+        Ok(Self {
+            nanoseconds: time_to_day_nanos(hour, minute, second, nanos)?,
+            offset: Offset::default(),
+        })
+    }
+
+    /// Creates a new [`Time`] instance from hour, minute and seconds without validating them. Intended for literals in const contexts, like lookup tables of known times.
+    ///
+    /// The values are only checked with debug assertions, which panic if the time is invalid. Without debug assertions, invalid values don't cause undefined behavior but produce a logically invalid [`Time`].
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// const MARKET_OPEN: Time = Time::from_hms_unchecked(9, 30, 0);
+    /// assert_eq!(Time::from_hms(9, 30, 0).unwrap(), MARKET_OPEN);
+    /// ```
+    pub const fn from_hms_unchecked(hour: u32, minute: u32, second: u32) -> Self {
+        debug_assert!(is_valid_time(hour, minute, second), "Invalid time");
+
+        Self {
+            nanoseconds: time_to_day_seconds_unchecked(hour, minute, second) as u64 * NANOS_PER_SEC,
+            offset: Offset::Fixed(0),
+        }
+    }
+
     /// Returns the time as hour, minute and seconds.
     ///
     /// ```rust
@@ -116,6 +162,18 @@ impl Time {
         (hour, minute, second)
     }
 
+    /// Returns the time as hour, minute, seconds and nanoseconds. Counterpart of [`Time::from_hms_nano`].
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// let time = Time::from_hms_nano(12, 32, 1, 5).unwrap();
+    /// assert_eq!((12, 32, 1, 5), time.as_hms_nano());
+    /// ```
+    pub fn as_hms_nano(&self) -> (u32, u32, u32, u32) {
+        let (hour, minute, second) = self.as_hms();
+        (hour, minute, second, self.nano())
+    }
+
     /// Creates a new [`Time`] instance from seconds.
     ///
     /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided seconds are invalid (over `86399`)
@@ -151,6 +209,80 @@ impl Time {
         (self.nanoseconds / NANOS_PER_SEC) as u32
     }
 
+    /// Creates a new [`Time`] instance from milliseconds.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided milliseconds are invalid (over `86_399_999`)
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// let time = Time::from_millis(1_234).unwrap();
+    /// assert_eq!("00:00:01.234", time.format("HH:mm:ss.nnn"));
+    /// ```
+    pub fn from_millis(millis: u32) -> Result<Self, AstrolabeError> {
+        if millis >= SECS_PER_DAY * 1_000 {
+            return Err(create_simple_oor(
+                "milliseconds",
+                0,
+                SECS_PER_DAY as i128 * 1_000 - 1,
+                millis as i128,
+            ));
+        }
+        Ok(Self {
+            nanoseconds: millis as u64 * 1_000_000,
+            offset: Offset::default(),
+        })
+    }
+
+    /// Returns the time as milliseconds since midnight in UTC, regardless of the offset. Subsecond values are truncated.
+    ///
+    /// For a [`DateTime`], use [`DateTime::time`] or `Time::from(date_time)` to get its time first.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Time, TimeUtilities};
+    /// let time = Time::from_hms(12, 12, 12).unwrap().set_nano(999_999).unwrap();
+    /// assert_eq!(43_932_000, time.as_millis());
+    /// ```
+    pub fn as_millis(&self) -> u32 {
+        (self.nanoseconds / 1_000_000) as u32
+    }
+
+    /// Creates a new [`Time`] instance from microseconds.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided microseconds are invalid (over `86_399_999_999`)
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// let time = Time::from_micros(1_234).unwrap();
+    /// assert_eq!("00:00:00.001234", time.format("HH:mm:ss.nnnn"));
+    /// ```
+    pub fn from_micros(micros: u64) -> Result<Self, AstrolabeError> {
+        if micros >= SECS_PER_DAY_U64 * 1_000_000 {
+            return Err(create_simple_oor(
+                "microseconds",
+                0,
+                SECS_PER_DAY_U64 as i128 * 1_000_000 - 1,
+                micros as i128,
+            ));
+        }
+        Ok(Self {
+            nanoseconds: micros * 1_000,
+            offset: Offset::default(),
+        })
+    }
+
+    /// Returns the time as microseconds since midnight in UTC, regardless of the offset. Subsecond values are truncated.
+    ///
+    /// For a [`DateTime`], use [`DateTime::time`] or `Time::from(date_time)` to get its time first.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Time, TimeUtilities};
+    /// let time = Time::from_hms(12, 12, 12).unwrap().set_nano(999).unwrap();
+    /// assert_eq!(43_932_000_000, time.as_micros());
+    /// ```
+    pub fn as_micros(&self) -> u64 {
+        self.nanoseconds / 1_000
+    }
+
     /// Creates a new [`Time`] instance from nanoseconds.
     ///
     /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided nanoseconds are invalid (over `86_399_999_999_999`)
@@ -175,7 +307,7 @@ impl Time {
         })
     }
 
-    /// Returns the time as nanoseconds.
+    /// Returns the time as nanoseconds since midnight in UTC, regardless of the offset. This is the raw representation used by [`Time::from_raw_nanos`].
     ///
     /// ```rust
     /// # use astrolabe::Time;
@@ -186,35 +318,191 @@ impl Time {
         self.nanoseconds
     }
 
+    /// Creates a new [`Time`] instance in UTC from its raw representation, the nanoseconds since midnight in UTC. Same as [`Time::from_nanos`].
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the nanoseconds are `86_400_000_000_000` or more. Together with [`Time::as_nanos`], this allows implementing custom serialization formats. The offset is not part of the raw representation and has to be stored separately if needed.
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// let time = Time::from_raw_nanos(43_932_000_000_000).unwrap();
+    /// assert_eq!("12:12:12", time.to_string());
+    /// assert!(Time::from_raw_nanos(86_400_000_000_000).is_err());
+    /// ```
+    pub fn from_raw_nanos(nanoseconds: u64) -> Result<Self, AstrolabeError> {
+        Self::from_nanos(nanoseconds)
+    }
+
+    /// Creates a new [`Time`] instance in UTC from a [`Duration`] since midnight. Durations of a day or longer wrap around midnight, e.g. `50` hours result in `02:00:00`.
+    ///
+    /// Use [`Time::try_from`] to get an error for durations of a day or longer instead.
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// # use std::time::Duration;
+    /// let time = Time::from_duration_wrapping(Duration::from_secs(50 * 3600));
+    /// assert_eq!("02:00:00", time.to_string());
+    /// ```
+    pub fn from_duration_wrapping(duration: Duration) -> Self {
+        Self {
+            nanoseconds: (duration.as_nanos() % NANOS_PER_DAY as u128) as u64,
+            offset: Offset::default(),
+        }
+    }
+
+    /// Returns the time one second later, or `None` if it would wrap around midnight.
+    ///
+    /// The limit is midnight in UTC, which matches the ordering of [`Ord`], so this can be used to step through ranges of times. Use [`wrapping_add_seconds`](Time::wrapping_add_seconds) to wrap around midnight instead.
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// let time = Time::from_hms(12, 59, 59).unwrap();
+    /// assert_eq!("13:00:00", time.next_second().unwrap().to_string());
+    /// assert_eq!(None, Time::from_hms(23, 59, 59).unwrap().next_second());
+    /// ```
+    pub fn next_second(&self) -> Option<Self> {
+        self.checked_step(NANOS_PER_SEC as i64)
+    }
+
+    /// Returns the time one second earlier, or `None` if it would wrap around midnight. See [`Time::next_second`].
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// let time = Time::from_hms(13, 0, 0).unwrap();
+    /// assert_eq!("12:59:59", time.previous_second().unwrap().to_string());
+    /// assert_eq!(None, Time::from_nanos(999_999_999).unwrap().previous_second());
+    /// ```
+    pub fn previous_second(&self) -> Option<Self> {
+        self.checked_step(-(NANOS_PER_SEC as i64))
+    }
+
+    /// Returns the time one nanosecond later, or `None` at `23:59:59.999999999` UTC. See [`Time::next_second`].
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// let time = Time::from_hms(12, 0, 0).unwrap();
+    /// assert_eq!(43_200_000_000_001, time.next_nano().unwrap().as_nanos());
+    /// assert_eq!(None, Time::from_nanos(86_399_999_999_999).unwrap().next_nano());
+    /// ```
+    pub fn next_nano(&self) -> Option<Self> {
+        self.checked_step(1)
+    }
+
+    /// Returns the time one nanosecond earlier, or `None` at midnight UTC. See [`Time::next_second`].
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// let time = Time::from_hms(12, 0, 0).unwrap();
+    /// assert_eq!(43_199_999_999_999, time.previous_nano().unwrap().as_nanos());
+    /// assert_eq!(None, Time::default().previous_nano());
+    /// ```
+    pub fn previous_nano(&self) -> Option<Self> {
+        self.checked_step(-1)
+    }
+
+    /// Shifts the time by less than a day, returning `None` if it would wrap around midnight
+    fn checked_step(&self, nanos: i64) -> Option<Self> {
+        let nanoseconds = self.nanoseconds as i64 + nanos;
+        if !(0..NANOS_PER_DAY as i64).contains(&nanoseconds) {
+            return None;
+        }
+
+        Some(Self {
+            nanoseconds: nanoseconds as u64,
+            offset: self.offset,
+        })
+    }
+
     /// Parses a string with a given format and creates a new [`Time`] instance from it. See [`Time::format`] for a list of available symbols.
     ///
-    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the given string could not be parsed with the given format.
+    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the given string could not be parsed with the given format, or an [`OutOfRange`](AstrolabeError::OutOfRange) error if a parsed value is out of range (E.g. minute `60` or hour `13` for `h`).
     ///
     /// ```rust
     /// # use astrolabe::Time;
     /// let date = Time::parse("12:32:01", "HH:mm:ss").unwrap();
     /// assert_eq!("12:32:01", date.format("HH:mm:ss"));
     /// ```
+    #[doc(alias = "strptime")]
     pub fn parse(string: &str, format: &str) -> Result<Self, AstrolabeError> {
+        Self::parse_with(string, format, &ParseOptions::default())
+    }
+
+    /// Parses a string with a given format like [`Time::parse`], using the names of the given [`Locale`].
+    ///
+    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the given string could not be parsed with the given format and locale.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Time, Locale};
+    /// let time = Time::parse_localized("9:30 nachm.", "h:mm aaaa", &Locale::GERMAN).unwrap();
+    /// assert_eq!("21:30:00", time.to_string());
+    /// ```
+    pub fn parse_localized(
+        string: &str,
+        format: &str,
+        locale: &Locale,
+    ) -> Result<Self, AstrolabeError> {
+        Self::parse_with(
+            string,
+            format,
+            &ParseOptions {
+                locale: *locale,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Parses a string with a given format like [`Time::parse`], using custom [`ParseOptions`].
+    ///
+    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the given string could not be parsed with the given format and options.
+    ///
+    /// ```rust
+    /// # use astrolabe::{ParseOptions, PeriodTokens, Time};
+    /// let options = ParseOptions {
+    ///     period_tokens: Some(PeriodTokens::new(&["A"], &["P"])),
+    ///     ..Default::default()
+    /// };
+    /// let time = Time::parse_with("09:30 P", "hh:mm a", &options).unwrap();
+    /// assert_eq!("21:30:00", time.format("HH:mm:ss"));
+    /// ```
+    pub fn parse_with(
+        string: &str,
+        format: &str,
+        options: &ParseOptions,
+    ) -> Result<Self, AstrolabeError> {
+        match parse_fixed_width(string, format) {
+            Some(parsed) => Self::from_nanos(parsed.nanoseconds),
+            None => Self::parse_generic(string, format, options),
+        }
+    }
+
+    /// Parses a string with any format string, used if [`parse_fixed_width`] doesn't apply
+    pub(crate) fn parse_generic(
+        mut string: &str,
+        format: &str,
+        options: &ParseOptions,
+    ) -> Result<Self, AstrolabeError> {
         let parts = parse_format_string(format);
 
         let mut time = ParsedTime::default();
-        let mut string = string.to_string();
+        let length = string.len();
 
         for part in parts {
-            // Escaped apostrophes
-            if part.starts_with('\u{0000}') {
-                string.replace_range(0..part.len(), "");
-                continue;
-            }
-
-            // Escaped parts
-            if part.starts_with('\'') {
-                string.replace_range(0..part.len() - if part.ends_with('\'') { 2 } else { 1 }, "");
-                continue;
-            }
+            let part = match part {
+                FormatPart::Symbol(part) => part,
+                // Escaped apostrophes
+                FormatPart::EscapedQuote => {
+                    string = &string[1..];
+                    continue;
+                }
+                // Escaped parts
+                FormatPart::Literal(literal) => {
+                    string = &string[literal.len()..];
+                    continue;
+                }
+            };
 
-            let parsed_part = parse_time_part(&part, &mut string)?;
+            let position = length - string.len();
+            let parsed_part = parse_time_part(part, &mut string, options)
+                .map_err(|e| e.at_position(position, part))?;
             if let Some(parsed_part) = parsed_part {
                 match parsed_part.unit {
                     ParseUnit::Hour => time.hour = Some(parsed_part.value as u64),
@@ -228,10 +516,6 @@ impl Time {
                     }
                     ParseUnit::Minute => time.minute = Some(parsed_part.value as u64),
                     ParseUnit::Second => time.second = Some(parsed_part.value as u64),
-                    ParseUnit::Decis => time.decis = Some(parsed_part.value as u64),
-                    ParseUnit::Centis => time.centis = Some(parsed_part.value as u64),
-                    ParseUnit::Millis => time.millis = Some(parsed_part.value as u64),
-                    ParseUnit::Micros => time.micros = Some(parsed_part.value as u64),
                     ParseUnit::Nanos => time.nanos = Some(parsed_part.value as u64),
                     // Can't be any other variant than `ParseUnit::Offset`
                     _ => time.offset = Some(parsed_part.value as i32),
@@ -239,23 +523,11 @@ impl Time {
             };
         }
 
-        let mut nanoseconds = 0;
+        if options.reject_conflicts {
+            time.check_conflicts()?;
+        }
 
-        if time.hour.is_some() {
-            nanoseconds += time.hour.unwrap_or(0) * SECS_PER_HOUR_U64 * NANOS_PER_SEC;
-        } else {
-            nanoseconds += (time.period_hour.unwrap_or(0)
-                + time.period.unwrap_or(Period::AM) as u64)
-                * SECS_PER_HOUR_U64
-                * NANOS_PER_SEC;
-        }
-        nanoseconds += time.minute.unwrap_or(0) * SECS_PER_MINUTE_U64 * NANOS_PER_SEC;
-        nanoseconds += time.second.unwrap_or(0) * NANOS_PER_SEC;
-        nanoseconds += time.decis.unwrap_or(0) * 100_000_000;
-        nanoseconds += time.centis.unwrap_or(0) * 10_000_000;
-        nanoseconds += time.millis.unwrap_or(0) * 1_000_000;
-        nanoseconds += time.micros.unwrap_or(0) * 1_000;
-        nanoseconds += time.nanos.unwrap_or(0);
+        let nanoseconds = time.nanoseconds()?;
 
         Ok(if let Some(offset) = time.offset {
             Self::from_nanos(nanoseconds)?.as_offset(Offset::from_seconds(offset)?)
@@ -264,6 +536,25 @@ impl Time {
         })
     }
 
+    /// Parses a string with a given format like [`Time::parse`], but returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if a 24-hour hour (`H`, `k`) doesn't match a 12-hour hour (`h`, `K`) with its day period. See [`ParseOptions::reject_conflicts`].
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// assert!(Time::parse_checked("02 PM 13", "hh a HH").is_err());
+    /// let time = Time::parse_checked("01 PM 13", "hh a HH").unwrap();
+    /// assert_eq!("13:00:00", time.format("HH:mm:ss"));
+    /// ```
+    pub fn parse_checked(string: &str, format: &str) -> Result<Self, AstrolabeError> {
+        Self::parse_with(
+            string,
+            format,
+            &ParseOptions {
+                reject_conflicts: true,
+                ..Default::default()
+            },
+        )
+    }
+
     /// Formatting with format strings based on [Unicode Date Field Symbols](https://www.unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table).
     ///
     /// Please note that not all symbols are implemented. If you need something that is not implemented, please open an issue on [GitHub](https://github.com/GiyoMoon/astrolabe/issues) describing your need.
@@ -280,6 +571,9 @@ impl Time {
     /// |                            | bbb     | am, pm,<br/>noon, midnight     | *                    |
     /// |                            | bbbb    | a.m., p.m.,<br/>noon, midnight |                      |
     /// |                            | bbbbb   | a, p, n, mi                    |                      |
+    /// | flexible day period        | B..BBB  | in the morning, at night       | *                    |
+    /// |                            | BBBB    | in the morning, at night       |                      |
+    /// |                            | BBBBB   | in the morning, at night       |                      |
     /// | hour                       | h       | 1, 12                          | [1-12]               |
     /// |                            | hh      | 01, 12                         | *                    |
     /// |                            | H       | 0, 23                          | [0-23]               |
@@ -307,59 +601,564 @@ impl Time {
     /// |                            | xxx     | -08:00, +00:00                 | *                    |
     /// |                            | xxxx    | -0800, -075258, +0000          |                      |
     /// |                            | xxxxx   | -08:00, -07:52:58, +00:00      |                      |
+    /// |                            | z..zzz  | CEST, GMT-08:00                | Designation if known, *|
+    /// |                            | zzzz    | GMT-08:00, GMT                 |                      |
     ///
     /// `*` = Default
     ///
     /// If the sequence is longer than listed in the table, the output will be the same as the default pattern for this unit (marked with `*`).
     ///
+    /// Flexible day periods (`B`) are morning from 06:00 to 11:59, afternoon from 12:00 to 17:59, evening from 18:00 to 20:59 and night from 21:00 to 05:59. Unlike `b`, they have no separate noon and midnight.
+    ///
+    /// Time zone designations (`z..zzz`) are only known for [`Offset::Local`](crate::Offset::Local), where they are read from the time zone data of the system. Fixed offsets and designations which are numeric (E.g. `+13`) are formatted like `zzzz`. When parsing, `GMT` offsets set the offset, while designations are skipped.
+    ///
+    /// Numeric values are never truncated, the length of the sequence only sets the minimum width. The only exception are subseconds (`n..nnnnn`), which are cut to the requested precision.
+    ///
     /// Surround any character with apostrophes (`'`) to escape them.
     /// If you want escape `'`, write `''`.
     ///
+    /// Letters which are not a format symbol are output as they are. Use [`Time::try_format`] to get an error instead.
+    ///
+    /// Like [`DateTime::format`](crate::DateTime::format), the time is formatted with its offset applied and the zone symbols (`X`/`x`) print that offset.
+    ///
     /// ```rust
-    /// # use astrolabe::Time;
+    /// # use astrolabe::{Offset, OffsetUtilities, Time};
     /// let time = Time::from_hms(12, 32, 1).unwrap();
     /// assert_eq!("12:32:01", time.format("HH:mm:ss"));
+    /// let with_offset = time.set_offset(Offset::from_hms(1, 0, 0).unwrap());
+    /// assert_eq!("13:32 +01:00", with_offset.format("HH:mm xxx"));
     /// // Escape characters
     /// assert_eq!("12:mm:ss", time.format("HH:'mm:ss'"));
     /// assert_eq!("12:'32:01'", time.format("HH:''mm:ss''"));
     /// ```
     ///
+    #[doc(alias = "strftime")]
     pub fn format(&self, format: &str) -> String {
-        let offset_seconds = self.offset.resolve();
+        self.format_localized(format, &Locale::ENGLISH)
+    }
 
-        let parts = parse_format_string(format);
-        parts
-            .iter()
-            .flat_map(|part| -> Vec<char> {
-                // Escaped apostrophes
-                if part.starts_with('\u{0000}') {
-                    return part.replace('\u{0000}', "'").chars().collect::<Vec<char>>();
-                }
+    /// Formats the time like [`Time::format`], but returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error if the format string contains an unescaped letter which is not a time format symbol, a symbol sequence which is too long or an escaped part which is not closed, like [`validate_format`](crate::validate_format). The error names the problematic part and its byte position.
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// let time = Time::from_hms(12, 32, 1).unwrap();
+    /// assert_eq!("12:32:01", time.try_format("HH:mm:ss").unwrap());
+    /// assert!(time.try_format("HH:mm:ss ffff").is_err());
+    /// ```
+    pub fn try_format(&self, format: &str) -> Result<String, AstrolabeError> {
+        check_format_symbols(format, &[TIME_SYMBOLS])?;
+        Ok(self.format(format))
+    }
 
-                // Escape parts starting with apostrophe
-                if part.starts_with('\'') {
-                    let part = part.replace('\u{0000}', "'");
-                    return part[1..part.len() - usize::from(part.ends_with('\''))]
-                        .chars()
-                        .collect::<Vec<char>>();
-                }
+    /// Formatting with format strings based on [Unicode Date Field Symbols](https://www.unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table), using the names of the given [`Locale`]. See [`Time::format`] for the available symbols.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Time, Locale};
+    /// let time = Time::from_hms(12, 0, 0).unwrap();
+    /// assert_eq!("12 Uhr Mittag", time.format_localized("h 'Uhr' bbbb", &Locale::GERMAN));
+    /// ```
+    pub fn format_localized(&self, format: &str, locale: &Locale) -> String {
+        let (offset_seconds, designation) = self.offset.resolve_zone();
+        let nanoseconds = add_offset_to_nanos(self.nanoseconds, offset_seconds);
 
-                format_time_part(
+        let mut formatted = String::with_capacity(format.len());
+        for part in parse_format_string(format) {
+            match part {
+                FormatPart::Symbol(part) => format_time_part(
+                    &mut formatted,
                     part,
-                    add_offset_to_nanos(self.nanoseconds, offset_seconds),
+                    nanoseconds,
                     offset_seconds,
-                )
-                .chars()
-                .collect::<Vec<char>>()
-            })
-            .collect::<String>()
+                    designation.as_deref(),
+                    locale,
+                ),
+                // Escaped parts
+                FormatPart::Literal(literal) => formatted.push_str(literal),
+                // Escaped apostrophes
+                FormatPart::EscapedQuote => formatted.push('\''),
+            }
+        }
+        formatted
     }
 
-    /// Returns the duration between the provided time.
+    /// Returns the duration between the provided time. The offsets of both instances are not relevant.
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// let start = Time::from_hms(22, 15, 0).unwrap();
+    /// let end = Time::from_hms(3, 40, 0).unwrap();
+    /// assert_eq!(66_900, start.duration_between(&end).as_secs());
+    /// assert_eq!(66_900, end.duration_between(&start).as_secs());
+    /// ```
     pub fn duration_between(&self, other: &Self) -> Duration {
-        let nanos = self.nanoseconds as i64 - other.nanoseconds as i64;
+        Duration::from_nanos((*self - *other).unsigned_abs())
+    }
 
-        Duration::from_nanos(nanos.unsigned_abs())
+    /// Returns the duration until the provided time is reached on a clock face. Wraps around midnight if `other` is earlier in the day. The offsets of both instances are not relevant.
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// let start = Time::from_hms(22, 15, 0).unwrap();
+    /// let end = Time::from_hms(3, 40, 0).unwrap();
+    /// assert_eq!(19_500, start.duration_until(&end).as_secs());
+    /// assert_eq!(66_900, end.duration_until(&start).as_secs());
+    /// ```
+    pub fn duration_until(&self, other: &Self) -> Duration {
+        Duration::from_nanos((*other - *self).rem_euclid(NANOS_PER_DAY as i64) as u64)
+    }
+
+    /// Returns `true` if the time is between `start` and `end`. Use [`Bounds`] to specify whether `start` and `end` are included.
+    ///
+    /// If `start` is after `end`, the range wraps around midnight. The comparison uses the same ordering as [`Ord`], which compares the times in UTC.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Bounds, Time};
+    /// let start = Time::from_hms(22, 0, 0).unwrap();
+    /// let end = Time::from_hms(6, 0, 0).unwrap();
+    ///
+    /// assert!(Time::from_hms(23, 0, 0).unwrap().is_between(&start, &end, Bounds::Inclusive));
+    /// assert!(Time::from_hms(1, 0, 0).unwrap().is_between(&start, &end, Bounds::Inclusive));
+    /// assert!(!Time::from_hms(12, 0, 0).unwrap().is_between(&start, &end, Bounds::Inclusive));
+    /// ```
+    pub fn is_between(&self, start: &Self, end: &Self, bounds: Bounds) -> bool {
+        if start > end {
+            bounds.after_start(self, start) || bounds.before_end(self, end)
+        } else {
+            bounds.after_start(self, start) && bounds.before_end(self, end)
+        }
+    }
+
+    /// Returns the time if it is between `start` and `end`, otherwise the closest time which is. Use [`Bounds`] to specify whether `start` and `end` are included. An excluded bound is clamped to one nanosecond after `start` or before `end`.
+    ///
+    /// If `start` is after `end`, the range wraps around midnight and a time outside of it is clamped to whichever bound is closer. If both are equally close, `end` is returned. The returned time has the offset of `self`.
+    ///
+    /// Panics if no time is between `start` and `end`.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Bounds, Time};
+    /// let start = Time::from_hms(22, 0, 0).unwrap();
+    /// let end = Time::from_hms(6, 0, 0).unwrap();
+    ///
+    /// let time = Time::from_hms(8, 0, 0).unwrap();
+    /// assert_eq!(end, time.clamp_between(&start, &end, Bounds::Inclusive));
+    /// let time = Time::from_hms(20, 0, 0).unwrap();
+    /// assert_eq!(start, time.clamp_between(&start, &end, Bounds::Inclusive));
+    /// ```
+    pub fn clamp_between(&self, start: &Self, end: &Self, bounds: Bounds) -> Self {
+        let lower = start.nanoseconds as i64 + if bounds.includes_start() { 0 } else { 1 };
+        let upper = end.nanoseconds as i64 - if bounds.includes_end() { 0 } else { 1 };
+        let nanoseconds = self.nanoseconds as i64;
+
+        let nanoseconds = if start > end {
+            let wraps_start = lower < NANOS_PER_DAY as i64;
+            let wraps_end = upper >= 0;
+            assert!(wraps_start || wraps_end, "No time is between start and end");
+
+            if nanoseconds >= lower || nanoseconds <= upper {
+                nanoseconds
+            } else if !wraps_start || (wraps_end && nanoseconds - upper <= lower - nanoseconds) {
+                upper
+            } else {
+                lower
+            }
+        } else {
+            assert!(lower <= upper, "No time is between start and end");
+            nanoseconds.clamp(lower, upper)
+        };
+
+        Self {
+            nanoseconds: nanoseconds as u64,
+            offset: self.offset,
+        }
+    }
+
+    /// Returns the time if it is between `min` and `max`, otherwise `min` or `max` with the offset of `self`.
+    ///
+    /// Like [`Ord::clamp`], the times are compared in UTC and the range doesn't wrap around midnight. `10:00+02:00` is therefore before `09:30Z`. Unlike [`Ord::clamp`], the returned time always keeps the offset of `self`. Use [`clamp_between`](Time::clamp_between) for ranges which wrap around midnight.
+    ///
+    /// Panics if `min` is after `max`.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Offset, OffsetUtilities, Time};
+    /// let min = Time::from_hms(10, 0, 0).unwrap();
+    /// let max = Time::from_hms(18, 0, 0).unwrap();
+    ///
+    /// // 09:30 UTC
+    /// let time = Time::from_hms(11, 30, 0).unwrap().as_offset(Offset::from_hms(2, 0, 0).unwrap());
+    /// let clamped = time.clamp(min, max);
+    /// assert_eq!(min, clamped);
+    /// assert_eq!("12:00:00", clamped.format("HH:mm:ss"));
+    /// ```
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min <= max, "min is after max");
+        self.clamp_between(&min, &max, Bounds::Inclusive)
+    }
+
+    /// Returns the earlier of both times, comparing them in UTC like [`Ord::min`]. If both are the same time in UTC, `self` is returned, even if the offsets differ.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Offset, OffsetUtilities, Time};
+    /// // 10:00+02:00 is 08:00 UTC
+    /// let time = Time::from_hms(8, 0, 0).unwrap().set_offset(Offset::from_hms(2, 0, 0).unwrap());
+    /// let other = Time::from_hms(9, 30, 0).unwrap();
+    /// assert_eq!(time, time.min(other));
+    /// assert_eq!(other, time.max(other));
+    /// ```
+    pub fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the later of both times, comparing them in UTC like [`Ord::max`]. If both are the same time in UTC, `self` is returned, even if the offsets differ.
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// let time = Time::from_hms(8, 0, 0).unwrap();
+    /// let other = Time::from_hms(9, 30, 0).unwrap();
+    /// assert_eq!(other, time.max(other));
+    /// ```
+    pub fn max(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Rounds the time down to the previous multiple of `duration` in local time. The offset is kept.
+    ///
+    /// Multiples are counted from local midnight, so `duration` has to evenly divide a day. Otherwise, or if the duration is zero, an [`OutOfRange`](AstrolabeError::OutOfRange) error is returned.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Offset, OffsetUtilities, Time};
+    /// # use std::time::Duration;
+    /// let time = Time::from_hms(10, 44, 0).unwrap();
+    /// assert_eq!("10:30:00", time.floor_to(Duration::from_secs(15 * 60)).unwrap().to_string());
+    ///
+    /// // Hours are aligned to the local clock
+    /// let time = time.set_offset(Offset::from_hms(5, 30, 0).unwrap());
+    /// assert_eq!("16:00", time.floor_to(Duration::from_secs(3600)).unwrap().format("HH:mm"));
+    /// ```
+    pub fn floor_to(&self, duration: Duration) -> Result<Self, AstrolabeError> {
+        self.align_to(duration, floor_nanos)
+    }
+
+    /// Rounds the time up to the next multiple of `duration` in local time, wrapping around from `23:59:59` to `00:00:00`. Times which already are a multiple are returned unchanged. The offset is kept.
+    ///
+    /// Multiples are counted from local midnight, so `duration` has to evenly divide a day. Otherwise, or if the duration is zero, an [`OutOfRange`](AstrolabeError::OutOfRange) error is returned.
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// # use std::time::Duration;
+    /// let time = Time::from_hms(23, 31, 0).unwrap();
+    /// assert_eq!("00:00:00", time.ceil_to(Duration::from_secs(3600)).unwrap().to_string());
+    /// ```
+    pub fn ceil_to(&self, duration: Duration) -> Result<Self, AstrolabeError> {
+        self.align_to(duration, ceil_nanos)
+    }
+
+    /// Rounds the time to the nearest multiple of `duration` in local time, wrapping around from `23:59:59` to `00:00:00`. Ties are rounded up. The offset is kept.
+    ///
+    /// Multiples are counted from local midnight, so `duration` has to evenly divide a day. Otherwise, or if the duration is zero, an [`OutOfRange`](AstrolabeError::OutOfRange) error is returned.
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// # use std::time::Duration;
+    /// let quarter = Duration::from_secs(15 * 60);
+    /// assert_eq!("10:00:00", Time::from_hms(10, 7, 29).unwrap().round_to(quarter).unwrap().to_string());
+    /// assert_eq!("10:15:00", Time::from_hms(10, 7, 30).unwrap().round_to(quarter).unwrap().to_string());
+    /// ```
+    pub fn round_to(&self, duration: Duration) -> Result<Self, AstrolabeError> {
+        self.align_to(duration, round_nanos)
+    }
+
+    /// Adds hours, wrapping around from `23:59:59` to `00:00:00`. Equivalent to [`add_hours`](TimeUtilities::add_hours).
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// let time = Time::from_hms(23, 0, 0).unwrap();
+    /// assert_eq!("00:00:00", time.wrapping_add_hours(25).to_string());
+    /// ```
+    pub fn wrapping_add_hours(&self, hours: u32) -> Self {
+        self.overflowing_add_hours(hours).0
+    }
+
+    /// Subtracts hours, wrapping around from `00:00:00` to `23:59:59`. Equivalent to [`sub_hours`](TimeUtilities::sub_hours).
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// let time = Time::from_hms(1, 0, 0).unwrap();
+    /// assert_eq!("23:00:00", time.wrapping_sub_hours(2).to_string());
+    /// ```
+    pub fn wrapping_sub_hours(&self, hours: u32) -> Self {
+        self.overflowing_sub_hours(hours).0
+    }
+
+    /// Adds hours and returns the wrapped time together with the number of days carried over.
+    ///
+    /// Days are counted in the offset of the instance, e.g. a day is carried over when passing local midnight.
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// let time = Time::from_hms(23, 0, 0).unwrap();
+    /// let (time, days) = time.overflowing_add_hours(25);
+    /// assert_eq!("00:00:00", time.to_string());
+    /// assert_eq!(2, days);
+    /// ```
+    pub fn overflowing_add_hours(&self, hours: u32) -> (Self, i32) {
+        self.overflowing_add_nanoseconds(hours as i128 * NANOS_PER_HOUR as i128)
+    }
+
+    /// Subtracts hours and returns the wrapped time together with the number of days carried over (zero or negative).
+    ///
+    /// Days are counted in the offset of the instance, e.g. a day is carried over when passing local midnight.
+    ///
+    /// ```rust
+    /// # use astrolabe::Time;
+    /// let time = Time::from_hms(1, 0, 0).unwrap();
+    /// let (time, days) = time.overflowing_sub_hours(2);
+    /// assert_eq!("23:00:00", time.to_string());
+    /// assert_eq!(-1, days);
+    /// ```
+    pub fn overflowing_sub_hours(&self, hours: u32) -> (Self, i32) {
+        self.overflowing_add_nanoseconds(-(hours as i128 * NANOS_PER_HOUR as i128))
+    }
+
+    /// Adds minutes, wrapping around from `23:59:59` to `00:00:00`. Equivalent to [`add_minutes`](TimeUtilities::add_minutes).
+    pub fn wrapping_add_minutes(&self, minutes: u32) -> Self {
+        self.overflowing_add_minutes(minutes).0
+    }
+
+    /// Subtracts minutes, wrapping around from `00:00:00` to `23:59:59`. Equivalent to [`sub_minutes`](TimeUtilities::sub_minutes).
+    pub fn wrapping_sub_minutes(&self, minutes: u32) -> Self {
+        self.overflowing_sub_minutes(minutes).0
+    }
+
+    /// Adds minutes and returns the wrapped time together with the number of days carried over.
+    ///
+    /// See [`overflowing_add_hours`](Time::overflowing_add_hours) for details.
+    pub fn overflowing_add_minutes(&self, minutes: u32) -> (Self, i32) {
+        self.overflowing_add_nanoseconds(minutes as i128 * NANOS_PER_MINUTE as i128)
+    }
+
+    /// Subtracts minutes and returns the wrapped time together with the number of days carried over (zero or negative).
+    ///
+    /// See [`overflowing_sub_hours`](Time::overflowing_sub_hours) for details.
+    pub fn overflowing_sub_minutes(&self, minutes: u32) -> (Self, i32) {
+        self.overflowing_add_nanoseconds(-(minutes as i128 * NANOS_PER_MINUTE as i128))
+    }
+
+    /// Adds seconds, wrapping around from `23:59:59` to `00:00:00`. Equivalent to [`add_seconds`](TimeUtilities::add_seconds).
+    pub fn wrapping_add_seconds(&self, seconds: u32) -> Self {
+        self.overflowing_add_seconds(seconds).0
+    }
+
+    /// Subtracts seconds, wrapping around from `00:00:00` to `23:59:59`. Equivalent to [`sub_seconds`](TimeUtilities::sub_seconds).
+    pub fn wrapping_sub_seconds(&self, seconds: u32) -> Self {
+        self.overflowing_sub_seconds(seconds).0
+    }
+
+    /// Adds seconds and returns the wrapped time together with the number of days carried over.
+    ///
+    /// See [`overflowing_add_hours`](Time::overflowing_add_hours) for details.
+    pub fn overflowing_add_seconds(&self, seconds: u32) -> (Self, i32) {
+        self.overflowing_add_nanoseconds(seconds as i128 * NANOS_PER_SEC as i128)
+    }
+
+    /// Subtracts seconds and returns the wrapped time together with the number of days carried over (zero or negative).
+    ///
+    /// See [`overflowing_sub_hours`](Time::overflowing_sub_hours) for details.
+    pub fn overflowing_sub_seconds(&self, seconds: u32) -> (Self, i32) {
+        self.overflowing_add_nanoseconds(-(seconds as i128 * NANOS_PER_SEC as i128))
+    }
+
+    /// Adds millis, wrapping around from `23:59:59` to `00:00:00`. Equivalent to [`add_millis`](TimeUtilities::add_millis).
+    pub fn wrapping_add_millis(&self, millis: u32) -> Self {
+        self.overflowing_add_millis(millis).0
+    }
+
+    /// Subtracts millis, wrapping around from `00:00:00` to `23:59:59`. Equivalent to [`sub_millis`](TimeUtilities::sub_millis).
+    pub fn wrapping_sub_millis(&self, millis: u32) -> Self {
+        self.overflowing_sub_millis(millis).0
+    }
+
+    /// Adds millis and returns the wrapped time together with the number of days carried over.
+    ///
+    /// See [`overflowing_add_hours`](Time::overflowing_add_hours) for details.
+    pub fn overflowing_add_millis(&self, millis: u32) -> (Self, i32) {
+        self.overflowing_add_nanoseconds(millis as i128 * 1_000_000)
+    }
+
+    /// Subtracts millis and returns the wrapped time together with the number of days carried over (zero or negative).
+    ///
+    /// See [`overflowing_sub_hours`](Time::overflowing_sub_hours) for details.
+    pub fn overflowing_sub_millis(&self, millis: u32) -> (Self, i32) {
+        self.overflowing_add_nanoseconds(-(millis as i128 * 1_000_000))
+    }
+
+    /// Adds micros, wrapping around from `23:59:59` to `00:00:00`. Equivalent to [`add_micros`](TimeUtilities::add_micros).
+    pub fn wrapping_add_micros(&self, micros: u32) -> Self {
+        self.overflowing_add_micros(micros).0
+    }
+
+    /// Subtracts micros, wrapping around from `00:00:00` to `23:59:59`. Equivalent to [`sub_micros`](TimeUtilities::sub_micros).
+    pub fn wrapping_sub_micros(&self, micros: u32) -> Self {
+        self.overflowing_sub_micros(micros).0
+    }
+
+    /// Adds micros and returns the wrapped time together with the number of days carried over.
+    ///
+    /// See [`overflowing_add_hours`](Time::overflowing_add_hours) for details.
+    pub fn overflowing_add_micros(&self, micros: u32) -> (Self, i32) {
+        self.overflowing_add_nanoseconds(micros as i128 * 1_000)
+    }
+
+    /// Subtracts micros and returns the wrapped time together with the number of days carried over (zero or negative).
+    ///
+    /// See [`overflowing_sub_hours`](Time::overflowing_sub_hours) for details.
+    pub fn overflowing_sub_micros(&self, micros: u32) -> (Self, i32) {
+        self.overflowing_add_nanoseconds(-(micros as i128 * 1_000))
+    }
+
+    /// Adds nanos, wrapping around from `23:59:59` to `00:00:00`. Equivalent to [`add_nanos`](TimeUtilities::add_nanos).
+    pub fn wrapping_add_nanos(&self, nanos: u32) -> Self {
+        self.overflowing_add_nanos(nanos).0
+    }
+
+    /// Subtracts nanos, wrapping around from `00:00:00` to `23:59:59`. Equivalent to [`sub_nanos`](TimeUtilities::sub_nanos).
+    pub fn wrapping_sub_nanos(&self, nanos: u32) -> Self {
+        self.overflowing_sub_nanos(nanos).0
+    }
+
+    /// Adds nanos and returns the wrapped time together with the number of days carried over.
+    ///
+    /// See [`overflowing_add_hours`](Time::overflowing_add_hours) for details.
+    pub fn overflowing_add_nanos(&self, nanos: u32) -> (Self, i32) {
+        self.overflowing_add_nanoseconds(nanos as i128)
+    }
+
+    /// Subtracts nanos and returns the wrapped time together with the number of days carried over (zero or negative).
+    ///
+    /// See [`overflowing_sub_hours`](Time::overflowing_sub_hours) for details.
+    pub fn overflowing_sub_nanos(&self, nanos: u32) -> (Self, i32) {
+        self.overflowing_add_nanoseconds(-(nanos as i128))
+    }
+
+    /// Returns the value of the given unit. Useful if the unit is only known at runtime.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Time, TimeUnit};
+    /// let time = Time::from_hms(12, 32, 1).unwrap();
+    /// assert_eq!(12, time.get_unit(TimeUnit::Hour));
+    /// assert_eq!(32, time.get_unit(TimeUnit::Minute));
+    /// ```
+    pub fn get_unit(&self, unit: TimeUnit) -> i64 {
+        match unit {
+            TimeUnit::Hour => self.hour() as i64,
+            TimeUnit::Minute => self.minute() as i64,
+            TimeUnit::Second => self.second() as i64,
+            TimeUnit::Milli => self.milli() as i64,
+            TimeUnit::Micro => self.micro() as i64,
+            TimeUnit::Nano => self.nano() as i64,
+        }
+    }
+
+    /// Sets the given unit to the provided value, like [`set_hour`](TimeUtilities::set_hour) and the other `set_` functions.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the provided value is out of range.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Time, TimeUnit};
+    /// let time = Time::from_hms(12, 32, 1).unwrap();
+    /// assert_eq!("12:45:01", time.set_unit(TimeUnit::Minute, 45).unwrap().to_string());
+    /// assert!(time.set_unit(TimeUnit::Minute, 60).is_err());
+    /// ```
+    pub fn set_unit(&self, unit: TimeUnit, value: i64) -> Result<Self, AstrolabeError> {
+        let value = Unit::from(unit).value(value)?;
+        match unit {
+            TimeUnit::Hour => self.set_hour(value),
+            TimeUnit::Minute => self.set_minute(value),
+            TimeUnit::Second => self.set_second(value),
+            TimeUnit::Milli => self.set_milli(value),
+            TimeUnit::Micro => self.set_micro(value),
+            TimeUnit::Nano => self.set_nano(value),
+        }
+    }
+
+    /// Adds the provided amount of the given unit, or subtracts it if the amount is negative. Same as the `add_` and `sub_` functions, e.g. [`add_hours`](TimeUtilities::add_hours), which wrap around midnight.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the absolute amount is larger than `u32::MAX`.
+    ///
+    /// ```rust
+    /// # use astrolabe::{Time, TimeUnit};
+    /// let time = Time::from_hms(12, 32, 1).unwrap();
+    /// assert_eq!("23:32:01", time.apply_unit(TimeUnit::Hour, -13).unwrap().to_string());
+    /// ```
+    pub fn apply_unit(&self, unit: TimeUnit, amount: i64) -> Result<Self, AstrolabeError> {
+        let (negative, amount) = Unit::from(unit).amount(amount)?;
+        Ok(match (unit, negative) {
+            (TimeUnit::Hour, false) => self.add_hours(amount),
+            (TimeUnit::Hour, true) => self.sub_hours(amount),
+            (TimeUnit::Minute, false) => self.add_minutes(amount),
+            (TimeUnit::Minute, true) => self.sub_minutes(amount),
+            (TimeUnit::Second, false) => self.add_seconds(amount),
+            (TimeUnit::Second, true) => self.sub_seconds(amount),
+            (TimeUnit::Milli, false) => self.add_millis(amount),
+            (TimeUnit::Milli, true) => self.sub_millis(amount),
+            (TimeUnit::Micro, false) => self.add_micros(amount),
+            (TimeUnit::Micro, true) => self.sub_micros(amount),
+            (TimeUnit::Nano, false) => self.add_nanos(amount),
+            (TimeUnit::Nano, true) => self.sub_nanos(amount),
+        })
+    }
+
+    /// Aligns the local nanoseconds to a multiple of `duration` using the given rounding function
+    fn align_to(
+        &self,
+        duration: Duration,
+        align: fn(u64, u64) -> u64,
+    ) -> Result<Self, AstrolabeError> {
+        let step = day_divisor_nanos(duration)?;
+        let offset_seconds = self.offset.resolve();
+
+        let nanoseconds = add_offset_to_nanos(self.nanoseconds, offset_seconds);
+        let nanoseconds = align(nanoseconds, step) % NANOS_PER_DAY;
+
+        Ok(Self {
+            nanoseconds: remove_offset_from_nanos(nanoseconds, offset_seconds),
+            offset: self.offset,
+        })
+    }
+
+    /// Adds (or subtracts) nanoseconds and returns the wrapped time together with the days carried over in the offset of the instance
+    fn overflowing_add_nanoseconds(&self, nanos: i128) -> (Self, i32) {
+        let local_nanos =
+            add_offset_to_nanos(self.nanoseconds, self.offset.resolve()) as i128 + nanos;
+        let days = local_nanos.div_euclid(NANOS_PER_DAY as i128) as i32;
+        let nanoseconds = (self.nanoseconds as i128 + nanos).rem_euclid(NANOS_PER_DAY as i128);
+
+        (
+            Self {
+                nanoseconds: nanoseconds as u64,
+                offset: self.offset,
+            },
+            days,
+        )
+    }
+
+    /// Adds (or subtracts) nanoseconds and clamps the result at `00:00:00` and `23:59:59.999999999` in the offset of the instance
+    fn saturating_shift(&self, nanos: i128) -> Self {
+        let offset_seconds = self.offset.resolve();
+        let local_nanos = (add_offset_to_nanos(self.nanoseconds, offset_seconds) as i128 + nanos)
+            .clamp(0, NANOS_PER_DAY as i128 - 1);
+
+        Self {
+            nanoseconds: remove_offset_from_nanos(local_nanos as u64, offset_seconds),
+            offset: self.offset,
+        }
     }
 }
 
@@ -606,6 +1405,54 @@ impl TimeUtilities for Time {
         }
     }
 
+    fn saturating_add_hours(&self, hours: u32) -> Self {
+        self.saturating_shift(hours as i128 * NANOS_PER_HOUR as i128)
+    }
+
+    fn saturating_add_minutes(&self, minutes: u32) -> Self {
+        self.saturating_shift(minutes as i128 * NANOS_PER_MINUTE as i128)
+    }
+
+    fn saturating_add_seconds(&self, seconds: u32) -> Self {
+        self.saturating_shift(seconds as i128 * NANOS_PER_SEC as i128)
+    }
+
+    fn saturating_add_millis(&self, millis: u32) -> Self {
+        self.saturating_shift(millis as i128 * 1_000_000)
+    }
+
+    fn saturating_add_micros(&self, micros: u32) -> Self {
+        self.saturating_shift(micros as i128 * 1_000)
+    }
+
+    fn saturating_add_nanos(&self, nanos: u32) -> Self {
+        self.saturating_shift(nanos as i128)
+    }
+
+    fn saturating_sub_hours(&self, hours: u32) -> Self {
+        self.saturating_shift(-(hours as i128) * NANOS_PER_HOUR as i128)
+    }
+
+    fn saturating_sub_minutes(&self, minutes: u32) -> Self {
+        self.saturating_shift(-(minutes as i128) * NANOS_PER_MINUTE as i128)
+    }
+
+    fn saturating_sub_seconds(&self, seconds: u32) -> Self {
+        self.saturating_shift(-(seconds as i128) * NANOS_PER_SEC as i128)
+    }
+
+    fn saturating_sub_millis(&self, millis: u32) -> Self {
+        self.saturating_shift(-(millis as i128) * 1_000_000)
+    }
+
+    fn saturating_sub_micros(&self, micros: u32) -> Self {
+        self.saturating_shift(-(micros as i128) * 1_000)
+    }
+
+    fn saturating_sub_nanos(&self, nanos: u32) -> Self {
+        self.saturating_shift(-(nanos as i128))
+    }
+
     fn clear_until_hour(&self) -> Self {
         let nanoseconds = remove_offset_from_nanos(0, self.offset.resolve());
         Self {
@@ -777,8 +1624,10 @@ impl OffsetUtilities for Time {
     }
 
     fn as_offset(&self, offset: Offset) -> Self {
-        let new_seconds = remove_offset_from_nanos(self.nanoseconds, offset.resolve());
-        Self::from_nanos(new_seconds).unwrap().set_offset(offset)
+        Self {
+            nanoseconds: remove_offset_from_nanos(self.nanoseconds, offset.resolve()),
+            offset,
+        }
     }
 
     fn get_offset(&self) -> Offset {
@@ -801,26 +1650,100 @@ impl From<&Time> for Time {
     }
 }
 
+/// Creates a [`Time`] in UTC from a [`Duration`] since midnight. Durations of a day or longer return an [`OutOfRange`](AstrolabeError::OutOfRange) error. See [`Time::from_duration_wrapping`] for a wrapping conversion.
+///
+/// ```rust
+/// # use astrolabe::Time;
+/// # use std::time::Duration;
+/// let time = Time::try_from(Duration::from_secs(45_296)).unwrap();
+/// assert_eq!("12:34:56", time.to_string());
+/// assert!(Time::try_from(Duration::from_secs(86_400)).is_err());
+/// ```
+impl TryFrom<Duration> for Time {
+    type Error = AstrolabeError;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        let nanos = value.as_nanos();
+        if nanos >= NANOS_PER_DAY as u128 {
+            return Err(create_simple_oor(
+                "nanoseconds",
+                0,
+                NANOS_PER_DAY as i128 - 1,
+                nanos as i128,
+            ));
+        }
+        Ok(Self {
+            nanoseconds: nanos as u64,
+            offset: Offset::default(),
+        })
+    }
+}
+
+/// Returns the duration since midnight in the offset of the time.
+///
+/// ```rust
+/// # use astrolabe::{Offset, OffsetUtilities, Time};
+/// # use std::time::Duration;
+/// let time = Time::from_hms(12, 34, 56).unwrap();
+/// assert_eq!(Duration::from_secs(45_296), Duration::from(time));
+/// let time = time.set_offset(Offset::from_hms(2, 0, 0).unwrap());
+/// assert_eq!(Duration::from_secs(52_496), Duration::from(time));
+/// ```
+impl From<Time> for Duration {
+    fn from(value: Time) -> Self {
+        Duration::from_nanos(add_offset_to_nanos(
+            value.nanoseconds,
+            value.offset.resolve(),
+        ))
+    }
+}
+
 impl From<DateTime> for Time {
     fn from(value: DateTime) -> Self {
-        Self {
-            nanoseconds: (value.as_nanos() % NANOS_PER_DAY as i128) as u64,
-            offset: value.get_offset(),
-        }
+        value.time()
     }
 }
 impl From<&DateTime> for Time {
     fn from(value: &DateTime) -> Self {
-        Self {
-            nanoseconds: (value.as_nanos() % NANOS_PER_DAY as i128) as u64,
-            offset: value.get_offset(),
-        }
+        value.time()
     }
 }
 
+/// Formats the time as `HH:mm:ss`.
+///
+/// The precision flag appends up to 9 fractional digits of the second, the alternate flag appends the offset like in RFC 3339 (`Z` or `+02:00`).
+///
+/// ```rust
+/// # use astrolabe::{Offset, OffsetUtilities, Time, TimeUtilities};
+/// let time = Time::from_hms(15, 30, 20).unwrap().add_nanos(123_456_789);
+/// assert_eq!("15:30:20", format!("{}", time));
+/// assert_eq!("15:30:20.123456789", format!("{:.9}", time));
+/// assert_eq!("15:30:20Z", format!("{:#}", time));
+/// let time = time.as_offset(Offset::from_hms(-2, 0, 0).unwrap());
+/// assert_eq!("15:30:20.123-02:00", format!("{:#.3}", time));
+/// ```
 impl Display for Time {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.format("HH:mm:ss"))
+        // Resolving the offset once for all parts
+        let time = Self {
+            nanoseconds: self.nanoseconds,
+            offset: Offset::Fixed(self.offset.resolve()),
+        };
+        let digits = f.precision().map_or(0, |precision| precision.min(9) as u32);
+
+        write!(f, "{}", time.format("HH:mm:ss"))?;
+        if digits > 0 {
+            write!(
+                f,
+                ".{:0width$}",
+                time.nano() / 10_u32.pow(9 - digits),
+                width = digits as usize
+            )?;
+        }
+        if f.alternate() {
+            write!(f, "{}", time.format("XXX"))?;
+        }
+        Ok(())
     }
 }
 
@@ -841,11 +1764,62 @@ impl Ord for Time {
     }
 }
 
+impl Hash for Time {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_nanos().hash(state);
+    }
+}
+
+/// Parses a `HH:mm:ss` formatted string, optionally followed by a fraction of a second and an offset (E.g. `15:30:20`, `15:30:20.123` or `15:30:20.5+02:00`). The offset accepts the same notations as [`Offset`]'s `FromStr` implementation.
+///
+/// ```rust
+/// # use astrolabe::{Offset, OffsetUtilities, Time};
+/// let time: Time = "15:30:20.5+02:00".parse().unwrap();
+/// assert_eq!("15:30:20.500", time.format("HH:mm:ss.nnn"));
+/// assert_eq!(Offset::Fixed(7200), time.get_offset());
+/// ```
 impl FromStr for Time {
     type Err = AstrolabeError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::parse(s, "HH:mm:ss")
+        let bytes = s.as_bytes();
+        if bytes.len() < 8 || bytes[2] != b':' || bytes[5] != b':' || !s.is_char_boundary(8) {
+            return Err(create_invalid_format(format!(
+                "Time has to be in the format 'HH:mm:ss'. Value is '{}'.",
+                s
+            )));
+        }
+
+        let hour = parse_digits(&s[0..2], 2, "hour")?;
+        let minute = parse_digits(&s[3..5], 2, "minute")?;
+        let second = parse_digits(&s[6..8], 2, "second")?;
+
+        let mut rest = &s[8..];
+        let nanos = match rest.strip_prefix('.') {
+            Some(fraction) => {
+                let length = fraction
+                    .chars()
+                    .take_while(|char| char.is_ascii_digit())
+                    .count();
+                rest = &fraction[length..];
+                parse_subseconds(&fraction[..length])?
+            }
+            None => 0,
+        };
+
+        let seconds = time_to_day_seconds(hour, minute, second)? as u64;
+        let time = Self::from_nanos(seconds * NANOS_PER_SEC + nanos)?;
+
+        if rest.is_empty() {
+            return Ok(time);
+        }
+        let offset = rest.parse::<Offset>().map_err(|error| {
+            create_invalid_format(format!(
+                "Failed parsing offset from given string: {}",
+                error
+            ))
+        })?;
+        Ok(time.as_offset(offset))
     }
 }
 
@@ -865,28 +1839,28 @@ impl AddAssign for Time {
     }
 }
 
+/// Returns the signed difference in nanoseconds. The offsets of both instances are not relevant.
+///
+/// ```rust
+/// # use astrolabe::Time;
+/// let start = Time::from_hms(22, 15, 0).unwrap();
+/// let end = Time::from_hms(3, 40, 0).unwrap();
+/// assert_eq!(-66_900_000_000_000, end - start);
+/// ```
 impl Sub for Time {
-    type Output = Time;
+    type Output = i64;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        Time {
-            nanoseconds: self.nanoseconds - rhs.nanoseconds,
-            offset: self.offset,
-        }
-    }
-}
-impl SubAssign for Time {
-    fn sub_assign(&mut self, rhs: Self) {
-        *self = *self - rhs;
+        self.nanoseconds as i64 - rhs.nanoseconds as i64
     }
 }
 
+/// Wraps around from `23:59:59` to `00:00:00`, see [`Time::overflowing_add_nanos`] to get the days carried over.
 impl Add<Duration> for Time {
     type Output = Self;
 
     fn add(self, rhs: Duration) -> Self::Output {
-        let nanos = self.as_nanos() + rhs.as_nanos() as u64;
-        Self::from_nanos(nanos).unwrap()
+        self.overflowing_add_nanoseconds(rhs.as_nanos() as i128).0
     }
 }
 impl AddAssign<Duration> for Time {
@@ -895,12 +1869,13 @@ impl AddAssign<Duration> for Time {
     }
 }
 
+/// Wraps around from `00:00:00` to `23:59:59`, see [`Time::overflowing_sub_nanos`] to get the days carried over.
 impl Sub<Duration> for Time {
     type Output = Self;
 
     fn sub(self, rhs: Duration) -> Self::Output {
-        let nanos = self.as_nanos() - rhs.as_nanos() as u64;
-        Self::from_nanos(nanos).unwrap()
+        self.overflowing_add_nanoseconds(-(rhs.as_nanos() as i128))
+            .0
     }
 }
 impl SubAssign<Duration> for Time {
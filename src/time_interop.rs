@@ -0,0 +1,150 @@
+use crate::{
+    errors::{out_of_range::create_custom_oor, AstrolabeError},
+    util::{
+        constants::{JULIAN_DAY_OFFSET, NANOS_PER_SEC},
+        offset::{add_offset_to_dn, add_offset_to_nanos, remove_offset_from_dn},
+    },
+    Date, DateTime, Offset, Time,
+};
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+/// Converts days since `0001-01-01` to a [`time::Date`], which only supports the years -9999 to 9999 by default
+fn to_time_date(days: i32) -> Result<time::Date, AstrolabeError> {
+    i32::try_from(days as i64 + JULIAN_DAY_OFFSET)
+        .ok()
+        .and_then(|julian_day| time::Date::from_julian_day(julian_day).ok())
+        .ok_or_else(|| {
+            create_custom_oor(format!(
+                "Date {} is outside of the range supported by time ({} to {}).",
+                Date::from_days(days),
+                Date::from(time::Date::MIN),
+                Date::from(time::Date::MAX)
+            ))
+        })
+}
+
+fn from_time_date(date: time::Date) -> i32 {
+    (date.to_julian_day() as i64 - JULIAN_DAY_OFFSET) as i32
+}
+
+fn to_time_time(nanoseconds: u64) -> time::Time {
+    let seconds = nanoseconds / NANOS_PER_SEC;
+    time::Time::from_hms_nano(
+        (seconds / 3600) as u8,
+        (seconds % 3600 / 60) as u8,
+        (seconds % 60) as u8,
+        (nanoseconds % NANOS_PER_SEC) as u32,
+    )
+    .expect("Nanoseconds are always less than a day")
+}
+
+fn from_time_time(time: time::Time) -> u64 {
+    let (hour, minute, second, nanosecond) = time.as_hms_nano();
+    (hour as u64 * 3600 + minute as u64 * 60 + second as u64) * NANOS_PER_SEC + nanosecond as u64
+}
+
+fn to_primitive(days: i32, nanoseconds: u64) -> Result<PrimitiveDateTime, AstrolabeError> {
+    Ok(PrimitiveDateTime::new(
+        to_time_date(days)?,
+        to_time_time(nanoseconds),
+    ))
+}
+
+/// Dates outside of the range of `time` (The years -9999 to 9999 by default) return an [`OutOfRange`](AstrolabeError::OutOfRange) error.
+impl TryFrom<Date> for time::Date {
+    type Error = AstrolabeError;
+
+    fn try_from(value: Date) -> Result<Self, Self::Error> {
+        to_time_date(value.days)
+    }
+}
+
+impl From<time::Date> for Date {
+    fn from(value: time::Date) -> Self {
+        Self {
+            days: from_time_date(value),
+        }
+    }
+}
+
+/// Converts the local time of the offset.
+impl From<Time> for time::Time {
+    fn from(value: Time) -> Self {
+        to_time_time(add_offset_to_nanos(
+            value.nanoseconds,
+            value.offset.resolve(),
+        ))
+    }
+}
+
+/// The time is interpreted as UTC.
+impl From<time::Time> for Time {
+    fn from(value: time::Time) -> Self {
+        Self {
+            nanoseconds: from_time_time(value),
+            offset: Offset::default(),
+        }
+    }
+}
+
+/// Converts the local date and time of the offset. Dates outside of the range of `time` (The years -9999 to 9999 by default) return an [`OutOfRange`](AstrolabeError::OutOfRange) error.
+impl TryFrom<DateTime> for PrimitiveDateTime {
+    type Error = AstrolabeError;
+
+    fn try_from(value: DateTime) -> Result<Self, Self::Error> {
+        let (days, nanoseconds) =
+            add_offset_to_dn(value.days, value.nanoseconds, value.offset.resolve());
+        to_primitive(days, nanoseconds)
+    }
+}
+
+/// The date and time are interpreted as UTC.
+impl From<PrimitiveDateTime> for DateTime {
+    fn from(value: PrimitiveDateTime) -> Self {
+        Self {
+            days: from_time_date(value.date()),
+            nanoseconds: from_time_time(value.time()),
+            offset: Offset::default(),
+        }
+    }
+}
+
+/// Keeps the instant and maps the offset to a [`UtcOffset`]. [`Offset::Local`] is resolved to the current local offset.
+///
+/// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the date in UTC or in the offset is outside of the range of `time` (The years -9999 to 9999 by default).
+impl TryFrom<DateTime> for OffsetDateTime {
+    type Error = AstrolabeError;
+
+    fn try_from(value: DateTime) -> Result<Self, Self::Error> {
+        let offset_seconds = value.offset.resolve();
+        // Also make sure the date in UTC is in range, as time expects it to be
+        to_primitive(value.days, value.nanoseconds)?;
+        let (local_days, local_nanoseconds) =
+            add_offset_to_dn(value.days, value.nanoseconds, offset_seconds);
+        let local = to_primitive(local_days, local_nanoseconds)?;
+        let offset = UtcOffset::from_whole_seconds(offset_seconds)
+            .expect("Offsets are always less than a day");
+        Ok(local.assume_offset(offset))
+    }
+}
+
+/// Keeps the instant and maps the [`UtcOffset`] to [`Offset::Fixed`].
+///
+/// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the offset is not between `UTC-23:59:59` and `UTC+23:59:59`, as `time` supports offsets of up to `25:59:59`.
+impl TryFrom<OffsetDateTime> for DateTime {
+    type Error = AstrolabeError;
+
+    fn try_from(value: OffsetDateTime) -> Result<Self, Self::Error> {
+        let offset = Offset::from_seconds(value.offset().whole_seconds())?;
+        let (days, nanoseconds) = remove_offset_from_dn(
+            from_time_date(value.date()),
+            from_time_time(value.time()),
+            offset.resolve(),
+        );
+        Ok(Self {
+            days,
+            nanoseconds,
+            offset,
+        })
+    }
+}
@@ -15,42 +15,29 @@ pub(crate) const SECS_PER_DAY_U64: u64 = 24 * SECS_PER_HOUR_U64;
 pub(crate) const DAYS_TO_1970: u64 = 719_162;
 pub(crate) const DAYS_TO_1970_I64: i64 = 719_162;
 
+/// Julian day number of 01. January 0001
+pub(crate) const JULIAN_DAY_OFFSET: i64 = 1_721_426;
+/// Days from 01. January 0001 to 17. November 1858 (Modified Julian Date 0)
+pub(crate) const DAYS_TO_MJD_EPOCH: i64 = 678_575;
+/// Julian date of 17. November 1858 00:00 (Modified Julian Date 0)
+pub(crate) const MJD_JULIAN_DATE: f64 = 2_400_000.5;
+
 /// year, month, day of month, day of year
 pub(crate) const MAX_DATE: (i32, u32, u32, u32) = (5_879_611, 7, 12, 193);
 /// year, month, day of month, day of year
 pub(crate) const MIN_DATE: (i32, u32, u32, u32) = (-5_879_611, 6, 23, 174);
 
-pub(crate) const MONTH_ABBREVIATED: [&str; 12] = [
-    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
-];
-pub(crate) const MONTH_WIDE: [&str; 12] = [
-    "January",
-    "February",
-    "March",
-    "April",
-    "May",
-    "June",
-    "July",
-    "August",
-    "September",
-    "October",
-    "November",
-    "December",
-];
-pub(crate) const MONTH_NARROW: [&str; 12] =
-    ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"];
-
-pub(crate) const WDAY_ABBREVIATED: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
-pub(crate) const WDAY_WIDE: [&str; 7] = [
-    "Sunday",
-    "Monday",
-    "Tuesday",
-    "Wednesday",
-    "Thursday",
-    "Friday",
-    "Saturday",
+/// Maximum length of an RFC 3339 timestamp with nanosecond precision (`-5879611-06-23T00:00:00.000000000+23:59`)
+pub(crate) const RFC3339_MAX_LEN: usize = 39;
+
+/// Formats tried by [`Date::parse_auto`](crate::Date::parse_auto), in order. `yy` comes before `yyyy` as `yyyy` would also accept two digit years.
+pub(crate) const AUTO_DATE_FORMATS: [&str; 6] = [
+    "yyyy-MM-dd",
+    "dd.MM.yyyy",
+    "d MMM yy",
+    "d MMM yyyy",
+    "d MMMM yy",
+    "d MMMM yyyy",
 ];
-pub(crate) const WDAY_NARROW: [&str; 7] = ["S", "M", "T", "W", "T", "F", "S"];
-pub(crate) const WDAY_SHORT: [&str; 7] = ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"];
 
 pub(crate) const BUG_MSG: &str = "This shouldn't happen. Please report this bug on GitHub (https://github.com/GiyoMoon/astrolabe/issues). Thanks!";
@@ -0,0 +1,108 @@
+use crate::{
+    errors::{out_of_range::create_custom_oor, AstrolabeError},
+    Date, HolidayCalendar,
+};
+
+/// Returns `true` if the day is a Saturday or Sunday
+pub(crate) fn is_weekend(days: i64) -> bool {
+    // Day 0 (January 1, 0001) is a Monday
+    days.rem_euclid(7) >= 5
+}
+
+/// Moves the day in the given direction until it is neither on a weekend nor a holiday
+fn roll(mut days: i32, direction: i32, calendar: &HolidayCalendar) -> Option<i32> {
+    while is_weekend(days as i64) || calendar.contains(&Date { days }) {
+        days = days.checked_add(direction)?;
+    }
+    Some(days)
+}
+
+/// Moves a day which is not on a weekend by a signed amount of days which are not on a weekend
+fn shift_weekdays(days: i64, weekdays: i64) -> i64 {
+    let mut days = days + weekdays / 5 * 7;
+    let mut remaining = weekdays % 5;
+    let step = weekdays.signum();
+    while remaining != 0 {
+        days += step;
+        if !is_weekend(days) {
+            remaining -= step;
+        }
+    }
+    days
+}
+
+/// Adds business days. If the start is not a business day, it is first rolled forward to the next business day.
+pub(crate) fn add_business_days(
+    days: i32,
+    business_days: u32,
+    calendar: &HolidayCalendar,
+) -> Result<i32, AstrolabeError> {
+    let overflow = || {
+        create_custom_oor(format!(
+            "Instance would result into an overflow if {} business days were added.",
+            business_days,
+        ))
+    };
+
+    let mut days = roll(days, 1, calendar).ok_or_else(overflow)?;
+    let mut remaining = business_days as u64;
+    while remaining > 0 {
+        let target =
+            i32::try_from(shift_weekdays(days as i64, remaining as i64)).map_err(|_| overflow())?;
+        // Holidays which were skipped have to be added again
+        remaining = calendar.weekday_holidays_between(days + 1, target);
+        days = target;
+    }
+    Ok(days)
+}
+
+/// Subtracts business days. If the start is not a business day, it is first rolled back to the previous business day.
+pub(crate) fn sub_business_days(
+    days: i32,
+    business_days: u32,
+    calendar: &HolidayCalendar,
+) -> Result<i32, AstrolabeError> {
+    let underflow = || {
+        create_custom_oor(format!(
+            "Instance would result into an underflow if {} business days were subtracted.",
+            business_days,
+        ))
+    };
+
+    let mut days = roll(days, -1, calendar).ok_or_else(underflow)?;
+    let mut remaining = business_days as u64;
+    while remaining > 0 {
+        let target = i32::try_from(shift_weekdays(days as i64, -(remaining as i64)))
+            .map_err(|_| underflow())?;
+        // Holidays which were skipped have to be subtracted again
+        remaining = calendar.weekday_holidays_between(target, days - 1);
+        days = target;
+    }
+    Ok(days)
+}
+
+/// Returns the number of business days from `start` (inclusive) to `end` (exclusive). Negative if `end` is before `start`.
+pub(crate) fn business_days_between(start: i32, end: i32, calendar: &HolidayCalendar) -> i64 {
+    let (lower, upper) = if start <= end {
+        (start as i64, end as i64)
+    } else {
+        (end as i64, start as i64)
+    };
+
+    let full_weeks = (upper - lower) / 7;
+    let mut business_days = full_weeks * 5;
+    for days in lower + full_weeks * 7..upper {
+        if !is_weekend(days) {
+            business_days += 1;
+        }
+    }
+    if upper > lower {
+        business_days -= calendar.weekday_holidays_between(lower as i32, upper as i32 - 1) as i64;
+    }
+
+    if start <= end {
+        business_days
+    } else {
+        -business_days
+    }
+}
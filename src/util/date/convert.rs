@@ -1,87 +1,62 @@
-use super::validate::{validate_date, validate_doy};
+//! Conversions between day numbers and calendar dates.
+//!
+//! Dates are stored as days since 01. January 0001 of the proleptic Gregorian calendar, which extends the Gregorian leap year rule to all years before its introduction in 1582. A year is a leap year if it is divisible by 4, except for years divisible by 100 which are not divisible by 400.
+//!
+//! There is no year 0, the year before 0001 is -0001. Negative years are therefore shifted by one before applying the leap year rule (see [`is_leap_year`]): -0001 corresponds to the astronomical year 0 and is a leap year, as are -0005, -0101 is not, and -0401 is again.
+//!
+//! [`days_to_date`] and [`date_to_days_unchecked`] use closed-form formulas without loops. Both count years from 01. March, which makes the leap day the last day of a year, so the day of year of a month doesn't depend on the year. The day number is then split into 400 year cycles of 146097 days each.
+
+use super::validate::{create_year_zero_oor, validate_date, validate_doy};
 use crate::{
     errors::{
-        out_of_range::{create_conditional_oor, create_simple_oor},
+        out_of_range::{create_conditional_oor, create_custom_oor, create_simple_oor},
         AstrolabeError,
     },
-    util::leap::{is_leap_year, leap_years},
+    util::{
+        constants::{DAYS_TO_MJD_EPOCH, MAX_DATE, MIN_DATE},
+        leap::{is_leap_year, leap_years},
+    },
 };
 
+/// Days from 01. March of the astronomical year 0 (-0001) to 01. January 0001
+const DAYS_FROM_MARCH_0: i64 = 306;
+const DAYS_PER_400Y: i64 = 365 * 400 + 97;
+
 /// Converts days (since 01. January 0001) to a date (year, month, day of month). Days can be negative.
 ///
-/// Logic originally released by the musl project (http://www.musl-libc.org/) under the MIT license. Taken from https://git.musl-libc.org/cgit/musl/tree/src/time/__secs_to_tm.c
-pub(crate) fn days_to_date(days: i32) -> (i32, u32, u32) {
-    // 2000-03-01. Days since 0001-01-01
-    const LEAPOCH: i64 = 730_179;
-    const DAYS_PER_400Y: i64 = 365 * 400 + 97;
-    const DAYS_PER_100Y: i64 = 365 * 100 + 24;
-    const DAYS_PER_4Y: i64 = 365 * 4 + 1;
-    const MONTH_DAYS: [i64; 12] = [31, 30, 31, 30, 31, 31, 30, 31, 30, 31, 31, 29];
-
-    let days = days as i64 - LEAPOCH;
-
-    let mut qc_cycles = days / DAYS_PER_400Y;
-    let mut remdays = days % DAYS_PER_400Y;
-
-    if remdays.is_negative() {
-        remdays += DAYS_PER_400Y;
-        qc_cycles -= 1;
-    }
-
-    let mut c_cycles = remdays / DAYS_PER_100Y;
-    if c_cycles == 4 {
-        c_cycles -= 1;
-    }
-    remdays -= c_cycles * DAYS_PER_100Y;
-
-    let q_cycles = remdays / DAYS_PER_4Y;
-
-    // Doesn't seem to occur if days is of type i32
-    // if q_cycles == 25 {
-    //     q_cycles -= 1;
-    // }
-
-    remdays -= q_cycles * DAYS_PER_4Y;
-
-    let mut remyears = remdays / 365;
-    if remyears == 4 {
-        remyears -= 1;
-    }
-    let mut year = 2000 + remyears + 4 * q_cycles + 100 * c_cycles + 400 * qc_cycles;
-
-    remdays -= remyears * 365;
-
-    let mut mon = 0;
-    for mdays in MONTH_DAYS.iter() {
-        mon += 1;
-        if remdays < *mdays {
-            break;
-        }
-        remdays -= *mdays;
-    }
-    let mday = remdays + 1;
-
-    let mon = if mon + 2 > 12 {
-        year += 1;
-        mon - 10
+/// Closed-form algorithm based on `civil_from_days` by Howard Hinnant (https://howardhinnant.github.io/date_algorithms.html#civil_from_days).
+pub(crate) const fn days_to_date(days: i32) -> (i32, u32, u32) {
+    let days = days as i64 + DAYS_FROM_MARCH_0;
+
+    let era = days.div_euclid(DAYS_PER_400Y);
+    // Day of era [0, 146096]
+    let doe = days.rem_euclid(DAYS_PER_400Y);
+    // Year of era [0, 399]
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    // Day of year, starting at 01. March [0, 365]
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    // Month, starting at March [0, 11]
+    let month_index = (5 * doy + 2) / 153;
+
+    let day = doy - (153 * month_index + 2) / 5 + 1;
+    let (month, year_offset) = if month_index < 10 {
+        (month_index + 3, 0)
     } else {
-        mon + 2
+        (month_index - 9, 1)
     };
+    let year = era * 400 + yoe + year_offset;
 
     // As there is no year 0, subtract one if year is lower than 1
-    if year < 1 {
-        year -= 1;
-    }
+    let year = if year < 1 { year - 1 } else { year };
 
-    (year as i32, mon as u32, mday as u32)
+    (year as i32, month as u32, day as u32)
 }
 
 /// Converts a date (year, month and day of month) to days since 01. January 0001
 pub(crate) fn date_to_days(year: i32, month: u32, day: u32) -> Result<i32, AstrolabeError> {
     validate_date(year, month, day)?;
 
-    let leap_years = leap_years(year);
-    let (mut doy, mdays) = year_month_to_doy(year, month)?;
+    let mdays = year_month_to_doy(year, month)?.1;
 
     if day > mdays || day == 0 {
         return Err(create_conditional_oor(
@@ -92,14 +67,72 @@ pub(crate) fn date_to_days(year: i32, month: u32, day: u32) -> Result<i32, Astro
             format!("because month is {}", month),
         ));
     }
-    doy += day - 1;
 
-    Ok(if year.is_negative() {
-        doy = if is_leap_year(year) { 366 } else { 365 } - doy;
-        (year + 1) * 365 - leap_years as i32 - doy as i32
-    } else {
-        (year.abs() - 1) * 365 + leap_years as i32 + doy as i32
-    })
+    Ok(date_to_days_unchecked(year, month, day))
+}
+
+/// Converts a date (year, month and day of month) to days since 01. January 0001 without validating it. Months outside of 1-12 are treated as December.
+pub(crate) const fn date_to_days_unchecked(year: i32, month: u32, day: u32) -> i32 {
+    date_to_days_i64(year, month, day) as i32
+}
+
+/// Same as [`date_to_days_unchecked`], but returns an `i64` which doesn't overflow for dates just outside of the range of [`Date`](crate::Date)
+const fn date_to_days_i64(year: i32, month: u32, day: u32) -> i64 {
+    let month = if month >= 1 && month <= 12 { month } else { 12 } as i64;
+
+    // Astronomical year, counted from March so the leap day is the last day of a year
+    let year = if year.is_negative() { year + 1 } else { year } as i64;
+    let year = if month <= 2 { year - 1 } else { year };
+
+    let era = year.div_euclid(400);
+    // Year of era [0, 399]
+    let yoe = year.rem_euclid(400);
+    // Day of year, starting at 01. March [0, 365]
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day as i64 - 1;
+    // Day of era [0, 146096]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * DAYS_PER_400Y + doe - DAYS_FROM_MARCH_0
+}
+
+/// Converts a date to days since 01. January 0001 like [`date_to_days`], but carries months outside of 1-12 into the year and days outside of the month into the month. Month 0 is December of the previous year and day 0 is the last day of the previous month.
+///
+/// The result isn't checked against the range of [`Date`](crate::Date). Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the year is 0 or the normalized year is out of range.
+pub(crate) fn lenient_date_to_days(
+    year: i32,
+    month: i64,
+    day: i64,
+) -> Result<i128, AstrolabeError> {
+    if year == 0 {
+        return Err(create_year_zero_oor());
+    }
+    // Skip year 0
+    let continuous_year = if year < 1 { year + 1 } else { year };
+
+    let total_months = continuous_year as i128 * 12 + month as i128 - 1;
+    let target_year = match total_months.div_euclid(12) {
+        year if year < 1 => year - 1,
+        year => year,
+    };
+    if target_year < MIN_DATE.0 as i128 || target_year > MAX_DATE.0 as i128 {
+        return Err(create_custom_oor(format!(
+            "Normalized year {} is out of range.",
+            target_year
+        )));
+    }
+    let target_month = total_months.rem_euclid(12) as u32 + 1;
+
+    Ok(date_to_days_i64(target_year as i32, target_month, 1) as i128 + day as i128 - 1)
+}
+
+/// Returns the days in the given month. Months outside of 1-12 are treated as December.
+pub(crate) const fn month_days(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
 }
 
 /// Converts year and day of year to days since 01. January 0001
@@ -172,9 +205,38 @@ pub(crate) fn days_to_doy(days: i32) -> u32 {
     doy + day
 }
 
-/// Converts days to day of week
-pub(crate) fn days_to_wday(days: i32, monday_first: bool) -> u32 {
-    (days.unsigned_abs() % 7 + if monday_first { 0 } else { 1 }) % 7
+/// Converts days to day of week (`0-6`, `0` is Sunday)
+pub(crate) fn days_to_wday(days: i32) -> u32 {
+    // 0001-01-01 is a Monday
+    (days.rem_euclid(7) as u32 + 1) % 7
+}
+
+/// Converts days to the ISO 8601 day of week (`1-7`, `1` is Monday)
+pub(crate) fn days_to_iso_wday(days: i32) -> u32 {
+    wday_to_iso_wday(days_to_wday(days))
+}
+
+/// Converts a day of week with `0` as Sunday to the ISO 8601 day of week (`1-7`, `1` is Monday)
+pub(crate) fn wday_to_iso_wday(wday: u32) -> u32 {
+    (wday + 6) % 7 + 1
+}
+
+/// Returns the week of the month (`1-6`) of the given days. The first week starts on the 1st and following weeks start on `week_start` (`0` is Sunday).
+pub(crate) fn days_to_week_of_month(days: i32, week_start: u32) -> u32 {
+    let day = days_to_date(days).2;
+    let first_wday = (days_to_wday(days) + 7 - (day - 1) % 7) % 7;
+    let first_week_offset = (first_wday + 7 - week_start) % 7;
+
+    (day - 1 + first_week_offset) / 7 + 1
+}
+
+/// Returns the week of the year (`1-54`) of the given days. The first week starts on the 1st of January and following weeks start on `week_start` (`0` is Sunday).
+pub(crate) fn days_to_week_of_year(days: i32, week_start: u32) -> u32 {
+    let doy = days_to_doy(days);
+    let first_wday = (days_to_wday(days) + 7 - (doy - 1) % 7) % 7;
+    let first_week_offset = (first_wday + 7 - week_start) % 7;
+
+    (doy - 1 + first_week_offset) / 7 + 1
 }
 
 /// Get a list of specific weekdays in a month
@@ -185,7 +247,7 @@ pub(crate) fn weekdays_in_month(year: i32, month: u32, weekday: u8) -> Vec<u32>
 
     let mut weekday_index = 0;
     for index in 0..=6 {
-        if days_to_wday(start_days + index as i32, false) == weekday as u32 {
+        if days_to_wday(start_days + index as i32) == weekday as u32 {
             weekday_index = index;
             break;
         }
@@ -204,6 +266,11 @@ pub(crate) fn weekdays_in_month(year: i32, month: u32, weekday: u8) -> Vec<u32>
     weekdays
 }
 
+/// Converts days to the modified Julian day
+pub(crate) fn days_to_mjd(days: i32) -> i64 {
+    days as i64 - DAYS_TO_MJD_EPOCH
+}
+
 /// Converts days to week of year
 /// Formula taken from https://tondering.dk/claus/cal/week.php#calcweekno
 pub(crate) fn days_to_wyear(days: i32) -> u32 {
@@ -231,43 +298,14 @@ pub(crate) fn days_to_wyear(days: i32) -> u32 {
     }
 }
 
-/// Returns the years between two dates, considering day of year and subday nanoseconds
+/// Returns the years between two dates, considering month, day of month and subday nanoseconds. Comparing the day of year instead would be off by one day after the 29th of February.
 pub(crate) fn years_between(
     first_days: i32,
     first_nanos: u64,
     second_days: i32,
     second_nanos: u64,
 ) -> i32 {
-    let first_year = days_to_date(first_days).0;
-    let first_doy = days_to_doy(first_days);
-
-    let second_year = days_to_date(second_days).0;
-    let second_doy = days_to_doy(second_days);
-
-    let mut years_between = first_year - second_year;
-
-    // Fix needed as year 0 doesn't exist
-    if first_year >= 1 && second_year < 1 {
-        years_between -= 1
-    } else if first_year < 1 && second_year >= 1 {
-        years_between += 1
-    };
-
-    let extra_year = if years_between == 0 {
-        0
-    } else if first_year > second_year
-        && (first_doy < second_doy || (first_doy == second_doy && first_nanos < second_nanos))
-    {
-        -1
-    } else if first_year < second_year
-        && (first_doy > second_doy || (first_doy == second_doy && first_nanos > second_nanos))
-    {
-        1
-    } else {
-        0
-    };
-
-    years_between + extra_year
+    months_between(first_days, first_nanos, second_days, second_nanos) / 12
 }
 
 /// Returns the months between two dates, considering day of month and subday nanoseconds
@@ -293,11 +331,11 @@ pub(crate) fn months_between(
 
     let extra_month = if months_between == 0 {
         0
-    } else if first_year > second_year
+    } else if months_between > 0
         && (first_day < second_day || (first_day == second_day && first_nanos < second_nanos))
     {
         -1
-    } else if first_year < second_year
+    } else if months_between < 0
         && (first_day > second_day || (first_day == second_day && first_nanos > second_nanos))
     {
         1
@@ -307,3 +345,119 @@ pub(crate) fn months_between(
 
     months_between + extra_month
 }
+
+#[cfg(test)]
+mod convert_tests {
+    use super::{date_to_days_unchecked, days_to_date};
+    use crate::util::leap::{is_leap_year, leap_years};
+
+    /// Previous loop based implementation of [`days_to_date`], taken from musl
+    fn days_to_date_reference(days: i32) -> (i32, u32, u32) {
+        const LEAPOCH: i64 = 730_179;
+        const DAYS_PER_400Y: i64 = 365 * 400 + 97;
+        const DAYS_PER_100Y: i64 = 365 * 100 + 24;
+        const DAYS_PER_4Y: i64 = 365 * 4 + 1;
+        const MONTH_DAYS: [i64; 12] = [31, 30, 31, 30, 31, 31, 30, 31, 30, 31, 31, 29];
+
+        let days = days as i64 - LEAPOCH;
+
+        let mut qc_cycles = days / DAYS_PER_400Y;
+        let mut remdays = days % DAYS_PER_400Y;
+        if remdays.is_negative() {
+            remdays += DAYS_PER_400Y;
+            qc_cycles -= 1;
+        }
+
+        let c_cycles = (remdays / DAYS_PER_100Y).min(3);
+        remdays -= c_cycles * DAYS_PER_100Y;
+
+        let q_cycles = remdays / DAYS_PER_4Y;
+        remdays -= q_cycles * DAYS_PER_4Y;
+
+        let remyears = (remdays / 365).min(3);
+        let mut year = 2000 + remyears + 4 * q_cycles + 100 * c_cycles + 400 * qc_cycles;
+        remdays -= remyears * 365;
+
+        let mut mon = 0;
+        for mdays in MONTH_DAYS.iter() {
+            mon += 1;
+            if remdays < *mdays {
+                break;
+            }
+            remdays -= *mdays;
+        }
+        let mday = remdays + 1;
+
+        let mon = if mon + 2 > 12 {
+            year += 1;
+            mon - 10
+        } else {
+            mon + 2
+        };
+
+        if year < 1 {
+            year -= 1;
+        }
+
+        (year as i32, mon as u32, mday as u32)
+    }
+
+    /// Previous implementation of [`date_to_days_unchecked`] based on [`leap_years`]
+    fn date_to_days_reference(year: i32, month: u32, day: u32) -> i32 {
+        const DAYS_UNTIL_MONTH: [u32; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+        let leap_day = u32::from(month >= 3 && is_leap_year(year));
+        let mut doy = DAYS_UNTIL_MONTH[month as usize - 1] + leap_day + day - 1;
+
+        let leap_years = leap_years(year);
+        if year.is_negative() {
+            doy = if is_leap_year(year) { 366 } else { 365 } - doy;
+            (year + 1) * 365 - leap_years as i32 - doy as i32
+        } else {
+            (year - 1) * 365 + leap_years as i32 + doy as i32
+        }
+    }
+
+    fn assert_round_trip(days: i32) {
+        let date = days_to_date(days);
+        assert_eq!(days_to_date_reference(days), date, "days: {}", days);
+
+        let (year, month, day) = date;
+        assert_eq!(
+            days,
+            date_to_days_unchecked(year, month, day),
+            "days: {}",
+            days
+        );
+        assert_eq!(
+            date_to_days_reference(year, month, day),
+            date_to_days_unchecked(year, month, day),
+            "days: {}",
+            days
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        // Around 0001-01-01, which covers more than 800 years in both directions
+        for days in -300_000..300_000 {
+            assert_round_trip(days);
+        }
+
+        for days in (i32::MIN..=i32::MIN + 1_000).chain(i32::MAX - 1_000..=i32::MAX) {
+            assert_round_trip(days);
+        }
+    }
+
+    #[test]
+    fn months_outside_of_range() {
+        assert_eq!(
+            date_to_days_unchecked(2022, 12, 1),
+            date_to_days_unchecked(2022, 0, 1)
+        );
+        assert_eq!(
+            date_to_days_unchecked(2022, 12, 1),
+            date_to_days_unchecked(2022, 13, 1)
+        );
+    }
+}
@@ -1,7 +1,10 @@
-use super::convert::{date_to_days, days_to_date, year_doy_to_days, year_month_to_doy};
+use super::convert::{date_to_days, days_to_date, month_days, year_doy_to_days};
 use crate::{
-    errors::{out_of_range::create_custom_oor, AstrolabeError},
-    util::leap::is_leap_year,
+    errors::{
+        out_of_range::{create_conditional_oor, create_custom_oor},
+        AstrolabeError,
+    },
+    MonthOverflow, Weekday,
 };
 
 pub(crate) fn set_year(days: i32, year: i32) -> Result<i32, AstrolabeError> {
@@ -29,50 +32,44 @@ pub(crate) fn set_day_of_year(days: i32, day_of_year: u32) -> Result<i32, Astrol
 }
 
 pub(crate) fn add_years(days: i32, years: u32) -> Result<i32, AstrolabeError> {
-    let (year, month, mut day) = days_to_date(days);
-    let mut target_year: i32 = year + years as i32;
+    add_years_with(days, years, MonthOverflow::ClampToLastDay)
+}
+
+pub(crate) fn add_years_with(
+    days: i32,
+    years: u32,
+    overflow: MonthOverflow,
+) -> Result<i32, AstrolabeError> {
+    let (year, month, day) = days_to_date(days);
+    let mut target_year = year as i64 + years as i64;
     // Skip year 0
     if year < 0 && target_year >= 0 {
         target_year += 1;
     }
+    let target_year = i32::try_from(target_year).map_err(|_| {
+        create_custom_oor(format!(
+            "Instance would result into an overflow if {} years were added.",
+            years,
+        ))
+    })?;
 
-    if is_leap_year(year) && !is_leap_year(target_year) && month == 2 && day == 29 {
-        day = 28;
-    }
-
-    date_to_days(target_year, month, day)
+    resolve_month_overflow(target_year, month, day, overflow)
 }
 
 pub(crate) fn add_months(days: i32, months: u32) -> Result<i32, AstrolabeError> {
-    let (year, month, day) = days_to_date(days);
-    let mut total_months = year * 12 + month as i32 + months as i32 - 1;
-    // Skip year 0
-    if total_months <= 11 {
-        total_months += 12;
-    }
+    shift_months(days, months as i64)
+}
 
-    let target_year = total_months / 12;
-    let target_month = if (month + months) % 12 == 0 {
-        12
-    } else {
-        (month + months) % 12
-    };
-    let target_day = match day {
-        day if day < 29 => day,
-        _ => {
-            let (_, mdays) = year_month_to_doy(target_year, target_month).unwrap();
-            if day > mdays {
-                mdays
-            } else {
-                day
-            }
-        }
-    };
-    date_to_days(target_year, target_month, target_day)
+pub(crate) fn add_months_with(
+    days: i32,
+    months: u32,
+    overflow: MonthOverflow,
+) -> Result<i32, AstrolabeError> {
+    shift_months_with(days, months as i64, overflow)
 }
 
 pub(crate) fn add_days(old_days: i32, days: u32) -> Result<i32, AstrolabeError> {
-    old_days.checked_add(days as i32).ok_or_else(|| {
+    i32::try_from(old_days as i64 + days as i64).map_err(|_| {
         create_custom_oor(format!(
             "Instance would result into an overflow if {} days were added.",
             days,
@@ -81,57 +78,146 @@ pub(crate) fn add_days(old_days: i32, days: u32) -> Result<i32, AstrolabeError>
 }
 
 pub(crate) fn sub_years(days: i32, years: u32) -> Result<i32, AstrolabeError> {
-    let (year, month, mut day) = days_to_date(days);
-    let mut target_year: i32 = year - years as i32;
+    sub_years_with(days, years, MonthOverflow::ClampToLastDay)
+}
+
+pub(crate) fn sub_years_with(
+    days: i32,
+    years: u32,
+    overflow: MonthOverflow,
+) -> Result<i32, AstrolabeError> {
+    let (year, month, day) = days_to_date(days);
+    let mut target_year = year as i64 - years as i64;
     // Skip year 0
     if year > 0 && target_year <= 0 {
         target_year -= 1;
     }
+    let target_year = i32::try_from(target_year).map_err(|_| {
+        create_custom_oor(format!(
+            "Instance would result into an overflow if {} years were subtracted.",
+            years,
+        ))
+    })?;
 
-    if is_leap_year(year) && !is_leap_year(target_year) && month == 2 && day == 29 {
-        day = 28;
-    }
-
-    date_to_days(target_year, month, day)
+    resolve_month_overflow(target_year, month, day, overflow)
 }
 
 pub(crate) fn sub_months(days: i32, months: u32) -> Result<i32, AstrolabeError> {
+    shift_months(days, -(months as i64))
+}
+
+pub(crate) fn sub_months_with(
+    days: i32,
+    months: u32,
+    overflow: MonthOverflow,
+) -> Result<i32, AstrolabeError> {
+    shift_months_with(days, -(months as i64), overflow)
+}
+
+pub(crate) fn add_quarters(days: i32, quarters: u32) -> Result<i32, AstrolabeError> {
+    shift_months(days, quarters as i64 * 3)
+}
+
+pub(crate) fn sub_quarters(days: i32, quarters: u32) -> Result<i32, AstrolabeError> {
+    shift_months(days, -(quarters as i64 * 3))
+}
+
+/// Moves a date to the first day of its quarter
+pub(crate) fn first_day_of_quarter(days: i32) -> Result<i32, AstrolabeError> {
+    let (year, month, _) = days_to_date(days);
+
+    date_to_days(year, (month - 1) / 3 * 3 + 1, 1)
+}
+
+/// Moves a date to the last day of its quarter
+pub(crate) fn last_day_of_quarter(days: i32) -> Result<i32, AstrolabeError> {
+    let (year, month, _) = days_to_date(days);
+    let last_month = (month - 1) / 3 * 3 + 3;
+
+    date_to_days(year, last_month, month_days(year, last_month))
+}
+
+/// Moves a date by a signed amount of months. The day of month is clamped to the last day of the target month.
+pub(crate) fn shift_months(days: i32, months: i64) -> Result<i32, AstrolabeError> {
+    shift_months_with(days, months, MonthOverflow::ClampToLastDay)
+}
+
+/// Moves a date by a signed amount of months. A day of month which doesn't exist in the target month is resolved with the given [`MonthOverflow`].
+pub(crate) fn shift_months_with(
+    days: i32,
+    months: i64,
+    overflow: MonthOverflow,
+) -> Result<i32, AstrolabeError> {
     let (year, month, day) = days_to_date(days);
-    let mut total_months = year * 12 + month as i32 - months as i32 - 1;
     // Skip year 0
-    if total_months <= 11 {
-        if year > 0 {
-            total_months -= 24;
-        } else {
-            total_months -= 12;
-        }
-    }
+    let continuous_year = if year < 1 { year + 1 } else { year };
 
-    let target_year = total_months / 12;
-    let target_month = if (month - months) % 12 == 0 {
-        12
-    } else {
-        (month - months) % 12
+    let total_months = continuous_year as i64 * 12 + month as i64 - 1 + months;
+    let target_year = match total_months.div_euclid(12) {
+        year if year < 1 => year - 1,
+        year => year,
     };
-    let target_day = match day {
-        day if day < 29 => day,
-        _ => {
-            let (_, mdays) = year_month_to_doy(target_year, target_month).unwrap();
-            if day > mdays {
-                mdays
-            } else {
-                day
-            }
-        }
-    };
-    date_to_days(target_year, target_month, target_day)
+    let target_year = i32::try_from(target_year).map_err(|_| {
+        create_custom_oor(format!(
+            "Instance would result into an overflow if {} months were added.",
+            months,
+        ))
+    })?;
+    let target_month = total_months.rem_euclid(12) as u32 + 1;
+
+    resolve_month_overflow(target_year, target_month, day, overflow)
+}
+
+/// Converts a date to days. A day of month which doesn't exist in the month is resolved with the given [`MonthOverflow`].
+fn resolve_month_overflow(
+    year: i32,
+    month: u32,
+    day: u32,
+    overflow: MonthOverflow,
+) -> Result<i32, AstrolabeError> {
+    let month_days = month_days(year, month);
+    if day <= month_days {
+        return date_to_days(year, month, day);
+    }
+
+    match overflow {
+        MonthOverflow::ClampToLastDay => date_to_days(year, month, month_days),
+        MonthOverflow::RollToNextMonth => Ok(date_to_days(year, month, month_days)? + 1),
+        MonthOverflow::Error => Err(create_conditional_oor(
+            "day",
+            1,
+            month_days as i128,
+            day as i128,
+            format!("because year is {} and month is {}", year, month),
+        )),
+    }
 }
 
 pub(crate) fn sub_days(old_days: i32, days: u32) -> Result<i32, AstrolabeError> {
-    old_days.checked_sub(days as i32).ok_or_else(|| {
+    i32::try_from(old_days as i64 - days as i64).map_err(|_| {
         create_custom_oor(format!(
             "Instance would result into an overflow if {} days were added.",
             days,
         ))
     })
 }
+
+/// Returns the days until the next occurrence of the target weekday (`1-7`, or `0-6` if `or_same` is true)
+pub(crate) fn days_until_weekday(weekday: u8, target: Weekday, or_same: bool) -> u32 {
+    let days = (u8::from(target) + 7 - weekday) % 7;
+    if days == 0 && !or_same {
+        7
+    } else {
+        days as u32
+    }
+}
+
+/// Returns the days since the previous occurrence of the target weekday (`1-7`, or `0-6` if `or_same` is true)
+pub(crate) fn days_since_weekday(weekday: u8, target: Weekday, or_same: bool) -> u32 {
+    let days = (weekday + 7 - u8::from(target)) % 7;
+    if days == 0 && !or_same {
+        7
+    } else {
+        days as u32
+    }
+}
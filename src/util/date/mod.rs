@@ -1,3 +1,4 @@
+pub(crate) mod business;
 pub(crate) mod convert;
 pub(crate) mod manipulate;
 pub(crate) mod validate;
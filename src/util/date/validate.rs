@@ -1,10 +1,11 @@
 use crate::{
     errors::{
-        out_of_range::{create_conditional_oor, create_simple_oor},
+        out_of_range::{create_conditional_oor, create_simple_oor, Message},
         AstrolabeError, OutOfRange,
     },
     util::{
         constants::{MAX_DATE, MIN_DATE},
+        date::convert::month_days,
         leap::is_leap_year,
     },
 };
@@ -12,14 +13,7 @@ use crate::{
 /// Checks if the given date (year, month and day of month) is in the valid range for the [`Date`]/[`DateTime`] struct
 pub(crate) fn validate_date(year: i32, month: u32, day: u32) -> Result<(), AstrolabeError> {
     if year == 0 {
-        return Err(AstrolabeError::OutOfRange(OutOfRange {
-            name: "year",
-            min: MIN_DATE.0 as i128,
-            max: MAX_DATE.0 as i128,
-            value: year as i128,
-            custom: Some("Year cannot be 0. After the year -1 comes 1.".to_string()),
-            conditional: None,
-        }));
+        return Err(create_year_zero_oor());
     } else if year < MIN_DATE.0 {
         return Err(create_simple_oor(
             "year",
@@ -71,17 +65,24 @@ pub(crate) fn validate_date(year: i32, month: u32, day: u32) -> Result<(), Astro
     Ok(())
 }
 
+/// Returns `true` if the given date is valid and in the valid range for the [`Date`]/[`DateTime`] struct. Const counterpart of [`validate_date`].
+pub(crate) const fn is_valid_date(year: i32, month: u32, day: u32) -> bool {
+    if year == 0 || year < MIN_DATE.0 || year > MAX_DATE.0 || month == 0 || month > 12 {
+        return false;
+    }
+    if day == 0 || day > month_days(year, month) {
+        return false;
+    }
+    if year == MIN_DATE.0 && (month < MIN_DATE.1 || (month == MIN_DATE.1 && day < MIN_DATE.2)) {
+        return false;
+    }
+    !(year == MAX_DATE.0 && (month > MAX_DATE.1 || (month == MAX_DATE.1 && day > MAX_DATE.2)))
+}
+
 /// Checks if the given year and day of year is in the valid range for the [`Date`]/[`DateTime`] struct
 pub(crate) fn validate_doy(year: i32, doy: u32) -> Result<(), AstrolabeError> {
     if year == 0 {
-        return Err(AstrolabeError::OutOfRange(OutOfRange {
-            name: "year",
-            min: MIN_DATE.0 as i128,
-            max: MAX_DATE.0 as i128,
-            value: year as i128,
-            custom: Some("Year cannot be 0. After the year -1 comes 1.".to_string()),
-            conditional: None,
-        }));
+        return Err(create_year_zero_oor());
     } else if year < MIN_DATE.0 {
         return Err(create_simple_oor(
             "year",
@@ -140,3 +141,17 @@ pub(crate) fn validate_doy(year: i32, doy: u32) -> Result<(), AstrolabeError> {
 
     Ok(())
 }
+
+/// Returns the error for the year 0, which doesn't exist
+pub(crate) fn create_year_zero_oor() -> AstrolabeError {
+    AstrolabeError::OutOfRange(OutOfRange {
+        name: "year",
+        min: MIN_DATE.0 as i128,
+        max: MAX_DATE.0 as i128,
+        value: 0,
+        message: Some(Message::Custom(
+            "Year cannot be 0. After the year -1 comes 1.".to_string(),
+        )),
+        span: None,
+    })
+}
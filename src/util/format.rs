@@ -1,108 +1,139 @@
 use super::{
-    constants::{
-        MONTH_ABBREVIATED, MONTH_NARROW, MONTH_WIDE, NANOS_PER_SEC, SECS_PER_DAY, SECS_PER_HOUR,
-        SECS_PER_MINUTE, WDAY_ABBREVIATED, WDAY_NARROW, WDAY_SHORT, WDAY_WIDE,
+    constants::{NANOS_PER_SEC, RFC3339_MAX_LEN, SECS_PER_DAY, SECS_PER_HOUR, SECS_PER_MINUTE},
+    date::convert::{
+        days_to_date, days_to_doy, days_to_mjd, days_to_wday, days_to_week_of_month,
+        wday_to_iso_wday,
     },
-    date::convert::{days_to_date, days_to_doy, days_to_wday, days_to_wyear},
+    parse::{parse_format_string, FormatPart},
     time::convert::nanos_to_time,
 };
+use crate::{
+    errors::{invalid_format::create_positioned_invalid_format, AstrolabeError},
+    Era, Locale,
+};
 
-/// Formats string parts based on https://www.unicode.org/reports/tr35/tr35-dates.html#table-date-field-symbol-table
+/// Formats string parts based on https://www.unicode.org/reports/tr35/tr35-dates.html#table-date-field-symbol-table and pushes them to `out`
 /// **Note**: Not all field types/symbols are implemented.
-pub(crate) fn format_part(chars: &str, days: i32, nanoseconds: u64, offset: i32) -> String {
+pub(crate) fn format_part(
+    out: &mut String,
+    chars: &str,
+    days: i32,
+    nanoseconds: u64,
+    offset: i32,
+    designation: Option<&str>,
+    locale: &Locale,
+) {
     // Using unwrap because it's safe to assume that chars has a length of at least 1
     let first_char = chars.chars().next().unwrap();
     match first_char {
-        'G' | 'y' | 'q' | 'M' | 'w' | 'd' | 'D' | 'e' => format_date_part(chars, days),
-        'a' | 'b' | 'h' | 'H' | 'K' | 'k' | 'm' | 's' | 'n' | 'X' | 'x' => {
-            format_time_part(chars, nanoseconds, offset)
+        'G' | 'y' | 'u' | '+' | 'q' | 'M' | 'w' | 'W' | 'd' | 'D' | 'o' | 'e' | 'g' => {
+            format_date_part(out, chars, days, locale)
+        }
+        'a' | 'b' | 'B' | 'h' | 'H' | 'K' | 'k' | 'm' | 's' | 'n' | 'X' | 'x' | 'z' => {
+            format_time_part(out, chars, nanoseconds, offset, designation, locale)
         }
-        _ => chars.to_string(),
+        _ => out.push_str(chars),
     }
 }
 
-/// Formats string parts based on https://www.unicode.org/reports/tr35/tr35-dates.html#table-date-field-symbol-table
+/// Formats string parts based on https://www.unicode.org/reports/tr35/tr35-dates.html#table-date-field-symbol-table and pushes them to `out`
 /// This function only formats date parts while ignoring time related parts (E.g. hour, minute)
-pub(crate) fn format_date_part(chars: &str, days: i32) -> String {
+pub(crate) fn format_date_part(out: &mut String, chars: &str, days: i32, locale: &Locale) {
     // Using unwrap because it's safe to assume that chars has a length of at least 1
     let first_char = chars.chars().next().unwrap();
     match first_char {
-        'G' => match chars.len() {
-            1..=3 => {
-                if days.is_negative() {
-                    "BC".to_string()
-                } else {
-                    "AD".to_string()
-                }
-            }
-            5 => {
-                if days.is_negative() {
-                    "B".to_string()
-                } else {
-                    "A".to_string()
-                }
-            }
-            _ => {
-                if days.is_negative() {
-                    "Before Christ".to_string()
-                } else {
-                    "Anno Domini".to_string()
-                }
-            }
-        },
+        'G' => {
+            let eras = match chars.len() {
+                1..=3 => locale.eras_abbreviated,
+                5 => locale.eras_narrow,
+                _ => locale.eras_wide,
+            };
+            out.push_str(eras[Era::from_days(days).index()]);
+        }
         'y' => match chars.len() {
-            2 => {
-                let mut year = days_to_date(days).0;
-                let year_string = year.to_string();
-
-                if year_string.len() > 2 {
-                    let last_two = &year_string[year_string.len() - 2..];
-                    // Using unwrap because it's safe to assume that this string can be parsed
-                    year = last_two.parse::<i32>().unwrap();
-                }
-                zero_padded_i(year, 2)
-            }
-            _ => zero_padded_i(days_to_date(days).0, chars.len()),
+            // Negative years keep their sign, so they can be parsed again
+            2 => push_zero_padded_i(out, (days_to_date(days).0 % 100).into(), 2),
+            _ => push_zero_padded_i(out, days_to_date(days).0.into(), chars.len()),
         },
+        // Astronomical year, 1 BC is year 0
+        'u' => {
+            let year = days_to_date(days).0;
+            let year = if year.is_negative() { year + 1 } else { year };
+            push_zero_padded_i(out, year.into(), chars.len())
+        }
+        '+' if chars.starts_with("+y") => {
+            let year = days_to_date(days).0;
+            if !year.is_negative() {
+                out.push('+');
+            }
+            push_zero_padded_i(out, year.into(), chars.len() - 1);
+        }
         'q' => {
             let quarter = (days_to_date(days).1 - 1) / 3 + 1;
             match chars.len() {
-                1 | 2 => zero_padded(quarter, chars.len()),
-                3 => format!("Q{}", quarter),
-                4 => {
-                    let ordinal = add_ordinal_indicator(quarter);
-                    format!("{} quarter", ordinal)
-                }
-                _ => zero_padded(quarter, 1),
+                1 | 2 => push_zero_padded(out, quarter, chars.len()),
+                3 => out.push_str(locale.quarters_abbreviated[(quarter - 1) as usize]),
+                4 => out.push_str(locale.quarters_wide[(quarter - 1) as usize]),
+                _ => push_zero_padded(out, quarter, 1),
             }
         }
-        'M' => format_month(chars.len(), days),
-        'w' => zero_padded(days_to_wyear(days), get_length(chars.len(), 2, 2)),
-        'd' => zero_padded(days_to_date(days).2, get_length(chars.len(), 2, 2)),
-        'D' => zero_padded(days_to_doy(days), get_length(chars.len(), 1, 3)),
-        'e' => format_wday(chars.len(), days),
-        _ => chars.to_string(),
+        'M' => format_month(out, chars.len(), days, locale),
+        'w' => push_zero_padded(
+            out,
+            locale.first_weekday.week_of_year(days),
+            get_length(chars.len(), 2, 2),
+        ),
+        'W' => push_zero_padded(out, days_to_week_of_month(days, 0), 1),
+        'd' => push_zero_padded(out, days_to_date(days).2, get_length(chars.len(), 2, 2)),
+        'D' => push_zero_padded(out, days_to_doy(days), get_length(chars.len(), 1, 3)),
+        'o' => out.push_str(ordinal_suffix(days_to_date(days).2)),
+        'e' => format_wday(out, chars.len(), days, locale),
+        'g' => push_zero_padded_i(out, days_to_mjd(days), chars.len()),
+        _ => out.push_str(chars),
     }
 }
 
-/// Formats string parts based on https://www.unicode.org/reports/tr35/tr35-dates.html#table-date-field-symbol-table
+/// Formats string parts based on https://www.unicode.org/reports/tr35/tr35-dates.html#table-date-field-symbol-table and pushes them to `out`
 /// This function only formats time parts while ignoring date related parts (E.g. year, day)
-pub(crate) fn format_time_part(chars: &str, nanoseconds: u64, offset: i32) -> String {
+pub(crate) fn format_time_part(
+    out: &mut String,
+    chars: &str,
+    nanoseconds: u64,
+    offset: i32,
+    designation: Option<&str>,
+    locale: &Locale,
+) {
     // Using unwrap because it's safe to assume that chars has a length of at least 1
     let first_char = chars.chars().next().unwrap();
     match first_char {
-        'a' => format_period(nanoseconds, get_length(chars.len(), 3, 5), false),
-        'b' => format_period(nanoseconds, get_length(chars.len(), 3, 5), true),
+        'a' => out.push_str(format_period(
+            nanoseconds,
+            get_length(chars.len(), 3, 5),
+            false,
+            locale,
+        )),
+        'b' => out.push_str(format_period(
+            nanoseconds,
+            get_length(chars.len(), 3, 5),
+            true,
+            locale,
+        )),
+        'B' => out.push_str(format_flexible_period(nanoseconds, chars.len(), locale)),
         'h' => {
             let hour = if nanos_to_time(nanoseconds).0 % 12 == 0 {
                 12
             } else {
                 nanos_to_time(nanoseconds).0 % 12
             };
-            zero_padded(hour, get_length(chars.len(), 2, 2))
+            push_zero_padded(out, hour, get_length(chars.len(), 2, 2))
         }
-        'H' => zero_padded(nanos_to_time(nanoseconds).0, get_length(chars.len(), 2, 2)),
-        'K' => zero_padded(
+        'H' => push_zero_padded(
+            out,
+            nanos_to_time(nanoseconds).0,
+            get_length(chars.len(), 2, 2),
+        ),
+        'K' => push_zero_padded(
+            out,
             nanos_to_time(nanoseconds).0 % 12,
             get_length(chars.len(), 2, 2),
         ),
@@ -112,10 +143,18 @@ pub(crate) fn format_time_part(chars: &str, nanoseconds: u64, offset: i32) -> St
             } else {
                 nanos_to_time(nanoseconds).0
             };
-            zero_padded(hour, get_length(chars.len(), 2, 2))
+            push_zero_padded(out, hour, get_length(chars.len(), 2, 2))
         }
-        'm' => zero_padded(nanos_to_time(nanoseconds).1, get_length(chars.len(), 2, 2)),
-        's' => zero_padded(nanos_to_time(nanoseconds).2, get_length(chars.len(), 2, 2)),
+        'm' => push_zero_padded(
+            out,
+            nanos_to_time(nanoseconds).1,
+            get_length(chars.len(), 2, 2),
+        ),
+        's' => push_zero_padded(
+            out,
+            nanos_to_time(nanoseconds).2,
+            get_length(chars.len(), 2, 2),
+        ),
         'n' => {
             let mut length = get_length(chars.len(), 3, 5);
             if length == 4 {
@@ -126,172 +165,269 @@ pub(crate) fn format_time_part(chars: &str, nanoseconds: u64, offset: i32) -> St
 
             let subsec_nanos = (nanoseconds % NANOS_PER_SEC) as u32;
 
-            zero_padded(subsec_nanos / 10_u32.pow(9 - length as u32), length)
+            push_zero_padded(out, subsec_nanos / 10_u32.pow(9 - length as u32), length)
         }
-        'X' => format_zone(chars.len(), offset, true),
-        'x' => format_zone(chars.len(), offset, false),
-        _ => chars.to_string(),
+        'X' => format_zone(out, chars.len(), offset, true),
+        'x' => format_zone(out, chars.len(), offset, false),
+        'z' => match designation {
+            // Numeric designations like `+13` are formatted as GMT offsets instead
+            Some(designation)
+                if chars.len() < 4
+                    && !designation.starts_with(|char| char == '+' || char == '-') =>
+            {
+                out.push_str(designation)
+            }
+            _ => format_gmt_zone(out, offset),
+        },
+        _ => out.push_str(chars),
     }
 }
 
+/// Formats the year of era (`y`) for format strings which contain the era (`G`), so the sign of the year is expressed by the era instead (E.g. `BC2022` instead of `BC-2022`)
+pub(crate) fn format_year_of_era(out: &mut String, chars: &str, days: i32) {
+    let year = days_to_date(days).0.unsigned_abs();
+    match chars.len() {
+        2 => push_zero_padded(out, year % 100, 2),
+        _ => push_zero_padded(out, year, chars.len()),
+    }
+}
+
+/// Returns `true` if the format string contains the era symbol (`G`) outside of escaped parts
+pub(crate) fn has_era_symbol(format: &str) -> bool {
+    parse_format_string(format)
+        .any(|part| matches!(part, FormatPart::Symbol(symbol) if symbol.starts_with('G')))
+}
+
 /// Formats the month of a date based on https://www.unicode.org/reports/tr35/tr35-dates.html#dfst-month
-fn format_month(length: usize, days: i32) -> String {
+fn format_month(out: &mut String, length: usize, days: i32, locale: &Locale) {
     let month = days_to_date(days).1;
 
     match length {
-        1 | 2 => zero_padded(month, length),
-        3 => MONTH_ABBREVIATED
-            .into_iter()
-            .nth((month - 1) as usize)
-            .unwrap()
-            .to_string(),
-        5 => MONTH_NARROW
-            .into_iter()
-            .nth((month - 1) as usize)
-            .unwrap()
-            .to_string(),
-        _ => MONTH_WIDE
-            .into_iter()
-            .nth((month - 1) as usize)
-            .unwrap()
-            .to_string(),
+        1 | 2 => push_zero_padded(out, month, length),
+        3 => out.push_str(locale.months_abbreviated[(month - 1) as usize]),
+        5 => out.push_str(locale.months_narrow[(month - 1) as usize]),
+        _ => out.push_str(locale.months_wide[(month - 1) as usize]),
     }
 }
 
 /// Formats the week day of a date based on https://www.unicode.org/reports/tr35/tr35-dates.html#dfst-month
-fn format_wday(length: usize, days: i32) -> String {
+fn format_wday(out: &mut String, length: usize, days: i32, locale: &Locale) {
+    let wday = days_to_wday(days) as usize;
+    let number = locale.first_weekday.number(wday as u32);
+
     match length {
-        1 | 2 => zero_padded(days_to_wday(days, false) + 1, length),
-        3 => WDAY_ABBREVIATED
-            .into_iter()
-            .nth(days_to_wday(days, false) as usize)
-            .unwrap()
-            .to_string(),
-        4 => WDAY_WIDE
-            .into_iter()
-            .nth(days_to_wday(days, false) as usize)
-            .unwrap()
-            .to_string(),
-        5 => WDAY_NARROW
-            .into_iter()
-            .nth(days_to_wday(days, false) as usize)
-            .unwrap()
-            .to_string(),
-        6 => WDAY_SHORT
-            .into_iter()
-            .nth(days_to_wday(days, false) as usize)
-            .unwrap()
-            .to_string(),
-        7 => zero_padded(days_to_wday(days, true) + 1, 1),
-        8 => zero_padded(days_to_wday(days, true) + 1, 2),
-        _ => zero_padded(days_to_wday(days, false) + 1, 1),
+        1 | 2 => push_zero_padded(out, number, length),
+        3 => out.push_str(locale.weekdays_abbreviated[wday]),
+        4 => out.push_str(locale.weekdays_wide[wday]),
+        5 => out.push_str(locale.weekdays_narrow[wday]),
+        6 => out.push_str(locale.weekdays_short[wday]),
+        7 => push_zero_padded(out, wday_to_iso_wday(wday as u32), 1),
+        8 => push_zero_padded(out, wday_to_iso_wday(wday as u32), 2),
+        _ => push_zero_padded(out, number, 1),
     }
 }
 
-/// Formats the time period
-fn format_period(nanos: u64, length: usize, seperate_12: bool) -> String {
-    const FORMATS: [[&str; 4]; 5] = [
-        ["AM", "PM", "noon", "midnight"],
-        ["AM", "PM", "noon", "midnight"],
-        ["am", "pm", "noon", "midnight"],
-        ["a.m.", "p.m.", "noon", "midnight"],
-        ["a", "p", "n", "mi"],
-    ];
+/// Returns the name of the time period
+fn format_period(nanos: u64, length: usize, seperate_12: bool, locale: &Locale) -> &'static str {
+    let periods = locale.periods[length - 1];
     let time = (nanos / NANOS_PER_SEC) as u32 % SECS_PER_DAY;
 
     match time {
-        time if seperate_12 && time == 0 => {
-            FORMATS.into_iter().nth(length - 1).unwrap()[3].to_string()
-        }
-        time if seperate_12 && time == 43200 => {
-            FORMATS.into_iter().nth(length - 1).unwrap()[2].to_string()
-        }
-        time if time < 43200 => FORMATS.into_iter().nth(length - 1).unwrap()[0].to_string(),
-        _ => FORMATS.into_iter().nth(length - 1).unwrap()[1].to_string(),
+        time if seperate_12 && time == 0 => periods[3],
+        time if seperate_12 && time == 43200 => periods[2],
+        time if time < 43200 => periods[0],
+        _ => periods[1],
+    }
+}
+
+/// Returns the name of the flexible day period. Morning is from 06:00 to 11:59, afternoon from 12:00 to 17:59, evening from 18:00 to 20:59 and night from 21:00 to 05:59.
+fn format_flexible_period(nanos: u64, length: usize, locale: &Locale) -> &'static str {
+    let periods = match length {
+        4 => locale.flexible_periods[1],
+        5 => locale.flexible_periods[2],
+        _ => locale.flexible_periods[0],
+    };
+
+    match nanos_to_time(nanos).0 {
+        6..=11 => periods[0],
+        12..=17 => periods[1],
+        18..=20 => periods[2],
+        _ => periods[3],
     }
 }
 
 /// Formats the time zone
-fn format_zone(length: usize, offset: i32, with_z: bool) -> String {
+fn format_zone(out: &mut String, length: usize, offset: i32, with_z: bool) {
     if with_z && offset == 0 {
-        return "Z".to_string();
+        out.push('Z');
+        return;
     }
 
     let hour = offset.unsigned_abs() / SECS_PER_HOUR;
     let minute = offset.unsigned_abs() % SECS_PER_HOUR / SECS_PER_MINUTE;
     let second = offset.unsigned_abs() % SECS_PER_HOUR % SECS_PER_MINUTE;
-    let prefix = if offset.is_negative() { "-" } else { "+" };
+
+    out.push(if offset.is_negative() { '-' } else { '+' });
+    push_zero_padded(out, hour, 2);
 
     match length {
         1 => {
-            format!(
-                "{}{}{}",
-                prefix,
-                zero_padded(hour, 2),
-                if minute != 0 {
-                    zero_padded(minute, 2)
-                } else {
-                    "".to_string()
-                }
-            )
-        }
-        2 => {
-            format!(
-                "{}{}{}",
-                prefix,
-                zero_padded(hour, 2),
-                zero_padded(minute, 2)
-            )
+            if minute != 0 {
+                push_zero_padded(out, minute, 2);
+            }
         }
+        2 => push_zero_padded(out, minute, 2),
         4 => {
-            format!(
-                "{}{}{}{}",
-                prefix,
-                zero_padded(hour, 2),
-                zero_padded(minute, 2),
-                if second != 0 {
-                    zero_padded(second, 2)
-                } else {
-                    "".to_string()
-                }
-            )
+            push_zero_padded(out, minute, 2);
+            if second != 0 {
+                push_zero_padded(out, second, 2);
+            }
         }
         5 => {
-            format!(
-                "{}{}:{}{}",
-                prefix,
-                zero_padded(hour, 2),
-                zero_padded(minute, 2),
-                if second != 0 {
-                    format!(":{}", zero_padded(second, 2))
-                } else {
-                    "".to_string()
-                }
-            )
+            out.push(':');
+            push_zero_padded(out, minute, 2);
+            if second != 0 {
+                out.push(':');
+                push_zero_padded(out, second, 2);
+            }
         }
         _ => {
-            format!(
-                "{}{}:{}",
-                prefix,
-                zero_padded(hour, 2),
-                zero_padded(minute, 2)
-            )
+            out.push(':');
+            push_zero_padded(out, minute, 2);
         }
     }
 }
 
-/// Formats a number as a zero padded string
-pub(crate) fn zero_padded_i(number: i32, length: usize) -> String {
-    format!(
-        "{}{}",
-        if number.is_negative() { "-" } else { "" },
-        zero_padded(number.unsigned_abs(), length)
-    )
+/// Formats the time zone as a GMT offset (E.g. `GMT+02:00`), or `GMT` if the offset is zero
+fn format_gmt_zone(out: &mut String, offset: i32) {
+    out.push_str("GMT");
+    if offset != 0 {
+        format_zone(out, 5, offset, false);
+    }
+}
+
+/// Pushes a signed number as zero padded digits, prefixed with `-` if it is negative
+pub(crate) fn push_zero_padded_i(out: &mut String, number: i64, length: usize) {
+    if number.is_negative() {
+        out.push('-');
+    }
+    push_digits(out, number.unsigned_abs(), length);
+}
+
+/// Pushes a number as zero padded digits
+pub(crate) fn push_zero_padded(out: &mut String, number: u32, length: usize) {
+    push_digits(out, number.into(), length);
+}
+
+/// Pushes the digits of a number, padded with zeros to at least `length` digits, without going through `format!`
+fn push_digits(out: &mut String, number: u64, length: usize) {
+    // `u64::MAX` has 20 digits
+    let mut digits = [0_u8; 20];
+    let mut count = 0;
+    let mut number = number;
+    loop {
+        digits[count] = b'0' + (number % 10) as u8;
+        number /= 10;
+        count += 1;
+        if number == 0 {
+            break;
+        }
+    }
+
+    for _ in count..length {
+        out.push('0');
+    }
+    for digit in digits[..count].iter().rev() {
+        out.push(char::from(*digit));
+    }
+}
+
+/// Writes an RFC 3339 timestamp (`yyyy-MM-ddTHH:mm:ss.nXXX`) with the given number of decimal places into the buffer without allocating. Returns the number of written bytes.
+pub(crate) fn write_rfc3339(
+    buf: &mut [u8; RFC3339_MAX_LEN],
+    days: i32,
+    nanoseconds: u64,
+    offset: i32,
+    decimals: u32,
+) -> usize {
+    let (year, month, day) = days_to_date(days);
+    let (hour, minute, second) = nanos_to_time(nanoseconds);
+
+    let mut writer = ByteWriter { buf, length: 0 };
+    if year.is_negative() {
+        writer.push(b'-');
+    }
+    writer.push_padded(year.unsigned_abs(), 4);
+    writer.push(b'-');
+    writer.push_padded(month, 2);
+    writer.push(b'-');
+    writer.push_padded(day, 2);
+    writer.push(b'T');
+    writer.push_padded(hour, 2);
+    writer.push(b':');
+    writer.push_padded(minute, 2);
+    writer.push(b':');
+    writer.push_padded(second, 2);
+
+    if decimals > 0 {
+        let subsec_nanos = (nanoseconds % NANOS_PER_SEC) as u32;
+        writer.push(b'.');
+        writer.push_padded(subsec_nanos / 10_u32.pow(9 - decimals), decimals as usize);
+    }
+
+    if offset == 0 {
+        writer.push(b'Z');
+    } else {
+        writer.push(if offset.is_negative() { b'-' } else { b'+' });
+        writer.push_padded(offset.unsigned_abs() / SECS_PER_HOUR, 2);
+        writer.push(b':');
+        writer.push_padded(offset.unsigned_abs() % SECS_PER_HOUR / SECS_PER_MINUTE, 2);
+    }
+
+    writer.length
+}
+
+/// Writes ASCII bytes into a fixed buffer
+struct ByteWriter<'a> {
+    buf: &'a mut [u8; RFC3339_MAX_LEN],
+    length: usize,
+}
+
+impl ByteWriter<'_> {
+    fn push(&mut self, byte: u8) {
+        self.buf[self.length] = byte;
+        self.length += 1;
+    }
+
+    /// Writes a number as zero padded digits
+    fn push_padded(&mut self, number: u32, length: usize) {
+        let mut digits = 1;
+        while digits < 10 && number >= 10_u32.pow(digits) {
+            digits += 1;
+        }
+        let digits = (digits as usize).max(length);
+
+        let mut number = number;
+        for index in (0..digits).rev() {
+            self.buf[self.length + index] = b'0' + (number % 10) as u8;
+            number /= 10;
+        }
+        self.length += digits;
+    }
 }
 
-/// Formats a number as a zero padded string
-pub(crate) fn zero_padded(number: u32, length: usize) -> String {
-    format!("{:0width$}", number, width = length)
+/// English ordinal suffixes, which are used regardless of the locale
+pub(crate) const ORDINAL_SUFFIXES: [&str; 4] = ["st", "nd", "rd", "th"];
+
+/// Returns the English ordinal suffix of a number, e.g. `nd` for `2` and `th` for `12`
+pub(crate) fn ordinal_suffix(number: u32) -> &'static str {
+    match (number % 10, number % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
 }
 
 /// Determines length of formatting part based on actual, default and max length
@@ -303,12 +439,227 @@ pub(crate) fn get_length(length: usize, default: usize, max: usize) -> usize {
     }
 }
 
-/// Formats a number as an ordinal number
-pub(crate) fn add_ordinal_indicator(number: u32) -> String {
-    match number {
-        number if (number - 1) % 10 == 0 && number != 11 => format!("{}st", number),
-        number if (number - 2) % 10 == 0 && number != 12 => format!("{}nd", number),
-        number if (number - 3) % 10 == 0 && number != 13 => format!("{}rd", number),
-        _ => format!("{}th", number),
+/// Symbols which are implemented by [`format_date_part`] with the maximum length of a sequence, `usize::MAX` if the length is unlimited
+pub(crate) const DATE_SYMBOLS: &[(u8, usize)] = &[
+    (b'G', 5),
+    (b'y', usize::MAX),
+    (b'u', usize::MAX),
+    (b'q', 4),
+    (b'M', 5),
+    (b'w', 2),
+    (b'W', 1),
+    (b'd', 2),
+    (b'D', 3),
+    (b'o', 1),
+    (b'e', 8),
+    (b'g', usize::MAX),
+];
+/// Symbols which are implemented by [`format_time_part`] with the maximum length of a sequence
+pub(crate) const TIME_SYMBOLS: &[(u8, usize)] = &[
+    (b'a', 5),
+    (b'b', 5),
+    (b'B', 5),
+    (b'h', 2),
+    (b'H', 2),
+    (b'K', 2),
+    (b'k', 2),
+    (b'm', 2),
+    (b's', 2),
+    (b'n', 5),
+    (b'X', 5),
+    (b'x', 5),
+    (b'z', 4),
+];
+
+/// Checks a format string against the given symbol tables. Returns an error naming the first unescaped ASCII letter which is not one of the given symbols, the first symbol sequence which is longer than allowed, an ordinal suffix which doesn't follow the day of month, a `+` which isn't followed by the year or the first escaped part which is not closed, together with its byte position.
+pub(crate) fn check_format_symbols(
+    format: &str,
+    symbols: &[&[(u8, usize)]],
+) -> Result<(), AstrolabeError> {
+    let mut parts = parse_format_string(format);
+    // The ordinal suffix has to directly follow the day of month
+    let mut after_day = false;
+
+    while let Some(part) = parts.next() {
+        let symbol = match part {
+            FormatPart::Symbol(symbol) => symbol,
+            _ => {
+                after_day = false;
+                continue;
+            }
+        };
+        let mut start = parts.offset() - symbol.len();
+        // Signed years are prefixed with a `+`
+        let symbol = match symbol.strip_prefix("+y") {
+            Some(_) => {
+                start += 1;
+                &symbol[1..]
+            }
+            None => symbol,
+        };
+
+        let byte = symbol.as_bytes()[0];
+        if byte == b'+' {
+            return Err(create_positioned_invalid_format(
+                format!(
+                    "Sign '+' at position {} has to be followed by the year ('y').",
+                    start
+                ),
+                start,
+                &symbol[..1],
+            ));
+        }
+        if !byte.is_ascii_alphabetic() {
+            after_day = false;
+            continue;
+        }
+
+        let max_length = symbols
+            .iter()
+            .flat_map(|symbols| symbols.iter())
+            .find(|(known, _)| *known == byte)
+            .map(|(_, max_length)| *max_length)
+            .ok_or_else(|| {
+                create_positioned_invalid_format(
+                    format!(
+                        "Unsupported format symbol '{}' at position {}.",
+                        byte as char, start
+                    ),
+                    start,
+                    symbol,
+                )
+            })?;
+        if symbol.len() > max_length {
+            return Err(create_positioned_invalid_format(
+                format!(
+                    "Format symbol sequence '{}' at position {} is too long. '{}' can be repeated at most {} times.",
+                    symbol, start, byte as char, max_length
+                ),
+                start,
+                symbol,
+            ));
+        }
+        if byte == b'o' && !after_day {
+            return Err(create_positioned_invalid_format(
+                format!(
+                    "Ordinal suffix 'o' at position {} has to directly follow the day of month ('d' or 'dd').",
+                    start
+                ),
+                start,
+                symbol,
+            ));
+        }
+        after_day = byte == b'd';
+    }
+
+    match parts.escape_start() {
+        Some(start) => Err(unclosed_escape(format, start)),
+        None => Ok(()),
+    }
+}
+
+/// Returns the error for an escaped part which starts at the given byte position and is not closed
+fn unclosed_escape(format: &str, start: usize) -> AstrolabeError {
+    create_positioned_invalid_format(
+        format!("Escaped part starting at position {} is not closed.", start),
+        start,
+        &format[start..],
+    )
+}
+
+/// Returns `true` if the given format string only contains known format symbols, no symbol sequence is longer than listed in the symbol table, the ordinal suffix (`o`) directly follows the day of month, an unescaped `+` is followed by the year (`+yyyy`) and all escaped parts are closed. See [`DateTime::format`](crate::DateTime::format) for a list of available symbols.
+///
+/// Unlike the formatting functions, which output unknown letters as they are and format too long sequences (E.g. `dddd`) like the default pattern, this check treats both as invalid. Non-letter characters other than `+` are always valid. The check can be used in const contexts, see [`format_spec!`](crate::format_spec).
+///
+/// ```rust
+/// # use astrolabe::is_valid_format;
+/// assert!(is_valid_format("yyyy-MM-dd'T'HH:mm:ss"));
+/// // Unknown symbol
+/// assert!(!is_valid_format("yyyy-MM-ddTHH:mm:ss"));
+/// // Too long sequence
+/// assert!(!is_valid_format("yyyy-MM-dddd"));
+/// // Ordinal suffix without the day of month
+/// assert!(!is_valid_format("MMMM yyyyo"));
+/// // Sign without the year
+/// assert!(!is_valid_format("+MM"));
+/// // Unclosed escaped part
+/// assert!(!is_valid_format("yyyy-MM-dd 'at HH:mm"));
+/// ```
+pub const fn is_valid_format(format: &str) -> bool {
+    // Same rules as `check_format_symbols`, which can't be used in const contexts
+    let bytes = format.as_bytes();
+    let mut escaped = false;
+    let mut after_day = false;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+        if byte == b'\'' {
+            // Escaped apostrophe
+            if index + 1 < bytes.len() && bytes[index + 1] == b'\'' {
+                index += 2;
+                after_day = false;
+                continue;
+            }
+            escaped = !escaped;
+            after_day = false;
+            index += 1;
+        } else if !escaped && byte == b'+' {
+            // Signed year, the sign is only valid in front of the year
+            if index + 1 >= bytes.len() || bytes[index + 1] != b'y' {
+                return false;
+            }
+            after_day = false;
+            index += 1;
+        } else if escaped || !byte.is_ascii_alphabetic() {
+            after_day = false;
+            index += 1;
+        } else {
+            let start = index;
+            while index < bytes.len() && bytes[index] == byte {
+                index += 1;
+            }
+            if index - start > max_symbol_length(byte) || (byte == b'o' && !after_day) {
+                return false;
+            }
+            after_day = byte == b'd';
+        }
+    }
+
+    !escaped
+}
+
+/// Checks a format string without formatting a value. See [`DateTime::format`](crate::DateTime::format) for a list of available symbols.
+///
+/// The rules are the same as for [`is_valid_format`], but this function returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error naming the problematic part and its byte position.
+///
+/// ```rust
+/// # use astrolabe::validate_format;
+/// assert!(validate_format("yyyy-MM-dd'T'HH:mm:ss").is_ok());
+/// assert_eq!(
+///     "Unsupported format symbol 'f' at position 11.",
+///     validate_format("yyyy-MM-dd ffff").unwrap_err().to_string()
+/// );
+/// ```
+pub fn validate_format(format: &str) -> Result<(), AstrolabeError> {
+    check_format_symbols(format, &[DATE_SYMBOLS, TIME_SYMBOLS])
+}
+
+/// Returns the maximum sequence length of a date or time format symbol, `0` if the byte is not a format symbol
+const fn max_symbol_length(byte: u8) -> usize {
+    let mut index = 0;
+    while index < DATE_SYMBOLS.len() {
+        if DATE_SYMBOLS[index].0 == byte {
+            return DATE_SYMBOLS[index].1;
+        }
+        index += 1;
+    }
+    let mut index = 0;
+    while index < TIME_SYMBOLS.len() {
+        if TIME_SYMBOLS[index].0 == byte {
+            return TIME_SYMBOLS[index].1;
+        }
+        index += 1;
     }
+    0
 }
@@ -1,21 +1,21 @@
 /// Returns leap years between the year 0001 and the given year (exluding the year itself)
-pub(crate) fn leap_years(mut year: i32) -> u32 {
+pub(crate) const fn leap_years(mut year: i32) -> u32 {
     if year.is_positive() {
         year -= 1;
     }
     if year.is_negative() {
         year += 1;
     }
-    let year_abs = year.abs();
-    let mut leaps = year_abs / 4 - year_abs / 100 + year_abs / 400;
     if year.is_negative() {
-        leaps += 1;
+        // Year 0 (-1) is a leap year, the given year itself is excluded
+        let year_abs = year.abs() - 1;
+        return (year_abs / 4 - year_abs / 100 + year_abs / 400 + 1) as u32;
     }
-    leaps as u32
+    (year / 4 - year / 100 + year / 400) as u32
 }
 
 /// Checks if the given year is a leap year
-pub(crate) fn is_leap_year(mut year: i32) -> bool {
+pub(crate) const fn is_leap_year(mut year: i32) -> bool {
     if year.is_negative() {
         year += 1;
     }
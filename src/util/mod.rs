@@ -4,4 +4,6 @@ pub(crate) mod format;
 pub(crate) mod leap;
 pub(crate) mod offset;
 pub(crate) mod parse;
+#[cfg(test)]
+pub(crate) mod random;
 pub(crate) mod time;
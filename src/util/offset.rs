@@ -3,18 +3,16 @@ use super::{
     time::convert::{days_nanos_to_nanos, nanos_to_days_nanos},
 };
 
-/// Adds a given offset to nanoseconds
+/// Adds a given offset to nanoseconds, wrapping around midnight
 pub(crate) fn add_offset_to_nanos(nanoseconds: u64, offset: i32) -> u64 {
-    (((nanoseconds as i64 + offset as i64 * NANOS_PER_SEC as i64) + NANOS_PER_DAY as i64)
-        % NANOS_PER_DAY as i64)
-        .unsigned_abs()
+    (nanoseconds as i64 + offset as i64 * NANOS_PER_SEC as i64).rem_euclid(NANOS_PER_DAY as i64)
+        as u64
 }
 
-/// Removes a given offset from nanoseconds
+/// Removes a given offset from nanoseconds, wrapping around midnight
 pub(crate) fn remove_offset_from_nanos(nanoseconds: u64, offset: i32) -> u64 {
-    (((nanoseconds as i64 - offset as i64 * NANOS_PER_SEC as i64) + NANOS_PER_DAY as i64)
-        % NANOS_PER_DAY as i64)
-        .unsigned_abs()
+    (nanoseconds as i64 - offset as i64 * NANOS_PER_SEC as i64).rem_euclid(NANOS_PER_DAY as i64)
+        as u64
 }
 
 /// Adds a given offset to days and nanoseconds
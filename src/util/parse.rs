@@ -1,86 +1,337 @@
 use super::{
-    constants::{
-        MONTH_ABBREVIATED, MONTH_WIDE, SECS_PER_HOUR, SECS_PER_HOUR_U64, SECS_PER_MINUTE,
-        SECS_PER_MINUTE_U64, WDAY_WIDE,
-    },
-    format::get_length,
+    constants::{NANOS_PER_HOUR, NANOS_PER_MINUTE, NANOS_PER_SEC, SECS_PER_HOUR, SECS_PER_MINUTE},
+    date::convert::{date_to_days, days_to_date, days_to_wday, year_doy_to_days},
+    format::{get_length, ordinal_suffix, ORDINAL_SUFFIXES},
 };
 use crate::{
-    errors::{invalid_format::create_invalid_format, AstrolabeError},
-    Date, DateUtilities,
+    errors::{
+        invalid_format::create_invalid_format,
+        out_of_range::{create_custom_oor, create_simple_oor},
+        AstrolabeError,
+    },
+    Era, FirstWeekday, Locale, ParseOptions,
 };
+use std::{fmt::Display, ops::Range};
+
+/// Describes which notations are accepted when parsing an offset with [`parse_offset`]
+#[derive(Clone, Copy)]
+pub(crate) struct OffsetFormat {
+    /// `Z` is accepted for a zero offset
+    pub(crate) with_z: bool,
+    /// Hour, minute and second are separated by `:`
+    pub(crate) with_colon: bool,
+    /// Minutes can be omitted (E.g. `+01`)
+    pub(crate) optional_minutes: bool,
+    /// Seconds can be added (E.g. `+01:00:00`)
+    pub(crate) optional_seconds: bool,
+}
+
+impl OffsetFormat {
+    /// `Z`, `+HH:MM` or `-HH:MM`
+    pub(crate) const RFC3339: Self = Self {
+        with_z: true,
+        with_colon: true,
+        optional_minutes: false,
+        optional_seconds: false,
+    };
+}
+
+/// Parses an offset from the start of the given string to offset seconds and advances the string past the parsed characters
+pub(crate) fn parse_offset(string: &mut &str, format: OffsetFormat) -> Result<i32, AstrolabeError> {
+    let prefix = pick_str(1, string)?;
+
+    let multiplier = match prefix {
+        "Z" if format.with_z => return Ok(0),
+        "+" => 1,
+        "-" => -1,
+        _ => {
+            return Err(create_invalid_format(format!(
+            "Failed parsing offset prefix from given string. Value is '{}'. Prefix has to be {}.",
+            prefix,
+            if format.with_z {
+                "'Z', '+' or '-'"
+            } else {
+                "either '+' or '-'"
+            }
+        )))
+        }
+    };
 
-/// Parses the offset part from an RFC 3339 timestamp string to offset seconds
-pub(crate) fn parse_offset(string: &str) -> Result<i32, AstrolabeError> {
-    if string.starts_with('Z') {
-        return Ok(0);
+    let hour = pick_offset_part(string, "hour", 24)?;
+
+    let minute = if !format.optional_minutes || has_offset_part(string, format.with_colon) {
+        if format.with_colon {
+            remove_offset_separator(string)?;
+        }
+        pick_offset_part(string, "minute", 60)?
+    } else {
+        0
+    };
+
+    let second = if format.optional_seconds && has_offset_part(string, format.with_colon) {
+        if format.with_colon {
+            remove_offset_separator(string)?;
+        }
+        pick_offset_part(string, "second", 60)?
+    } else {
+        0
+    };
+
+    Ok((hour * SECS_PER_HOUR + minute * SECS_PER_MINUTE + second) as i32 * multiplier)
+}
+
+/// Checks if the string continues with another (optionally `:` separated) offset part
+fn has_offset_part(string: &str, with_colon: bool) -> bool {
+    let mut chars = string.chars();
+    if with_colon && chars.next() != Some(':') {
+        return false;
     }
-    if string.len() != 6 {
-        return Err(create_invalid_format(
-            "Failed parsing the offset from the RFC 3339 string. Format should be +XX:XX or -XX:XX"
-                .to_string(),
-        ));
+    chars.next().map_or(false, |char| char.is_ascii_digit())
+}
+
+/// Skips the `:` separator between two offset parts
+fn remove_offset_separator(string: &mut &str) -> Result<(), AstrolabeError> {
+    let separator = pick_str(1, string)?;
+    if separator != ":" {
+        return Err(create_invalid_format(format!(
+            "Failed parsing offset separator from given string. Value is '{}'. Separator has to be ':'.",
+            separator
+        )));
     }
+    Ok(())
+}
 
-    let hour = string[1..3].parse::<u32>().map_err(|_| {
-        create_invalid_format(
-            "Failed parsing the hour of the offset from the RFC 3339 string".to_string(),
-        )
-    })?;
-    let min = string[4..6].parse::<u32>().map_err(|_| {
-        create_invalid_format(
-            "Failed parsing the minute of the offset from the RFC 3339 string".to_string(),
-        )
-    })?;
+/// Picks a two digit offset part and checks that it is less than `max`
+fn pick_offset_part(string: &mut &str, part_name: &str, max: u32) -> Result<u32, AstrolabeError> {
+    let digits = pick_str(2, string)?;
+    if !digits.chars().all(|char| char.is_ascii_digit()) {
+        return Err(create_invalid_format(format!(
+            "Failed parsing offset {} from given string. Value is '{}'.",
+            part_name, digits
+        )));
+    }
 
-    if hour > 23 {
-        return Err(create_invalid_format(
-            "Failed parsing the hour of the offset from the RFC 3339 string. Hour has to be less than 24".to_string(),
-        ));
-    } else if min > 59 {
-        return Err(create_invalid_format(
-            "Failed parsing the minute of the offset from the RFC 3339 string. Minute has to be less than 60".to_string(),
-        ));
+    // Using unwrap because the string only consists of two digits
+    let value = digits.parse::<u32>().unwrap();
+    if value >= max {
+        return Err(create_invalid_format(format!(
+            "Failed parsing offset {} from given string. Value is '{}'. Offset {} has to be less than {}.",
+            part_name, digits, part_name, max
+        )));
     }
+    Ok(value)
+}
 
-    let offset = hour * SECS_PER_HOUR + min * SECS_PER_MINUTE;
+/// Parses a number with exactly `length` digits, used for fixed width parts like `MM` or `HH`
+pub(crate) fn parse_digits(
+    digits: &str,
+    length: usize,
+    part_name: &str,
+) -> Result<u32, AstrolabeError> {
+    if digits.len() != length || !digits.chars().all(|char| char.is_ascii_digit()) {
+        return Err(create_invalid_format(format!(
+            "Failed parsing {} from given string. Expected {} digits, value is '{}'.",
+            part_name, length, digits
+        )));
+    }
+    // Using unwrap because the digits are validated above and too short to overflow
+    Ok(digits.parse().unwrap())
+}
 
-    Ok(if string.starts_with('+') {
-        offset as i32
-    } else {
-        -(offset as i32)
-    })
+/// Normalizes the digits of a fraction of a second to nanoseconds. Digits after the ninth are truncated.
+pub(crate) fn parse_subseconds(digits: &str) -> Result<u64, AstrolabeError> {
+    if digits.is_empty() || !digits.chars().all(|char| char.is_ascii_digit()) {
+        return Err(create_invalid_format(format!(
+            "Failed parsing subseconds from given string. Value is '{}'.",
+            digits
+        )));
+    }
+
+    let nanos = digits
+        .bytes()
+        .take(9)
+        .fold(0, |nanos, digit| nanos * 10 + (digit - b'0') as u64);
+
+    Ok(nanos * 10_u64.pow(9 - digits.len().min(9) as u32))
 }
 
-/// Parse a format string and return parts to format
-pub(crate) fn parse_format_string(format: &str) -> Vec<String> {
-    let escaped_format = format.replace("''", "\u{0000}");
+/// Date and time parsed by [`parse_fixed_width`]
+pub(crate) struct FixedWidth<'a> {
+    /// Days since 01. January 0001. Fields missing in the format default to `0001-01-01`.
+    pub(crate) days: i32,
+    /// Nanoseconds of the day
+    pub(crate) nanoseconds: u64,
+    /// The rest of the string after the parsed format
+    pub(crate) rest: &'a str,
+}
 
-    let mut parts: Vec<String> = Vec::new();
-    let mut currently_escaped = false;
+/// Fast path for formats which only consist of fixed-width numeric symbols (`yyyy`, `MM`, `dd`, `HH`, `mm`, `ss` and `n` to `nnnnn`), ASCII separators and escaped text, like `yyyy-MM-dd HH:mm:ss`. Format and string are read in a single scan and digits are accumulated inline.
+///
+/// Returns `None` if the format contains any other symbol or if the string doesn't exactly match the format, e.g. if a separator differs, a field is not numeric or out of range, the date doesn't exist or a year is followed by another digit. The generic parser has to be used in that case, which returns the same result for strings accepted here and the appropriate error for all others.
+pub(crate) fn parse_fixed_width<'a>(string: &'a str, format: &str) -> Option<FixedWidth<'a>> {
+    let input = string.as_bytes();
+    let format = format.as_bytes();
 
-    for char in escaped_format.chars() {
-        match char {
-            '\'' => {
-                if !currently_escaped {
-                    parts.push(char.to_string());
-                } else {
-                    // Using unwrap because it's safe to assume that parts has a length of at least 1
-                    parts.last_mut().unwrap().push(char);
-                }
-                currently_escaped = !currently_escaped;
+    let (mut year, mut month, mut day) = (1, 1, 1);
+    let (mut hour, mut minute, mut second, mut nanos) = (0, 0, 0, 0);
+    let mut position = 0;
+    let mut index = 0;
+
+    while let Some(&symbol) = format.get(index) {
+        if symbol == b'\'' {
+            // Escaped text has to match exactly. Escaped apostrophes are left to the generic parser.
+            let length = format[index + 1..].iter().position(|byte| *byte == b'\'')?;
+            let end = index + 1 + length;
+            if length == 0 || format.get(end + 1) == Some(&b'\'') {
+                return None;
+            }
+            if input.get(position..position + length)? != &format[index + 1..end] {
+                return None;
             }
+            position += length;
+            index = end + 1;
+            continue;
+        }
+
+        let mut run = 1;
+        while format.get(index + run) == Some(&symbol) {
+            run += 1;
+        }
+        index += run;
+
+        let width = match (symbol, run) {
+            (b'y', 4) => 4,
+            (b'M' | b'd' | b'H' | b'm' | b's', 2) => 2,
+            (b'n', 1..=3) => run,
+            (b'n', 4) => 6,
+            (b'n', 5) => 9,
+            // Signed years (`+yyyy`) and other symbols are not supported
+            (b'+', _) => return None,
+            (symbol, _) if symbol.is_ascii_alphabetic() || !symbol.is_ascii() => return None,
+            // The generic parser skips separators without checking them
             _ => {
-                if currently_escaped || parts.last().unwrap_or(&"".to_string()).starts_with(char) {
-                    // Using unwrap because it's safe to assume that parts has a length of at least 1
-                    parts.last_mut().unwrap().push(char);
-                } else {
-                    parts.push(char.to_string());
+                if input
+                    .get(position..position + run)?
+                    .iter()
+                    .any(|byte| *byte != symbol)
+                {
+                    return None;
                 }
+                position += run;
+                continue;
             }
         };
+
+        let mut value = 0;
+        for digit in input.get(position..position + width)? {
+            if !digit.is_ascii_digit() {
+                return None;
+            }
+            value = value * 10 + (digit - b'0') as u32;
+        }
+        position += width;
+
+        match symbol {
+            // The generic parser reads all digits of a year
+            b'y' if !input.get(position).map_or(false, u8::is_ascii_digit) => year = value,
+            b'M' => month = value,
+            b'd' => day = value,
+            b'H' if value < 24 => hour = value as u64,
+            b'm' if value < 60 => minute = value as u64,
+            b's' if value < 60 => second = value as u64,
+            b'n' => nanos = value as u64 * 10_u64.pow(9 - width as u32),
+            _ => return None,
+        }
+    }
+
+    Some(FixedWidth {
+        days: date_to_days(year as i32, month, day).ok()?,
+        nanoseconds: hour * NANOS_PER_HOUR
+            + minute * NANOS_PER_MINUTE
+            + second * NANOS_PER_SEC
+            + nanos,
+        rest: &string[position..],
+    })
+}
+
+/// Part of a format string, borrowed from the format string
+pub(crate) enum FormatPart<'a> {
+    /// Run of the same unescaped character, e.g. `yyyy` or `-`, or a run of `y` with a `+` prefix (`+yyyy`)
+    Symbol(&'a str),
+    /// Escaped text between apostrophes, without the apostrophes
+    Literal(&'a str),
+    /// Escaped apostrophe (`''`), inside or outside of escaped text
+    EscapedQuote,
+}
+
+/// Iterator over the parts of a format string, see [`parse_format_string`]
+pub(crate) struct FormatParts<'a> {
+    format: &'a str,
+    length: usize,
+    /// Byte position of the apostrophe which opened the current escaped part
+    escape_start: Option<usize>,
+}
+
+impl FormatParts<'_> {
+    /// Returns the byte position in the format string where the next part starts
+    pub(crate) fn offset(&self) -> usize {
+        self.length - self.format.len()
+    }
+
+    /// Returns the byte position of the apostrophe which opened the current escaped part, if it is not closed yet
+    pub(crate) fn escape_start(&self) -> Option<usize> {
+        self.escape_start
+    }
+}
+
+impl<'a> Iterator for FormatParts<'a> {
+    type Item = FormatPart<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(rest) = self.format.strip_prefix("''") {
+                self.format = rest;
+                return Some(FormatPart::EscapedQuote);
+            }
+
+            let first = self.format.chars().next()?;
+            if first == '\'' {
+                self.escape_start = match self.escape_start {
+                    Some(_) => None,
+                    None => Some(self.offset()),
+                };
+                self.format = &self.format[1..];
+                continue;
+            }
+
+            let end = if self.escape_start.is_some() {
+                self.format.find('\'')
+            } else if first == '+' && self.format[1..].starts_with('y') {
+                // Signed year
+                self.format[1..].find(|char| char != 'y').map(|end| end + 1)
+            } else {
+                self.format.find(|char| char != first)
+            }
+            .unwrap_or(self.format.len());
+
+            let (part, rest) = self.format.split_at(end);
+            self.format = rest;
+            return Some(if self.escape_start.is_some() {
+                FormatPart::Literal(part)
+            } else {
+                FormatPart::Symbol(part)
+            });
+        }
+    }
+}
+
+/// Parse a format string and return parts to format
+pub(crate) fn parse_format_string(format: &str) -> FormatParts<'_> {
+    FormatParts {
+        format,
+        length: format.len(),
+        escape_start: None,
     }
-    parts
 }
 
 pub(crate) struct ParsedPart {
@@ -89,29 +340,214 @@ pub(crate) struct ParsedPart {
 }
 
 pub(crate) enum ParseUnit {
+    Era,
     Year,
+    Quarter,
     Month,
     DayOfMonth,
     DayOfYear,
+    Weekday,
+    WeekOfYear,
+    /// Index of the suffix in [`ORDINAL_SUFFIXES`]
+    OrdinalSuffix,
     Hour,
     Period,
     PeriodHour,
     Minute,
     Second,
-    Decis,
-    Centis,
-    Millis,
-    Micros,
     Nanos,
     Offset,
 }
 
 #[derive(Default)]
 pub(crate) struct ParsedDate {
+    pub(crate) era: Option<Era>,
     pub(crate) year: Option<i32>,
+    pub(crate) quarter: Option<u32>,
     pub(crate) month: Option<u32>,
     pub(crate) day_of_month: Option<u32>,
     pub(crate) day_of_year: Option<u32>,
+    /// `0` is Sunday
+    pub(crate) weekday: Option<u32>,
+    pub(crate) week_of_year: Option<u32>,
+    /// If the year was parsed with an explicit `+` or `-` sign
+    pub(crate) signed_year: bool,
+    pub(crate) spans: ParsedSpans,
+}
+
+/// Byte ranges of the parsed date fields in the parsed string, used for errors about the resulting date
+#[derive(Default)]
+pub(crate) struct ParsedSpans {
+    year: Option<Range<usize>>,
+    quarter: Option<Range<usize>>,
+    month: Option<Range<usize>>,
+    day_of_month: Option<Range<usize>>,
+    day_of_year: Option<Range<usize>>,
+}
+
+impl ParsedDate {
+    /// Stores the span of a parsed part. Units which don't affect the resulting date are ignored.
+    pub(crate) fn set_span(&mut self, unit: &ParseUnit, span: Range<usize>) {
+        let field = match unit {
+            ParseUnit::Year => &mut self.spans.year,
+            ParseUnit::Quarter => &mut self.spans.quarter,
+            ParseUnit::Month => &mut self.spans.month,
+            ParseUnit::DayOfMonth => &mut self.spans.day_of_month,
+            ParseUnit::DayOfYear => &mut self.spans.day_of_year,
+            _ => return,
+        };
+        *field = Some(span);
+    }
+
+    /// Checks a parsed ordinal suffix, given as its index in [`ORDINAL_SUFFIXES`], against the parsed day of month
+    pub(crate) fn check_ordinal_suffix(&self, index: i64) -> Result<(), AstrolabeError> {
+        let suffix = ORDINAL_SUFFIXES[index as usize];
+        match self.day_of_month {
+            Some(day) if ordinal_suffix(day) != suffix => Err(create_invalid_format(format!(
+                "Ordinal suffix '{}' doesn't match the day of month {}. Expected '{}'.",
+                suffix,
+                day,
+                ordinal_suffix(day)
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Stores the parsed year and whether the parsed string starts with a sign
+    pub(crate) fn set_year(&mut self, year: i32, parsed: &str) {
+        self.year = Some(year);
+        self.signed_year = parsed.starts_with(|char| char == '+' || char == '-');
+    }
+
+    /// Returns the parsed date as days since 01. January 0001. If a day of year was parsed, it takes precedence over the month and day of month.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error with the span of the parsed field which is out of range, e.g. the day of month if it doesn't exist in the parsed month.
+    pub(crate) fn days(&self) -> Result<i32, AstrolabeError> {
+        let days = match self.day_of_year {
+            Some(day_of_year) => year_doy_to_days(self.year(), day_of_year, false),
+            None => self
+                .month()
+                .and_then(|month| date_to_days(self.year(), month, self.day_of_month.unwrap_or(1))),
+        };
+
+        days.map_err(|e| {
+            let span = match &e {
+                AstrolabeError::OutOfRange(e) => match e.field_name() {
+                    "year" => self.spans.year.clone(),
+                    "quarter" => self.spans.quarter.clone(),
+                    "month" => self
+                        .spans
+                        .month
+                        .clone()
+                        .or_else(|| self.spans.quarter.clone()),
+                    "day" => self.spans.day_of_month.clone(),
+                    "day of year" => self.spans.day_of_year.clone(),
+                    _ => None,
+                },
+                _ => None,
+            };
+            e.with_span(span)
+        })
+    }
+
+    /// Returns the parsed year, falling back to `1`. Positive years are flipped to negative ones if the parsed era is BC, as years before `0001` are negative.
+    pub(crate) fn year(&self) -> i32 {
+        let year = self.year.unwrap_or(1);
+        match self.era {
+            Some(Era::BC) if year.is_positive() => -year,
+            _ => year,
+        }
+    }
+
+    /// Returns the parsed month. Falls back to the first month of the parsed quarter and then to January.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the parsed quarter is out of range.
+    pub(crate) fn month(&self) -> Result<u32, AstrolabeError> {
+        match (self.month, self.quarter) {
+            (Some(month), _) => Ok(month),
+            (None, Some(quarter)) if (1..=4).contains(&quarter) => Ok((quarter - 1) * 3 + 1),
+            (None, Some(quarter)) => Err(create_simple_oor("quarter", 1, 4, quarter as i128)),
+            (None, None) => Ok(1),
+        }
+    }
+
+    /// Checks that the parsed era, weekday, week of year, month and day of month match the date of the given days and that the year has no sign if an era was parsed. The week of year is counted with the given [`FirstWeekday`].
+    ///
+    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error naming the conflicting fields.
+    pub(crate) fn check_conflicts(
+        &self,
+        days: i32,
+        first_weekday: FirstWeekday,
+    ) -> Result<(), AstrolabeError> {
+        if self.era == Some(Era::AD) && Era::from_days(days) == Era::BC {
+            return Err(create_conflict("era", "AD", "year", "BC"));
+        }
+        if self.era.is_some() && self.signed_year {
+            return Err(create_invalid_format(
+                "Parsed year has a sign, which conflicts with the parsed era. Years are positive in both eras.".to_string(),
+            ));
+        }
+
+        let (_, month, day_of_month) = days_to_date(days);
+        if self.day_of_year.is_some() {
+            if let Some(parsed_month) = self.month.filter(|parsed| *parsed != month) {
+                return Err(create_conflict("month", parsed_month, "day of year", month));
+            }
+            if let Some(parsed_day) = self.day_of_month.filter(|parsed| *parsed != day_of_month) {
+                return Err(create_conflict(
+                    "day of month",
+                    parsed_day,
+                    "day of year",
+                    day_of_month,
+                ));
+            }
+        }
+
+        let weekday = days_to_wday(days);
+        if let Some(parsed_weekday) = self.weekday.filter(|parsed| *parsed != weekday) {
+            return Err(create_conflict(
+                "weekday",
+                WEEKDAY_NAMES[parsed_weekday as usize],
+                "date",
+                WEEKDAY_NAMES[weekday as usize],
+            ));
+        }
+
+        let week_of_year = first_weekday.week_of_year(days);
+        if let Some(parsed_week) = self.week_of_year.filter(|parsed| *parsed != week_of_year) {
+            return Err(create_conflict(
+                "week of year",
+                parsed_week,
+                "date",
+                week_of_year,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// Creates an error for a parsed field which conflicts with the value resulting from another parsed field
+fn create_conflict(
+    name: &str,
+    value: impl Display,
+    other_name: &str,
+    other_value: impl Display,
+) -> AstrolabeError {
+    create_invalid_format(format!(
+        "Parsed {} {} conflicts with parsed {} which results in {} {}.",
+        name, value, other_name, name, other_value
+    ))
 }
 
 #[derive(Default)]
@@ -121,14 +557,63 @@ pub(crate) struct ParsedTime {
     pub(crate) period: Option<Period>,
     pub(crate) minute: Option<u64>,
     pub(crate) second: Option<u64>,
-    pub(crate) decis: Option<u64>,
-    pub(crate) centis: Option<u64>,
-    pub(crate) millis: Option<u64>,
-    pub(crate) micros: Option<u64>,
     pub(crate) nanos: Option<u64>,
     pub(crate) offset: Option<i32>,
 }
 
+impl ParsedTime {
+    /// Sums up the parsed fields to nanoseconds since midnight.
+    ///
+    /// Returns an [`OutOfRange`](AstrolabeError::OutOfRange) error if the sum would overflow. Whether the sum fits into a day is checked by the caller.
+    pub(crate) fn nanoseconds(&self) -> Result<u64, AstrolabeError> {
+        let overflow =
+            || create_custom_oor("Parsed time would result into an overflow.".to_string());
+
+        let hour = match self.hour {
+            Some(hour) => hour,
+            None => self
+                .period_hour
+                .unwrap_or(0)
+                .checked_add(self.period.unwrap_or(Period::AM) as u64)
+                .ok_or_else(overflow)?,
+        };
+
+        [
+            (hour, NANOS_PER_HOUR),
+            (self.minute.unwrap_or(0), NANOS_PER_MINUTE),
+            (self.second.unwrap_or(0), NANOS_PER_SEC),
+            (self.nanos.unwrap_or(0), 1),
+        ]
+        .into_iter()
+        .try_fold(0_u64, |nanoseconds, (value, factor)| {
+            value.checked_mul(factor)?.checked_add(nanoseconds)
+        })
+        .ok_or_else(overflow)
+    }
+
+    /// Checks that an hour parsed with a 24-hour symbol (`H`, `k`) matches an hour parsed with a 12-hour symbol (`h`, `K`) and the day period.
+    ///
+    /// Returns an [`InvalidFormat`](AstrolabeError::InvalidFormat) error naming the conflicting fields.
+    pub(crate) fn check_conflicts(&self) -> Result<(), AstrolabeError> {
+        if let (Some(hour), Some(period_hour)) = (self.hour, self.period_hour) {
+            let matches = match self.period {
+                Some(period) => period_hour + period as u64 == hour,
+                None => period_hour == hour % 12,
+            };
+            if !matches {
+                return Err(create_conflict(
+                    "hour",
+                    hour,
+                    "12-hour hour and day period",
+                    period_hour + self.period.unwrap_or(Period::AM) as u64,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy)]
 pub(crate) enum Period {
     AM = 0,
     PM = 12,
@@ -138,14 +623,17 @@ pub(crate) enum Period {
 /// **Note**: Not all field types/symbols are implemented.
 pub(crate) fn parse_part(
     chars: &str,
-    string: &mut String,
+    string: &mut &str,
+    options: &ParseOptions,
 ) -> Result<Option<ParsedPart>, AstrolabeError> {
     // Using unwrap because it's safe to assume that chars has a length of at least 1
     let first_char = chars.chars().next().unwrap();
     Ok(match first_char {
-        'G' | 'y' | 'q' | 'M' | 'w' | 'd' | 'D' | 'e' => parse_date_part(chars, string)?,
-        'a' | 'b' | 'h' | 'H' | 'K' | 'k' | 'm' | 's' | 'n' | 'X' | 'x' => {
-            parse_time_part(chars, string)?
+        'G' | 'y' | 'u' | '+' | 'q' | 'M' | 'w' | 'W' | 'd' | 'D' | 'o' | 'e' => {
+            parse_date_part(chars, string, options)?
+        }
+        'a' | 'b' | 'B' | 'h' | 'H' | 'K' | 'k' | 'm' | 's' | 'n' | 'X' | 'x' | 'z' => {
+            parse_time_part(chars, string, options)?
         }
         _ => {
             remove_part(chars.len(), string)?;
@@ -158,37 +646,33 @@ pub(crate) fn parse_part(
 /// This function only parses date parts while ignoring time related parts (E.g. hour, minute)
 pub(crate) fn parse_date_part(
     chars: &str,
-    string: &mut String,
+    string: &mut &str,
+    options: &ParseOptions,
 ) -> Result<Option<ParsedPart>, AstrolabeError> {
     // Using unwrap because it's safe to assume that chars has a length of at least 1
     let first_char = chars.chars().next().unwrap();
     Ok(match first_char {
-        'G' => match chars.len() {
-            1..=3 => {
-                remove_part(2, string)?;
-                None
-            }
-            5 => {
-                remove_part(1, string)?;
-                None
-            }
-            _ => {
-                if string.starts_with("Before Christ") {
-                    // Using unwrap because it's safe to assume that the string is long enough
-                    remove_part("Before Christ".len(), string).unwrap();
-                    None
-                } else if string.starts_with("Anno Domini") {
-                    // Using unwrap because it's safe to assume that the string is long enough
-                    remove_part("Anno Domini".len(), string).unwrap();
-                    None
-                } else {
-                    return Err(create_invalid_format(format!(
-                        "Could not parse '{}' from given string.",
-                        chars
-                    )));
-                }
-            }
-        },
+        'G' if options.era_tokens.is_some() => {
+            // Using unwrap because the guard checks that era tokens are set
+            let tokens = options.era_tokens.as_ref().unwrap();
+            let (list_index, _) = pick_token(chars, string, &[&tokens.ad, &tokens.bc], true)?;
+            Some(ParsedPart {
+                value: if list_index == 0 { 1 } else { 0 },
+                unit: ParseUnit::Era,
+            })
+        }
+        'G' => {
+            let eras = match chars.len() {
+                1..=3 => options.locale.eras_abbreviated,
+                5 => options.locale.eras_narrow,
+                _ => options.locale.eras_wide,
+            };
+            let (_, token_index) = pick_token(chars, string, &[&eras], false)?;
+            Some(ParsedPart {
+                value: token_index as i64,
+                unit: ParseUnit::Era,
+            })
+        }
         'y' => match chars.len() {
             2 => {
                 if string.starts_with('-') {
@@ -199,87 +683,93 @@ pub(crate) fn parse_date_part(
                     })
                 } else {
                     let sub_century_year = pick_part::<i32>(2, string, "year")?;
-                    let current_century = Date::now().year() / 1000 * 1000;
                     Some(ParsedPart {
-                        value: (current_century + sub_century_year) as i64,
+                        value: options.two_digit_year.expand(sub_century_year) as i64,
                         unit: ParseUnit::Year,
                     })
                 }
             }
-            1 | 3 | 4 => {
+            _ => {
                 let mut year_length = usize::from(string.starts_with('-'));
-                let string_length = string.chars().count();
-                while string_length > year_length
-                    && string.chars().nth(year_length).unwrap().is_ascii_digit()
-                {
-                    year_length += 1;
-                }
-
+                year_length += count_digits(&string[year_length..]);
                 let year = pick_part::<i32>(year_length, string, "year")?;
 
-                Some(ParsedPart {
-                    value: year as i64,
-                    unit: ParseUnit::Year,
-                })
-            }
-            _ => {
-                let year = if string.starts_with('-') {
-                    pick_part::<i32>(chars.len() + 1, string, "year")?
-                } else {
-                    pick_part::<i32>(chars.len(), string, "year")?
-                };
-
                 Some(ParsedPart {
                     value: year as i64,
                     unit: ParseUnit::Year,
                 })
             }
         },
-        'q' => match chars.len() {
-            1 | 2 => {
-                remove_part(chars.len(), string)?;
-                None
-            }
-            3 => {
-                remove_part(2, string)?;
-                None
-            }
-            4 => {
-                for quarter in ["1st quarter", "2nd quarter", "3rd quarter", "4th quarter"] {
-                    if string.starts_with(quarter) {
-                        // Using unwrap because it's safe to assume that the string is long enough
-                        remove_part(quarter.len(), string).unwrap();
-                        return Ok(None);
-                    };
-                }
+        // Astronomical year, year 0 is parsed as 1 BC (-1)
+        'u' => {
+            let mut year_length = usize::from(string.starts_with('-'));
+            year_length += count_digits(&string[year_length..]);
+            let year = pick_part::<i32>(year_length, string, "year")? as i64;
+
+            Some(ParsedPart {
+                value: if year > 0 { year } else { year - 1 },
+                unit: ParseUnit::Year,
+            })
+        }
+        '+' if chars.starts_with("+y") => {
+            if !string.starts_with(|char| char == '+' || char == '-') {
                 return Err(create_invalid_format(format!(
-                    "Could not parse '{}' from given string.",
-                    chars
+                    "Failed parsing year sign from given string. Value is '{}'. Sign has to be either '+' or '-'.",
+                    string.chars().next().map(String::from).unwrap_or_default()
                 )));
             }
-            _ => {
-                remove_part(1, string)?;
-                None
-            }
-        },
-        'M' => parse_month(chars.len(), string)?,
-        'w' => match chars.len() {
-            1 => match string.chars().nth(1) {
-                Some(char) if char.is_ascii_digit() => {
-                    // Using unwrap because it's safe to assume that the string is long enough
-                    remove_part(2, string).unwrap();
-                    None
+            let year_length = 1 + count_digits(&string[1..]);
+            let year = pick_part::<i32>(year_length, string, "year")?;
+
+            Some(ParsedPart {
+                value: year as i64,
+                unit: ParseUnit::Year,
+            })
+        }
+        'q' => {
+            let quarter = match chars.len() {
+                2 => pick_part::<u32>(2, string, "quarter")?,
+                3 => {
+                    let tokens = &options.locale.quarters_abbreviated;
+                    pick_token(chars, string, &[tokens], false)?.1 as u32 + 1
                 }
-                _ => {
-                    remove_part(1, string)?;
-                    None
+                4 => {
+                    let tokens = &options.locale.quarters_wide;
+                    pick_token(chars, string, &[tokens], false)?.1 as u32 + 1
                 }
-            },
-            _ => {
-                remove_part(get_length(chars.len(), 2, 2), string)?;
-                None
-            }
-        },
+                _ => pick_part::<u32>(1, string, "quarter")?,
+            };
+
+            Some(ParsedPart {
+                value: quarter as i64,
+                unit: ParseUnit::Quarter,
+            })
+        }
+        'M' => parse_month(chars, string, &options.locale)?,
+        'w' => {
+            let length = match chars.len() {
+                1 => match string.chars().nth(1) {
+                    Some(char) if char.is_ascii_digit() => 2,
+                    _ => 1,
+                },
+                _ => get_length(chars.len(), 2, 2),
+            };
+            let max = match options.locale.first_weekday {
+                FirstWeekday::Standard => 53,
+                FirstWeekday::On(_) => 54,
+            };
+            pick_optional_ranged_part(length, string, "week of year", 1, max)?.map(|week| {
+                ParsedPart {
+                    value: week as i64,
+                    unit: ParseUnit::WeekOfYear,
+                }
+            })
+        }
+        // Week of month is always a single digit
+        'W' => {
+            remove_part(1, string)?;
+            None
+        }
         'd' => match chars.len() {
             1 => match string.chars().nth(1) {
                 Some(char) if char.is_ascii_digit() => {
@@ -367,7 +857,22 @@ pub(crate) fn parse_date_part(
                 }
             },
         },
-        'e' => parse_wday(chars.len(), string)?,
+        'e' => parse_wday(chars, string, &options.locale)?,
+        // The ordinal suffix is optional, but has to match the day of month if present
+        'o' => match ORDINAL_SUFFIXES
+            .iter()
+            .position(|suffix| string.starts_with(suffix))
+        {
+            Some(index) => {
+                // Using unwrap because the string starts with the suffix
+                remove_part(ORDINAL_SUFFIXES[index].len(), string).unwrap();
+                Some(ParsedPart {
+                    value: index as i64,
+                    unit: ParseUnit::OrdinalSuffix,
+                })
+            }
+            None => None,
+        },
         _ => {
             remove_part(chars.len(), string)?;
             None
@@ -379,134 +884,79 @@ pub(crate) fn parse_date_part(
 /// This function only parses time parts while ignoring date related parts (E.g. year, day)
 pub(crate) fn parse_time_part(
     chars: &str,
-    string: &mut String,
+    string: &mut &str,
+    options: &ParseOptions,
 ) -> Result<Option<ParsedPart>, AstrolabeError> {
     // Using unwrap because it's safe to assume that chars has a length of at least 1
     let first_char = chars.chars().next().unwrap();
     Ok(match first_char {
-        'a' => match chars.len() {
-            4 => {
-                let period = pick_part::<String>(4, string, "period")?;
-                match period.as_str() {
-                    "a.m." => Some(ParsedPart {
-                        value: 0,
-                        unit: ParseUnit::Period,
-                    }),
-                    "p.m." => Some(ParsedPart {
-                        value: 1,
-                        unit: ParseUnit::Period,
-                    }),
-                    _ => {
-                        return Err(create_invalid_format(format!(
-                            "Could not parse '{}' from given string.",
-                            chars
-                        )));
-                    }
-                }
-            }
-            5 => {
-                let period = pick_part::<String>(1, string, "period")?;
-                match period.as_str() {
-                    "a" => Some(ParsedPart {
-                        value: 0,
-                        unit: ParseUnit::Period,
-                    }),
-                    "p" => Some(ParsedPart {
-                        value: 1,
-                        unit: ParseUnit::Period,
-                    }),
-                    _ => {
-                        return Err(create_invalid_format(format!(
-                            "Could not parse '{}' from given string.",
-                            chars
-                        )));
-                    }
-                }
-            }
-            _ => {
-                let period = pick_part::<String>(2, string, "period")?;
-                match period.as_str() {
-                    "am" | "AM" => Some(ParsedPart {
-                        value: 0,
-                        unit: ParseUnit::Period,
-                    }),
-                    "pm" | "PM" => Some(ParsedPart {
-                        value: 1,
-                        unit: ParseUnit::Period,
-                    }),
-                    _ => {
-                        return Err(create_invalid_format(format!(
-                            "Could not parse '{}' from given string.",
-                            chars
-                        )));
-                    }
-                }
-            }
-        },
-        'b' => match chars.len() {
-            4 => {
-                for (n, period) in ["a.m.", "midnight", "p.m.", "noon"].iter().enumerate() {
-                    if string.starts_with(period) {
-                        // Using unwrap because it's safe to assume that the string is long enough
-                        remove_part(period.len(), string).unwrap();
-                        return Ok(Some(ParsedPart {
-                            value: if n <= 1 { 0 } else { 1 },
-                            unit: ParseUnit::Period,
-                        }));
-                    };
-                }
-                return Err(create_invalid_format(format!(
-                    "Could not parse '{}' from given string.",
-                    chars
-                )));
-            }
-            5 => {
-                for (n, period) in ["a", "mi", "p", "n"].iter().enumerate() {
-                    if string.starts_with(period) {
-                        // Using unwrap because it's safe to assume that the string is long enough
-                        remove_part(period.len(), string).unwrap();
-                        return Ok(Some(ParsedPart {
-                            value: if n <= 1 { 0 } else { 1 },
-                            unit: ParseUnit::Period,
-                        }));
-                    };
-                }
-                return Err(create_invalid_format(format!(
-                    "Could not parse '{}' from given string.",
-                    chars
-                )));
-            }
-            _ => {
-                for (n, period) in ["am", "AM", "midnight", "pm", "PM", "noon"]
-                    .iter()
-                    .enumerate()
-                {
-                    if string.starts_with(period) {
-                        // Using unwrap because it's safe to assume that the string is long enough
-                        remove_part(period.len(), string).unwrap();
-                        return Ok(Some(ParsedPart {
-                            value: if n <= 2 { 0 } else { 1 },
-                            unit: ParseUnit::Period,
-                        }));
-                    };
-                }
-                return Err(create_invalid_format(format!(
-                    "Could not parse '{}' from given string.",
-                    chars
-                )));
-            }
-        },
+        'a' | 'b' if options.period_tokens.is_some() => {
+            // Using unwrap because the guard checks that period tokens are set
+            let tokens = options.period_tokens.as_ref().unwrap();
+            Some(ParsedPart {
+                value: pick_token(chars, string, &[&tokens.am, &tokens.pm], true)?.0 as i64,
+                unit: ParseUnit::Period,
+            })
+        }
+        'a' | 'b' => {
+            let periods = &options.locale.periods;
+            let row = match chars.len() {
+                4 => periods[3],
+                5 => periods[4],
+                _ => periods[0],
+            };
+            // Wide and abbreviated day periods of the default length are also accepted in their lowercase form
+            let lower_row = match chars.len() {
+                4 | 5 => row,
+                _ => periods[2],
+            };
+            let index = if first_char == 'a' {
+                pick_token(
+                    chars,
+                    string,
+                    &[&[row[0], lower_row[0]], &[row[1], lower_row[1]]],
+                    false,
+                )?
+                .0
+            } else {
+                pick_token(
+                    chars,
+                    string,
+                    &[
+                        &[row[0], lower_row[0], row[3]],
+                        &[row[1], lower_row[1], row[2]],
+                    ],
+                    false,
+                )?
+                .0
+            };
+
+            Some(ParsedPart {
+                value: index as i64,
+                unit: ParseUnit::Period,
+            })
+        }
+        'B' => {
+            let periods = match chars.len() {
+                4 => options.locale.flexible_periods[1],
+                5 => options.locale.flexible_periods[2],
+                _ => options.locale.flexible_periods[0],
+            };
+            // Flexible day periods don't change the parsed time
+            pick_token(chars, string, &[&periods], false)?;
+            None
+        }
         'h' => match chars.len() {
             1 => match string.chars().nth(1) {
                 Some(char) if char.is_ascii_digit() => {
-                    let hour = pick_part::<u32>(2, string, "hour")?;
+                    let hour = pick_ranged_part(2, string, "hour", 1, 12)?;
                     Some(ParsedPart {
                         value: if hour == 12 { 0 } else { hour } as i64,
                         unit: ParseUnit::PeriodHour,
                     })
                 }
                 _ => {
-                    let hour = pick_part::<u32>(1, string, "hour")?;
+                    let hour = pick_ranged_part(1, string, "hour", 1, 12)?;
                     Some(ParsedPart {
                         // Hour cannot be 12
                         value: hour as i64,
@@ -515,7 +965,7 @@ pub(crate) fn parse_time_part(
                 }
             },
             _ => {
-                let hour = pick_part::<u32>(2, string, "hour")?;
+                let hour = pick_ranged_part(2, string, "hour", 1, 12)?;
                 Some(ParsedPart {
                     value: if hour == 12 { 0 } else { hour } as i64,
                     unit: ParseUnit::PeriodHour,
@@ -525,14 +975,14 @@ pub(crate) fn parse_time_part(
         'H' => match chars.len() {
             1 => match string.chars().nth(1) {
                 Some(char) if char.is_ascii_digit() => {
-                    let hour = pick_part::<u32>(2, string, "hour")?;
+                    let hour = pick_ranged_part(2, string, "hour", 0, 23)?;
                     Some(ParsedPart {
                         value: hour as i64,
                         unit: ParseUnit::Hour,
                     })
                 }
                 _ => {
-                    let hour = pick_part::<u32>(1, string, "hour")?;
+                    let hour = pick_ranged_part(1, string, "hour", 0, 23)?;
                     Some(ParsedPart {
                         value: hour as i64,
                         unit: ParseUnit::Hour,
@@ -540,7 +990,7 @@ pub(crate) fn parse_time_part(
                 }
             },
             _ => {
-                let hour = pick_part::<u32>(2, string, "hour")?;
+                let hour = pick_ranged_part(2, string, "hour", 0, 23)?;
                 Some(ParsedPart {
                     value: hour as i64,
                     unit: ParseUnit::Hour,
@@ -550,14 +1000,14 @@ pub(crate) fn parse_time_part(
         'K' => match chars.len() {
             1 => match string.chars().nth(1) {
                 Some(char) if char.is_ascii_digit() => {
-                    let hour = pick_part::<u32>(2, string, "hour")?;
+                    let hour = pick_ranged_part(2, string, "hour", 0, 11)?;
                     Some(ParsedPart {
                         value: hour as i64,
                         unit: ParseUnit::PeriodHour,
                     })
                 }
                 _ => {
-                    let hour = pick_part::<u32>(1, string, "hour")?;
+                    let hour = pick_ranged_part(1, string, "hour", 0, 11)?;
                     Some(ParsedPart {
                         value: hour as i64,
                         unit: ParseUnit::PeriodHour,
@@ -565,7 +1015,7 @@ pub(crate) fn parse_time_part(
                 }
             },
             _ => {
-                let hour = pick_part::<u32>(2, string, "hour")?;
+                let hour = pick_ranged_part(2, string, "hour", 0, 11)?;
                 Some(ParsedPart {
                     value: hour as i64,
                     unit: ParseUnit::PeriodHour,
@@ -575,14 +1025,14 @@ pub(crate) fn parse_time_part(
         'k' => match chars.len() {
             1 => match string.chars().nth(1) {
                 Some(char) if char.is_ascii_digit() => {
-                    let hour = pick_part::<u32>(2, string, "hour")?;
+                    let hour = pick_ranged_part(2, string, "hour", 1, 24)?;
                     Some(ParsedPart {
                         value: if hour == 24 { 0 } else { hour } as i64,
                         unit: ParseUnit::Hour,
                     })
                 }
                 _ => {
-                    let hour = pick_part::<u32>(1, string, "hour")?;
+                    let hour = pick_ranged_part(1, string, "hour", 1, 24)?;
                     Some(ParsedPart {
                         // Hour cannot be 24
                         value: hour as i64,
@@ -591,7 +1041,7 @@ pub(crate) fn parse_time_part(
                 }
             },
             _ => {
-                let hour = pick_part::<u32>(2, string, "hour")?;
+                let hour = pick_ranged_part(2, string, "hour", 1, 24)?;
                 Some(ParsedPart {
                     value: if hour == 24 { 0 } else { hour } as i64,
                     unit: ParseUnit::Hour,
@@ -601,14 +1051,14 @@ pub(crate) fn parse_time_part(
         'm' => match chars.len() {
             1 => match string.chars().nth(1) {
                 Some(char) if char.is_ascii_digit() => {
-                    let minute = pick_part::<u32>(2, string, "minute")?;
+                    let minute = pick_ranged_part(2, string, "minute", 0, 59)?;
                     Some(ParsedPart {
                         value: minute as i64,
                         unit: ParseUnit::Minute,
                     })
                 }
                 _ => {
-                    let minute = pick_part::<u32>(1, string, "minute")?;
+                    let minute = pick_ranged_part(1, string, "minute", 0, 59)?;
                     Some(ParsedPart {
                         value: minute as i64,
                         unit: ParseUnit::Minute,
@@ -616,7 +1066,7 @@ pub(crate) fn parse_time_part(
                 }
             },
             _ => {
-                let minute = pick_part::<u32>(2, string, "minute")?;
+                let minute = pick_ranged_part(2, string, "minute", 0, 59)?;
                 Some(ParsedPart {
                     value: minute as i64,
                     unit: ParseUnit::Minute,
@@ -626,14 +1076,14 @@ pub(crate) fn parse_time_part(
         's' => match chars.len() {
             1 => match string.chars().nth(1) {
                 Some(char) if char.is_ascii_digit() => {
-                    let seconds = pick_part::<u32>(2, string, "seconds")?;
+                    let seconds = pick_ranged_part(2, string, "second", 0, 59)?;
                     Some(ParsedPart {
                         value: seconds as i64,
                         unit: ParseUnit::Second,
                     })
                 }
                 _ => {
-                    let seconds = pick_part::<u32>(1, string, "seconds")?;
+                    let seconds = pick_ranged_part(1, string, "second", 0, 59)?;
                     Some(ParsedPart {
                         value: seconds as i64,
                         unit: ParseUnit::Second,
@@ -641,57 +1091,109 @@ pub(crate) fn parse_time_part(
                 }
             },
             _ => {
-                let seconds = pick_part::<u32>(2, string, "seconds")?;
+                let seconds = pick_ranged_part(2, string, "second", 0, 59)?;
                 Some(ParsedPart {
                     value: seconds as i64,
                     unit: ParseUnit::Second,
                 })
             }
         },
-        'n' => match chars.len() {
-            1 => {
-                let subsecond = pick_part::<u32>(1, string, "subseconds")?;
+        'n' => {
+            let digits = match chars.len() {
+                1 => 1,
+                2 => 2,
+                4 => 6,
+                5 => 9,
+                _ => 3,
+            };
+            let subseconds = pick_str(digits, string)?;
 
-                Some(ParsedPart {
-                    value: subsecond as i64,
-                    unit: ParseUnit::Decis,
-                })
-            }
-            2 => {
-                let subsecond = pick_part::<u32>(2, string, "subseconds")?;
-
-                Some(ParsedPart {
-                    value: subsecond as i64,
-                    unit: ParseUnit::Centis,
-                })
-            }
-            4 => {
-                let subsecond = pick_part::<u32>(6, string, "subseconds")?;
-
-                Some(ParsedPart {
-                    value: subsecond as i64,
-                    unit: ParseUnit::Micros,
-                })
-            }
-            5 => {
-                let subsecond = pick_part::<u32>(9, string, "subseconds")?;
+            Some(ParsedPart {
+                value: parse_subseconds(subseconds)? as i64,
+                unit: ParseUnit::Nanos,
+            })
+        }
+        'X' | 'x' => {
+            let format = match chars.len() {
+                1 => OffsetFormat {
+                    with_z: false,
+                    with_colon: false,
+                    optional_minutes: true,
+                    optional_seconds: false,
+                },
+                2 => OffsetFormat {
+                    with_z: false,
+                    with_colon: false,
+                    optional_minutes: false,
+                    optional_seconds: false,
+                },
+                4 => OffsetFormat {
+                    with_z: false,
+                    with_colon: false,
+                    optional_minutes: false,
+                    optional_seconds: true,
+                },
+                5 => OffsetFormat {
+                    with_z: false,
+                    with_colon: true,
+                    optional_minutes: false,
+                    optional_seconds: true,
+                },
+                _ => OffsetFormat {
+                    with_z: false,
+                    with_colon: true,
+                    optional_minutes: false,
+                    optional_seconds: false,
+                },
+            };
 
+            Some(ParsedPart {
+                value: parse_offset(
+                    string,
+                    OffsetFormat {
+                        with_z: first_char == 'X',
+                        ..format
+                    },
+                )? as i64,
+                unit: ParseUnit::Offset,
+            })
+        }
+        'z' => match string.strip_prefix("GMT") {
+            Some(rest) => {
+                *string = rest;
+                let offset = if string.starts_with(|char| char == '+' || char == '-') {
+                    parse_offset(
+                        string,
+                        OffsetFormat {
+                            with_z: false,
+                            with_colon: true,
+                            optional_minutes: true,
+                            optional_seconds: true,
+                        },
+                    )?
+                } else {
+                    0
+                };
                 Some(ParsedPart {
-                    value: subsecond as i64,
-                    unit: ParseUnit::Nanos,
+                    value: offset as i64,
+                    unit: ParseUnit::Offset,
                 })
             }
-            _ => {
-                let subsecond = pick_part::<u32>(3, string, "subseconds")?;
-
-                Some(ParsedPart {
-                    value: subsecond as i64,
-                    unit: ParseUnit::Millis,
-                })
+            // Designations are ambiguous (E.g. `IST`), so they are skipped without setting an offset
+            None => {
+                let length = string
+                    .find(|char: char| !char.is_ascii_alphabetic())
+                    .unwrap_or(string.len());
+                if length == 0 {
+                    return Err(create_invalid_format(format!(
+                        "Failed parsing time zone from given string. Value is '{}'. Time zone has to be a designation or 'GMT' offset.",
+                        string
+                    )));
+                }
+                *string = &string[length..];
+                None
             }
         },
-        'X' => parse_zone(chars.len(), string, true)?,
-        'x' => parse_zone(chars.len(), string, false)?,
         _ => {
             remove_part(chars.len(), string)?;
             None
@@ -700,253 +1202,400 @@ pub(crate) fn parse_time_part(
 }
 
 /// Parses the month of a date based on https://www.unicode.org/reports/tr35/tr35-dates.html#dfst-month
-fn parse_month(length: usize, string: &mut String) -> Result<Option<ParsedPart>, AstrolabeError> {
-    Ok(match length {
+fn parse_month(
+    chars: &str,
+    string: &mut &str,
+    locale: &Locale,
+) -> Result<Option<ParsedPart>, AstrolabeError> {
+    let months = match chars.len() {
         1 | 2 => {
-            let month = pick_part::<u32>(length, string, "month")?;
+            let month = pick_part::<u32>(chars.len(), string, "month")?;
 
-            Some(ParsedPart {
+            return Ok(Some(ParsedPart {
                 value: month as i64,
                 unit: ParseUnit::Month,
-            })
-        }
-        3 => {
-            for (n, month) in MONTH_ABBREVIATED.iter().enumerate() {
-                if string.starts_with(month) {
-                    // Using unwrap because it's safe to assume that the string is long enough
-                    remove_part(month.len(), string).unwrap();
-                    return Ok(Some(ParsedPart {
-                        value: (n + 1) as i64,
-                        unit: ParseUnit::Month,
-                    }));
-                };
-            }
-            return Err(create_invalid_format(
-                "Could not parse month from given string.".to_string(),
-            ));
+            }));
         }
-        // Narrow month parsing doesn't work as there are multiple months with the same letter
+        3 => &locale.months_abbreviated,
+        // Narrow month parsing doesn't work as there are multiple months with the same name
         5 => {
-            remove_part(1, string)?;
-            None
+            pick_token(chars, string, &[&locale.months_narrow], false)?;
+            return Ok(None);
         }
-        _ => {
-            for (n, month) in MONTH_WIDE.iter().enumerate() {
-                if string.starts_with(month) {
-                    // Using unwrap because it's safe to assume that the string is long enough
-                    remove_part(month.len(), string).unwrap();
-                    return Ok(Some(ParsedPart {
-                        value: (n + 1) as i64,
-                        unit: ParseUnit::Month,
-                    }));
-                };
-            }
-            return Err(create_invalid_format(
-                "Could not parse month from given string.".to_string(),
-            ));
-        }
-    })
-}
+        _ => &locale.months_wide,
+    };
 
-/// Parses the week day of a date based on https://www.unicode.org/reports/tr35/tr35-dates.html#dfst-month
-fn parse_wday(length: usize, string: &mut String) -> Result<Option<ParsedPart>, AstrolabeError> {
-    Ok(match length {
-        2 | 3 => {
-            remove_part(length, string)?;
-            None
-        }
-        4 => {
-            for wday in WDAY_WIDE {
-                if string.starts_with(wday) {
-                    // Using unwrap because it's safe to assume that the string is long enough
-                    remove_part(wday.len(), string).unwrap();
-                    return Ok(None);
-                };
-            }
-            return Err(create_invalid_format(
-                "Could not parse week day from given string.".to_string(),
-            ));
-        }
-        6 | 8 => {
-            remove_part(2, string)?;
-            None
-        }
-        // 1, 5, 7 and 9+ all consist of 1 char
-        _ => {
-            remove_part(1, string)?;
-            None
-        }
-    })
+    let (_, month) = pick_token(chars, string, &[months], false)?;
+    Ok(Some(ParsedPart {
+        value: (month + 1) as i64,
+        unit: ParseUnit::Month,
+    }))
 }
 
-/// Parses the time zone
-fn parse_zone(
-    length: usize,
-    string: &mut String,
-    with_z: bool,
+/// Parses the week day of a date based on https://www.unicode.org/reports/tr35/tr35-dates.html#dfst-month
+fn parse_wday(
+    chars: &str,
+    string: &mut &str,
+    locale: &Locale,
 ) -> Result<Option<ParsedPart>, AstrolabeError> {
-    let prefix = pick_part::<String>(1, string, "timezone prefix")?;
-
-    let multiplier = match prefix.as_str() {
-        "Z" if with_z => {
-            return Ok(Some(ParsedPart {
-                value: 0,
-                unit: ParseUnit::Offset,
+    let wdays = match chars.len() {
+        3 => &locale.weekdays_abbreviated,
+        4 => &locale.weekdays_wide,
+        // Narrow week days are skipped like narrow months
+        5 => &locale.weekdays_narrow,
+        6 => &locale.weekdays_short,
+        // ISO 8601 weekdays, `1` is Monday
+        7 | 8 => {
+            let number = pick_optional_ranged_part(chars.len() - 6, string, "weekday", 1, 7)?;
+            return Ok(number.map(|number| ParsedPart {
+                value: (number % 7) as i64,
+                unit: ParseUnit::Weekday,
             }));
         }
-        "+" => 1,
-        "-" => -1,
+        // 1, 2 and 9+ are numeric weekdays starting on the first weekday of the locale
         _ => {
-            return Err(create_invalid_format(
-                "Couldn't parse prefix of timezone offset. Prefix has to be either '+' or '-'."
-                    .to_string(),
-            ))
+            let length = if chars.len() == 2 { 2 } else { 1 };
+            let number = pick_optional_ranged_part(length, string, "weekday", 1, 7)?;
+            return Ok(number.map(|number| ParsedPart {
+                value: locale.first_weekday.wday(number) as i64,
+                unit: ParseUnit::Weekday,
+            }));
         }
     };
 
-    let hour = pick_part::<u32>(2, string, "timezone hour")?;
+    let (_, weekday) = pick_token(chars, string, &[wdays], false)?;
+    // Narrow week days are ambiguous
+    Ok((chars.len() != 5).then(|| ParsedPart {
+        value: weekday as i64,
+        unit: ParseUnit::Weekday,
+    }))
+}
 
-    Ok(match length {
-        1 => match string.chars().next() {
-            Some(char) if char.is_ascii_digit() => {
-                let minute = pick_part::<u32>(2, string, "timezone minute")?;
+/// Returns the number of ASCII digits at the start of the string
+fn count_digits(string: &str) -> usize {
+    string
+        .find(|char: char| !char.is_ascii_digit())
+        .unwrap_or(string.len())
+}
 
-                let offset = (hour * SECS_PER_HOUR_U64 as u32 + minute * SECS_PER_MINUTE_U64 as u32)
-                    as i64
-                    * multiplier;
+/// Skips the next `length` characters of the string
+fn remove_part(length: usize, string: &mut &str) -> Result<(), AstrolabeError> {
+    pick_str(length, string).map(|_| ())
+}
 
-                Some(ParsedPart {
-                    value: offset,
-                    unit: ParseUnit::Offset,
-                })
-            }
-            _ => {
-                let offset = (hour * SECS_PER_HOUR_U64 as u32) as i64 * multiplier;
+/// Returns the next `length` characters and advances the string past them, without copying
+fn pick_str<'a>(length: usize, string: &mut &'a str) -> Result<&'a str, AstrolabeError> {
+    let too_short = || {
+        create_invalid_format(
+            "String to parse is too short. Please check your format string.".to_string(),
+        )
+    };
+    // Only counts up to `length` characters, so long strings aren't walked for every part
+    if string.chars().take(length).count() < length {
+        return Err(too_short());
+    }
+    let part = string.get(..length).ok_or_else(too_short)?;
+    *string = &string[length..];
+    Ok(part)
+}
 
-                Some(ParsedPart {
-                    value: offset,
-                    unit: ParseUnit::Offset,
-                })
+/// Skips the longest matching token at the start of the string. Returns the index of the token list containing the match and the index of the token in that list.
+fn pick_token<T: AsRef<str>>(
+    chars: &str,
+    string: &mut &str,
+    token_lists: &[&[T]],
+    ignore_case: bool,
+) -> Result<(usize, usize), AstrolabeError> {
+    let mut longest: Option<(usize, usize, usize)> = None;
+    for (list_index, tokens) in token_lists.iter().enumerate() {
+        for (token_index, token) in tokens.iter().enumerate() {
+            let token = token.as_ref();
+            let matches = !token.is_empty()
+                && string.get(0..token.len()).map_or(false, |part| {
+                    if ignore_case {
+                        part.eq_ignore_ascii_case(token)
+                    } else {
+                        part == token
+                    }
+                });
+            if matches && longest.map_or(true, |(_, _, length)| token.len() > length) {
+                longest = Some((list_index, token_index, token.len()));
             }
-        },
-        2 => {
-            let minute = pick_part::<u32>(2, string, "timezone minute")?;
+        }
+    }
 
-            let offset = (hour * SECS_PER_HOUR_U64 as u32 + minute * SECS_PER_MINUTE_U64 as u32)
-                as i64
-                * multiplier;
+    match longest {
+        Some((list_index, token_index, length)) => {
+            *string = &string[length..];
+            Ok((list_index, token_index))
+        }
+        None => Err(create_invalid_format(format!(
+            "Could not parse '{}' from given string.",
+            chars
+        ))),
+    }
+}
 
-            Some(ParsedPart {
-                value: offset,
-                unit: ParseUnit::Offset,
-            })
+/// Picks a numeric part like [`pick_ranged_part`], but skips the part and returns `None` if it isn't numeric. Used for the numeric weekday and week of year, which don't determine the date.
+fn pick_optional_ranged_part(
+    length: usize,
+    string: &mut &str,
+    part_name: &'static str,
+    min: u32,
+    max: u32,
+) -> Result<Option<u32>, AstrolabeError> {
+    if string
+        .chars()
+        .take(length)
+        .all(|char| char.is_ascii_digit())
+    {
+        pick_ranged_part(length, string, part_name, min, max).map(Some)
+    } else {
+        remove_part(length, string)?;
+        Ok(None)
+    }
+}
+
+/// Picks a numeric part like [`pick_part`] and checks that it is in the given range
+fn pick_ranged_part(
+    length: usize,
+    string: &mut &str,
+    part_name: &'static str,
+    min: u32,
+    max: u32,
+) -> Result<u32, AstrolabeError> {
+    let value = pick_part::<u32>(length, string, part_name)?;
+    if !(min..=max).contains(&value) {
+        return Err(create_simple_oor(
+            part_name,
+            min as i128,
+            max as i128,
+            value as i128,
+        ));
+    }
+    Ok(value)
+}
+
+fn pick_part<T: std::str::FromStr>(
+    length: usize,
+    string: &mut &str,
+    part_name: &str,
+) -> Result<T, AstrolabeError> {
+    let mut rest = *string;
+    let part = pick_str(length, &mut rest)?;
+    let value = part.parse::<T>().map_err(|_| {
+        create_invalid_format(format!(
+            "Failed parsing {} from given string. Value is '{}'.",
+            part_name, part
+        ))
+    })?;
+    *string = rest;
+    Ok(value)
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::{parse_fixed_width, ParsedTime, Period};
+    use crate::{util::random::Random, Date, DateTime, ParseOptions, Time};
+
+    #[test]
+    fn nanoseconds_overflow() {
+        let overflowing = [
+            ParsedTime {
+                hour: Some(u64::MAX / 3_600_000_000_000 + 1),
+                ..Default::default()
+            },
+            ParsedTime {
+                period_hour: Some(u64::MAX),
+                period: Some(Period::PM),
+                ..Default::default()
+            },
+            ParsedTime {
+                minute: Some(u64::MAX / 60_000_000_000 + 1),
+                ..Default::default()
+            },
+            ParsedTime {
+                second: Some(u64::MAX / 1_000_000_000 + 1),
+                ..Default::default()
+            },
+            ParsedTime {
+                hour: Some(1),
+                nanos: Some(u64::MAX),
+                ..Default::default()
+            },
+        ];
+        for time in overflowing {
+            assert!(time.nanoseconds().is_err());
         }
-        4 => match string.chars().nth(2) {
-            Some(char) if char.is_ascii_digit() => {
-                let minute = pick_part::<u32>(2, string, "timezone minute")?;
-                let second = pick_part::<u32>(2, string, "timezone second")?;
 
-                let offset = (hour * SECS_PER_HOUR_U64 as u32
-                    + minute * SECS_PER_MINUTE_U64 as u32
-                    + second) as i64
-                    * multiplier;
+        let time = ParsedTime {
+            hour: Some(23),
+            minute: Some(59),
+            second: Some(59),
+            nanos: Some(999_999_999),
+            ..Default::default()
+        };
+        assert_eq!(86_399_999_999_999, time.nanoseconds().unwrap());
 
-                Some(ParsedPart {
-                    value: offset,
-                    unit: ParseUnit::Offset,
-                })
-            }
-            _ => {
-                let minute = pick_part::<u32>(2, string, "timezone minute")?;
+        let time = ParsedTime {
+            period_hour: Some(11),
+            period: Some(Period::PM),
+            ..Default::default()
+        };
+        assert_eq!(82_800_000_000_000, time.nanoseconds().unwrap());
+    }
 
-                let offset = (hour * SECS_PER_HOUR_U64 as u32 + minute * SECS_PER_MINUTE_U64 as u32)
-                    as i64
-                    * multiplier;
+    const FIXED_WIDTH_FORMATS: [&str; 9] = [
+        "yyyy-MM-dd HH:mm:ss",
+        "yyyy-MM-dd'T'HH:mm:ss.nnn'Z'",
+        "dd.MM.yyyy",
+        "MM/dd/yyyy HH:mm",
+        "HH:mm:ss.nnnnn",
+        "HH:mm:ss,nnnn",
+        "yyyy-MM-dd",
+        "ss mm HH n nn",
+        "yyyy/MM/dd 'um' HH:mm:ss dd",
+    ];
 
-                Some(ParsedPart {
-                    value: offset,
-                    unit: ParseUnit::Offset,
-                })
-            }
-        },
-        5 => match string.chars().nth(4) {
-            Some(char) if char.is_ascii_digit() => {
-                // Using unwrap because it's safe to assume that the string is long enough
-                remove_part(1, string).unwrap();
-                let minute = pick_part::<u32>(2, string, "timezone minute")?;
-                // Using unwrap because it's safe to assume that the string is long enough
-                remove_part(1, string).unwrap();
-                let second = pick_part::<u32>(2, string, "timezone second")?;
-
-                let offset = (hour * SECS_PER_HOUR_U64 as u32
-                    + minute * SECS_PER_MINUTE_U64 as u32
-                    + second) as i64
-                    * multiplier;
+    /// Checks that the generic parsers return the same result as the fast path if it accepts the string. Returns `false` if the fast path doesn't apply.
+    fn assert_same_as_generic(string: &str, format: &str) -> bool {
+        let parsed = match parse_fixed_width(string, format) {
+            Some(parsed) => parsed,
+            None => return false,
+        };
+        let message = format!("'{}' with '{}'", string, format);
 
-                Some(ParsedPart {
-                    value: offset,
-                    unit: ParseUnit::Offset,
-                })
+        let expected = format!(
+            "{:?}",
+            DateTime::parse_with(string, format, &Default::default())
+        );
+        for options in [
+            ParseOptions::default(),
+            ParseOptions {
+                reject_conflicts: true,
+                ..Default::default()
+            },
+        ] {
+            let generic = DateTime::parse_generic(string, format, &options);
+            assert_eq!(expected, format!("{:?}", generic), "{}", message);
+            let generic = Time::parse_generic(string, format, &options);
+            assert_eq!(
+                format!("{:?}", Time::parse_with(string, format, &options)),
+                format!("{:?}", generic),
+                "{}",
+                message
+            );
+            if !format.contains('n') {
+                let generic = Date::parse_generic(string, format, &options).unwrap();
+                assert_eq!(
+                    (Date { days: parsed.days }, parsed.rest.len()),
+                    generic,
+                    "{}",
+                    message
+                );
             }
-            _ => {
-                remove_part(1, string)?;
-                let minute = pick_part::<u32>(2, string, "timezone minute")?;
+        }
+        true
+    }
 
-                let offset = (hour * SECS_PER_HOUR_U64 as u32 + minute * SECS_PER_MINUTE_U64 as u32)
-                    as i64
-                    * multiplier;
+    #[test]
+    fn fixed_width_valid() {
+        let mut random = Random::new(0x2545_f491_4f6c_dd1d);
+        for format in FIXED_WIDTH_FORMATS {
+            for _ in 0..1_000 {
+                // Years 1 to 9999
+                let days = random.below(3_652_059) as i32;
+                let nanoseconds = random.below(86_400_000_000_000);
+                let string = DateTime::from_raw(days, nanoseconds)
+                    .unwrap()
+                    .format(format);
 
-                Some(ParsedPart {
-                    value: offset,
-                    unit: ParseUnit::Offset,
-                })
+                assert!(assert_same_as_generic(&string, format), "{}", string);
+                // Trailing characters are ignored like by the generic parser
+                assert!(assert_same_as_generic(&format!("{}x", string), format));
             }
-        },
-        _ => {
-            remove_part(1, string)?;
-            let minute = pick_part::<u32>(2, string, "timezone minute")?;
+        }
+    }
 
-            let offset = (hour * SECS_PER_HOUR_U64 as u32 + minute * SECS_PER_MINUTE_U64 as u32)
-                as i64
-                * multiplier;
+    #[test]
+    fn fixed_width_random() {
+        let mut random = Random::new(0x9e37_79b9_7f4a_7c15);
+        let bytes = b"0123456789-:.,/ TZx+";
+        let mut accepted = 0;
+        let mut rejected = 0;
+        for format in FIXED_WIDTH_FORMATS {
+            for _ in 0..10_000 {
+                let days = random.below(3_652_059) as i32;
+                let nanoseconds = random.below(86_400_000_000_000);
+                let mut string = DateTime::from_raw(days, nanoseconds)
+                    .unwrap()
+                    .format(format)
+                    .into_bytes();
 
-            Some(ParsedPart {
-                value: offset,
-                unit: ParseUnit::Offset,
-            })
-        }
-    })
-}
+                // Replace, insert or remove up to three characters
+                for _ in 0..random.below(3) + 1 {
+                    let byte = bytes[random.below(bytes.len() as u64) as usize];
+                    let index = random.below(string.len() as u64 + 1) as usize;
+                    match random.below(3) {
+                        0 if index < string.len() => string[index] = byte,
+                        1 => string.insert(index, byte),
+                        _ if index < string.len() => {
+                            string.remove(index);
+                        }
+                        _ => {}
+                    }
+                }
 
-fn remove_part(length: usize, string: &mut String) -> Result<(), AstrolabeError> {
-    if string.chars().count() < length {
-        Err(create_invalid_format(
-            "String to parse is too short. Please check your format string.".to_string(),
-        ))
-    } else {
-        string.replace_range(0..length, "");
-        Ok(())
+                // Using unwrap because only ASCII characters are inserted
+                let string = String::from_utf8(string).unwrap();
+                if assert_same_as_generic(&string, format) {
+                    accepted += 1;
+                } else {
+                    rejected += 1;
+                }
+            }
+        }
+        // Replaced digits are often still accepted
+        assert!(accepted > 5_000);
+        assert!(rejected > 50_000);
     }
-}
 
-fn pick_part<T: std::str::FromStr>(
-    length: usize,
-    string: &mut String,
-    part_name: &str,
-) -> Result<T, AstrolabeError> {
-    if string.chars().count() < length {
-        Err(create_invalid_format(
-            "String to parse is too short. Please check your format string.".to_string(),
-        ))
-    } else {
-        let part = string[0..length].parse::<T>().map_err(|_| {
-            create_invalid_format(format!(
-                "Failed parsing {} from given string. Value is '{}'.",
-                part_name,
-                &string[0..length]
-            ))
-        })?;
-        string.replace_range(0..length, "");
-        Ok(part)
+    #[test]
+    fn fixed_width_fallback() {
+        let cases = [
+            // Unsupported symbols
+            ("22-05-02", "yy-MM-dd"),
+            ("2022-05-02T10:00:00Z", "yyyy-MM-dd'T'HH:mm:ssX"),
+            ("+2022-05-02", "+yyyy-MM-dd"),
+            ("2022-5-2", "yyyy-M-d"),
+            ("02 May 2022", "dd MMM yyyy"),
+            ("'2022", "''yyyy"),
+            ("2022-05-02", "yyyy'-MM-dd"),
+            ("2022-05-02 10:30:00", "yyyy-MM-dd HHH:mm:ss"),
+            ("10:30:00.1234567", "HH:mm:ss.nnnnnn"),
+            // Strings which don't match exactly
+            ("2022/05/02", "yyyy-MM-dd"),
+            ("20220502", "yyyyMMdd"),
+            ("12022-05-02", "yyyy-MM-dd"),
+            ("-022-05-02", "yyyy-MM-dd"),
+            ("2022-+5-02", "yyyy-MM-dd"),
+            ("2022-05-0", "yyyy-MM-dd"),
+            ("2022-05-02 24:00:00", "yyyy-MM-dd HH:mm:ss"),
+            ("2022-05-02 10:60:00", "yyyy-MM-dd HH:mm:ss"),
+            ("2022-05-02 10:00:60", "yyyy-MM-dd HH:mm:ss"),
+            ("2022-13-02", "yyyy-MM-dd"),
+            ("2022-02-29", "yyyy-MM-dd"),
+            ("0000-01-01", "yyyy-MM-dd"),
+            ("2022-05-02 10:00", "yyyy-MM-dd'T'HH:mm"),
+            ("2022-05-02", "yyyy-MM-dd'T'"),
+        ];
+        for (string, format) in cases {
+            assert!(parse_fixed_width(string, format).is_none(), "{}", format);
+        }
+
+        let parsed = parse_fixed_width("2022-05-02 rest", "yyyy-MM-dd").unwrap();
+        assert_eq!(" rest", parsed.rest);
+        let parsed = parse_fixed_width("", "").unwrap();
+        assert_eq!((0, 0), (parsed.days, parsed.nanoseconds));
     }
 }
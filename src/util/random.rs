@@ -0,0 +1,17 @@
+/// Deterministic xorshift generator, so randomized unit tests use the same inputs in every run
+pub(crate) struct Random(u64);
+
+impl Random {
+    /// Creates a generator from a non-zero seed
+    pub(crate) fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Returns a number in `0..max`
+    pub(crate) fn below(&mut self, max: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % max
+    }
+}
@@ -2,10 +2,11 @@ use super::validate::validate_time;
 use crate::{
     errors::{out_of_range::create_simple_oor, AstrolabeError},
     util::constants::{
-        NANOS_PER_DAY, NANOS_PER_HOUR, NANOS_PER_MINUTE, NANOS_PER_SEC, SECS_PER_DAY_U64,
-        SECS_PER_HOUR, SECS_PER_MINUTE,
+        DAYS_TO_1970, NANOS_PER_DAY, NANOS_PER_HOUR, NANOS_PER_MINUTE, NANOS_PER_SEC,
+        SECS_PER_DAY_U64, SECS_PER_HOUR, SECS_PER_MINUTE,
     },
 };
+use std::time::{Duration, SystemTime, SystemTimeError, UNIX_EPOCH};
 
 /// Converts nanoseconds to time units (hour, minute, second)
 pub(crate) fn nanos_to_time(nanos: u64) -> (u32, u32, u32) {
@@ -32,7 +33,32 @@ pub(crate) fn time_to_day_seconds(
 ) -> Result<u32, AstrolabeError> {
     validate_time(hour, minute, second)?;
 
-    Ok(hour * SECS_PER_HOUR + minute * SECS_PER_MINUTE + second)
+    Ok(time_to_day_seconds_unchecked(hour, minute, second))
+}
+
+/// Converts hour, minute, second and subsecond nanoseconds to nanoseconds since midnight
+pub(crate) fn time_to_day_nanos(
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanos: u32,
+) -> Result<u64, AstrolabeError> {
+    let seconds = time_to_day_seconds(hour, minute, second)? as u64;
+    if nanos > 999_999_999 {
+        return Err(create_simple_oor(
+            "nanoseconds",
+            0,
+            999_999_999,
+            nanos as i128,
+        ));
+    }
+
+    Ok(seconds * NANOS_PER_SEC + nanos as u64)
+}
+
+/// Converts hour, minute and second to seconds without validating them
+pub(crate) const fn time_to_day_seconds_unchecked(hour: u32, minute: u32, second: u32) -> u32 {
+    hour * SECS_PER_HOUR + minute * SECS_PER_MINUTE + second
 }
 
 /// Converts days and nanoseconds to seconds
@@ -113,6 +139,29 @@ pub(crate) fn nanos_to_days_nanos(nanoseconds: i128) -> Result<(i32, u64), Astro
     Ok((days, adjusted_day_nanos))
 }
 
+/// Converts the result of [`SystemTime::duration_since`](std::time::SystemTime::duration_since) with the Unix epoch to days and nanoseconds. An error means that the system clock is before the epoch, its duration is subtracted from the epoch instead.
+pub(crate) fn since_epoch_to_days_nanos(
+    since_epoch: Result<Duration, SystemTimeError>,
+) -> (i32, u64) {
+    let nanoseconds = match since_epoch {
+        Ok(duration) => duration.as_nanos() as i128,
+        Err(error) => -(error.duration().as_nanos() as i128),
+    };
+
+    nanos_to_days_nanos(nanoseconds + DAYS_TO_1970 as i128 * NANOS_PER_DAY as i128)
+        .expect("System time is out of range")
+}
+
+/// Returns the current time as days and nanoseconds. Uses the mocked time of the [`testing`](crate::testing) module if one is set.
+pub(crate) fn now_days_nanos() -> (i32, u64) {
+    #[cfg(feature = "test-util")]
+    if let Some(now) = crate::testing::mock_now() {
+        return (now.days, now.nanoseconds);
+    }
+
+    since_epoch_to_days_nanos(SystemTime::now().duration_since(UNIX_EPOCH))
+}
+
 /// Converts time units (hour, minute and seconds) and nanoseconds to nanoseconds. Only the subsecond nanoseconds are used.
 pub(crate) fn time_nanos_to_nanos(hour: u32, minute: u32, second: u32, nanos: u64) -> u64 {
     let time_seconds = time_to_day_seconds(hour, minute, second).unwrap();
@@ -226,3 +275,45 @@ pub(crate) fn since_i128(
             0
         }
 }
+
+#[cfg(test)]
+mod convert_tests {
+    use super::since_epoch_to_days_nanos;
+    use crate::{util::constants::DAYS_TO_1970, DateTime};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn since_epoch() {
+        let epoch = DAYS_TO_1970 as i32;
+        assert_eq!((epoch, 0), since_epoch_to_days_nanos(Ok(Duration::ZERO)));
+        assert_eq!(
+            (epoch + 1, 1),
+            since_epoch_to_days_nanos(Ok(Duration::new(86_400, 1)))
+        );
+
+        // System clock before the epoch
+        let before = |duration: Duration| UNIX_EPOCH.duration_since(UNIX_EPOCH + duration);
+        assert!(before(Duration::from_secs(1)).is_err());
+        assert_eq!(
+            (epoch - 1, 86_399_000_000_000),
+            since_epoch_to_days_nanos(before(Duration::from_secs(1)))
+        );
+        assert_eq!(
+            (epoch - 1, 0),
+            since_epoch_to_days_nanos(before(Duration::from_secs(86_400)))
+        );
+        assert_eq!(
+            (epoch - 2, 86_399_999_999_999),
+            since_epoch_to_days_nanos(before(Duration::new(86_400, 1)))
+        );
+
+        let (days, nanoseconds) = since_epoch_to_days_nanos(before(Duration::from_secs(
+            10 * 365 * 86_400 + 3 * 86_400 - 12 * 3600,
+        )));
+        let date_time = DateTime::from_raw(days, nanoseconds).unwrap();
+        assert_eq!(
+            "1960-01-01 12:00:00",
+            date_time.format("yyyy-MM-dd HH:mm:ss")
+        );
+    }
+}
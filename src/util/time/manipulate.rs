@@ -1,8 +1,12 @@
 use super::convert::{nanos_to_time, time_nanos_to_nanos};
 use crate::{
-    errors::{out_of_range::create_conditional_oor, AstrolabeError},
-    util::constants::{NANOS_PER_SEC, SECS_PER_HOUR_U64, SECS_PER_MINUTE_U64},
+    errors::{
+        out_of_range::{create_conditional_oor, create_custom_oor},
+        AstrolabeError,
+    },
+    util::constants::{NANOS_PER_DAY, NANOS_PER_SEC, SECS_PER_HOUR_U64, SECS_PER_MINUTE_U64},
 };
+use std::time::Duration;
 
 pub(crate) fn set_hour(nanos: u64, hour: u32) -> Result<u64, AstrolabeError> {
     if hour > 23 {
@@ -182,3 +186,37 @@ pub(crate) fn clear_nanos_until_nanos(nanos: u64) -> u64 {
     let (hour, minute, second) = nanos_to_time(nanos);
     time_nanos_to_nanos(hour, minute, second, nanos / 1_000 * 1_000)
 }
+
+/// Returns the duration in nanoseconds if it evenly divides a day
+pub(crate) fn day_divisor_nanos(duration: Duration) -> Result<u64, AstrolabeError> {
+    let nanos = duration.as_nanos();
+    if nanos == 0 || nanos > NANOS_PER_DAY as u128 || NANOS_PER_DAY as u128 % nanos != 0 {
+        return Err(create_custom_oor(format!(
+            "Duration of {} nanoseconds has to evenly divide a day.",
+            nanos
+        )));
+    }
+    Ok(nanos as u64)
+}
+
+/// Rounds nanoseconds down to a multiple of `step`
+pub(crate) fn floor_nanos(nanos: u64, step: u64) -> u64 {
+    nanos - nanos % step
+}
+
+/// Rounds nanoseconds up to a multiple of `step`. Can return [`NANOS_PER_DAY`].
+pub(crate) fn ceil_nanos(nanos: u64, step: u64) -> u64 {
+    match nanos % step {
+        0 => nanos,
+        remainder => nanos - remainder + step,
+    }
+}
+
+/// Rounds nanoseconds to the nearest multiple of `step`, rounding ties up. Can return [`NANOS_PER_DAY`].
+pub(crate) fn round_nanos(nanos: u64, step: u64) -> u64 {
+    if nanos % step * 2 >= step {
+        ceil_nanos(nanos, step)
+    } else {
+        floor_nanos(nanos, step)
+    }
+}
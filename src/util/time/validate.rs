@@ -15,3 +15,8 @@ pub(crate) fn validate_time(hour: u32, minute: u32, second: u32) -> Result<(), A
 
     Ok(())
 }
+
+/// Returns `true` if the given time is valid. Const counterpart of [`validate_time`].
+pub(crate) const fn is_valid_time(hour: u32, minute: u32, second: u32) -> bool {
+    hour <= 23 && minute <= 59 && second <= 59
+}
@@ -0,0 +1,51 @@
+mod common;
+
+#[cfg(test)]
+#[cfg(feature = "arbitrary")]
+mod arbitrary_tests {
+    use crate::common::Random;
+    use arbitrary::{Arbitrary, Unstructured};
+    use astrolabe::{Date, DateTime, DateUtilities, Offset, OffsetUtilities, Precision, Time};
+
+    /// RFC 3339 only supports four digit years and offsets in whole minutes
+    fn rfc3339_representable(date_time: DateTime) -> DateTime {
+        let year = date_time.year().rem_euclid(9_999) + 1;
+        let seconds = date_time.get_offset().resolve();
+        date_time
+            .set_year(year)
+            .or_else(|_| date_time.set_day(28).unwrap().set_year(year))
+            .unwrap()
+            .as_offset(Offset::Fixed(seconds / 60 * 60))
+    }
+
+    #[test]
+    fn rfc3339_round_trip() {
+        let data = Random::new(0x2545_f491_4f6c_dd1d).bytes(10_000 * 24);
+        let mut u = Unstructured::new(&data);
+        for _ in 0..10_000 {
+            let date_time = rfc3339_representable(DateTime::arbitrary(&mut u).unwrap());
+            let formatted = date_time.format_rfc3339(Precision::Nanos);
+            let parsed = DateTime::parse_rfc3339(&formatted).unwrap();
+            assert_eq!(date_time, parsed);
+            assert_eq!(date_time.get_offset(), parsed.get_offset());
+        }
+    }
+
+    #[test]
+    fn valid() {
+        let data = Random::new(0x9e37_79b9_7f4a_7c15).bytes(10_000 * 16);
+        let mut u = Unstructured::new(&data);
+        for _ in 0..10_000 {
+            let date = Date::arbitrary(&mut u).unwrap();
+            let (year, month, day) = date.as_ymd();
+            assert_eq!(date, Date::from_ymd(year, month, day).unwrap());
+            let time = Time::arbitrary(&mut u).unwrap();
+            assert!(time.as_nanos() < 86_400_000_000_000);
+            let seconds = match Offset::arbitrary(&mut u).unwrap() {
+                Offset::Fixed(seconds) => seconds,
+                _ => panic!("Expected a fixed offset"),
+            };
+            assert!((-86_399..=86_399).contains(&seconds));
+        }
+    }
+}
@@ -0,0 +1,407 @@
+#[cfg(test)]
+mod bounds_tests {
+    use astrolabe::{
+        Bounds, Date, DateTime, DateUtilities, Offset, OffsetUtilities, Time, TimeUtilities,
+    };
+
+    const BOUNDS: [Bounds; 4] = [
+        Bounds::Inclusive,
+        Bounds::Exclusive,
+        Bounds::StartInclusive,
+        Bounds::EndInclusive,
+    ];
+
+    // (before start, at start, inside, at end, after end) for each variant of `BOUNDS`
+    const TRUTH_TABLE: [[bool; 5]; 4] = [
+        [false, true, true, true, false],
+        [false, false, true, false, false],
+        [false, true, true, false, false],
+        [false, false, true, true, false],
+    ];
+
+    #[test]
+    fn date_time() {
+        let start = DateTime::from_ymdhms(2022, 5, 2, 10, 0, 0).unwrap();
+        let end = DateTime::from_ymdhms(2022, 5, 3, 10, 0, 0).unwrap();
+        let values = [
+            start.sub_nanos(1),
+            start,
+            start.add_hours(12),
+            end,
+            end.add_nanos(1),
+        ];
+
+        for (bounds, expected) in BOUNDS.iter().zip(TRUTH_TABLE) {
+            for (value, expected) in values.iter().zip(expected) {
+                assert_eq!(
+                    expected,
+                    value.is_between(&start, &end, *bounds),
+                    "{} {:?}",
+                    value,
+                    bounds
+                );
+            }
+            // Start after end
+            assert!(!values[2].is_between(&end, &start, *bounds));
+        }
+
+        // Instant-based
+        let value = start.set_offset(Offset::Fixed(-3600));
+        assert!(value.is_between(&start, &end, Bounds::Inclusive));
+        assert!(!value.is_between(&start, &end, Bounds::Exclusive));
+    }
+
+    #[test]
+    fn date_time_clamp() {
+        let start = DateTime::from_ymdhms(2022, 5, 2, 10, 0, 0).unwrap();
+        let end = DateTime::from_ymdhms(2022, 5, 3, 10, 0, 0).unwrap();
+        let before = start.sub_hours(1);
+        let inside = start.add_hours(12);
+        let after = end.add_hours(1);
+
+        for bounds in BOUNDS {
+            assert_eq!(inside, inside.clamp_between(&start, &end, bounds));
+        }
+        assert_eq!(start, before.clamp_between(&start, &end, Bounds::Inclusive));
+        assert_eq!(end, after.clamp_between(&start, &end, Bounds::Inclusive));
+        assert_eq!(
+            start.add_nanos(1),
+            before.clamp_between(&start, &end, Bounds::Exclusive)
+        );
+        assert_eq!(
+            end.sub_nanos(1),
+            after.clamp_between(&start, &end, Bounds::Exclusive)
+        );
+        assert_eq!(
+            start,
+            start.clamp_between(&start, &end, Bounds::StartInclusive)
+        );
+        assert_eq!(
+            end.sub_nanos(1),
+            end.clamp_between(&start, &end, Bounds::StartInclusive)
+        );
+        assert_eq!(
+            start.add_nanos(1),
+            start.clamp_between(&start, &end, Bounds::EndInclusive)
+        );
+        assert_eq!(end, end.clamp_between(&start, &end, Bounds::EndInclusive));
+
+        // Offset of `self` is kept
+        let before = before.set_offset(Offset::Fixed(3600));
+        let clamped = before.clamp_between(&start, &end, Bounds::Inclusive);
+        assert_eq!(start, clamped);
+        assert_eq!(Offset::Fixed(3600), clamped.get_offset());
+
+        // Single instance
+        assert_eq!(
+            start,
+            before.clamp_between(&start, &start, Bounds::Inclusive)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn date_time_clamp_empty() {
+        let start = DateTime::from_ymdhms(2022, 5, 2, 10, 0, 0).unwrap();
+        start.clamp_between(&start, &start.add_nanos(1), Bounds::Exclusive);
+    }
+
+    #[test]
+    fn date() {
+        let start = Date::from_ymd(2022, 5, 2).unwrap();
+        let end = Date::from_ymd(2022, 5, 9).unwrap();
+        let values = [
+            start.sub_days(1),
+            start,
+            start.add_days(3),
+            end,
+            end.add_days(1),
+        ];
+
+        for (bounds, expected) in BOUNDS.iter().zip(TRUTH_TABLE) {
+            for (value, expected) in values.iter().zip(expected) {
+                assert_eq!(
+                    expected,
+                    value.is_between(&start, &end, *bounds),
+                    "{} {:?}",
+                    value,
+                    bounds
+                );
+            }
+            // Start after end
+            assert!(!values[2].is_between(&end, &start, *bounds));
+        }
+    }
+
+    #[test]
+    fn date_clamp() {
+        let start = Date::from_ymd(2022, 5, 2).unwrap();
+        let end = Date::from_ymd(2022, 5, 9).unwrap();
+        let before = start.sub_days(10);
+        let inside = start.add_days(3);
+        let after = end.add_days(10);
+
+        for bounds in BOUNDS {
+            assert_eq!(inside, inside.clamp_between(&start, &end, bounds));
+        }
+        assert_eq!(start, before.clamp_between(&start, &end, Bounds::Inclusive));
+        assert_eq!(end, after.clamp_between(&start, &end, Bounds::Inclusive));
+        assert_eq!(
+            start.add_days(1),
+            before.clamp_between(&start, &end, Bounds::Exclusive)
+        );
+        assert_eq!(
+            end.sub_days(1),
+            after.clamp_between(&start, &end, Bounds::Exclusive)
+        );
+        assert_eq!(
+            start,
+            before.clamp_between(&start, &end, Bounds::StartInclusive)
+        );
+        assert_eq!(
+            end.sub_days(1),
+            after.clamp_between(&start, &end, Bounds::StartInclusive)
+        );
+        assert_eq!(
+            start.add_days(1),
+            before.clamp_between(&start, &end, Bounds::EndInclusive)
+        );
+        assert_eq!(end, after.clamp_between(&start, &end, Bounds::EndInclusive));
+    }
+
+    #[test]
+    #[should_panic]
+    fn date_clamp_empty() {
+        let start = Date::from_ymd(2022, 5, 2).unwrap();
+        start.clamp_between(&start, &start, Bounds::StartInclusive);
+    }
+
+    #[test]
+    fn time() {
+        let start = Time::from_hms(9, 0, 0).unwrap();
+        let end = Time::from_hms(17, 0, 0).unwrap();
+        let values = [
+            start.sub_nanos(1),
+            start,
+            Time::from_hms(12, 0, 0).unwrap(),
+            end,
+            end.add_nanos(1),
+        ];
+
+        for (bounds, expected) in BOUNDS.iter().zip(TRUTH_TABLE) {
+            for (value, expected) in values.iter().zip(expected) {
+                assert_eq!(
+                    expected,
+                    value.is_between(&start, &end, *bounds),
+                    "{} {:?}",
+                    value,
+                    bounds
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn time_wrap_around() {
+        let start = Time::from_hms(22, 0, 0).unwrap();
+        let end = Time::from_hms(6, 0, 0).unwrap();
+        let values = [
+            start.sub_nanos(1),
+            start,
+            Time::from_hms(0, 0, 0).unwrap(),
+            end,
+            end.add_nanos(1),
+        ];
+
+        for (bounds, expected) in BOUNDS.iter().zip(TRUTH_TABLE) {
+            for (value, expected) in values.iter().zip(expected) {
+                assert_eq!(
+                    expected,
+                    value.is_between(&start, &end, *bounds),
+                    "{} {:?}",
+                    value,
+                    bounds
+                );
+            }
+            assert!(Time::from_hms(23, 59, 59)
+                .unwrap()
+                .is_between(&start, &end, *bounds));
+            assert!(!Time::from_hms(12, 0, 0)
+                .unwrap()
+                .is_between(&start, &end, *bounds));
+        }
+    }
+
+    #[test]
+    fn time_clamp() {
+        let start = Time::from_hms(9, 0, 0).unwrap();
+        let end = Time::from_hms(17, 0, 0).unwrap();
+        let before = Time::from_hms(1, 0, 0).unwrap();
+        let after = Time::from_hms(23, 0, 0).unwrap();
+
+        assert_eq!(start, before.clamp_between(&start, &end, Bounds::Inclusive));
+        assert_eq!(end, after.clamp_between(&start, &end, Bounds::Inclusive));
+        assert_eq!(
+            start.add_nanos(1),
+            before.clamp_between(&start, &end, Bounds::Exclusive)
+        );
+        assert_eq!(
+            end.sub_nanos(1),
+            after.clamp_between(&start, &end, Bounds::Exclusive)
+        );
+
+        // Wrap around from 17:00 to 09:00, clamped to the closer bound
+        let inside = Time::from_hms(23, 0, 0).unwrap();
+        let morning = Time::from_hms(10, 0, 0).unwrap();
+        let afternoon = Time::from_hms(16, 0, 0).unwrap();
+        let center = Time::from_hms(13, 0, 0).unwrap();
+        for bounds in BOUNDS {
+            assert_eq!(inside, inside.clamp_between(&end, &start, bounds));
+        }
+        assert_eq!(
+            start,
+            morning.clamp_between(&end, &start, Bounds::Inclusive)
+        );
+        assert_eq!(
+            end,
+            afternoon.clamp_between(&end, &start, Bounds::Inclusive)
+        );
+        assert_eq!(
+            start.sub_nanos(1),
+            morning.clamp_between(&end, &start, Bounds::Exclusive)
+        );
+        assert_eq!(
+            end.add_nanos(1),
+            afternoon.clamp_between(&end, &start, Bounds::Exclusive)
+        );
+        // Equally close to both bounds
+        assert_eq!(start, center.clamp_between(&end, &start, Bounds::Inclusive));
+
+        // Offset of `self` is kept
+        let before = before.set_offset(Offset::Fixed(3600));
+        let clamped = before.clamp_between(&start, &end, Bounds::Inclusive);
+        assert_eq!(start, clamped);
+        assert_eq!(Offset::Fixed(3600), clamped.get_offset());
+    }
+
+    #[test]
+    #[should_panic]
+    fn time_clamp_empty() {
+        let start = Time::from_hms(9, 0, 0).unwrap();
+        start.clamp_between(&start, &start, Bounds::EndInclusive);
+    }
+
+    #[test]
+    fn date_time_min_max_clamp() {
+        let plus_two = Offset::from_hms(2, 0, 0).unwrap();
+        // 10:00+02:00 is 08:00 UTC
+        let local = DateTime::from_ymdhms(2022, 5, 2, 10, 0, 0)
+            .unwrap()
+            .as_offset(plus_two);
+        let utc = DateTime::from_ymdhms(2022, 5, 2, 9, 30, 0).unwrap();
+
+        assert!(local < utc);
+        assert_eq!(Offset::Fixed(7200), local.min(utc).get_offset());
+        assert_eq!(Offset::Fixed(0), local.max(utc).get_offset());
+        assert_eq!(
+            Offset::Fixed(7200),
+            utc.max(local).max(utc).min(local).get_offset()
+        );
+
+        // Equal instants return self
+        let same = local.set_offset(Offset::Fixed(0));
+        assert_eq!(Offset::Fixed(7200), local.min(same).get_offset());
+        assert_eq!(Offset::Fixed(7200), local.max(same).get_offset());
+        assert_eq!(Offset::Fixed(0), same.min(local).get_offset());
+        assert_eq!(Offset::Fixed(0), same.max(local).get_offset());
+
+        let min = DateTime::from_ymdhms(2022, 5, 2, 9, 0, 0).unwrap();
+        let max = DateTime::from_ymdhms(2022, 5, 2, 12, 0, 0).unwrap();
+        let clamped = local.clamp(min, max);
+        assert_eq!(min, clamped);
+        assert_eq!("11:00", clamped.format("HH:mm"));
+        assert_eq!(Offset::Fixed(7200), clamped.get_offset());
+        // Unlike `Ord::clamp`, the offset of self is kept
+        assert_eq!(Offset::Fixed(0), Ord::clamp(local, min, max).get_offset());
+
+        assert_eq!(utc, utc.clamp(min, max));
+        let after = DateTime::from_ymdhms(2022, 5, 2, 13, 0, 0)
+            .unwrap()
+            .set_offset(plus_two);
+        assert_eq!("14:00", after.clamp(min, max).format("HH:mm"));
+        assert_eq!(max, max.clamp(max, max));
+
+        assert!(utc.is_between(&local, &max, Bounds::Inclusive));
+        assert!(local.is_between(&min.sub_hours(2), &utc, Bounds::Exclusive));
+        assert!(!local.is_between(&min, &max, Bounds::Inclusive));
+    }
+
+    #[test]
+    #[should_panic]
+    fn date_time_clamp_min_after_max() {
+        let date_time = DateTime::from_ymd(2022, 5, 2).unwrap();
+        date_time.clamp(date_time.add_nanos(1), date_time);
+    }
+
+    #[test]
+    fn date_min_max_clamp() {
+        let min = Date::from_ymd(-1, 12, 30).unwrap();
+        let max = Date::from_ymd(1, 1, 2).unwrap();
+
+        assert_eq!(min, min.min(max));
+        assert_eq!(min, max.min(min));
+        assert_eq!(max, min.max(max));
+        assert_eq!(max, max.max(min));
+
+        assert_eq!(min, Date::from_ymd(-100, 1, 1).unwrap().clamp(min, max));
+        assert_eq!(max, Date::from_ymd(100, 1, 1).unwrap().clamp(min, max));
+        let inside = Date::from_ymd(1, 1, 1).unwrap();
+        assert_eq!(inside, inside.clamp(min, max));
+        assert_eq!(min, inside.clamp(min, min));
+    }
+
+    #[test]
+    #[should_panic]
+    fn date_clamp_min_after_max() {
+        let date = Date::from_ymd(2022, 5, 2).unwrap();
+        date.clamp(date.add_days(1), date);
+    }
+
+    #[test]
+    fn time_min_max_clamp() {
+        let plus_two = Offset::from_hms(2, 0, 0).unwrap();
+        // 10:00+02:00 is 08:00 UTC
+        let local = Time::from_hms(10, 0, 0).unwrap().as_offset(plus_two);
+        let utc = Time::from_hms(9, 30, 0).unwrap();
+
+        assert!(local < utc);
+        assert_eq!(Offset::Fixed(7200), local.min(utc).get_offset());
+        assert_eq!(Offset::Fixed(0), local.max(utc).get_offset());
+        let same = local.set_offset(Offset::Fixed(0));
+        assert_eq!(Offset::Fixed(7200), local.min(same).get_offset());
+        assert_eq!(Offset::Fixed(0), same.max(local).get_offset());
+
+        let min = Time::from_hms(9, 0, 0).unwrap();
+        let max = Time::from_hms(12, 0, 0).unwrap();
+        let clamped = local.clamp(min, max);
+        assert_eq!(min, clamped);
+        assert_eq!("11:00", clamped.format("HH:mm"));
+        assert_eq!(utc, utc.clamp(min, max));
+
+        // Doesn't wrap around midnight
+        let late = Time::from_hms(23, 0, 0).unwrap();
+        assert_eq!(max, late.clamp(min, max));
+        let early = Time::from_hms(1, 0, 0).unwrap();
+        assert_eq!(min, early.clamp(min, max));
+
+        assert!(utc.is_between(&local, &max, Bounds::Inclusive));
+        assert!(!local.is_between(&min, &max, Bounds::Inclusive));
+    }
+
+    #[test]
+    #[should_panic]
+    fn time_clamp_min_after_max() {
+        let time = Time::from_hms(12, 0, 0).unwrap();
+        time.clamp(time, Time::from_hms(6, 0, 0).unwrap());
+    }
+}
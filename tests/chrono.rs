@@ -0,0 +1,155 @@
+#[cfg(test)]
+#[cfg(feature = "chrono")]
+mod chrono_tests {
+    use astrolabe::{Date, DateTime, DateUtilities, Offset, OffsetUtilities, Time, TimeUtilities};
+    use chrono::{FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike};
+
+    #[test]
+    fn date() {
+        let date = Date::from_ymd(2022, 5, 2).unwrap();
+        let naive = NaiveDate::from_ymd_opt(2022, 5, 2).unwrap();
+        assert_eq!(naive, NaiveDate::try_from(date).unwrap());
+        assert_eq!(date, Date::from(naive));
+
+        // chrono has a year 0, which is year -1 in astrolabe
+        let naive = NaiveDate::from_ymd_opt(0, 12, 31).unwrap();
+        assert_eq!(Date::from_ymd(-1, 12, 31).unwrap(), Date::from(naive));
+        let naive = NaiveDate::from_ymd_opt(-44, 3, 15).unwrap();
+        assert_eq!(Date::from_ymd(-45, 3, 15).unwrap(), Date::from(naive));
+
+        for naive in [
+            NaiveDate::MIN,
+            NaiveDate::MAX,
+            NaiveDate::from_ymd_opt(1, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(0, 2, 29).unwrap(),
+        ] {
+            assert_eq!(naive, NaiveDate::try_from(Date::from(naive)).unwrap());
+        }
+
+        let min = Date::from(NaiveDate::MIN);
+        let max = Date::from(NaiveDate::MAX);
+        assert!(NaiveDate::try_from(min.sub_days(1)).is_err());
+        assert!(NaiveDate::try_from(max.add_days(1)).is_err());
+        assert!(NaiveDate::try_from(Date::from_days(i32::MIN)).is_err());
+        assert!(NaiveDate::try_from(Date::from_days(i32::MAX)).is_err());
+    }
+
+    #[test]
+    fn time() {
+        let time = Time::from_hms(12, 32, 1)
+            .unwrap()
+            .set_nano(123_456_789)
+            .unwrap();
+        let naive = NaiveTime::from_hms_nano_opt(12, 32, 1, 123_456_789).unwrap();
+        assert_eq!(naive, NaiveTime::from(time));
+        assert_eq!(time, Time::try_from(naive).unwrap());
+
+        // The local time is converted
+        let time = time.set_offset(Offset::Fixed(-3600));
+        assert_eq!(
+            NaiveTime::from_hms_nano_opt(11, 32, 1, 123_456_789).unwrap(),
+            NaiveTime::from(time)
+        );
+
+        for naive in [
+            NaiveTime::MIN,
+            NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).unwrap(),
+        ] {
+            assert_eq!(naive, NaiveTime::from(Time::try_from(naive).unwrap()));
+        }
+
+        let leap_second = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_000_000_000).unwrap();
+        assert!(Time::try_from(leap_second).is_err());
+    }
+
+    #[test]
+    fn naive_date_time() {
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 15, 30, 20)
+            .unwrap()
+            .set_nano(1)
+            .unwrap();
+        let naive = NaiveDate::from_ymd_opt(2022, 5, 2)
+            .unwrap()
+            .and_hms_nano_opt(15, 30, 20, 1)
+            .unwrap();
+        assert_eq!(naive, NaiveDateTime::try_from(date_time).unwrap());
+        assert_eq!(date_time, DateTime::try_from(naive).unwrap());
+
+        // The local date and time are converted
+        let date_time = date_time.set_offset(Offset::Fixed(9 * 3600));
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2022, 5, 3)
+                .unwrap()
+                .and_hms_nano_opt(0, 30, 20, 1)
+                .unwrap(),
+            NaiveDateTime::try_from(date_time).unwrap()
+        );
+
+        for naive in [NaiveDateTime::MIN, NaiveDateTime::MAX] {
+            assert_eq!(
+                naive,
+                NaiveDateTime::try_from(DateTime::try_from(naive).unwrap()).unwrap()
+            );
+        }
+
+        let max = DateTime::try_from(NaiveDateTime::MAX).unwrap();
+        assert!(NaiveDateTime::try_from(max.add_nanos(1)).is_err());
+        let min = DateTime::try_from(NaiveDateTime::MIN).unwrap();
+        assert!(NaiveDateTime::try_from(min.sub_nanos(1)).is_err());
+
+        let leap_second = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_milli_opt(23, 59, 59, 1_500)
+            .unwrap();
+        assert!(DateTime::try_from(leap_second).is_err());
+    }
+
+    #[test]
+    fn fixed_offset() {
+        let offset = FixedOffset::east_opt(2 * 3600 + 30 * 60).unwrap();
+        let chrono = offset
+            .with_ymd_and_hms(2022, 5, 2, 15, 30, 20)
+            .unwrap()
+            .with_nanosecond(999_999_999)
+            .unwrap();
+        let date_time = DateTime::try_from(chrono).unwrap();
+        assert_eq!(
+            "2022-05-02T15:30:20.999999999+02:30",
+            date_time.format_rfc3339(astrolabe::Precision::Nanos)
+        );
+        assert_eq!(Offset::Fixed(9000), date_time.get_offset());
+        assert_eq!(chrono, chrono::DateTime::try_from(date_time).unwrap());
+        assert_eq!(
+            chrono.offset(),
+            chrono::DateTime::<FixedOffset>::try_from(date_time)
+                .unwrap()
+                .offset()
+        );
+
+        // Boundary values with the largest offsets
+        for seconds in [-86_399, 0, 86_399] {
+            let offset = FixedOffset::east_opt(seconds).unwrap();
+            for naive in [
+                NaiveDateTime::MIN + chrono::Duration::days(1),
+                NaiveDateTime::MAX - chrono::Duration::days(1),
+            ] {
+                let chrono = offset.from_utc_datetime(&naive);
+                let date_time = DateTime::try_from(chrono).unwrap();
+                assert_eq!(Offset::Fixed(seconds), date_time.get_offset());
+                assert_eq!(chrono, chrono::DateTime::try_from(date_time).unwrap());
+            }
+        }
+
+        // The local date has to be in range as well
+        let max = DateTime::try_from(NaiveDateTime::MAX).unwrap();
+        assert!(chrono::DateTime::<FixedOffset>::try_from(max).is_ok());
+        assert!(
+            chrono::DateTime::<FixedOffset>::try_from(max.set_offset(Offset::Fixed(1))).is_err()
+        );
+        let min = DateTime::try_from(NaiveDateTime::MIN).unwrap();
+        assert!(
+            chrono::DateTime::<FixedOffset>::try_from(min.set_offset(Offset::Fixed(-1))).is_err()
+        );
+        assert!(chrono::DateTime::<FixedOffset>::try_from(max.add_nanos(1)).is_err());
+    }
+}
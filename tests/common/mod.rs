@@ -0,0 +1,28 @@
+// Each test crate only uses some of the helpers
+#![allow(dead_code)]
+
+/// Deterministic xorshift generator, so randomized tests use the same inputs in every run
+pub struct Random(u64);
+
+impl Random {
+    /// Creates a generator from a non-zero seed
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a number in `0..max`
+    pub fn below(&mut self, max: u64) -> u64 {
+        self.next_u64() % max
+    }
+
+    pub fn bytes(&mut self, length: usize) -> Vec<u8> {
+        (0..length).map(|_| self.next_u64() as u8).collect()
+    }
+}
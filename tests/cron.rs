@@ -1,8 +1,11 @@
 #[cfg(test)]
 mod cron_tests {
-    use std::str::FromStr;
+    use std::{str::FromStr, time::Duration};
 
-    use astrolabe::CronSchedule;
+    use astrolabe::{
+        errors::AstrolabeError, CronSchedule, DateTime, Offset, OffsetUtilities, TimeUtilities,
+        Upcoming,
+    };
 
     #[test]
     fn parse() {
@@ -20,10 +23,25 @@ mod cron_tests {
         )
         .is_ok());
         assert!(CronSchedule::from_str("* * * * *").is_ok());
+        assert!(CronSchedule::parse("* * ? * ?").is_ok());
+        assert!(CronSchedule::parse("* * * * * *").is_ok());
+        assert!(CronSchedule::parse("0 0 12 1 1 ? 2025-2030").is_ok());
+        assert!(CronSchedule::parse("0,59 * * ? jan 1,7 1,5879611").is_ok());
+        assert!(CronSchedule::parse("* * * * * sun-sat 2024/2,2030-2040/5,*/3").is_ok());
 
         // Test invalid cron expressions
         assert!(CronSchedule::parse("").is_err());
-        assert!(CronSchedule::parse("* * * * * *").is_err());
+        assert!(CronSchedule::parse("* * * * * * * *").is_err());
+        assert!(CronSchedule::parse("60 * * * * *").is_err());
+        assert!(CronSchedule::parse("* * * * * 0").is_err());
+        assert!(CronSchedule::parse("* * * * * 8").is_err());
+        assert!(CronSchedule::parse("* * * * * * 0").is_err());
+        assert!(CronSchedule::parse("* * * * * * 5879612").is_err());
+        assert!(CronSchedule::parse("* * * * * * 2030-2020").is_err());
+        assert!(CronSchedule::parse("* * * * * * 2020/0").is_err());
+        assert!(CronSchedule::parse("* * * * * * 2020-").is_err());
+        assert!(CronSchedule::parse("* * * * * * bla").is_err());
+        assert!(CronSchedule::parse("? * * * *").is_err());
         assert!(CronSchedule::parse("a a a a a").is_err());
         assert!(CronSchedule::parse("60 * * * *").is_err());
         assert!(CronSchedule::parse("* 24 * * *").is_err());
@@ -47,10 +65,230 @@ mod cron_tests {
         assert!(CronSchedule::from_str("").is_err());
     }
 
+    #[test]
+    fn invalid_format_errors() {
+        // (expression, field, position, token)
+        let cases = [
+            ("* * * * Mondy", "day of week", 8, "Mondy"),
+            ("* * * Janu *", "month", 6, "Janu"),
+            ("* 1,a * * *", "hour", 4, "a"),
+            ("*/0 * * * *", "minute", 0, "*/0"),
+            ("0-  * * * *", "minute", 2, ""),
+            ("5-1 * * * *", "minute", 0, "5-1"),
+            ("*  *  L  *  *", "day of month", 6, "L"),
+            ("* * * * * 1#2", "day of week", 10, "1#2"),
+            ("0 0 12 1 1 ? 2025,2030-x", "year", 18, "2030-x"),
+            ("0 0 12 1 1 ? 2025,2030-", "year", 23, ""),
+        ];
+        for (expression, field, position, token) in cases {
+            match CronSchedule::parse(expression).unwrap_err() {
+                AstrolabeError::InvalidFormat(error) => {
+                    assert_eq!(Some(field), error.field_name(), "{}", expression);
+                    assert_eq!(Some(position), error.position(), "{}", expression);
+                    assert_eq!(Some(token), error.token(), "{}", expression);
+                }
+                error => panic!("Expected invalid format error: {:?}", error),
+            }
+        }
+
+        match CronSchedule::parse("* * * * * * * *").unwrap_err() {
+            AstrolabeError::InvalidFormat(error) => {
+                assert_eq!(None, error.field_name());
+                assert_eq!(None, error.position());
+            }
+            error => panic!("Expected invalid format error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn out_of_range_errors() {
+        // (expression, field, min, max, value)
+        let cases = [
+            ("0 25 * * *", "hour", 0, 23, 25),
+            ("60 * * * *", "minute", 0, 59, 60),
+            ("256 * * * *", "minute", 0, 59, 256),
+            ("* * 0 * *", "day of month", 1, 31, 0),
+            ("* * * 1-13 *", "month", 1, 12, 13),
+            ("* * * * 8", "day of week", 0, 7, 8),
+            ("* * * * * 0", "day of week", 1, 7, 0),
+            ("60 * * * * *", "second", 0, 59, 60),
+            ("* * * * * * 0", "year", 1, 5_879_611, 0),
+            ("* * * * * * 2022,5879612", "year", 1, 5_879_611, 5_879_612),
+            // Days which don't exist in any of the months
+            ("0 0 30 2 *", "day of month", 1, 29, 30),
+            ("0 0 30-31 feb ?", "day of month", 1, 29, 30),
+            ("0 0 31 2,4,6 *", "day of month", 1, 30, 31),
+            ("0 0 0 31 9,11 ? 2025", "day of month", 1, 30, 31),
+        ];
+        for (expression, field, min, max, value) in cases {
+            match CronSchedule::parse(expression).unwrap_err() {
+                AstrolabeError::OutOfRange(error) => {
+                    assert_eq!(field, error.field_name(), "{}", expression);
+                    assert_eq!(min, error.min(), "{}", expression);
+                    assert_eq!(max, error.max(), "{}", expression);
+                    assert_eq!(value, error.value(), "{}", expression);
+                }
+                error => panic!("Expected out of range error: {:?}", error),
+            }
+        }
+
+        // Day of week is combined with OR, so the schedule still has occurrences
+        assert!(CronSchedule::parse("0 0 30 2 mon").is_ok());
+        assert!(CronSchedule::parse("0 0 29 2 *").is_ok());
+        assert!(CronSchedule::parse("0 0 31 1,2 *").is_ok());
+
+        // With AND, the day of month has to exist as well
+        match CronSchedule::parse("0 0 30 2 1")
+            .unwrap()
+            .with_dom_dow_and()
+            .unwrap_err()
+        {
+            AstrolabeError::OutOfRange(error) => {
+                assert_eq!("day of month", error.field_name());
+                assert_eq!(29, error.max());
+                assert_eq!(30, error.value());
+            }
+            error => panic!("Expected out of range error: {:?}", error),
+        }
+        assert!(CronSchedule::parse("0 0 29 2 1")
+            .unwrap()
+            .with_dom_dow_and()
+            .is_ok());
+
+        // Never matching schedules stop searching instead of looping forever
+        let schedule = CronSchedule::parse("0 0 0 29 2 ? 2025/2").unwrap();
+        assert_eq!(None, schedule.next_occurrence());
+    }
+
+    #[test]
+    fn matches() {
+        let schedule = CronSchedule::parse("30 9 * * mon-fri").unwrap();
+        // Monday
+        assert!(schedule.matches(&DateTime::from_ymdhms(2022, 5, 2, 9, 30, 0).unwrap()));
+        // Sunday
+        assert!(!schedule.matches(&DateTime::from_ymdhms(2022, 5, 1, 9, 30, 0).unwrap()));
+        assert!(!schedule.matches(&DateTime::from_ymdhms(2022, 5, 2, 9, 31, 0).unwrap()));
+        assert!(!schedule.matches(&DateTime::from_ymdhms(2022, 5, 2, 10, 30, 0).unwrap()));
+        // Truncated to seconds
+        assert!(schedule.matches(
+            &DateTime::from_ymdhms(2022, 5, 2, 9, 30, 0)
+                .unwrap()
+                .add_nanos(999_999_999)
+        ));
+        assert!(!schedule.matches(&DateTime::from_ymdhms(2022, 5, 2, 9, 30, 1).unwrap()));
+
+        // Steps
+        let schedule = CronSchedule::parse("*/20 */5 * * *").unwrap();
+        for (hour, minute) in [(0, 0), (5, 20), (10, 40), (20, 0)] {
+            assert!(schedule.matches(&DateTime::from_ymdhms(2022, 5, 2, hour, minute, 0).unwrap()));
+        }
+        for (hour, minute) in [(5, 10), (6, 0), (20, 30), (23, 0)] {
+            assert!(!schedule.matches(&DateTime::from_ymdhms(2022, 5, 2, hour, minute, 0).unwrap()));
+        }
+
+        // Ranges and lists
+        let schedule = CronSchedule::parse("0 0 10-15,20 * *").unwrap();
+        assert!(schedule.matches(&DateTime::from_ymdhms(2022, 5, 10, 0, 0, 0).unwrap()));
+        assert!(schedule.matches(&DateTime::from_ymdhms(2022, 5, 15, 0, 0, 0).unwrap()));
+        assert!(schedule.matches(&DateTime::from_ymdhms(2022, 5, 20, 0, 0, 0).unwrap()));
+        assert!(!schedule.matches(&DateTime::from_ymdhms(2022, 5, 16, 0, 0, 0).unwrap()));
+        assert!(!schedule.matches(&DateTime::from_ymdhms(2022, 5, 9, 0, 0, 0).unwrap()));
+
+        // Names
+        let schedule = CronSchedule::parse("0 12 * jan,jul sat,sun").unwrap();
+        // Saturday
+        assert!(schedule.matches(&DateTime::from_ymdhms(2022, 1, 1, 12, 0, 0).unwrap()));
+        // Sunday
+        assert!(schedule.matches(&DateTime::from_ymdhms(2022, 7, 3, 12, 0, 0).unwrap()));
+        // Monday
+        assert!(!schedule.matches(&DateTime::from_ymdhms(2022, 1, 3, 12, 0, 0).unwrap()));
+        // Saturday in another month
+        assert!(!schedule.matches(&DateTime::from_ymdhms(2022, 5, 7, 12, 0, 0).unwrap()));
+
+        // Day of month or day of week if both are restricted
+        let schedule = CronSchedule::parse("0 0 13 * fri").unwrap();
+        // Friday the 6th
+        assert!(schedule.matches(&DateTime::from_ymdhms(2022, 5, 6, 0, 0, 0).unwrap()));
+        // Friday the 13th
+        assert!(schedule.matches(&DateTime::from_ymdhms(2022, 5, 13, 0, 0, 0).unwrap()));
+        // Tuesday the 13th
+        assert!(schedule.matches(&DateTime::from_ymdhms(2022, 9, 13, 0, 0, 0).unwrap()));
+        assert!(!schedule.matches(&DateTime::from_ymdhms(2022, 5, 12, 0, 0, 0).unwrap()));
+        let schedule = schedule.with_dom_dow_and().unwrap();
+        assert!(schedule.matches(&DateTime::from_ymdhms(2022, 5, 13, 0, 0, 0).unwrap()));
+        assert!(!schedule.matches(&DateTime::from_ymdhms(2022, 5, 6, 0, 0, 0).unwrap()));
+        assert!(!schedule.matches(&DateTime::from_ymdhms(2022, 9, 13, 0, 0, 0).unwrap()));
+
+        let schedule = CronSchedule::parse("30 9 * * mon-fri").unwrap();
+
+        // Fields are compared in the offset of the instance
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 7, 30, 0)
+            .unwrap()
+            .set_offset(Offset::from_hms(2, 0, 0).unwrap());
+        assert!(schedule.matches(&date_time));
+
+        let schedule = schedule.with_jitter(Duration::ZERO, 1).unwrap();
+        assert!(schedule.matches(&DateTime::from_ymdhms(2022, 5, 2, 9, 30, 0).unwrap()));
+    }
+
     #[test]
     fn debug() {
         let schedule = CronSchedule::parse("0 0 1 1 0").unwrap();
-        assert_eq!("CronSchedule { minutes: {0}, hours: {0}, days_of_month: {1}, months: {1}, days_of_week: {0}, last_schedule: None }", format!("{:?}", schedule));
+        assert_eq!("CronSchedule { seconds: {0}, minutes: {0}, hours: {0}, days_of_month: {1}, months: {1}, days_of_week: {0}, years: None, days_of_month_wildcard: false, days_of_week_wildcard: false, dom_dow_and: false, max_jitter: 0ns, seed: 0, last_schedule: None }", format!("{:?}", schedule));
+    }
+
+    #[test]
+    fn upcoming() {
+        let schedule = CronSchedule::parse("0 * * * *").unwrap();
+
+        let occurrences = schedule.upcoming_n(5);
+        assert_eq!(5, occurrences.len());
+        for (previous, next) in occurrences.iter().zip(occurrences.iter().skip(1)) {
+            assert_eq!(3600, next.seconds_since(previous));
+        }
+        for occurrence in &occurrences {
+            assert!(schedule.matches(occurrence));
+        }
+        assert_eq!(occurrences[0], schedule.next_occurrence().unwrap());
+
+        // Iterating doesn't modify the schedule
+        let first: Vec<DateTime> = schedule.upcoming().take(5).collect();
+        let second: Vec<DateTime> = (&schedule).into_iter().take(5).collect();
+        assert_eq!(first, second);
+        let mut count = 0;
+        for occurrence in &schedule {
+            assert_eq!(0, occurrence.minute());
+            count += 1;
+            if count == 3 {
+                break;
+            }
+        }
+
+        let schedule = CronSchedule::parse("0 0 12 1 1 ? 2020").unwrap();
+        assert!(schedule.next_occurrence().is_none());
+        assert!(schedule.upcoming_n(5).is_empty());
+        assert_eq!(0, schedule.upcoming().count());
+
+        // Occurrences end at the maximum date
+        let schedule = CronSchedule::parse("0 0 12 1 1 ? 5879611").unwrap();
+        assert_eq!(
+            vec![DateTime::from_ymdhms(5_879_611, 1, 1, 12, 0, 0).unwrap()],
+            schedule.upcoming_n(2)
+        );
+        let schedule = CronSchedule::parse("0 0 0 13 7 ? 5879611").unwrap();
+        assert!(schedule.next_occurrence().is_none());
+        let schedule = CronSchedule::parse("0 0 0 * * ? 5879611").unwrap();
+        assert_eq!(
+            DateTime::from_ymd(5_879_611, 7, 12).unwrap(),
+            schedule.upcoming().last().unwrap()
+        );
+    }
+
+    #[test]
+    fn send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<CronSchedule>();
+        assert_send_sync::<Upcoming>();
     }
 
     #[test]
@@ -59,4 +297,78 @@ mod cron_tests {
         #[allow(clippy::redundant_clone)]
         let _ = schedule.clone();
     }
+
+    #[test]
+    fn jitter_bounds() {
+        let schedule = CronSchedule::parse("* * * * * ?").unwrap();
+        let day = Duration::from_secs(86_400);
+        assert!(schedule.clone().with_jitter(day, 1).is_ok());
+        for max_jitter in [
+            day + Duration::from_nanos(1),
+            Duration::from_secs(1000 * 365 * 86_400),
+            Duration::from_secs(u64::MAX),
+        ] {
+            match schedule.clone().with_jitter(max_jitter, 1).unwrap_err() {
+                AstrolabeError::OutOfRange(error) => {
+                    assert_eq!("max_jitter", error.field_name());
+                    assert_eq!(max_jitter.as_nanos() as i128, error.value());
+                }
+                error => panic!("Unexpected error: {:?}", error),
+            }
+        }
+
+        // The largest jitter on a schedule with an occurrence every second
+        let schedule = schedule.with_jitter(day, 1).unwrap();
+        let next = schedule.next_occurrence().unwrap();
+        assert!(schedule.matches(&next));
+
+        // No panic near the range limits
+        let min = DateTime::from_ymd(-5_879_611, 6, 23).unwrap();
+        schedule.matches(&min);
+        let schedule = CronSchedule::parse("0 0 0 * * ?")
+            .unwrap()
+            .with_jitter(Duration::from_secs(60), 1)
+            .unwrap();
+        assert!(!schedule.matches(&min.add_minutes(2)));
+        assert!(CronSchedule::parse("0 0 0 * * ?").unwrap().matches(&min));
+        assert!(!schedule.matches(&DateTime::from_ymdhms(5_879_611, 7, 12, 23, 59, 59).unwrap()));
+    }
+
+    #[test]
+    fn eq() {
+        let schedule = CronSchedule::parse("*/5 9-17 * * mon-fri").unwrap();
+        assert_eq!(schedule, schedule.clone());
+        assert_eq!(
+            schedule,
+            CronSchedule::parse("0,5,10,15,20,25,30,35,40,45,50,55 9-17 * * 1-5").unwrap()
+        );
+        assert_ne!(schedule, CronSchedule::parse("*/5 9-17 * * *").unwrap());
+        assert_ne!(schedule, schedule.clone().with_dom_dow_and().unwrap());
+        assert_ne!(
+            schedule,
+            schedule
+                .clone()
+                .with_jitter(Duration::from_secs(10), 1)
+                .unwrap()
+        );
+
+        // The iteration state is not compared
+        let mut iterated = schedule.clone();
+        iterated.next();
+        assert_eq!(schedule, iterated);
+
+        // Shared across threads
+        let schedule = std::sync::Arc::new(schedule);
+        let handles = (0..2)
+            .map(|_| {
+                let schedule = schedule.clone();
+                std::thread::spawn(move || {
+                    schedule.matches(&DateTime::from_ymdhms(2022, 5, 2, 9, 5, 0).unwrap())
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            assert!(handle.join().unwrap());
+        }
+    }
 }
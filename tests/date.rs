@@ -1,7 +1,18 @@
+mod common;
+
 #[cfg(test)]
 mod date_tests {
-    use astrolabe::{Date, DateTime, DateUtilities};
-    use std::time::Duration;
+    use crate::common::Random;
+    use astrolabe::{
+        errors::AstrolabeError, Date, DateTime, DateUnit, DateUtilities, Era, FirstWeekday,
+        HolidayCalendar, Locale, MonthOverflow, Offset, OffsetUtilities, ParseOptions, Time,
+        TwoDigitYear, WeekNumbering, Weekday,
+    };
+    use std::{
+        collections::{hash_map::DefaultHasher, HashSet},
+        hash::{Hash, Hasher},
+        time::Duration,
+    };
 
     #[test]
     fn debug() {
@@ -47,9 +58,32 @@ mod date_tests {
         assert_eq!(std::cmp::Ordering::Less, date.cmp(&date_2));
     }
 
+    #[test]
+    fn hash() {
+        let date = Date::from_ymd(2022, 5, 2).unwrap();
+        let date_2 = Date::from_ymd(2022, 5, 2).unwrap();
+        assert_eq!(hash_of(&date), hash_of(&date_2));
+        assert_ne!(hash_of(&date), hash_of(&date.add_days(1)));
+
+        let mut set = HashSet::new();
+        set.insert(date);
+        set.insert(date_2);
+        assert_eq!(1, set.len());
+    }
+
+    fn hash_of(date: &Date) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        date.hash(&mut hasher);
+        hasher.finish()
+    }
+
     #[test]
     fn now() {
         assert!(2021 < Date::now().year());
+        assert!(2021 < Date::today().year());
+        assert!(2021 < Date::today_local().year());
+        // The local date is at most a day apart from the UTC date
+        assert!(Date::today_local().days_since(&Date::today()).abs() <= 1);
     }
 
     #[test]
@@ -84,6 +118,39 @@ mod date_tests {
         from_ymd_err(-5_879_611, 5, 1);
     }
 
+    #[test]
+    fn raw() {
+        assert_eq!(Date::from_ymd(1, 1, 1).unwrap(), Date::from_days(0));
+        assert_eq!(Date::from_ymd(-1, 12, 31).unwrap(), Date::from_days(-1));
+        assert_eq!(
+            Date::from_ymd(-5_879_611, 6, 23).unwrap(),
+            Date::from_days(i32::MIN)
+        );
+        assert_eq!(
+            Date::from_ymd(5_879_611, 7, 12).unwrap(),
+            Date::from_days(i32::MAX)
+        );
+        for days in [i32::MIN, -1, 0, 738_276, i32::MAX] {
+            assert_eq!(days, Date::from_days(days).as_days());
+            assert_eq!(days, Date::from_days(days).days());
+        }
+        // Year 0 is skipped
+        assert_eq!(-366, Date::from_ymd(-1, 1, 1).unwrap().days());
+        assert_eq!(-367, Date::from_ymd(-2, 12, 31).unwrap().days());
+
+        // Ordering of dates matches the ordering of days
+        let dates = [
+            Date::from_ymd(-2, 3, 1).unwrap(),
+            Date::from_ymd(-1, 12, 31).unwrap(),
+            Date::from_ymd(1, 1, 1).unwrap(),
+            Date::from_ymd(2022, 5, 2).unwrap(),
+        ];
+        for pair in dates.windows(2) {
+            assert!(pair[0] < pair[1]);
+            assert!(pair[0].days() < pair[1].days());
+        }
+    }
+
     fn from_ymd_ok(year: i32, month: u32, day: u32) {
         assert_eq!(
             (year, month, day),
@@ -159,6 +226,157 @@ mod date_tests {
         assert_eq!(1, date.weekday());
     }
 
+    #[test]
+    fn weekday() {
+        // 2022-05-01 is a Sunday, 2022-05-02 a Monday and 2022-05-07 a Saturday
+        let expected = [
+            ((2022, 5, 1), 0, 7, 0, "1", "7"),
+            ((2022, 5, 2), 1, 1, 1, "2", "1"),
+            ((2022, 5, 7), 6, 6, 6, "7", "6"),
+            ((-1, 12, 31), 0, 7, 0, "1", "7"),
+        ];
+        for ((year, month, day), weekday, iso, sunday0, e, e7) in expected {
+            let date = Date::from_ymd(year, month, day).unwrap();
+            assert_eq!(weekday, date.weekday());
+            assert_eq!(iso, date.weekday_iso());
+            assert_eq!(sunday0, date.weekday_sunday0());
+            assert_eq!(e, date.format("e"));
+            assert_eq!(e7, date.format("eeeeeee"));
+        }
+    }
+
+    #[test]
+    fn week_of_year() {
+        let sunday_locale = Locale {
+            first_weekday: FirstWeekday::On(Weekday::Sunday),
+            ..Locale::ENGLISH
+        };
+        // (year, month, day, ISO, Sunday start, simple)
+        let cases = [
+            (2015, 12, 31, 53, 53, 53),
+            (2016, 1, 1, 53, 1, 1),
+            (2021, 1, 1, 53, 1, 1),
+            (2016, 1, 3, 53, 2, 1),
+            (2016, 1, 4, 1, 2, 1),
+            (2016, 1, 7, 1, 2, 1),
+            (2016, 1, 8, 1, 2, 2),
+            (2019, 12, 30, 1, 53, 52),
+            (2000, 12, 31, 52, 54, 53),
+            (2022, 5, 2, 18, 19, 18),
+        ];
+        for (year, month, day, iso, sunday_start, simple) in cases {
+            let date = Date::from_ymd(year, month, day).unwrap();
+            assert_eq!(iso, date.week_of_year(WeekNumbering::Iso));
+            assert_eq!(sunday_start, date.week_of_year(WeekNumbering::SundayStart));
+            assert_eq!(simple, date.week_of_year(WeekNumbering::SimpleJan1));
+
+            // The format symbol and the parser agree with the getter
+            assert_eq!(iso.to_string(), date.format("w"));
+            assert_eq!(
+                sunday_start.to_string(),
+                date.format_localized("w", &sunday_locale)
+            );
+            let string = date.format("yyyy-MM-dd ww");
+            assert_eq!(date, Date::parse_checked(&string, "yyyy-MM-dd ww").unwrap());
+            let string = format!("{} {:02}", date.format("yyyy-MM-dd"), sunday_start);
+            let options = ParseOptions {
+                locale: sunday_locale,
+                reject_conflicts: true,
+                ..Default::default()
+            };
+            assert_eq!(
+                date,
+                Date::parse_with(&string, "yyyy-MM-dd ww", &options).unwrap()
+            );
+            if iso != sunday_start {
+                assert!(Date::parse_checked(&string, "yyyy-MM-dd ww").is_err());
+            }
+        }
+
+        // Sunday start weeks begin on the 1st of January and on every Sunday
+        for year in [2000, 2015, 2016, 2021, 2022, 2023] {
+            let mut expected = 1;
+            let mut date = Date::from_ymd(year, 1, 1).unwrap();
+            while date.year() == year {
+                if date.day_of_year() > 1 && date.weekday() == Weekday::Sunday as u8 {
+                    expected += 1;
+                }
+                assert_eq!(expected, date.week_of_year(WeekNumbering::SundayStart));
+                assert_eq!(
+                    (date.day_of_year() - 1) / 7 + 1,
+                    date.week_of_year(WeekNumbering::SimpleJan1)
+                );
+                date = date.add_days(1);
+            }
+        }
+
+        // Weeks of a `DateTime` are based on its local date
+        let date_time = DateTime::from_ymdhms(2016, 1, 1, 0, 30, 0).unwrap();
+        assert_eq!(1, date_time.week_of_year(WeekNumbering::SundayStart));
+        let date_time = date_time.set_offset(Offset::from_hms(-1, 0, 0).unwrap());
+        assert_eq!(53, date_time.week_of_year(WeekNumbering::SundayStart));
+        assert_eq!(53, date_time.week_of_year(WeekNumbering::Iso));
+    }
+
+    #[test]
+    fn week_of_month() {
+        // 2023-01 starts on Sunday, 2022-08 on Monday, 2022-11 on Tuesday, 2022-06 on Wednesday,
+        // 2022-09 on Thursday, 2022-07 on Friday and 2022-10 on Saturday
+        let months = [
+            (2023, 1),
+            (2022, 8),
+            (2022, 11),
+            (2022, 6),
+            (2022, 9),
+            (2022, 7),
+            (2022, 10),
+        ];
+        for (first_weekday, (year, month)) in months.into_iter().enumerate() {
+            assert_eq!(
+                first_weekday as u8,
+                Date::from_ymd(year, month, 1).unwrap().weekday()
+            );
+
+            for week_start in 0..7 {
+                let week_start = Weekday::try_from(week_start).unwrap();
+                let mut expected = 1;
+                for day in 1..=31 {
+                    let date = match Date::from_ymd(year, month, day) {
+                        Ok(date) => date,
+                        Err(_) => break,
+                    };
+                    if day > 1 && date.weekday() == week_start as u8 {
+                        expected += 1;
+                    }
+                    assert_eq!(expected, date.week_of_month_with(week_start));
+                    if week_start == Weekday::Sunday {
+                        assert_eq!(expected, date.week_of_month());
+                    }
+                }
+            }
+        }
+
+        // A 31-day month starting on Saturday spans 6 partial weeks
+        let week = |day| Date::from_ymd(2022, 10, day).unwrap().week_of_month();
+        assert_eq!(1, week(1));
+        assert_eq!(2, week(2));
+        assert_eq!(2, week(8));
+        assert_eq!(5, week(29));
+        assert_eq!(6, week(30));
+        assert_eq!(6, week(31));
+        let week = |day| {
+            Date::from_ymd(2022, 10, day)
+                .unwrap()
+                .week_of_month_with(Weekday::Monday)
+        };
+        assert_eq!(1, week(2));
+        assert_eq!(2, week(3));
+        assert_eq!(6, week(31));
+
+        // February of a non-leap year starting on the week start has exactly four weeks
+        assert_eq!(4, Date::from_ymd(2015, 2, 28).unwrap().week_of_month());
+    }
+
     #[test]
     fn timestamp() {
         assert_eq!(0, Date::from_timestamp(0).timestamp());
@@ -207,6 +425,12 @@ mod date_tests {
         assert_eq!("1971-01-01", modified.format("yyyy-MM-dd"));
         let modified = date.add_months(14);
         assert_eq!("1971-03-01", modified.format("yyyy-MM-dd"));
+        let modified = Date::from_ymd(2022, 3, 31).unwrap().sub_months(5);
+        assert_eq!("2021-10-31", modified.format("yyyy-MM-dd"));
+        let modified = Date::from_ymd(2022, 3, 31).unwrap().sub_months(13);
+        assert_eq!("2021-02-28", modified.format("yyyy-MM-dd"));
+        let modified = Date::from_ymd(1, 3, 31).unwrap().sub_months(5);
+        assert_eq!("-0001-10-31", modified.format("yyyy-MM-dd"));
 
         // Leap year cases
         let modified = date.add_days(30);
@@ -266,6 +490,29 @@ mod date_tests {
         assert_eq!("0001-02-01", date.add_months(1).format("yyyy-MM-dd"));
     }
 
+    #[test]
+    fn saturating() {
+        let date = Date::from_ymd(2022, 5, 2).unwrap();
+        assert_eq!(date, date.saturating_add_days(0));
+        assert_eq!(date.add_years(3), date.saturating_add_years(3));
+        assert_eq!(date.sub_months(14), date.saturating_sub_months(14));
+
+        let max = Date::from_ymd(5_879_611, 7, 12).unwrap();
+        let min = Date::from_ymd(-5_879_611, 6, 23).unwrap();
+        assert_eq!(max, date.saturating_add_years(u32::MAX));
+        assert_eq!(max, date.saturating_add_years(5_877_590));
+        assert_eq!(max, date.saturating_add_months(u32::MAX));
+        assert_eq!(max, date.saturating_add_quarters(u32::MAX));
+        assert_eq!(max, date.saturating_add_days(u32::MAX));
+        assert_eq!(max, max.saturating_add_days(1));
+        assert_eq!(min, date.saturating_sub_years(u32::MAX));
+        assert_eq!(min, date.saturating_sub_months(u32::MAX));
+        assert_eq!(min, date.saturating_sub_quarters(u32::MAX));
+        assert_eq!(min, date.saturating_sub_days(u32::MAX));
+        assert_eq!(min, min.saturating_sub_days(1));
+        assert_eq!(max.sub_days(1), max.saturating_sub_days(1));
+    }
+
     #[test]
     #[should_panic]
     fn add_overflow_days() {
@@ -308,6 +555,65 @@ mod date_tests {
         date.sub_years(1);
     }
 
+    #[test]
+    #[should_panic]
+    fn add_overflow_quarters() {
+        let date = Date::from_ymd(5_879_611, 4, 13).unwrap();
+        date.add_quarters(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sub_underflow_quarters() {
+        let date = Date::from_ymd(-5_879_611, 9, 22).unwrap();
+        date.sub_quarters(1);
+    }
+
+    #[test]
+    fn quarter() {
+        let date = |year, month, day| Date::from_ymd(year, month, day).unwrap();
+
+        for (month, quarter) in [
+            (1, 1),
+            (3, 1),
+            (4, 2),
+            (6, 2),
+            (7, 3),
+            (9, 3),
+            (10, 4),
+            (12, 4),
+        ] {
+            assert_eq!(quarter, date(2022, month, 15).quarter());
+        }
+
+        // Quarter boundaries
+        assert_eq!(date(2022, 1, 1), date(2022, 1, 1).first_day_of_quarter());
+        assert_eq!(date(2022, 1, 1), date(2022, 3, 31).first_day_of_quarter());
+        assert_eq!(date(2022, 3, 31), date(2022, 1, 1).last_day_of_quarter());
+        assert_eq!(date(2022, 6, 30), date(2022, 4, 1).last_day_of_quarter());
+        assert_eq!(date(2022, 10, 1), date(2022, 12, 31).first_day_of_quarter());
+        assert_eq!(date(2022, 12, 31), date(2022, 10, 1).last_day_of_quarter());
+        assert_eq!(date(-1, 12, 31), date(-1, 11, 5).last_day_of_quarter());
+
+        // Crossing years
+        assert_eq!(date(2023, 2, 15), date(2022, 11, 15).add_quarters(1));
+        assert_eq!(date(2022, 11, 15), date(2023, 2, 15).sub_quarters(1));
+        assert_eq!(date(1, 2, 15), date(-1, 11, 15).add_quarters(1));
+        assert_eq!(date(-1, 11, 15), date(1, 2, 15).sub_quarters(1));
+        assert_eq!(date(2032, 5, 2), date(2022, 5, 2).add_quarters(40));
+
+        // Day of month is clamped like with months
+        assert_eq!(date(2024, 2, 29), date(2023, 11, 30).add_quarters(1));
+        assert_eq!(date(2023, 2, 28), date(2022, 11, 30).add_quarters(1));
+        assert_eq!(date(2024, 2, 29), date(2024, 5, 31).sub_quarters(1));
+        assert_eq!(date(2024, 6, 30), date(2024, 3, 31).add_quarters(1));
+        for quarters in 0..12 {
+            let start = date(2023, 8, 31);
+            assert_eq!(start.add_months(quarters * 3), start.add_quarters(quarters));
+            assert_eq!(start.sub_months(quarters * 3), start.sub_quarters(quarters));
+        }
+    }
+
     #[test]
     fn set() {
         let date = Date::from_ymd(2000, 5, 10).unwrap();
@@ -447,6 +753,553 @@ mod date_tests {
         assert_eq!(0, date.years_since(&date2));
         assert_eq!(0, date.months_since(&date2));
         assert_eq!(-1, date.days_since(&date2));
+        let date = Date::from_ymd(2022, 3, 31).unwrap();
+        let date2 = Date::from_ymd(2022, 5, 1).unwrap();
+        assert_eq!(1, date2.months_since(&date));
+        assert_eq!(-1, date.months_since(&date2));
+        // The day of year is shifted by one after the 29th of February of a leap year
+        let date = Date::from_ymd(2020, 5, 2).unwrap();
+        let date2 = Date::from_ymd(2022, 5, 2).unwrap();
+        assert_eq!(2, date2.years_since(&date));
+        assert_eq!(-2, date.years_since(&date2));
+        let date2 = Date::from_ymd(2021, 5, 1).unwrap();
+        assert_eq!(0, date2.years_since(&date));
+        assert_eq!(0, date.years_since(&date2));
+    }
+
+    #[test]
+    fn ordinal() {
+        // (year, month, day, ordinal date)
+        let cases = [
+            (2022, 1, 5, "2022-005"),
+            (2022, 1, 24, "2022-024"),
+            (2022, 5, 15, "2022-135"),
+            (2022, 12, 31, "2022-365"),
+            (2024, 12, 31, "2024-366"),
+            (2024, 2, 29, "2024-060"),
+            (-44, 3, 15, "-0044-074"),
+        ];
+        for (year, month, day, ordinal) in cases {
+            let date = Date::from_ymd(year, month, day).unwrap();
+            assert_eq!(ordinal, date.format_ordinal());
+            assert_eq!(date, Date::parse_ordinal(ordinal).unwrap());
+        }
+
+        assert!(Date::parse_ordinal("2022-366").is_err());
+        assert!(Date::parse_ordinal("2022-000").is_err());
+        assert!(Date::parse_ordinal("2022-5").is_err());
+        assert!(Date::parse_ordinal("2022-05").is_err());
+        assert!(Date::parse_ordinal("2022-1355").is_err());
+        assert!(Date::parse_ordinal("2022-135 ").is_err());
+        assert!(Date::parse_ordinal("2022/135").is_err());
+    }
+
+    #[test]
+    fn parse_auto() {
+        let expected = Date::from_ymd(2022, 5, 2).unwrap();
+        let current_century = Date::now().year() / 100 * 100;
+        let cases = ["2022-05-02", "02.05.2022", "02 May 2022", "2 May 2022"];
+        for string in cases {
+            assert_eq!(expected, Date::parse_auto(string).unwrap());
+        }
+        assert_eq!(
+            Date::from_ymd(current_century + 22, 5, 2).unwrap(),
+            Date::parse_auto("02 May 22").unwrap()
+        );
+        assert_eq!(
+            Date::from_ymd(2022, 9, 2).unwrap(),
+            Date::parse_auto("2 September 2022").unwrap()
+        );
+
+        assert!(Date::parse_auto("May 2 2022").is_err());
+        assert!(Date::parse_auto("02 May 2022 ").is_err());
+        assert!(Date::parse_auto("").is_err());
+    }
+
+    #[test]
+    fn two_digit_year() {
+        let options = |two_digit_year| ParseOptions {
+            two_digit_year,
+            ..Default::default()
+        };
+        let pivot = options(TwoDigitYear::StartingAt(1950));
+        let century = options(TwoDigitYear::StartingAt(1900));
+
+        assert_eq!(
+            Date::from_ymd(2022, 5, 2).unwrap(),
+            Date::parse_auto_with("02 May 22", &pivot).unwrap()
+        );
+        assert_eq!(
+            Date::from_ymd(1922, 5, 2).unwrap(),
+            Date::parse_auto_with("02 May 22", &century).unwrap()
+        );
+        assert_eq!(
+            Date::from_ymd(1972, 5, 2).unwrap(),
+            Date::parse_auto_with("2 May 72", &pivot).unwrap()
+        );
+        // Four digit years are not affected
+        assert_eq!(
+            Date::from_ymd(2022, 5, 2).unwrap(),
+            Date::parse_auto_with("2 May 2022", &century).unwrap()
+        );
+
+        assert_eq!(
+            Date::from_ymd(1950, 5, 2).unwrap(),
+            Date::parse_with("02 May 50", "dd MMM yy", &pivot).unwrap()
+        );
+        assert_eq!(
+            Date::from_ymd(2049, 5, 2).unwrap(),
+            Date::parse_with("02 May 49", "dd MMM yy", &pivot).unwrap()
+        );
+        assert_eq!(
+            Date::from_ymd(1999, 5, 2).unwrap(),
+            Date::parse_with("02 May 99", "dd MMM yy", &century).unwrap()
+        );
+        assert_eq!(
+            Date::from_ymd(1900, 5, 2).unwrap(),
+            Date::parse_with("02 May 00", "dd MMM yy", &century).unwrap()
+        );
+        assert_eq!(
+            TwoDigitYear::CurrentCentury,
+            ParseOptions::default().two_digit_year
+        );
+    }
+
+    #[test]
+    fn julian_day() {
+        // (year, month, day, julian day)
+        let cases = [
+            (2000, 1, 1, 2_451_545),
+            (2022, 5, 2, 2_459_702),
+            (1858, 11, 17, 2_400_001),
+            (1970, 1, 1, 2_440_588),
+            (1, 1, 1, 1_721_426),
+            (-1, 12, 31, 1_721_425),
+            (-4714, 11, 24, 0),
+            (-4714, 11, 23, -1),
+        ];
+        for (year, month, day, julian_day) in cases {
+            let date = Date::from_ymd(year, month, day).unwrap();
+            assert_eq!(julian_day, date.julian_day());
+            assert_eq!(date, Date::from_julian_day(julian_day).unwrap());
+        }
+
+        let min = Date::from_ymd(-5879611, 6, 23).unwrap();
+        let max = Date::from_ymd(5879611, 7, 12).unwrap();
+        let step = (max.julian_day() - min.julian_day()) / 997;
+        let mut julian_day = min.julian_day();
+        while julian_day <= max.julian_day() {
+            let date = Date::from_julian_day(julian_day).unwrap();
+            assert_eq!(julian_day, date.julian_day());
+            assert_eq!(
+                date,
+                Date::from_ymd(date.year(), date.month(), date.day()).unwrap()
+            );
+            julian_day += step;
+        }
+        assert_eq!(min, Date::from_julian_day(min.julian_day()).unwrap());
+        assert_eq!(max, Date::from_julian_day(max.julian_day()).unwrap());
+
+        assert!(Date::from_julian_day(min.julian_day() - 1).is_err());
+        assert!(Date::from_julian_day(max.julian_day() + 1).is_err());
+        assert!(Date::from_julian_day(i64::MIN).is_err());
+        assert!(Date::from_julian_day(i64::MAX).is_err());
+    }
+
+    #[test]
+    fn calendar_round_trip() {
+        // Every day of a few years around the leap year rule boundaries
+        let ranges = [
+            (-5_879_611, -5_879_609),
+            (-805, -795),
+            (-405, -395),
+            (-105, -95),
+            (-5, 5),
+            (95, 105),
+            (395, 405),
+            (1595, 1605),
+            (1695, 1705),
+            (1895, 1905),
+            (1995, 2005),
+            (2095, 2105),
+            (2395, 2405),
+            (5_879_609, 5_879_611),
+        ];
+        for (start, end) in ranges {
+            assert_consecutive_days(start, end);
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn calendar_round_trip_full_range() {
+        let (min, max) = (-5_879_611, 5_879_611);
+        for year in (min..=max).filter(|year| *year != 0) {
+            let next_year = if year == -1 { 1 } else { year + 1 };
+            let last_february_day = if is_leap_year(year) { 29 } else { 28 };
+            let dates = [
+                (year, 1, 1),
+                (year, 2, last_february_day),
+                (year, 3, 1),
+                (year, 6, 30),
+                (year, 7, 1),
+                (year, 12, 31),
+                (next_year, 1, 1),
+            ];
+            let in_range = |(year, month, day): (i32, u32, u32)| {
+                (year, month, day) >= (min, 6, 23) && (year, month, day) <= (max, 7, 12)
+            };
+
+            let mut previous: Option<((i32, u32, u32), Date)> = None;
+            for date in dates.into_iter().filter(|date| in_range(*date)) {
+                let parsed = Date::from_ymd(date.0, date.1, date.2).unwrap();
+                assert_eq!(date, parsed.as_ymd());
+                if let Some((previous_date, previous)) = previous {
+                    assert_eq!(
+                        days_between(previous_date, date),
+                        parsed.julian_day() - previous.julian_day(),
+                        "{:?} to {:?}",
+                        previous_date,
+                        date
+                    );
+                }
+                previous = Some((date, parsed));
+            }
+        }
+    }
+
+    /// Walks through every day of the given years and checks that the day numbers and calendar dates are consecutive
+    fn assert_consecutive_days(start_year: i32, end_year: i32) {
+        let start = Date::from_ymd(start_year, 1, 1)
+            .unwrap_or_else(|_| Date::from_ymd(start_year, 6, 23).unwrap());
+        let end = Date::from_ymd(end_year, 12, 31)
+            .unwrap_or_else(|_| Date::from_ymd(end_year, 7, 12).unwrap());
+
+        let mut expected = start.as_ymd();
+        for julian_day in start.julian_day()..=end.julian_day() {
+            let date = Date::from_julian_day(julian_day).unwrap();
+            assert_eq!(expected, date.as_ymd(), "julian day {}", julian_day);
+            assert_eq!(
+                date,
+                Date::from_ymd(expected.0, expected.1, expected.2).unwrap()
+            );
+            expected = next_date(expected);
+        }
+    }
+
+    /// Leap year rule of the proleptic Gregorian calendar. As there is no year 0, the year -1 is a leap year.
+    fn is_leap_year(year: i32) -> bool {
+        let year = if year < 0 { year + 1 } else { year };
+        year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+    }
+
+    fn month_days(year: i32, month: u32) -> u32 {
+        match month {
+            2 if is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    fn next_date((year, month, day): (i32, u32, u32)) -> (i32, u32, u32) {
+        if day < month_days(year, month) {
+            (year, month, day + 1)
+        } else if month < 12 {
+            (year, month + 1, 1)
+        } else if year == -1 {
+            (1, 1, 1)
+        } else {
+            (year + 1, 1, 1)
+        }
+    }
+
+    /// Counts the days between two dates of the same or consecutive years
+    fn days_between(start: (i32, u32, u32), end: (i32, u32, u32)) -> i64 {
+        let day_of_year = |(year, month, day): (i32, u32, u32)| {
+            (1..month).map(|month| month_days(year, month)).sum::<u32>() + day
+        };
+        if start.0 == end.0 {
+            day_of_year(end) as i64 - day_of_year(start) as i64
+        } else {
+            let year_days = if is_leap_year(start.0) { 366 } else { 365 };
+            (year_days - day_of_year(start) + day_of_year(end)) as i64
+        }
+    }
+
+    #[test]
+    fn nth_weekday_of_month() {
+        // (year, month, weekday, n, expected day)
+        let cases = [
+            (2022, 5, Weekday::Tuesday, 2, 10),
+            (2022, 11, Weekday::Thursday, 4, 24),
+            (2022, 5, Weekday::Sunday, 1, 1),
+            (2022, 5, Weekday::Tuesday, 5, 31),
+            (2024, 2, Weekday::Thursday, 1, 1),
+            (2024, 2, Weekday::Thursday, 5, 29),
+            (2021, 2, Weekday::Monday, 4, 22),
+            (2000, 2, Weekday::Tuesday, 5, 29),
+            (1, 1, Weekday::Monday, 1, 1),
+            (-1, 12, Weekday::Sunday, 5, 31),
+            (-1, 12, Weekday::Monday, 1, 4),
+        ];
+        for (year, month, weekday, n, day) in cases {
+            let date = Date::nth_weekday_of_month(year, month, weekday, n).unwrap();
+            assert_eq!((year, month, day), date.as_ymd());
+            assert_eq!(u8::from(weekday), date.weekday());
+        }
+
+        // Months without a fifth occurrence
+        assert!(Date::nth_weekday_of_month(2024, 2, Weekday::Friday, 5).is_err());
+        assert!(Date::nth_weekday_of_month(2021, 2, Weekday::Monday, 5).is_err());
+        assert!(Date::nth_weekday_of_month(2022, 5, Weekday::Monday, 6).is_err());
+        assert!(Date::nth_weekday_of_month(2022, 5, Weekday::Monday, 0).is_err());
+
+        assert!(Date::nth_weekday_of_month(2022, 0, Weekday::Monday, 1).is_err());
+        assert!(Date::nth_weekday_of_month(2022, 13, Weekday::Monday, 1).is_err());
+        assert!(Date::nth_weekday_of_month(5879612, 1, Weekday::Monday, 1).is_err());
+    }
+
+    #[test]
+    fn last_weekday_of_month() {
+        // (year, month, weekday, expected day)
+        let cases = [
+            (2022, 5, Weekday::Monday, 30),
+            (2022, 5, Weekday::Tuesday, 31),
+            (2022, 5, Weekday::Wednesday, 25),
+            (2024, 2, Weekday::Thursday, 29),
+            (2024, 2, Weekday::Friday, 23),
+            (2023, 2, Weekday::Tuesday, 28),
+            (2021, 2, Weekday::Sunday, 28),
+            (-1, 12, Weekday::Sunday, 31),
+            (-1, 12, Weekday::Saturday, 30),
+        ];
+        for (year, month, weekday, day) in cases {
+            let date = Date::last_weekday_of_month(year, month, weekday).unwrap();
+            assert_eq!((year, month, day), date.as_ymd());
+        }
+
+        assert!(Date::last_weekday_of_month(2022, 13, Weekday::Monday).is_err());
+        // Last Saturday of July 5879611 is after the maximum date
+        assert!(Date::last_weekday_of_month(5879611, 7, Weekday::Saturday).is_err());
+    }
+
+    #[test]
+    fn weekday_conversion() {
+        for value in 0..7 {
+            assert_eq!(value, u8::from(Weekday::try_from(value).unwrap()));
+        }
+        assert_eq!(Weekday::Sunday, Weekday::try_from(0).unwrap());
+        assert!(Weekday::try_from(7).is_err());
+
+        // Negative days
+        assert_eq!(0, Date::from_ymd(-1, 12, 31).unwrap().weekday());
+        assert_eq!(6, Date::from_ymd(-1, 12, 30).unwrap().weekday());
+    }
+
+    #[test]
+    fn occurrence_of() {
+        const WEEKDAYS: [Weekday; 7] = [
+            Weekday::Sunday,
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+            Weekday::Saturday,
+        ];
+        // Sunday, 1. May 2022
+        let sunday = Date::from_ymd(2022, 5, 1).unwrap();
+
+        for start in 0..7 {
+            let date = sunday.add_days(start);
+            for weekday in WEEKDAYS {
+                let target = u8::from(weekday) as u32;
+                let until = (target + 7 - start) % 7;
+                let since = (start + 7 - target) % 7;
+
+                let next = date.next_occurrence_of(weekday);
+                assert_eq!(
+                    if until == 0 { 7 } else { until } as i64,
+                    next.days_since(&date)
+                );
+                assert_eq!(
+                    until as i64,
+                    date.next_occurrence_of_or_same(weekday).days_since(&date)
+                );
+
+                let previous = date.previous_occurrence_of(weekday);
+                assert_eq!(
+                    if since == 0 { 7 } else { since } as i64,
+                    date.days_since(&previous)
+                );
+                assert_eq!(
+                    since as i64,
+                    date.days_since(&date.previous_occurrence_of_or_same(weekday))
+                );
+
+                for result in [
+                    next,
+                    previous,
+                    date.next_occurrence_of_or_same(weekday),
+                    date.previous_occurrence_of_or_same(weekday),
+                ] {
+                    assert_eq!(u8::from(weekday), result.weekday());
+                }
+            }
+        }
+
+        // Negative days
+        let date = Date::from_ymd(1, 1, 1).unwrap();
+        assert_eq!(
+            Date::from_ymd(-1, 12, 31).unwrap(),
+            date.previous_occurrence_of(Weekday::Sunday)
+        );
+        assert_eq!(
+            Date::from_ymd(-1, 12, 25).unwrap(),
+            date.previous_occurrence_of(Weekday::Monday)
+        );
+    }
+
+    #[test]
+    fn is_weekend() {
+        // Monday, 2. May 2022
+        let monday = Date::from_ymd(2022, 5, 2).unwrap();
+        let weekend = (0..7)
+            .map(|days| monday.add_days(days).is_weekend())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![false, false, false, false, false, true, true], weekend);
+
+        // Monday, 1. January 0001 and Sunday, 31. December -0001
+        assert!(!Date::from_ymd(1, 1, 1).unwrap().is_weekend());
+        assert!(Date::from_ymd(-1, 12, 31).unwrap().is_weekend());
+        assert!(Date::from_ymd(-1, 12, 30).unwrap().is_weekend());
+        assert!(!Date::from_ymd(-1, 12, 29).unwrap().is_weekend());
+    }
+
+    #[test]
+    fn business_days() {
+        // Friday, 6. May 2022
+        let friday = Date::from_ymd(2022, 5, 6).unwrap();
+        let saturday = friday.add_days(1);
+        let sunday = friday.add_days(2);
+        let monday = friday.add_days(3);
+
+        assert_eq!(friday, friday.add_business_days(0));
+        assert_eq!(monday, friday.add_business_days(1));
+        assert_eq!(friday.add_days(7), friday.add_business_days(5));
+        assert_eq!(friday.add_days(11), friday.add_business_days(7));
+        assert_eq!(friday, monday.sub_business_days(1));
+        assert_eq!(friday.sub_days(7), friday.sub_business_days(5));
+
+        // Starting on a weekend rolls to the next (or previous) business day first
+        assert_eq!(monday, saturday.add_business_days(0));
+        assert_eq!(monday, sunday.add_business_days(0));
+        assert_eq!(monday.add_days(1), saturday.add_business_days(1));
+        assert_eq!(friday, saturday.sub_business_days(0));
+        assert_eq!(friday, sunday.sub_business_days(0));
+        assert_eq!(friday.sub_days(1), sunday.sub_business_days(1));
+
+        assert_eq!(0, friday.business_days_since(&friday));
+        assert_eq!(1, monday.business_days_since(&friday));
+        assert_eq!(-1, friday.business_days_since(&monday));
+        assert_eq!(1, saturday.business_days_since(&friday));
+        assert_eq!(0, monday.business_days_since(&saturday));
+        assert_eq!(5, friday.add_days(7).business_days_since(&friday));
+        assert_eq!(260, friday.add_days(364).business_days_since(&friday));
+    }
+
+    #[test]
+    fn business_days_with_holidays() {
+        // Thursday, 22. December 2022
+        let thursday = Date::from_ymd(2022, 12, 22).unwrap();
+        let calendar = [(2022, 12, 24), (2022, 12, 26), (2022, 12, 27), (2023, 1, 2)]
+            .into_iter()
+            .map(|(year, month, day)| Date::from_ymd(year, month, day).unwrap())
+            .collect::<HolidayCalendar>();
+        assert_eq!(4, calendar.len());
+
+        let expected = [
+            (0, "2022/12/22"),
+            (1, "2022/12/23"),
+            (2, "2022/12/28"),
+            (3, "2022/12/29"),
+            (5, "2023/01/03"),
+        ];
+        for (business_days, result) in expected {
+            let date = thursday.add_business_days_with(business_days, &calendar);
+            assert_eq!(result, date.to_string());
+            assert_eq!(
+                business_days as i64,
+                date.business_days_since_with(&thursday, &calendar)
+            );
+            assert_eq!(
+                thursday,
+                date.sub_business_days_with(business_days, &calendar)
+            );
+        }
+
+        // Starting on a holiday rolls to the next (or previous) business day first
+        let holiday = Date::from_ymd(2022, 12, 26).unwrap();
+        assert_eq!(
+            "2022/12/28",
+            holiday.add_business_days_with(0, &calendar).to_string()
+        );
+        assert_eq!(
+            "2022/12/23",
+            holiday.sub_business_days_with(0, &calendar).to_string()
+        );
+        assert_eq!(
+            -1,
+            holiday.business_days_since_with(&holiday.add_days(3), &calendar)
+        );
+    }
+
+    #[test]
+    fn business_days_randomized() {
+        let mut random = Random::new(0x2545_f491_4f6c_dd1d);
+        let start = Date::from_ymd(1970, 1, 1).unwrap();
+        let calendar = (0..200)
+            .map(|_| start.add_days(random.below(20_000) as u32))
+            .collect::<HolidayCalendar>();
+
+        for _ in 0..1000 {
+            let date = start.add_days(random.below(20_000) as u32);
+            let business_days = random.below(1_000) as u32;
+
+            let added = date.add_business_days_with(business_days, &calendar);
+            assert!(!added.is_weekend() && !calendar.contains(&added));
+            let rolled = date.add_business_days_with(0, &calendar);
+            assert_eq!(
+                business_days as i64,
+                added.business_days_since_with(&rolled, &calendar)
+            );
+
+            // Reference implementation walking day by day
+            let mut expected = rolled;
+            let mut remaining = business_days;
+            while remaining > 0 {
+                expected = expected.add_days(1);
+                if !expected.is_weekend() && !calendar.contains(&expected) {
+                    remaining -= 1;
+                }
+            }
+            assert_eq!(expected, added);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_overflow_business_days() {
+        let date = Date::from_ymd(5_879_611, 7, 1).unwrap();
+        date.add_business_days(10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sub_underflow_business_days() {
+        let date = Date::from_ymd(-5_879_611, 6, 30).unwrap();
+        date.sub_business_days(10);
     }
 
     #[test]
@@ -473,6 +1326,54 @@ mod date_tests {
         assert_eq!(-2, date.year());
     }
 
+    #[test]
+    fn century_decade_era() {
+        let cases = [
+            (-5_879_610, -58_797, -587_961, Era::BC),
+            (-1001, -11, -101, Era::BC),
+            (-1000, -10, -100, Era::BC),
+            (-101, -2, -11, Era::BC),
+            (-100, -1, -10, Era::BC),
+            (-11, -1, -2, Era::BC),
+            (-10, -1, -1, Era::BC),
+            (-5, -1, -1, Era::BC),
+            (-1, -1, -1, Era::BC),
+            (1, 1, 0, Era::AD),
+            (9, 1, 0, Era::AD),
+            (10, 1, 1, Era::AD),
+            (100, 1, 10, Era::AD),
+            (101, 2, 10, Era::AD),
+            (1999, 20, 199, Era::AD),
+            (2000, 20, 200, Era::AD),
+            (2001, 21, 200, Era::AD),
+            (2029, 21, 202, Era::AD),
+            (2030, 21, 203, Era::AD),
+            (5_879_610, 58_797, 587_961, Era::AD),
+        ];
+        for (year, century, decade, era) in cases {
+            for date in [
+                Date::from_ymd(year, 1, 1).unwrap(),
+                Date::from_ymd(year, 12, 31).unwrap(),
+            ] {
+                assert_eq!(century, date.century(), "century of {}", year);
+                assert_eq!(decade, date.decade(), "decade of {}", year);
+                assert_eq!(era, date.era(), "era of {}", year);
+            }
+        }
+
+        // The era matches the `G` format symbol
+        let last_bc = Date::from_ymd(-1, 12, 31).unwrap();
+        let first_ad = last_bc.add_days(1);
+        assert_eq!(Era::BC, last_bc.era());
+        assert_eq!("BC", last_bc.format("G"));
+        assert_eq!(Era::AD, first_ad.era());
+        assert_eq!("AD", first_ad.format("G"));
+        assert_eq!(
+            Era::BC,
+            Date::parse("BC 0001-12-31", "G yyyy-MM-dd").unwrap().era()
+        );
+    }
+
     #[test]
     fn from() {
         let date = Date::from_ymd(2022, 5, 10).unwrap();
@@ -500,9 +1401,528 @@ mod date_tests {
         assert_eq!("2022-05-09", date.format("yyyy-MM-dd"));
     }
 
+    #[test]
+    fn std_add_truncates() {
+        let date = Date::from_ymd(2022, 5, 10).unwrap();
+        assert_eq!(date, date + Duration::from_secs(24 * 60 * 60 - 1));
+        assert_eq!(
+            date,
+            date - Duration::from_nanos(24 * 60 * 60 * 1_000_000_000 - 1)
+        );
+        assert_eq!(
+            "2022-05-12",
+            (date + Duration::from_secs(2 * 24 * 60 * 60 + 12 * 60 * 60)).format("yyyy-MM-dd")
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn std_add_overflow() {
+        let date = Date::from_ymd(5_879_611, 7, 12).unwrap();
+        let _ = date + Duration::from_secs(24 * 60 * 60);
+    }
+
+    #[test]
+    #[should_panic]
+    fn std_add_assign_overflow() {
+        let mut date = Date::from_ymd(5_879_611, 7, 11).unwrap();
+        date += Duration::from_secs(2 * 24 * 60 * 60);
+    }
+
+    #[test]
+    #[should_panic]
+    fn std_add_overflow_u32() {
+        let date = Date::from_ymd(2022, 5, 10).unwrap();
+        let _ = date + Duration::from_secs((u32::MAX as u64 + 1) * 24 * 60 * 60);
+    }
+
+    #[test]
+    #[should_panic]
+    fn std_sub_underflow() {
+        let date = Date::from_ymd(-5_879_611, 6, 23).unwrap();
+        let _ = date - Duration::from_secs(24 * 60 * 60);
+    }
+
+    #[test]
+    #[should_panic]
+    fn std_sub_assign_underflow() {
+        let mut date = Date::from_ymd(-5_879_611, 6, 24).unwrap();
+        date -= Duration::from_secs(2 * 24 * 60 * 60);
+    }
+
+    #[test]
+    fn std_add_time() {
+        let date = Date::from_ymd(2022, 5, 2).unwrap();
+        let time = Time::from_hms(12, 32, 1).unwrap();
+        assert_eq!(DateTime::from(date).set_time(time), date + time);
+
+        let offset = Offset::from_hms(-5, 0, 0).unwrap();
+        let date_time = date + Time::from_hms(22, 0, 0).unwrap().as_offset(offset);
+        assert_eq!(
+            "2022-05-02 22:00:00 -05:00",
+            date_time.format("yyyy-MM-dd HH:mm:ss xxx")
+        );
+        assert_eq!(offset, date_time.get_offset());
+        assert_eq!(
+            DateTime::from_ymdhms(2022, 5, 3, 3, 0, 0).unwrap(),
+            date_time.set_offset(Offset::default())
+        );
+
+        let max = Date::from_ymd(5_879_611, 7, 12).unwrap();
+        assert_eq!(
+            DateTime::from_ymdhms(5_879_611, 7, 12, 23, 59, 59).unwrap(),
+            max + Time::from_hms(23, 59, 59).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn std_add_time_overflow() {
+        let date = Date::from_ymd(5_879_611, 7, 12).unwrap();
+        let offset = Offset::from_hms(-5, 0, 0).unwrap();
+        let _ = date + Time::from_hms(22, 0, 0).unwrap().as_offset(offset);
+    }
+
     #[test]
     fn display() {
         let date = Date::from_ymd(2022, 5, 10).unwrap();
         assert_eq!("2022/05/10", format!("{}", date));
+        assert_eq!("2022/05/10", format!("{:.3}", date));
+        assert_eq!("2022-05-10", format!("{:#}", date));
+        assert_eq!("2022-05-10", format!("{:#.3}", date));
+
+        for (year, month, day, expected) in [
+            (-1, 12, 31, "-0001-12-31"),
+            (-44, 3, 15, "-0044-03-15"),
+            (1, 1, 1, "0001-01-01"),
+            (12345, 1, 1, "12345-01-01"),
+        ] {
+            let date = Date::from_ymd(year, month, day).unwrap();
+            assert_eq!(expected, format!("{:#}", date));
+            assert_eq!(date, format!("{:#}", date).parse().unwrap());
+        }
+    }
+
+    #[test]
+    fn from_str() {
+        let cases = [
+            ("2022-05-02", "2022-05-02"),
+            ("0001-01-01", "0001-01-01"),
+            ("-0044-03-15", "-0044-03-15"),
+            ("12345-01-01", "12345-01-01"),
+            ("+12345-01-01", "12345-01-01"),
+            ("5879611-07-12", "5879611-07-12"),
+        ];
+        for (string, expected) in cases {
+            let date: Date = string.parse().unwrap();
+            assert_eq!(expected, date.format("yyyy-MM-dd"), "{}", string);
+        }
+
+        let invalid = [
+            ("", "format"),
+            ("2022-05", "format"),
+            ("22-05-02", "year"),
+            ("abcd-05-02", "year"),
+            ("99999999999-01-01", "year"),
+            ("2022-5-02", "month"),
+            ("2022-05-2", "day"),
+            ("2022-05-02x", "day"),
+            ("2022-05-02-01", "day"),
+            ("--2022-05-02", "year"),
+        ];
+        for (string, component) in invalid {
+            let error = string.parse::<Date>().unwrap_err().to_string();
+            assert!(error.contains(component), "{}: {}", string, error);
+        }
+
+        assert!("2022-13-02".parse::<Date>().is_err());
+        assert!("2022-02-29".parse::<Date>().is_err());
+        assert!("5879611-07-13".parse::<Date>().is_err());
+    }
+
+    #[test]
+    fn from_ymd_unchecked() {
+        const DATES: [Date; 4] = [
+            Date::from_ymd_unchecked(2022, 5, 2),
+            Date::from_ymd_unchecked(2024, 2, 29),
+            Date::from_ymd_unchecked(-5_879_611, 6, 23),
+            Date::from_ymd_unchecked(5_879_611, 7, 12),
+        ];
+        assert_eq!(Date::from_ymd(2022, 5, 2).unwrap(), DATES[0]);
+        assert_eq!(Date::from_ymd(2024, 2, 29).unwrap(), DATES[1]);
+        assert_eq!(Date::from_ymd(-5_879_611, 6, 23).unwrap(), DATES[2]);
+        assert_eq!(Date::from_ymd(5_879_611, 7, 12).unwrap(), DATES[3]);
+
+        for year in (-801..=801).filter(|year| *year != 0) {
+            for month in 1..=12 {
+                for day in 1..=31 {
+                    if let Ok(date) = Date::from_ymd(year, month, day) {
+                        assert_eq!(date, Date::from_ymd_unchecked(year, month, day));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Invalid date")]
+    fn from_ymd_unchecked_invalid() {
+        Date::from_ymd_unchecked(2022, 2, 29);
+    }
+
+    #[test]
+    fn units() {
+        let date = Date::from_ymd(2022, 5, 2).unwrap();
+        for unit in DateUnit::ALL {
+            let (value, set_value, set, added, subtracted) = match unit {
+                DateUnit::Year => (
+                    date.year(),
+                    2000,
+                    date.set_year(2000),
+                    date.add_years(3),
+                    date.sub_years(3),
+                ),
+                DateUnit::Month => (
+                    date.month() as i32,
+                    12,
+                    date.set_month(12),
+                    date.add_months(3),
+                    date.sub_months(3),
+                ),
+                DateUnit::Day => (
+                    date.day() as i32,
+                    31,
+                    date.set_day(31),
+                    date.add_days(3),
+                    date.sub_days(3),
+                ),
+            };
+            assert_eq!(value as i64, date.get_unit(unit));
+            assert_eq!(set.unwrap(), date.set_unit(unit, set_value).unwrap());
+            assert_eq!(added, date.apply_unit(unit, 3).unwrap());
+            assert_eq!(subtracted, date.apply_unit(unit, -3).unwrap());
+            assert_eq!(date, date.apply_unit(unit, 0).unwrap());
+
+            assert!(date.set_unit(unit, i64::MAX).is_err());
+            assert!(date.set_unit(unit, i64::MIN).is_err());
+            assert!(date.apply_unit(unit, i64::MAX).is_err());
+            assert!(date.apply_unit(unit, i64::MIN).is_err());
+        }
+
+        assert!(date.set_unit(DateUnit::Month, 13).is_err());
+        assert!(date.set_unit(DateUnit::Day, -1).is_err());
+        assert!(date.apply_unit(DateUnit::Year, 6_000_000).is_err());
+        assert!(date.apply_unit(DateUnit::Day, -(u32::MAX as i64)).is_err());
+    }
+
+    #[test]
+    fn from_ymd_lenient() {
+        // (year, month, day, expected)
+        let cases = [
+            (2022, 5, 2, "2022-05-02"),
+            (2022, 0, 1, "2021-12-01"),
+            (2022, 13, 1, "2023-01-01"),
+            (2022, 25, 1, "2024-01-01"),
+            (2022, -11, 1, "2021-01-01"),
+            (2022, -12, 1, "2020-12-01"),
+            (2022, 1, 0, "2021-12-31"),
+            (2022, 3, 0, "2022-02-28"),
+            (2024, 3, 0, "2024-02-29"),
+            (2022, 1, 32, "2022-02-01"),
+            (2022, 2, 30, "2022-03-02"),
+            (2022, 12, 32, "2023-01-01"),
+            (2022, 1, -364, "2021-01-01"),
+            (2022, 0, 0, "2021-11-30"),
+            (2022, 1, 366, "2023-01-01"),
+            // Year 0 is skipped
+            (1, 0, 1, "-0001-12-01"),
+            (1, 1, 0, "-0001-12-31"),
+            (-1, 13, 1, "0001-01-01"),
+            (-1, 12, 32, "0001-01-01"),
+        ];
+        for (year, month, day, expected) in cases {
+            let date = Date::from_ymd_lenient(year, month, day).unwrap();
+            assert_eq!(
+                expected,
+                date.format("yyyy-MM-dd"),
+                "{}-{}-{}",
+                year,
+                month,
+                day
+            );
+        }
+
+        assert_eq!(
+            Date::from_ymd(5_879_611, 7, 12).unwrap(),
+            Date::from_ymd_lenient(5_879_611, 6, 42).unwrap()
+        );
+        assert_eq!(
+            Date::from_ymd(-5_879_611, 6, 23).unwrap(),
+            Date::from_ymd_lenient(-5_879_611, 7, -7).unwrap()
+        );
+
+        assert!(Date::from_ymd_lenient(0, 1, 1).is_err());
+        assert!(Date::from_ymd_lenient(5_879_611, 7, 13).is_err());
+        assert!(Date::from_ymd_lenient(5_879_611, 13, 1).is_err());
+        assert!(Date::from_ymd_lenient(-5_879_611, 6, 22).is_err());
+        assert!(Date::from_ymd_lenient(2022, i64::MAX, 1).is_err());
+        assert!(Date::from_ymd_lenient(2022, i64::MIN, 1).is_err());
+        assert!(Date::from_ymd_lenient(2022, 1, i64::MAX).is_err());
+        assert!(Date::from_ymd_lenient(2022, 1, i64::MIN).is_err());
+    }
+
+    #[test]
+    fn from_ordinal() {
+        // (year, day of year, expected)
+        let cases = [
+            (2022, 1, "2022-01-01"),
+            (2022, 122, "2022-05-02"),
+            (2022, 365, "2022-12-31"),
+            (2024, 60, "2024-02-29"),
+            (2024, 366, "2024-12-31"),
+            (-1, 366, "-0001-12-31"),
+            (-5, 366, "-0005-12-31"),
+            (-101, 365, "-0101-12-31"),
+            (-401, 366, "-0401-12-31"),
+        ];
+        for (year, day_of_year, expected) in cases {
+            let date = Date::from_ordinal(year, day_of_year).unwrap();
+            assert_eq!(expected, date.format("yyyy-MM-dd"));
+            assert_eq!(day_of_year, date.ordinal());
+            assert_eq!(date.day_of_year(), date.ordinal());
+            assert_eq!(date, Date::parse_ordinal(&date.format_ordinal()).unwrap());
+        }
+
+        assert!(Date::from_ordinal(2022, 0).is_err());
+        assert!(Date::from_ordinal(2022, 366).is_err());
+        assert!(Date::from_ordinal(2100, 366).is_err());
+        assert!(Date::from_ordinal(-2, 366).is_err());
+        assert!(Date::from_ordinal(-101, 366).is_err());
+        assert!(Date::from_ordinal(0, 1).is_err());
+        assert!(Date::from_ordinal(5_879_611, 194).is_err());
+        assert!(Date::from_ordinal(-5_879_611, 173).is_err());
+    }
+
+    #[test]
+    fn month_overflow() {
+        assert_eq!(MonthOverflow::ClampToLastDay, MonthOverflow::default());
+
+        let date = Date::from_ymd(2022, 1, 31).unwrap();
+        assert_eq!(
+            "2022/02/28",
+            date.add_months_with(1, MonthOverflow::ClampToLastDay)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "2022/03/01",
+            date.add_months_with(1, MonthOverflow::RollToNextMonth)
+                .unwrap()
+                .to_string()
+        );
+        match date.add_months_with(1, MonthOverflow::Error).unwrap_err() {
+            AstrolabeError::OutOfRange(error) => {
+                assert_eq!("day", error.field_name());
+                assert_eq!(1, error.min());
+                assert_eq!(28, error.max());
+                assert_eq!(31, error.value());
+            }
+            error => panic!("Expected out of range error: {:?}", error),
+        }
+        assert_eq!(
+            date.add_months(1),
+            date.add_months_with(1, MonthOverflow::default()).unwrap()
+        );
+
+        // Leap year
+        let date = Date::from_ymd(2024, 1, 31).unwrap();
+        assert_eq!(
+            "2024/02/29",
+            date.add_months_with(1, MonthOverflow::ClampToLastDay)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "2024/03/01",
+            date.add_months_with(1, MonthOverflow::RollToNextMonth)
+                .unwrap()
+                .to_string()
+        );
+
+        // Days which exist in the target month are not affected
+        let date = Date::from_ymd(2022, 1, 28).unwrap();
+        for overflow in [
+            MonthOverflow::ClampToLastDay,
+            MonthOverflow::RollToNextMonth,
+            MonthOverflow::Error,
+        ] {
+            assert_eq!(
+                "2022/02/28",
+                date.add_months_with(1, overflow).unwrap().to_string()
+            );
+        }
+
+        let date = Date::from_ymd(2022, 3, 31).unwrap();
+        assert_eq!(
+            "2022/02/28",
+            date.sub_months_with(1, MonthOverflow::ClampToLastDay)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "2022/03/01",
+            date.sub_months_with(1, MonthOverflow::RollToNextMonth)
+                .unwrap()
+                .to_string()
+        );
+        assert!(date.sub_months_with(1, MonthOverflow::Error).is_err());
+        assert_eq!(
+            "2021/12/31",
+            date.sub_months_with(3, MonthOverflow::Error)
+                .unwrap()
+                .to_string()
+        );
+
+        let date = Date::from_ymd(2024, 2, 29).unwrap();
+        assert_eq!(
+            "2025/02/28",
+            date.add_years_with(1, MonthOverflow::ClampToLastDay)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "2025/03/01",
+            date.add_years_with(1, MonthOverflow::RollToNextMonth)
+                .unwrap()
+                .to_string()
+        );
+        assert!(date.add_years_with(1, MonthOverflow::Error).is_err());
+        assert_eq!(
+            "2023/02/28",
+            date.sub_years_with(1, MonthOverflow::ClampToLastDay)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "2023/03/01",
+            date.sub_years_with(1, MonthOverflow::RollToNextMonth)
+                .unwrap()
+                .to_string()
+        );
+        assert!(date.sub_years_with(1, MonthOverflow::Error).is_err());
+        for overflow in [
+            MonthOverflow::ClampToLastDay,
+            MonthOverflow::RollToNextMonth,
+            MonthOverflow::Error,
+        ] {
+            assert_eq!(
+                "2028/02/29",
+                date.add_years_with(4, overflow).unwrap().to_string()
+            );
+            assert_eq!(
+                "2020/02/29",
+                date.sub_years_with(4, overflow).unwrap().to_string()
+            );
+        }
+        // 2100 is not a leap year
+        assert!(Date::from_ymd(2096, 2, 29)
+            .unwrap()
+            .add_years_with(4, MonthOverflow::Error)
+            .is_err());
+
+        // Year -1 is a leap year because there is no year 0
+        let date = Date::from_ymd(-1, 2, 29).unwrap();
+        assert_eq!(
+            "-0005/02/29",
+            date.sub_years_with(4, MonthOverflow::Error)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "-0002/03/01",
+            date.sub_years_with(1, MonthOverflow::RollToNextMonth)
+                .unwrap()
+                .to_string()
+        );
+
+        assert!(Date::from_ymd(5_879_611, 7, 12)
+            .unwrap()
+            .add_months_with(1, MonthOverflow::RollToNextMonth)
+            .is_err());
+    }
+
+    #[test]
+    fn next_previous_day() {
+        let date = Date::from_ymd(2024, 2, 28).unwrap();
+        assert_eq!(
+            Date::from_ymd(2024, 2, 29).unwrap(),
+            date.next_day().unwrap()
+        );
+        assert_eq!(
+            Date::from_ymd(2024, 2, 27).unwrap(),
+            date.previous_day().unwrap()
+        );
+        assert_eq!(
+            Date::from_ymd(-1, 12, 31).unwrap(),
+            Date::from_ymd(1, 1, 1).unwrap().previous_day().unwrap()
+        );
+        assert_eq!(
+            Date::from_ymd(1, 1, 1).unwrap(),
+            Date::from_ymd(-1, 12, 31).unwrap().next_day().unwrap()
+        );
+
+        let max = Date::from_ymd(5_879_611, 7, 12).unwrap();
+        assert_eq!(None, max.next_day());
+        assert_eq!(
+            Date::from_ymd(5_879_611, 7, 11).unwrap(),
+            max.previous_day().unwrap()
+        );
+        assert_eq!(max, max.previous_day().unwrap().next_day().unwrap());
+
+        let min = Date::from_ymd(-5_879_611, 6, 23).unwrap();
+        assert_eq!(None, min.previous_day());
+        assert_eq!(
+            Date::from_ymd(-5_879_611, 6, 24).unwrap(),
+            min.next_day().unwrap()
+        );
+        assert_eq!(min, min.next_day().unwrap().previous_day().unwrap());
+    }
+
+    #[test]
+    fn is_today() {
+        let now = DateTime::from_ymdhms(2022, 5, 2, 23, 30, 0).unwrap();
+        let date = Date::from_ymd(2022, 5, 2).unwrap();
+        assert!(date.is_today_at(&now));
+        assert!(!date.add_days(1).is_today_at(&now));
+        assert!(!date.sub_days(1).is_today_at(&now));
+
+        // The local date of the snapshot is used
+        let now = now.set_offset(Offset::from_hms(2, 0, 0).unwrap());
+        assert!(!date.is_today_at(&now));
+        assert!(date.add_days(1).is_today_at(&now));
+        let now = now.set_offset(Offset::from_hms(-2, 0, 0).unwrap());
+        assert!(date.is_today_at(&now));
+
+        assert!(Date::today().is_today());
+        assert!(!Date::today().add_days(2).is_today());
+        assert!(Date::today_local().is_today_local());
+        assert!(!Date::today_local().sub_days(2).is_today_local());
+    }
+
+    #[test]
+    fn same_month_year() {
+        let date = Date::from_ymd(2022, 5, 2).unwrap();
+        assert!(date.same_month_as(&date));
+        assert!(date.same_month_as(&Date::from_ymd(2022, 5, 31).unwrap()));
+        assert!(!date.same_month_as(&Date::from_ymd(2022, 6, 1).unwrap()));
+        assert!(!date.same_month_as(&Date::from_ymd(2023, 5, 2).unwrap()));
+        assert!(date.same_year_as(&Date::from_ymd(2022, 1, 1).unwrap()));
+        assert!(!date.same_year_as(&Date::from_ymd(2021, 12, 31).unwrap()));
+
+        // There is no year 0
+        let date = Date::from_ymd(-1, 12, 31).unwrap();
+        assert!(!date.same_year_as(&Date::from_ymd(1, 1, 1).unwrap()));
+        assert!(date.same_year_as(&Date::from_ymd(-1, 1, 1).unwrap()));
     }
 }
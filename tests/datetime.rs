@@ -1,9 +1,18 @@
+mod common;
+
 #[cfg(test)]
 mod datetime_tests {
-    use std::time::Duration;
+    use crate::common::Random;
+    use std::{
+        collections::{hash_map::DefaultHasher, HashSet},
+        hash::{Hash, Hasher},
+        time::Duration,
+    };
 
     use astrolabe::{
-        Date, DateTime, DateUtilities, Offset, OffsetUtilities, Precision, Time, TimeUtilities,
+        errors::AstrolabeError, Date, DateTime, DateUnit, DateUtilities, Era, MonthOverflow,
+        Offset, OffsetUtilities, Precision, RelativeThresholds, Time, TimeUnit, TimeUtilities,
+        Unit, Weekday,
     };
 
     #[test]
@@ -59,10 +68,95 @@ mod datetime_tests {
         assert_eq!(std::cmp::Ordering::Less, date_time.cmp(&date_time_2));
     }
 
+    #[test]
+    fn hash() {
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 0, 0).unwrap();
+        // Same instant, different offsets
+        let date_time_2 = date_time.set_offset(Offset::Fixed(3600));
+        let date_time_3 = date_time.set_offset(Offset::Fixed(-43200));
+        assert!(date_time == date_time_2);
+        assert_eq!(hash_of(&date_time), hash_of(&date_time_2));
+        assert_eq!(hash_of(&date_time), hash_of(&date_time_3));
+        assert_ne!(hash_of(&date_time), hash_of(&date_time.add_nanos(1)));
+
+        let mut set = HashSet::new();
+        set.insert(date_time);
+        set.insert(date_time_2);
+        set.insert(date_time_3);
+        assert_eq!(1, set.len());
+    }
+
+    fn hash_of(date_time: &DateTime) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        date_time.hash(&mut hasher);
+        hasher.finish()
+    }
+
     #[test]
     fn now() {
         assert!(2021 < DateTime::now().year());
         assert!(2021 < DateTime::now_local().year());
+
+        let today = DateTime::today();
+        assert_eq!(
+            (0, 0, 0, 0),
+            (today.hour(), today.minute(), today.second(), today.nano())
+        );
+        assert!(today <= DateTime::now());
+    }
+
+    #[test]
+    fn now_precision() {
+        // Subsecond values are populated. A few samples guard against a clock which happens to be at a full second.
+        assert!((0..10).any(|_| DateTime::now().nano() != 0));
+
+        // Only fails if the wall clock is adjusted between both calls
+        let first = DateTime::now();
+        let second = DateTime::now();
+        assert!(second.nanos_since(&first) >= 0);
+
+        let seconds = DateTime::now_seconds();
+        assert_eq!(0, seconds.nano());
+        assert!(2021 < seconds.year());
+        let now = DateTime::now();
+        // Allow for a clock adjustment of a second between both calls
+        assert!((-1..=2).contains(&now.seconds_since(&seconds)));
+    }
+
+    #[test]
+    fn raw() {
+        let date_time = DateTime::from_raw(738_276, 55_820_000_000_000).unwrap();
+        assert_eq!(
+            DateTime::from_ymdhms(2022, 5, 2, 15, 30, 20).unwrap(),
+            date_time
+        );
+        assert_eq!(Offset::Fixed(0), date_time.get_offset());
+        assert_eq!((738_276, 55_820_000_000_000), date_time.as_raw());
+        assert_eq!(
+            date_time.as_raw(),
+            date_time.set_offset(Offset::Fixed(-7200)).as_raw()
+        );
+
+        assert_eq!(
+            DateTime::from_ymdhms(1, 1, 1, 0, 0, 0).unwrap(),
+            DateTime::from_raw(0, 0).unwrap()
+        );
+        let max = DateTime::from_raw(i32::MAX, 86_399_999_999_999).unwrap();
+        assert_eq!((5_879_611, 7, 12, 23, 59, 59), max.as_ymdhms());
+        let min = DateTime::from_raw(i32::MIN, 0).unwrap();
+        assert_eq!((-5_879_611, 6, 23, 0, 0, 0), min.as_ymdhms());
+        assert!(DateTime::from_raw(0, 86_400_000_000_000).is_err());
+        assert!(DateTime::from_raw(0, u64::MAX).is_err());
+
+        let date = Date::from_ymd(-1, 12, 31).unwrap();
+        let date_time = DateTime::from_days_nanos(date.days(), 3_600_000_000_000).unwrap();
+        assert_eq!((-1, 12, 31, 1, 0, 0), date_time.as_ymdhms());
+        assert_eq!(date, date_time.date());
+        assert_eq!(
+            DateTime::from_raw(738_276, 55_820_000_000_000),
+            DateTime::from_days_nanos(738_276, 55_820_000_000_000)
+        );
+        assert!(DateTime::from_days_nanos(0, 86_400_000_000_000).is_err());
     }
 
     #[test]
@@ -269,6 +363,76 @@ mod datetime_tests {
         assert_eq!(expected, start.duration_between(&end));
     }
 
+    #[test]
+    fn parse_or() {
+        let default = DateTime::from_ymdhms(2000, 1, 1, 0, 0, 0).unwrap();
+        let format = "yyyy-MM-dd HH:mm:ss";
+        let valid = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap();
+
+        assert_eq!(
+            valid,
+            DateTime::parse_or("2022-05-02 12:32:01", format, default)
+        );
+        assert_eq!(default, DateTime::parse_or("", format, default));
+        assert_eq!(default, DateTime::parse_or("", "", default));
+        assert_eq!(default, DateTime::parse_or("garbage", format, default));
+        assert_eq!(
+            default,
+            DateTime::parse_or("2022-13-02 12:32:01", format, default)
+        );
+
+        assert_eq!(valid, DateTime::parse_or_now("2022-05-02 12:32:01", format));
+        for string in ["", "garbage", "2022-05-02 25:00:00"] {
+            let before = DateTime::now();
+            let parsed = DateTime::parse_or_now(string, format);
+            assert!(before <= parsed && parsed <= DateTime::now());
+        }
+    }
+
+    #[test]
+    fn quarter() {
+        let date_time = DateTime::from_ymdhms(2024, 11, 30, 12, 32, 1).unwrap();
+        assert_eq!(4, date_time.quarter());
+        assert_eq!(
+            "2024-10-01 12:32:01",
+            date_time
+                .first_day_of_quarter()
+                .format("yyyy-MM-dd HH:mm:ss")
+        );
+        assert_eq!(
+            "2024-12-31 12:32:01",
+            date_time
+                .last_day_of_quarter()
+                .format("yyyy-MM-dd HH:mm:ss")
+        );
+        assert_eq!(
+            "2025-02-28 12:32:01",
+            date_time.add_quarters(1).format("yyyy-MM-dd HH:mm:ss")
+        );
+        assert_eq!(
+            "2024-02-29 12:32:01",
+            date_time.sub_quarters(3).format("yyyy-MM-dd HH:mm:ss")
+        );
+
+        // The quarter is determined in local wall time
+        let date_time = DateTime::from_ymdhms(2022, 3, 31, 23, 0, 0)
+            .unwrap()
+            .set_offset(Offset::from_hms(2, 0, 0).unwrap());
+        assert_eq!(2, date_time.quarter());
+        assert_eq!(
+            "2022-04-01 01:00:00",
+            date_time
+                .first_day_of_quarter()
+                .format("yyyy-MM-dd HH:mm:ss")
+        );
+        assert_eq!(
+            "2022-06-30 01:00:00",
+            date_time
+                .last_day_of_quarter()
+                .format("yyyy-MM-dd HH:mm:ss")
+        );
+    }
+
     #[test]
     fn get() {
         let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1)
@@ -280,6 +444,8 @@ mod datetime_tests {
         assert_eq!(2, date_time.day());
         assert_eq!(122, date_time.day_of_year());
         assert_eq!(1, date_time.weekday());
+        assert_eq!(1, date_time.weekday_iso());
+        assert_eq!(1, date_time.weekday_sunday0());
         assert_eq!(12, date_time.hour());
         assert_eq!(32, date_time.minute());
         assert_eq!(1, date_time.second());
@@ -292,6 +458,15 @@ mod datetime_tests {
         assert_eq!(1, date_time.day());
         assert_eq!(1, date_time.day_of_year());
         assert_eq!(1, date_time.weekday());
+        // The weekday is local, 2022-05-01 is a Sunday
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 0, 30, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(-3600));
+        assert_eq!(0, date_time.weekday());
+        assert_eq!(7, date_time.weekday_iso());
+        assert_eq!(0, date_time.weekday_sunday0());
+        assert_eq!("1 7", date_time.format("e eeeeeee"));
+        let date_time = DateTime::from_ymdhms(1, 1, 1, 0, 0, 0).unwrap();
         assert_eq!(0, date_time.hour());
         assert_eq!(0, date_time.minute());
         assert_eq!(0, date_time.second());
@@ -300,6 +475,27 @@ mod datetime_tests {
         assert_eq!(0, date_time.nano());
     }
 
+    #[test]
+    fn century_decade_era() {
+        let date_time = DateTime::from_ymdhms(2030, 1, 1, 0, 30, 0).unwrap();
+        assert_eq!(21, date_time.century());
+        assert_eq!(203, date_time.decade());
+        assert_eq!(Era::AD, date_time.era());
+
+        // Local years are compared
+        let date_time = DateTime::from_ymdhms(1, 1, 1, 0, 30, 0).unwrap();
+        assert_eq!(
+            (1, 0, Era::AD),
+            (date_time.century(), date_time.decade(), date_time.era())
+        );
+        let date_time = date_time.set_offset(Offset::from_hms(-1, 0, 0).unwrap());
+        assert_eq!(-1, date_time.year());
+        assert_eq!(
+            (-1, -1, Era::BC),
+            (date_time.century(), date_time.decade(), date_time.era())
+        );
+    }
+
     #[test]
     fn timestamp() {
         assert_eq!(0, DateTime::from_timestamp(0).timestamp());
@@ -420,6 +616,152 @@ mod datetime_tests {
         assert!(DateTime::parse_rfc3339("2022-05-02T15:30:20.02+01:60").is_err());
     }
 
+    #[test]
+    fn parse_rfc3339_lenient() {
+        let expected = DateTime::parse_rfc3339("2022-05-02T15:30:20.123+02:00").unwrap();
+        for string in [
+            "2022-05-02T15:30:20.123+02:00",
+            "2022-05-02t15:30:20.123+02:00",
+            "2022-05-02 15:30:20.123+02:00",
+            "2022-05-02T15:30:20,123+02:00",
+            "2022-05-02T15:30:20.123+0200",
+            "2022-05-02 15:30:20,123+0200",
+        ] {
+            let date_time = DateTime::parse_rfc3339_lenient(string).unwrap();
+            assert_eq!(expected, date_time);
+            assert_eq!(Offset::Fixed(7200), date_time.get_offset());
+            // Only the canonical form is accepted by the strict parser
+            assert_eq!(
+                string == "2022-05-02T15:30:20.123+02:00",
+                DateTime::parse_rfc3339(string).is_ok()
+            );
+        }
+
+        let date_time = DateTime::parse_rfc3339_lenient("2022-05-02 15:30:20Z").unwrap();
+        assert_eq!(
+            "2022-05-02T15:30:20Z",
+            date_time.format_rfc3339(Precision::Seconds)
+        );
+        let date_time = DateTime::parse_rfc3339_lenient("2022-05-02T15:30:20-0530").unwrap();
+        assert_eq!(
+            "2022-05-02T15:30:20-05:30",
+            date_time.format_rfc3339(Precision::Seconds)
+        );
+
+        assert!(DateTime::parse_rfc3339_lenient("2022-05-02_15:30:20Z").is_err());
+        assert!(DateTime::parse_rfc3339_lenient("2022/05/02T15:30:20Z").is_err());
+        assert!(DateTime::parse_rfc3339_lenient("2022-05-02T15.30.20Z").is_err());
+        assert!(DateTime::parse_rfc3339_lenient("2022-05-02T15:30:20;123Z").is_err());
+        assert!(DateTime::parse_rfc3339_lenient("2022-05-02T15:30:20,Z").is_err());
+        assert!(DateTime::parse_rfc3339_lenient("2022-05-02T15:30:20+02").is_err());
+        assert!(DateTime::parse_rfc3339_lenient("2022-05-02T15:30:20+020").is_err());
+        assert!(DateTime::parse_rfc3339_lenient("2022-05-02T15:30:20+02:0").is_err());
+        assert!(DateTime::parse_rfc3339_lenient("2022-05-02T15:30:20+02:000").is_err());
+        assert!(DateTime::parse_rfc3339_lenient("2022-05-02T15:30:20").is_err());
+
+        // Multibyte characters in the seconds and subseconds are rejected
+        for string in [
+            "2022-05-02T15:30:2éZxxxx",
+            "2022-05-02T12:00:0é",
+            "2022-05-02T12:00:00éZ",
+            "2022-05-02T12:00:00.1é",
+        ] {
+            match DateTime::parse_rfc3339(string).unwrap_err() {
+                AstrolabeError::InvalidFormat(error) => {
+                    assert_eq!(string.find('é'), error.position())
+                }
+                error => panic!("Unexpected error: {:?}", error),
+            }
+            assert!(DateTime::parse_rfc3339_lenient(string).is_err());
+            assert!(string.parse::<DateTime>().is_err());
+        }
+
+        let error = DateTime::parse_rfc3339("2022-05-02 15:30:20Z").unwrap_err();
+        assert_eq!(
+            "Failed parsing separator at position 10 from RFC 3339 string. Separator has to be 'T'.",
+            error.to_string()
+        );
+        let error = DateTime::parse_rfc3339_lenient("2022-05-02_15:30:20Z").unwrap_err();
+        assert_eq!(
+            "Failed parsing separator at position 10 from RFC 3339 string. Separator has to be 'T' or 't' or ' '.",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn parse_rfc3339_and_format_string() {
+        // Equivalent inputs are accepted by both paths
+        for string in [
+            "2022-05-02T15:30:20Z",
+            "2022-05-02T15:30:20+12:34",
+            "2022-05-02T15:30:20-23:59",
+            "2022-05-02T15:30:20.1Z",
+            "2022-05-02T15:30:20.123456789+01:00",
+        ] {
+            let format = match string.find('.') {
+                Some(index) => match string.len() - index {
+                    3 => "yyyy-MM-ddTHH:mm:ss.nXXX",
+                    _ => "yyyy-MM-ddTHH:mm:ss.nnnnnXXX",
+                },
+                None => "yyyy-MM-ddTHH:mm:ssXXX",
+            };
+            assert_eq!(
+                DateTime::parse_rfc3339(string).unwrap(),
+                DateTime::parse(string, format).unwrap()
+            );
+        }
+
+        // Invalid offsets return the same errors through both paths
+        for string in [
+            "2022-05-02T15:30:20+24:00",
+            "2022-05-02T15:30:20+01:60",
+            "2022-05-02T15:30:20+ee:00",
+            "2022-05-02T15:30:20+01:ee",
+            "2022-05-02T15:30:20+01-00",
+            "2022-05-02T15:30:20*01:00",
+            "2022-05-02T15:30:20+01:0",
+        ] {
+            assert_eq!(
+                DateTime::parse_rfc3339(string).unwrap_err(),
+                DateTime::parse(string, "yyyy-MM-ddTHH:mm:ssXXX").unwrap_err()
+            );
+        }
+
+        // Subseconds beyond nanosecond precision are truncated
+        let date_time = DateTime::parse_rfc3339("2022-05-02T15:30:20.1234567891Z").unwrap();
+        assert_eq!(123456789, date_time.nano());
+        let date_time = DateTime::parse_rfc3339("2022-05-02T15:30:20.123456789012Z").unwrap();
+        assert_eq!(123456789, date_time.nano());
+        assert_eq!(1651505420, date_time.timestamp());
+        // Truncated instead of rounded
+        let date_time = DateTime::parse_rfc3339("2022-05-02T15:30:20.9999999999+01:00").unwrap();
+        assert_eq!(999999999, date_time.nano());
+        assert_eq!(20, date_time.second());
+        let date_time = DateTime::parse_rfc3339("2022-05-02T15:30:20.000000000999Z").unwrap();
+        assert_eq!(0, date_time.nano());
+
+        for string in ["2022-05-02T15:30:20.Z", "2022-05-02T15:30:20.+01:00"] {
+            let error = DateTime::parse_rfc3339(string).unwrap_err();
+            assert_eq!(
+                "Failed parsing subseconds from RFC 3339 string. Expected at least one digit after the decimal separator.",
+                error.to_string()
+            );
+            assert_eq!(
+                Some(20),
+                match error {
+                    AstrolabeError::InvalidFormat(error) => error.position(),
+                    _ => None,
+                }
+            );
+        }
+        assert!(DateTime::parse_rfc3339_lenient("2022-05-02T15:30:20,Z").is_err());
+        assert!(DateTime::parse_rfc3339("2022-05-02T15:30:20.").is_err());
+
+        assert!(DateTime::parse_rfc3339("2022-05-02T15:30:20Zabc").is_err());
+        assert!(DateTime::parse_rfc3339("2022-05-02T15:30:20+01:00abc").is_err());
+        assert!(DateTime::parse("2022-05-02T15:30:20.+1Z", "yyyy-MM-ddTHH:mm:ss.nnXXX").is_err());
+    }
+
     #[test]
     fn format_rfc3339() {
         let date_time = DateTime::from_ymdhms(1970, 1, 1, 0, 0, 0).unwrap();
@@ -654,6 +996,49 @@ mod datetime_tests {
         );
     }
 
+    #[test]
+    fn saturating() {
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 15, 30, 20).unwrap();
+        assert_eq!(date_time, date_time.saturating_add_years(0));
+        assert_eq!(date_time, date_time.saturating_sub_nanos(0));
+        assert_eq!(date_time.add_days(3), date_time.saturating_add_days(3));
+        assert_eq!(date_time.sub_hours(30), date_time.saturating_sub_hours(30));
+
+        let max = "5879611-07-12T23:59:59.999999999Z";
+        let min = "-5879611-06-23T00:00:00.000000000Z";
+        let rfc3339 = |date_time: DateTime| date_time.format("yyyy-MM-ddTHH:mm:ss.nnnnnXXX");
+        // The time is clamped as well, not only the date
+        assert_eq!(max, rfc3339(date_time.saturating_add_years(u32::MAX)));
+        assert_eq!(max, rfc3339(date_time.saturating_add_years(5_877_590)));
+        assert_eq!(max, rfc3339(date_time.saturating_add_months(u32::MAX)));
+        assert_eq!(max, rfc3339(date_time.saturating_add_quarters(u32::MAX)));
+        assert_eq!(max, rfc3339(date_time.saturating_add_days(u32::MAX)));
+        assert_eq!(min, rfc3339(date_time.saturating_sub_years(u32::MAX)));
+        assert_eq!(min, rfc3339(date_time.saturating_sub_months(u32::MAX)));
+        assert_eq!(min, rfc3339(date_time.saturating_sub_quarters(u32::MAX)));
+        assert_eq!(min, rfc3339(date_time.saturating_sub_days(u32::MAX)));
+
+        let max = date_time.saturating_add_days(u32::MAX);
+        let min = date_time.saturating_sub_days(u32::MAX);
+        assert_eq!(max, max.saturating_add_nanos(1));
+        assert_eq!(max, max.saturating_add_minutes(u32::MAX));
+        assert_eq!(max.sub_nanos(1), max.saturating_sub_nanos(1));
+        assert_eq!(min, min.saturating_sub_nanos(1));
+        assert_eq!(min, min.saturating_sub_seconds(u32::MAX));
+        assert_eq!(min.add_nanos(1), min.saturating_add_nanos(1));
+
+        // The local date and time are in range as well
+        let offset = Offset::Fixed(3600);
+        let clamped = date_time.set_offset(offset).saturating_add_days(u32::MAX);
+        assert_eq!(offset, clamped.get_offset());
+        assert_eq!("5879611-07-12T23:59:59.999999999+01:00", rfc3339(clamped));
+        let offset = Offset::Fixed(-3600);
+        let clamped = date_time.set_offset(offset).saturating_sub_millis(u32::MAX);
+        assert_eq!("2022-03-13T21:27:32.705000000-01:00", rfc3339(clamped));
+        let clamped = date_time.set_offset(offset).saturating_sub_years(u32::MAX);
+        assert_eq!("-5879611-06-23T00:00:00.000000000-01:00", rfc3339(clamped));
+    }
+
     #[test]
     #[should_panic]
     fn add_overflow_days() {
@@ -1190,6 +1575,307 @@ mod datetime_tests {
         assert_eq!(-1, date_time.nanos_since(&date_time2));
     }
 
+    #[test]
+    fn since_offset() {
+        // 2022-05-01 01:00 local vs 2022-03-31 23:00 local
+        let date_time = DateTime::from_ymdhms(2022, 4, 30, 13, 0, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(43200));
+        let date_time2 = DateTime::from_ymdhms(2022, 4, 1, 11, 0, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(-43200));
+        assert_eq!(1, date_time.months_since(&date_time2));
+        assert_eq!(-1, date_time2.months_since(&date_time));
+        assert_eq!(29, date_time.days_since(&date_time2));
+        assert_eq!(-29, date_time2.days_since(&date_time));
+
+        // 2023-01-01 01:00 local vs 2021-12-31 23:00 local
+        let date_time = DateTime::from_ymdhms(2022, 12, 31, 13, 0, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(43200));
+        let date_time2 = DateTime::from_ymdhms(2022, 1, 1, 11, 0, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(-43200));
+        assert_eq!(1, date_time.years_since(&date_time2));
+        assert_eq!(-1, date_time2.years_since(&date_time));
+        assert_eq!(12, date_time.months_since(&date_time2));
+        assert_eq!(364, date_time.days_since(&date_time2));
+    }
+
+    #[test]
+    fn julian_date() {
+        let date_time = DateTime::from_ymd(2000, 1, 1).unwrap();
+        assert_eq!(2_451_544.5, date_time.julian_date());
+        assert_eq!(51_544.0, date_time.modified_julian_date());
+
+        let date_time = DateTime::from_ymdhms(2000, 1, 1, 12, 0, 0).unwrap();
+        assert_eq!(2_451_545.0, date_time.julian_date());
+        assert_eq!(51_544.5, date_time.modified_julian_date());
+
+        let date_time = DateTime::from_ymdhms(1858, 11, 17, 6, 0, 0).unwrap();
+        assert_eq!(2_400_000.75, date_time.julian_date());
+        assert_eq!(0.25, date_time.modified_julian_date());
+
+        let date_time = DateTime::from_ymdhms(-4714, 11, 24, 12, 0, 0).unwrap();
+        assert_eq!(0.0, date_time.julian_date());
+        let date_time = DateTime::from_ymdhms(-4714, 11, 24, 0, 0, 0).unwrap();
+        assert_eq!(-0.5, date_time.julian_date());
+
+        // The offset is not relevant
+        let date_time = DateTime::from_ymdhms(2000, 1, 1, 12, 0, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(-43200));
+        assert_eq!(2_451_545.0, date_time.julian_date());
+
+        // Consistent with the Julian day number of the date
+        let mut random = Random::new(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..1000 {
+            let date_time =
+                DateTime::from_timestamp(random.below(400_000_000_000) as i64 - 200_000_000_000);
+            let date = Date::from(date_time);
+            assert_eq!(
+                date.julian_day() as f64 - 0.5,
+                date_time.clear_until_hour().julian_date()
+            );
+            assert!(date_time.julian_date() >= date.julian_day() as f64 - 0.5);
+            assert!(date_time.julian_date() < date.julian_day() as f64 + 0.5);
+        }
+    }
+
+    #[test]
+    fn occurrence_of() {
+        // Monday, 2. May 2022 10:30
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 10, 30, 0).unwrap();
+        assert_eq!(
+            "2022-05-06 10:30",
+            date_time
+                .next_occurrence_of(Weekday::Friday)
+                .format("yyyy-MM-dd HH:mm")
+        );
+        assert_eq!(
+            "2022-05-09 10:30",
+            date_time
+                .next_occurrence_of(Weekday::Monday)
+                .format("yyyy-MM-dd HH:mm")
+        );
+        assert_eq!(
+            date_time,
+            date_time.next_occurrence_of_or_same(Weekday::Monday)
+        );
+        assert_eq!(
+            "2022-04-29 10:30",
+            date_time
+                .previous_occurrence_of(Weekday::Friday)
+                .format("yyyy-MM-dd HH:mm")
+        );
+        assert_eq!(
+            date_time,
+            date_time.previous_occurrence_of_or_same(Weekday::Monday)
+        );
+
+        // Local weekday: Monday 23:30 UTC is Tuesday 01:30 at +02:00
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 23, 30, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(7200));
+        let next = date_time.next_occurrence_of(Weekday::Tuesday);
+        assert_eq!("2022-05-10 01:30", next.format("yyyy-MM-dd HH:mm"));
+        assert_eq!(Offset::Fixed(7200), next.get_offset());
+        assert_eq!(
+            date_time,
+            date_time.next_occurrence_of_or_same(Weekday::Tuesday)
+        );
+        assert_eq!(
+            "2022-05-02 01:30",
+            date_time
+                .previous_occurrence_of(Weekday::Monday)
+                .format("yyyy-MM-dd HH:mm")
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn occurrence_of_overflow() {
+        let date_time = DateTime::from_ymd(5879611, 7, 12).unwrap();
+        date_time.next_occurrence_of(Weekday::Monday);
+    }
+
+    #[test]
+    fn days_since_midnight() {
+        // (self, other, days_since, calendar_days_since)
+        let cases = [
+            ((2022, 5, 3, 0, 1, 0, 0), (2022, 5, 2, 23, 59, 0, 0), 0, 1),
+            (
+                (2022, 5, 3, 0, 0, 0, 0),
+                (2022, 5, 2, 23, 59, 59, 999_999_999),
+                0,
+                1,
+            ),
+            ((2022, 5, 3, 0, 0, 0, 0), (2022, 5, 2, 0, 0, 0, 0), 1, 1),
+            ((2022, 5, 3, 23, 59, 0, 0), (2022, 5, 2, 0, 0, 0, 0), 1, 1),
+            ((2022, 5, 4, 0, 0, 0, 0), (2022, 5, 2, 23, 59, 0, 0), 1, 2),
+            ((2022, 5, 4, 0, 1, 0, 0), (2022, 5, 3, 0, 1, 0, 0), 1, 1),
+            ((2022, 5, 4, 0, 0, 0, 0), (2022, 5, 3, 0, 0, 0, 1), 0, 1),
+            ((2022, 5, 2, 0, 0, 0, 0), (2022, 5, 2, 0, 0, 0, 0), 0, 0),
+            ((2022, 5, 2, 23, 59, 0, 0), (2022, 5, 2, 0, 0, 0, 0), 0, 0),
+            ((2022, 5, 5, 0, 0, 0, 0), (2022, 5, 2, 23, 59, 59, 0), 2, 3),
+            ((2023, 1, 1, 0, 0, 0, 0), (2022, 12, 31, 23, 59, 0, 0), 0, 1),
+            ((1, 1, 1, 0, 30, 0, 0), (-1, 12, 31, 23, 30, 0, 0), 0, 1),
+            ((1, 1, 2, 0, 0, 0, 0), (-1, 12, 31, 0, 0, 0, 0), 2, 2),
+        ];
+
+        let create = |(year, month, day, hour, minute, second, nano): (
+            i32,
+            u32,
+            u32,
+            u32,
+            u32,
+            u32,
+            u32,
+        )| {
+            DateTime::from_ymdhms(year, month, day, hour, minute, second)
+                .unwrap()
+                .add_nanos(nano)
+        };
+        for (date_time, other, days, calendar_days) in cases {
+            let date_time = create(date_time);
+            let other = create(other);
+            assert_eq!(
+                days,
+                date_time.days_since(&other),
+                "{} since {}",
+                date_time,
+                other
+            );
+            assert_eq!(
+                -days,
+                other.days_since(&date_time),
+                "{} since {}",
+                other,
+                date_time
+            );
+            assert_eq!(
+                calendar_days,
+                date_time.calendar_days_since(&other),
+                "{} since {}",
+                date_time,
+                other
+            );
+            assert_eq!(
+                -calendar_days,
+                other.calendar_days_since(&date_time),
+                "{} since {}",
+                other,
+                date_time
+            );
+        }
+
+        // Calendar days use the local dates: 2022-05-03 00:30 +01:00 vs 2022-05-02 23:30 UTC
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 23, 30, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(3600));
+        let other = DateTime::from_ymdhms(2022, 5, 2, 23, 30, 0).unwrap();
+        assert_eq!(0, date_time.days_since(&other));
+        assert_eq!(1, date_time.calendar_days_since(&other));
+    }
+
+    #[test]
+    fn since_year_one_boundary() {
+        // -1 days + 23h vs 0 days + 01h
+        let date_time = DateTime::from_ymdhms(-1, 12, 31, 23, 0, 0).unwrap();
+        let date_time2 = DateTime::from_ymdhms(1, 1, 1, 1, 0, 0).unwrap();
+        assert_eq!(0, date_time2.days_since(&date_time));
+        assert_eq!(2, date_time2.hours_since(&date_time));
+        assert_eq!(120, date_time2.minutes_since(&date_time));
+        assert_eq!(7_200, date_time2.seconds_since(&date_time));
+        assert_eq!(0, date_time.days_since(&date_time2));
+        assert_eq!(-2, date_time.hours_since(&date_time2));
+        assert_eq!(-120, date_time.minutes_since(&date_time2));
+        assert_eq!(-7_200, date_time.seconds_since(&date_time2));
+
+        // Incomplete units are truncated toward zero in both directions
+        let date_time = DateTime::from_ymdhms(-1, 12, 30, 23, 30, 0)
+            .unwrap()
+            .set_nano(500_000_000)
+            .unwrap();
+        let date_time2 = DateTime::from_ymdhms(1, 1, 1, 0, 15, 0).unwrap();
+        assert_eq!(1, date_time2.days_since(&date_time));
+        assert_eq!(24, date_time2.hours_since(&date_time));
+        assert_eq!(1_484, date_time2.minutes_since(&date_time));
+        assert_eq!(89_099, date_time2.seconds_since(&date_time));
+        assert_eq!(89_099_500, date_time2.millis_since(&date_time));
+        assert_eq!(89_099_500_000, date_time2.micros_since(&date_time));
+        assert_eq!(89_099_500_000_000, date_time2.nanos_since(&date_time));
+        assert_eq!(-1, date_time.days_since(&date_time2));
+        assert_eq!(-24, date_time.hours_since(&date_time2));
+        assert_eq!(-1_484, date_time.minutes_since(&date_time2));
+        assert_eq!(-89_099, date_time.seconds_since(&date_time2));
+        assert_eq!(-89_099_500, date_time.millis_since(&date_time2));
+        assert_eq!(-89_099_500_000, date_time.micros_since(&date_time2));
+        assert_eq!(-89_099_500_000_000, date_time.nanos_since(&date_time2));
+
+        // Both operands before year 1
+        let date_time = DateTime::from_ymdhms(-1, 12, 30, 1, 0, 0).unwrap();
+        let date_time2 = DateTime::from_ymdhms(-1, 12, 31, 0, 59, 59).unwrap();
+        assert_eq!(0, date_time2.days_since(&date_time));
+        assert_eq!(23, date_time2.hours_since(&date_time));
+        assert_eq!(0, date_time.days_since(&date_time2));
+        assert_eq!(-23, date_time.hours_since(&date_time2));
+
+        // Same day, different sub-day values
+        let date_time = DateTime::from_ymdhms(-1, 12, 31, 0, 0, 1).unwrap();
+        let date_time2 = DateTime::from_ymdhms(-1, 12, 31, 0, 0, 0).unwrap();
+        assert_eq!(0, date_time2.days_since(&date_time));
+        assert_eq!(0, date_time2.hours_since(&date_time));
+        assert_eq!(-1, date_time2.seconds_since(&date_time));
+        assert_eq!(1, date_time.seconds_since(&date_time2));
+    }
+
+    #[test]
+    fn since_randomized() {
+        const NANOS_PER_SEC: i128 = 1_000_000_000;
+        // Roughly year -2000 to year 4000 in seconds relative to 1970
+        const RANGE_SECS: u64 = 200_000_000_000;
+
+        let mut random = Random::new(0x2545_f491_4f6c_dd1d);
+
+        for _ in 0..10_000 {
+            let mut random_date_time = |near: Option<i64>| {
+                let seconds = match near {
+                    // Close to the other operand to exercise incomplete units
+                    Some(near) => near + random.below(200_000) as i64 - 100_000,
+                    None => random.below(RANGE_SECS) as i64 - (RANGE_SECS / 2) as i64,
+                };
+                let nanos = random.below(NANOS_PER_SEC as u64) as u32;
+                let date_time = DateTime::from_timestamp(seconds).add_nanos(nanos);
+                (date_time, seconds as i128 * NANOS_PER_SEC + nanos as i128)
+            };
+
+            let (date_time, reference) = random_date_time(None);
+            let (date_time2, reference2) = random_date_time(Some(date_time.timestamp()));
+
+            for (lhs, lhs_ref, rhs, rhs_ref) in [
+                (date_time, reference, date_time2, reference2),
+                (date_time2, reference2, date_time, reference),
+            ] {
+                // Integer division truncates toward zero
+                let diff = lhs_ref - rhs_ref;
+                assert_eq!(
+                    diff / (86_400 * NANOS_PER_SEC),
+                    lhs.days_since(&rhs) as i128
+                );
+                assert_eq!(
+                    diff / (3_600 * NANOS_PER_SEC),
+                    lhs.hours_since(&rhs) as i128
+                );
+                assert_eq!(diff / (60 * NANOS_PER_SEC), lhs.minutes_since(&rhs) as i128);
+                assert_eq!(diff / NANOS_PER_SEC, lhs.seconds_since(&rhs) as i128);
+                assert_eq!(diff / 1_000_000, lhs.millis_since(&rhs));
+                assert_eq!(diff / 1_000, lhs.micros_since(&rhs));
+                assert_eq!(diff, lhs.nanos_since(&rhs));
+            }
+        }
+    }
+
     #[test]
     fn from() {
         let date_time = DateTime::from_ymdhms(2022, 5, 10, 12, 32, 1)
@@ -1223,10 +1909,108 @@ mod datetime_tests {
         );
     }
 
+    #[test]
+    fn parts() {
+        let date_time = DateTime::from_ymdhms(2022, 5, 10, 23, 32, 1)
+            .unwrap()
+            .set_nano(123_456_789)
+            .unwrap();
+        for seconds in [0, 3600, -3600, 5 * 3600 + 30 * 60, -86_399, 86_399] {
+            let date_time = date_time.set_offset(Offset::Fixed(seconds));
+            let (date, time) = (date_time.date(), date_time.time());
+            assert_eq!(
+                (date_time.year(), date_time.month(), date_time.day()),
+                date.as_ymd()
+            );
+            assert_eq!(
+                (date_time.hour(), date_time.minute(), date_time.second()),
+                (time.hour(), time.minute(), time.second())
+            );
+            assert_eq!(date_time.nano(), time.nano());
+            assert_eq!(Offset::Fixed(seconds), time.get_offset());
+            assert_eq!(date_time, date.at(time));
+            assert_eq!(date_time.get_offset(), date.at(time).get_offset());
+            assert_eq!(date_time, date_time.replace_date(date));
+            assert_eq!(time, Time::from(date_time));
+        }
+
+        // Before 0001-01-01
+        let date_time = DateTime::from_ymdhms(-1, 12, 31, 23, 0, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(3600));
+        assert_eq!(Date::from_ymd(1, 1, 1).unwrap(), date_time.date());
+        assert_eq!("00:00:00", date_time.time().to_string());
+        assert_eq!("00:00:00", Time::from(date_time).to_string());
+        assert_eq!(date_time, date_time.date().at(date_time.time()));
+    }
+
+    #[test]
+    fn replace_date() {
+        let date_time = DateTime::from_ymdhms(2022, 5, 10, 23, 32, 1)
+            .unwrap()
+            .set_nano(1)
+            .unwrap()
+            .as_offset(Offset::Fixed(-7200));
+        let date = Date::from_ymd(2024, 2, 29).unwrap();
+        let replaced = date_time.replace_date(date);
+        assert_eq!(
+            "2024-02-29T23:32:01.000000001-02:00",
+            replaced.format_rfc3339(Precision::Nanos)
+        );
+        assert_eq!(date, replaced.date());
+        assert_eq!(date_time.time(), replaced.time());
+        assert_eq!(Offset::Fixed(-7200), replaced.get_offset());
+
+        let date_time = date_time.set_offset(Offset::Fixed(3600));
+        let max = Date::from_ymd(5_879_611, 7, 12).unwrap();
+        let min = Date::from_ymd(-5_879_611, 6, 23).unwrap();
+        assert_eq!(max, date_time.replace_date(max).date());
+        assert_eq!(min, date_time.replace_date(min).date());
+    }
+
+    #[test]
+    #[should_panic]
+    fn replace_date_overflow() {
+        let date_time = DateTime::from_ymdhms(2022, 5, 10, 23, 32, 1)
+            .unwrap()
+            .as_offset(Offset::Fixed(-7200));
+        date_time.replace_date(Date::from_ymd(5_879_611, 7, 12).unwrap());
+    }
+
     #[test]
     fn display() {
         let date_time = DateTime::from_ymdhms(2022, 5, 10, 12, 31, 1).unwrap();
         assert_eq!("2022/05/10 12:31:01", format!("{}", date_time));
+
+        let date_time = date_time.add_nanos(123_456_789);
+        assert_eq!("2022/05/10 12:31:01", format!("{}", date_time));
+        assert_eq!("2022/05/10 12:31:01", format!("{:.0}", date_time));
+        assert_eq!("2022/05/10 12:31:01.1", format!("{:.1}", date_time));
+        assert_eq!("2022/05/10 12:31:01.123", format!("{:.3}", date_time));
+        assert_eq!("2022/05/10 12:31:01.123456789", format!("{:.9}", date_time));
+        assert_eq!(
+            "2022/05/10 12:31:01.123456789",
+            format!("{:.12}", date_time)
+        );
+        assert_eq!("2022-05-10T12:31:01Z", format!("{:#}", date_time));
+        assert_eq!("2022-05-10T12:31:01Z", format!("{:#.0}", date_time));
+        assert_eq!("2022-05-10T12:31:01.123Z", format!("{:#.3}", date_time));
+        assert_eq!(
+            date_time.format_rfc3339(Precision::Nanos),
+            format!("{:#.9}", date_time)
+        );
+
+        let date_time = date_time.set_offset(Offset::Fixed(-5400));
+        assert_eq!("2022/05/10 11:01:01", format!("{}", date_time));
+        assert_eq!("2022/05/10 11:01:01.12", format!("{:.2}", date_time));
+        assert_eq!("2022-05-10T11:01:01-01:30", format!("{:#}", date_time));
+        assert_eq!(
+            "2022-05-10T11:01:01.123456-01:30",
+            format!("{:#.6}", date_time)
+        );
+
+        let date_time = DateTime::from_ymdhms(-1, 12, 31, 23, 59, 59).unwrap();
+        assert_eq!("-0001-12-31T23:59:59Z", format!("{:#}", date_time));
     }
 
     #[test]
@@ -1291,4 +2075,1034 @@ mod datetime_tests {
         assert_eq!((1, 8, 1), DateTime::from_ymd(1, 8, 1).unwrap().as_ymd());
         assert_eq!((1, 11, 1), DateTime::from_ymd(1, 11, 1).unwrap().as_ymd());
     }
+
+    #[test]
+    fn round_to() {
+        let quarter = Duration::from_secs(15 * 60);
+        let hour = Duration::from_secs(3600);
+        let day = Duration::from_secs(86_400);
+        let format = "yyyy-MM-dd HH:mm:ss";
+
+        // (UTC instant, offset in seconds, duration, floor, ceil, round), results in local time
+        let cases = [
+            (
+                (2022, 5, 2, 23, 52, 30),
+                0,
+                quarter,
+                "2022-05-02 23:45:00",
+                "2022-05-03 00:00:00",
+                "2022-05-03 00:00:00",
+            ),
+            (
+                (2022, 5, 2, 23, 52, 30),
+                0,
+                hour,
+                "2022-05-02 23:00:00",
+                "2022-05-03 00:00:00",
+                "2022-05-03 00:00:00",
+            ),
+            (
+                (2022, 5, 2, 23, 52, 30),
+                0,
+                day,
+                "2022-05-02 00:00:00",
+                "2022-05-03 00:00:00",
+                "2022-05-03 00:00:00",
+            ),
+            (
+                (2022, 5, 2, 0, 7, 29),
+                0,
+                quarter,
+                "2022-05-02 00:00:00",
+                "2022-05-02 00:15:00",
+                "2022-05-02 00:00:00",
+            ),
+            (
+                (2022, 5, 2, 12, 0, 0),
+                0,
+                day,
+                "2022-05-02 00:00:00",
+                "2022-05-03 00:00:00",
+                "2022-05-03 00:00:00",
+            ),
+            // +05:30, local 2022-05-02 23:50
+            (
+                (2022, 5, 2, 18, 20, 0),
+                19_800,
+                quarter,
+                "2022-05-02 23:45:00",
+                "2022-05-03 00:00:00",
+                "2022-05-02 23:45:00",
+            ),
+            (
+                (2022, 5, 2, 18, 20, 0),
+                19_800,
+                hour,
+                "2022-05-02 23:00:00",
+                "2022-05-03 00:00:00",
+                "2022-05-03 00:00:00",
+            ),
+            (
+                (2022, 5, 2, 18, 20, 0),
+                19_800,
+                day,
+                "2022-05-02 00:00:00",
+                "2022-05-03 00:00:00",
+                "2022-05-03 00:00:00",
+            ),
+            // -03:00, local 2022-05-02 23:10
+            (
+                (2022, 5, 3, 2, 10, 0),
+                -10_800,
+                quarter,
+                "2022-05-02 23:00:00",
+                "2022-05-02 23:15:00",
+                "2022-05-02 23:15:00",
+            ),
+            (
+                (2022, 5, 3, 2, 10, 0),
+                -10_800,
+                hour,
+                "2022-05-02 23:00:00",
+                "2022-05-03 00:00:00",
+                "2022-05-02 23:00:00",
+            ),
+            (
+                (2022, 5, 3, 2, 10, 0),
+                -10_800,
+                day,
+                "2022-05-02 00:00:00",
+                "2022-05-03 00:00:00",
+                "2022-05-03 00:00:00",
+            ),
+            // -03:00, exactly local midnight
+            (
+                (2022, 5, 3, 3, 0, 0),
+                -10_800,
+                quarter,
+                "2022-05-03 00:00:00",
+                "2022-05-03 00:00:00",
+                "2022-05-03 00:00:00",
+            ),
+            (
+                (2022, 5, 3, 3, 0, 0),
+                -10_800,
+                hour,
+                "2022-05-03 00:00:00",
+                "2022-05-03 00:00:00",
+                "2022-05-03 00:00:00",
+            ),
+            (
+                (2022, 5, 3, 3, 0, 0),
+                -10_800,
+                day,
+                "2022-05-03 00:00:00",
+                "2022-05-03 00:00:00",
+                "2022-05-03 00:00:00",
+            ),
+        ];
+
+        for ((year, month, day, hour, minute, second), offset, duration, floor, ceil, round) in
+            cases
+        {
+            let date_time = DateTime::from_ymdhms(year, month, day, hour, minute, second)
+                .unwrap()
+                .set_offset(Offset::Fixed(offset));
+            let case = date_time.format(format);
+
+            let floored = date_time.floor_to(duration).unwrap();
+            assert_eq!(floor, floored.format(format), "floor {}", case);
+            assert_eq!(Offset::Fixed(offset), floored.get_offset());
+            assert_eq!(
+                ceil,
+                date_time.ceil_to(duration).unwrap().format(format),
+                "ceil {}",
+                case
+            );
+            assert_eq!(
+                round,
+                date_time.round_to(duration).unwrap().format(format),
+                "round {}",
+                case
+            );
+        }
+
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 10, 0, 0).unwrap();
+        assert!(date_time.floor_to(Duration::ZERO).is_err());
+        assert!(date_time.ceil_to(Duration::from_secs(7 * 60)).is_err());
+        assert!(date_time.round_to(Duration::from_secs(2 * 86_400)).is_err());
+        assert!(date_time.round_to(Duration::from_nanos(7)).is_err());
+
+        let max = DateTime::from_ymdhms(5879611, 7, 12, 23, 59, 59).unwrap();
+        assert!(max.floor_to(day).is_ok());
+        assert!(max.ceil_to(day).is_err());
+        assert!(max.round_to(hour).is_err());
+    }
+
+    #[test]
+    fn from_ymdhms_unchecked() {
+        const DATE_TIMES: [DateTime; 3] = [
+            DateTime::from_ymdhms_unchecked(2022, 5, 2, 12, 32, 1),
+            DateTime::from_ymdhms_unchecked(-1, 12, 31, 23, 59, 59),
+            DateTime::from_ymdhms_unchecked(1, 1, 1, 0, 0, 0),
+        ];
+        assert_eq!(
+            DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap(),
+            DATE_TIMES[0]
+        );
+        assert_eq!(
+            DateTime::from_ymdhms(-1, 12, 31, 23, 59, 59).unwrap(),
+            DATE_TIMES[1]
+        );
+        assert_eq!(
+            DateTime::from_ymdhms(1, 1, 1, 0, 0, 0).unwrap(),
+            DATE_TIMES[2]
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Invalid time")]
+    fn from_ymdhms_unchecked_invalid() {
+        DateTime::from_ymdhms_unchecked(2022, 5, 2, 12, 60, 0);
+    }
+
+    #[test]
+    fn format_rfc3339_digits() {
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 15, 30, 20)
+            .unwrap()
+            .set_nano(123_456_789)
+            .unwrap();
+
+        let expected = [
+            "2022-05-02T15:30:20Z",
+            "2022-05-02T15:30:20.1Z",
+            "2022-05-02T15:30:20.12Z",
+            "2022-05-02T15:30:20.123Z",
+            "2022-05-02T15:30:20.1234Z",
+            "2022-05-02T15:30:20.12345Z",
+            "2022-05-02T15:30:20.123456Z",
+            "2022-05-02T15:30:20.1234567Z",
+            "2022-05-02T15:30:20.12345678Z",
+            "2022-05-02T15:30:20.123456789Z",
+        ];
+        for (digits, expected) in expected.iter().enumerate() {
+            assert_eq!(
+                *expected,
+                date_time.format_rfc3339(Precision::Digits(digits as u8))
+            );
+            assert_eq!(digits as u8, Precision::Digits(digits as u8).digits());
+        }
+        assert_eq!(
+            "2022-05-02T15:30:20.123456789Z",
+            date_time.format_rfc3339(Precision::Digits(10))
+        );
+        assert_eq!(
+            "2022-05-02T15:30:20.123456789Z",
+            date_time.format_rfc3339(Precision::Digits(u8::MAX))
+        );
+
+        // Validated digits
+        for digits in 1..=9 {
+            assert_eq!(
+                Precision::Digits(digits),
+                Precision::from_digits(digits).unwrap()
+            );
+        }
+        assert_eq!(
+            "digits must be in the range 1..=9",
+            Precision::from_digits(0).unwrap_err().to_string()
+        );
+        assert!(Precision::from_digits(10).is_err());
+        assert!(Precision::from_digits(u8::MAX).is_err());
+
+        // Named precisions
+        assert_eq!(expected[0], date_time.format_rfc3339(Precision::Seconds));
+        assert_eq!(expected[1], date_time.format_rfc3339(Precision::Decis));
+        assert_eq!(expected[2], date_time.format_rfc3339(Precision::Centis));
+        assert_eq!(expected[3], date_time.format_rfc3339(Precision::Millis));
+        assert_eq!(expected[6], date_time.format_rfc3339(Precision::Micros));
+        assert_eq!(expected[9], date_time.format_rfc3339(Precision::Nanos));
+
+        // Truncated, not rounded
+        let date_time = date_time.set_nano(999_999_999).unwrap();
+        assert_eq!(
+            "2022-05-02T15:30:20.9999Z",
+            date_time.format_rfc3339(Precision::Digits(4))
+        );
+        let date_time = date_time
+            .set_nano(50_000)
+            .unwrap()
+            .set_offset(Offset::from_hms(-5, 30, 0).unwrap());
+        assert_eq!(
+            "2022-05-02T10:00:20.00005-05:30",
+            date_time.format_rfc3339(Precision::Digits(5))
+        );
+        assert_eq!(
+            date_time,
+            DateTime::parse_rfc3339(&date_time.format_rfc3339(Precision::Digits(5))).unwrap()
+        );
+    }
+
+    #[test]
+    fn format_rfc3339_auto() {
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 15, 30, 20).unwrap();
+        assert_eq!(
+            "2022-05-02T15:30:20Z",
+            date_time.format_rfc3339(Precision::Auto)
+        );
+
+        let expected = [
+            (100_000_000, "2022-05-02T15:30:20.1Z"),
+            (120_000_000, "2022-05-02T15:30:20.12Z"),
+            (123_000_000, "2022-05-02T15:30:20.123Z"),
+            (123_400_000, "2022-05-02T15:30:20.1234Z"),
+            (123_450_000, "2022-05-02T15:30:20.12345Z"),
+            (123_456_000, "2022-05-02T15:30:20.123456Z"),
+            (123_456_700, "2022-05-02T15:30:20.1234567Z"),
+            (123_456_780, "2022-05-02T15:30:20.12345678Z"),
+            (123_456_789, "2022-05-02T15:30:20.123456789Z"),
+            (500_000_000, "2022-05-02T15:30:20.5Z"),
+            (10_000_000, "2022-05-02T15:30:20.01Z"),
+            (1_000_000, "2022-05-02T15:30:20.001Z"),
+            (100_000, "2022-05-02T15:30:20.0001Z"),
+            (10_000, "2022-05-02T15:30:20.00001Z"),
+            (1_000, "2022-05-02T15:30:20.000001Z"),
+            (100, "2022-05-02T15:30:20.0000001Z"),
+            (10, "2022-05-02T15:30:20.00000001Z"),
+            (1, "2022-05-02T15:30:20.000000001Z"),
+            (100_000_001, "2022-05-02T15:30:20.100000001Z"),
+        ];
+        for (nano, expected) in expected {
+            let date_time = date_time.set_nano(nano).unwrap();
+            assert_eq!(expected, date_time.format_rfc3339(Precision::Auto));
+            assert_eq!(
+                date_time,
+                DateTime::parse_rfc3339(&date_time.format_rfc3339(Precision::Auto)).unwrap()
+            );
+        }
+
+        assert_eq!(9, Precision::Auto.digits());
+        assert_eq!(39, DateTime::rfc3339_max_len(Precision::Auto));
+    }
+
+    #[test]
+    fn format_rfc3339_buf() {
+        let precisions = || {
+            [
+                Precision::Seconds,
+                Precision::Decis,
+                Precision::Centis,
+                Precision::Millis,
+                Precision::Micros,
+                Precision::Nanos,
+                Precision::Digits(0),
+                Precision::Digits(4),
+                Precision::Digits(7),
+                Precision::Digits(10),
+                Precision::Auto,
+            ]
+        };
+        let date_times = [
+            DateTime::from_ymdhms(-5_879_611, 6, 23, 0, 0, 0).unwrap(),
+            DateTime::from_ymdhms(-10_000, 1, 1, 0, 0, 0).unwrap(),
+            DateTime::from_ymdhms(-1, 12, 31, 23, 59, 59).unwrap(),
+            DateTime::from_ymdhms(1, 1, 1, 0, 0, 0).unwrap(),
+            DateTime::from_ymdhms(999, 9, 9, 9, 9, 9).unwrap(),
+            DateTime::from_ymdhms(2022, 5, 2, 15, 30, 20)
+                .unwrap()
+                .set_nano(123_456_789)
+                .unwrap(),
+            DateTime::from_ymdhms(10_000, 1, 1, 0, 0, 0).unwrap(),
+            DateTime::from_ymdhms(5_879_611, 7, 11, 23, 59, 59)
+                .unwrap()
+                .set_nano(999_999_999)
+                .unwrap(),
+        ];
+        let offsets = [
+            Offset::Fixed(0),
+            Offset::from_hms(5, 30, 0).unwrap(),
+            Offset::from_hms(-23, 59, 59).unwrap(),
+        ];
+
+        for date_time in date_times {
+            for offset in offsets {
+                let date_time = match date_time.try_set_offset(offset) {
+                    Ok(date_time) => date_time,
+                    Err(_) => continue,
+                };
+                for precision in precisions() {
+                    let expected = date_time.format_rfc3339(precision.clone());
+                    let max_len = DateTime::rfc3339_max_len(precision.clone());
+                    assert!(expected.len() <= max_len);
+
+                    let mut buf = [0; 39];
+                    let length = date_time
+                        .format_rfc3339_buf(precision.clone(), &mut buf)
+                        .unwrap();
+                    assert_eq!(expected.len(), length);
+                    assert_eq!(expected.as_bytes(), &buf[..length]);
+
+                    // Exact length
+                    let mut buf = vec![0; length];
+                    assert_eq!(
+                        length,
+                        date_time
+                            .format_rfc3339_buf(precision.clone(), &mut buf)
+                            .unwrap()
+                    );
+                    assert_eq!(expected.as_bytes(), &buf[..]);
+
+                    // Too small buffer between guard bytes
+                    let mut guarded = vec![0xAA; length + 7];
+                    let error = date_time
+                        .format_rfc3339_buf(precision, &mut guarded[4..length + 3])
+                        .unwrap_err();
+                    assert_eq!(length, error.required());
+                    assert_eq!(length - 1, error.available());
+                    assert!(guarded.iter().all(|byte| *byte == 0xAA));
+                }
+            }
+        }
+
+        assert_eq!(29, DateTime::rfc3339_max_len(Precision::Seconds));
+        assert_eq!(31, DateTime::rfc3339_max_len(Precision::Decis));
+        assert_eq!(32, DateTime::rfc3339_max_len(Precision::Centis));
+        assert_eq!(34, DateTime::rfc3339_max_len(Precision::Digits(4)));
+        assert_eq!(39, DateTime::rfc3339_max_len(Precision::Digits(255)));
+        assert_eq!(33, DateTime::rfc3339_max_len(Precision::Millis));
+        assert_eq!(36, DateTime::rfc3339_max_len(Precision::Micros));
+        assert_eq!(39, DateTime::rfc3339_max_len(Precision::Nanos));
+
+        let date_time = DateTime::from_ymdhms(-5_879_611, 6, 23, 0, 0, 0)
+            .unwrap()
+            .set_offset(Offset::from_hms(23, 59, 0).unwrap());
+        let mut buf = [0; DateTime::rfc3339_max_len(Precision::Nanos)];
+        assert_eq!(
+            39,
+            date_time
+                .format_rfc3339_buf(Precision::Nanos, &mut buf)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn units() {
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1)
+            .unwrap()
+            .set_nano(123_456_789)
+            .unwrap();
+        for unit in Unit::ALL {
+            let (value, set, added, subtracted) = match unit {
+                Unit::Year => (
+                    date_time.year() as i64,
+                    date_time.set_year(7),
+                    date_time.add_years(30),
+                    date_time.sub_years(30),
+                ),
+                Unit::Month => (
+                    date_time.month() as i64,
+                    date_time.set_month(7),
+                    date_time.add_months(30),
+                    date_time.sub_months(30),
+                ),
+                Unit::Day => (
+                    date_time.day() as i64,
+                    date_time.set_day(7),
+                    date_time.add_days(30),
+                    date_time.sub_days(30),
+                ),
+                Unit::Hour => (
+                    date_time.hour() as i64,
+                    date_time.set_hour(7),
+                    date_time.add_hours(30),
+                    date_time.sub_hours(30),
+                ),
+                Unit::Minute => (
+                    date_time.minute() as i64,
+                    date_time.set_minute(7),
+                    date_time.add_minutes(30),
+                    date_time.sub_minutes(30),
+                ),
+                Unit::Second => (
+                    date_time.second() as i64,
+                    date_time.set_second(7),
+                    date_time.add_seconds(30),
+                    date_time.sub_seconds(30),
+                ),
+                Unit::Milli => (
+                    date_time.milli() as i64,
+                    date_time.set_milli(7),
+                    date_time.add_millis(30),
+                    date_time.sub_millis(30),
+                ),
+                Unit::Micro => (
+                    date_time.micro() as i64,
+                    date_time.set_micro(7),
+                    date_time.add_micros(30),
+                    date_time.sub_micros(30),
+                ),
+                Unit::Nano => (
+                    date_time.nano() as i64,
+                    date_time.set_nano(7),
+                    date_time.add_nanos(30),
+                    date_time.sub_nanos(30),
+                ),
+            };
+            assert_eq!(value, date_time.get_unit(unit));
+            assert_eq!(set.unwrap(), date_time.set_unit(unit, 7).unwrap());
+            assert_eq!(added, date_time.apply_unit(unit, 30).unwrap());
+            assert_eq!(subtracted, date_time.apply_unit(unit, -30).unwrap());
+            assert_eq!(date_time, date_time.apply_unit(unit, 0).unwrap());
+
+            assert!(date_time.set_unit(unit, i64::MAX).is_err());
+            assert!(date_time.set_unit(unit, i64::MIN).is_err());
+            // i64::MAX microseconds are about 292_000 years
+            let in_range = matches!(unit, Unit::Micro | Unit::Nano);
+            assert_eq!(in_range, date_time.apply_unit(unit, i64::MAX).is_ok());
+            assert_eq!(in_range, date_time.apply_unit(unit, i64::MIN).is_ok());
+        }
+
+        for unit in DateUnit::ALL {
+            assert_eq!(
+                date_time.date().get_unit(unit),
+                date_time.get_unit(unit.into())
+            );
+        }
+        for unit in TimeUnit::ALL {
+            assert_eq!(
+                date_time.time().get_unit(unit),
+                date_time.get_unit(unit.into())
+            );
+        }
+
+        let max = DateTime::from_ymdhms(5_879_611, 7, 12, 23, 59, 59).unwrap();
+        assert!(max.apply_unit(Unit::Second, 1).is_err());
+        assert!(max.apply_unit(Unit::Day, 1).is_err());
+        assert!(max.apply_unit(Unit::Hour, -1).is_ok());
+    }
+
+    #[test]
+    fn from_ymdhms_lenient() {
+        let format = "yyyy-MM-dd HH:mm:ss";
+        // (year, month, day, hour, minute, second, expected)
+        let cases = [
+            (2022, 5, 2, 12, 32, 1, "2022-05-02 12:32:01"),
+            (2022, 13, 1, 0, 0, 0, "2023-01-01 00:00:00"),
+            (2022, 0, 1, 0, 0, 0, "2021-12-01 00:00:00"),
+            (2022, 1, 0, 0, 0, 0, "2021-12-31 00:00:00"),
+            (2022, 1, 32, 0, 0, 0, "2022-02-01 00:00:00"),
+            (2022, 5, 2, 24, 0, 0, "2022-05-03 00:00:00"),
+            (2022, 12, 31, 24, 0, 0, "2023-01-01 00:00:00"),
+            (2022, 5, 2, 0, 60, 60, "2022-05-02 01:01:00"),
+            (2022, 1, 1, 0, 0, -1, "2021-12-31 23:59:59"),
+            (2022, 1, 1, -1, 0, 0, "2021-12-31 23:00:00"),
+            (2022, 1, 1, -24, -60, 0, "2021-12-30 23:00:00"),
+            (1, 1, 1, 0, 0, -1, "-0001-12-31 23:59:59"),
+            (2022, 5, 2, 0, 0, 86_400 * 365, "2023-05-02 00:00:00"),
+        ];
+        for (year, month, day, hour, minute, second, expected) in cases {
+            let date_time =
+                DateTime::from_ymdhms_lenient(year, month, day, hour, minute, second).unwrap();
+            assert_eq!(expected, date_time.format(format));
+        }
+
+        assert_eq!(
+            DateTime::from_ymdhms(5_879_611, 7, 12, 23, 59, 59).unwrap(),
+            DateTime::from_ymdhms_lenient(5_879_611, 7, 13, 0, 0, -1).unwrap()
+        );
+        assert!(DateTime::from_ymdhms_lenient(5_879_611, 7, 12, 24, 0, 0).is_err());
+        assert!(DateTime::from_ymdhms_lenient(-5_879_611, 6, 23, 0, 0, -1).is_err());
+        assert!(DateTime::from_ymdhms_lenient(0, 1, 1, 0, 0, 0).is_err());
+        assert!(DateTime::from_ymdhms_lenient(2022, 1, 1, i64::MAX, i64::MAX, i64::MAX).is_err());
+        assert!(DateTime::from_ymdhms_lenient(2022, 1, 1, i64::MIN, 0, 0).is_err());
+    }
+
+    #[test]
+    fn from_ordinal() {
+        let date_time = DateTime::from_ordinal(2024, 366).unwrap();
+        assert_eq!(
+            "2024-12-31 00:00:00",
+            date_time.format("yyyy-MM-dd HH:mm:ss")
+        );
+        assert_eq!(366, date_time.ordinal());
+        assert_eq!(
+            DateTime::from(Date::from_ordinal(-5, 60).unwrap()),
+            DateTime::from_ordinal(-5, 60).unwrap()
+        );
+
+        assert!(DateTime::from_ordinal(2022, 366).is_err());
+        assert!(DateTime::from_ordinal(-2, 366).is_err());
+    }
+
+    #[test]
+    fn month_overflow() {
+        let date_time = DateTime::from_ymdhms(2022, 1, 31, 12, 32, 1)
+            .unwrap()
+            .set_offset(Offset::Fixed(3600));
+        let clamped = date_time
+            .add_months_with(1, MonthOverflow::ClampToLastDay)
+            .unwrap();
+        assert_eq!("2022/02/28 13:32:01", clamped.to_string());
+        assert_eq!(date_time.get_offset(), clamped.get_offset());
+        assert_eq!(
+            "2022/03/01 13:32:01",
+            date_time
+                .add_months_with(1, MonthOverflow::RollToNextMonth)
+                .unwrap()
+                .to_string()
+        );
+        assert!(date_time.add_months_with(1, MonthOverflow::Error).is_err());
+        assert_eq!(
+            "2022/03/31 13:32:01",
+            date_time
+                .add_months_with(2, MonthOverflow::Error)
+                .unwrap()
+                .to_string()
+        );
+
+        let date_time = DateTime::from_ymdhms(2022, 3, 31, 23, 59, 59).unwrap();
+        assert_eq!(
+            "2022/02/28 23:59:59",
+            date_time
+                .sub_months_with(1, MonthOverflow::ClampToLastDay)
+                .unwrap()
+                .to_string()
+        );
+
+        let date_time = DateTime::from_ymdhms(2024, 2, 29, 0, 0, 0).unwrap();
+        assert_eq!(
+            "2025/03/01 00:00:00",
+            date_time
+                .add_years_with(1, MonthOverflow::RollToNextMonth)
+                .unwrap()
+                .to_string()
+        );
+        assert!(date_time.add_years_with(1, MonthOverflow::Error).is_err());
+        assert_eq!(
+            "2023/02/28 00:00:00",
+            date_time
+                .sub_years_with(1, MonthOverflow::ClampToLastDay)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "2020/02/29 00:00:00",
+            date_time
+                .sub_years_with(4, MonthOverflow::Error)
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn next_previous() {
+        let date_time = DateTime::from_ymdhms(2022, 12, 31, 23, 59, 59)
+            .unwrap()
+            .set_offset(Offset::Fixed(3600));
+        let next = date_time.next_second().unwrap();
+        assert_eq!((2023, 1, 1, 0, 0, 0), next.as_ymdhms());
+        assert_eq!(date_time.get_offset(), next.get_offset());
+        assert_eq!(date_time, next.previous_second().unwrap());
+        assert_eq!(date_time.add_nanos(1), date_time.next_nano().unwrap());
+        assert_eq!(date_time.sub_nanos(1), date_time.previous_nano().unwrap());
+        assert_eq!(
+            date_time,
+            date_time.previous_nano().unwrap().next_nano().unwrap()
+        );
+
+        let midnight = DateTime::from_ymd(2022, 5, 2).unwrap();
+        assert_eq!(
+            (2022, 5, 1, 23, 59, 59),
+            midnight.previous_second().unwrap().as_ymdhms()
+        );
+        assert_eq!(
+            (738_275, 86_399_999_999_999),
+            midnight.previous_nano().unwrap().as_raw()
+        );
+
+        let max = DateTime::from_raw(i32::MAX, 86_399_999_999_999).unwrap();
+        assert_eq!(None, max.next_nano());
+        assert_eq!(None, max.next_second());
+        assert_eq!(
+            (i32::MAX, 86_399_999_999_998),
+            max.previous_nano().unwrap().as_raw()
+        );
+        let last_second = DateTime::from_raw(i32::MAX, 86_398_999_999_999).unwrap();
+        assert_eq!(max, last_second.next_second().unwrap());
+        assert_eq!(
+            None,
+            DateTime::from_raw(i32::MAX, 86_399_000_000_000)
+                .unwrap()
+                .next_second()
+        );
+
+        let min = DateTime::from_raw(i32::MIN, 0).unwrap();
+        assert_eq!(None, min.previous_nano());
+        assert_eq!(None, min.previous_second());
+        assert_eq!((i32::MIN, 1), min.next_nano().unwrap().as_raw());
+        assert_eq!(
+            min,
+            DateTime::from_raw(i32::MIN, 1_000_000_000)
+                .unwrap()
+                .previous_second()
+                .unwrap()
+        );
+        assert_eq!(
+            None,
+            DateTime::from_raw(i32::MIN, 999_999_999)
+                .unwrap()
+                .previous_second()
+        );
+    }
+
+    #[test]
+    fn with() {
+        let date_time = DateTime::from_ymdhms(2020, 2, 29, 12, 32, 1)
+            .unwrap()
+            .set_nano(5)
+            .unwrap();
+
+        assert_eq!(
+            "2021-03-01 12:32:01.000000005",
+            date_time
+                .with_date(2021, 3, 1)
+                .unwrap()
+                .format("yyyy-MM-dd HH:mm:ss.nnnnn")
+        );
+        assert!(date_time.with_date(2021, 2, 29).is_err());
+        assert!(date_time.with_date(2021, 13, 1).is_err());
+        assert!(date_time.with_year(2021).is_err());
+        assert!(date_time.with_date(5_879_612, 1, 1).is_err());
+
+        assert_eq!(
+            "2024-01-31 09:00:00.000000005",
+            date_time
+                .with_year(2024)
+                .unwrap()
+                .with_month(1)
+                .unwrap()
+                .with_day(31)
+                .unwrap()
+                .with_hms(9, 0, 0)
+                .unwrap()
+                .format("yyyy-MM-dd HH:mm:ss.nnnnn")
+        );
+        assert_eq!(
+            "2020-02-29 12:32:01.000000000",
+            date_time
+                .with_nano(0)
+                .unwrap()
+                .format("yyyy-MM-dd HH:mm:ss.nnnnn")
+        );
+        assert!(date_time.with_hms(24, 0, 0).is_err());
+        assert!(date_time.with_hms(0, 60, 0).is_err());
+        assert!(date_time.with_nano(1_000_000_000).is_err());
+
+        let local = date_time.set_offset(Offset::Fixed(-3600 * 5));
+        let changed = local
+            .with_date(2021, 3, 1)
+            .unwrap()
+            .with_hms(23, 0, 0)
+            .unwrap();
+        assert_eq!(Offset::Fixed(-3600 * 5), changed.get_offset());
+        assert_eq!(
+            "2021-03-01 23:00:00-05:00",
+            changed.format("yyyy-MM-dd HH:mm:ssXXX")
+        );
+    }
+
+    #[test]
+    fn future_past_elapsed() {
+        let now = DateTime::from_ymdhms(2022, 5, 2, 12, 0, 0).unwrap();
+        let before = now.sub_nanos(1);
+        let after = now.add_nanos(1);
+
+        assert!(after.is_future_at(&now));
+        assert!(!after.is_past_at(&now));
+        assert!(before.is_past_at(&now));
+        assert!(!before.is_future_at(&now));
+        assert!(!now.is_future_at(&now));
+        assert!(!now.is_past_at(&now));
+
+        assert_eq!(Duration::from_nanos(1), before.elapsed_at(&now));
+        assert_eq!(Duration::ZERO, after.elapsed_at(&now));
+        assert_eq!(Duration::ZERO, now.elapsed_at(&now));
+        assert_eq!(
+            Duration::from_secs(86_400 * 365),
+            now.sub_years(1).elapsed_at(&now)
+        );
+
+        // Offsets are not relevant
+        let offset = Offset::from_hms(-5, 0, 0).unwrap();
+        assert!(after.set_offset(offset).is_future_at(&now));
+        assert!(before.is_past_at(&now.set_offset(offset)));
+        assert!(!now.set_offset(offset).is_future_at(&now));
+        assert_eq!(
+            Duration::from_nanos(1),
+            before.set_offset(offset).elapsed_at(&now)
+        );
+        // Same wall clock time, but five hours later
+        assert!(now.as_offset(offset).is_future_at(&now));
+
+        // Checks against a single snapshot are consistent with each other
+        let now = DateTime::now();
+        for date_time in [now.sub_seconds(1), now, now.add_seconds(1)] {
+            assert_eq!(
+                date_time.is_past_at(&now),
+                !date_time.elapsed_at(&now).is_zero()
+            );
+            assert!(!(date_time.is_past_at(&now) && date_time.is_future_at(&now)));
+        }
+
+        assert!(DateTime::now().add_hours(1).is_future());
+        assert!(DateTime::now().sub_hours(1).is_past());
+        assert!(DateTime::now().sub_hours(1).elapsed() >= Duration::from_secs(3600));
+        assert_eq!(Duration::ZERO, DateTime::now().add_hours(1).elapsed());
+    }
+
+    #[test]
+    fn format_relative() {
+        let now = DateTime::from_ymdhms(2022, 5, 2, 12, 0, 0).unwrap();
+
+        // Seconds from now, past and future phrasing
+        for (seconds, past, future) in [
+            (0, "just now", "just now"),
+            (1, "just now", "in a few seconds"),
+            (44, "just now", "in a few seconds"),
+            (45, "1 minute ago", "in 1 minute"),
+            (89, "1 minute ago", "in 1 minute"),
+            (90, "2 minutes ago", "in 2 minutes"),
+            (44 * 60 + 29, "44 minutes ago", "in 44 minutes"),
+            (44 * 60 + 30, "1 hour ago", "in 1 hour"),
+            (90 * 60, "2 hours ago", "in 2 hours"),
+            (21 * 3600 + 29 * 60, "21 hours ago", "in 21 hours"),
+            (21 * 3600 + 30 * 60, "1 day ago", "in 1 day"),
+            (36 * 3600, "2 days ago", "in 2 days"),
+            (25 * 86_400 + 11 * 3600, "25 days ago", "in 25 days"),
+            // Less than a full calendar month, but more than the days threshold
+            (25 * 86_400 + 12 * 3600, "1 month ago", "in 1 month"),
+        ] {
+            assert_eq!(past, now.sub_seconds(seconds).format_relative(&now));
+            assert_eq!(future, now.add_seconds(seconds).format_relative(&now));
+        }
+
+        // Calendar months and years from now
+        for (months, past, future) in [
+            (1, "1 month ago", "in 1 month"),
+            (2, "2 months ago", "in 2 months"),
+            (10, "10 months ago", "in 10 months"),
+            (11, "1 year ago", "in 1 year"),
+            (12, "1 year ago", "in 1 year"),
+            (23, "1 year ago", "in 1 year"),
+            (24, "2 years ago", "in 2 years"),
+            (100 * 12, "100 years ago", "in 100 years"),
+        ] {
+            assert_eq!(past, now.sub_months(months).format_relative(&now));
+            assert_eq!(future, now.add_months(months).format_relative(&now));
+        }
+
+        // Calendar differences instead of 30 or 365 days
+        let now = DateTime::from_ymd(2022, 3, 1).unwrap();
+        let date_time = DateTime::from_ymd(2022, 1, 1).unwrap();
+        assert_eq!("2 months ago", date_time.format_relative(&now));
+        assert_eq!("in 2 months", now.format_relative(&date_time));
+        let now = DateTime::from_ymd(2022, 12, 31).unwrap();
+        let date_time = DateTime::from_ymd(2020, 1, 1).unwrap();
+        assert_eq!("2 years ago", date_time.format_relative(&now));
+        assert_eq!("in 2 years", now.format_relative(&date_time));
+
+        // Offsets don't change the difference of instants
+        let offset = Offset::from_hms(-5, 0, 0).unwrap();
+        let date_time = now.sub_hours(3);
+        assert_eq!(
+            "3 hours ago",
+            date_time.set_offset(offset).format_relative(&now)
+        );
+        assert_eq!(
+            "3 hours ago",
+            date_time.format_relative(&now.set_offset(offset))
+        );
+
+        let thresholds = RelativeThresholds {
+            seconds: 10,
+            minutes: 60,
+            hours: 24,
+            days: 7,
+            months: 12,
+        };
+        for (date_time, expected) in [
+            (now.sub_seconds(9), "just now"),
+            (now.sub_seconds(10), "1 minute ago"),
+            (now.sub_minutes(59), "59 minutes ago"),
+            (now.sub_hours(23), "23 hours ago"),
+            (now.sub_days(6), "6 days ago"),
+            (now.sub_days(7), "1 month ago"),
+            (now.sub_months(11), "11 months ago"),
+            (now.add_months(12), "in 1 year"),
+        ] {
+            assert_eq!(expected, date_time.format_relative_with(&now, &thresholds));
+        }
+        assert_eq!(RelativeThresholds::DEFAULT, RelativeThresholds::default());
+    }
+
+    #[test]
+    fn same_day_month_year() {
+        let plus_twelve = Offset::from_hms(12, 0, 0).unwrap();
+        let minus_twelve = Offset::from_hms(-12, 0, 0).unwrap();
+
+        // 2022-05-02 12:00 UTC is 2022-05-03 00:00 at +12:00 and 2022-05-02 00:00 at -12:00
+        let utc = DateTime::from_ymdhms(2022, 5, 2, 12, 0, 0).unwrap();
+        let east = utc.set_offset(plus_twelve);
+        let west = utc.set_offset(minus_twelve);
+        assert_eq!(utc, east);
+        assert_eq!(utc, west);
+
+        assert!(utc.same_day_as(&west));
+        assert!(!utc.same_day_as(&east));
+        assert!(!east.same_day_as(&west));
+        assert!(east.same_day_as(&DateTime::from_ymd(2022, 5, 3).unwrap()));
+
+        // Evaluated in a common offset, the same instant is always on the same day
+        for offset in [Offset::Fixed(0), plus_twelve, minus_twelve] {
+            assert!(east.same_day_as_in(&west, offset));
+            assert!(east.same_month_as_in(&west, offset));
+            assert!(east.same_year_as_in(&west, offset));
+        }
+
+        // One nanosecond apart, but different days in +12:00 and -12:00
+        let before = DateTime::from_ymdhms(2022, 5, 2, 11, 59, 59)
+            .unwrap()
+            .set_nano(999_999_999)
+            .unwrap();
+        assert!(before.same_day_as(&utc));
+        assert!(before.same_day_as_in(&utc, Offset::Fixed(0)));
+        assert!(!before.same_day_as_in(&utc, plus_twelve));
+        assert!(!before.same_day_as_in(&utc, minus_twelve));
+        assert!(!before
+            .set_offset(plus_twelve)
+            .same_day_as(&utc.set_offset(plus_twelve)));
+
+        // Month and year boundaries
+        let new_year = DateTime::from_ymdhms(2022, 12, 31, 12, 0, 0).unwrap();
+        assert!(new_year.same_year_as(&new_year.set_offset(minus_twelve)));
+        assert!(!new_year.same_year_as(&new_year.set_offset(plus_twelve)));
+        assert!(!new_year.same_month_as(&new_year.set_offset(plus_twelve)));
+        assert!(new_year.same_month_as_in(&new_year.set_offset(plus_twelve), minus_twelve));
+        assert!(!new_year.same_year_as_in(&new_year.sub_nanos(1), plus_twelve));
+        assert!(new_year.same_year_as_in(&new_year.sub_nanos(1), Offset::Fixed(0)));
+
+        // Out of range local dates don't panic
+        let max = DateTime::from_ymdhms(5_879_611, 7, 12, 23, 59, 59).unwrap();
+        assert!(max.same_day_as_in(&max, Offset::Fixed(0)));
+        assert!(!max.same_day_as_in(&max, plus_twelve));
+        assert!(!max.same_month_as_in(&max, plus_twelve));
+        assert!(!max.same_year_as_in(&max, plus_twelve));
+
+        // Same month in different years
+        let date_time = DateTime::from_ymd(2022, 5, 2).unwrap();
+        assert!(!date_time.same_month_as(&date_time.sub_years(1)));
+        assert!(!date_time.same_day_as(&date_time.sub_years(1)));
+        assert!(date_time.same_month_as(&date_time.add_days(29)));
+        assert!(!date_time.same_month_as(&date_time.add_days(30)));
+    }
+
+    #[test]
+    fn from_ymdhms_nano() {
+        let field_name = |result: Result<DateTime, AstrolabeError>| match result {
+            Err(AstrolabeError::OutOfRange(error)) => error.field_name().to_string(),
+            other => panic!("Expected an out of range error, got {:?}", other),
+        };
+
+        let date_time = DateTime::from_ymdhms_nano(2022, 5, 2, 12, 32, 1, 123_456_789).unwrap();
+        assert_eq!(
+            (2022, 5, 2, 12, 32, 1, 123_456_789),
+            date_time.as_ymdhms_nano()
+        );
+        assert_eq!(
+            DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1)
+                .unwrap()
+                .set_nano(123_456_789)
+                .unwrap(),
+            date_time
+        );
+
+        // Boundaries of each component
+        assert!(DateTime::from_ymdhms_nano(-5_879_610, 6, 23, 0, 0, 0, 0).is_ok());
+        assert!(DateTime::from_ymdhms_nano(5_879_611, 7, 11, 23, 59, 59, 999_999_999).is_ok());
+        assert_eq!(
+            "year",
+            field_name(DateTime::from_ymdhms_nano(5_879_612, 1, 1, 0, 0, 0, 0))
+        );
+        assert_eq!(
+            "month",
+            field_name(DateTime::from_ymdhms_nano(2022, 0, 1, 0, 0, 0, 0))
+        );
+        assert_eq!(
+            "month",
+            field_name(DateTime::from_ymdhms_nano(2022, 13, 1, 0, 0, 0, 0))
+        );
+        assert_eq!(
+            "day",
+            field_name(DateTime::from_ymdhms_nano(2022, 2, 29, 0, 0, 0, 0))
+        );
+        assert!(DateTime::from_ymdhms_nano(2020, 2, 29, 0, 0, 0, 0).is_ok());
+        assert_eq!(
+            "hour",
+            field_name(DateTime::from_ymdhms_nano(2022, 5, 2, 24, 0, 0, 0))
+        );
+        assert_eq!(
+            "minute",
+            field_name(DateTime::from_ymdhms_nano(2022, 5, 2, 23, 60, 0, 0))
+        );
+        assert_eq!(
+            "second",
+            field_name(DateTime::from_ymdhms_nano(2022, 5, 2, 23, 59, 60, 0))
+        );
+        assert_eq!(
+            "nanoseconds",
+            field_name(DateTime::from_ymdhms_nano(
+                2022,
+                5,
+                2,
+                23,
+                59,
+                59,
+                1_000_000_000
+            ))
+        );
+
+        // The first invalid value is reported
+        assert_eq!(
+            "month",
+            field_name(DateTime::from_ymdhms_nano(
+                2022,
+                13,
+                32,
+                24,
+                60,
+                60,
+                1_000_000_000
+            ))
+        );
+        assert_eq!(
+            "hour",
+            field_name(DateTime::from_ymdhms_nano(
+                2022,
+                5,
+                2,
+                24,
+                60,
+                60,
+                1_000_000_000
+            ))
+        );
+
+        // Like as_ymdhms, the decomposition is in UTC
+        let date_time = date_time.set_offset(Offset::from_hms(12, 0, 0).unwrap());
+        assert_eq!(
+            (2022, 5, 2, 12, 32, 1, 123_456_789),
+            date_time.as_ymdhms_nano()
+        );
+    }
 }
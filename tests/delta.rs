@@ -0,0 +1,512 @@
+mod common;
+
+#[cfg(test)]
+mod delta_tests {
+    use crate::common::Random;
+    use astrolabe::{
+        errors::AstrolabeError, DateTime, DateTimeDelta, DateUtilities, Offset, OffsetUtilities,
+        Precision, TimeUtilities,
+    };
+
+    #[test]
+    fn since() {
+        let start = DateTime::from_ymdhms(2022, 5, 2, 10, 20, 30).unwrap();
+        assert_eq!(DateTimeDelta::default(), start.since(&start));
+
+        let end = DateTime::from_ymdhms(2025, 7, 7, 14, 22, 31)
+            .unwrap()
+            .add_nanos(500_000_000);
+        let delta = end.since(&start);
+        assert_eq!(
+            DateTimeDelta {
+                years: 3,
+                months: 2,
+                days: 5,
+                hours: 4,
+                minutes: 2,
+                seconds: 1,
+                nanos: 500_000_000,
+            },
+            delta
+        );
+        assert_eq!(end, start + delta);
+
+        let delta = start.since(&end);
+        assert_eq!(
+            DateTimeDelta {
+                years: -3,
+                months: -2,
+                days: -5,
+                hours: -4,
+                minutes: -2,
+                seconds: -1,
+                nanos: -500_000_000,
+            },
+            delta
+        );
+        assert_eq!(start, end + delta);
+
+        // Less than a day
+        let end = DateTime::from_ymdhms(2022, 5, 3, 9, 20, 30).unwrap();
+        let delta = end.since(&start);
+        assert_eq!(
+            DateTimeDelta {
+                hours: 23,
+                ..Default::default()
+            },
+            delta
+        );
+    }
+
+    #[test]
+    fn since_month_end() {
+        // (self, other, expected years, months, days)
+        let cases = [
+            ((2022, 3, 31), (2022, 1, 31), 0, 2, 0),
+            ((2022, 3, 30), (2022, 1, 31), 0, 1, 30),
+            ((2022, 3, 2), (2022, 1, 31), 0, 1, 2),
+            ((2022, 2, 28), (2022, 1, 31), 0, 0, 28),
+            ((2024, 2, 29), (2024, 1, 31), 0, 0, 29),
+            ((2024, 3, 1), (2024, 1, 31), 0, 1, 1),
+            ((2025, 2, 28), (2024, 2, 29), 0, 11, 30),
+            ((2025, 3, 1), (2024, 2, 29), 1, 0, 1),
+            ((2022, 1, 31), (2022, 3, 31), 0, -2, 0),
+            ((2022, 2, 28), (2022, 3, 31), 0, -1, 0),
+            ((2022, 2, 27), (2022, 3, 31), 0, -1, -1),
+            ((2022, 1, 31), (2022, 3, 1), 0, -1, -1),
+            ((1, 1, 1), (-1, 12, 31), 0, 0, 1),
+            ((1, 2, 1), (-1, 12, 1), 0, 2, 0),
+            ((-1, 12, 1), (1, 2, 1), 0, -2, 0),
+        ];
+
+        for ((year, month, day), (other_year, other_month, other_day), years, months, days) in cases
+        {
+            let date_time = DateTime::from_ymd(year, month, day).unwrap();
+            let other = DateTime::from_ymd(other_year, other_month, other_day).unwrap();
+            let delta = date_time.since(&other);
+            assert_eq!(
+                (years, months, days),
+                (delta.years, delta.months, delta.days),
+                "{} since {}",
+                date_time,
+                other
+            );
+            assert_eq!(date_time, other + delta, "{} since {}", date_time, other);
+        }
+    }
+
+    #[test]
+    fn since_offset() {
+        // 2022-05-01 01:00 +12:00 since 2022-03-31 23:00 -12:00
+        let date_time = DateTime::from_ymdhms(2022, 4, 30, 13, 0, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(43200));
+        let other = DateTime::from_ymdhms(2022, 4, 1, 11, 0, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(-43200));
+
+        // Counted in the offset of `other`: 2022-04-30 01:00 since 2022-03-31 23:00
+        let delta = date_time.since(&other);
+        assert_eq!(
+            DateTimeDelta {
+                days: 29,
+                hours: 2,
+                ..Default::default()
+            },
+            delta
+        );
+        assert_eq!(date_time, other + delta);
+        assert_eq!(Offset::Fixed(-43200), (other + delta).get_offset());
+    }
+
+    #[test]
+    fn since_randomized() {
+        let mut random = Random::new(0x9e37_79b9_7f4a_7c15);
+
+        for _ in 0..1000 {
+            let date_time =
+                DateTime::from_timestamp(random.below(20_000_000_000) as i64 - 10_000_000_000)
+                    .add_nanos(random.below(1_000_000_000) as u32);
+            let other =
+                DateTime::from_timestamp(random.below(20_000_000_000) as i64 - 10_000_000_000)
+                    .add_nanos(random.below(1_000_000_000) as u32);
+
+            let delta = date_time.since(&other);
+            assert_eq!(
+                date_time,
+                other + delta,
+                "{:?} since {:?}",
+                date_time,
+                other
+            );
+            assert_eq!(date_time.months_since(&other) / 12, delta.years);
+            assert_eq!(date_time.months_since(&other) % 12, delta.months);
+        }
+    }
+
+    #[test]
+    fn display() {
+        let start = DateTime::from_ymd(2022, 1, 31).unwrap();
+
+        assert_eq!("0 seconds", start.since(&start).to_string());
+        assert_eq!("1 second", start.add_seconds(1).since(&start).to_string());
+        assert_eq!(
+            "-1.25 seconds",
+            start.since(&start.add_millis(1250)).to_string()
+        );
+        assert_eq!(
+            "1 year, 1 month, 1 day, 1 hour, 1 minute, 1.000000001 seconds",
+            DateTimeDelta {
+                years: 1,
+                months: 1,
+                days: 1,
+                hours: 1,
+                minutes: 1,
+                seconds: 1,
+                nanos: 1,
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "-2 years, 3 days",
+            DateTimeDelta {
+                years: -2,
+                days: -3,
+                ..Default::default()
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_overflow() {
+        let date_time = DateTime::from_ymd(5_879_611, 6, 13).unwrap();
+        let _ = date_time
+            + DateTimeDelta {
+                months: 1,
+                ..Default::default()
+            };
+    }
+
+    #[test]
+    fn calendar_duration_between() {
+        // (earlier, later, expected years, months, days)
+        let cases = [
+            ((2022, 1, 31), (2022, 1, 31), 0, 0, 0),
+            ((2022, 1, 31), (2022, 2, 27), 0, 0, 27),
+            ((2022, 1, 31), (2022, 2, 28), 0, 1, 0),
+            ((2022, 1, 31), (2022, 3, 1), 0, 1, 1),
+            ((2022, 1, 31), (2022, 3, 31), 0, 2, 0),
+            ((2024, 1, 31), (2024, 2, 28), 0, 0, 28),
+            ((2024, 1, 31), (2024, 2, 29), 0, 1, 0),
+            ((2024, 2, 29), (2025, 2, 28), 1, 0, 0),
+            ((2024, 2, 29), (2025, 3, 1), 1, 0, 1),
+            ((2024, 2, 29), (2028, 2, 28), 3, 11, 30),
+            ((2024, 2, 29), (2028, 2, 29), 4, 0, 0),
+            ((2023, 2, 28), (2024, 2, 29), 1, 0, 1),
+            ((2022, 2, 27), (2022, 3, 31), 0, 1, 4),
+            ((-1, 12, 31), (1, 1, 1), 0, 0, 1),
+            ((-1, 12, 1), (1, 2, 1), 0, 2, 0),
+            ((-1, 2, 29), (1, 2, 28), 1, 0, 0),
+            ((-5, 2, 29), (-1, 2, 29), 4, 0, 0),
+            ((-1, 1, 31), (-1, 2, 29), 0, 1, 0),
+            ((-2, 3, 31), (2, 4, 30), 3, 1, 0),
+        ];
+
+        for ((year, month, day), (other_year, other_month, other_day), years, months, days) in cases
+        {
+            let earlier = DateTime::from_ymdhms(year, month, day, 10, 0, 0).unwrap();
+            let later =
+                DateTime::from_ymdhms(other_year, other_month, other_day, 10, 0, 0).unwrap();
+            let delta = later.calendar_duration_between(&earlier);
+            assert_eq!(
+                DateTimeDelta {
+                    years,
+                    months,
+                    days,
+                    ..Default::default()
+                },
+                delta,
+                "{} to {}",
+                earlier,
+                later
+            );
+            assert_eq!(delta, earlier.calendar_duration_between(&later));
+            assert_eq!(later, earlier + delta, "{} to {}", earlier, later);
+        }
+
+        // Time of day before the anchor time doesn't complete the month
+        let earlier = DateTime::from_ymdhms(2022, 1, 31, 12, 0, 0).unwrap();
+        let later = DateTime::from_ymdhms(2022, 2, 28, 11, 59, 59).unwrap();
+        assert_eq!(
+            DateTimeDelta {
+                days: 27,
+                hours: 23,
+                minutes: 59,
+                seconds: 59,
+                ..Default::default()
+            },
+            later.calendar_duration_between(&earlier)
+        );
+
+        // Counted in the offset of the earlier instance
+        let earlier = DateTime::from_ymdhms(2022, 1, 31, 0, 0, 0)
+            .unwrap()
+            .as_offset(Offset::Fixed(3600));
+        let later = DateTime::from_ymdhms(2022, 2, 27, 23, 30, 0).unwrap();
+        let delta = later.calendar_duration_between(&earlier);
+        assert_eq!(
+            DateTimeDelta {
+                months: 1,
+                minutes: 30,
+                ..Default::default()
+            },
+            delta
+        );
+        assert_eq!(later, earlier + delta);
+    }
+
+    #[test]
+    fn calendar_duration_between_randomized() {
+        let mut random = Random::new(0x2545_f491_4f6c_dd1d);
+
+        for _ in 0..1000 {
+            let date_time =
+                DateTime::from_timestamp(random.below(140_000_000_000) as i64 - 70_000_000_000)
+                    .add_nanos(random.below(1_000_000_000) as u32);
+            let other =
+                DateTime::from_timestamp(random.below(140_000_000_000) as i64 - 70_000_000_000)
+                    .add_nanos(random.below(1_000_000_000) as u32);
+            let (earlier, later) = if date_time < other {
+                (date_time, other)
+            } else {
+                (other, date_time)
+            };
+
+            let delta = date_time.calendar_duration_between(&other);
+            assert!(!delta.is_negative());
+            assert_eq!(later, earlier + delta, "{:?} to {:?}", earlier, later);
+            // One more month would be after the later instance
+            let next_month = earlier
+                + DateTimeDelta {
+                    years: delta.years,
+                    months: delta.months + 1,
+                    ..Default::default()
+                };
+            assert!(next_month > later, "{:?} to {:?}", earlier, later);
+        }
+    }
+
+    #[test]
+    fn iso8601() {
+        for string in [
+            "P1Y2M10DT2H30M",
+            "P1Y",
+            "P11M",
+            "P3D",
+            "PT1H",
+            "PT1M",
+            "PT1S",
+            "PT0.5S",
+            "PT1.000000001S",
+            "P1DT23H59M59.999999999S",
+            "-P1Y2M10DT2H30M",
+            "-PT0.25S",
+            "PT0S",
+        ] {
+            let delta = DateTimeDelta::parse_iso8601(string).unwrap();
+            assert_eq!(string, delta.format_iso8601());
+            assert_eq!(
+                delta,
+                DateTimeDelta::parse_iso8601(&delta.format_iso8601()).unwrap()
+            );
+        }
+
+        assert_eq!(
+            DateTimeDelta {
+                years: 1,
+                months: 2,
+                days: 10,
+                hours: 2,
+                minutes: 30,
+                seconds: 0,
+                nanos: 0,
+            },
+            DateTimeDelta::parse_iso8601("P1Y2M10DT2H30M").unwrap()
+        );
+        assert_eq!(
+            DateTimeDelta {
+                days: -15,
+                seconds: -1,
+                nanos: -500_000_000,
+                ..Default::default()
+            },
+            DateTimeDelta::parse_iso8601("-P2W1DT1,5S").unwrap()
+        );
+
+        // Larger values are carried
+        assert_eq!(
+            "P1Y2M",
+            DateTimeDelta::parse_iso8601("P14M")
+                .unwrap()
+                .format_iso8601()
+        );
+        assert_eq!(
+            "P1DT12H",
+            DateTimeDelta::parse_iso8601("PT36H")
+                .unwrap()
+                .format_iso8601()
+        );
+        assert_eq!(
+            "PT1M30S",
+            DateTimeDelta::parse_iso8601("PT90S")
+                .unwrap()
+                .format_iso8601()
+        );
+        assert_eq!(
+            "P14D",
+            DateTimeDelta::parse_iso8601("P2W")
+                .unwrap()
+                .format_iso8601()
+        );
+        assert_eq!(
+            "P1Y",
+            DateTimeDelta::parse_iso8601("+P1Y")
+                .unwrap()
+                .format_iso8601()
+        );
+        assert_eq!(
+            "PT0S",
+            DateTimeDelta::parse_iso8601("P0D")
+                .unwrap()
+                .format_iso8601()
+        );
+
+        // Round trip of computed deltas
+        let start = DateTime::from_ymdhms(2022, 1, 31, 8, 0, 0).unwrap();
+        let end = DateTime::from_ymdhms(2023, 3, 2, 12, 30, 0)
+            .unwrap()
+            .add_nanos(120_000_000);
+        let delta = end.since(&start);
+        assert_eq!("P1Y1M2DT4H30M0.12S", delta.format_iso8601());
+        assert_eq!(
+            delta,
+            DateTimeDelta::parse_iso8601(&delta.format_iso8601()).unwrap()
+        );
+        let delta = start.since(&end);
+        assert_eq!("-P1Y1M2DT4H30M0.12S", delta.format_iso8601());
+        assert_eq!(
+            delta,
+            DateTimeDelta::parse_iso8601(&delta.format_iso8601()).unwrap()
+        );
+    }
+
+    #[test]
+    fn iso8601_errors() {
+        for string in [
+            "",
+            "-",
+            "1Y",
+            "P",
+            "PT",
+            "P1",
+            "P1YT",
+            "PY",
+            "P1M1Y",
+            "P1D1D",
+            "P1H",
+            "PT1D",
+            "PT1S1M",
+            "P1DTT1H",
+            "P1.5D",
+            "PT1.S",
+            "PT1.5M",
+            "PT1.1234567891S",
+            "P1Y ",
+            "P1Ä",
+            "p1y",
+            "--P1Y",
+            "P99999999999999999999Y",
+        ] {
+            assert!(
+                matches!(
+                    DateTimeDelta::parse_iso8601(string),
+                    Err(AstrolabeError::InvalidFormat(_))
+                ),
+                "{}",
+                string
+            );
+        }
+
+        assert!(matches!(
+            DateTimeDelta::parse_iso8601("P178956971Y"),
+            Err(AstrolabeError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            DateTimeDelta::parse_iso8601("P2147483648D"),
+            Err(AstrolabeError::OutOfRange(_))
+        ));
+        assert!(DateTimeDelta::parse_iso8601("P2147483647D").is_ok());
+    }
+
+    #[test]
+    fn add_relative() {
+        let date_time = DateTime::from_ymd(2022, 1, 31).unwrap();
+        let delta = DateTimeDelta::parse_iso8601("P1M").unwrap();
+        assert_eq!(
+            DateTime::from_ymd(2022, 2, 28).unwrap(),
+            date_time.add_relative(&delta).unwrap()
+        );
+        assert_eq!(date_time + delta, date_time.add_relative(&delta).unwrap());
+
+        // Calendar units are applied before exact units
+        let delta = DateTimeDelta::parse_iso8601("P1MT24H").unwrap();
+        assert_eq!(
+            DateTime::from_ymd(2022, 3, 1).unwrap(),
+            date_time.add_relative(&delta).unwrap()
+        );
+        let delta = DateTimeDelta::parse_iso8601("-P1MT1H").unwrap();
+        assert_eq!(
+            DateTime::from_ymdhms(2021, 12, 30, 23, 0, 0).unwrap(),
+            date_time.add_relative(&delta).unwrap()
+        );
+
+        let date_time = DateTime::from_ymd(2022, 3, 31).unwrap();
+        let delta = DateTimeDelta::parse_iso8601("P1MT1H").unwrap();
+        assert_eq!(
+            DateTime::from_ymdhms(2022, 2, 27, 23, 0, 0).unwrap(),
+            date_time.sub_relative(&delta).unwrap()
+        );
+        let delta = DateTimeDelta::parse_iso8601("-P1M").unwrap();
+        assert_eq!(
+            DateTime::from_ymd(2022, 4, 30).unwrap(),
+            date_time.sub_relative(&delta).unwrap()
+        );
+
+        // Months are added in the calendar of the offset
+        let date_time = DateTime::from_ymdhms(2022, 1, 31, 23, 0, 0)
+            .unwrap()
+            .as_offset(Offset::Fixed(3600));
+        let delta = DateTimeDelta::parse_iso8601("P1M").unwrap();
+        assert_eq!(
+            "2022-02-28T23:00:00+01:00",
+            date_time
+                .add_relative(&delta)
+                .unwrap()
+                .format_rfc3339(Precision::Seconds)
+        );
+
+        let delta = DateTimeDelta::parse_iso8601("P2147483647D").unwrap();
+        assert!(matches!(
+            DateTime::from_ymd(2022, 1, 1).unwrap().add_relative(&delta),
+            Err(AstrolabeError::OutOfRange(_))
+        ));
+        assert!(matches!(
+            DateTime::from_days_nanos(-2, 0)
+                .unwrap()
+                .sub_relative(&delta),
+            Err(AstrolabeError::OutOfRange(_))
+        ));
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod errors_tests {
-    use astrolabe::DateTime;
+    use astrolabe::{errors::AstrolabeError, validate_format, Date, DateTime, Time};
+    use std::error::Error;
     use std::{
         collections::hash_map::DefaultHasher,
         hash::{Hash, Hasher},
@@ -53,4 +54,156 @@ mod errors_tests {
         let clone = invalid_format.clone();
         assert!(invalid_format == clone);
     }
+
+    #[test]
+    fn out_of_range_fields() {
+        let error = match DateTime::from_ymd(2022, 13, 1).unwrap_err() {
+            AstrolabeError::OutOfRange(error) => error,
+            error => panic!("Expected out of range error: {:?}", error),
+        };
+        assert_eq!("month", error.field_name());
+        assert_eq!(1, error.min());
+        assert_eq!(12, error.max());
+        assert_eq!(13, error.value());
+
+        let error = match Time::parse("24", "HH").unwrap_err() {
+            AstrolabeError::OutOfRange(error) => error,
+            error => panic!("Expected out of range error: {:?}", error),
+        };
+        assert_eq!("hour", error.field_name());
+        assert_eq!(0, error.min());
+        assert_eq!(23, error.max());
+        assert_eq!(24, error.value());
+        assert_eq!(None, error.span());
+    }
+
+    #[test]
+    fn out_of_range_span() {
+        let out_of_range = |result: Result<Date, AstrolabeError>| match result.unwrap_err() {
+            AstrolabeError::OutOfRange(error) => error,
+            error => panic!("Expected out of range error: {:?}", error),
+        };
+
+        // The day is parsed before the month it doesn't exist in
+        let error = out_of_range(Date::parse("31-02-2022", "dd-MM-yyyy"));
+        assert_eq!("day", error.field_name());
+        assert_eq!(28, error.max());
+        assert_eq!(31, error.value());
+        assert_eq!(Some(0..2), error.span());
+
+        let error = out_of_range(Date::parse("2022-02-29", "yyyy-MM-dd"));
+        assert_eq!(Some(8..10), error.span());
+        let error = out_of_range(Date::parse("29.Feb 2022", "d.MMM yyyy"));
+        assert_eq!(Some(0..2), error.span());
+        let error = out_of_range(Date::parse("0000-02-01", "yyyy-MM-dd"));
+        assert_eq!("year", error.field_name());
+        assert_eq!(Some(0..4), error.span());
+        let error = out_of_range(Date::parse("2022, day 366", "yyyy, 'day' DDD"));
+        assert_eq!("day of year", error.field_name());
+        assert_eq!(Some(10..13), error.span());
+        let error = out_of_range(Date::parse("2022 Q5", "yyyy 'Q'q"));
+        assert_eq!("quarter", error.field_name());
+        assert_eq!(Some(6..7), error.span());
+
+        let error = match DateTime::parse("31.04.2022 12:00", "dd.MM.yyyy HH:mm").unwrap_err() {
+            AstrolabeError::OutOfRange(error) => error,
+            error => panic!("Expected out of range error: {:?}", error),
+        };
+        assert_eq!("day", error.field_name());
+        assert_eq!(Some(0..2), error.span());
+
+        // Day of year takes precedence over month and day of month
+        let date = Date::parse("31-02-2022 032", "dd-MM-yyyy DDD").unwrap();
+        assert_eq!("2022/02/01", date.to_string());
+        let date = DateTime::parse("032 2022-12-31", "DDD yyyy-MM-dd").unwrap();
+        assert_eq!("2022/02/01 00:00:00", date.to_string());
+        assert!(matches!(
+            Date::parse_checked("31-12-2022 032", "dd-MM-yyyy DDD"),
+            Err(AstrolabeError::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn invalid_format_fields() {
+        // (error, position, token)
+        let cases = [
+            (
+                Date::parse("2022-Mai-02", "yyyy-MMM-dd").unwrap_err(),
+                Some(5),
+                Some("MMM"),
+            ),
+            (
+                Date::parse("2022-05-xx", "yyyy-MM-dd").unwrap_err(),
+                Some(8),
+                Some("dd"),
+            ),
+            (
+                Time::parse("12:3", "HH:mm").unwrap_err(),
+                Some(3),
+                Some("mm"),
+            ),
+            (
+                DateTime::parse("2022-05-02 12:32 PM", "yyyy-MM-dd HH:mm X").unwrap_err(),
+                Some(17),
+                Some("X"),
+            ),
+            (
+                DateTime::parse_rfc3339("2022-0x-02T12:32:01Z").unwrap_err(),
+                Some(5),
+                Some("MM"),
+            ),
+            (
+                DateTime::parse_rfc3339("2022-05-02T12:32:01+2:00").unwrap_err(),
+                Some(19),
+                Some("XXX"),
+            ),
+            (
+                validate_format("yyyy-MM-dd ffff").unwrap_err(),
+                Some(11),
+                Some("ffff"),
+            ),
+            (
+                validate_format("yyyy-MM-dd dddd").unwrap_err(),
+                Some(11),
+                Some("dddd"),
+            ),
+            (
+                validate_format("yyyy 'at HH:mm").unwrap_err(),
+                Some(5),
+                Some("'at HH:mm"),
+            ),
+            (
+                Date::from_ymd(2022, 5, 2)
+                    .unwrap()
+                    .try_format("yyyy HH")
+                    .unwrap_err(),
+                Some(5),
+                Some("HH"),
+            ),
+            (DateTime::parse_rfc3339("test").unwrap_err(), None, None),
+        ];
+        for (error, position, token) in cases {
+            let message = error.to_string();
+            match error {
+                AstrolabeError::InvalidFormat(error) => {
+                    assert_eq!(message, error.message());
+                    assert_eq!(position, error.position(), "{}", message);
+                    assert_eq!(token, error.token(), "{}", message);
+                }
+                error => panic!("Expected invalid format error: {:?}", error),
+            }
+        }
+    }
+
+    #[test]
+    fn source() {
+        let error = DateTime::from_ymd(2022, 13, 1).unwrap_err();
+        assert_eq!(error.to_string(), error.source().unwrap().to_string());
+
+        let error = DateTime::parse_rfc3339("test").unwrap_err();
+        assert_eq!(error.to_string(), error.source().unwrap().to_string());
+
+        let boxed: Box<dyn Error> = Box::new(error);
+        assert!(boxed.source().is_some());
+    }
 }
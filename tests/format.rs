@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod format_tests {
-    use astrolabe::{Date, DateTime, Offset, OffsetUtilities, Time};
+    use astrolabe::{
+        format_spec, is_valid_format, Date, DateTime, Offset, OffsetUtilities, Time, TimeUtilities,
+    };
 
     #[test]
     fn era() {
@@ -20,6 +22,25 @@ mod format_tests {
         assert_eq!("B", date.format("GGGGG"));
         assert_eq!("Before Christ", date.format("GGGGGG"));
         assert_eq!("Before Christ", date.format("GGGGGGG"));
+
+        // With an era, the year is the year of the era
+        let date = Date::from_ymd(-2022, 5, 2).unwrap();
+        assert_eq!("BC2022", date.format("Gyyyy"));
+        assert_eq!("2022 BC", date.format("yyyy G"));
+        assert_eq!("BC 22", date.format("G yy"));
+        assert_eq!("BC 0002022", date.format("G yyyyyyy"));
+        assert_eq!("-2022", date.format("yyyy"));
+        assert_eq!("G-2022", date.format("'G'yyyy"));
+        let date_time = DateTime::from_ymdhms(-2022, 5, 2, 12, 32, 1).unwrap();
+        assert_eq!(
+            "Before Christ 2022 12:32",
+            date_time.format("GGGG yyyy HH:mm")
+        );
+        assert_eq!("-2022 12:32", date_time.format("yyyy HH:mm"));
+        assert_eq!(
+            "AD 2022",
+            Date::from_ymd(2022, 5, 2).unwrap().format("G yyyy")
+        );
     }
 
     #[test]
@@ -65,6 +86,64 @@ mod format_tests {
         assert_eq!("-002", date.format("yyy"));
         assert_eq!("-0002", date.format("yyyy"));
         assert_eq!("-00002", date.format("yyyyy"));
+
+        let date = Date::from_ymd(-134, 1, 1).unwrap();
+        assert_eq!("-34", date.format("yy"));
+        let date = Date::from_ymd(100_000, 1, 1).unwrap();
+        assert_eq!("00", date.format("yy"));
+        assert_eq!("100000", date.format("yyyyy"));
+    }
+
+    #[test]
+    fn signed_year() {
+        let date = Date::from_ymd(2022, 1, 1).unwrap();
+        assert_eq!("+2022", date.format("+y"));
+        assert_eq!("+2022", date.format("+yy"));
+        assert_eq!("+002022", date.format("+yyyyyy"));
+        assert_eq!("+002022-01-01", date.format("+yyyyyy-MM-dd"));
+
+        let date = Date::from_ymd(-34, 1, 1).unwrap();
+        assert_eq!("-34", date.format("+y"));
+        assert_eq!("-0034", date.format("+yyyy"));
+        assert_eq!("-000034", date.format("+yyyyyy"));
+
+        let date_time = DateTime::from_ymd(5, 1, 1).unwrap();
+        assert_eq!("+0005", date_time.format("+yyyy"));
+        assert_eq!(
+            "+100000",
+            Date::from_ymd(100_000, 1, 1).unwrap().format("+yyyy")
+        );
+
+        // Escaped or not followed by a year, + is a literal
+        assert_eq!("+2022", Date::from_ymd(2022, 1, 1).unwrap().format("'+'y"));
+        assert_eq!(
+            "2022 +01",
+            Date::from_ymd(2022, 1, 1).unwrap().format("y +MM")
+        );
+        assert_eq!("++01", Date::from_ymd(2022, 1, 1).unwrap().format("++MM"));
+        assert_eq!(
+            "12:00 +yy",
+            Time::from_hms(12, 0, 0).unwrap().format("HH:mm +yy")
+        );
+    }
+
+    #[test]
+    fn extended_year() {
+        for (year, extended) in [(-2, "-1"), (-1, "0"), (1, "1"), (2022, "2022")] {
+            assert_eq!(extended, Date::from_ymd(year, 1, 1).unwrap().format("u"));
+        }
+        let date = Date::from_ymd(-1, 1, 1).unwrap();
+        assert_eq!("0000", date.format("uuuu"));
+        let date = Date::from_ymd(-34, 1, 1).unwrap();
+        assert_eq!("-033", date.format("uuu"));
+
+        // y and u only diverge for years before 0001
+        for year in [-100_000, -34, -2, -1, 1, 2, 2022, 100_000] {
+            let date = Date::from_ymd(year, 5, 2).unwrap();
+            assert_eq!(year > 0, date.format("yyyy") == date.format("uuuu"));
+        }
+        let date_time = DateTime::from_ymd(-1, 5, 2).unwrap();
+        assert_eq!("0000-05-02 -0001", date_time.format("uuuu-MM-dd yyyy"));
     }
 
     #[test]
@@ -233,6 +312,18 @@ mod format_tests {
         assert_eq!("10", date.format("ww"));
     }
 
+    #[test]
+    fn week_of_month() {
+        let date = Date::from_ymd(2022, 10, 1).unwrap();
+        assert_eq!("1", date.format("W"));
+        let date = Date::from_ymd(2022, 10, 31).unwrap();
+        assert_eq!("6", date.format("W"));
+        let date_time = DateTime::from_ymdhms(2022, 10, 2, 0, 0, 0).unwrap();
+        assert_eq!("2", date_time.format("W"));
+        assert_eq!("2022-10 W2", date_time.format("yyyy-MM 'W'W"));
+        assert!(is_valid_format("yyyy-MM W"));
+    }
+
     #[test]
     fn day() {
         let date = Date::from_ymd(1970, 1, 1).unwrap();
@@ -251,6 +342,52 @@ mod format_tests {
         assert_eq!("31", date.format("ddd"));
     }
 
+    #[test]
+    fn ordinal_suffix() {
+        let suffixes = [
+            (1, "1st"),
+            (2, "2nd"),
+            (3, "3rd"),
+            (4, "4th"),
+            (10, "10th"),
+            (11, "11th"),
+            (12, "12th"),
+            (13, "13th"),
+            (14, "14th"),
+            (20, "20th"),
+            (21, "21st"),
+            (22, "22nd"),
+            (23, "23rd"),
+            (24, "24th"),
+            (30, "30th"),
+            (31, "31st"),
+        ];
+        for (day, expected) in suffixes {
+            let date = Date::from_ymd(2022, 5, day).unwrap();
+            assert_eq!(expected, date.format("do"));
+            assert_eq!(expected, DateTime::from(date).format("do"));
+        }
+
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap();
+        assert_eq!("May 2nd, 2022", date_time.format("MMM do, yyyy"));
+        assert_eq!("02nd", date_time.format("ddo"));
+        // Not a time symbol, passed through as it is
+        assert_eq!("12o", Time::from_hms(12, 0, 0).unwrap().format("Ho"));
+        assert!(is_valid_format("MMM do, yyyy"));
+
+        // Only valid directly after the day of month
+        assert!(is_valid_format("ddo"));
+        for format in ["o", "yo", "Wo", "d'x'o", "d o", "d''o"] {
+            assert!(!is_valid_format(format), "{}", format);
+            assert!(astrolabe::validate_format(format).is_err(), "{}", format);
+            assert!(date_time.try_format(format).is_err(), "{}", format);
+        }
+        assert_eq!(
+            "Ordinal suffix 'o' at position 4 has to directly follow the day of month ('d' or 'dd').",
+            astrolabe::validate_format("yyyyo").unwrap_err().to_string()
+        );
+    }
+
     #[test]
     fn year_day() {
         let date = Date::from_ymd(1970, 1, 1).unwrap();
@@ -259,6 +396,12 @@ mod format_tests {
         assert_eq!("001", date.format("DDD"));
         assert_eq!("1", date.format("DDDD"));
 
+        let date = Date::from_ymd(2022, 1, 5).unwrap();
+        assert_eq!("5", date.format("D"));
+        assert_eq!("05", date.format("DD"));
+        assert_eq!("005", date.format("DDD"));
+        assert_eq!("5", date.format("DDDD"));
+
         let date = Date::from_ymd(2020, 1, 24).unwrap();
         assert_eq!("24", date.format("D"));
         assert_eq!("24", date.format("DD"));
@@ -282,6 +425,31 @@ mod format_tests {
         assert_eq!("365", date.format("DD"));
         assert_eq!("365", date.format("DDD"));
         assert_eq!("365", date.format("DDDD"));
+
+        let date = Date::from_ymd(2024, 12, 31).unwrap();
+        assert_eq!("366", date.format("D"));
+        assert_eq!("366", date.format("DD"));
+        assert_eq!("366", date.format("DDD"));
+        assert_eq!("366", date.format("DDDD"));
+    }
+
+    #[test]
+    fn modified_julian_day() {
+        let date = Date::from_ymd(2022, 5, 2).unwrap();
+        assert_eq!("59701", date.format("g"));
+        assert_eq!("0059701", date.format("ggggggg"));
+        let date = Date::from_ymd(1858, 11, 17).unwrap();
+        assert_eq!("0", date.format("g"));
+        assert_eq!("00", date.format("gg"));
+        let date = Date::from_ymd(1858, 11, 16).unwrap();
+        assert_eq!("-1", date.format("g"));
+        assert_eq!("-001", date.format("ggg"));
+
+        // Uses the local date
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 23, 0, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(3600));
+        assert_eq!("59702", date_time.format("g"));
     }
 
     #[test]
@@ -390,6 +558,51 @@ mod format_tests {
         assert_eq!("pm", time.format("bbbbbb"));
     }
 
+    #[test]
+    fn flexible_period() {
+        // (hour, expected) at each boundary
+        let cases = [
+            (0, "at night"),
+            (5, "at night"),
+            (6, "in the morning"),
+            (11, "in the morning"),
+            (12, "in the afternoon"),
+            (17, "in the afternoon"),
+            (18, "in the evening"),
+            (20, "in the evening"),
+            (21, "at night"),
+            (23, "at night"),
+        ];
+        for (hour, expected) in cases {
+            let time = Time::from_hms(hour, 0, 0).unwrap();
+            assert_eq!(expected, time.format("B"));
+            assert_eq!(expected, time.format("BBBB"));
+            assert_eq!(expected, time.format("BBBBB"));
+            assert_eq!(expected, time.format("BBBBBB"));
+            let time = Time::from_hms(hour, 59, 59).unwrap();
+            assert_eq!(expected, time.format("B"));
+        }
+
+        // Unlike `b`, noon and midnight are part of the surrounding periods
+        let time = Time::from_hms(12, 0, 0).unwrap();
+        assert_eq!("noon, in the afternoon", time.format("b, B"));
+        let time = Time::from_hms(0, 0, 0).unwrap();
+        assert_eq!("midnight, at night", time.format("b, B"));
+
+        // Uses the local time
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 4, 0, 0)
+            .unwrap()
+            .set_offset(Offset::from_hms(3, 0, 0).unwrap());
+        assert_eq!("7 in the morning", date_time.format("h B"));
+        assert_eq!(
+            "7 in the morning",
+            Time::from_hms(4, 0, 0)
+                .unwrap()
+                .set_offset(Offset::from_hms(3, 0, 0).unwrap())
+                .format("h B")
+        );
+    }
+
     #[test]
     fn hour() {
         let time = Time::from_hms(0, 0, 0).unwrap();
@@ -541,6 +754,31 @@ mod format_tests {
         assert_eq!("01:01:01 +01:01", time.format("HH:mm:ss xxxxxx"));
     }
 
+    #[test]
+    fn zone_name() {
+        let time = Time::from_hms(0, 0, 0).unwrap();
+        assert_eq!("00:00:00 GMT", time.format("HH:mm:ss z"));
+        assert_eq!("00:00:00 GMT", time.format("HH:mm:ss zzzz"));
+
+        let time = time.set_offset(Offset::from_hms(2, 0, 0).unwrap());
+        assert_eq!("02:00:00 GMT+02:00", time.format("HH:mm:ss z"));
+        assert_eq!("02:00:00 GMT+02:00", time.format("HH:mm:ss zzz"));
+        assert_eq!("02:00:00 GMT+02:00", time.format("HH:mm:ss zzzz"));
+
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 0, 0)
+            .unwrap()
+            .set_offset(Offset::from_hms(-5, 30, 0).unwrap());
+        assert_eq!("06:30 GMT-05:30", date_time.format("HH:mm z"));
+        assert_eq!("06:30 GMT-05:30", date_time.format("HH:mm zzzz"));
+        let date_time = date_time.set_offset(Offset::from_seconds(3661).unwrap());
+        assert_eq!("13:01 GMT+01:01:01", date_time.format("HH:mm zz"));
+
+        // Formatting with the local timezone always produces a name
+        let date_time = DateTime::now().set_offset(Offset::Local);
+        assert!(!date_time.format("z").is_empty());
+        assert!(date_time.format("zzzz").starts_with("GMT"));
+    }
+
     #[test]
     fn subsecond_values() {
         let time = Time::from_nanos(1123456789).unwrap();
@@ -631,4 +869,284 @@ mod format_tests {
 
         assert_eq!("", date_time.format(""));
     }
+
+    #[test]
+    fn format_spec() {
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap();
+
+        assert_eq!("AD 2022", date_time.format(format_spec!("G yyyy")));
+        assert_eq!(
+            "Q2 05 18 02 122 2",
+            date_time.format(format_spec!("qqq MM ww dd DDD e"))
+        );
+        assert_eq!(
+            "PM pm 12 12 0 12",
+            date_time.format(format_spec!("a bbb h H K k"))
+        );
+        assert_eq!("32 01 000", date_time.format(format_spec!("mm ss nnn")));
+        assert_eq!("Z +00:00", date_time.format(format_spec!("X xxx")));
+        assert_eq!(
+            "2022-05-02T12:32:01 o'clock",
+            date_time.format(format_spec!("yyyy-MM-dd'T'HH:mm:ss 'o''clock'"))
+        );
+
+        const FORMAT: &str = "HH:mm";
+        assert_eq!("12:32", date_time.format(format_spec!(FORMAT)));
+    }
+
+    #[test]
+    fn valid_format() {
+        assert!(is_valid_format(""));
+        assert!(is_valid_format("GyqMwdDeabhHKkmsnXx"));
+        assert!(is_valid_format("yyyy/MM/dd - äöü 12:00"));
+        assert!(is_valid_format("'Today is' eeee"));
+        assert!(is_valid_format("''"));
+        assert!(is_valid_format("'It''s' HH:mm"));
+
+        assert!(!is_valid_format("yyyy-MM-ddTHH:mm"));
+        assert!(!is_valid_format("Q"));
+        assert!(!is_valid_format("'"));
+        assert!(!is_valid_format("'''"));
+        assert!(!is_valid_format("HH:mm 'o''clock"));
+        assert!(!is_valid_format("dddd"));
+        assert!(!is_valid_format("HHH"));
+
+        assert!(is_valid_format("+yyyy-MM-dd"));
+        assert!(is_valid_format("'+'MM"));
+        assert!(!is_valid_format("+MM"));
+        assert!(!is_valid_format("+"));
+        assert!(!is_valid_format("yyyy +"));
+        assert!(!is_valid_format("++yyyy"));
+    }
+
+    #[test]
+    fn validate_format() {
+        assert!(astrolabe::validate_format("").is_ok());
+        assert!(astrolabe::validate_format("yyyyyy").is_ok());
+        assert!(astrolabe::validate_format("GyqMwWdoDegabBhHKkmsnXx").is_ok());
+        assert!(astrolabe::validate_format("GGGGG MMMMM eeeeeeee nnnnn XXXXX gggggg").is_ok());
+        assert!(astrolabe::validate_format("'Today is' eeee, 'It''s' HH:mm ''").is_ok());
+        assert!(astrolabe::validate_format("yyyy/MM/dd - äöü").is_ok());
+
+        let error = |format: &str| astrolabe::validate_format(format).unwrap_err().to_string();
+        assert!(astrolabe::validate_format("+yyyy-MM-dd '+'HH").is_ok());
+        assert_eq!(
+            "Sign '+' at position 0 has to be followed by the year ('y').",
+            error("+MM")
+        );
+        assert_eq!(
+            "Sign '+' at position 5 has to be followed by the year ('y').",
+            error("yyyy +")
+        );
+        assert_eq!(
+            "Sign '+' at position 0 has to be followed by the year ('y').",
+            error("++yyyy")
+        );
+        assert_eq!(
+            "Escaped part starting at position 11 is not closed.",
+            error("yyyy-MM-dd 'at HH:mm")
+        );
+        assert_eq!(
+            "Escaped part starting at position 0 is not closed.",
+            error("'")
+        );
+        assert_eq!(
+            "Unsupported format symbol 'f' at position 0.",
+            error("ffff")
+        );
+        assert_eq!(
+            "Unsupported format symbol 'T' at position 13.",
+            error("äyyyy-MM-dd THH:mm")
+        );
+        assert_eq!(
+            "Format symbol sequence 'ddd' at position 8 is too long. 'd' can be repeated at most 2 times.",
+            error("yyyy-MM-ddd")
+        );
+        assert!(astrolabe::validate_format("aaaaaa").is_err());
+        assert!(astrolabe::validate_format("HHH").is_err());
+        assert!(astrolabe::validate_format("do").is_ok());
+        assert!(astrolabe::validate_format("WW").is_err());
+        assert!(astrolabe::validate_format("+yyyy").is_ok());
+    }
+
+    #[test]
+    fn format_checks_agree() {
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap();
+        let formats = [
+            "",
+            "yyyy-MM-dd'T'HH:mm:ss",
+            "+yyyy",
+            "+MM",
+            "yyyy +",
+            "'It''s' HH:mm ''",
+            "yyyy 'abc",
+            "HH:mm 'o''clock",
+            "'''",
+            "dddd",
+            "WW",
+            "ffff",
+            "yyyy-MM-ddTHH:mm",
+            "äöü",
+        ];
+        for format in formats {
+            let valid = is_valid_format(format);
+            assert_eq!(
+                valid,
+                astrolabe::validate_format(format).is_ok(),
+                "{}",
+                format
+            );
+            assert_eq!(valid, date_time.try_format(format).is_ok(), "{}", format);
+            if let Err(error) = astrolabe::validate_format(format) {
+                assert_eq!(error, date_time.try_format(format).unwrap_err());
+            }
+        }
+    }
+
+    #[test]
+    fn unified_signature() {
+        // All types share the same infallible signature, so they can be used in generic code
+        trait Format {
+            fn format(&self, format: &str) -> String;
+        }
+        impl Format for Date {
+            fn format(&self, format: &str) -> String {
+                Date::format(self, format)
+            }
+        }
+        impl Format for Time {
+            fn format(&self, format: &str) -> String {
+                Time::format(self, format)
+            }
+        }
+        impl Format for DateTime {
+            fn format(&self, format: &str) -> String {
+                DateTime::format(self, format)
+            }
+        }
+        fn format_all(values: &[&dyn Format], format: &str) -> Vec<String> {
+            values.iter().map(|value| value.format(format)).collect()
+        }
+
+        let date = Date::from_ymd(2022, 5, 2).unwrap();
+        let time = Time::from_hms(12, 32, 1).unwrap();
+        let date_time = DateTime::from(date).set_time(time);
+        assert_eq!(
+            vec!["2022-05-02", "yyyy-MM-dd", "2022-05-02"],
+            format_all(&[&date, &time, &date_time], "yyyy-MM-dd")
+        );
+        // Symbols which don't apply to a type are kept as is
+        assert_eq!(
+            vec!["HH:mm", "12:32", "12:32"],
+            format_all(&[&date, &time, &date_time], "HH:mm")
+        );
+    }
+
+    #[test]
+    fn try_format() {
+        let date = Date::from_ymd(2022, 5, 2).unwrap();
+        let time = Time::from_hms(12, 32, 1).unwrap();
+        let date_time = DateTime::from(date).set_time(time);
+
+        assert_eq!(
+            date.format("yyyy-MM-dd"),
+            date.try_format("yyyy-MM-dd").unwrap()
+        );
+        assert_eq!(
+            time.format("HH:mm:ss"),
+            time.try_format("HH:mm:ss").unwrap()
+        );
+        assert_eq!(
+            date_time.format("yyyy-MM-dd'T'HH:mm:ss ''B''"),
+            date_time.try_format("yyyy-MM-dd'T'HH:mm:ss ''B''").unwrap()
+        );
+        assert_eq!("2022 äöü", date.try_format("yyyy äöü").unwrap());
+
+        let error = date_time.try_format("yyyy-MM-ddTHH:mm").unwrap_err();
+        assert_eq!(
+            "Unsupported format symbol 'T' at position 10.",
+            error.to_string()
+        );
+        let error = date_time.try_format("äyyyy Q").unwrap_err();
+        assert_eq!(
+            "Unsupported format symbol 'Q' at position 7.",
+            error.to_string()
+        );
+        // Unclosed escaped parts are rejected like in validate_format
+        assert_eq!(
+            astrolabe::validate_format("yyyy 'abc").unwrap_err(),
+            date.try_format("yyyy 'abc").unwrap_err()
+        );
+        assert!(time.try_format("HH 'o''clock").is_err());
+        // Symbols which don't apply to a type are rejected
+        assert!(date.try_format("yyyy HH").is_err());
+        assert!(time.try_format("yyyy HH").is_err());
+        assert!(date_time.try_format("yyyy HH").is_ok());
+    }
+
+    #[test]
+    fn time_offset() {
+        let formats = [
+            "HH:mm xxx",
+            "HH:mm:ss.nnnnn XXXXX",
+            "hh:mm a X",
+            "k:m:s xxxx",
+            "K B XX",
+        ];
+        let offsets = [
+            (0, 0, 0),
+            (1, 0, 0),
+            (5, 30, 0),
+            (-8, 0, 0),
+            (-9, 30, 0),
+            (13, 45, 0),
+            (23, 59, 59),
+            (-23, 59, 59),
+        ];
+        let date_times = [
+            DateTime::from_ymdhms(2022, 5, 2, 0, 0, 0).unwrap(),
+            DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap(),
+            DateTime::from_ymdhms(2022, 5, 2, 23, 59, 59)
+                .unwrap()
+                .set_nano(999_999_999)
+                .unwrap(),
+        ];
+
+        for date_time in date_times {
+            for (hour, minute, second) in offsets {
+                let offset = Offset::from_hms(hour, minute, second).unwrap();
+                for date_time in [date_time.set_offset(offset), date_time.as_offset(offset)] {
+                    let time = Time::from(date_time);
+                    assert_eq!(offset, time.get_offset());
+                    for format in formats {
+                        assert_eq!(
+                            date_time.format(format),
+                            time.format(format),
+                            "{} {:?}",
+                            format,
+                            offset
+                        );
+                    }
+                }
+            }
+        }
+
+        // Setting the offset on a time directly
+        let time = Time::from_hms(12, 32, 1)
+            .unwrap()
+            .set_offset(Offset::Fixed(3600));
+        assert_eq!("13:32 +01:00", time.format("HH:mm xxx"));
+        let time = Time::from_hms(12, 32, 1)
+            .unwrap()
+            .as_offset(Offset::Fixed(-3600));
+        assert_eq!("12:32:01-01:00", time.format("HH:mm:ssXXX"));
+        assert_eq!(
+            time,
+            Time::parse(&time.format("HHmmssXXX"), "HHmmssXXX").unwrap()
+        );
+        assert_eq!(
+            Time::parse("123201+01:00", "HHmmssXXX").unwrap(),
+            Time::from_hms(11, 32, 1).unwrap()
+        );
+    }
 }
@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod locale_tests {
+    use astrolabe::{Date, DateTime, FirstWeekday, Locale, ParseOptions, Time, Weekday};
+
+    #[test]
+    fn default() {
+        assert_eq!(Locale::ENGLISH, Locale::default());
+        assert_eq!(Locale::ENGLISH, ParseOptions::default().locale);
+    }
+
+    #[test]
+    fn format() {
+        let date_time = DateTime::from_ymdhms(2022, 3, 2, 0, 0, 0).unwrap();
+        let english = |format: &str| date_time.format_localized(format, &Locale::ENGLISH);
+        let german = |format: &str| date_time.format_localized(format, &Locale::GERMAN);
+
+        assert_eq!(
+            date_time.format("G GGGG GGGGG qqq qqqq MMM MMMM MMMMM eee eeee eeeee eeeeee bbbb"),
+            english("G GGGG GGGGG qqq qqqq MMM MMMM MMMMM eee eeee eeeee eeeeee bbbb")
+        );
+        assert_eq!("n. Chr.", german("G"));
+        assert_eq!("nach Christus", german("GGGG"));
+        assert_eq!("Q1", german("qqq"));
+        assert_eq!("1. Quartal", german("qqqq"));
+        assert_eq!("März", german("MMM"));
+        assert_eq!("März", german("MMMM"));
+        assert_eq!("M", german("MMMMM"));
+        assert_eq!("Mi.", german("eee"));
+        assert_eq!("Mittwoch", german("eeee"));
+        assert_eq!("M", german("eeeee"));
+        assert_eq!("Mi", german("eeeeee"));
+        assert_eq!("Mitternacht", german("bbbb"));
+        assert_eq!("vorm.", german("aaaa"));
+        assert_eq!("nachts", german("BBBB"));
+        assert_eq!(
+            "v. Chr.",
+            DateTime::from_ymdhms(-1, 1, 1, 0, 0, 0)
+                .unwrap()
+                .format_localized("G", &Locale::GERMAN)
+        );
+
+        let date = Date::from_ymd(2022, 9, 4).unwrap();
+        assert_eq!(
+            "So., 4. Sept. 2022",
+            date.format_localized("eee, d. MMM yyyy", &Locale::GERMAN)
+        );
+        let time = Time::from_hms(15, 0, 0).unwrap();
+        assert_eq!("3 nachm.", time.format_localized("h aaaa", &Locale::GERMAN));
+        assert_eq!("3 PM", time.format_localized("h a", &Locale::GERMAN));
+    }
+
+    #[test]
+    fn parse() {
+        let formats = [
+            "eeee, d. MMMM yyyy GGGG, h:mm aaaa",
+            "eee, dd. MMM yyyy G, qqqq, hh:mm bbbb",
+            "eeeeee dd.MM.yyyy qqq HH:mm:ss",
+        ];
+        let date_times = [
+            DateTime::from_ymdhms(2022, 3, 2, 0, 0, 0).unwrap(),
+            DateTime::from_ymdhms(2022, 9, 4, 12, 0, 0).unwrap(),
+            DateTime::from_ymdhms(2021, 12, 31, 23, 59, 0).unwrap(),
+        ];
+        for format in formats {
+            for date_time in date_times {
+                let formatted = date_time.format_localized(format, &Locale::GERMAN);
+                assert_eq!(
+                    date_time,
+                    DateTime::parse_localized(&formatted, format, &Locale::GERMAN).unwrap()
+                );
+            }
+        }
+
+        let date = Date::parse_localized("4. Sept. 2022", "d. MMM yyyy", &Locale::GERMAN).unwrap();
+        assert_eq!(Date::from_ymd(2022, 9, 4).unwrap(), date);
+        let time = Time::parse_localized("12 Uhr Mittag", "h 'Uhr' bbbb", &Locale::GERMAN).unwrap();
+        assert_eq!(Time::from_hms(12, 0, 0).unwrap(), time);
+
+        // Names are matched case-sensitively and only in the given locale
+        assert!(Date::parse_localized("4. sept. 2022", "d. MMM yyyy", &Locale::GERMAN).is_err());
+        assert!(Date::parse_localized("4. Sep 2022", "d. MMM yyyy", &Locale::GERMAN).is_err());
+        assert!(Date::parse("4. Sept. 2022", "d. MMM yyyy").is_err());
+    }
+
+    #[test]
+    fn narrow() {
+        // Narrow months and week days are ambiguous, so they are skipped and don't set the month
+        for locale in [Locale::ENGLISH, Locale::GERMAN] {
+            let date = Date::parse_localized("M 2022-05", "MMMMM yyyy-MM", &locale).unwrap();
+            assert_eq!(Date::from_ymd(2022, 5, 1).unwrap(), date);
+            let date = Date::parse_localized("2022-M", "yyyy-MMMMM", &locale).unwrap();
+            assert_eq!(Date::from_ymd(2022, 1, 1).unwrap(), date);
+            let date = Date::parse_localized("M 2022-05-03", "eeeee yyyy-MM-dd", &locale).unwrap();
+            assert_eq!(Date::from_ymd(2022, 5, 3).unwrap(), date);
+            assert!(Date::parse_localized("X 2022-05", "MMMMM yyyy-MM", &locale).is_err());
+        }
+    }
+
+    #[test]
+    fn first_weekday() {
+        assert_eq!(FirstWeekday::Standard, FirstWeekday::default());
+        assert_eq!(FirstWeekday::Standard, Locale::ENGLISH.first_weekday);
+        assert_eq!(FirstWeekday::Standard, Locale::GERMAN.first_weekday);
+
+        let sunday = Locale {
+            first_weekday: FirstWeekday::On(Weekday::Sunday),
+            ..Locale::ENGLISH
+        };
+        let monday = Locale {
+            first_weekday: FirstWeekday::On(Weekday::Monday),
+            ..Locale::ENGLISH
+        };
+
+        // (date, standard, sunday start, monday start), each as `e ee w ww eeeeeee`
+        let cases = [
+            // Saturday
+            ((2022, 1, 1), "7 07 52 52 6", "7 07 1 01 6", "6 06 1 01 6"),
+            // Sunday
+            ((2022, 1, 2), "1 01 52 52 7", "1 01 2 02 7", "7 07 1 01 7"),
+            // Monday
+            ((2022, 1, 3), "2 02 1 01 1", "2 02 2 02 1", "1 01 2 02 1"),
+            // Sunday
+            ((2023, 1, 1), "1 01 52 52 7", "1 01 1 01 7", "7 07 1 01 7"),
+            // Monday
+            ((2023, 1, 2), "2 02 1 01 1", "2 02 1 01 1", "1 01 2 02 1"),
+            // Wednesday
+            (
+                (2025, 12, 31),
+                "4 04 1 01 3",
+                "4 04 53 53 3",
+                "3 03 53 53 3",
+            ),
+            // Sunday
+            (
+                (2000, 12, 31),
+                "1 01 52 52 7",
+                "1 01 54 54 7",
+                "7 07 53 53 7",
+            ),
+        ];
+        for ((year, month, day), standard, sunday_start, monday_start) in cases {
+            let date = Date::from_ymd(year, month, day).unwrap();
+            let format = "e ee w ww eeeeeee";
+            assert_eq!(standard, date.format(format), "{}", date);
+            assert_eq!(
+                sunday_start,
+                date.format_localized(format, &sunday),
+                "{}",
+                date
+            );
+            assert_eq!(
+                monday_start,
+                date.format_localized(format, &monday),
+                "{}",
+                date
+            );
+            assert_eq!(
+                monday_start,
+                DateTime::from(date).format_localized(format, &monday)
+            );
+
+            for (locale, expected) in [
+                (Locale::ENGLISH, standard),
+                (sunday, sunday_start),
+                (monday, monday_start),
+            ] {
+                let options = ParseOptions {
+                    locale,
+                    reject_conflicts: true,
+                    ..Default::default()
+                };
+                let string = format!("{} {}", date.format("yyyy-MM-dd"), expected);
+                let format = "yyyy-MM-dd e ee w ww eeeeeee";
+                assert_eq!(date, Date::parse_with(&string, format, &options).unwrap());
+                assert_eq!(
+                    DateTime::from(date),
+                    DateTime::parse_with(&string, format, &options).unwrap()
+                );
+            }
+        }
+
+        // Parsed numeric weekdays and weeks are checked with the first weekday of the locale
+        let options = |locale: Locale| ParseOptions {
+            locale,
+            reject_conflicts: true,
+            ..Default::default()
+        };
+        assert!(Date::parse_with("2022-01-02 7", "yyyy-MM-dd e", &options(monday)).is_ok());
+        assert!(Date::parse_with("2022-01-02 1", "yyyy-MM-dd e", &options(monday)).is_err());
+        assert!(Date::parse_with("2022-01-02 1", "yyyy-MM-dd e", &options(sunday)).is_ok());
+        assert!(
+            Date::parse_with("2022-01-03 01", "yyyy-MM-dd ww", &options(Locale::ENGLISH)).is_ok()
+        );
+        assert!(Date::parse_with("2022-01-03 01", "yyyy-MM-dd ww", &options(monday)).is_err());
+        assert!(Date::parse_with("2022-01-03 2", "yyyy-MM-dd w", &options(monday)).is_ok());
+
+        // Numeric weekdays and weeks are only checked for their range by default
+        assert!(Date::parse_with("2022-01-02 1", "yyyy-MM-dd e", &ParseOptions::default()).is_ok());
+        assert!(Date::parse("2022-01-02 8", "yyyy-MM-dd e").is_err());
+        assert!(Date::parse("2022-01-02 00", "yyyy-MM-dd ee").is_err());
+        assert!(Date::parse("2022-01-02 54", "yyyy-MM-dd ww").is_err());
+        assert!(Date::parse_with("2022-01-02 54", "yyyy-MM-dd ww", &options(sunday)).is_err());
+        assert!(Date::parse_with("2000-12-31 54", "yyyy-MM-dd ww", &options(sunday)).is_ok());
+    }
+}
@@ -413,4 +413,464 @@ mod offset_tests {
         let date_time = DateTime::from_ymdhms(-5_879_611, 6, 23, 0, 0, 0).unwrap();
         date_time.set_offset(Offset::Fixed(-1));
     }
+
+    #[test]
+    #[should_panic]
+    fn as_offset_overflow() {
+        let date_time = DateTime::from_ymdhms(5_879_611, 7, 12, 23, 59, 59).unwrap();
+        date_time.as_offset(Offset::Fixed(-1));
+    }
+
+    #[test]
+    fn time_wrap() {
+        let max_offset = Offset::from_hms(23, 59, 59).unwrap();
+        let min_offset = Offset::from_hms(-23, 59, 59).unwrap();
+        let midnight = Time::from_hms(0, 0, 0).unwrap();
+        let last_nano = Time::from_nanos(86_399_999_999_999).unwrap();
+        let format = |time: Time| time.format("HH:mm:ss.nnnnn xxxxx");
+
+        // Same instant, local time wraps around midnight
+        assert_eq!(
+            "23:59:59.000000000 +23:59:59",
+            format(midnight.set_offset(max_offset))
+        );
+        assert_eq!(
+            "00:00:01.000000000 -23:59:59",
+            format(midnight.set_offset(min_offset))
+        );
+        assert_eq!(
+            "23:59:58.999999999 +23:59:59",
+            format(last_nano.set_offset(max_offset))
+        );
+        assert_eq!(
+            "00:00:00.999999999 -23:59:59",
+            format(last_nano.set_offset(min_offset))
+        );
+
+        // Same local time, UTC time wraps around midnight
+        let time = midnight.as_offset(max_offset);
+        assert_eq!("00:00:00.000000000 +23:59:59", format(time));
+        assert_eq!(max_offset, time.get_offset());
+        assert_eq!(
+            "00:00:01.000000000 +00:00",
+            format(time.set_offset(Offset::default()))
+        );
+        let time = midnight.as_offset(min_offset);
+        assert_eq!("00:00:00.000000000 -23:59:59", format(time));
+        assert_eq!(min_offset, time.get_offset());
+        assert_eq!(
+            "23:59:59.000000000 +00:00",
+            format(time.set_offset(Offset::default()))
+        );
+        let time = last_nano.as_offset(max_offset);
+        assert_eq!("23:59:59.999999999 +23:59:59", format(time));
+        assert_eq!(
+            "00:00:00.999999999 +00:00",
+            format(time.set_offset(Offset::default()))
+        );
+        let time = last_nano.as_offset(min_offset);
+        assert_eq!("23:59:59.999999999 -23:59:59", format(time));
+        assert_eq!(
+            "23:59:58.999999999 +00:00",
+            format(time.set_offset(Offset::default()))
+        );
+
+        // Fixed offsets of more than a day wrap as well
+        let time = midnight.set_offset(Offset::Fixed(-(2 * 86_400 + 3_600)));
+        assert_eq!("23:00:00", time.format("HH:mm:ss"));
+        let time = midnight.as_offset(Offset::Fixed(2 * 86_400 + 3_600));
+        assert_eq!("00:00:00", time.format("HH:mm:ss"));
+        assert!(time.as_nanos() < 86_400_000_000_000);
+        assert_eq!(
+            "23:00:00",
+            time.set_offset(Offset::default()).format("HH:mm:ss")
+        );
+    }
+
+    #[test]
+    fn try_offset() {
+        let max = DateTime::from_ymdhms(5_879_611, 7, 12, 23, 59, 59).unwrap();
+        let minus_one = Offset::from_hms(-1, 0, 0).unwrap();
+        let plus_one = Offset::from_hms(1, 0, 0).unwrap();
+
+        // Local time is one hour before the maximum
+        let date_time = max.try_set_offset(minus_one).unwrap();
+        assert_eq!(
+            "5879611-07-12 22:59:59",
+            date_time.format("yyyy-MM-dd HH:mm:ss")
+        );
+        assert_eq!(max.set_offset(minus_one), date_time);
+        assert!(max.try_set_offset(plus_one).is_err());
+
+        // UTC time would be one hour after the maximum
+        assert!(max.try_as_offset(minus_one).is_err());
+        let date_time = max.try_as_offset(plus_one).unwrap();
+        assert_eq!(
+            "5879611-07-12 23:59:59",
+            date_time.format("yyyy-MM-dd HH:mm:ss")
+        );
+        assert_eq!(max.as_offset(plus_one), date_time);
+
+        let min = DateTime::from_ymdhms(-5_879_611, 6, 23, 0, 0, 0).unwrap();
+        assert!(min.try_set_offset(minus_one).is_err());
+        assert!(min.try_set_offset(plus_one).is_ok());
+        assert!(min.try_as_offset(minus_one).is_ok());
+        assert!(min.try_as_offset(plus_one).is_err());
+
+        // Parsing returns an error instead of panicking
+        assert!(
+            DateTime::parse("5879611-07-12 23:59:59 -01:00", "yyyy-MM-dd HH:mm:ss xxx").is_err()
+        );
+        assert!(
+            DateTime::parse("5879611-07-12 23:59:59 +01:00", "yyyy-MM-dd HH:mm:ss xxx").is_ok()
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        let cases = [
+            ("Z", 0),
+            ("+00:00", 0),
+            ("-00:00", 0),
+            ("+05", 18_000),
+            ("+05:30", 19_800),
+            ("+0530", 19_800),
+            ("-08:00", -28_800),
+            ("-0800", -28_800),
+            ("+01:02:03", 3_723),
+            ("+010203", 3_723),
+            ("+23:59:59", 86_399),
+            ("-23:59:59", -86_399),
+        ];
+        for (string, seconds) in cases {
+            assert_eq!(
+                Offset::Fixed(seconds),
+                string.parse::<Offset>().unwrap(),
+                "{}",
+                string
+            );
+        }
+
+        let invalid = [
+            "",
+            "z",
+            "05:30",
+            "+5",
+            "+24:00",
+            "+05:60",
+            "+05:30:60",
+            "+05:3",
+            "+05:30Z",
+            "+05:30 ",
+            "+0530:00",
+            "UTC",
+            "Europe/Zurich",
+        ];
+        for string in invalid {
+            assert!(string.parse::<Offset>().is_err(), "{}", string);
+        }
+    }
+
+    #[test]
+    fn display() {
+        let cases = [
+            (0, "Z"),
+            (7_200, "+02:00"),
+            (19_800, "+05:30"),
+            (-1_800, "-00:30"),
+            (-28_378, "-07:52:58"),
+            (3_723, "+01:02:03"),
+            (86_399, "+23:59:59"),
+            (-86_399, "-23:59:59"),
+        ];
+        for (seconds, string) in cases {
+            assert_eq!(string, Offset::Fixed(seconds).to_string());
+            assert_eq!(Offset::Fixed(seconds), string.parse().unwrap());
+        }
+
+        // All accepted spellings round-trip through their canonical form
+        let spellings = [
+            "Z",
+            "+00:00",
+            "-00",
+            "+05",
+            "-05",
+            "+05:30",
+            "+0530",
+            "-0800",
+            "+01:02:03",
+            "+010203",
+            "-23:59:59",
+        ];
+        for string in spellings {
+            let offset: Offset = string.parse().unwrap();
+            assert_eq!(offset, offset.to_string().parse().unwrap(), "{}", string);
+        }
+    }
+
+    #[test]
+    fn from_env() {
+        // Each case uses its own variable, as tests run in parallel
+        std::env::set_var("ASTROLABE_TEST_OFFSET_SET", "+05:30");
+        assert_eq!(
+            Offset::Fixed(19_800),
+            Offset::from_env("ASTROLABE_TEST_OFFSET_SET").unwrap()
+        );
+        std::env::set_var("ASTROLABE_TEST_OFFSET_SET", "Z");
+        assert_eq!(
+            Offset::Fixed(0),
+            Offset::from_env("ASTROLABE_TEST_OFFSET_SET").unwrap()
+        );
+        std::env::remove_var("ASTROLABE_TEST_OFFSET_SET");
+
+        std::env::remove_var("ASTROLABE_TEST_OFFSET_UNSET");
+        let error = Offset::from_env("ASTROLABE_TEST_OFFSET_UNSET").unwrap_err();
+        assert!(error.to_string().contains("is not set"), "{}", error);
+
+        std::env::set_var("ASTROLABE_TEST_OFFSET_GARBAGE", "five past noon");
+        let error = Offset::from_env("ASTROLABE_TEST_OFFSET_GARBAGE").unwrap_err();
+        assert!(error.to_string().contains("Failed parsing"), "{}", error);
+        assert!(!error.to_string().contains("is not set"), "{}", error);
+        std::env::remove_var("ASTROLABE_TEST_OFFSET_GARBAGE");
+    }
+
+    #[test]
+    fn setters_with_offset() {
+        type Setter = fn(DateTime) -> DateTime;
+        fn utc(date_time: DateTime) -> String {
+            date_time
+                .set_offset(Offset::Fixed(0))
+                .format("yyyy-MM-dd HH:mm:ss")
+        }
+
+        // +02:00, local date is one day ahead of UTC
+        let positive = Offset::from_hms(2, 0, 0).unwrap();
+        let positive_base = DateTime::from_ymdhms(2022, 5, 31, 23, 30, 0).unwrap();
+        // -05:00, local date is one day behind UTC
+        let negative = Offset::from_hms(-5, 0, 0).unwrap();
+        let negative_base = DateTime::from_ymdhms(2022, 6, 1, 2, 0, 0).unwrap();
+        // +05:30, local time is not aligned to full UTC hours
+        let half = Offset::from_hms(5, 30, 0).unwrap();
+        let half_base = DateTime::from_ymdhms(2022, 5, 31, 23, 0, 0).unwrap();
+
+        // (offset, base in UTC, setter, offset set before, offset set after)
+        let cases: [(Offset, DateTime, Setter, &str, &str); 19] = [
+            (
+                positive,
+                positive_base,
+                |dt| dt.set_year(2020).unwrap(),
+                "2020-05-31 23:30:00",
+                "2020-05-31 23:30:00",
+            ),
+            (
+                positive,
+                positive_base,
+                |dt| dt.set_month(7).unwrap(),
+                "2022-06-30 23:30:00",
+                "2022-07-31 23:30:00",
+            ),
+            (
+                positive,
+                positive_base,
+                |dt| dt.set_day(15).unwrap(),
+                "2022-06-14 23:30:00",
+                "2022-05-15 23:30:00",
+            ),
+            (
+                positive,
+                positive_base,
+                |dt| dt.set_day_of_year(1).unwrap(),
+                "2021-12-31 23:30:00",
+                "2022-01-01 23:30:00",
+            ),
+            (
+                positive,
+                positive_base,
+                |dt| dt.set_hour(9).unwrap(),
+                "2022-06-01 07:30:00",
+                "2022-05-31 09:30:00",
+            ),
+            (
+                positive,
+                positive_base,
+                |dt| dt.set_minute(0).unwrap(),
+                "2022-05-31 23:00:00",
+                "2022-05-31 23:00:00",
+            ),
+            (
+                positive,
+                positive_base,
+                |dt| dt.set_second(30).unwrap(),
+                "2022-05-31 23:30:30",
+                "2022-05-31 23:30:30",
+            ),
+            (
+                negative,
+                negative_base,
+                |dt| dt.set_year(2020).unwrap(),
+                "2020-06-01 02:00:00",
+                "2020-06-01 02:00:00",
+            ),
+            (
+                negative,
+                negative_base,
+                |dt| dt.set_month(7).unwrap(),
+                "2022-08-01 02:00:00",
+                "2022-07-01 02:00:00",
+            ),
+            (
+                negative,
+                negative_base,
+                |dt| dt.set_day(15).unwrap(),
+                "2022-05-16 02:00:00",
+                "2022-06-15 02:00:00",
+            ),
+            (
+                negative,
+                negative_base,
+                |dt| dt.set_day_of_year(1).unwrap(),
+                "2022-01-02 02:00:00",
+                "2022-01-01 02:00:00",
+            ),
+            (
+                negative,
+                negative_base,
+                |dt| dt.set_hour(9).unwrap(),
+                "2022-05-31 14:00:00",
+                "2022-06-01 09:00:00",
+            ),
+            (
+                negative,
+                negative_base,
+                |dt| dt.set_minute(45).unwrap(),
+                "2022-06-01 02:45:00",
+                "2022-06-01 02:45:00",
+            ),
+            (
+                negative,
+                negative_base,
+                |dt| dt.set_second(30).unwrap(),
+                "2022-06-01 02:00:30",
+                "2022-06-01 02:00:30",
+            ),
+            (
+                half,
+                half_base,
+                |dt| dt.set_day(1).unwrap(),
+                "2022-05-31 23:00:00",
+                "2022-05-01 23:00:00",
+            ),
+            (
+                half,
+                half_base,
+                |dt| dt.set_hour(0).unwrap(),
+                "2022-05-31 19:00:00",
+                "2022-05-31 00:00:00",
+            ),
+            (
+                half,
+                half_base,
+                |dt| dt.set_minute(0).unwrap(),
+                "2022-05-31 22:30:00",
+                "2022-05-31 23:00:00",
+            ),
+            (
+                half,
+                half_base,
+                |dt| dt.set_milli(5).unwrap(),
+                "2022-05-31 23:00:00",
+                "2022-05-31 23:00:00",
+            ),
+            (
+                half,
+                half_base,
+                |dt| dt.set_nano(5).unwrap(),
+                "2022-05-31 23:00:00",
+                "2022-05-31 23:00:00",
+            ),
+        ];
+
+        for (index, (offset, base, setter, before, after)) in cases.iter().enumerate() {
+            let offset_before = setter(base.set_offset(*offset));
+            assert_eq!(
+                *before,
+                utc(offset_before),
+                "case {} (offset before)",
+                index
+            );
+            assert_eq!(*offset, offset_before.get_offset(), "case {}", index);
+
+            let offset_after = setter(*base).set_offset(*offset);
+            assert_eq!(*after, utc(offset_after), "case {} (offset after)", index);
+            assert_eq!(*offset, offset_after.get_offset(), "case {}", index);
+        }
+
+        // Setting a day that only exists in the local month fails if the offset is set afterwards
+        let base = DateTime::from_ymdhms(2022, 4, 30, 23, 0, 0).unwrap();
+        assert!(base.set_offset(positive).set_day(31).is_ok());
+        assert!(base.set_day(31).is_err());
+
+        // Time behaves the same way, wrapping around midnight
+        let time = Time::from_hms(23, 30, 0).unwrap();
+        let local = time.set_offset(positive).set_hour(9).unwrap();
+        assert_eq!("09:30:00", local.format("HH:mm:ss"));
+        assert_eq!(
+            "07:30:00",
+            local.set_offset(Offset::Fixed(0)).format("HH:mm:ss")
+        );
+        let converted = time.set_hour(9).unwrap().set_offset(positive);
+        assert_eq!("11:30:00", converted.format("HH:mm:ss"));
+
+        let time = Time::from_hms(2, 0, 0).unwrap();
+        let local = time.set_offset(negative).set_hour(22).unwrap();
+        assert_eq!(
+            "03:00:00",
+            local.set_offset(Offset::Fixed(0)).format("HH:mm:ss")
+        );
+        let converted = time.set_hour(22).unwrap().set_offset(negative);
+        assert_eq!("17:00:00", converted.format("HH:mm:ss"));
+    }
+
+    #[test]
+    fn duration_between() {
+        let offsets = [
+            Offset::Fixed(0),
+            Offset::from_hms(2, 0, 0).unwrap(),
+            Offset::from_hms(-5, 0, 0).unwrap(),
+            Offset::from_hms(5, 30, 0).unwrap(),
+            Offset::from_hms(-23, 59, 59).unwrap(),
+        ];
+        let base = DateTime::from_ymdhms(2022, 5, 2, 23, 30, 0)
+            .unwrap()
+            .set_nano(500)
+            .unwrap();
+
+        for first in offsets {
+            for second in offsets {
+                // Same instant, different offsets
+                let a = base.set_offset(first);
+                let b = base.set_offset(second);
+                assert_eq!(0, a.duration_between(&b).as_nanos());
+                assert_eq!(0, b.duration_between(&a).as_nanos());
+
+                // Same wall clock time, different offsets
+                let a = base.as_offset(first);
+                let b = base.as_offset(second);
+                let difference = (first.resolve() - second.resolve()).unsigned_abs() as u64;
+                assert_eq!(difference, a.duration_between(&b).as_secs());
+                assert_eq!(difference, b.duration_between(&a).as_secs());
+                assert_eq!(0, a.duration_between(&b).subsec_nanos());
+            }
+        }
+
+        // Crossing a day boundary in one offset but not in the other
+        let start = DateTime::from_ymdhms(2022, 5, 2, 23, 0, 0)
+            .unwrap()
+            .as_offset(Offset::from_hms(-5, 0, 0).unwrap());
+        let end = DateTime::from_ymdhms(2022, 5, 3, 1, 0, 0)
+            .unwrap()
+            .as_offset(Offset::from_hms(2, 0, 0).unwrap());
+        assert_eq!(5 * 3600, start.duration_between(&end).as_secs());
+        assert_eq!(5 * 3600, end.duration_between(&start).as_secs());
+    }
 }
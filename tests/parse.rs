@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod parse_tests {
-    use astrolabe::{Date, DateTime, Time};
+    use astrolabe::{
+        errors::AstrolabeError, Date, DateTime, DateUtilities, EraTokens, ParseOptions,
+        PeriodTokens, Time,
+    };
 
     #[test]
     fn era() {
@@ -32,33 +35,45 @@ mod parse_tests {
         parse_ok_d("2022-Anno Domini05-02", "yyyy-GGGGGGGMM-dd");
         parse_ok_d("2022-05-02Anno Domini", "yyyy-MM-ddGGGGGGG");
 
-        parse_ok_d("BC2022-05-02", "Gyyyy-MM-dd");
-        parse_ok_d("2022-BC05-02", "yyyy-GMM-dd");
-        parse_ok_d("2022-05-02BC", "yyyy-MM-ddG");
+        parse_ok_custom_d("BC2022-05-02", "Gyyyy-MM-dd", "-2022/05/02");
+        parse_ok_custom_d("2022-BC05-02", "yyyy-GMM-dd", "-2022/05/02");
+        parse_ok_custom_d("2022-05-02BC", "yyyy-MM-ddG", "-2022/05/02");
 
-        parse_ok_d("BC2022-05-02", "GGyyyy-MM-dd");
-        parse_ok_d("2022-BC05-02", "yyyy-GGMM-dd");
-        parse_ok_d("2022-05-02BC", "yyyy-MM-ddGG");
+        parse_ok_custom_d("BC2022-05-02", "GGyyyy-MM-dd", "-2022/05/02");
+        parse_ok_custom_d("2022-BC05-02", "yyyy-GGMM-dd", "-2022/05/02");
+        parse_ok_custom_d("2022-05-02BC", "yyyy-MM-ddGG", "-2022/05/02");
 
-        parse_ok_d("BC2022-05-02", "GGGyyyy-MM-dd");
-        parse_ok_d("2022-BC05-02", "yyyy-GGGMM-dd");
-        parse_ok_d("2022-05-02BC", "yyyy-MM-ddGGG");
+        parse_ok_custom_d("BC2022-05-02", "GGGyyyy-MM-dd", "-2022/05/02");
+        parse_ok_custom_d("2022-BC05-02", "yyyy-GGGMM-dd", "-2022/05/02");
+        parse_ok_custom_d("2022-05-02BC", "yyyy-MM-ddGGG", "-2022/05/02");
 
-        parse_ok_d("Before Christ2022-05-02", "GGGGyyyy-MM-dd");
-        parse_ok_d("2022-Before Christ05-02", "yyyy-GGGGMM-dd");
-        parse_ok_d("2022-05-02Before Christ", "yyyy-MM-ddGGGG");
+        parse_ok_custom_d("Before Christ2022-05-02", "GGGGyyyy-MM-dd", "-2022/05/02");
+        parse_ok_custom_d("2022-Before Christ05-02", "yyyy-GGGGMM-dd", "-2022/05/02");
+        parse_ok_custom_d("2022-05-02Before Christ", "yyyy-MM-ddGGGG", "-2022/05/02");
 
-        parse_ok_d("B2022-05-02", "GGGGGyyyy-MM-dd");
-        parse_ok_d("2022-B05-02", "yyyy-GGGGGMM-dd");
-        parse_ok_d("2022-05-02B", "yyyy-MM-ddGGGGG");
+        parse_ok_custom_d("B2022-05-02", "GGGGGyyyy-MM-dd", "-2022/05/02");
+        parse_ok_custom_d("2022-B05-02", "yyyy-GGGGGMM-dd", "-2022/05/02");
+        parse_ok_custom_d("2022-05-02B", "yyyy-MM-ddGGGGG", "-2022/05/02");
 
-        parse_ok_d("Before Christ2022-05-02", "GGGGGGyyyy-MM-dd");
-        parse_ok_d("2022-Before Christ05-02", "yyyy-GGGGGGMM-dd");
-        parse_ok_d("2022-05-02Before Christ", "yyyy-MM-ddGGGGGG");
+        parse_ok_custom_d("Before Christ2022-05-02", "GGGGGGyyyy-MM-dd", "-2022/05/02");
+        parse_ok_custom_d("2022-Before Christ05-02", "yyyy-GGGGGGMM-dd", "-2022/05/02");
+        parse_ok_custom_d("2022-05-02Before Christ", "yyyy-MM-ddGGGGGG", "-2022/05/02");
 
-        parse_ok_d("Before Christ2022-05-02", "GGGGGGGyyyy-MM-dd");
-        parse_ok_d("2022-Before Christ05-02", "yyyy-GGGGGGGMM-dd");
-        parse_ok_d("2022-05-02Before Christ", "yyyy-MM-ddGGGGGGG");
+        parse_ok_custom_d(
+            "Before Christ2022-05-02",
+            "GGGGGGGyyyy-MM-dd",
+            "-2022/05/02",
+        );
+        parse_ok_custom_d(
+            "2022-Before Christ05-02",
+            "yyyy-GGGGGGGMM-dd",
+            "-2022/05/02",
+        );
+        parse_ok_custom_d(
+            "2022-05-02Before Christ",
+            "yyyy-MM-ddGGGGGGG",
+            "-2022/05/02",
+        );
 
         parse_err_d("AD", "GGGG");
         parse_err_d("AD2022-05-02", "GGGGyyyy-MM-dd");
@@ -66,6 +81,45 @@ mod parse_tests {
 
         parse_err_d("", "G");
         parse_err_d("", "GGGGG");
+
+        // Already negative years stay negative
+        parse_ok_custom_d("BC-2022-05-02", "Gyyyy-MM-dd", "-2022/05/02");
+        // Without a year, BC results in the year -1
+        parse_ok_custom_d("BC05-02", "GMM-dd", "-0001/05/02");
+
+        let options = ParseOptions {
+            locale: astrolabe::Locale::GERMAN,
+            ..Default::default()
+        };
+        let date = Date::parse_with("2022 v. Chr.", "yyyy G", &options).unwrap();
+        assert_eq!("-2022/01/01", date.format("yyyy/MM/dd"));
+        let date = Date::parse_with("2022 n. Chr.", "yyyy G", &options).unwrap();
+        assert_eq!("2022/01/01", date.format("yyyy/MM/dd"));
+
+        // Round trip through the formatted era and year
+        for year in [-2022, -1, 1, 2022] {
+            let date = Date::from_ymd(year, 5, 2).unwrap();
+            let formatted = date.format("Gyyyy-MM-dd");
+            assert_eq!(date, Date::parse(&formatted, "Gyyyy-MM-dd").unwrap());
+            let date_time = DateTime::from_ymdhms(year, 5, 2, 12, 32, 1).unwrap();
+            let formatted = date_time.format("GGGG yyyy-MM-dd HH:mm:ss");
+            assert_eq!(
+                date_time,
+                DateTime::parse(&formatted, "GGGG yyyy-MM-dd HH:mm:ss").unwrap()
+            );
+        }
+
+        // AD with a negative year is only rejected if conflicts are checked
+        parse_ok_custom_d("AD-2022-05-02", "Gyyyy-MM-dd", "-2022/05/02");
+        assert!(Date::parse_checked("AD-2022-05-02", "Gyyyy-MM-dd").is_err());
+        assert!(DateTime::parse_checked("AD-2022-05-02", "Gyyyy-MM-dd").is_err());
+        assert!(Date::parse_checked("BC2022-05-02", "Gyyyy-MM-dd").is_ok());
+
+        // A sign on the year conflicts with the era
+        assert!(Date::parse_checked("BC-2022-05-02", "Gyyyy-MM-dd").is_err());
+        assert!(Date::parse_checked("AD+2022-05-02", "G+yyyy-MM-dd").is_err());
+        assert!(DateTime::parse_checked("2022-05-02 BC-22", "yyyy-MM-dd Gyy").is_err());
+        assert!(Date::parse_checked("-2022-05-02", "yyyy-MM-dd").is_ok());
     }
 
     #[test]
@@ -118,6 +172,87 @@ mod parse_tests {
         parse_err_d("", "yyyyy");
     }
 
+    #[test]
+    fn year_round_trip() {
+        for year in [-100_000, -34, -5, 5, 99, 100_000] {
+            let date = Date::from_ymd(year, 5, 2).unwrap();
+            for format in [
+                "y-MM-dd",
+                "yyy-MM-dd",
+                "yyyy-MM-dd",
+                "yyyyy-MM-dd",
+                "yyyyyyy-MM-dd",
+                "+y-MM-dd",
+                "+yyyy-MM-dd",
+                "+yyyyyy-MM-dd",
+                "MM/dd/+yyyyyy",
+            ] {
+                let formatted = date.format(format);
+                assert_eq!(
+                    date,
+                    Date::parse(&formatted, format).unwrap(),
+                    "{} with {}",
+                    formatted,
+                    format
+                );
+            }
+        }
+
+        // Negative years keep their sign with yy
+        for year in [-5, -34] {
+            let date = Date::from_ymd(year, 5, 2).unwrap();
+            let formatted = date.format("yy-MM-dd");
+            assert_eq!(date, Date::parse(&formatted, "yy-MM-dd").unwrap());
+        }
+
+        let date_time =
+            DateTime::parse("+002022-05-02T10:00:00Z", "+yyyyyy-MM-ddTHH:mm:ssXXX").unwrap();
+        assert_eq!(2022, date_time.year());
+        assert_eq!(
+            "+002022-05-02T10:00:00Z",
+            date_time.format("+yyyyyy-MM-ddTHH:mm:ssXXX")
+        );
+        let date_time =
+            DateTime::parse("-000034-05-02T10:00:00Z", "+yyyyyy-MM-ddTHH:mm:ssXXX").unwrap();
+        assert_eq!(-34, date_time.year());
+
+        // A sign is required
+        assert!(Date::parse("002022-05-02", "+yyyyyy-MM-dd").is_err());
+        assert!(Date::parse("+-2022-05-02", "+yyyy-MM-dd").is_err());
+        assert!(Date::parse("+", "+y").is_err());
+        assert!(Date::parse("", "+y").is_err());
+
+        // A + which isn't followed by a year is a literal
+        let date = Date::parse("2022 +05-02", "yyyy +MM-dd").unwrap();
+        assert_eq!(Date::from_ymd(2022, 5, 2).unwrap(), date);
+    }
+
+    #[test]
+    fn extended_year() {
+        // Astronomical year 0 is 1 BC
+        for (extended, year) in [("-2", -3), ("-1", -2), ("0", -1), ("1", 1), ("2022", 2022)] {
+            let date = Date::parse(&format!("{}-05-02", extended), "u-MM-dd").unwrap();
+            assert_eq!(Date::from_ymd(year, 5, 2).unwrap(), date);
+            assert_eq!(format!("{}-05-02", extended), date.format("u-MM-dd"));
+        }
+        let date_time = DateTime::parse("0000-05-02 10:00:00", "uuuu-MM-dd HH:mm:ss").unwrap();
+        assert_eq!(-1, date_time.year());
+
+        for year in [-100_000, -34, -2, -1, 1, 2, 2022, 100_000] {
+            let date = Date::from_ymd(year, 5, 2).unwrap();
+            for format in ["u-MM-dd", "uuuu-MM-dd", "uuuuuu-MM-dd"] {
+                assert_eq!(date, Date::parse(&date.format(format), format).unwrap());
+            }
+            // The same string is parsed into different years with y and u if it isn't positive
+            let formatted = date.format("yyyy-MM-dd");
+            let parsed = Date::parse(&formatted, "uuuu-MM-dd").unwrap();
+            assert_eq!(year > 0, date == parsed);
+        }
+
+        assert!(Date::parse("-", "u").is_err());
+        assert!(Date::parse("", "u").is_err());
+    }
+
     #[test]
     fn quarter() {
         parse_ok_d("12022-05-02", "qyyyy-MM-dd");
@@ -208,6 +343,18 @@ mod parse_tests {
         parse_err_d("", "qqq");
         parse_err_d("", "qqqq");
         parse_err_d("", "qqqqq");
+
+        // Without a month, the quarter sets the first month of the quarter
+        parse_ok_custom_d("2022 Q1", "yyyy qqq", "2022/01/01");
+        parse_ok_custom_d("2022 2", "yyyy q", "2022/04/01");
+        parse_ok_custom_d("2022 03", "yyyy qq", "2022/07/01");
+        parse_ok_custom_d("4th quarter 2022", "qqqq yyyy", "2022/10/01");
+        parse_ok_custom_d("Q4 2022 15", "qqq yyyy dd", "2022/10/15");
+        let date_time = DateTime::parse("Q3 2022 12:00", "qqq yyyy HH:mm").unwrap();
+        assert_eq!("2022/07/01 12:00:00", date_time.to_string());
+        parse_err_d("2022 0", "yyyy q");
+        parse_err_d("2022 5", "yyyy q");
+        parse_err_d("2022 Q5", "yyyy qqq");
     }
 
     #[test]
@@ -259,6 +406,14 @@ mod parse_tests {
         parse_err_d("", "ww");
     }
 
+    #[test]
+    fn week_of_month() {
+        parse_ok_d("2022-05-02 W1", "yyyy-MM-dd 'W'W");
+        parse_ok_d("12022-05-02", "Wyyyy-MM-dd");
+        parse_ok_dt("2022-05-02 6", "yyyy-MM-dd W");
+        parse_err_d("", "W");
+    }
+
     #[test]
     fn day_of_month() {
         parse_ok_d("2-2022-05", "d-yyyy-MM");
@@ -278,6 +433,42 @@ mod parse_tests {
         parse_err_d("aa", "dd");
     }
 
+    #[test]
+    fn ordinal_suffix() {
+        parse_ok_d("May 2nd, 2022", "MMM do, yyyy");
+        parse_ok_d("2022-05-02nd", "yyyy-MM-ddo");
+        // The suffix is optional
+        parse_ok_d("May 2, 2022", "MMM do, yyyy");
+        parse_ok_dt("May 2nd, 2022 12:32", "MMM do, yyyy HH:mm");
+
+        for (string, day) in [
+            ("1st", 1),
+            ("11th", 11),
+            ("12th", 12),
+            ("13th", 13),
+            ("21st", 21),
+            ("22nd", 22),
+            ("23rd", 23),
+        ] {
+            let date = Date::parse(&format!("2022-05-{}", string), "yyyy-MM-do").unwrap();
+            assert_eq!(Date::from_ymd(2022, 5, day).unwrap(), date);
+        }
+
+        parse_err_d("May 2xx, 2022", "MMM do, yyyy");
+
+        // The suffix has to match the day
+        for string in ["2st", "1th", "11st", "12nd", "23th"] {
+            let error = Date::parse(&format!("2022-05-{}", string), "yyyy-MM-do").unwrap_err();
+            match error {
+                AstrolabeError::InvalidFormat(error) => {
+                    assert_eq!(Some(8 + string.len() - 2), error.position(), "{}", string)
+                }
+                error => panic!("Unexpected error: {:?}", error),
+            }
+        }
+        assert!(DateTime::parse("May 2st, 2022 12:32", "MMM do, yyyy HH:mm").is_err());
+    }
+
     #[test]
     fn day_of_year() {
         parse_ok_d("122-2022", "D-yyyy");
@@ -484,6 +675,28 @@ mod parse_tests {
         parse_err_t("ab", "b");
     }
 
+    #[test]
+    fn period_flexible() {
+        parse_ok_t("12:32:01 in the afternoon", "HH:mm:ss B");
+        parse_ok_t("12:32:01 in the afternoon", "HH:mm:ss BBBB");
+        parse_ok_t("12:32:01 in the afternoon", "HH:mm:ss BBBBB");
+        parse_ok_t("in the morning 12:32:01", "B HH:mm:ss");
+        // The period is recognized but doesn't change the time
+        parse_ok_custom_t("12:32:01 at night", "HH:mm:ss B", "12:32:01", "HH:mm:ss");
+        parse_ok_custom_t("9:15 in the evening", "h:mm B", "09:15:00", "HH:mm:ss");
+
+        let formats = ["h:mm B", "HH:mm BBBB", "yyyy-MM-dd h:mm:ss BBBBB"];
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 18, 30, 0).unwrap();
+        for format in formats {
+            let formatted = date_time.format(format);
+            assert!(DateTime::parse(&formatted, format).is_ok());
+        }
+
+        parse_err_t("12:32:01 in the night", "hh:mm:ss B");
+        parse_err_t("12:32:01 In the morning", "hh:mm:ss B");
+        parse_err_t("", "B");
+    }
+
     #[test]
     fn hour_h_lower() {
         parse_ok_custom_t("11-3201-PM", "h-mmss-a", "23:32:01", "HH:mm:ss");
@@ -745,6 +958,34 @@ mod parse_tests {
         assert_eq!("2022/05/02 +01:00", date_time.format("yyyy/MM/dd xxx"));
     }
 
+    #[test]
+    fn zone_name() {
+        let time = Time::parse("12:32:01 GMT+01:30", "HH:mm:ss zzzz").unwrap();
+        assert_eq!("12:32:01 +01:30", time.format("HH:mm:ss xxxxx"));
+        let time = Time::parse("12:32:01 GMT-05", "HH:mm:ss z").unwrap();
+        assert_eq!("12:32:01 -05:00", time.format("HH:mm:ss xxxxx"));
+        let time = Time::parse("12:32:01 GMT+01:01:01", "HH:mm:ss z").unwrap();
+        assert_eq!("12:32:01 +01:01:01", time.format("HH:mm:ss xxxxx"));
+        let time = Time::parse("12:32:01 GMT", "HH:mm:ss z").unwrap();
+        assert_eq!("12:32:01 +00:00", time.format("HH:mm:ss xxxxx"));
+
+        // Designations are skipped
+        let date_time =
+            DateTime::parse("2022-05-02 12:32:01 CEST", "yyyy-MM-dd HH:mm:ss z").unwrap();
+        assert_eq!(
+            "2022-05-02 12:32:01 +00:00",
+            date_time.format("yyyy-MM-dd HH:mm:ss xxxxx")
+        );
+        let date_time = DateTime::parse("12:32 GMT+02:00 2022", "HH:mm zzzz yyyy").unwrap();
+        assert_eq!(
+            "2022-01-01 12:32 +02:00",
+            date_time.format("yyyy-MM-dd HH:mm xxx")
+        );
+
+        assert!(Time::parse("12:32:01 ", "HH:mm:ss z").is_err());
+        assert!(Time::parse("12:32:01 GMT+", "HH:mm:ss z").is_err());
+    }
+
     #[test]
     fn escape() {
         parse_ok_d("yyyMMdd2022-05-02yyyMMdd", "'yyyMMdd'yyy-MM-dd'yyyMMdd");
@@ -837,6 +1078,90 @@ mod parse_tests {
         assert!(DateTime::parse("2022-05-02 24", "yyyy-MM-dd HH").is_err());
     }
 
+    #[test]
+    fn out_of_range() {
+        // (string, format, name, value)
+        let cases = [
+            ("2022-05-02 99", "yyyy-MM-dd mm", "minute", 99),
+            ("60", "ss", "second", 60),
+            ("24", "HH", "hour", 24),
+            ("00", "hh", "hour", 0),
+            ("0", "h", "hour", 0),
+            ("13", "hh", "hour", 13),
+            ("12", "KK", "hour", 12),
+            ("00", "kk", "hour", 0),
+            ("25", "kk", "hour", 25),
+            ("2022-13-01", "yyyy-MM-dd", "month", 13),
+            ("2022-02-29", "yyyy-MM-dd", "day", 29),
+            ("2022-366", "yyyy-DDD", "day of year", 366),
+        ];
+        for (string, format, name, value) in cases {
+            match DateTime::parse(string, format) {
+                Err(AstrolabeError::OutOfRange(error)) => {
+                    assert_eq!(name, error.field_name(), "{}", string);
+                    assert_eq!(value, error.value(), "{}", string);
+                }
+                result => panic!("Expected out of range error for {}: {:?}", string, result),
+            }
+        }
+
+        // Upper bounds are still accepted
+        parse_ok_custom_t("23:59:59", "HH:mm:ss", "23:59:59", "HH:mm:ss");
+        parse_ok_custom_t("24", "kk", "00:00:00", "HH:mm:ss");
+        parse_ok_custom_t("11 PM", "KK a", "23:00:00", "HH:mm:ss");
+        assert!(Time::parse("12:60", "HH:mm").is_err());
+    }
+
+    #[test]
+    fn conflicts() {
+        // Lenient by default
+        assert!(DateTime::parse("02 PM 13", "hh a HH").is_ok());
+        assert!(Date::parse("Tuesday, 2022-05-02", "eeee, yyyy-MM-dd").is_ok());
+        assert!(Date::parse("2022-05-03 122", "yyyy-MM-dd DDD").is_ok());
+
+        let error = |result: Result<DateTime, AstrolabeError>| result.unwrap_err().to_string();
+        assert_eq!(
+            "Parsed hour 13 conflicts with parsed 12-hour hour and day period which results in hour 14.",
+            error(DateTime::parse_checked("02 PM 13", "hh a HH"))
+        );
+        assert_eq!(
+            "Parsed hour 13 conflicts with parsed 12-hour hour and day period which results in hour 2.",
+            error(DateTime::parse_checked("02 13", "hh HH"))
+        );
+        assert_eq!(
+            "Parsed weekday Tuesday conflicts with parsed date which results in weekday Monday.",
+            error(DateTime::parse_checked(
+                "Tuesday, 2022-05-02",
+                "eeee, yyyy-MM-dd"
+            ))
+        );
+        assert_eq!(
+            "Parsed day of month 3 conflicts with parsed day of year which results in day of month 2.",
+            error(DateTime::parse_checked("2022-05-03 122", "yyyy-MM-dd DDD"))
+        );
+        assert_eq!(
+            "Parsed month 6 conflicts with parsed day of year which results in month 5.",
+            error(DateTime::parse_checked("2022-06 122", "yyyy-MM DDD"))
+        );
+
+        assert!(Time::parse_checked("02 PM 13", "hh a HH").is_err());
+        assert!(Time::parse_checked("12 AM 24", "hh a kk").is_ok());
+        assert!(Time::parse_checked("12 PM 12", "hh a HH").is_ok());
+        assert!(Time::parse_checked("11 23", "KK HH").is_ok());
+        assert!(Date::parse_checked("Mon, 2022-05-02", "eee, yyyy-MM-dd").is_ok());
+        assert!(Date::parse_checked("Tu, 2022-05-02", "eeeeee, yyyy-MM-dd").is_err());
+        // Narrow and numeric week days are not checked
+        assert!(Date::parse_checked("T, 2022-05-02", "eeeee, yyyy-MM-dd").is_ok());
+        assert!(Date::parse_checked("2022-05-02 122 May", "yyyy-MM-dd DDD MMM").is_ok());
+        assert!(Date::parse_checked("2022-05-02 122 Jun", "yyyy-MM-dd DDD MMM").is_err());
+
+        let options = ParseOptions {
+            reject_conflicts: true,
+            ..Default::default()
+        };
+        assert!(DateTime::parse_with("Sun 2022-05-02", "eee yyyy-MM-dd", &options).is_err());
+    }
+
     #[test]
     fn minimal() {
         parse_ok_custom_t("", "", "00:00:00 000000000", "HH:mm:ss nnnnn");
@@ -864,6 +1189,96 @@ mod parse_tests {
         );
     }
 
+    #[test]
+    fn time_range() {
+        let time = Time::parse("23:59:59.999999999", "HH:mm:ss.nnnnn").unwrap();
+        assert_eq!(86_399_999_999_999, time.as_nanos());
+        let date_time =
+            DateTime::parse("2022-05-02 23:59:59.999999999", "yyyy-MM-dd HH:mm:ss.nnnnn").unwrap();
+        assert_eq!(
+            "2022/05/02 23:59:59 999999999",
+            date_time.format("yyyy/MM/dd HH:mm:ss nnnnn")
+        );
+
+        parse_err_t("24:00:00", "HH:mm:ss");
+        parse_err_t("99:99:99", "HH:mm:ss");
+        parse_err_t("11:99:99 PM", "hh:mm:ss a");
+        assert!(DateTime::parse("2022-05-02 99:99:99", "yyyy-MM-dd HH:mm:ss").is_err());
+    }
+
+    #[test]
+    fn custom_tokens() {
+        let options = ParseOptions {
+            period_tokens: Some(PeriodTokens::new(&["A", "AM"], &["P", "PM", "NOON"])),
+            era_tokens: Some(EraTokens::new(&["AD"], &["BC"])),
+            ..Default::default()
+        };
+        let time = |string: &str, format: &str| {
+            Time::parse_with(string, format, &options).map(|time| time.format("HH:mm"))
+        };
+
+        // Custom tokens are accepted with all widths of 'a' and 'b', ignoring case
+        assert_eq!("09:30", time("09:30A", "hh:mma").unwrap());
+        assert_eq!("21:30", time("09:30P", "hh:mmaaaa").unwrap());
+        assert_eq!("21:30", time("09:30p", "hh:mmaaaaa").unwrap());
+        assert_eq!("12:00", time("12:00NOON", "hh:mmb").unwrap());
+        assert_eq!("00:00", time("12:00a", "hh:mmbbbb").unwrap());
+
+        // The longest matching token is used
+        assert_eq!("21:30", time("09:30PM", "hh:mma").unwrap());
+        assert_eq!("21:30", time("09:30PM!", "hh:mma!").unwrap());
+        assert_eq!("21:30", time("09:30P!", "hh:mma!").unwrap());
+        assert!(time("09:30PM", "hh:mmaM").is_err());
+
+        // Built-in tokens are rejected if not part of the custom tokens
+        assert!(time("09:30mi", "hh:mmbbbbb").is_err());
+        assert!(time("09:30midnight", "hh:mmb").is_err());
+        assert!(time("09:30", "hh:mma").is_err());
+
+        // Interaction with the 12-hour clock symbols
+        assert_eq!("00:30", time("12:30A", "hh:mma").unwrap());
+        assert_eq!("12:30", time("12:30P", "hh:mma").unwrap());
+        assert_eq!("23:30", time("11:30P", "KK:mma").unwrap());
+        assert_eq!("21:30", time("P09:30", "ahh:mm").unwrap());
+
+        // Eras
+        let date = |string: &str, format: &str| {
+            Date::parse_with(string, format, &options).map(|date| date.format("yyyy-MM-dd"))
+        };
+        assert_eq!("2022-05-02", date("2022AD-05-02", "yyyyG-MM-dd").unwrap());
+        assert_eq!(
+            "-2022-05-02",
+            date("2022bc-05-02", "yyyyGGGG-MM-dd").unwrap()
+        );
+        assert!(date("2022Anno Domini-05-02", "yyyyGGGG-MM-dd").is_err());
+        assert!(date("2022A-05-02", "yyyyGGGGG-MM-dd").is_err());
+
+        let date_time =
+            DateTime::parse_with("AD2022-05-02 09:30P", "Gyyyy-MM-dd hh:mma", &options).unwrap();
+        assert_eq!("2022-05-02 21:30", date_time.format("yyyy-MM-dd HH:mm"));
+
+        // Only the provided tables are replaced
+        let options = ParseOptions {
+            era_tokens: Some(EraTokens::new(&["A.D."], &["B.C."])),
+            ..Default::default()
+        };
+        let date_time =
+            DateTime::parse_with("2022A.D.-05-02 09:30 PM", "yyyyG-MM-dd hh:mm a", &options)
+                .unwrap();
+        assert_eq!("2022-05-02 21:30", date_time.format("yyyy-MM-dd HH:mm"));
+
+        // Default options behave like parse
+        assert_eq!(
+            DateTime::parse("2022-05-02 09:30 PM", "yyyy-MM-dd hh:mm a").unwrap(),
+            DateTime::parse_with(
+                "2022-05-02 09:30 PM",
+                "yyyy-MM-dd hh:mm a",
+                &ParseOptions::default()
+            )
+            .unwrap()
+        );
+    }
+
     fn parse_ok_d(string: &str, format: &str) {
         let date = Date::parse(string, format).unwrap();
         assert_eq!("2022/05/02", date.format("yyyy/MM/dd"));
@@ -898,4 +1313,26 @@ mod parse_tests {
         let time = Time::parse(string, format);
         assert!(time.is_err());
     }
+
+    // Only meaningful with optimizations, run with `cargo test --release`
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn throughput() {
+        use astrolabe::TimeUtilities;
+
+        let start = std::time::Instant::now();
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 1).unwrap();
+        let strings: Vec<String> = (0..100_000)
+            .map(|index| {
+                date_time
+                    .add_seconds(index)
+                    .format("yyyy-MM-dd HH:mm:ss.nnnXXX")
+            })
+            .collect();
+        for (index, string) in strings.iter().enumerate() {
+            let parsed = DateTime::parse(string, "yyyy-MM-dd HH:mm:ss.nnnXXX").unwrap();
+            assert_eq!(date_time.add_seconds(index as u32), parsed);
+        }
+        assert!(start.elapsed() < std::time::Duration::from_secs(1));
+    }
 }
@@ -0,0 +1,157 @@
+mod common;
+
+#[cfg(test)]
+#[cfg(feature = "rand")]
+mod rand_tests {
+    use crate::common::Random;
+    use astrolabe::{Date, DateTime, DateUtilities, Offset, OffsetUtilities, Time};
+    use rand::{Error, Rng, RngCore};
+
+    const NANOS_PER_DAY: u64 = 86_400_000_000_000;
+
+    impl RngCore for Random {
+        fn next_u32(&mut self) -> u32 {
+            Random::next_u64(self) as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            Random::next_u64(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for byte in dest {
+                *byte = Random::next_u64(self) as u8;
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn standard() {
+        let mut rng = Random::new(0x2545_f491_4f6c_dd1d);
+
+        let dates = (0..10_000).map(|_| rng.gen::<Date>()).collect::<Vec<_>>();
+        // Both signs of the full range are covered
+        assert!(dates.iter().any(|date| date.year() < -1_000_000));
+        assert!(dates.iter().any(|date| date.year() > 1_000_000));
+
+        for _ in 0..10_000 {
+            let time: Time = rng.gen();
+            assert!(time.as_nanos() < NANOS_PER_DAY);
+            assert_eq!(Offset::Fixed(0), time.get_offset());
+
+            let date_time: DateTime = rng.gen();
+            assert_eq!(Offset::Fixed(0), date_time.get_offset());
+            let (_, nanoseconds) = date_time.as_raw();
+            assert!(nanoseconds < NANOS_PER_DAY);
+        }
+    }
+
+    #[test]
+    fn date_range() {
+        let mut rng = Random::new(0x9e37_79b9_7f4a_7c15);
+
+        // Spanning the BC/AD boundary
+        let start = Date::from_ymd(-1, 12, 20).unwrap();
+        let end = Date::from_ymd(1, 1, 10).unwrap();
+        let dates = (0..10_000)
+            .map(|_| rng.gen_range(start..end))
+            .collect::<Vec<_>>();
+        assert!(dates.iter().all(|date| start <= *date && *date < end));
+        assert_eq!(start, *dates.iter().min().unwrap());
+        assert_eq!(end.sub_days(1), *dates.iter().max().unwrap());
+
+        let dates = (0..10_000)
+            .map(|_| rng.gen_range(start..=end))
+            .collect::<Vec<_>>();
+        assert_eq!(end, *dates.iter().max().unwrap());
+
+        let single = Date::from_ymd(2022, 5, 2).unwrap();
+        assert_eq!(single, rng.gen_range(single..=single));
+    }
+
+    #[test]
+    fn time_range() {
+        let mut rng = Random::new(0xdead_beef_cafe_babe);
+
+        let start = Time::from_hms(8, 0, 0).unwrap();
+        let end = Time::from_hms(17, 30, 0).unwrap();
+        let mut min = end;
+        let mut max = start;
+        for _ in 0..10_000 {
+            let time = rng.gen_range(start..end);
+            assert!(start <= time && time < end);
+            min = min.min(time);
+            max = max.max(time);
+        }
+        // The observed extremes are close to the bounds
+        assert!(min.as_nanos() - start.as_nanos() < 60_000_000_000 * 10);
+        assert!(end.as_nanos() - max.as_nanos() < 60_000_000_000 * 10);
+
+        let end = Time::from_nanos(NANOS_PER_DAY - 1).unwrap();
+        for _ in 0..10_000 {
+            let time = rng.gen_range(start..=end);
+            assert!(start <= time && time <= end);
+            assert!(time.as_nanos() < NANOS_PER_DAY);
+        }
+
+        let start = Time::from_hms(8, 0, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(3600));
+        let time = rng.gen_range(start..=start);
+        assert_eq!(start, time);
+        assert_eq!(Offset::Fixed(3600), time.get_offset());
+    }
+
+    #[test]
+    fn date_time_range() {
+        let mut rng = Random::new(0x0123_4567_89ab_cdef);
+
+        // Spanning the BC/AD boundary
+        let start = DateTime::from_ymdhms(-1, 12, 31, 12, 0, 0).unwrap();
+        let end = DateTime::from_ymdhms(1, 1, 1, 12, 0, 0).unwrap();
+        let mut min = end;
+        let mut max = start;
+        for _ in 0..10_000 {
+            let date_time = rng.gen_range(start..end);
+            assert!(start <= date_time && date_time < end);
+            let (_, nanoseconds) = date_time.as_raw();
+            assert!(nanoseconds < NANOS_PER_DAY);
+            min = min.min(date_time);
+            max = max.max(date_time);
+        }
+        assert!(min.duration_between(&start).as_secs() < 60);
+        assert!(end.duration_between(&max).as_secs() < 60);
+        assert!(min.year() == -1 && max.year() == 1);
+
+        // Keeps the offset of the lower bound
+        let start = DateTime::from_ymd(2022, 5, 2)
+            .unwrap()
+            .set_offset(Offset::Fixed(-7200));
+        let end = start.add_days(1);
+        for _ in 0..1_000 {
+            let date_time = rng.gen_range(start..=end);
+            assert!(start <= date_time && date_time <= end);
+            assert_eq!(Offset::Fixed(-7200), date_time.get_offset());
+        }
+
+        // Falls back to the offset of the upper bound at the range limits
+        let start = DateTime::from_ymdhms(5_879_611, 7, 12, 0, 0, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(23 * 3600));
+        let end = DateTime::from_ymdhms(5_879_611, 7, 12, 23, 59, 59).unwrap();
+        let mut fallbacks = 0;
+        for _ in 0..1_000 {
+            let date_time = rng.gen_range(start..=end);
+            assert!(start <= date_time && date_time <= end);
+            if date_time.get_offset() == Offset::Fixed(0) {
+                fallbacks += 1;
+            }
+        }
+        assert!(fallbacks > 0);
+    }
+}
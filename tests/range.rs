@@ -0,0 +1,181 @@
+#[cfg(test)]
+mod range_tests {
+    use astrolabe::{Date, DateTime, DateUtilities, Offset, OffsetUtilities, TimeUtilities};
+    use std::time::Duration;
+
+    #[test]
+    fn date_range() {
+        let start = Date::from_ymd(2022, 2, 27).unwrap();
+        let end = Date::from_ymd(2022, 3, 2).unwrap();
+
+        let range = Date::range(start, end);
+        assert_eq!(3, range.len());
+        assert_eq!(
+            vec!["2022/02/27", "2022/02/28", "2022/03/01"],
+            range.map(|date| date.to_string()).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["2022/03/01", "2022/02/28", "2022/02/27"],
+            Date::range(start, end)
+                .rev()
+                .map(|date| date.to_string())
+                .collect::<Vec<_>>()
+        );
+
+        // Backwards
+        let range = Date::range(end, start);
+        assert_eq!(3, range.len());
+        assert_eq!(
+            vec!["2022/03/02", "2022/03/01", "2022/02/28"],
+            range.map(|date| date.to_string()).collect::<Vec<_>>()
+        );
+
+        // Both ends
+        let mut range = Date::range(start, end);
+        assert_eq!(Some(start), range.next());
+        assert_eq!(Some(end.sub_days(1)), range.next_back());
+        assert_eq!(1, range.len());
+        assert_eq!(Some(start.add_days(1)), range.next_back());
+        assert_eq!(None, range.next());
+        assert_eq!(None, range.next_back());
+        assert_eq!(0, range.len());
+    }
+
+    #[test]
+    fn date_range_edge_cases() {
+        let date = Date::from_ymd(2022, 5, 2).unwrap();
+        assert_eq!(0, Date::range(date, date).len());
+        assert_eq!(None, Date::range(date, date).next());
+        assert_eq!(None, Date::range(date, date).next_back());
+
+        let min = Date::from_ymd(-5879611, 6, 30).unwrap();
+        let max = Date::from_ymd(5879611, 7, 12).unwrap();
+
+        let mut range = Date::range(min, max);
+        assert_eq!(4_294_967_288, range.len());
+        assert_eq!(Some(min), range.next());
+        assert_eq!(Some(max.sub_days(1)), range.next_back());
+
+        let mut range = Date::range(max, min);
+        assert_eq!(4_294_967_288, range.len());
+        assert_eq!(Some(max), range.next());
+        assert_eq!(Some(min.add_days(1)), range.next_back());
+
+        assert_eq!(
+            vec![max.sub_days(1)],
+            Date::range(max.sub_days(1), max).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![min.add_days(1)],
+            Date::range(min.add_days(1), min).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn date_time_range_step() {
+        let start = DateTime::from_ymdhms(2022, 5, 2, 23, 30, 0).unwrap();
+        let end = DateTime::from_ymdhms(2022, 5, 3, 0, 30, 0).unwrap();
+        let step = Duration::from_secs(15 * 60);
+
+        let range = DateTime::range_step(start, end, step);
+        assert_eq!((4, Some(4)), range.size_hint());
+        assert_eq!(
+            vec!["23:30", "23:45", "00:00", "00:15"],
+            range
+                .map(|date_time| date_time.format("HH:mm"))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["00:15", "00:00", "23:45", "23:30"],
+            DateTime::range_step(start, end, step)
+                .rev()
+                .map(|date_time| date_time.format("HH:mm"))
+                .collect::<Vec<_>>()
+        );
+
+        // Backwards
+        assert_eq!(
+            vec!["00:30", "00:15", "00:00", "23:45"],
+            DateTime::range_step(end, start, step)
+                .map(|date_time| date_time.format("HH:mm"))
+                .collect::<Vec<_>>()
+        );
+
+        // Step doesn't divide the range
+        let range = DateTime::range_step(start, end, Duration::from_secs(25 * 60));
+        assert_eq!((3, Some(3)), range.size_hint());
+        assert_eq!(
+            Some(start.add_minutes(50)),
+            DateTime::range_step(start, end, Duration::from_secs(25 * 60)).next_back()
+        );
+
+        // Offset of the start is kept
+        let start = start.set_offset(Offset::Fixed(3600));
+        assert!(DateTime::range_step(start, end, step)
+            .all(|date_time| date_time.get_offset() == Offset::Fixed(3600)));
+    }
+
+    #[test]
+    fn date_time_range_step_edge_cases() {
+        let date_time = DateTime::from_ymd(2022, 5, 2).unwrap();
+        let step = Duration::from_secs(1);
+        assert_eq!(
+            (0, Some(0)),
+            DateTime::range_step(date_time, date_time, step).size_hint()
+        );
+        assert_eq!(
+            None,
+            DateTime::range_step(date_time, date_time, step).next()
+        );
+
+        // Step larger than the range
+        let end = date_time.add_seconds(10);
+        let range = DateTime::range_step(date_time, end, Duration::from_secs(3600));
+        assert_eq!(vec![date_time], range.collect::<Vec<_>>());
+        let range = DateTime::range_step(end, date_time, Duration::from_secs(3600));
+        assert_eq!(vec![end], range.collect::<Vec<_>>());
+
+        let min = DateTime::from_ymd(-5879611, 6, 30).unwrap();
+        let max = DateTime::from_ymdhms(5879611, 7, 12, 23, 59, 59)
+            .unwrap()
+            .add_nanos(999_999_999);
+
+        let mut range = DateTime::range_step(min, max, Duration::from_secs(86400));
+        assert_eq!((4_294_967_289, Some(4_294_967_289)), range.size_hint());
+        assert_eq!(Some(min), range.next());
+        assert_eq!(
+            Some(
+                max.sub_nanos(999_999_999)
+                    .sub_hours(23)
+                    .sub_minutes(59)
+                    .sub_seconds(59)
+            ),
+            range.next_back()
+        );
+
+        let mut range = DateTime::range_step(max, min, Duration::from_secs(86400));
+        assert_eq!(Some(max), range.next());
+        assert_eq!(
+            Some(
+                min.add_nanos(999_999_999)
+                    .add_hours(23)
+                    .add_minutes(59)
+                    .add_seconds(59)
+            ),
+            range.next_back()
+        );
+
+        // Size doesn't fit into usize
+        let mut range = DateTime::range_step(min, max, Duration::from_nanos(1));
+        assert_eq!((usize::MAX, None), range.size_hint());
+        assert_eq!(Some(min), range.next());
+        assert_eq!(Some(max.sub_nanos(1)), range.next_back());
+    }
+
+    #[test]
+    #[should_panic]
+    fn date_time_range_zero_step() {
+        let date_time = DateTime::from_ymd(2022, 5, 2).unwrap();
+        DateTime::range_step(date_time, date_time.add_days(1), Duration::ZERO);
+    }
+}
@@ -1,8 +1,20 @@
 #[cfg(test)]
 #[cfg(feature = "serde")]
 mod serde_tests {
-    use astrolabe::{Date, DateTime, Time};
-    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+    use astrolabe::{
+        serde::{auto_precision, rfc3339_or_now},
+        Date, DateTime, Time, TimeUtilities,
+    };
+    use serde::{
+        de::{
+            value::{Error, StrDeserializer, UnitDeserializer},
+            IntoDeserializer,
+        },
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, assert_tokens, Token,
+    };
 
     #[test]
     fn time() {
@@ -39,4 +51,91 @@ mod serde_tests {
             "invalid type: integer `0`, expected an RFC 3339 formatted date string",
         );
     }
+
+    #[derive(Debug, PartialEq)]
+    struct LastSeen(DateTime);
+
+    impl Serialize for LastSeen {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            rfc3339_or_now::serialize(&self.0, serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for LastSeen {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            rfc3339_or_now::deserialize(deserializer).map(LastSeen)
+        }
+    }
+
+    #[test]
+    fn rfc3339_or_now() {
+        let last_seen = LastSeen(DateTime::from_ymdhms(2022, 5, 2, 12, 32, 10).unwrap());
+        assert_tokens(&last_seen, &[Token::String("2022-05-02T12:32:10Z")]);
+        assert_de_tokens(
+            &last_seen,
+            &[Token::Some, Token::String("2022-05-02T12:32:10Z")],
+        );
+
+        let last_seen = LastSeen(DateTime::parse_rfc3339("2022-05-02T12:32:10+02:00").unwrap());
+        assert_tokens(&last_seen, &[Token::String("2022-05-02T12:32:10+02:00")]);
+
+        // Null and empty strings result in now
+        let before = DateTime::now();
+        let unit: UnitDeserializer<Error> = ().into_deserializer();
+        let from_null = LastSeen::deserialize(unit).unwrap().0;
+        let empty: StrDeserializer<Error> = "".into_deserializer();
+        let from_empty = LastSeen::deserialize(empty).unwrap().0;
+        let after = DateTime::now();
+        assert!(before <= from_null && from_null <= after);
+        assert!(before <= from_empty && from_empty <= after);
+
+        assert_de_tokens_error::<LastSeen>(
+            &[Token::String("garbage")],
+            "RFC 3339 string cannot be shorter than 20 chars",
+        );
+        assert_de_tokens_error::<LastSeen>(
+            &[Token::I32(0)],
+            "invalid type: integer `0`, expected an RFC 3339 formatted date string, an empty string or null",
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Precise(DateTime);
+
+    impl Serialize for Precise {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            auto_precision::serialize(&self.0, serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Precise {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            auto_precision::deserialize(deserializer).map(Precise)
+        }
+    }
+
+    #[test]
+    fn auto_precision() {
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 32, 10).unwrap();
+        assert_tokens(
+            &Precise(date_time),
+            &[Token::String("2022-05-02T12:32:10Z")],
+        );
+
+        let precise = Precise(date_time.set_milli(500).unwrap());
+        assert_tokens(&precise, &[Token::String("2022-05-02T12:32:10.5Z")]);
+        // The default implementation drops subseconds
+        assert_ser_tokens(&precise.0, &[Token::String("2022-05-02T12:32:10Z")]);
+
+        let precise = Precise(date_time.set_nano(1).unwrap());
+        assert_tokens(&precise, &[Token::String("2022-05-02T12:32:10.000000001Z")]);
+
+        let precise = Precise(DateTime::parse_rfc3339("2022-05-02T12:32:10.123+02:00").unwrap());
+        assert_tokens(&precise, &[Token::String("2022-05-02T12:32:10.123+02:00")]);
+
+        assert_de_tokens_error::<Precise>(
+            &[Token::I32(0)],
+            "invalid type: integer `0`, expected an RFC 3339 formatted date string",
+        );
+    }
 }
@@ -0,0 +1,122 @@
+#[cfg(test)]
+#[cfg(feature = "sqlx-sqlite")]
+mod sqlx_sqlite_tests {
+    use astrolabe::{Date, DateTime, Offset, OffsetUtilities, Time};
+    use sqlx::SqlitePool;
+
+    #[sqlx::test(migrations = false)]
+    async fn datetime(db_pool: SqlitePool) {
+        let datetime = DateTime::from_ymdhms_nano(2024, 1, 2, 10, 30, 40, 123_456_789)
+            .unwrap()
+            .set_offset(Offset::from_hms(2, 0, 0).unwrap());
+        let result: DateTime = sqlx::query_scalar("SELECT ?")
+            .bind(datetime)
+            .fetch_one(&db_pool)
+            .await
+            .unwrap();
+        assert_eq!(datetime, result);
+
+        // Encoded values can be used with SQLite's date and time functions
+        let result: String = sqlx::query_scalar("SELECT datetime(?)")
+            .bind(datetime)
+            .fetch_one(&db_pool)
+            .await
+            .unwrap();
+        assert_eq!("2024-01-02 10:30:40", result);
+
+        let result: DateTime = sqlx::query_scalar("SELECT datetime('2024-01-02 10:30:40')")
+            .fetch_one(&db_pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            DateTime::from_ymdhms(2024, 1, 2, 10, 30, 40).unwrap(),
+            result
+        );
+
+        let result: DateTime = sqlx::query_scalar("SELECT julianday('2024-01-02 10:30:40.123')")
+            .fetch_one(&db_pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            DateTime::from_ymdhms_nano(2024, 1, 2, 10, 30, 40, 123_000_000).unwrap(),
+            result
+        );
+
+        let result: DateTime = sqlx::query_scalar("SELECT '2024-01-02T10:30:40+02:00'")
+            .fetch_one(&db_pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            DateTime::from_ymdhms(2024, 1, 2, 8, 30, 40).unwrap(),
+            result
+        );
+    }
+
+    #[sqlx::test(migrations = false)]
+    async fn datetime_order(db_pool: SqlitePool) {
+        sqlx::query("CREATE TABLE astrolabe_tests (timestamp TEXT)")
+            .execute(&db_pool)
+            .await
+            .unwrap();
+        let datetimes = [
+            DateTime::from_ymdhms(2024, 1, 2, 10, 30, 40).unwrap(),
+            DateTime::from_ymdhms_nano(2024, 1, 2, 10, 30, 40, 1).unwrap(),
+            DateTime::from_ymdhms(980, 1, 2, 10, 30, 40).unwrap(),
+        ];
+        for datetime in datetimes {
+            sqlx::query("INSERT INTO astrolabe_tests (timestamp) VALUES (?)")
+                .bind(datetime)
+                .execute(&db_pool)
+                .await
+                .unwrap();
+        }
+
+        let result: Vec<DateTime> =
+            sqlx::query_scalar("SELECT timestamp FROM astrolabe_tests ORDER BY timestamp")
+                .fetch_all(&db_pool)
+                .await
+                .unwrap();
+        assert_eq!(vec![datetimes[2], datetimes[0], datetimes[1]], result);
+    }
+
+    #[sqlx::test(migrations = false)]
+    async fn date(db_pool: SqlitePool) {
+        let date = Date::from_ymd(2024, 1, 2).unwrap();
+        let result: Date = sqlx::query_scalar("SELECT ?")
+            .bind(date)
+            .fetch_one(&db_pool)
+            .await
+            .unwrap();
+        assert_eq!(date, result);
+
+        let result: Date = sqlx::query_scalar("SELECT date(?, '+1 month')")
+            .bind(date)
+            .fetch_one(&db_pool)
+            .await
+            .unwrap();
+        assert_eq!(Date::from_ymd(2024, 2, 2).unwrap(), result);
+
+        let result: Date = sqlx::query_scalar("SELECT julianday('2024-01-02 23:59:59')")
+            .fetch_one(&db_pool)
+            .await
+            .unwrap();
+        assert_eq!(date, result);
+    }
+
+    #[sqlx::test(migrations = false)]
+    async fn time(db_pool: SqlitePool) {
+        let time = Time::from_hms_nano(10, 30, 40, 123_456_789).unwrap();
+        let result: Time = sqlx::query_scalar("SELECT ?")
+            .bind(time)
+            .fetch_one(&db_pool)
+            .await
+            .unwrap();
+        assert_eq!(time, result);
+
+        let result: Time = sqlx::query_scalar("SELECT time('10:30:40', '+1 hour')")
+            .fetch_one(&db_pool)
+            .await
+            .unwrap();
+        assert_eq!(Time::from_hms(11, 30, 40).unwrap(), result);
+    }
+}
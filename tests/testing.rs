@@ -0,0 +1,110 @@
+#[cfg(test)]
+#[cfg(feature = "test-util")]
+mod testing_tests {
+    use std::{thread, time::Duration};
+
+    use astrolabe::{
+        testing, CronSchedule, Date, DateTime, DateUtilities, Offset, OffsetUtilities, Time,
+        TimeUtilities,
+    };
+
+    #[test]
+    fn now() {
+        let mocked = DateTime::from_ymdhms(2022, 5, 2, 10, 17, 3)
+            .unwrap()
+            .set_nano(123_456_789)
+            .unwrap();
+        testing::set_mock_now(mocked);
+
+        assert_eq!(mocked, DateTime::now());
+        assert_eq!(mocked.clear_until_milli(), DateTime::now_seconds());
+        assert_eq!(mocked.clear_until_hour(), DateTime::today());
+        assert_eq!(mocked, DateTime::now_local());
+        assert_eq!(Offset::Local, DateTime::now_local().get_offset());
+        assert_eq!(Date::from_ymd(2022, 5, 2).unwrap(), Date::now());
+        assert_eq!(Date::from_ymd(2022, 5, 2).unwrap(), Date::today());
+        assert_eq!(
+            Time::from_hms(10, 17, 3)
+                .unwrap()
+                .set_nano(123_456_789)
+                .unwrap(),
+            Time::now()
+        );
+
+        // The offset of the mocked instance is ignored
+        testing::set_mock_now(mocked.set_offset(Offset::from_hms(2, 0, 0).unwrap()));
+        assert_eq!(Offset::Fixed(0), DateTime::now().get_offset());
+        assert_eq!(mocked, DateTime::now());
+
+        // The mocked time doesn't advance
+        thread::sleep(Duration::from_millis(5));
+        assert_eq!(mocked, DateTime::now());
+
+        assert!(mocked.add_nanos(1).is_future());
+        assert!(!mocked.is_future());
+        assert!(!mocked.is_past());
+        assert_eq!(Duration::from_secs(60), mocked.sub_minutes(1).elapsed());
+        assert!(Date::from_ymd(2022, 5, 2).unwrap().is_today());
+        assert!(!Date::from_ymd(2022, 5, 3).unwrap().is_today());
+
+        testing::clear_mock_now();
+        assert!(2022 < DateTime::now().year());
+    }
+
+    #[test]
+    fn per_thread() {
+        let mocked = DateTime::from_ymd(2000, 1, 1).unwrap();
+        testing::set_mock_now(mocked);
+
+        let other = thread::spawn(DateTime::now).join().unwrap();
+        assert!(2022 < other.year());
+        assert_eq!(mocked, DateTime::now());
+
+        testing::clear_mock_now();
+    }
+
+    #[test]
+    fn cron() {
+        testing::set_mock_now(DateTime::from_ymdhms(2022, 5, 2, 23, 50, 0).unwrap());
+
+        let schedule = CronSchedule::parse("0 */12 * * *").unwrap();
+        assert_eq!(
+            vec![
+                "2022-05-03 00:00",
+                "2022-05-03 12:00",
+                "2022-05-04 00:00",
+                "2022-05-04 12:00"
+            ],
+            schedule
+                .upcoming_n(4)
+                .iter()
+                .map(|date_time| date_time.format("yyyy-MM-dd HH:mm"))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            DateTime::from_ymd(2022, 5, 3).unwrap(),
+            schedule.next_occurrence().unwrap()
+        );
+
+        // Iterating the same schedule twice gives the same occurrences
+        let first: Vec<DateTime> = schedule.upcoming().take(10).collect();
+        let second: Vec<DateTime> = schedule.upcoming().take(10).collect();
+        assert_eq!(first, second);
+
+        // Moving the clock forward moves the occurrences
+        testing::set_mock_now(DateTime::from_ymdhms(2022, 5, 3, 0, 0, 0).unwrap());
+        assert_eq!(
+            DateTime::from_ymdhms(2022, 5, 3, 12, 0, 0).unwrap(),
+            schedule.next_occurrence().unwrap()
+        );
+
+        let schedule = CronSchedule::parse("0 0 * * *")
+            .unwrap()
+            .with_jitter(Duration::from_secs(600), 42)
+            .unwrap();
+        let first = schedule.upcoming_n(5);
+        assert_eq!(first, schedule.upcoming_n(5));
+
+        testing::clear_mock_now();
+    }
+}
@@ -1,8 +1,14 @@
 #[cfg(test)]
 mod time_tests {
-    use std::time::Duration;
+    use std::{
+        collections::{hash_map::DefaultHasher, HashSet},
+        hash::{Hash, Hasher},
+        time::Duration,
+    };
 
-    use astrolabe::{DateTime, Time, TimeUtilities};
+    use astrolabe::{
+        errors::AstrolabeError, DateTime, Offset, OffsetUtilities, Time, TimeUnit, TimeUtilities,
+    };
 
     #[test]
     fn debug() {
@@ -54,12 +60,41 @@ mod time_tests {
         assert_eq!(std::cmp::Ordering::Less, time.cmp(&time_2));
     }
 
+    #[test]
+    fn hash() {
+        let time = Time::from_hms(12, 0, 0).unwrap();
+        // Same instant, different offsets
+        let time_2 = time.set_offset(Offset::Fixed(3600));
+        let time_3 = time.set_offset(Offset::Fixed(-43200));
+        assert!(time == time_2);
+        assert_eq!(hash_of(&time), hash_of(&time_2));
+        assert_eq!(hash_of(&time), hash_of(&time_3));
+        assert_ne!(hash_of(&time), hash_of(&time.add_nanos(1)));
+
+        let mut set = HashSet::new();
+        set.insert(time);
+        set.insert(time_2);
+        set.insert(time_3);
+        assert_eq!(1, set.len());
+    }
+
+    fn hash_of(time: &Time) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        time.hash(&mut hasher);
+        hasher.finish()
+    }
+
     #[test]
     fn now() {
         let _ = Time::now();
         let _ = Time::now_local();
     }
 
+    #[test]
+    fn now_precision() {
+        assert!((0..10).any(|_| Time::now().nano() != 0));
+    }
+
     #[test]
     fn from_hms() {
         // check allowed limits
@@ -94,6 +129,69 @@ mod time_tests {
         assert!(Time::from_seconds(86400).is_err())
     }
 
+    #[test]
+    fn milliseconds() {
+        assert_eq!(0, Time::from_millis(0).unwrap().as_millis());
+        assert_eq!(
+            43_200_000,
+            Time::from_millis(43_200_000).unwrap().as_millis()
+        );
+        assert_eq!(
+            86_399_999,
+            Time::from_millis(86_399_999).unwrap().as_millis()
+        );
+        assert!(Time::from_millis(86_400_000).is_err());
+
+        assert_eq!(
+            Time::from_hms(12, 0, 0).unwrap(),
+            Time::from_millis(43_200_000).unwrap()
+        );
+        let last = Time::from_nanos(86_399_999_999_999).unwrap();
+        assert_eq!(86_399_999, last.as_millis());
+        assert_eq!(
+            "23:59:59.999",
+            Time::from_millis(86_399_999)
+                .unwrap()
+                .format("HH:mm:ss.nnn")
+        );
+
+        // The offset is not applied
+        let time = Time::from_hms(12, 0, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(3600));
+        assert_eq!(43_200_000, time.as_millis());
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 12, 0, 0).unwrap();
+        assert_eq!(43_200_000, Time::from(date_time).as_millis());
+    }
+
+    #[test]
+    fn microseconds() {
+        assert_eq!(0, Time::from_micros(0).unwrap().as_micros());
+        assert_eq!(
+            43_200_000_000,
+            Time::from_micros(43_200_000_000).unwrap().as_micros()
+        );
+        assert_eq!(
+            86_399_999_999,
+            Time::from_micros(86_399_999_999).unwrap().as_micros()
+        );
+        assert!(Time::from_micros(86_400_000_000).is_err());
+        assert!(Time::from_micros(u64::MAX).is_err());
+
+        assert_eq!(
+            Time::from_hms(12, 0, 0).unwrap(),
+            Time::from_micros(43_200_000_000).unwrap()
+        );
+        let last = Time::from_nanos(86_399_999_999_999).unwrap();
+        assert_eq!(86_399_999_999, last.as_micros());
+        assert_eq!(
+            "23:59:59.999999",
+            Time::from_micros(86_399_999_999)
+                .unwrap()
+                .format("HH:mm:ss.nnnn")
+        );
+    }
+
     #[test]
     fn nanoseconds() {
         assert_eq!(0, Time::from_nanos(0).unwrap().as_nanos());
@@ -104,6 +202,65 @@ mod time_tests {
         assert!(Time::from_nanos(86_400_000_000_000).is_err())
     }
 
+    #[test]
+    fn raw() {
+        assert_eq!(
+            Time::from_nanos(1_234).unwrap(),
+            Time::from_raw_nanos(1_234).unwrap()
+        );
+        assert!(Time::from_raw_nanos(86_400_000_000_000).is_err());
+        let time = Time::from_hms(12, 0, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(3600));
+        assert_eq!(43_200_000_000_000, time.as_nanos());
+        assert_eq!(
+            time.as_nanos(),
+            Time::from_raw_nanos(time.as_nanos()).unwrap().as_nanos()
+        );
+    }
+
+    #[test]
+    fn duration() {
+        let day = Duration::from_secs(86_400);
+
+        match Time::try_from(day).unwrap_err() {
+            AstrolabeError::OutOfRange(error) => {
+                assert_eq!("nanoseconds", error.field_name());
+                assert_eq!(0, error.min());
+                assert_eq!(86_399_999_999_999, error.max());
+                assert_eq!(86_400_000_000_000, error.value());
+            }
+            error => panic!("Expected out of range error: {:?}", error),
+        }
+        assert!(Time::try_from(Duration::MAX).is_err());
+
+        let time = Time::try_from(day - Duration::from_nanos(1)).unwrap();
+        assert_eq!((23, 59, 59), time.as_hms());
+        assert_eq!(999_999_999, time.nano());
+        assert_eq!(day - Duration::from_nanos(1), Duration::from(time));
+        assert_eq!(Time::default(), Time::try_from(Duration::ZERO).unwrap());
+
+        assert_eq!(
+            Time::from_hms(2, 0, 0).unwrap(),
+            Time::from_duration_wrapping(Duration::from_secs(50 * 3600))
+        );
+        assert_eq!(Time::default(), Time::from_duration_wrapping(day));
+        assert_eq!(
+            time,
+            Time::from_duration_wrapping(day * 3 - Duration::from_nanos(1))
+        );
+        assert_eq!(
+            Duration::from_nanos((Duration::MAX.as_nanos() % 86_400_000_000_000) as u64),
+            Duration::from(Time::from_duration_wrapping(Duration::MAX))
+        );
+
+        // Counted in the offset of the time
+        let time = Time::from_hms(23, 0, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(3600));
+        assert_eq!(Duration::ZERO, Duration::from(time));
+    }
+
     #[test]
     fn duration_between() {
         duration_between_ok(Duration::from_secs(0), Time::default(), Time::default());
@@ -404,6 +561,20 @@ mod time_tests {
     fn display() {
         let time = Time::from_hms(12, 31, 1).unwrap();
         assert_eq!("12:31:01", format!("{}", time));
+
+        let time = time.add_nanos(123_456_789);
+        assert_eq!("12:31:01", format!("{}", time));
+        assert_eq!("12:31:01", format!("{:.0}", time));
+        assert_eq!("12:31:01.1", format!("{:.1}", time));
+        assert_eq!("12:31:01.123", format!("{:.3}", time));
+        assert_eq!("12:31:01.123456789", format!("{:.9}", time));
+        assert_eq!("12:31:01.123456789", format!("{:.12}", time));
+        assert_eq!("12:31:01Z", format!("{:#}", time));
+        assert_eq!("12:31:01.123456Z", format!("{:#.6}", time));
+
+        let time = time.set_offset(Offset::Fixed(-5400));
+        assert_eq!("11:01:01", format!("{}", time));
+        assert_eq!("11:01:01.12-01:30", format!("{:#.2}", time));
     }
 
     #[test]
@@ -417,11 +588,194 @@ mod time_tests {
 
     #[test]
     fn time_sub() {
-        let mut time = Time::from_hms(12, 32, 1).unwrap();
-        let modified = time - Time::from_seconds(60 * 3 + 3).unwrap();
-        assert_eq!("12:28:58.000000000", modified.format("HH:mm:ss.nnnnn"));
-        time -= Time::from_seconds(60 * 3 + 3).unwrap();
-        assert_eq!("12:28:58.000000000", time.format("HH:mm:ss.nnnnn"));
+        let time = Time::from_hms(12, 32, 1).unwrap();
+        let other = Time::from_hms(12, 28, 58).unwrap();
+        assert_eq!(183_000_000_000, time - other);
+        assert_eq!(-183_000_000_000, other - time);
+        assert_eq!(0, time - time);
+
+        // Offsets are resolved: 14:32:01 +02:00 is 12:32:01 UTC
+        let local = time.set_offset(Offset::Fixed(7200));
+        assert_eq!(0, local - time);
+        assert_eq!(183_000_000_000, local - other);
+    }
+
+    #[test]
+    fn duration_until() {
+        let start = Time::from_hms(22, 15, 0).unwrap();
+        let end = Time::from_hms(3, 40, 0).unwrap();
+        assert_eq!(Duration::from_secs(19_500), start.duration_until(&end));
+        assert_eq!(Duration::from_secs(66_900), end.duration_until(&start));
+        assert_eq!(Duration::from_secs(66_900), start.duration_between(&end));
+        assert_eq!(Duration::from_secs(66_900), end.duration_between(&start));
+        assert_eq!(Duration::ZERO, start.duration_until(&start));
+
+        // Straddling midnight
+        let before = Time::from_hms(23, 59, 59).unwrap();
+        let after = Time::from_hms(0, 0, 1).unwrap();
+        assert_eq!(Duration::from_secs(2), before.duration_until(&after));
+        assert_eq!(Duration::from_secs(86_398), after.duration_until(&before));
+        assert_eq!(Duration::from_secs(86_398), before.duration_between(&after));
+        assert_eq!(
+            Duration::from_nanos(1),
+            Time::from_nanos(86_399_999_999_999)
+                .unwrap()
+                .duration_until(&Time::default())
+        );
+
+        // Identical instants at different offsets
+        let utc = Time::from_hms(23, 0, 0).unwrap();
+        let local = utc.set_offset(Offset::Fixed(7200));
+        assert_eq!("01:00:00", local.format("HH:mm:ss"));
+        assert_eq!(Duration::ZERO, utc.duration_until(&local));
+        assert_eq!(Duration::ZERO, local.duration_until(&utc));
+        assert_eq!(Duration::ZERO, local.duration_between(&utc));
+
+        // 01:00 +02:00 is 23:00 UTC, 02:00 UTC is three hours later
+        let other = Time::from_hms(2, 0, 0).unwrap();
+        assert_eq!(Duration::from_secs(3 * 3600), local.duration_until(&other));
+        assert_eq!(Duration::from_secs(21 * 3600), other.duration_until(&local));
+    }
+
+    #[test]
+    fn std_wrapping() {
+        let time = Time::from_hms(23, 0, 0).unwrap();
+        assert_eq!(
+            "01:00:00",
+            (time + Duration::from_secs(2 * 3600)).to_string()
+        );
+        assert_eq!(
+            "00:00:00",
+            (time + Duration::from_secs(25 * 3600)).to_string()
+        );
+        assert_eq!(
+            "23:00:00",
+            (time + Duration::from_secs(365 * 86_400)).to_string()
+        );
+        assert_eq!(time, time + Duration::MAX - Duration::MAX);
+
+        let time = Time::from_hms(1, 0, 0).unwrap();
+        assert_eq!(
+            "23:00:00",
+            (time - Duration::from_secs(2 * 3600)).to_string()
+        );
+        assert_eq!("01:00:00", (time - Duration::from_secs(86_400)).to_string());
+        assert_eq!(
+            "00:59:59.999999999",
+            (time - Duration::from_nanos(86_400_000_000_001)).format("HH:mm:ss.nnnnn")
+        );
+    }
+
+    #[test]
+    fn overflowing() {
+        let time = Time::from_hms(23, 0, 0).unwrap();
+        assert_eq!(
+            (Time::from_hms(0, 0, 0).unwrap(), 2),
+            time.overflowing_add_hours(25)
+        );
+        assert_eq!(
+            (Time::from_hms(23, 0, 0).unwrap(), 0),
+            time.overflowing_add_hours(0)
+        );
+        assert_eq!(
+            (Time::from_hms(0, 0, 0).unwrap(), 1),
+            time.overflowing_add_minutes(60)
+        );
+        assert_eq!(
+            (Time::from_hms(22, 59, 59).unwrap(), 0),
+            time.overflowing_sub_seconds(1)
+        );
+        assert_eq!(
+            (Time::from_hms(23, 0, 0).unwrap(), -1),
+            time.overflowing_sub_hours(24)
+        );
+        assert_eq!(
+            (Time::from_hms(23, 0, 0).unwrap(), -2),
+            time.overflowing_sub_hours(48)
+        );
+
+        let time = Time::from_hms(0, 30, 0).unwrap();
+        assert_eq!(
+            (Time::from_hms(23, 30, 0).unwrap(), -1),
+            time.overflowing_sub_hours(1)
+        );
+        assert_eq!(
+            (Time::from_hms(0, 0, 0).unwrap(), -1),
+            time.overflowing_sub_minutes(24 * 60 + 30)
+        );
+        assert_eq!(
+            (Time::from_hms(0, 29, 59).unwrap(), 0),
+            time.overflowing_sub_millis(1_000)
+        );
+
+        // Carries from subsecond units
+        let time = Time::from_hms(23, 59, 59)
+            .unwrap()
+            .set_nano(999_999_999)
+            .unwrap();
+        assert_eq!((Time::default(), 1), time.overflowing_add_nanos(1));
+        assert_eq!(
+            (Time::default().add_nanos(999), 1),
+            time.overflowing_add_micros(1)
+        );
+        assert_eq!(
+            (Time::default().add_nanos(999_999), 1),
+            time.overflowing_add_millis(1)
+        );
+        assert_eq!((time, 0), time.overflowing_add_nanos(0));
+        assert_eq!((time, -1), Time::default().overflowing_sub_nanos(1));
+        assert_eq!(
+            (Time::default().sub_micros(1), -1),
+            Time::default().overflowing_sub_micros(1)
+        );
+        assert_eq!(
+            (Time::from_hms(6, 28, 15).unwrap(), 49_710),
+            Time::default().overflowing_add_seconds(u32::MAX)
+        );
+
+        // Days are carried over at midnight in the offset of the instance
+        let time = Time::from_hms(21, 0, 0)
+            .unwrap()
+            .set_offset(Offset::Fixed(7200));
+        let (wrapped, days) = time.overflowing_add_hours(2);
+        assert_eq!(1, days);
+        assert_eq!("01:00:00", wrapped.format("HH:mm:ss"));
+        assert_eq!(Offset::Fixed(7200), wrapped.get_offset());
+        assert_eq!(0, time.overflowing_add_hours(0).1);
+        assert_eq!(0, time.overflowing_sub_hours(23).1);
+        assert_eq!(-1, time.overflowing_sub_hours(24).1);
+    }
+
+    #[test]
+    fn wrapping() {
+        let time = Time::from_hms(23, 0, 0).unwrap();
+        assert_eq!(time.add_hours(25), time.wrapping_add_hours(25));
+        assert_eq!(time.add_minutes(90), time.wrapping_add_minutes(90));
+        assert_eq!(time.add_seconds(3_601), time.wrapping_add_seconds(3_601));
+        assert_eq!(
+            time.add_millis(u32::MAX),
+            time.wrapping_add_millis(u32::MAX)
+        );
+        assert_eq!(
+            time.add_micros(u32::MAX),
+            time.wrapping_add_micros(u32::MAX)
+        );
+        assert_eq!(time.add_nanos(u32::MAX), time.wrapping_add_nanos(u32::MAX));
+
+        let time = Time::from_hms(1, 0, 0).unwrap();
+        assert_eq!(time.sub_hours(25), time.wrapping_sub_hours(25));
+        assert_eq!(time.sub_minutes(90), time.wrapping_sub_minutes(90));
+        assert_eq!(time.sub_seconds(3_601), time.wrapping_sub_seconds(3_601));
+        assert_eq!(
+            time.sub_millis(u32::MAX),
+            time.wrapping_sub_millis(u32::MAX)
+        );
+        assert_eq!(
+            time.sub_micros(u32::MAX),
+            time.wrapping_sub_micros(u32::MAX)
+        );
+        assert_eq!(time.sub_nanos(u32::MAX), time.wrapping_sub_nanos(u32::MAX));
+        assert_eq!("23:00:00", time.wrapping_sub_hours(2).to_string());
     }
 
     #[test]
@@ -441,4 +795,322 @@ mod time_tests {
         time -= Duration::from_secs(60 * 3 + 3);
         assert_eq!("12:28:58.000000000", time.format("HH:mm:ss.nnnnn"));
     }
+
+    #[test]
+    fn round_to() {
+        let quarter = Duration::from_secs(15 * 60);
+        let hour = Duration::from_secs(3600);
+        let day = Duration::from_secs(86_400);
+
+        // (UTC time, offset in seconds, duration, floor, ceil, round), results in local time
+        let cases = [
+            ((23, 52, 30), 0, quarter, "23:45:00", "00:00:00", "00:00:00"),
+            ((23, 52, 30), 0, hour, "23:00:00", "00:00:00", "00:00:00"),
+            ((23, 52, 30), 0, day, "00:00:00", "00:00:00", "00:00:00"),
+            ((0, 7, 29), 0, quarter, "00:00:00", "00:15:00", "00:00:00"),
+            (
+                (18, 20, 0),
+                19_800,
+                quarter,
+                "23:45:00",
+                "00:00:00",
+                "23:45:00",
+            ),
+            (
+                (18, 20, 0),
+                19_800,
+                hour,
+                "23:00:00",
+                "00:00:00",
+                "00:00:00",
+            ),
+            (
+                (2, 10, 0),
+                -10_800,
+                hour,
+                "23:00:00",
+                "00:00:00",
+                "23:00:00",
+            ),
+            ((3, 0, 0), -10_800, hour, "00:00:00", "00:00:00", "00:00:00"),
+        ];
+
+        for ((hour, minute, second), offset, duration, floor, ceil, round) in cases {
+            let time = Time::from_hms(hour, minute, second)
+                .unwrap()
+                .set_offset(Offset::Fixed(offset));
+            let case = time.to_string();
+
+            let floored = time.floor_to(duration).unwrap();
+            assert_eq!(floor, floored.format("HH:mm:ss"), "floor {}", case);
+            assert_eq!(Offset::Fixed(offset), floored.get_offset());
+            assert_eq!(
+                ceil,
+                time.ceil_to(duration).unwrap().format("HH:mm:ss"),
+                "ceil {}",
+                case
+            );
+            assert_eq!(
+                round,
+                time.round_to(duration).unwrap().format("HH:mm:ss"),
+                "round {}",
+                case
+            );
+        }
+
+        let time = Time::from_hms(10, 0, 0).unwrap();
+        assert!(time.floor_to(Duration::ZERO).is_err());
+        assert!(time.ceil_to(Duration::from_secs(7 * 60)).is_err());
+        assert!(time.round_to(Duration::from_secs(2 * 86_400)).is_err());
+    }
+
+    #[test]
+    fn from_str() {
+        let cases = [
+            ("15:30:20", "15:30:20.000000000", 0),
+            ("00:00:00", "00:00:00.000000000", 0),
+            ("23:59:59.999999999", "23:59:59.999999999", 0),
+            ("15:30:20.123", "15:30:20.123000000", 0),
+            ("15:30:20.1234567891", "15:30:20.123456789", 0),
+            ("15:30:20Z", "15:30:20.000000000", 0),
+            ("15:30:20+02:00", "15:30:20.000000000", 7_200),
+            ("15:30:20.5-01:30", "15:30:20.500000000", -5_400),
+            ("01:00:00+0530", "01:00:00.000000000", 19_800),
+        ];
+        for (string, expected, offset) in cases {
+            let time: Time = string.parse().unwrap();
+            assert_eq!(expected, time.format("HH:mm:ss.nnnnn"), "{}", string);
+            assert_eq!(Offset::Fixed(offset), time.get_offset(), "{}", string);
+        }
+
+        let utc: Time = "13:30:20Z".parse().unwrap();
+        let local: Time = "15:30:20+02:00".parse().unwrap();
+        assert_eq!(utc, local);
+
+        let invalid = [
+            ("", "format"),
+            ("15:30", "format"),
+            ("15-30-20", "format"),
+            ("1:30:20", "format"),
+            ("1a:30:20", "hour"),
+            ("15:3a:20", "minute"),
+            ("15:30:2a", "second"),
+            ("15:30:20.", "subseconds"),
+            ("15:30:20x", "offset"),
+            ("15:30:20+25:00", "offset"),
+            ("15:30:20.5 +02:00", "offset"),
+            ("24:00:00", "hour"),
+            ("15:60:00", "minute"),
+        ];
+        for (string, component) in invalid {
+            let error = string.parse::<Time>().unwrap_err().to_string();
+            assert!(error.contains(component), "{}: {}", string, error);
+        }
+    }
+
+    #[test]
+    fn saturating() {
+        let time = Time::from_hms(12, 32, 1).unwrap();
+        assert_eq!(time, time.saturating_add_hours(0));
+        assert_eq!(time, time.saturating_sub_nanos(0));
+        assert_eq!(time.add_minutes(5), time.saturating_add_minutes(5));
+        assert_eq!(time.sub_seconds(5), time.saturating_sub_seconds(5));
+
+        let max = Time::from_nanos(86_399_999_999_999).unwrap();
+        let min = Time::from_hms(0, 0, 0).unwrap();
+        assert_eq!(max, time.saturating_add_hours(12));
+        assert_eq!(max, time.saturating_add_hours(u32::MAX));
+        assert_eq!(max, time.saturating_add_minutes(u32::MAX));
+        assert_eq!(max, time.saturating_add_seconds(u32::MAX));
+        assert_eq!(max, time.saturating_add_millis(u32::MAX));
+        assert_eq!(max, max.saturating_add_nanos(1));
+        assert_eq!(min, time.saturating_sub_hours(13));
+        assert_eq!(min, time.saturating_sub_hours(u32::MAX));
+        assert_eq!(min, time.saturating_sub_minutes(u32::MAX));
+        assert_eq!(min, time.saturating_sub_seconds(u32::MAX));
+        assert_eq!(min, time.saturating_sub_millis(u32::MAX));
+        assert_eq!(
+            time.sub_micros(u32::MAX),
+            time.saturating_sub_micros(u32::MAX)
+        );
+        assert_eq!(min, min.saturating_sub_nanos(1));
+
+        // Clamps in the offset of the instance
+        let time = time.set_offset(Offset::Fixed(-3600));
+        assert_eq!("11:32:01", time.to_string());
+        let clamped = time.saturating_add_hours(24);
+        assert_eq!("23:59:59.999999999", clamped.format("HH:mm:ss.nnnnn"));
+        assert_eq!(Offset::Fixed(-3600), clamped.get_offset());
+        assert_eq!("00:00:00", time.saturating_sub_hours(24).to_string());
+    }
+
+    #[test]
+    fn from_hms_unchecked() {
+        const TIMES: [Time; 3] = [
+            Time::from_hms_unchecked(0, 0, 0),
+            Time::from_hms_unchecked(9, 30, 0),
+            Time::from_hms_unchecked(23, 59, 59),
+        ];
+        assert_eq!(Time::from_hms(0, 0, 0).unwrap(), TIMES[0]);
+        assert_eq!(Time::from_hms(9, 30, 0).unwrap(), TIMES[1]);
+        assert_eq!(Time::from_hms(23, 59, 59).unwrap(), TIMES[2]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Invalid time")]
+    fn from_hms_unchecked_invalid() {
+        Time::from_hms_unchecked(24, 0, 0);
+    }
+
+    #[test]
+    fn units() {
+        let time = Time::from_hms(12, 32, 1)
+            .unwrap()
+            .set_nano(123_456_789)
+            .unwrap();
+        for unit in TimeUnit::ALL {
+            let (value, set, added, subtracted) = match unit {
+                TimeUnit::Hour => (
+                    time.hour(),
+                    time.set_hour(7),
+                    time.add_hours(30),
+                    time.sub_hours(30),
+                ),
+                TimeUnit::Minute => (
+                    time.minute(),
+                    time.set_minute(7),
+                    time.add_minutes(30),
+                    time.sub_minutes(30),
+                ),
+                TimeUnit::Second => (
+                    time.second(),
+                    time.set_second(7),
+                    time.add_seconds(30),
+                    time.sub_seconds(30),
+                ),
+                TimeUnit::Milli => (
+                    time.milli(),
+                    time.set_milli(7),
+                    time.add_millis(30),
+                    time.sub_millis(30),
+                ),
+                TimeUnit::Micro => (
+                    time.micro(),
+                    time.set_micro(7),
+                    time.add_micros(30),
+                    time.sub_micros(30),
+                ),
+                TimeUnit::Nano => (
+                    time.nano(),
+                    time.set_nano(7),
+                    time.add_nanos(30),
+                    time.sub_nanos(30),
+                ),
+            };
+            assert_eq!(value as i64, time.get_unit(unit));
+            assert_eq!(set.unwrap(), time.set_unit(unit, 7).unwrap());
+            assert_eq!(added, time.apply_unit(unit, 30).unwrap());
+            assert_eq!(subtracted, time.apply_unit(unit, -30).unwrap());
+            assert_eq!(time, time.apply_unit(unit, 0).unwrap());
+
+            assert!(time.set_unit(unit, -1).is_err());
+            assert!(time.set_unit(unit, i64::MAX).is_err());
+            assert!(time.apply_unit(unit, i64::MAX).is_err());
+            assert!(time.apply_unit(unit, i64::MIN).is_err());
+        }
+
+        assert!(time.set_unit(TimeUnit::Hour, 24).is_err());
+        assert!(time.apply_unit(TimeUnit::Nano, u32::MAX as i64).is_ok());
+    }
+
+    #[test]
+    fn next_previous() {
+        let time = Time::from_hms(12, 59, 59).unwrap();
+        assert_eq!(
+            Time::from_hms(13, 0, 0).unwrap(),
+            time.next_second().unwrap()
+        );
+        assert_eq!(
+            Time::from_hms(12, 59, 58).unwrap(),
+            time.previous_second().unwrap()
+        );
+        assert_eq!(time.add_nanos(1), time.next_nano().unwrap());
+        assert_eq!(time.sub_nanos(1), time.previous_nano().unwrap());
+
+        let last = Time::from_nanos(86_399_999_999_999).unwrap();
+        assert_eq!(None, last.next_nano());
+        assert_eq!(None, last.next_second());
+        assert_eq!(86_399_999_999_998, last.previous_nano().unwrap().as_nanos());
+        assert_eq!(
+            last,
+            Time::from_nanos(86_398_999_999_999)
+                .unwrap()
+                .next_second()
+                .unwrap()
+        );
+        assert_eq!(None, Time::from_hms(23, 59, 59).unwrap().next_second());
+
+        let midnight = Time::default();
+        assert_eq!(None, midnight.previous_nano());
+        assert_eq!(None, midnight.previous_second());
+        assert_eq!(1, midnight.next_nano().unwrap().as_nanos());
+        assert_eq!(
+            None,
+            Time::from_nanos(999_999_999).unwrap().previous_second()
+        );
+        assert_eq!(
+            midnight,
+            Time::from_hms(0, 0, 1).unwrap().previous_second().unwrap()
+        );
+
+        // The limits are in UTC and the offset is kept
+        let time = Time::from_hms(23, 59, 59)
+            .unwrap()
+            .set_offset(Offset::Fixed(3600));
+        assert_eq!(None, time.next_second());
+        let time = time.previous_second().unwrap();
+        assert_eq!("00:59:58", time.to_string());
+        assert_eq!(Offset::Fixed(3600), time.get_offset());
+    }
+
+    #[test]
+    fn from_hms_nano() {
+        let field_name = |result: Result<Time, AstrolabeError>| match result {
+            Err(AstrolabeError::OutOfRange(error)) => error.field_name().to_string(),
+            other => panic!("Expected an out of range error, got {:?}", other),
+        };
+
+        let time = Time::from_hms_nano(12, 32, 1, 123_456_789).unwrap();
+        assert_eq!((12, 32, 1, 123_456_789), time.as_hms_nano());
+        assert_eq!(
+            Time::from_hms(12, 32, 1)
+                .unwrap()
+                .set_nano(123_456_789)
+                .unwrap(),
+            time
+        );
+
+        assert_eq!(
+            (0, 0, 0, 0),
+            Time::from_hms_nano(0, 0, 0, 0).unwrap().as_hms_nano()
+        );
+        assert_eq!(
+            (23, 59, 59, 999_999_999),
+            Time::from_hms_nano(23, 59, 59, 999_999_999)
+                .unwrap()
+                .as_hms_nano()
+        );
+        assert_eq!("hour", field_name(Time::from_hms_nano(24, 0, 0, 0)));
+        assert_eq!("minute", field_name(Time::from_hms_nano(23, 60, 0, 0)));
+        assert_eq!("second", field_name(Time::from_hms_nano(23, 59, 60, 0)));
+        assert_eq!(
+            "nanoseconds",
+            field_name(Time::from_hms_nano(23, 59, 59, 1_000_000_000))
+        );
+        assert_eq!(
+            "minute",
+            field_name(Time::from_hms_nano(23, 60, 60, 1_000_000_000))
+        );
+    }
 }
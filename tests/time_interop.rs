@@ -0,0 +1,146 @@
+#[cfg(test)]
+#[cfg(feature = "time")]
+mod time_interop_tests {
+    use astrolabe::{Date, DateTime, DateUtilities, Offset, OffsetUtilities, Time, TimeUtilities};
+    use time::{Month, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+    #[test]
+    fn now() {
+        let now = DateTime::now();
+        let offset_date_time = OffsetDateTime::try_from(now).unwrap();
+        assert_eq!(now.timestamp(), offset_date_time.unix_timestamp());
+        assert_eq!(now.nano(), offset_date_time.nanosecond());
+        assert_eq!(now, DateTime::try_from(offset_date_time).unwrap());
+
+        let now = DateTime::now().set_offset(Offset::Fixed(-34_200));
+        let offset_date_time = OffsetDateTime::try_from(now).unwrap();
+        assert_eq!(now.timestamp(), offset_date_time.unix_timestamp());
+        assert_eq!(-34_200, offset_date_time.offset().whole_seconds());
+        let back = DateTime::try_from(offset_date_time).unwrap();
+        assert_eq!(now, back);
+        assert_eq!(now.get_offset(), back.get_offset());
+        assert_eq!(now.timestamp(), back.timestamp());
+    }
+
+    #[test]
+    fn date() {
+        let date = Date::from_ymd(2022, 5, 2).unwrap();
+        let time_date = time::Date::from_calendar_date(2022, Month::May, 2).unwrap();
+        assert_eq!(time_date, time::Date::try_from(date).unwrap());
+        assert_eq!(date, Date::from(time_date));
+
+        // time has a year 0, which is year -1 in astrolabe
+        let time_date = time::Date::from_calendar_date(0, Month::December, 31).unwrap();
+        assert_eq!(Date::from_ymd(-1, 12, 31).unwrap(), Date::from(time_date));
+
+        for time_date in [time::Date::MIN, time::Date::MAX] {
+            assert_eq!(
+                time_date,
+                time::Date::try_from(Date::from(time_date)).unwrap()
+            );
+        }
+
+        let min = Date::from(time::Date::MIN);
+        let max = Date::from(time::Date::MAX);
+        assert!(time::Date::try_from(min.sub_days(1)).is_err());
+        assert!(time::Date::try_from(max.add_days(1)).is_err());
+        assert!(time::Date::try_from(Date::from_days(i32::MIN)).is_err());
+        assert!(time::Date::try_from(Date::from_days(i32::MAX)).is_err());
+    }
+
+    #[test]
+    fn time() {
+        let time = Time::from_hms(12, 32, 1)
+            .unwrap()
+            .set_nano(123_456_789)
+            .unwrap();
+        let time_time = time::Time::from_hms_nano(12, 32, 1, 123_456_789).unwrap();
+        assert_eq!(time_time, time::Time::from(time));
+        assert_eq!(time, Time::from(time_time));
+
+        // The local time is converted
+        let time = time.set_offset(Offset::Fixed(-3600));
+        assert_eq!(
+            time::Time::from_hms_nano(11, 32, 1, 123_456_789).unwrap(),
+            time::Time::from(time)
+        );
+
+        for time_time in [
+            time::Time::MIDNIGHT,
+            time::Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap(),
+        ] {
+            assert_eq!(time_time, time::Time::from(Time::from(time_time)));
+        }
+    }
+
+    #[test]
+    fn primitive() {
+        let date_time = DateTime::from_ymdhms(2022, 5, 2, 15, 30, 20)
+            .unwrap()
+            .set_nano(1)
+            .unwrap();
+        let primitive = PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2022, Month::May, 2).unwrap(),
+            time::Time::from_hms_nano(15, 30, 20, 1).unwrap(),
+        );
+        assert_eq!(primitive, PrimitiveDateTime::try_from(date_time).unwrap());
+        assert_eq!(date_time, DateTime::from(primitive));
+
+        // The local date and time are converted
+        let date_time = date_time.set_offset(Offset::Fixed(9 * 3600));
+        assert_eq!(
+            PrimitiveDateTime::new(
+                time::Date::from_calendar_date(2022, Month::May, 3).unwrap(),
+                time::Time::from_hms_nano(0, 30, 20, 1).unwrap(),
+            ),
+            PrimitiveDateTime::try_from(date_time).unwrap()
+        );
+
+        for primitive in [PrimitiveDateTime::MIN, PrimitiveDateTime::MAX] {
+            assert_eq!(
+                primitive,
+                PrimitiveDateTime::try_from(DateTime::from(primitive)).unwrap()
+            );
+        }
+
+        let max = DateTime::from(PrimitiveDateTime::MAX);
+        assert!(PrimitiveDateTime::try_from(max.add_nanos(1)).is_err());
+        let min = DateTime::from(PrimitiveDateTime::MIN);
+        assert!(PrimitiveDateTime::try_from(min.sub_nanos(1)).is_err());
+    }
+
+    #[test]
+    fn offset() {
+        // Boundary values with the largest offsets
+        for seconds in [-86_399, 0, 86_399] {
+            let offset = UtcOffset::from_whole_seconds(seconds).unwrap();
+            for primitive in [
+                PrimitiveDateTime::MIN + time::Duration::DAY,
+                PrimitiveDateTime::MAX - time::Duration::DAY,
+            ] {
+                let offset_date_time = primitive.assume_utc().to_offset(offset);
+                let date_time = DateTime::try_from(offset_date_time).unwrap();
+                assert_eq!(Offset::Fixed(seconds), date_time.get_offset());
+                assert_eq!(offset_date_time.unix_timestamp(), date_time.timestamp());
+                assert_eq!(
+                    offset_date_time,
+                    OffsetDateTime::try_from(date_time).unwrap()
+                );
+            }
+        }
+
+        // The date in UTC and the local date have to be in range
+        let max = DateTime::from(PrimitiveDateTime::MAX);
+        assert!(OffsetDateTime::try_from(max).is_ok());
+        assert!(OffsetDateTime::try_from(max.set_offset(Offset::Fixed(1))).is_err());
+        assert!(OffsetDateTime::try_from(max.as_offset(Offset::Fixed(-1))).is_err());
+        let min = DateTime::from(PrimitiveDateTime::MIN);
+        assert!(OffsetDateTime::try_from(min.set_offset(Offset::Fixed(-1))).is_err());
+        assert!(OffsetDateTime::try_from(min.as_offset(Offset::Fixed(1))).is_err());
+
+        // time supports offsets of up to 25:59:59
+        let offset = UtcOffset::from_hms(25, 0, 0).unwrap();
+        let offset_date_time = OffsetDateTime::UNIX_EPOCH.to_offset(offset);
+        assert!(DateTime::try_from(offset_date_time).is_err());
+    }
+}